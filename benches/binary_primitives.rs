@@ -0,0 +1,105 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ion_rs::binary::writer::BinaryWriter;
+use ion_rs::raw_reader::{RawReader, StreamItem};
+use ion_rs::result::IonResult;
+use ion_rs::types::decimal::Decimal;
+use ion_rs::{IonType, RawBinaryReader};
+use std::io;
+
+const VALUE_COUNT: i64 = 10_000;
+
+// Each stream is dominated by small values so that the cost of decoding headers, lengths and the
+// primitive encodings below them outweighs the cost of everything else:
+//
+// * uint:     small positive and negative integers, whose magnitudes are UInts.
+// * decimal:  decimals, whose exponents are VarInts and whose coefficients are Ints.
+// * float:    32- and 64-bit floats.
+// * var_uint: annotated structs, whose field IDs, annotation lengths and annotations are VarUInts.
+fn write_stream(name: &str) -> IonResult<Vec<u8>> {
+    let mut buffer = Vec::new();
+    let mut writer = BinaryWriter::new(&mut buffer);
+    for i in 0..VALUE_COUNT {
+        match name {
+            "uint" => {
+                writer.write_i64(i % 200 - 100)?;
+                writer.write_i64(i * 1_000_003)?;
+            }
+            "decimal" => {
+                writer.write_decimal(&Decimal::new(i, -2))?;
+                writer.write_decimal(&Decimal::new(-i * 7, 3))?;
+            }
+            "float" => {
+                writer.write_f32(i as f32 * 0.5)?;
+                writer.write_f64(i as f64 * 0.25)?;
+            }
+            _ => {
+                writer.set_annotations(&["event"]);
+                writer.write_struct(|s| {
+                    s.set_field_name("id").write_i64(i)?;
+                    s.set_field_name("ok").write_bool(i % 2 == 0)?;
+                    s.set_field_name("tags").write_list(|l| {
+                        l.write_symbol("a")?;
+                        l.write_symbol("b")
+                    })
+                })?;
+            }
+        }
+    }
+    writer.flush()?;
+    drop(writer);
+    Ok(buffer)
+}
+
+// Reads every value in the stream, stepping into each container.
+fn read_stream(data: &[u8]) -> IonResult<usize> {
+    let mut reader = RawBinaryReader::new(io::Cursor::new(data));
+    read_values(&mut reader)
+}
+
+fn read_values<R: RawReader>(reader: &mut R) -> IonResult<usize> {
+    let mut values = 0;
+    while let Some(item) = reader.next()? {
+        let ion_type = match item {
+            StreamItem::Value(ion_type, false) => ion_type,
+            _ => continue,
+        };
+        values += 1;
+        match ion_type {
+            IonType::Integer => {
+                black_box(reader.read_i64()?);
+            }
+            IonType::Decimal => {
+                black_box(reader.read_decimal()?);
+            }
+            IonType::Float => {
+                black_box(reader.read_f64()?);
+            }
+            IonType::Boolean => {
+                black_box(reader.read_bool()?);
+            }
+            IonType::Symbol => {
+                black_box(reader.read_symbol()?);
+            }
+            IonType::List | IonType::SExpression | IonType::Struct => {
+                reader.step_in()?;
+                values += read_values(reader)?;
+                reader.step_out()?;
+            }
+            _ => {}
+        }
+    }
+    Ok(values)
+}
+
+fn binary_primitives(c: &mut Criterion) {
+    let mut group = c.benchmark_group("binary_primitives");
+    for name in &["uint", "decimal", "float", "var_uint"] {
+        let data = write_stream(name).unwrap();
+        group.throughput(Throughput::Bytes(data.len() as u64));
+        group.bench_function(*name, |b| b.iter(|| read_stream(black_box(&data)).unwrap()));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, binary_primitives);
+criterion_main!(benches);
@@ -0,0 +1,119 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ion_rs::result::IonResult;
+use ion_rs::text::raw_text_reader::RawTextReader;
+use ion_rs::{IonType, Reader};
+use nom::bytes::streaming::{is_a, is_not, take_until};
+use nom::IResult;
+
+// Builds a text Ion stream resembling application logs: indented structs holding long messages,
+// with the occasional escape sequence and comment.
+fn log_records(count: usize) -> String {
+    let mut text = String::new();
+    for id in 0..count {
+        text.push_str(&format!(
+            "// request {id}\n\
+             {{\n    \
+                 id: {id},\n    \
+                 level: INFO,\n    \
+                 message: \"Handled request {id} for /api/v1/items in 12ms; the cache was warm \
+                 and no downstream services were called.\\n\",\n    \
+                 detail: '''Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do \
+                 eiusmod tempor incididunt ut labore et dolore magna aliqua. It's \\x22quoted\\x22.'''\n\
+             }}\n\n",
+            id = id
+        ));
+    }
+    text
+}
+
+// Reads every value in `text`, visiting each field of each top-level struct.
+fn read_log_records(text: &str) -> IonResult<usize> {
+    let mut reader = Reader::new(RawTextReader::new(text));
+    let mut string_bytes = 0;
+    while let Some((ion_type, _)) = reader.next()? {
+        assert_eq!(ion_type, IonType::Struct);
+        reader.step_in()?;
+        while let Some((ion_type, _)) = reader.next()? {
+            if ion_type == IonType::String {
+                string_bytes += reader.read_string()?.map(|s| s.len()).unwrap_or(0);
+            }
+        }
+        reader.step_out()?;
+    }
+    Ok(string_bytes)
+}
+
+// Compares the reader against the scanning primitives' byte-at-a-time baselines. Criterion
+// stores the results of each run, so before-and-after numbers for the reader can be compared by
+// running this benchmark with `--save-baseline` on one revision and `--baseline` on another.
+fn text_scanning(c: &mut Criterion) {
+    let text = log_records(1_000);
+
+    let mut group = c.benchmark_group("text_reader");
+    group.throughput(Throughput::Bytes(text.len() as u64));
+    group.bench_function("log_records", |b| {
+        b.iter(|| read_log_records(black_box(&text)).unwrap())
+    });
+    group.finish();
+
+    // A long string body containing no escapes, a comment and a whitespace run, each of which
+    // the text parsers must scan to the end of. The trailing delimiter ends each scan.
+    let body = "Lorem ipsum dolor sit amet, consectetur adipiscing elit. ".repeat(64);
+    let short_string = format!("{}\"", body);
+    let long_string = format!("{}'''", body);
+    let comment = format!("{}*/", body);
+    let whitespace = format!("{}x", " \n\t\r".repeat(256));
+
+    let mut group = c.benchmark_group("scanning");
+    for (name, input) in &[
+        ("short_string", &short_string),
+        ("long_string", &long_string),
+        ("comment", &comment),
+        ("whitespace", &whitespace),
+    ] {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::new("memchr", name), input, |b, input| {
+            b.iter(|| scan_memchr(name, black_box(input)))
+        });
+        group.bench_with_input(BenchmarkId::new("nom", name), input, |b, input| {
+            b.iter(|| scan_nom(name, black_box(input)).unwrap().1.len())
+        });
+    }
+    group.finish();
+}
+
+// Finds the length of the run at the head of `input` the way the text parsers do.
+fn scan_memchr(name: &str, input: &str) -> usize {
+    let bytes = input.as_bytes();
+    match name {
+        "short_string" => memchr::memchr2(b'"', b'\\', bytes).unwrap(),
+        "long_string" => {
+            let mut position = 0;
+            loop {
+                let index = position + memchr::memchr2(b'\'', b'\\', &bytes[position..]).unwrap();
+                if bytes[index] == b'\\' || bytes[index..].starts_with(b"'''") {
+                    break index;
+                }
+                position = index + 1;
+            }
+        }
+        "comment" => memchr::memmem::find(bytes, b"*/").unwrap(),
+        _ => bytes
+            .iter()
+            .position(|byte| !matches!(byte, b' ' | b'\t' | b'\r' | b'\n'))
+            .unwrap(),
+    }
+}
+
+// Finds the length of the run at the head of `input` with nom's byte-at-a-time combinators.
+fn scan_nom<'a>(name: &str, input: &'a str) -> IResult<&'a str, &'a str> {
+    match name {
+        "short_string" => is_not("\"\\")(input),
+        "long_string" => take_until("'''")(input),
+        "comment" => take_until("*/")(input),
+        _ => is_a(" \t\r\n")(input),
+    }
+}
+
+criterion_group!(benches, text_scanning);
+criterion_main!(benches);
@@ -0,0 +1,64 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ion_rs::binary::writer::BinaryWriter;
+use ion_rs::result::IonResult;
+use ion_rs::IonType;
+
+// The number of top-level values written in each iteration
+const VALUES_PER_ITERATION: usize = 1_000;
+
+// Writes a chain of `depth` structs, the innermost of which holds a few scalars:
+//
+//     {child: {child: ... {name: "leaf", id: 0, score: 1.5e0} ...}}
+fn write_nested_struct(
+    writer: &mut BinaryWriter<&mut Vec<u8>>,
+    depth: usize,
+    id: i64,
+) -> IonResult<()> {
+    writer.step_in(IonType::Struct)?;
+    for _ in 1..depth {
+        writer.set_field_name("child");
+        writer.step_in(IonType::Struct)?;
+    }
+    writer.set_field_name("name");
+    writer.write_string("leaf")?;
+    writer.set_field_name("id");
+    writer.write_i64(id)?;
+    writer.set_field_name("score");
+    writer.write_f64(1.5)?;
+    for _ in 0..depth {
+        writer.step_out()?;
+    }
+    Ok(())
+}
+
+fn write_values(output: &mut Vec<u8>, depth: usize) -> IonResult<()> {
+    let mut writer = BinaryWriter::new(output);
+    for id in 0..VALUES_PER_ITERATION {
+        write_nested_struct(&mut writer, depth, id as i64)?;
+    }
+    writer.flush()
+}
+
+// Measures the binary writer's throughput as the nesting depth of the values it writes grows.
+// Finding each container's length takes constant time, so the throughput in bytes levels off
+// instead of falling as the depth grows. See the `read` benchmark for how to compare results
+// across revisions.
+fn nested_write(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nested_write");
+    for depth in [1, 8, 64, 256] {
+        let mut output = Vec::new();
+        write_values(&mut output, depth).unwrap();
+        group.throughput(Throughput::Bytes(output.len() as u64));
+        group.bench_with_input(BenchmarkId::new("depth", depth), &depth, |b, &depth| {
+            b.iter(|| {
+                output.clear();
+                write_values(&mut output, black_box(depth)).unwrap();
+                output.len()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, nested_write);
+criterion_main!(benches);
@@ -0,0 +1,70 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ion_rs::result::IonResult;
+use ion_rs::text::raw_text_reader::RawTextReader;
+use ion_rs::value::reader::{element_reader, ElementReader};
+use ion_rs::{RawBinaryReader, RawReader, Reader};
+use ion_rs_benchmarks::datasets;
+use std::io;
+
+// Reads every top-level value into an OwnedElement, returning the number of values read.
+fn read_elements<C: RawReader>(mut reader: Reader<C>) -> IonResult<usize> {
+    let mut count = 0;
+    while reader.read_element()?.is_some() {
+        count += 1;
+    }
+    Ok(count)
+}
+
+// Each dataset is read into elements by the native readers and by the Ion C bindings, and into
+// `serde_json::Value`s from its JSON encoding. The encodings differ in size, so throughput is
+// reported in values rather than bytes.
+//
+// Criterion stores the results of each run, so regressions can be found by running
+// `cargo bench -p ion-rs-benchmarks -- --save-baseline <name>` on one revision and
+// `--baseline <name>` on another.
+fn read(c: &mut Criterion) {
+    for dataset in datasets() {
+        let mut group = c.benchmark_group(format!("read/{}", dataset.name));
+        group.throughput(Throughput::Elements(dataset.elements.len() as u64));
+        group.bench_function("ion-rs/binary", |b| {
+            b.iter(|| {
+                let raw_reader = RawBinaryReader::new(io::Cursor::new(black_box(&dataset.binary)));
+                read_elements(Reader::new(raw_reader)).unwrap()
+            })
+        });
+        group.bench_function("ion-rs/text", |b| {
+            b.iter(|| {
+                let raw_reader = RawTextReader::new(black_box(dataset.text.as_str()));
+                read_elements(Reader::new(raw_reader)).unwrap()
+            })
+        });
+        group.bench_function("ion-c/binary", |b| {
+            b.iter(|| {
+                element_reader()
+                    .read_all(black_box(&dataset.binary))
+                    .unwrap()
+                    .len()
+            })
+        });
+        group.bench_function("ion-c/text", |b| {
+            b.iter(|| {
+                element_reader()
+                    .read_all(black_box(dataset.text.as_bytes()))
+                    .unwrap()
+                    .len()
+            })
+        });
+        group.bench_function("serde_json", |b| {
+            b.iter(|| {
+                serde_json::Deserializer::from_str(black_box(&dataset.json))
+                    .into_iter::<serde_json::Value>()
+                    .map(|value| value.unwrap())
+                    .count()
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, read);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use ion_rs::binary::writer::BinaryWriter;
+use ion_rs::value::writer::{ElementWriter, Format};
+use ion_rs_benchmarks::datasets;
+
+// Each dataset is written from elements (or, for the baseline, from `serde_json::Value`s) to an
+// in-memory buffer. See the `read` benchmark for how to compare results across revisions.
+fn write(c: &mut Criterion) {
+    for dataset in datasets() {
+        let json_values: Vec<serde_json::Value> = serde_json::Deserializer::from_str(&dataset.json)
+            .into_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        // The Ion C writer needs a buffer that is large enough for its output up front.
+        let mut ion_c_buffer = vec![0u8; dataset.binary.len() * 2 + 4096];
+
+        let mut group = c.benchmark_group(format!("write/{}", dataset.name));
+        group.throughput(Throughput::Elements(dataset.elements.len() as u64));
+        group.bench_function("ion-rs/binary", |b| {
+            b.iter(|| {
+                let mut buffer = Vec::with_capacity(dataset.binary.len());
+                let mut writer = BinaryWriter::new(&mut buffer);
+                for element in black_box(&dataset.elements) {
+                    writer.write_element(element).unwrap();
+                }
+                writer.flush().unwrap();
+                drop(writer);
+                buffer.len()
+            })
+        });
+        group.bench_function("ion-c/binary", |b| {
+            b.iter(|| {
+                let mut writer = Format::Binary
+                    .element_writer_for_slice(&mut ion_c_buffer)
+                    .unwrap();
+                writer.write_all(black_box(&dataset.elements)).unwrap();
+                writer.finish().unwrap().len()
+            })
+        });
+        group.bench_function("serde_json", |b| {
+            b.iter(|| {
+                let mut buffer = Vec::with_capacity(dataset.json.len());
+                for value in black_box(&json_values) {
+                    serde_json::to_writer(&mut buffer, value).unwrap();
+                    buffer.push(b'\n');
+                }
+                buffer.len()
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, write);
+criterion_main!(benches);
@@ -0,0 +1,145 @@
+//! Datasets for the benchmarks in `benches/`, which compare `ion-rs` with the Ion C bindings and
+//! with `serde_json`.
+//!
+//! Each dataset is generated deterministically and then encoded as binary Ion, as text Ion and
+//! as newline-delimited JSON, so that every implementation being measured reads (or writes) the
+//! same values. The JSON encoding is the standard Ion-to-JSON down-conversion; see
+//! [ion_rs::value::json].
+
+use ion_rs::binary::writer::BinaryWriter;
+use ion_rs::result::IonResult;
+use ion_rs::value::json::to_json;
+use ion_rs::value::owned::OwnedElement;
+
+/// A set of top-level values in each of the encodings being compared.
+pub struct Dataset {
+    pub name: &'static str,
+    pub elements: Vec<OwnedElement>,
+    pub binary: Vec<u8>,
+    pub text: String,
+    pub json: String,
+}
+
+impl Dataset {
+    fn from_text(name: &'static str, text: String) -> IonResult<Dataset> {
+        let elements = OwnedElement::read_all(text.as_bytes())?;
+        let mut binary = Vec::new();
+        let mut writer = BinaryWriter::new(&mut binary);
+        for element in &elements {
+            writer.write_element(element)?;
+        }
+        writer.flush()?;
+        drop(writer);
+        let mut json = String::new();
+        for element in &elements {
+            json.push_str(&to_json(element)?);
+            json.push('\n');
+        }
+        Ok(Dataset {
+            name,
+            elements,
+            binary,
+            text,
+            json,
+        })
+    }
+}
+
+/// Returns every dataset:
+///
+/// * `nested_structs`: orders holding a customer record and a list of line items, several levels
+///   deep, which exercise stepping in and out of containers.
+/// * `flat_logs`: single-level log records of short scalars, which exercise per-value overhead.
+/// * `lob_heavy`: records that each hold a 3 KiB blob, which exercise bulk byte copying.
+pub fn datasets() -> Vec<Dataset> {
+    vec![
+        Dataset::from_text("nested_structs", nested_structs(500)),
+        Dataset::from_text("flat_logs", flat_logs(5_000)),
+        Dataset::from_text("lob_heavy", lob_heavy(200)),
+    ]
+    .into_iter()
+    .collect::<IonResult<_>>()
+    .expect("the generated datasets are valid Ion")
+}
+
+fn nested_structs(count: usize) -> String {
+    let mut text = String::new();
+    for id in 0..count {
+        text.push_str(&format!(
+            "{{id: {id}, created: 2021-03-04T05:{minute:02}:06Z, status: shipped, \
+             customer: {{name: \"Customer {id}\", tier: gold, \
+                address: {{street: \"{id} Main St\", city: \"Seattle\", zip: \"98101\"}}}}, \
+             items: [",
+            id = id,
+            minute = id % 60
+        ));
+        let items: Vec<String> = (0..(id % 5 + 1))
+            .map(|item| {
+                format!(
+                    "{{sku: \"SKU-{item:04}\", quantity: {quantity}, price: {price}.99, \
+                     tags: [sale, \"category-{item}\"]}}",
+                    item = item,
+                    quantity = item + 1,
+                    price = item * 10 + 5
+                )
+            })
+            .collect();
+        text.push_str(&items.join(", "));
+        text.push_str("]}\n");
+    }
+    text
+}
+
+fn flat_logs(count: usize) -> String {
+    let levels = ["DEBUG", "INFO", "INFO", "INFO", "WARN", "ERROR"];
+    let mut text = String::new();
+    for id in 0..count {
+        text.push_str(&format!(
+            "{{timestamp: 2021-03-04T05:06:{second:02}.{millis:03}Z, level: {level}, \
+             service: \"checkout\", host: \"host-{host}\", request_id: {id}, latency_ms: {latency}, \
+             ok: {ok}, message: \"Handled request {id} for /api/v1/items\"}}\n",
+            second = id % 60,
+            millis = id % 1000,
+            level = levels[id % levels.len()],
+            host = id % 16,
+            id = id,
+            latency = (id * 7) % 250,
+            ok = id % 10 != 0
+        ));
+    }
+    text
+}
+
+fn lob_heavy(count: usize) -> String {
+    // 3 KiB of "ABC"
+    let content = "QUJD".repeat(1024);
+    let mut text = String::new();
+    for id in 0..count {
+        text.push_str(&format!(
+            "{{name: \"file-{id}.bin\", size: 3072, content: {{{{{content}}}}}}}\n",
+            id = id,
+            content = content
+        ));
+    }
+    text
+}
+
+#[cfg(test)]
+mod dataset_tests {
+    use super::*;
+
+    #[test]
+    fn encodings_hold_the_same_values() -> IonResult<()> {
+        for dataset in datasets() {
+            assert!(!dataset.elements.is_empty());
+            assert_eq!(OwnedElement::read_all(&dataset.binary)?, dataset.elements);
+            assert_eq!(
+                dataset.json.lines().count(),
+                dataset.elements.len(),
+                "{}",
+                dataset.name
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,28 +1,41 @@
 use ion_rs::raw_reader::StreamItem;
 use ion_rs::result::IonResult;
-use ion_rs::{IonDataSource, IonType, RawBinaryReader, RawReader};
+use ion_rs::text::raw_text_reader::RawTextReader;
+use ion_rs::{IonType, RawBinaryReader, RawReader};
 use std::fs::File;
+use std::io::BufRead;
 use std::process::exit;
 
+// The binary Ion 1.0 version marker, which begins every binary Ion stream
+const BINARY_IVM: [u8; 4] = [0xE0, 0x01, 0x00, 0xEA];
+
 fn main() -> IonResult<()> {
     let args: Vec<String> = std::env::args().collect();
     let path = args.get(1).unwrap_or_else(|| {
-        eprintln!("USAGE:\n\n    {} [Binary Ion file]\n", args.get(0).unwrap());
+        eprintln!(
+            "USAGE:\n\n    {} [Binary or text Ion file]\n",
+            args.get(0).unwrap()
+        );
         eprintln!("No input file was specified.");
         exit(1);
     });
 
     let file = File::open(path)?;
-    let buf_reader = std::io::BufReader::new(file);
-    let mut cursor = RawBinaryReader::new(buf_reader);
-    let number_of_values = read_all_values(&mut cursor)?;
+    let mut buf_reader = std::io::BufReader::new(file);
+    // Binary streams start with an IVM; anything else is treated as text Ion.
+    let is_binary = buf_reader.fill_buf()?.starts_with(&BINARY_IVM);
+    let number_of_values = if is_binary {
+        read_all_values(&mut RawBinaryReader::new(buf_reader))?
+    } else {
+        read_all_values(&mut RawTextReader::new(buf_reader))?
+    };
     println!("Read {} values", number_of_values);
     Ok(())
 }
 
 // Visits each value in the stream recursively, reading each scalar into a native Rust type.
 // Prints the total number of values read upon completion.
-fn read_all_values<R: IonDataSource>(cursor: &mut RawBinaryReader<R>) -> IonResult<usize> {
+fn read_all_values<R: RawReader>(cursor: &mut R) -> IonResult<usize> {
     use IonType::*;
     use StreamItem::*;
     let mut count: usize = 0;
@@ -0,0 +1,164 @@
+use ion_rs::binary::writer::BinaryWriter;
+use ion_rs::result::IonResult;
+use ion_rs::{IonType, RawBinaryReader, Reader};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::io;
+use std::process::exit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+// Counts every allocation made by the program so that constructing a new reader or writer for
+// each document can be compared with resetting a single one.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// Encodes and then decodes many small, independent binary Ion documents of the form
+//
+//     {id: 0, name: "document", tags: [a, b, c]}
+//
+// first constructing a new writer and reader for each document, and then reusing a single
+// writer and reader by calling `reset()`. Prints the number of allocations and the time taken
+// by each approach.
+fn main() -> IonResult<()> {
+    let args: Vec<String> = std::env::args().collect();
+    let count = match args.get(1).map(|arg| arg.parse::<usize>()) {
+        None => 100_000,
+        Some(Ok(count)) => count,
+        Some(Err(_)) => {
+            eprintln!(
+                "USAGE:\n\n    {} [number of documents (default 100000)]\n",
+                args.get(0).unwrap()
+            );
+            exit(1);
+        }
+    };
+
+    let (documents, allocations, elapsed) = measure(|| {
+        (0..count)
+            .map(|id| {
+                let mut writer = BinaryWriter::new(Vec::new());
+                write_document(&mut writer, id as i64)?;
+                Ok(writer.reset(Vec::new()))
+            })
+            .collect::<IonResult<Vec<Vec<u8>>>>()
+    })?;
+    report(
+        "Writing with a new writer per document",
+        count,
+        allocations,
+        elapsed,
+    );
+
+    let (reused_documents, allocations, elapsed) = measure(|| {
+        let mut writer = BinaryWriter::new(Vec::new());
+        (0..count)
+            .map(|id| {
+                write_document(&mut writer, id as i64)?;
+                Ok(writer.reset(Vec::new()))
+            })
+            .collect::<IonResult<Vec<Vec<u8>>>>()
+    })?;
+    report("Writing with one reset writer", count, allocations, elapsed);
+    assert_eq!(documents, reused_documents);
+
+    let (_, allocations, elapsed) = measure(|| {
+        for document in &documents {
+            let mut reader =
+                Reader::new(RawBinaryReader::new(io::Cursor::new(document.as_slice())));
+            read_document(&mut reader)?;
+        }
+        Ok(())
+    })?;
+    report(
+        "Reading with a new reader per document",
+        count,
+        allocations,
+        elapsed,
+    );
+
+    let (_, allocations, elapsed) = measure(|| {
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(&[][..])));
+        for document in &documents {
+            reader.reset(io::Cursor::new(document.as_slice()));
+            read_document(&mut reader)?;
+        }
+        Ok(())
+    })?;
+    report("Reading with one reset reader", count, allocations, elapsed);
+    Ok(())
+}
+
+// Runs `f`, returning its result along with the number of allocations it made and its duration.
+fn measure<T, F>(f: F) -> IonResult<(T, usize, std::time::Duration)>
+where
+    F: FnOnce() -> IonResult<T>,
+{
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let result = f()?;
+    let elapsed = start.elapsed();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations_before;
+    Ok((result, allocations, elapsed))
+}
+
+fn report(description: &str, count: usize, allocations: usize, elapsed: std::time::Duration) {
+    println!(
+        "{}: {} allocations ({:.1} per document) in {:?}",
+        description,
+        allocations,
+        allocations as f64 / count as f64,
+        elapsed
+    );
+}
+
+fn write_document(writer: &mut BinaryWriter<Vec<u8>>, id: i64) -> IonResult<()> {
+    writer.write_struct(|s| {
+        s.set_field_name("id").write_i64(id)?;
+        s.set_field_name("name").write_string("document")?;
+        s.set_field_name("tags").write_list(|l| {
+            l.write_symbol("a")?;
+            l.write_symbol("b")?;
+            l.write_symbol("c")
+        })
+    })?;
+    writer.flush()
+}
+
+fn read_document(reader: &mut Reader<RawBinaryReader<io::Cursor<&[u8]>>>) -> IonResult<()> {
+    assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+    reader.step_in()?;
+    while let Some((ion_type, _)) = reader.next()? {
+        match ion_type {
+            IonType::Integer => {
+                let _id = reader.read_i64()?;
+            }
+            IonType::String => {
+                let _name = reader.read_str()?;
+            }
+            IonType::List => {
+                reader.step_in()?;
+                while reader.next()?.is_some() {
+                    let _tag = reader.read_raw_symbol()?;
+                }
+                reader.step_out()?;
+            }
+            _ => {}
+        }
+    }
+    reader.step_out()
+}
@@ -0,0 +1,20 @@
+#![no_main]
+//! Reads arbitrary bytes as a binary Ion stream. Malformed input should be reported as an
+//! `IonError`; the reader must never panic.
+
+use std::io;
+
+use libfuzzer_sys::fuzz_target;
+
+use ion_rs::{RawBinaryReader, Reader};
+
+fuzz_target!(|data: &[u8]| {
+    let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data)));
+    // Reading each top-level value as an element visits every nested value and the symbol
+    // tables between them.
+    while let Ok(Some(_)) = reader.next() {
+        if reader.read_element().is_err() {
+            break;
+        }
+    }
+});
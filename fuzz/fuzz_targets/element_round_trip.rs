@@ -0,0 +1,27 @@
+#![no_main]
+//! Writes arbitrary elements with the binary writer and checks that reading them back produces
+//! the same elements.
+
+use std::io;
+
+use libfuzzer_sys::fuzz_target;
+
+use ion_rs::binary::writer::BinarySystemWriter;
+use ion_rs::value::owned::OwnedElement;
+use ion_rs::{RawBinaryReader, Reader};
+
+fuzz_target!(|element: OwnedElement| {
+    let mut buffer = Vec::new();
+    let mut writer = BinarySystemWriter::new(&mut buffer);
+    writer.write_element(&element).unwrap();
+    writer.flush().unwrap();
+    drop(writer);
+
+    let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+    reader.next().unwrap();
+    let actual = reader.read_element().unwrap().unwrap();
+    // Elements containing a NaN are not equal to themselves.
+    if element == element {
+        assert_eq!(element, actual);
+    }
+});
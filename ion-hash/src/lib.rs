@@ -15,10 +15,28 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! Values can also be hashed directly from a [`Reader`] with [`hash_current_value`] (or
+//! [`sha256_current_value`]):
+//! ```rust
+//! use ion_rs::result::IonResult;
+//! use ion_rs::text::raw_text_reader::RawTextReader;
+//! use ion_rs::Reader;
+//!
+//! # fn main() -> IonResult<()> {
+//!   let mut reader = Reader::new(RawTextReader::new("\"hello world\""));
+//!   reader.next()?;
+//!   let digest = ion_hash::sha256_current_value(&mut reader)?;
+//!   println!("{:?}", digest);
+//! # Ok(())
+//! # }
+//! ```
 
 use digest::{FixedOutput, Output, Reset, Update};
 use ion_rs::result::IonResult;
+use ion_rs::value::owned::OwnedElement;
 use ion_rs::value::Element;
+use ion_rs::{RawReader, Reader};
 
 // TODO: Make sha2 an optional dependency.
 use sha2::Sha256;
@@ -35,6 +53,29 @@ pub fn sha256<E: Element + ?Sized>(elem: &E) -> IonResult<Output<Sha256>> {
     Sha256::hash_element(elem)
 }
 
+/// Utility to hash the current value of a [`Reader`] using SHA-256 as the hash function.
+/// See [`hash_current_value`].
+pub fn sha256_current_value<C: RawReader>(
+    reader: &mut Reader<C>,
+) -> IonResult<Option<Output<Sha256>>> {
+    hash_current_value::<Sha256, C>(reader)
+}
+
+/// Returns the Ion Hash of the value that `reader` is positioned on, computed with the digest
+/// `D`, or `None` if the reader is not positioned on a value. Symbols are hashed using their
+/// text as resolved by the reader's symbol table. Containers are read in their entirety, leaving
+/// the reader positioned after the value.
+pub fn hash_current_value<D, C>(reader: &mut Reader<C>) -> IonResult<Option<Output<D>>>
+where
+    D: Update + FixedOutput + Reset + Clone + Default,
+    C: RawReader,
+{
+    match reader.read_element()? {
+        Some(element) => <D as IonHasher<OwnedElement>>::hash_element(&element).map(Some),
+        None => Ok(None),
+    }
+}
+
 /// Bytes markers as per the spec.
 struct Markers;
 impl Markers {
@@ -0,0 +1,22 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+use ion_rs::result::IonResult;
+use ion_rs::text::raw_text_reader::RawTextReader;
+use ion_rs::value::reader::{element_reader, ElementReader};
+use ion_rs::Reader;
+
+#[test]
+fn hash_values_from_reader() -> IonResult<()> {
+    let text = r#"$ion_symbol_table::{symbols: ["a"]} {$10: [1, foo::"two"]} 3"#;
+    let elements = element_reader().read_all(text.as_bytes())?;
+    let mut reader = Reader::new(RawTextReader::new(text));
+
+    assert_eq!(ion_hash::sha256_current_value(&mut reader)?, None);
+    for element in &elements {
+        assert!(reader.next()?.is_some());
+        let digest = ion_hash::sha256_current_value(&mut reader)?;
+        assert_eq!(Some(ion_hash::sha256(element)?), digest);
+    }
+    assert_eq!(reader.next()?, None);
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_quote, Attribute, Data, DeriveInput, Fields, Generics, Ident, Lit, Meta, NestedMeta,
+};
+
+/// Derives `ion_rs::convert::IonSerialize` for a struct with named fields. See the
+/// `ion_rs::convert` module for the `#[ion(...)]` attributes that this macro supports.
+#[proc_macro_derive(IonSerialize, attributes(ion))]
+pub fn derive_ion_serialize(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_ion_serialize(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `ion_rs::convert::IonDeserialize` for a struct with named fields. See the
+/// `ion_rs::convert` module for the `#[ion(...)]` attributes that this macro supports.
+#[proc_macro_derive(IonDeserialize, attributes(ion))]
+pub fn derive_ion_deserialize(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand_ion_deserialize(input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+fn expand_ion_serialize(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let container = IonAttributes::parse(&input.attrs)?;
+    let fields = named_fields(&input)?;
+    let field_values = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let mut value = if field.attributes.symbol {
+            quote!(::ion_rs::convert::symbol_to_ion(&self.#ident))
+        } else {
+            quote!(::ion_rs::convert::IonSerialize::to_ion(&self.#ident))
+        };
+        if let Some(precision) = &field.attributes.precision {
+            value = quote!(::ion_rs::convert::truncate_timestamp(
+                #value,
+                ::ion_rs::types::timestamp::Precision::#precision
+            ));
+        }
+        let annotations = &field.attributes.annotations;
+        let name = &field.name;
+        quote!((#name, ::ion_rs::convert::annotate(#value, &[#(#annotations),*])))
+    });
+
+    let name = &input.ident;
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        parse_quote!(::ion_rs::convert::IonSerialize),
+    );
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let annotations = &container.annotations;
+    Ok(quote! {
+        impl #impl_generics ::ion_rs::convert::IonSerialize for #name #type_generics
+        #where_clause
+        {
+            fn to_ion(&self) -> ::ion_rs::value::owned::OwnedElement {
+                ::ion_rs::convert::annotate(
+                    ::ion_rs::convert::struct_to_ion(vec![#(#field_values),*]),
+                    &[#(#annotations),*],
+                )
+            }
+        }
+    })
+}
+
+fn expand_ion_deserialize(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let container = IonAttributes::parse(&input.attrs)?;
+    let fields = named_fields(&input)?;
+    let field_values = fields.iter().map(|field| {
+        let ident = &field.ident;
+        let name = &field.name;
+        let value = if field.attributes.symbol {
+            quote!(::ion_rs::convert::symbol_from_ion(value))
+        } else {
+            quote!(::ion_rs::convert::IonDeserialize::from_ion(value))
+        };
+        let annotations = &field.attributes.annotations;
+        quote! {
+            #ident: ::ion_rs::convert::field_from_ion(fields, #name, |value| {
+                ::ion_rs::convert::expect_annotations(value, &[#(#annotations),*])?;
+                #value
+            })?
+        }
+    });
+
+    let name = &input.ident;
+    let generics = add_trait_bounds(
+        input.generics.clone(),
+        parse_quote!(::ion_rs::convert::IonDeserialize),
+    );
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let annotations = &container.annotations;
+    Ok(quote! {
+        impl #impl_generics ::ion_rs::convert::IonDeserialize for #name #type_generics
+        #where_clause
+        {
+            fn from_ion(
+                element: &::ion_rs::value::owned::OwnedElement,
+            ) -> ::ion_rs::result::IonResult<Self> {
+                ::ion_rs::convert::expect_annotations(element, &[#(#annotations),*])?;
+                let fields = ::ion_rs::convert::struct_fields(element)?;
+                Ok(#name {
+                    #(#field_values),*
+                })
+            }
+        }
+    })
+}
+
+// A field of the struct being derived, along with the name of the Ion field it maps to.
+struct NamedField {
+    ident: Ident,
+    name: String,
+    attributes: IonAttributes,
+}
+
+fn named_fields(input: &DeriveInput) -> syn::Result<Vec<NamedField>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "Ion conversions can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Ion conversions can only be derived for structs",
+            ))
+        }
+    };
+    fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().expect("named fields have identifiers");
+            let attributes = IonAttributes::parse(&field.attrs)?;
+            let name = attributes
+                .rename
+                .clone()
+                .unwrap_or_else(|| ident.to_string().trim_start_matches("r#").to_string());
+            Ok(NamedField {
+                ident,
+                name,
+                attributes,
+            })
+        })
+        .collect()
+}
+
+fn add_trait_bounds(mut generics: Generics, bound: syn::TypeParamBound) -> Generics {
+    for param in generics.type_params_mut() {
+        param.bounds.push(bound.clone());
+    }
+    generics
+}
+
+// The settings specified by the `#[ion(...)]` attributes on a struct or one of its fields.
+#[derive(Default)]
+struct IonAttributes {
+    annotations: Vec<String>,
+    rename: Option<String>,
+    symbol: bool,
+    precision: Option<Ident>,
+}
+
+impl IonAttributes {
+    fn parse(attrs: &[Attribute]) -> syn::Result<IonAttributes> {
+        let mut attributes = IonAttributes::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("ion")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(syn::Error::new_spanned(meta, "expected `#[ion(...)]`")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("symbol") => {
+                        attributes.symbol = true;
+                    }
+                    NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                        let value = match &name_value.lit {
+                            Lit::Str(value) => value,
+                            lit => return Err(syn::Error::new_spanned(lit, "expected a string")),
+                        };
+                        let path = &name_value.path;
+                        if path.is_ident("annotation") {
+                            attributes.annotations.push(value.value());
+                        } else if path.is_ident("rename") {
+                            attributes.rename = Some(value.value());
+                        } else if path.is_ident("precision") {
+                            attributes.precision = Some(parse_precision(value)?);
+                        } else {
+                            return Err(syn::Error::new_spanned(path, "unknown Ion attribute"));
+                        }
+                    }
+                    nested => {
+                        return Err(syn::Error::new_spanned(nested, "unknown Ion attribute"));
+                    }
+                }
+            }
+        }
+        Ok(attributes)
+    }
+}
+
+// Converts the value of a `precision` attribute to the name of an
+// `ion_rs::types::timestamp::Precision` variant.
+fn parse_precision(value: &syn::LitStr) -> syn::Result<Ident> {
+    let variant = match value.value().as_str() {
+        "year" => "Year",
+        "month" => "Month",
+        "day" => "Day",
+        "minute" => "HourAndMinute",
+        "second" => "Second",
+        "fractional_seconds" => "FractionalSeconds",
+        _ => {
+            return Err(syn::Error::new_spanned(
+                value,
+                "expected one of `year`, `month`, `day`, `minute`, `second` or \
+                `fractional_seconds`",
+            ))
+        }
+    };
+    Ok(Ident::new(variant, value.span()))
+}
@@ -0,0 +1,107 @@
+use ion_rs::convert::{IonDeserialize, IonSerialize};
+use ion_rs::result::IonResult;
+use ion_rs::types::timestamp::{Precision, Timestamp};
+use ion_rs::value::owned::OwnedElement;
+use ion_rs::value::{Element, Struct};
+use ion_rs::IonType;
+
+#[derive(Debug, PartialEq, IonSerialize, IonDeserialize)]
+#[ion(annotation = "event")]
+struct Event {
+    #[ion(symbol)]
+    kind: String,
+    #[ion(rename = "at", precision = "second")]
+    time: Timestamp,
+    #[ion(annotation = "ms")]
+    duration: u32,
+    tags: Vec<String>,
+    detail: Option<String>,
+}
+
+#[derive(Debug, PartialEq, IonSerialize, IonDeserialize)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+fn element(text: &str) -> OwnedElement {
+    OwnedElement::read_all(text.as_bytes()).unwrap().remove(0)
+}
+
+fn event() -> IonResult<Event> {
+    Ok(Event {
+        kind: "deploy".to_string(),
+        time: Timestamp::with_ymd(2022, 1, 2)
+            .with_hms(3, 4, 5)
+            .with_milliseconds(678)
+            .build_at_offset(0)?,
+        duration: 1500,
+        tags: vec!["web".to_string(), "canary".to_string()],
+        detail: None,
+    })
+}
+
+#[test]
+fn serialize_struct_with_attributes() -> IonResult<()> {
+    let expected = element(
+        r#"event::{
+            kind: deploy,
+            at: 2022-01-02T03:04:05+00:00,
+            duration: ms::1500,
+            tags: ["web", "canary"],
+            detail: null,
+        }"#,
+    );
+    let actual = event()?.to_ion();
+    assert_eq!(expected, actual);
+    let fields = actual.as_struct().unwrap();
+    assert_eq!(IonType::Symbol, fields.get("kind").unwrap().ion_type());
+    let time = fields.get("at").unwrap().as_timestamp().unwrap();
+    assert_eq!(Precision::Second, time.precision());
+    Ok(())
+}
+
+#[test]
+fn deserialize_struct_with_attributes() -> IonResult<()> {
+    let text = event()?.to_ion().to_string();
+    let mut expected = event()?;
+    expected.time = expected.time.truncated_to(Precision::Second);
+    assert_eq!(expected, Event::from_ion(&element(&text))?);
+
+    // Missing `Option` fields are `None`.
+    let event = Event::from_ion(&element(
+        r#"event::{kind: deploy, at: 2022-01-02T03:04:05Z, duration: ms::1500, tags: []}"#,
+    ))?;
+    assert_eq!(None, event.detail);
+    Ok(())
+}
+
+#[test]
+fn deserialize_struct_errors() {
+    for text in &[
+        // missing the `event` annotation
+        r#"{kind: deploy, at: 2022-01-02T03:04:05Z, duration: ms::1500, tags: []}"#,
+        // `kind` is a string rather than a symbol
+        r#"event::{kind: "deploy", at: 2022-01-02T03:04:05Z, duration: ms::1500, tags: []}"#,
+        // `duration` is missing the `ms` annotation
+        r#"event::{kind: deploy, at: 2022-01-02T03:04:05Z, duration: 1500, tags: []}"#,
+        // `tags` is missing
+        r#"event::{kind: deploy, at: 2022-01-02T03:04:05Z, duration: ms::1500}"#,
+        // not a struct
+        r#"event::[kind, deploy]"#,
+    ] {
+        assert!(Event::from_ion(&element(text)).is_err(), "{}", text);
+    }
+}
+
+#[test]
+fn generic_struct_round_trip() -> IonResult<()> {
+    let pair = Pair {
+        first: 1i64,
+        second: 2i64,
+    };
+    let ion = pair.to_ion();
+    assert_eq!(element("{first: 1, second: 2}"), ion);
+    assert_eq!(pair, Pair::from_ion(&ion)?);
+    Ok(())
+}
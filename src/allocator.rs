@@ -0,0 +1,103 @@
+//! Memory allocators for the [RawBinaryReader](crate::RawBinaryReader)'s internal buffers.
+//!
+//! When the `allocator` feature is enabled, this module re-exports the [Allocator] trait and
+//! [Global] allocator from the [allocator-api2](https://docs.rs/allocator-api2) crate, and a custom
+//! allocator can be passed to [RawBinaryReaderBuilder::allocator](crate::RawBinaryReaderBuilder).
+//! When it is disabled, stand-ins with the same names are provided so that the reader's type
+//! signature is the same with or without the feature; [Global] is the only allocator available.
+
+use crate::data_source::FallbackBuffer;
+
+#[cfg(feature = "allocator")]
+pub use allocator_api2::alloc::{Allocator, Global};
+
+// A Vec whose storage is obtained from an `Allocator`.
+#[cfg(feature = "allocator")]
+pub(crate) use allocator_api2::vec::Vec as AllocVec;
+
+#[cfg(not(feature = "allocator"))]
+pub use fallback::{Allocator, Global};
+
+#[cfg(not(feature = "allocator"))]
+pub(crate) use fallback::AllocVec;
+
+#[cfg(not(feature = "allocator"))]
+mod fallback {
+    use alloc::vec::Vec;
+    use core::marker::PhantomData;
+    use core::ops::{Deref, DerefMut};
+
+    /// Stands in for allocator-api2's `Allocator` trait when the `allocator` feature is
+    /// disabled. [Global] is its only implementation.
+    pub trait Allocator: private::Sealed {}
+
+    mod private {
+        pub trait Sealed {}
+    }
+
+    /// The global memory allocator.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Global;
+
+    impl private::Sealed for Global {}
+    impl Allocator for Global {}
+
+    // A std Vec that accepts the same allocator type parameter as allocator-api2's Vec. Only the
+    // methods that the reader uses are provided; the rest are available via the slice deref.
+    #[derive(Clone, Debug)]
+    pub(crate) struct AllocVec<T, A: Allocator = Global> {
+        vec: Vec<T>,
+        allocator: PhantomData<A>,
+    }
+
+    impl<T, A: Allocator> AllocVec<T, A> {
+        pub fn new_in(_allocator: A) -> Self {
+            AllocVec {
+                vec: Vec::new(),
+                allocator: PhantomData,
+            }
+        }
+
+        pub fn push(&mut self, value: T) {
+            self.vec.push(value)
+        }
+
+        pub fn truncate(&mut self, len: usize) {
+            self.vec.truncate(len)
+        }
+
+        pub fn clear(&mut self) {
+            self.vec.clear()
+        }
+
+        pub fn resize(&mut self, new_len: usize, value: T)
+        where
+            T: Clone,
+        {
+            self.vec.resize(new_len, value)
+        }
+    }
+
+    impl<T, A: Allocator> Deref for AllocVec<T, A> {
+        type Target = [T];
+
+        fn deref(&self) -> &[T] {
+            &self.vec
+        }
+    }
+
+    impl<T, A: Allocator> DerefMut for AllocVec<T, A> {
+        fn deref_mut(&mut self) -> &mut [T] {
+            &mut self.vec
+        }
+    }
+}
+
+impl<A: Allocator> FallbackBuffer for AllocVec<u8, A> {
+    fn slice_of_len(&mut self, length: usize) -> &mut [u8] {
+        if self.len() < length {
+            self.resize(length, 0);
+        }
+        &mut self[..length]
+    }
+}
@@ -0,0 +1,367 @@
+//! Gathers statistics about the contents of a binary Ion stream in a single pass, which can help
+//! when deciding how to lay out large datasets (for example, which fields take up the most space,
+//! or which strings would be cheaper as symbols).
+//!
+//! The [Analyzer] visits every value in the stream, but only reads symbol values; the sizes of
+//! all other values come from their encoded lengths, so their bodies are skipped rather than
+//! decoded. Memory use does not depend on the size of the stream: the statistics for each Ion
+//! type take a fixed amount of space, and at most [max_tracked_names](Analyzer::max_tracked_names)
+//! distinct field names and symbols are recorded individually.
+//!
+//! ```
+//! use ion_rs::analysis::Analyzer;
+//! use ion_rs::binary::writer::BinaryWriter;
+//! use ion_rs::value::owned::OwnedElement;
+//! use ion_rs::{IonType, RawBinaryReader, Reader, Symbol};
+//! use std::io;
+//! # fn main() -> ion_rs::result::IonResult<()> {
+//!
+//! let mut binary = Vec::new();
+//! let mut writer = BinaryWriter::new(&mut binary);
+//! for element in OwnedElement::read_all(&b"{id: 1, tags: [a, b]} {id: 2, tags: [a]}"[..])? {
+//!     writer.write_element(&element)?;
+//! }
+//! writer.flush()?;
+//! drop(writer);
+//!
+//! let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(&binary)));
+//! let stats = Analyzer::new().analyze(&mut reader)?;
+//! assert_eq!(stats.values(IonType::Struct).count, 2);
+//! assert_eq!(stats.max_depth(), 2);
+//! assert_eq!(stats.field(&Symbol::from("tags")).unwrap().count, 2);
+//! assert_eq!(stats.symbol_uses(&Symbol::from("a")), 2);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use crate::allocator::Allocator;
+use crate::data_source::IonDataSource;
+use crate::result::IonResult;
+use crate::types::NUM_ION_TYPES;
+use crate::{IonType, RawBinaryReader, Reader, Symbol, SystemStreamItem};
+
+/// The number of values in some category and their total encoded size. See [StreamStats].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SizeStats {
+    /// The number of values, including nulls.
+    pub count: usize,
+    /// The total encoded size of the values in bytes, including their annotations wrappers and
+    /// type descriptors but not their field IDs. The size of a container includes the values
+    /// inside it.
+    pub encoded_bytes: usize,
+}
+
+impl SizeStats {
+    /// Returns the average encoded size of the values in bytes, or `None` if there are none.
+    pub fn average_bytes(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.encoded_bytes as f64 / self.count as f64)
+    }
+
+    fn record(&mut self, encoded_bytes: usize) {
+        self.count += 1;
+        self.encoded_bytes += encoded_bytes;
+    }
+}
+
+/// Reads a binary Ion stream to the end, gathering [StreamStats] about its values. See the
+/// [module documentation](self).
+#[derive(Clone, Debug)]
+pub struct Analyzer {
+    max_tracked_names: usize,
+}
+
+impl Analyzer {
+    /// The default for [max_tracked_names](Self::max_tracked_names).
+    pub const DEFAULT_MAX_TRACKED_NAMES: usize = 10_000;
+
+    pub fn new() -> Analyzer {
+        Analyzer {
+            max_tracked_names: Self::DEFAULT_MAX_TRACKED_NAMES,
+        }
+    }
+
+    /// Sets the number of distinct field names, and separately the number of distinct symbols,
+    /// whose statistics are recorded individually. Once that many have been seen, any others are
+    /// only counted in [untracked_fields](StreamStats::untracked_fields) and
+    /// [untracked_symbol_uses](StreamStats::untracked_symbol_uses).
+    pub fn max_tracked_names(mut self, max_tracked_names: usize) -> Analyzer {
+        self.max_tracked_names = max_tracked_names;
+        self
+    }
+
+    /// Reads the remaining values in `reader`, which should be at the top level, and returns
+    /// statistics about them.
+    pub fn analyze<R: IonDataSource, A: Allocator + Clone>(
+        &self,
+        reader: &mut Reader<RawBinaryReader<R, A>>,
+    ) -> IonResult<StreamStats> {
+        let mut stats = StreamStats::default();
+        // The depth at which the reader started
+        let depth = reader.depth();
+        loop {
+            // System values can only appear at the top level.
+            let item = if reader.depth() == depth {
+                reader.next_system_item()?
+            } else {
+                reader
+                    .next()?
+                    .map(|(ion_type, is_null)| SystemStreamItem::Value(ion_type, is_null))
+            };
+            match item {
+                Some(SystemStreamItem::VersionMarker(_, _)) => stats.version_markers += 1,
+                Some(SystemStreamItem::SymbolTable { .. }) => stats.symbol_tables += 1,
+                Some(SystemStreamItem::Value(ion_type, is_null)) => {
+                    self.record_value(reader, &mut stats, ion_type, is_null, depth)?;
+                    if ion_type.is_container() && !is_null {
+                        reader.step_in()?;
+                    }
+                }
+                None if reader.depth() > depth => reader.step_out()?,
+                None => return Ok(stats),
+            }
+        }
+    }
+
+    fn record_value<R: IonDataSource, A: Allocator + Clone>(
+        &self,
+        reader: &mut Reader<RawBinaryReader<R, A>>,
+        stats: &mut StreamStats,
+        ion_type: IonType,
+        is_null: bool,
+        start_depth: usize,
+    ) -> IonResult<()> {
+        let encoded_bytes = reader.encoded_range().map_or(0, |range| range.len());
+        stats.values[ion_type as usize].record(encoded_bytes);
+        stats.max_depth = stats.max_depth.max(reader.depth() - start_depth);
+
+        if let Some(field_name) = reader.field_name_symbol() {
+            self.record_symbol_use(stats, &field_name);
+            if stats.fields.len() < self.max_tracked_names || stats.fields.contains_key(&field_name)
+            {
+                stats
+                    .fields
+                    .entry(field_name)
+                    .or_default()
+                    .record(encoded_bytes);
+            } else {
+                stats.untracked_fields.record(encoded_bytes);
+            }
+        }
+        let annotations: Vec<Symbol> = reader.annotation_symbols().collect();
+        for annotation in &annotations {
+            self.record_symbol_use(stats, annotation);
+        }
+        if ion_type == IonType::Symbol && !is_null {
+            if let Some(symbol) = reader.read_symbol_value()? {
+                self.record_symbol_use(stats, &symbol);
+            }
+        }
+        Ok(())
+    }
+
+    fn record_symbol_use(&self, stats: &mut StreamStats, symbol: &Symbol) {
+        if let Some(uses) = stats.symbol_uses.get_mut(symbol) {
+            *uses += 1;
+        } else if stats.symbol_uses.len() < self.max_tracked_names {
+            stats.symbol_uses.insert(symbol.clone(), 1);
+        } else {
+            stats.untracked_symbol_uses += 1;
+        }
+    }
+}
+
+impl Default for Analyzer {
+    fn default() -> Self {
+        Analyzer::new()
+    }
+}
+
+/// Statistics about the values in a binary Ion stream, gathered by an [Analyzer].
+///
+/// Symbols are compared by their text, so every symbol whose text is unknown (such as `$0`, or
+/// one imported from a shared symbol table that is not in the reader's [Catalog](crate::Catalog))
+/// is counted as the same symbol.
+#[derive(Clone, Debug, Default)]
+pub struct StreamStats {
+    // Indexed by IonType discriminant
+    values: [SizeStats; NUM_ION_TYPES],
+    max_depth: usize,
+    fields: HashMap<Symbol, SizeStats>,
+    untracked_fields: SizeStats,
+    symbol_uses: HashMap<Symbol, usize>,
+    untracked_symbol_uses: usize,
+    version_markers: usize,
+    symbol_tables: usize,
+}
+
+impl StreamStats {
+    /// Returns the number of values of type `ion_type`, at any depth, and their size.
+    pub fn values(&self, ion_type: IonType) -> SizeStats {
+        self.values[ion_type as usize]
+    }
+
+    /// Returns the number of values of any type, at any depth.
+    pub fn value_count(&self) -> usize {
+        self.values.iter().map(|values| values.count).sum()
+    }
+
+    /// Returns the number of containers that the most deeply nested value is inside of. A stream
+    /// of top-level scalars has a depth of 0.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Returns the number of struct fields named `name`, in any struct, and the size of their
+    /// values. Returns `None` if there were none, or if the field name was not tracked.
+    pub fn field(&self, name: &Symbol) -> Option<SizeStats> {
+        self.fields.get(name).copied()
+    }
+
+    /// Returns the statistics of every tracked field name, largest total size first.
+    pub fn fields_by_size(&self) -> Vec<(&Symbol, SizeStats)> {
+        let mut fields: Vec<(&Symbol, SizeStats)> = self
+            .fields
+            .iter()
+            .map(|(name, stats)| (name, *stats))
+            .collect();
+        fields.sort_by(|(name1, stats1), (name2, stats2)| {
+            stats2
+                .encoded_bytes
+                .cmp(&stats1.encoded_bytes)
+                .then_with(|| name1.cmp(name2))
+        });
+        fields
+    }
+
+    /// Returns the combined statistics of the fields whose names were not tracked because
+    /// [max_tracked_names](Analyzer::max_tracked_names) had been reached.
+    pub fn untracked_fields(&self) -> SizeStats {
+        self.untracked_fields
+    }
+
+    /// Returns the number of times `symbol` was used as a field name, as an annotation or as a
+    /// symbol value. Returns 0 if it was not used, or if it was not tracked.
+    pub fn symbol_uses(&self, symbol: &Symbol) -> usize {
+        self.symbol_uses.get(symbol).copied().unwrap_or(0)
+    }
+
+    /// Returns every tracked symbol and the number of times it was used, most used first.
+    pub fn symbols_by_uses(&self) -> Vec<(&Symbol, usize)> {
+        let mut symbols: Vec<(&Symbol, usize)> = self
+            .symbol_uses
+            .iter()
+            .map(|(symbol, uses)| (symbol, *uses))
+            .collect();
+        symbols.sort_by(|(symbol1, uses1), (symbol2, uses2)| {
+            uses2.cmp(uses1).then_with(|| symbol1.cmp(symbol2))
+        });
+        symbols
+    }
+
+    /// Returns the number of uses of symbols that were not tracked because
+    /// [max_tracked_names](Analyzer::max_tracked_names) had been reached.
+    pub fn untracked_symbol_uses(&self) -> usize {
+        self.untracked_symbol_uses
+    }
+
+    /// Returns the number of Ion version markers in the stream, including the one at its start.
+    pub fn version_markers(&self) -> usize {
+        self.version_markers
+    }
+
+    /// Returns the number of local symbol tables in the stream.
+    pub fn symbol_tables(&self) -> usize {
+        self.symbol_tables
+    }
+}
+
+#[cfg(test)]
+mod analysis_tests {
+    use super::*;
+    use crate::binary::writer::BinaryWriter;
+    use crate::value::owned::OwnedElement;
+    use std::io;
+
+    fn analyze(text: &str, analyzer: Analyzer) -> IonResult<StreamStats> {
+        let mut binary = Vec::new();
+        let mut writer = BinaryWriter::new(&mut binary);
+        for element in OwnedElement::read_all(text.as_bytes())? {
+            writer.write_element(&element)?;
+        }
+        writer.flush()?;
+        drop(writer);
+        analyzer.analyze(&mut Reader::new(RawBinaryReader::new(io::Cursor::new(
+            binary,
+        ))))
+    }
+
+    #[test]
+    fn test_analyze() -> IonResult<()> {
+        let stats = analyze(
+            r#"{name: "a", tags: [x, y::x]} {name: "bcd", tags: null.list} 7 null"#,
+            Analyzer::new(),
+        )?;
+        assert_eq!(stats.value_count(), 10);
+        assert_eq!(stats.values(IonType::Struct).count, 2);
+        // `x` is encoded as 0x71 0x0C, and `y::x` as 0xE4 0x81 0x8D 0x71 0x0C
+        assert_eq!(
+            stats.values(IonType::Symbol),
+            SizeStats {
+                count: 2,
+                encoded_bytes: 7
+            }
+        );
+        assert_eq!(stats.values(IonType::List).count, 2);
+        assert_eq!(stats.values(IonType::Null).count, 1);
+        assert_eq!(stats.max_depth(), 2);
+        assert_eq!(
+            stats.field(&Symbol::from("name")),
+            Some(SizeStats {
+                count: 2,
+                encoded_bytes: 6
+            })
+        );
+        assert_eq!(
+            stats.field(&Symbol::from("name")).unwrap().average_bytes(),
+            Some(3.0)
+        );
+        assert_eq!(stats.field(&Symbol::from("missing")), None);
+        assert_eq!(stats.fields_by_size()[0].0, &Symbol::from("tags"));
+        assert_eq!(stats.symbol_uses(&Symbol::from("x")), 2);
+        assert_eq!(stats.symbol_uses(&Symbol::from("y")), 1);
+        assert_eq!(stats.symbol_uses(&Symbol::from("tags")), 2);
+        assert_eq!(stats.symbols_by_uses()[0].1, 2);
+        assert_eq!(stats.version_markers(), 1);
+        assert_eq!(stats.symbol_tables(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_with_limit() -> IonResult<()> {
+        let stats = analyze("{a: b, c: d} {a: e}", Analyzer::new().max_tracked_names(1))?;
+        assert_eq!(stats.field(&Symbol::from("a")).unwrap().count, 2);
+        assert_eq!(stats.field(&Symbol::from("c")), None);
+        assert_eq!(stats.untracked_fields().count, 1);
+        // The first symbol seen is the field name `a`.
+        assert_eq!(stats.symbol_uses(&Symbol::from("a")), 2);
+        assert_eq!(stats.symbol_uses(&Symbol::from("b")), 0);
+        assert_eq!(stats.untracked_symbol_uses(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_analyze_scalars() -> IonResult<()> {
+        let stats = analyze("1 2.5e0", Analyzer::default())?;
+        assert_eq!(stats.value_count(), 2);
+        assert_eq!(stats.max_depth(), 0);
+        assert_eq!(stats.fields_by_size(), vec![]);
+        assert_eq!(stats.symbols_by_uses(), vec![]);
+        assert_eq!(stats.symbol_tables(), 0);
+        Ok(())
+    }
+}
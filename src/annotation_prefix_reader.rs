@@ -0,0 +1,197 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset};
+use delegate::delegate;
+
+use crate::constants::v1_0::system_symbol_ids;
+use crate::raw_reader::StreamItem;
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::float::{Float, FloatKind};
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::{IonType, RawReader};
+
+/// A [RawReader] adapter that requires every top-level value to have at least one annotation
+/// whose text starts with a given prefix (for example, a `v1::` schema version annotation).
+/// Values nested inside containers are not checked.
+///
+/// The check is performed on the raw annotations, so it does not consult the symbol table.
+/// Annotations that are encoded as symbol IDs (as is typical in binary Ion) have no text and
+/// will never match the prefix. Local symbol tables (top-level structs annotated with
+/// `$ion_symbol_table`) are exempt from the check.
+///
+/// When a top-level value does not have a matching annotation, [next](RawReader::next) returns
+/// a decoding error. The reader remains positioned on the offending value, so calling `next`
+/// again will move past it.
+pub struct AnnotationPrefixReader<R: RawReader> {
+    raw_reader: R,
+    prefix: String,
+    // The number of top-level values (excluding local symbol tables) encountered so far
+    top_level_index: usize,
+}
+
+impl<R: RawReader> AnnotationPrefixReader<R> {
+    pub fn new(raw_reader: R, prefix: &str) -> AnnotationPrefixReader<R> {
+        AnnotationPrefixReader {
+            raw_reader,
+            prefix: prefix.to_string(),
+            top_level_index: 0,
+        }
+    }
+
+    /// Returns a reference to the wrapped [RawReader].
+    pub fn raw_reader(&self) -> &R {
+        &self.raw_reader
+    }
+
+    /// Consumes the adapter, returning the wrapped [RawReader].
+    pub fn into_raw_reader(self) -> R {
+        self.raw_reader
+    }
+
+    fn is_local_symbol_table(&self) -> bool {
+        if self.raw_reader.ion_type() != Some(IonType::Struct) {
+            return false;
+        }
+        match self.raw_reader.annotations() {
+            [symbol, ..] => {
+                symbol.matches(system_symbol_ids::ION_SYMBOL_TABLE, "$ion_symbol_table")
+            }
+            _ => false,
+        }
+    }
+
+    fn has_required_annotation(&self) -> bool {
+        let prefix = self.prefix.as_str();
+        self.raw_reader.annotations().iter().any(|annotation| {
+            annotation
+                .text()
+                .map(|text| text.starts_with(prefix))
+                .unwrap_or(false)
+        })
+    }
+}
+
+// `read_big_decimal` and `read_datetime` are deprecated, but still need to be forwarded to the
+// wrapped reader by the delegate! macro below.
+#[allow(deprecated)]
+impl<R: RawReader> RawReader for AnnotationPrefixReader<R> {
+    fn next(&mut self) -> IonResult<Option<StreamItem>> {
+        let item = self.raw_reader.next()?;
+        if let Some(StreamItem::Value(_, _)) = item {
+            if self.raw_reader.depth() == 0 && !self.is_local_symbol_table() {
+                let index = self.top_level_index;
+                self.top_level_index += 1;
+                if !self.has_required_annotation() {
+                    return decoding_error(format!(
+                        "top-level value #{} does not have an annotation starting with '{}'",
+                        index, self.prefix
+                    ));
+                }
+            }
+        }
+        Ok(item)
+    }
+
+    delegate! {
+        to self.raw_reader {
+            fn ion_version(&self) -> (u8, u8);
+            fn ion_type(&self) -> Option<IonType>;
+            fn is_null(&self) -> bool;
+            fn annotations(&self) -> &[RawSymbolToken];
+            fn field_name(&self) -> Option<&RawSymbolToken>;
+            fn read_null(&mut self) -> IonResult<Option<IonType>>;
+            fn read_bool(&mut self) -> IonResult<Option<bool>>;
+            fn read_i64(&mut self) -> IonResult<Option<i64>>;
+            fn read_integer(&mut self) -> IonResult<Option<Integer>>;
+            fn read_f32(&mut self) -> IonResult<Option<f32>>;
+            fn read_f32_exact(&mut self) -> IonResult<Option<f32>>;
+            fn read_float(&mut self) -> IonResult<Option<Float>>;
+            fn read_float_kind(&mut self) -> IonResult<Option<FloatKind>>;
+            fn read_f64(&mut self) -> IonResult<Option<f64>>;
+            fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+            #[cfg(feature = "std")]
+            fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
+            fn read_string(&mut self) -> IonResult<Option<String>>;
+            fn read_str(&mut self) -> IonResult<Option<&str>>;
+            fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>>;
+            fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+            fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+            fn read_blob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+            fn read_clob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+            fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+            fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
+            fn step_in(&mut self) -> IonResult<()>;
+            fn step_out(&mut self) -> IonResult<()>;
+            fn depth(&self) -> usize;
+
+            fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>> where F: FnOnce(&str) -> T;
+            fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>> where F: FnOnce(&[u8]) -> T;
+            fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
+            fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
+            fn read_blob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
+            fn read_clob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
+        }
+    }
+}
+
+#[cfg(test)]
+mod annotation_prefix_reader_tests {
+    use super::*;
+    use crate::raw_reader::StreamItem::Value;
+    use crate::result::IonError;
+    use crate::text::raw_text_reader::RawTextReader;
+
+    #[test]
+    fn values_with_prefix() -> IonResult<()> {
+        let text = "v1::1 'v1.2'::foo::{a: 2} bar::v1_beta::[3] $ion_symbol_table::{} v1::4";
+        let mut reader = RawTextReader::new(text).require_annotation_prefix("v1");
+        assert_eq!(reader.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(1));
+        assert_eq!(reader.next()?, Some(Value(IonType::Struct, false)));
+        // nested values do not need the annotation
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some(Value(IonType::Integer, false)));
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Some(Value(IonType::List, false)));
+        // local symbol tables are exempt
+        assert_eq!(reader.next()?, Some(Value(IonType::Struct, false)));
+        assert_eq!(reader.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(4));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn value_missing_prefix() -> IonResult<()> {
+        let text = "v1::1 v1::2 3 v1::4";
+        let mut reader = RawTextReader::new(text).require_annotation_prefix("v1");
+        assert_eq!(reader.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(reader.next()?, Some(Value(IonType::Integer, false)));
+        match reader.next() {
+            Err(IonError::DecodingError { description, .. }) => {
+                assert!(description.contains("#2"), "{}", description)
+            }
+            other => panic!("expected a decoding error, found {:?}", other),
+        }
+        // the reader is still positioned over the offending value
+        assert_eq!(reader.read_i64()?, Some(3));
+        assert_eq!(reader.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(4));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn symbol_id_annotations_do_not_match() {
+        let mut reader = RawTextReader::new("$10::1").require_annotation_prefix("v1");
+        assert!(reader.next().is_err());
+    }
+}
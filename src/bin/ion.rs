@@ -0,0 +1,151 @@
+//! A command line tool for inspecting and converting Ion data. It is only built when the `cli`
+//! feature is enabled:
+//!
+//! ```text
+//! cargo run --features cli --bin ion -- dump data.10n
+//! ```
+//!
+//! Each command reads the files it is given (or standard input if there are none), detecting
+//! whether each one is binary or text Ion, and writes its output to standard output.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::process::exit;
+
+use ion_rs::binary::writer::BinaryWriter;
+use ion_rs::result::IonResult;
+use ion_rs::text::writer::{TextFormat, TextWriterBuilder};
+use ion_rs::transcode::transcode;
+use ion_rs::value::json::JsonWriter;
+use ion_rs::value::writer::ElementWriter;
+use ion_rs::{RawReader, Reader};
+
+const USAGE: &str = "\
+USAGE:
+    ion dump [FILE...]
+        Writes the input as pretty-printed Ion text.
+
+    ion convert --to binary|text|json [FILE...]
+        Writes the input in the requested format. Binary output is written to standard output,
+        so it should usually be redirected to a file.
+
+    ion validate [FILE...]
+        Reads the input in full, reporting the number of top-level values in each input or the
+        first error encountered. Exits with a non-zero status if any input is invalid.
+";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum OutputFormat {
+    Binary,
+    Text,
+    Pretty,
+    Json,
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(|command| command.as_str()) {
+        Some("dump") => convert(OutputFormat::Pretty, &args[1..]),
+        Some("convert") => match (args.get(1).map(|a| a.as_str()), args.get(2)) {
+            (Some("--to"), Some(format)) => match format.as_str() {
+                "binary" => convert(OutputFormat::Binary, &args[3..]),
+                "text" => convert(OutputFormat::Text, &args[3..]),
+                "json" => convert(OutputFormat::Json, &args[3..]),
+                other => usage_error(&format!("Unsupported output format: '{}'", other)),
+            },
+            _ => usage_error("The convert command requires an output format (--to)."),
+        },
+        Some("validate") => validate(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+        Some(other) => usage_error(&format!("Unknown command: '{}'", other)),
+        None => usage_error("No command was specified."),
+    };
+    if let Err(error) = result {
+        eprintln!("ion: {}", error);
+        exit(1);
+    }
+}
+
+fn usage_error(message: &str) -> IonResult<()> {
+    eprintln!("{}\n\n{}", message, USAGE);
+    exit(2);
+}
+
+// Calls `f` with a reader for each of the provided paths in turn, or with a reader for standard
+// input if no paths were provided.
+fn for_each_input<F>(paths: &[String], mut f: F) -> IonResult<()>
+where
+    F: FnMut(&str, &mut Reader<Box<dyn RawReader>>) -> IonResult<()>,
+{
+    if paths.is_empty() {
+        let mut reader = Reader::from_input(io::stdin())?;
+        return f("<stdin>", &mut reader);
+    }
+    for path in paths {
+        let mut reader = Reader::from_input(File::open(path)?)?;
+        f(path, &mut reader)?;
+    }
+    Ok(())
+}
+
+fn convert(format: OutputFormat, paths: &[String]) -> IonResult<()> {
+    let stdout = io::stdout();
+    let output = stdout.lock();
+    match format {
+        OutputFormat::Binary => {
+            let mut writer = BinaryWriter::new(output);
+            for_each_input(paths, |_, reader| transcode(reader, &mut writer))?;
+            writer.flush()
+        }
+        OutputFormat::Text | OutputFormat::Pretty => {
+            let text_format = if format == OutputFormat::Pretty {
+                TextFormat::Pretty
+            } else {
+                TextFormat::Compact
+            };
+            let mut writer = TextWriterBuilder::new().format(text_format).build(output);
+            for_each_input(paths, |_, reader| transcode(reader, &mut writer))?;
+            writer.flush()
+        }
+        OutputFormat::Json => {
+            let mut writer = JsonWriter::new(io::BufWriter::new(output));
+            for_each_input(paths, |_, reader| {
+                while let Some(element) = reader.read_element()? {
+                    writer.write(&element)?;
+                }
+                Ok(())
+            })?;
+            writer.finish()?.flush()?;
+            Ok(())
+        }
+    }
+}
+
+fn validate(paths: &[String]) -> IonResult<()> {
+    let mut invalid_inputs = 0;
+    for_each_input(paths, |name, reader| {
+        let mut count = 0usize;
+        let result = loop {
+            match reader.read_element() {
+                Ok(Some(_)) => count += 1,
+                Ok(None) => break Ok(()),
+                Err(error) => break Err(error),
+            }
+        };
+        match result {
+            Ok(()) => println!("{}: OK ({} values)", name, count),
+            Err(error) => {
+                invalid_inputs += 1;
+                println!("{}: invalid after {} values: {}", name, count, error);
+            }
+        }
+        Ok(())
+    })?;
+    if invalid_inputs > 0 {
+        exit(1);
+    }
+    Ok(())
+}
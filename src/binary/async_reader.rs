@@ -0,0 +1,228 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! An asynchronous binary Ion reader for use with [tokio](https://tokio.rs).
+
+use std::io;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset};
+use delegate::delegate;
+use tokio::io::AsyncReadExt;
+
+use crate::binary::header::Header;
+use crate::binary::var_uint::MAX_ENCODED_SIZE_IN_BYTES;
+use crate::data_source::AsyncIonDataSource;
+use crate::raw_reader::StreamItem;
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, incomplete_data_error, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::float::{Float, FloatKind};
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::{IonType, RawBinaryReader, RawReader};
+
+/// A binary Ion reader that pulls its input from an [AsyncIonDataSource] such as a tokio
+/// `TcpStream`.
+///
+/// Each call to [next](AsyncRawBinaryReader::next) at the top level awaits the complete encoding
+/// of the next top-level value and buffers it in memory. Once a value has been buffered,
+/// everything else (reading scalars, stepping into containers and calling `next` on their
+/// children) operates on that buffer, which is why only `next` is async. Its methods otherwise
+/// behave like those of [RawBinaryReader].
+pub struct AsyncRawBinaryReader<R: AsyncIonDataSource> {
+    source: R,
+    // Reads the top-level value most recently buffered from `source`
+    reader: RawBinaryReader<io::Cursor<Vec<u8>>>,
+}
+
+// `read_big_decimal` and `read_datetime` are deprecated, but are still forwarded to the
+// buffered reader.
+#[allow(deprecated)]
+impl<R: AsyncIonDataSource> AsyncRawBinaryReader<R> {
+    pub fn new(source: R) -> Self {
+        AsyncRawBinaryReader {
+            source,
+            reader: RawBinaryReader::new(io::Cursor::new(Vec::new())),
+        }
+    }
+
+    /// Consumes the reader, returning its data source.
+    pub fn into_inner(self) -> R {
+        self.source
+    }
+
+    /// Advances the reader to the next item in the current container, waiting for more input if
+    /// the reader is at the top level. Returns `Ok(None)` once the end of the current container
+    /// (or of the data source) has been reached. As with [RawReader::next], any part of the
+    /// current value that has not been read is skipped.
+    pub async fn next(&mut self) -> IonResult<Option<StreamItem>> {
+        if self.reader.depth() > 0 {
+            return self.reader.next();
+        }
+        loop {
+            let mut buffer = Vec::new();
+            if !self.read_top_level_item(&mut buffer).await? {
+                return Ok(None);
+            }
+            self.reader = RawBinaryReader::new(io::Cursor::new(buffer));
+            // A buffer that only contains NOP padding has no items to return, so keep going.
+            if let Some(item) = self.reader.next()? {
+                return Ok(Some(item));
+            }
+        }
+    }
+
+    // Appends the encoding of the next top-level value, IVM or NOP pad to `buffer`. Returns
+    // `false` if the data source was exhausted before any bytes were read.
+    async fn read_top_level_item(&mut self, buffer: &mut Vec<u8>) -> IonResult<bool> {
+        let mut type_descriptor = [0u8; 1];
+        if self.source.read(&mut type_descriptor).await? == 0 {
+            return Ok(false);
+        }
+        buffer.push(type_descriptor[0]);
+        let header = Header::from_byte(type_descriptor[0])?;
+        let length = match header.top_level_length() {
+            Some(length) => length,
+            None => self.read_var_uint(buffer).await?,
+        };
+        let bytes_read = (&mut self.source)
+            .take(length as u64)
+            .read_to_end(buffer)
+            .await?;
+        if bytes_read < length {
+            return incomplete_data_error(format!(
+                "Unexpected end of stream: expected {} more bytes of a {:?} value, found {}.",
+                length, header.ion_type_code, bytes_read
+            ));
+        }
+        Ok(true)
+    }
+
+    // Reads a VarUInt length from the data source, appending its encoding to `buffer`.
+    async fn read_var_uint(&mut self, buffer: &mut Vec<u8>) -> IonResult<usize> {
+        let mut magnitude: usize = 0;
+        for _ in 0..MAX_ENCODED_SIZE_IN_BYTES {
+            let byte = match self.source.read_u8().await {
+                Ok(byte) => byte,
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                    return incomplete_data_error(
+                        "Unexpected end of stream while reading a VarUInt.",
+                    )
+                }
+                Err(e) => return Err(e.into()),
+            };
+            buffer.push(byte);
+            magnitude = (magnitude << 7) | (byte & 0b0111_1111) as usize;
+            if byte & 0b1000_0000 != 0 {
+                return Ok(magnitude);
+            }
+        }
+        decoding_error(format!(
+            "Found a VarUInt that was too large to fit in a usize; only {} bytes are supported.",
+            MAX_ENCODED_SIZE_IN_BYTES
+        ))
+    }
+
+    delegate! {
+        to self.reader {
+            pub fn ion_version(&self) -> (u8, u8);
+            pub fn ion_type(&self) -> Option<IonType>;
+            pub fn is_null(&self) -> bool;
+            pub fn annotations(&self) -> &[RawSymbolToken];
+            pub fn field_name(&self) -> Option<&RawSymbolToken>;
+            pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
+            pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
+            pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
+            pub fn read_integer(&mut self) -> IonResult<Option<Integer>>;
+            pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
+            pub fn read_f32_exact(&mut self) -> IonResult<Option<f32>>;
+            pub fn read_float(&mut self) -> IonResult<Option<Float>>;
+            pub fn read_float_kind(&mut self) -> IonResult<Option<FloatKind>>;
+            pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
+            pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+            pub fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
+            pub fn read_string(&mut self) -> IonResult<Option<String>>;
+            pub fn read_str(&mut self) -> IonResult<Option<&str>>;
+            pub fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>>;
+            pub fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            pub fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            pub fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+            pub fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+            pub fn read_blob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+            pub fn read_clob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+            pub fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+            pub fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
+            pub fn step_in(&mut self) -> IonResult<()>;
+            pub fn step_out(&mut self) -> IonResult<()>;
+            pub fn depth(&self) -> usize;
+
+            pub fn string_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&str) -> U;
+            pub fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
+            pub fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
+            pub fn read_blob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
+            pub fn read_clob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
+        }
+    }
+}
+
+#[cfg(test)]
+mod async_reader_tests {
+    use super::*;
+    use crate::raw_reader::StreamItem::*;
+
+    #[rustfmt::skip]
+    const DATA: &[u8] = &[
+        0xE0, 0x01, 0x00, 0xEA,       // IVM
+        0x83, b'f', b'o', b'o',       // "foo"
+        0xB4, 0x21, 0x01, 0x21, 0x02, // [1, 2]
+        0x01, 0xFF,                   // 2-byte NOP pad
+        0xE4, 0x81, 0x84, 0x21, 0x05, // $4::5
+        0xAE, 0x83, 1, 2, 3,          // {{ 0x01 0x02 0x03 }}, with a VarUInt length
+        0x0F,                         // null
+    ];
+
+    #[tokio::test]
+    async fn test_read_stream() -> IonResult<()> {
+        let mut reader = AsyncRawBinaryReader::new(DATA);
+        assert_eq!(Some(VersionMarker(1, 0)), reader.next().await?);
+        assert_eq!(Some(Value(IonType::String, false)), reader.next().await?);
+        assert_eq!(Some("foo".to_string()), reader.read_string()?);
+
+        assert_eq!(Some(Value(IonType::List, false)), reader.next().await?);
+        reader.step_in()?;
+        assert_eq!(Some(Value(IonType::Integer, false)), reader.next().await?);
+        assert_eq!(Some(1), reader.read_i64()?);
+        assert_eq!(Some(Value(IonType::Integer, false)), reader.next().await?);
+        assert_eq!(Some(2), reader.read_i64()?);
+        assert_eq!(None, reader.next().await?);
+        reader.step_out()?;
+
+        assert_eq!(Some(Value(IonType::Integer, false)), reader.next().await?);
+        assert_eq!(&[RawSymbolToken::SymbolId(4)], reader.annotations());
+        assert_eq!(Some(5), reader.read_i64()?);
+
+        assert_eq!(Some(Value(IonType::Blob, false)), reader.next().await?);
+        assert_eq!(Some(vec![1, 2, 3]), reader.read_blob_bytes()?);
+
+        assert_eq!(Some(Value(IonType::Null, true)), reader.next().await?);
+        assert_eq!(None, reader.next().await?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_skip_unread_values() -> IonResult<()> {
+        let mut reader = AsyncRawBinaryReader::new(DATA);
+        let mut items = Vec::new();
+        while let Some(item) = reader.next().await? {
+            items.push(item);
+        }
+        assert_eq!(6, items.len());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_incomplete_value_is_an_error() {
+        let mut reader = AsyncRawBinaryReader::new(&[0x83, b'f', b'o'][..]);
+        assert!(reader.next().await.is_err());
+    }
+}
@@ -0,0 +1,90 @@
+//! The binary encodings that the [RawBinaryReader](super::raw_binary_reader::RawBinaryReader)
+//! can read.
+//!
+//! Every version of Ion begins its binary encoding with the same four-byte Ion version marker
+//! (`0xE0 <major> <minor> 0xEA`), but what follows it is version-specific. The reader recognizes
+//! IVMs without consulting the encoding in effect, then asks the encoding that the IVM selects
+//! how to interpret the type descriptors that follow. Supporting a new version of Ion starts with
+//! adding a variant here.
+
+use crate::binary::constants::v1_0::length_codes;
+use crate::binary::header::{create_header_byte_jump_table, Header};
+use crate::result::IonResult;
+use alloc::vec::Vec;
+
+/// A binary Ion encoding, identified by the Ion version declared by the IVM that precedes it.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub(crate) enum BinaryEncoding {
+    #[default]
+    V1_0,
+}
+
+impl BinaryEncoding {
+    /// Returns the encoding used by Ion `major`.`minor`, or `None` if that version is not
+    /// supported.
+    pub fn for_version(major: u8, minor: u8) -> Option<BinaryEncoding> {
+        match (major, minor) {
+            (1, 0) => Some(BinaryEncoding::V1_0),
+            _ => None,
+        }
+    }
+
+    /// Returns the (major, minor) version of Ion that uses this encoding.
+    pub fn version(self) -> (u8, u8) {
+        match self {
+            BinaryEncoding::V1_0 => (1, 0),
+        }
+    }
+
+    /// Parses every possible type descriptor byte as a [Header], for use as a jump table when
+    /// reading a stream in this encoding.
+    pub fn header_table(self) -> Vec<IonResult<Option<Header>>> {
+        match self {
+            BinaryEncoding::V1_0 => create_header_byte_jump_table(),
+        }
+    }
+
+    /// Returns true if a value with the provided header is null. In Ion 1.0, a null of any type
+    /// has the length code `0xF`.
+    pub fn is_null(self, header: &Header) -> bool {
+        match self {
+            BinaryEncoding::V1_0 => header.length_code == length_codes::NULL,
+        }
+    }
+}
+
+#[cfg(test)]
+mod encoding_tests {
+    use super::*;
+
+    #[test]
+    fn versions() {
+        assert_eq!(
+            BinaryEncoding::for_version(1, 0),
+            Some(BinaryEncoding::V1_0)
+        );
+        assert_eq!(BinaryEncoding::V1_0.version(), (1, 0));
+        assert_eq!(BinaryEncoding::default(), BinaryEncoding::V1_0);
+        assert_eq!(BinaryEncoding::for_version(1, 1), None);
+        assert_eq!(BinaryEncoding::for_version(2, 0), None);
+    }
+
+    #[test]
+    fn typed_nulls() -> IonResult<()> {
+        let table = BinaryEncoding::V1_0.header_table();
+        assert_eq!(table.len(), 256);
+        let is_null = |byte: u8| -> IonResult<bool> {
+            let header = table[byte as usize].clone()?.unwrap();
+            Ok(BinaryEncoding::V1_0.is_null(&header))
+        };
+        // null.int, null.string, null.struct
+        for byte in &[0x2F, 0x8F, 0xDF] {
+            assert!(is_null(*byte)?);
+        }
+        // 0, "", {}
+        for byte in &[0x20, 0x80, 0xD0] {
+            assert!(!is_null(*byte)?);
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
-use std::convert::TryFrom;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
 
 use crate::{
     binary::{constants::v1_0::length_codes, nibbles::nibbles_from_byte, IonTypeCode},
@@ -35,6 +36,19 @@ impl Header {
     pub fn is_nop(&self) -> bool {
         self.ion_type_code == IonTypeCode::NullOrNop && self.length_code != length_codes::NULL
     }
+
+    /// Returns the number of bytes that follow this header at the top level of a stream, where
+    /// `0xE0` begins an Ion version marker rather than an annotation wrapper. Returns `None` if
+    /// the length is encoded as a VarUInt following the header, in which case that many bytes
+    /// follow the VarUInt.
+    pub fn top_level_length(&self) -> Option<usize> {
+        match (self.ion_type_code, self.length_code) {
+            (IonTypeCode::Annotation, 0) => Some(3),
+            (IonTypeCode::Boolean, _) | (_, length_codes::NULL) => Some(0),
+            (IonTypeCode::Struct, 1) | (_, length_codes::VAR_UINT) => None,
+            (_, length_code) => Some(length_code as usize),
+        }
+    }
 }
 
 /// Parses all possible values of a single byte and stores them in a newly allocated Vec.
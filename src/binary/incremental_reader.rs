@@ -0,0 +1,262 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! A binary Ion reader that is fed its input in chunks and can resume after running out of data.
+
+use std::io;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, FixedOffset};
+use delegate::delegate;
+
+use crate::binary::header::Header;
+use crate::binary::var_uint::MAX_ENCODED_SIZE_IN_BYTES;
+use crate::raw_reader::StreamItem;
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::{decoding_error, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::float::{Float, FloatKind};
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::{IonType, RawBinaryReader, RawReader};
+
+/// The outcome of a call to [IncrementalBinaryReader::next_item].
+#[derive(Debug, Eq, PartialEq)]
+pub enum ReadStatus {
+    /// The reader advanced to the next item.
+    Item(StreamItem),
+    /// The reader reached the end of the current container or, at the top level, the end of the
+    /// data that has been appended so far.
+    End,
+    /// The data that has been appended so far ends partway through the next top-level value.
+    /// The partial value is retained; once more data has been appended, calling `next_item`
+    /// again will resume reading it.
+    Incomplete,
+}
+
+/// A binary Ion reader whose input is supplied with [append_bytes](Self::append_bytes) as it
+/// becomes available, e.g. as the payloads of a framed network protocol arrive.
+///
+/// Top-level values are only read once all of their bytes have been appended. Until then,
+/// [next_item](Self::next_item) returns [ReadStatus::Incomplete] rather than a decoding error.
+/// As a result, reading the children of a container never runs out of data. Other methods
+/// behave like those of [RawBinaryReader] and refer to the most recent item returned by
+/// `next_item`.
+pub struct IncrementalBinaryReader {
+    // Bytes that have been appended but not yet handed to `reader`
+    pending: Vec<u8>,
+    // Reads the most recent complete top-level value
+    reader: RawBinaryReader<io::Cursor<Vec<u8>>>,
+}
+
+// `read_big_decimal` and `read_datetime` are deprecated, but are still forwarded to the
+// buffered reader.
+#[allow(deprecated)]
+impl IncrementalBinaryReader {
+    pub fn new() -> Self {
+        IncrementalBinaryReader {
+            pending: Vec::new(),
+            reader: RawBinaryReader::new(io::Cursor::new(Vec::new())),
+        }
+    }
+
+    /// Adds `bytes` to the end of the reader's input.
+    pub fn append_bytes(&mut self, bytes: &[u8]) {
+        self.pending.extend_from_slice(bytes);
+    }
+
+    /// Returns the number of appended bytes that belong to top-level values the reader has not
+    /// reached yet.
+    pub fn bytes_pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Advances the reader to the next item in the current container. As with [RawReader::next],
+    /// any part of the current value that has not been read is skipped.
+    pub fn next_item(&mut self) -> IonResult<ReadStatus> {
+        if self.reader.depth() > 0 {
+            return Ok(match self.reader.next()? {
+                Some(item) => ReadStatus::Item(item),
+                None => ReadStatus::End,
+            });
+        }
+        loop {
+            if self.pending.is_empty() {
+                return Ok(ReadStatus::End);
+            }
+            let length = match top_level_item_length(&self.pending)? {
+                Some(length) if length <= self.pending.len() => length,
+                _ => return Ok(ReadStatus::Incomplete),
+            };
+            let item_bytes: Vec<u8> = self.pending.drain(..length).collect();
+            self.reader = RawBinaryReader::new(io::Cursor::new(item_bytes));
+            // An item that was only NOP padding has nothing to return, so keep going.
+            if let Some(item) = self.reader.next()? {
+                return Ok(ReadStatus::Item(item));
+            }
+        }
+    }
+
+    delegate! {
+        to self.reader {
+            pub fn ion_version(&self) -> (u8, u8);
+            pub fn ion_type(&self) -> Option<IonType>;
+            pub fn is_null(&self) -> bool;
+            pub fn annotations(&self) -> &[RawSymbolToken];
+            pub fn field_name(&self) -> Option<&RawSymbolToken>;
+            pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
+            pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
+            pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
+            pub fn read_integer(&mut self) -> IonResult<Option<Integer>>;
+            pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
+            pub fn read_f32_exact(&mut self) -> IonResult<Option<f32>>;
+            pub fn read_float(&mut self) -> IonResult<Option<Float>>;
+            pub fn read_float_kind(&mut self) -> IonResult<Option<FloatKind>>;
+            pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
+            pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+            pub fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
+            pub fn read_string(&mut self) -> IonResult<Option<String>>;
+            pub fn read_str(&mut self) -> IonResult<Option<&str>>;
+            pub fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>>;
+            pub fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            pub fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+            pub fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+            pub fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+            pub fn read_blob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+            pub fn read_clob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+            pub fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+            pub fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
+            pub fn step_in(&mut self) -> IonResult<()>;
+            pub fn step_out(&mut self) -> IonResult<()>;
+            pub fn depth(&self) -> usize;
+
+            pub fn string_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&str) -> U;
+            pub fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
+            pub fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
+            pub fn read_blob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
+            pub fn read_clob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
+        }
+    }
+}
+
+impl Default for IncrementalBinaryReader {
+    fn default() -> Self {
+        IncrementalBinaryReader::new()
+    }
+}
+
+// Returns the total encoded length of the top-level value, IVM or NOP pad at the beginning of
+// `bytes`, or `None` if `bytes` ends before its length has been fully encoded.
+fn top_level_item_length(bytes: &[u8]) -> IonResult<Option<usize>> {
+    let header = Header::from_byte(bytes[0])?;
+    if let Some(length) = header.top_level_length() {
+        return Ok(Some(1 + length));
+    }
+    let mut magnitude: usize = 0;
+    for (index, byte) in bytes[1..].iter().enumerate() {
+        if index == MAX_ENCODED_SIZE_IN_BYTES {
+            return decoding_error(format!(
+                "Found a VarUInt that was too large to fit in a usize; only {} bytes are supported.",
+                MAX_ENCODED_SIZE_IN_BYTES
+            ));
+        }
+        magnitude = (magnitude << 7) | (byte & 0b0111_1111) as usize;
+        if byte & 0b1000_0000 != 0 {
+            return Ok(Some(1 + index + 1 + magnitude));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod incremental_reader_tests {
+    use super::*;
+    use crate::raw_reader::StreamItem::*;
+
+    #[rustfmt::skip]
+    const DATA: &[u8] = &[
+        0xE0, 0x01, 0x00, 0xEA,       // IVM
+        0x83, b'f', b'o', b'o',       // "foo"
+        0xB4, 0x21, 0x01, 0x21, 0x02, // [1, 2]
+        0x01, 0xFF,                   // 2-byte NOP pad
+        0xAE, 0x83, 1, 2, 3,          // {{ 0x01 0x02 0x03 }}, with a VarUInt length
+        0x0F,                         // null
+    ];
+
+    fn value(ion_type: IonType) -> ReadStatus {
+        ReadStatus::Item(Value(ion_type, false))
+    }
+
+    #[test]
+    fn test_read_complete_data() -> IonResult<()> {
+        let mut reader = IncrementalBinaryReader::new();
+        reader.append_bytes(DATA);
+        assert_eq!(ReadStatus::Item(VersionMarker(1, 0)), reader.next_item()?);
+        assert_eq!(value(IonType::String), reader.next_item()?);
+        assert_eq!(Some("foo".to_string()), reader.read_string()?);
+
+        assert_eq!(value(IonType::List), reader.next_item()?);
+        reader.step_in()?;
+        assert_eq!(value(IonType::Integer), reader.next_item()?);
+        assert_eq!(Some(1), reader.read_i64()?);
+        assert_eq!(value(IonType::Integer), reader.next_item()?);
+        assert_eq!(ReadStatus::End, reader.next_item()?);
+        reader.step_out()?;
+
+        assert_eq!(value(IonType::Blob), reader.next_item()?);
+        assert_eq!(Some(vec![1, 2, 3]), reader.read_blob_bytes()?);
+        assert_eq!(
+            ReadStatus::Item(Value(IonType::Null, true)),
+            reader.next_item()?
+        );
+        assert_eq!(ReadStatus::End, reader.next_item()?);
+        assert_eq!(0, reader.bytes_pending());
+        Ok(())
+    }
+
+    #[test]
+    fn test_resume_after_incomplete() -> IonResult<()> {
+        // Feed the reader one byte at a time, reading as far as possible after each one.
+        let mut reader = IncrementalBinaryReader::new();
+        let mut items = Vec::new();
+        let mut incomplete_count = 0;
+        for byte in DATA {
+            reader.append_bytes(&[*byte]);
+            loop {
+                match reader.next_item()? {
+                    ReadStatus::Item(item) => items.push(item),
+                    ReadStatus::Incomplete => {
+                        incomplete_count += 1;
+                        break;
+                    }
+                    ReadStatus::End => break,
+                }
+            }
+        }
+        assert_eq!(
+            vec![
+                VersionMarker(1, 0),
+                Value(IonType::String, false),
+                Value(IonType::List, false),
+                Value(IonType::Blob, false),
+                Value(IonType::Null, true),
+            ],
+            items
+        );
+        // Only the final bytes of the five items and of the NOP pad completed something.
+        assert_eq!(DATA.len() - 6, incomplete_count);
+        assert!(reader.is_null());
+        Ok(())
+    }
+
+    #[test]
+    fn test_incomplete_value_keeps_its_bytes() -> IonResult<()> {
+        let mut reader = IncrementalBinaryReader::new();
+        reader.append_bytes(&[0x83, b'f', b'o']);
+        assert_eq!(ReadStatus::Incomplete, reader.next_item()?);
+        assert_eq!(3, reader.bytes_pending());
+        reader.append_bytes(&[b'o']);
+        assert_eq!(value(IonType::String), reader.next_item()?);
+        assert_eq!(Some("foo".to_string()), reader.read_string()?);
+        Ok(())
+    }
+}
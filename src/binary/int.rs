@@ -1,8 +1,9 @@
-use std::mem;
+use alloc::format;
+use core::mem;
 
 use crate::data_source::IonDataSource;
+use crate::io::Write;
 use crate::result::{decoding_error, IonResult};
-use std::io::Write;
 
 type IntStorage = i64;
 const MAX_INT_SIZE_IN_BYTES: usize = mem::size_of::<IntStorage>();
@@ -36,6 +37,14 @@ impl Int {
             ));
         }
 
+        // If the data source's input buffer holds the entire Int, decode it in place.
+        let buffer = data_source.fill_buf()?;
+        if buffer.len() >= length {
+            let int = Self::decode(&buffer[..length]);
+            data_source.consume(length);
+            return Ok(int);
+        }
+
         // Create a stack-allocated buffer to hold the data we're going to read in.
         let mut buffer = [0u8; MAX_INT_SIZE_IN_BYTES];
         // Get a mutable reference to a portion of the buffer just big enough to fit
@@ -43,23 +52,26 @@ impl Int {
         let buffer = &mut buffer[0..length];
 
         data_source.read_exact(buffer)?;
-        let mut byte_iter = buffer.iter();
-
-        let first_byte: i64 = i64::from(byte_iter.next().copied().unwrap());
-        let sign: IntStorage = if first_byte & 0b1000_0000 == 0 { 1 } else { -1 };
-        let mut magnitude: IntStorage = first_byte & 0b0111_1111;
-
-        for &byte in byte_iter {
-            let byte = i64::from(byte);
-            magnitude <<= 8;
-            magnitude |= byte;
-        }
+        Ok(Self::decode(buffer))
+    }
 
-        Ok(Int {
+    // Decodes an Int from a non-empty slice of at most MAX_INT_SIZE_IN_BYTES bytes by loading the
+    // bytes as a single big-endian word and then clearing the sign bit.
+    #[inline]
+    fn decode(bytes: &[u8]) -> Int {
+        let length = bytes.len();
+        let mut word = [0u8; MAX_INT_SIZE_IN_BYTES];
+        word[MAX_INT_SIZE_IN_BYTES - length..].copy_from_slice(bytes);
+        let is_negative = bytes[0] & 0b1000_0000 != 0;
+        let sign_bit = 0b1000_0000u64 << ((length - 1) * 8);
+        let magnitude = (u64::from_be_bytes(word) & !sign_bit) as IntStorage;
+        let sign: IntStorage = if is_negative { -1 } else { 1 };
+
+        Int {
             size_in_bytes: length,
             value: magnitude * sign,
-            is_negative: sign == -1,
-        })
+            is_negative,
+        }
     }
 
     /// Encodes the provided `value` as an Int and writes it to the provided `sink`.
@@ -139,6 +151,28 @@ mod tests {
         assert_eq!(int.value(), -3_966_849);
     }
 
+    #[test]
+    fn test_read_int_small_buffer() {
+        // Construct a BufReader whose input buffer cannot hold all of the data at once
+        // to ensure that reads that span multiple I/O operations work as expected
+        let data = &[0b1011_1100, 0b1000_0111, 0b1000_0001];
+        let int = Int::read(
+            &mut io::BufReader::with_capacity(1, Cursor::new(data)),
+            data.len(),
+        )
+        .expect(READ_ERROR_MESSAGE);
+        assert_eq!(int.size_in_bytes(), 3);
+        assert_eq!(int.value(), -3_966_849);
+    }
+
+    #[test]
+    fn test_read_eight_byte_int() {
+        let data = &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let int = Int::read(&mut Cursor::new(data), data.len()).expect(READ_ERROR_MESSAGE);
+        assert_eq!(int.size_in_bytes(), 8);
+        assert_eq!(int.value(), -i64::MAX);
+    }
+
     #[test]
     fn test_read_int_negative_zero() {
         let data = &[0b1000_0000]; // Negative zero
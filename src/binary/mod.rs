@@ -3,17 +3,27 @@
 //! This module provides the necessary structures and logic to read values from a binary Ion
 //! data stream.
 
+#[cfg(feature = "tokio")]
+pub mod async_reader;
 pub(crate) mod constants;
+#[cfg(feature = "std")]
 pub mod decimal;
+mod encoding;
 mod header;
+#[cfg(feature = "std")]
+pub mod incremental_reader;
 mod int;
 mod nibbles;
 pub(crate) mod raw_binary_reader;
+#[cfg(feature = "std")]
 pub mod timestamp;
 mod type_code;
 pub mod uint;
 mod var_int;
 mod var_uint;
+#[cfg(feature = "std")]
 pub mod writer;
 
 pub use type_code::IonTypeCode;
+#[cfg(feature = "std")]
+pub(crate) use var_uint::VarUInt;
@@ -1,31 +1,40 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use bigdecimal::BigDecimal;
-use bytes::BigEndian;
-use bytes::ByteOrder;
 use chrono::offset::FixedOffset;
 use chrono::prelude::*;
 use delegate::delegate;
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
 
+use crate::allocator::{AllocVec, Allocator, Global};
+use crate::io;
 use crate::raw_reader::{RawReader, StreamItem};
 use crate::{
     binary::{
-        constants::v1_0::length_codes,
-        header::{create_header_byte_jump_table, Header},
-        int::Int,
-        uint::DecodedUInt,
-        var_int::VarInt,
-        var_uint::VarUInt,
-        IonTypeCode,
+        constants::v1_0::length_codes, encoding::BinaryEncoding, header::Header, int::Int,
+        uint::DecodedUInt, var_int::VarInt, var_uint::VarUInt, IonTypeCode,
     },
-    data_source::IonDataSource,
-    result::{decoding_error, illegal_operation, illegal_operation_raw, IonResult},
-    types::{IonType, SymbolId},
+    data_source::{FallbackBuffer, IonDataSource},
+    result::{
+        decoding_error, decoding_error_at, decoding_error_raw, illegal_operation,
+        illegal_operation_raw, incomplete_data_error_raw, limit_exceeded_error_raw,
+        DecodingErrorCategory, IonError, IonResult,
+    },
+    types::{integer::Integer, IonType, SymbolId},
 };
-use std::io;
+use core::convert::{TryFrom, TryInto};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::ToPrimitive;
 
 use crate::raw_symbol_token::RawSymbolToken;
+use crate::types::coefficient::{Coefficient, Sign as CoefficientSign};
 use crate::types::decimal::Decimal;
+use crate::types::float::Float;
 use crate::types::timestamp::Timestamp;
-use std::ops::Range;
+use core::ops::Range;
 
 /// Information about the value over which the RawBinaryReader is currently positioned.
 #[derive(Clone, Debug)]
@@ -212,18 +221,32 @@ impl Default for EncodedValue {
 }
 
 // A low-level reader that offers no symbol management.
-pub struct RawBinaryReader<R>
+//
+// The reader's scratch buffer and its container and annotation bookkeeping are allocated with `A`.
+// See [RawBinaryReaderBuilder::allocator].
+pub struct RawBinaryReader<R, A = Global>
 where
     R: IonDataSource,
+    A: Allocator + Clone,
 {
     // The file, socket, array, or other data source containing binary Ion bytes
     data_source: R,
     // Used for individual data_source.read() calls independent of input buffering
-    buffer: Vec<u8>,
+    buffer: AllocVec<u8, A>,
     // Tracks our position in the stream and information about the current value
-    cursor: CursorState,
-    // A jump table of pre-parsed header bytes
+    cursor: CursorState<A>,
+    // A jump table of header bytes pre-parsed according to `header_cache_encoding`
     header_cache: Vec<IonResult<Option<Header>>>,
+    header_cache_encoding: BinaryEncoding,
+    // The largest length that a value (or annotations wrapper) may declare, if any.
+    // See [RawBinaryReaderBuilder::max_value_length].
+    max_value_length: Option<usize>,
+    // Whether IVMs after the beginning of the stream are consumed without being returned.
+    // See [RawBinaryReaderBuilder::suppress_mid_stream_ivms].
+    suppress_mid_stream_ivms: bool,
+    // The offset at which the reader began looking for the most recent top-level item, which is
+    // where [recover](Self::recover) begins looking for the next one.
+    top_level_item_start: usize,
 }
 
 /* CursorState is broken out from the BinaryIonCursor struct to allow it to be cloned
@@ -231,9 +254,9 @@ where
  * See: https://github.com/amzn/ion-rust/issues/21
  */
 #[derive(Clone, Debug)]
-pub struct CursorState {
-    // The (major, minor) version pair of the stream being read. Defaults to (1, 0)
-    ion_version: (u8, u8),
+pub struct CursorState<A: Allocator + Clone = Global> {
+    // The encoding selected by the most recent IVM. Defaults to Ion 1.0
+    encoding: BinaryEncoding,
     // How many bytes we've read from our data source
     bytes_read: usize,
     // How deeply nested the cursor is at the moment
@@ -245,11 +268,11 @@ pub struct CursorState {
     // Information about the value on which the cursor is currently sitting
     value: EncodedValue,
     // All of the values into which the cursor has stepped. Empty at the top level.
-    parents: Vec<EncodedValue>,
+    parents: AllocVec<EncodedValue, A>,
     // All of the annotations on values in `parents` and the current value.
     // Having a single, reusable Vec reduces allocations and keeps the size of
     // the EncodedValue type (which is frequently moved) small.
-    annotations: Vec<RawSymbolToken>,
+    annotations: AllocVec<RawSymbolToken, A>,
 }
 
 /// Verifies that the current value is of the expected type and that the bytes representing that
@@ -275,76 +298,77 @@ macro_rules! read_safety_checks {
     };
 }
 
-impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
+impl<R: IonDataSource, A: Allocator + Clone> RawReader for RawBinaryReader<R, A> {
     fn ion_version(&self) -> (u8, u8) {
-        self.cursor.ion_version
+        self.cursor.encoding.version()
     }
 
     #[inline]
     fn next(&mut self) -> IonResult<Option<StreamItem>> {
         // Skip the remaining bytes of the current value, if any.
         let _ = self.skip_current_value()?;
-
-        if let Some(ref parent) = self.cursor.parents.last() {
-            // If the cursor is nested inside a parent object, don't attempt to read beyond the end of
-            // the parent. Users can call '.step_out()' to progress beyond the container.
-            if self.cursor.bytes_read >= parent.value_end_exclusive() {
-                return Ok(None);
-            }
+        if self.cursor.depth == 0 {
+            self.top_level_item_start = self.cursor.bytes_read;
         }
 
-        // If we're in a struct, read the field id that must precede each value.
-        self.cursor.value.field_id = if self.cursor.is_in_struct {
-            Some(RawSymbolToken::SymbolId(self.read_field_id()?))
-        } else {
-            self.cursor.value.field_id_length = 0;
-            None
-        };
+        // Any number of NOP pads (and, if they are being suppressed, mid-stream IVMs) may precede
+        // the next item. Each one is skipped by starting over, so a long run of padding cannot
+        // exhaust the stack.
+        let mut header = loop {
+            if let Some(parent) = self.cursor.parents.last() {
+                // If the cursor is nested inside a parent object, don't attempt to read beyond the
+                // end of the parent. Users can call '.step_out()' to progress beyond the container.
+                if self.cursor.bytes_read >= parent.value_end_exclusive() {
+                    self.clear_current_value();
+                    return Ok(None);
+                }
+            }
 
-        // Pull the next byte from the data source and interpret it as a value header
-        let mut header = match self.read_next_value_header()? {
-            Some(header) => header,
-            None => return Ok(None),
-        };
-        self.cursor.value.header = header;
+            // If we're in a struct, read the field id that must precede each value.
+            self.cursor.value.field_id = if self.cursor.is_in_struct {
+                Some(RawSymbolToken::SymbolId(self.read_field_id()?))
+            } else {
+                self.cursor.value.field_id_length = 0;
+                None
+            };
 
-        // Skip over consecutive NOP padding, but don't handle nulls
-        if header.is_nop() {
-            let number_of_bytes = self.read_standard_length()?;
+            // Pull the next byte from the data source and interpret it as a value header
+            let header = match self.read_next_value_header()? {
+                Some(header) => header,
+                None => {
+                    self.clear_current_value();
+                    return Ok(None);
+                }
+            };
+            self.cursor.value.header = header;
 
-            // If we're in a container, validate that the NOP pad doesn't overrun the container end
-            if let Some(parent) = self.cursor.parents.last() {
-                // The NOP padding described starts on the byte *after* the NOP header
-                let nop_offset = self.cursor.bytes_read;
-                let nop_range = (nop_offset)..(nop_offset + number_of_bytes);
-                let container_range = parent.value_range();
+            // Skip over consecutive NOP padding, but don't handle nulls
+            if header.is_nop() {
+                self.skip_nop_pad()?;
+                continue;
+            }
 
-                if nop_range.end > container_range.end {
-                    // This NOP is malformed, let's assemble data for error reporting
-                    return decoding_error(&format!(
-                        "{bytes}-byte NOP padding on byte range {nop_range:?} is {over} \
-                        byte{s} past container content range {container_range:?}",
-                        bytes = number_of_bytes,
-                        nop_range = nop_range,
-                        over = nop_range.end - container_range.end,
-                        s = if number_of_bytes == 1 { "" } else { "s" },
-                        container_range = container_range,
-                    ));
+            if header.ion_type_code == IonTypeCode::Annotation && header.length_code == 0 {
+                // This is actually the first byte in an Ion Version Marker
+                let ivm_offset = self.cursor.bytes_read - 1;
+                let previous_encoding = self.cursor.encoding;
+                let version_marker = self.read_ivm()?;
+                // An IVM that switches to a different version of Ion is returned even when
+                // mid-stream IVMs are suppressed.
+                if self.suppress_mid_stream_ivms
+                    && ivm_offset > 0
+                    && self.cursor.encoding == previous_encoding
+                {
+                    continue;
                 }
+                self.clear_annotations();
+                return Ok(Some(version_marker));
             }
-
-            self.skip_bytes(number_of_bytes)?;
-
-            //TODO: Find a way to do this non-recursively when we clean up/refactor next()
-            return self.next();
-        }
+            break header;
+        };
 
         self.clear_annotations();
         if header.ion_type_code == IonTypeCode::Annotation {
-            if header.length_code == 0 {
-                // This is actually the first byte in an Ion Version Marker
-                return Ok(Some(self.read_ivm()?));
-            }
             // We've found an annotated value. Read all of the annotation symbols leading
             // up to the value
             let _ = self.read_annotations()?;
@@ -354,10 +378,11 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
                 None => return Ok(None),
             };
             if header.is_nop() {
-                return decoding_error(&format!(
-                    "The annotation wrapper starting at byte {} contains NOP padding, which is illegal.",
-                    self.cursor.bytes_read
-                ));
+                return decoding_error_at(
+                    "The annotation wrapper contains NOP padding, which is illegal.",
+                    // The NOP's type descriptor byte is behind the cursor
+                    self.cursor.bytes_read - 1,
+                );
             }
             self.cursor.value.header = header;
         }
@@ -411,10 +436,12 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         match representation {
             0 => Ok(Some(false)),
             1 => Ok(Some(true)),
-            _ => decoding_error(&format!(
+            _ => Err(decoding_error_raw(format!(
                 "Found a boolean value with an illegal representation: {}",
                 representation
-            )),
+            ))
+            .with_offset(self.cursor.value.header_offset)
+            .with_category(DecodingErrorCategory::IllegalTypeDescriptor)),
         }
     }
 
@@ -422,18 +449,46 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
     // - Detect overflow and return an Error
     // - Add an integer_size() method that indicates whether the current value will fit in an i64
     fn read_i64(&mut self) -> IonResult<Option<i64>> {
-        read_safety_checks!(self, IonType::Integer);
+        match self.read_integer()? {
+            Some(Integer::I64(value)) => Ok(Some(value)),
+            Some(Integer::BigInt(value)) => decoding_error(format!(
+                "Integer {} is out of range for an i64; use read_integer() instead.",
+                value
+            )),
+            None => Ok(None),
+        }
+    }
 
-        let magnitude = self.read_value_as_uint()?.value();
+    fn read_integer(&mut self) -> IonResult<Option<Integer>> {
+        read_safety_checks!(self, IonType::Integer);
 
         use self::IonTypeCode::*;
-        let value = match self.cursor.value.header.ion_type_code {
-            PositiveInteger => magnitude as i64,
-            NegativeInteger => -(magnitude as i64),
-            itc @ _ => unreachable!("Unexpected IonTypeCode: {:?}", itc),
+        let is_negative = match self.cursor.value.header.ion_type_code {
+            PositiveInteger => false,
+            NegativeInteger => true,
+            itc => unreachable!("Unexpected IonTypeCode: {:?}", itc),
         };
 
-        Ok(Some(value))
+        let number_of_bytes = self.cursor.value.value_length;
+        if number_of_bytes <= core::mem::size_of::<u64>() {
+            let magnitude = i128::from(self.read_value_as_uint()?.value());
+            let value = if is_negative { -magnitude } else { magnitude };
+            return match i64::try_from(value) {
+                Ok(value) => Ok(Some(Integer::I64(value))),
+                Err(_) => Ok(Some(Integer::BigInt(BigInt::from(value)))),
+            };
+        }
+
+        // The magnitude is too large for a u64. It may still fit in an i64 if it has leading
+        // zero bytes, so we normalize it after decoding.
+        let magnitude =
+            self.read_slice(number_of_bytes, |bytes| Ok(BigUint::from_bytes_be(bytes)))?;
+        let sign = if is_negative { Sign::Minus } else { Sign::Plus };
+        let value = BigInt::from_biguint(sign, magnitude);
+        match value.to_i64() {
+            Some(value) => Ok(Some(Integer::I64(value))),
+            None => Ok(Some(Integer::BigInt(value))),
+        }
     }
 
     fn read_f32(&mut self) -> IonResult<Option<f32>> {
@@ -445,15 +500,21 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
     }
 
     fn read_f64(&mut self) -> IonResult<Option<f64>> {
+        Ok(self.read_float()?.map(|float| float.as_f64()))
+    }
+
+    fn read_float(&mut self) -> IonResult<Option<Float>> {
         read_safety_checks!(self, IonType::Float);
 
         let number_of_bytes = self.cursor.value.value_length;
 
         self.read_slice(number_of_bytes, |buffer: &[u8]| {
             let value = match number_of_bytes {
-                0 => 0f64,
-                4 => f64::from(BigEndian::read_f32(buffer)),
-                8 => BigEndian::read_f64(buffer),
+                // A zero-length float is 0e0, which has no encoded width
+                0 => Float::F64(0f64),
+                // `read_slice` passes exactly `number_of_bytes` bytes
+                4 => Float::F32(f32::from_be_bytes(buffer.try_into().unwrap())),
+                8 => Float::F64(f64::from_be_bytes(buffer.try_into().unwrap())),
                 _ => {
                     return decoding_error(&format!(
                         "Encountered an illegal value for a Float length: {}",
@@ -474,7 +535,7 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
 
         let exponent_var_int = self.read_var_int()?;
         let coefficient_size_in_bytes =
-            self.cursor.value.value_length - exponent_var_int.size_in_bytes();
+            self.remaining_value_length(exponent_var_int.size_in_bytes())?;
 
         let exponent = exponent_var_int.value() as i64;
         let coefficient = self.read_int(coefficient_size_in_bytes)?;
@@ -486,6 +547,7 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         Ok(Some(Decimal::new(coefficient.value(), exponent)))
     }
 
+    #[cfg(feature = "std")]
     fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
         read_safety_checks!(self, IonType::Decimal);
 
@@ -495,7 +557,7 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
 
         let exponent_var_int = self.read_var_int()?;
         let coefficient_size_in_bytes =
-            self.cursor.value.value_length - exponent_var_int.size_in_bytes();
+            self.remaining_value_length(exponent_var_int.size_in_bytes())?;
 
         let exponent = exponent_var_int.value() as i64;
         let coefficient = self.read_int(coefficient_size_in_bytes)?.value();
@@ -513,7 +575,7 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
     where
         F: FnOnce(&str) -> T,
     {
-        use std::str;
+        use core::str;
         read_safety_checks!(self, IonType::String);
 
         let length_in_bytes = self.cursor.value.value_length;
@@ -522,16 +584,31 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
             let string_ref = match str::from_utf8(buffer) {
                 Ok(utf8_text) => utf8_text,
                 Err(utf8_error) => {
-                    return decoding_error(&format!(
+                    return Err(decoding_error_raw(format!(
                         "The requested string was not valid UTF-8: {:?}",
                         utf8_error
                     ))
+                    .with_category(DecodingErrorCategory::InvalidUtf8))
                 }
             };
             Ok(Some(f(string_ref)))
         })
     }
 
+    fn read_str(&mut self) -> IonResult<Option<&str>> {
+        read_safety_checks!(self, IonType::String);
+
+        let bytes = self.read_value_into_buffer()?;
+        match core::str::from_utf8(bytes) {
+            Ok(text) => Ok(Some(text)),
+            Err(utf8_error) => Err(decoding_error_raw(format!(
+                "The requested string was not valid UTF-8: {:?}",
+                utf8_error
+            ))
+            .with_category(DecodingErrorCategory::InvalidUtf8)),
+        }
+    }
+
     fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
     where
         F: FnOnce(&[u8]) -> T,
@@ -574,18 +651,63 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         self.read_slice(number_of_bytes, |buffer: &[u8]| Ok(Some(f(buffer))))
     }
 
+    fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>> {
+        read_safety_checks!(self, IonType::Blob);
+        self.read_value_into_buffer().map(Some)
+    }
+
+    fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>> {
+        read_safety_checks!(self, IonType::Clob);
+        self.read_value_into_buffer().map(Some)
+    }
+
     fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
         self.clob_ref_map(|c| c.into())
     }
 
+    fn read_blob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>>
+    where
+        F: FnMut(&[u8]) -> IonResult<()>,
+    {
+        if chunk_size == 0 {
+            return illegal_operation("The chunk size for a blob read must be greater than zero.");
+        }
+        read_safety_checks!(self, IonType::Blob);
+        self.read_value_in_chunks(chunk_size, f).map(Some)
+    }
+
+    fn read_clob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>>
+    where
+        F: FnMut(&[u8]) -> IonResult<()>,
+    {
+        if chunk_size == 0 {
+            return illegal_operation("The chunk size for a clob read must be greater than zero.");
+        }
+        read_safety_checks!(self, IonType::Clob);
+        self.read_value_in_chunks(chunk_size, f).map(Some)
+    }
+
     fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
         read_safety_checks!(self, IonType::Timestamp);
 
         let datetime_start_offset = self.cursor.bytes_read;
 
+        // The smallest legal timestamp consists of an offset and a year.
+        if self.cursor.value.value_length == 0 {
+            return decoding_error_at(
+                "timestamps must specify an offset and a year",
+                self.cursor.value.header_offset,
+            );
+        }
         let offset = self.read_var_int()?;
         let is_known_offset = !offset.is_negative_zero();
         let offset_minutes = offset.value() as i32;
+        if self.finished_reading_value() {
+            return decoding_error_at(
+                "timestamps must specify a year",
+                self.cursor.value.header_offset,
+            );
+        }
         let year = self.read_var_uint()?.value() as u32;
 
         // Year precision
@@ -593,7 +715,11 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         let builder = Timestamp::with_year(year);
         if self.finished_reading_value() {
             let timestamp = builder.build()?;
-            return Ok(Some(timestamp));
+            return Ok(Some(with_date_offset(
+                timestamp,
+                is_known_offset,
+                offset_minutes,
+            )?));
         }
 
         // Month precision
@@ -602,7 +728,11 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         let builder = builder.with_month(month);
         if self.finished_reading_value() {
             let timestamp = builder.build()?;
-            return Ok(Some(timestamp));
+            return Ok(Some(with_date_offset(
+                timestamp,
+                is_known_offset,
+                offset_minutes,
+            )?));
         }
 
         // Day precision
@@ -611,14 +741,21 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         let builder = builder.with_day(day);
         if self.finished_reading_value() {
             let timestamp = builder.build()?;
-            return Ok(Some(timestamp));
+            return Ok(Some(with_date_offset(
+                timestamp,
+                is_known_offset,
+                offset_minutes,
+            )?));
         }
 
         // Hour-and-minute precision
 
         let hour = self.read_var_uint()?.value() as u32;
         if self.finished_reading_value() {
-            return decoding_error("timestamps with an hour must also specify a minute");
+            return decoding_error_at(
+                "timestamps with an hour must also specify a minute",
+                self.cursor.value.header_offset,
+            );
         }
         let minute = self.read_var_uint()?.value() as u32;
         let builder = builder.with_hour_and_minute(hour, minute);
@@ -650,11 +787,27 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         // The remaining bytes represent the coefficient. We need to determine how many bytes
         // we've read to know how many remain.
         let value_bytes_read = self.cursor.bytes_read - datetime_start_offset;
-        let coefficient_size_in_bytes = self.cursor.value.value_length - value_bytes_read;
+        let coefficient_size_in_bytes = self.remaining_value_length(value_bytes_read)?;
         let subsecond_coefficient = if coefficient_size_in_bytes == 0 {
-            0
+            Coefficient::from(0i64)
+        } else if coefficient_size_in_bytes <= core::mem::size_of::<i64>() {
+            Coefficient::from(self.read_int(coefficient_size_in_bytes)?.value())
         } else {
-            self.read_int(coefficient_size_in_bytes)?.value()
+            // Fractional seconds that are more precise than an i64 can hold (roughly 18 digits)
+            // have a coefficient that is too large for `read_int`.
+            self.read_slice(coefficient_size_in_bytes, |bytes| {
+                // The first bit is the coefficient's sign bit
+                let mut magnitude_bytes = bytes.to_vec();
+                let is_negative = magnitude_bytes[0] & 0b1000_0000 != 0;
+                magnitude_bytes[0] &= 0b0111_1111;
+                let magnitude = BigUint::from_bytes_be(&magnitude_bytes);
+                let sign = if is_negative {
+                    CoefficientSign::Negative
+                } else {
+                    CoefficientSign::Positive
+                };
+                Ok(Coefficient::new(sign, magnitude))
+            })?
         };
 
         let builder = builder
@@ -724,7 +877,7 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
             // The remaining bytes represent the coefficient. We need to determine how many bytes
             // we've read to know how many remain.
             let value_bytes_read = self.cursor.bytes_read - datetime_start_offset;
-            let coefficient_size_in_bytes = self.cursor.value.value_length - value_bytes_read;
+            let coefficient_size_in_bytes = self.remaining_value_length(value_bytes_read)?;
             subsecond_coefficient = self.read_int(coefficient_size_in_bytes)?.value();
         }
 
@@ -735,24 +888,40 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
         //      precision.
         const NANOSECONDS_PER_SECOND: f64 = 1_000_000_000f64;
         let fractional_seconds =
-            subsecond_coefficient as f64 * 10f64.powf(subsecond_exponent as f64);
+            subsecond_coefficient as f64 * 10f64.powi(subsecond_exponent as i32);
         let nanoseconds = (fractional_seconds * NANOSECONDS_PER_SECOND).round() as u32;
 
-        let naive_datetime = NaiveDate::from_ymd(year as i32, month as u32, day as u32)
-            .and_hms(hour as u32, minute as u32, second as u32)
-            .with_nanosecond(nanoseconds);
+        let naive_datetime = NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+            .and_then(|date| date.and_hms_opt(hour as u32, minute as u32, second as u32))
+            .and_then(|datetime| datetime.with_nanosecond(nanoseconds));
 
         if naive_datetime.is_none() {
-            return decoding_error(
+            return decoding_error_at(
                 format!(
                     "{}: year={}, month={}, day={}, hour={}, minute={}, second={}, exp={}, coeff={}, nanos={}",
                     "Read a timestamp that would not be a legal DateTime.",
                     year, month, day, hour, minute, second, subsecond_exponent, subsecond_coefficient, nanoseconds
-                )
+                ),
+                self.cursor.value.header_offset,
             );
         }
 
-        let offset = FixedOffset::west(offset_minutes as i32 * 60i32);
+        let offset = i32::try_from(offset_minutes)
+            .ok()
+            .and_then(|minutes| minutes.checked_mul(60))
+            .and_then(FixedOffset::west_opt);
+        let offset = match offset {
+            Some(offset) => offset,
+            None => {
+                return decoding_error_at(
+                    format!(
+                        "timestamp offset is out of range: {} minutes",
+                        offset_minutes
+                    ),
+                    self.cursor.value.header_offset,
+                )
+            }
+        };
         let datetime = offset.from_utc_datetime(&naive_datetime.unwrap());
         Ok(Some(datetime))
     }
@@ -760,11 +929,14 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
     #[inline]
     fn step_in(&mut self) -> IonResult<()> {
         use self::IonType::*;
-        use std::mem;
+        use core::mem;
+        if self.ion_type().is_none() {
+            return illegal_operation("Cannot `step_in`: the reader is not positioned on a value.");
+        }
         self.cursor.is_in_struct = match self.cursor.value.ion_type {
             Struct => true,
             List | SExpression => false,
-            _ => panic!("You cannot step into a(n) {:?}", self.cursor.value.ion_type),
+            scalar => return illegal_operation(format!("Cannot step_in() to a {:?}", scalar)),
         };
         self.cursor.parents.push(EncodedValue::default());
         // We've just push()ed a value onto the `parents` Vec, so it's safe to call
@@ -780,7 +952,7 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
 
     #[inline]
     fn step_out(&mut self) -> IonResult<()> {
-        use std::mem;
+        use core::mem;
         let bytes_to_skip;
 
         // Clear annotations belonging to the current value before we step out.
@@ -830,14 +1002,65 @@ impl<R: IonDataSource> RawReader for RawBinaryReader<R> {
     }
 }
 
+/// Streams the bytes of the blob or clob over which a [RawBinaryReader] is positioned.
+/// See [RawBinaryReader::blob_reader].
+struct LobReader<'a, R: IonDataSource, A: Allocator + Clone> {
+    reader: &'a mut RawBinaryReader<R, A>,
+    bytes_remaining: usize,
+}
+
+impl<'a, R: IonDataSource, A: Allocator + Clone> io::Read for LobReader<'a, R, A> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.bytes_remaining == 0 || buf.is_empty() {
+            return Ok(0);
+        }
+        let max_bytes_to_read = buf.len().min(self.bytes_remaining);
+        let bytes_read =
+            io::Read::read(&mut self.reader.data_source, &mut buf[..max_bytes_to_read])?;
+        if bytes_read == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Unexpected end of stream while reading a lob.",
+            ));
+        }
+        self.bytes_remaining -= bytes_read;
+        self.reader.cursor.bytes_read += bytes_read;
+        Ok(bytes_read)
+    }
+}
+
+// Date-precision (year, month, or day) timestamps have no time fields to which an offset could
+// apply, but their binary encoding always includes one. If the encoding specifies a known offset,
+// it is preserved on the resulting Timestamp rather than being silently discarded.
+fn with_date_offset(
+    mut timestamp: Timestamp,
+    is_known_offset: bool,
+    offset_minutes: i32,
+) -> IonResult<Timestamp> {
+    if is_known_offset {
+        let offset = offset_minutes
+            .checked_mul(60)
+            .and_then(FixedOffset::east_opt)
+            .ok_or_else(|| {
+                decoding_error_raw(format!(
+                    "timestamp offset is out of range: {} minutes",
+                    offset_minutes
+                ))
+            })?;
+        timestamp.offset = Some(offset);
+    }
+    Ok(timestamp)
+}
+
 const EMPTY_SLICE_U8: &[u8] = &[];
 const EMPTY_SLICE_RAW_SYMBOL_TOKEN: &[RawSymbolToken] = &[];
 
 /// Additional functionality that's only available if the data source is in-memory, such as a
 /// Vec<u8> or &[u8]).
-impl<T> RawBinaryReader<io::Cursor<T>>
+impl<T, A> RawBinaryReader<io::Cursor<T>, A>
 where
     T: AsRef<[u8]>,
+    A: Allocator + Clone,
 {
     delegate! {
         to self.cursor.value {
@@ -879,6 +1102,41 @@ where
         Some(&bytes[start..end])
     }
 
+    /// Returns a slice containing the current value's complete encoding: its annotations wrapper
+    /// (if present), its header, and its body. For containers, this includes all of the encoded
+    /// child values. Unlike [raw_bytes](Self::raw_bytes), the field ID is not included, so the
+    /// returned bytes can be copied verbatim into another stream (or into another struct
+    /// following a field ID) without being re-encoded.
+    ///
+    /// Calling this function does not advance the cursor; a subsequent call to
+    /// [next](RawReader::next) will skip over the value as usual.
+    ///
+    /// Returns `Ok(None)` if the cursor is not positioned over a value, or a decoding error if
+    /// the data source ends before the value does.
+    pub fn encoded_bytes(&self) -> IonResult<Option<&[u8]>> {
+        if self.ion_type().is_none() {
+            return Ok(None);
+        }
+        let start = self
+            .cursor
+            .value
+            .annotations_offset()
+            .unwrap_or(self.cursor.value.header_offset);
+        let end = self.cursor.value.value_end_exclusive();
+        let bytes = self.data_source.get_ref().as_ref();
+        if end > bytes.len() {
+            return decoding_error_at(
+                format!(
+                    "value ends at byte {} but the input is only {} bytes long",
+                    end,
+                    bytes.len()
+                ),
+                bytes.len(),
+            );
+        }
+        Ok(Some(&bytes[start..end]))
+    }
+
     /// Returns a slice containing the current value's header's raw bytes without advancing the
     /// cursor. Includes the type descriptor byte and any bytes used to represent the `length`
     /// field.
@@ -890,6 +1148,18 @@ where
         return Some(&bytes[self.cursor.value.header_range()]);
     }
 
+    /// Returns a slice containing the current value's header and body without advancing the
+    /// cursor. Unlike [encoded_bytes](Self::encoded_bytes), the annotations wrapper (if any) is
+    /// omitted, so the value can be copied into another stream and given different annotations
+    /// without being re-encoded.
+    pub fn raw_header_and_value_bytes(&self) -> Option<&[u8]> {
+        self.ion_type()?;
+        let start = self.cursor.value.header_offset;
+        let end = self.cursor.value.value_end_exclusive();
+        let bytes = self.data_source.get_ref().as_ref();
+        Some(&bytes[start..end])
+    }
+
     /// Returns a slice containing the current value's raw bytes (not including its field ID,
     /// annotations, or type descriptor byte) without advancing the cursor.
     pub fn raw_value_bytes(&self) -> Option<&[u8]> {
@@ -925,6 +1195,401 @@ where
         }
         None
     }
+
+    /// Returns a copy of the cursor's current state along with the data source's position.
+    /// Passing these to [restore_state] will return the reader to this point in the stream.
+    pub(crate) fn save_state(&self) -> (CursorState<A>, u64) {
+        (self.cursor.clone(), self.data_source.position())
+    }
+
+    /// Restores a cursor state and data source position previously captured by [save_state].
+    pub(crate) fn restore_state(&mut self, cursor: CursorState<A>, position: u64) {
+        self.set_encoding(cursor.encoding);
+        self.cursor = cursor;
+        self.data_source.set_position(position);
+    }
+}
+
+/// Zero-copy accessors that are only available if the data source is a borrowed slice. The
+/// returned references point directly into the input, so they remain valid after the reader has
+/// advanced to other values (or has been dropped).
+impl<'a, A> RawBinaryReader<io::Cursor<&'a [u8]>, A>
+where
+    A: Allocator + Clone,
+{
+    /// If the current value is a string, returns a reference to its text within the input;
+    /// otherwise, returns None.
+    pub fn read_str_ref(&mut self) -> IonResult<Option<&'a str>> {
+        read_safety_checks!(self, IonType::String);
+
+        let bytes = self.borrow_value_bytes()?;
+        match core::str::from_utf8(bytes) {
+            Ok(text) => Ok(Some(text)),
+            Err(utf8_error) => Err(decoding_error_raw(format!(
+                "The requested string was not valid UTF-8: {:?}",
+                utf8_error
+            ))
+            .with_category(DecodingErrorCategory::InvalidUtf8)),
+        }
+    }
+
+    /// If the current value is a blob, returns a reference to its bytes within the input;
+    /// otherwise, returns None.
+    pub fn read_blob_ref(&mut self) -> IonResult<Option<&'a [u8]>> {
+        read_safety_checks!(self, IonType::Blob);
+        self.borrow_value_bytes().map(Some)
+    }
+
+    /// If the current value is a clob, returns a reference to its bytes within the input;
+    /// otherwise, returns None.
+    pub fn read_clob_ref(&mut self) -> IonResult<Option<&'a [u8]>> {
+        read_safety_checks!(self, IonType::Clob);
+        self.borrow_value_bytes().map(Some)
+    }
+
+    // Returns the body of the current value as a slice of the input and advances the cursor
+    // past it, as reading the value into a buffer would.
+    fn borrow_value_bytes(&mut self) -> IonResult<&'a [u8]> {
+        let input: &'a [u8] = self.data_source.get_ref();
+        let start = self.cursor.bytes_read;
+        let end = start + self.cursor.value.value_length;
+        if end > input.len() {
+            return Err(
+                incomplete_data_error_raw("Unexpected end of stream.").with_offset(input.len())
+            );
+        }
+        self.skip_bytes(end - start)?;
+        Ok(&input[start..end])
+    }
+}
+
+/// A RawBinaryReader whose input is a memory-mapped file. Because [memmap2::Mmap] dereferences
+/// to a `&[u8]`, the reader's data source is a single contiguous in-memory buffer. This means
+/// that [RawReader::string_ref_map], [RawReader::blob_ref_map] and the other `*_map` methods
+/// will always be handed slices that point directly into the mapped pages; the `fallback_buffer`
+/// used for streaming sources is never touched.
+#[cfg(feature = "mmap")]
+impl RawBinaryReader<io::Cursor<memmap2::Mmap>> {
+    /// Memory-maps the file at `path` and constructs a RawBinaryReader over its contents.
+    ///
+    /// The file must not be modified (by this process or any other) while the reader is alive;
+    /// doing so is undefined behavior. See [memmap2::Mmap::map] for details.
+    pub fn from_mmap<P: AsRef<std::path::Path>>(path: P) -> IonResult<Self> {
+        let file = std::fs::File::open(path)?;
+        // Safety: the caller is responsible for ensuring that the underlying file is not
+        // modified while it is mapped. This requirement is documented above.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        Ok(RawBinaryReader::new(io::Cursor::new(mmap)))
+    }
+}
+
+/// Configures and constructs a [RawBinaryReader].
+///
+/// With the `allocator` feature enabled, [allocator](Self::allocator) can be used to provide an
+/// [Allocator] (such as an arena or bump allocator) for the reader's internal buffers: its scratch
+/// buffer for values that are not contiguous in the data source's input buffer, and the storage
+/// for the stack of parent containers and for the current value's annotations. Buffering done by
+/// the data source itself, the reader's table of decoded type descriptors, and values returned
+/// to the caller (like a `String` from `read_string`) still use the global allocator.
+///
+/// [max_value_length](Self::max_value_length) limits the lengths that values in the stream can
+/// declare, which protects against untrusted input causing large allocations.
+#[derive(Debug)]
+pub struct RawBinaryReaderBuilder<A: Allocator + Clone = Global> {
+    allocator: A,
+    max_value_length: Option<usize>,
+    suppress_mid_stream_ivms: bool,
+}
+
+impl RawBinaryReaderBuilder {
+    /// Creates a RawBinaryReaderBuilder that will use the [Global] allocator.
+    pub fn new() -> Self {
+        RawBinaryReaderBuilder {
+            allocator: Global,
+            max_value_length: None,
+            suppress_mid_stream_ivms: false,
+        }
+    }
+}
+
+impl Default for RawBinaryReaderBuilder {
+    fn default() -> Self {
+        RawBinaryReaderBuilder::new()
+    }
+}
+
+impl<A: Allocator + Clone> RawBinaryReaderBuilder<A> {
+    /// Sets the allocator that the reader will use for its internal buffers.
+    #[cfg(feature = "allocator")]
+    pub fn allocator<B: Allocator + Clone>(self, allocator: B) -> RawBinaryReaderBuilder<B> {
+        RawBinaryReaderBuilder {
+            allocator,
+            max_value_length: self.max_value_length,
+            suppress_mid_stream_ivms: self.suppress_mid_stream_ivms,
+        }
+    }
+
+    /// Sets the largest number of bytes that a value's header (or an annotations wrapper) may
+    /// declare that it contains. When `next()` encounters a value whose declared length exceeds
+    /// this limit, it returns a decoding error in the [DecodingErrorCategory::LimitExceeded]
+    /// category before reading any of the value's body. By default, there is no limit.
+    ///
+    /// Containers declare the length of their entire contents, so this also limits the size of
+    /// lists, s-expressions and structs.
+    pub fn max_value_length(mut self, max_value_length: usize) -> Self {
+        self.max_value_length = Some(max_value_length);
+        self
+    }
+
+    /// Configures whether Ion version markers that appear after the beginning of the stream are
+    /// returned by `next()`. Producers often repeat the IVM to mark the boundaries between
+    /// concatenated streams or to let consumers resynchronize. When suppression is enabled, such
+    /// IVMs are still validated, but `next()` moves on to the following item instead of returning
+    /// a [StreamItem::VersionMarker]. An IVM at the very beginning of the stream (or at the start
+    /// of a data source passed to [reset](RawBinaryReader::reset)) is always returned, as is an
+    /// IVM that changes the reader's [ion_version](RawReader::ion_version). By default, every IVM
+    /// is returned.
+    ///
+    /// Per the Ion spec, an IVM resets the symbol table. A [Reader](crate::Reader) relies on
+    /// seeing each IVM to do so, so suppression is only suitable for callers of the raw reader
+    /// that do not need to know where symbol tables end.
+    pub fn suppress_mid_stream_ivms(mut self, suppress: bool) -> Self {
+        self.suppress_mid_stream_ivms = suppress;
+        self
+    }
+
+    /// Constructs a RawBinaryReader over the provided data source.
+    pub fn build<R: IonDataSource>(self, data_source: R) -> RawBinaryReader<R, A> {
+        let mut reader = RawBinaryReader::new_in(data_source, self.allocator);
+        reader.max_value_length = self.max_value_length;
+        reader.suppress_mid_stream_ivms = self.suppress_mid_stream_ivms;
+        reader
+    }
+}
+
+/// The location of a top-level value in a binary Ion stream. See [RawBinaryReader::position] and
+/// [RawBinaryReader::seek_to].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StreamPosition {
+    // The offset of the value's annotations wrapper (if any) or header from the start of the stream
+    offset: usize,
+    // The encoding in effect at `offset`
+    encoding: BinaryEncoding,
+}
+
+impl StreamPosition {
+    /// Returns the number of bytes between the beginning of the stream and the first byte of the
+    /// value (or of its annotations wrapper, if it has one).
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+/// Describes how the value over which a [RawBinaryReader] is positioned was encoded, for tools
+/// that audit encodings (for example, to find lengths that were encoded in more bytes than
+/// necessary or annotation wrappers that could have been omitted). See
+/// [RawBinaryReader::value_encoding].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValueEncoding {
+    type_descriptor: u8,
+    declared_length: usize,
+    length_field_length: usize,
+    annotations_wrapper_length: Option<usize>,
+}
+
+impl ValueEncoding {
+    /// Returns the value's type descriptor byte.
+    pub fn type_descriptor(&self) -> u8 {
+        self.type_descriptor
+    }
+
+    /// Returns the length of the value's body as declared by its header, not including the
+    /// type descriptor byte or any length field.
+    pub fn declared_length(&self) -> usize {
+        self.declared_length
+    }
+
+    /// Returns true if the value's length was encoded as a VarUInt following the type descriptor
+    /// byte rather than in the type descriptor's length nibble.
+    pub fn has_varuint_length(&self) -> bool {
+        self.length_field_length > 0
+    }
+
+    /// Returns the number of bytes used to encode the VarUInt length following the type
+    /// descriptor byte, or 0 if there is none.
+    pub fn length_field_length(&self) -> usize {
+        self.length_field_length
+    }
+
+    /// Returns true if the value was wrapped in an annotations wrapper.
+    pub fn has_annotations_wrapper(&self) -> bool {
+        self.annotations_wrapper_length.is_some()
+    }
+
+    /// Returns the number of bytes used to encode the value's annotations wrapper, not including
+    /// the value itself, or `None` if it has no annotations wrapper.
+    pub fn annotations_wrapper_length(&self) -> Option<usize> {
+        self.annotations_wrapper_length
+    }
+}
+
+/// Random access, which is only available if the data source implements [io::Seek].
+impl<R, A> RawBinaryReader<R, A>
+where
+    R: IonDataSource + io::Seek,
+    A: Allocator + Clone,
+{
+    /// Repositions the reader so that the next call to [next](RawReader::next) will return the
+    /// top-level value at `position`, which must have been returned by [position](Self::position)
+    /// on a reader over the same stream. If the reader is currently inside a container, it is
+    /// returned to the top level.
+    ///
+    /// The data source is repositioned with [io::Seek::seek] using the position's offset from
+    /// the beginning of the stream, so the data source must have been at the beginning of the
+    /// stream when the reader was created (or last [reset](Self::reset)).
+    pub fn seek_to(&mut self, position: StreamPosition) -> IonResult<()> {
+        self.data_source
+            .seek(io::SeekFrom::Start(position.offset as u64))?;
+        self.set_encoding(position.encoding);
+        self.cursor.bytes_read = position.offset;
+        self.top_level_item_start = position.offset;
+        self.cursor.depth = 0;
+        self.cursor.index_at_depth = 0;
+        self.cursor.is_in_struct = false;
+        self.cursor.value = Default::default();
+        self.cursor.parents.clear();
+        self.cursor.annotations.clear();
+        Ok(())
+    }
+
+    /// Like [seek_to](Self::seek_to), but takes the raw offset of a top-level value from the
+    /// beginning of the stream, such as one returned by [encoded_range](Self::encoded_range) and
+    /// stored in an index. The offset must be that of a value (or of its annotations wrapper)
+    /// encoded using Ion 1.0; seeking to any other offset will produce decoding errors or
+    /// nonsensical values.
+    pub fn seek_to_offset(&mut self, offset: usize) -> IonResult<()> {
+        self.seek_to(StreamPosition {
+            offset,
+            encoding: BinaryEncoding::V1_0,
+        })
+    }
+
+    /// After [next](RawReader::next) or any other method has returned a decoding error,
+    /// repositions the reader at the top level so that the next call to `next` will return the
+    /// first plausible top-level item that follows the value that could not be read. This allows
+    /// an application to salvage the rest of a partially corrupted stream.
+    ///
+    /// If the header of the value that could not be read was well-formed, the reader skips to
+    /// the end of the value that its length describes. Otherwise, or if no well-formed value
+    /// begins there, the reader examines each later offset in turn until it finds an IVM or a
+    /// value whose encoding (including that of any values nested inside it) is structurally
+    /// valid and which ends within the stream. Corrupted bytes can happen to form a valid value,
+    /// so the item at which reading resumes is not guaranteed to be one that was written.
+    ///
+    /// Returns the range of bytes that were skipped: from the beginning of the top-level item
+    /// that could not be read to the offset at which reading will resume. If no plausible item
+    /// was found, the range ends at the end of the stream and `next` will return `None`.
+    pub fn recover(&mut self) -> IonResult<Range<usize>> {
+        let failed_at = self.top_level_item_start;
+        let stream_length = self.data_source.seek(io::SeekFrom::End(0))? as usize;
+        let encoding = self.cursor.encoding;
+        let mut value_end = self.failed_value_end(failed_at, stream_length);
+        let mut next_offset = failed_at + 1;
+        let resume_at = loop {
+            let candidate = match value_end.take() {
+                Some(end) => end,
+                None if next_offset < stream_length => {
+                    next_offset += 1;
+                    next_offset - 1
+                }
+                None => break stream_length,
+            };
+            if self.is_plausible_item_at(candidate, encoding, stream_length)? {
+                break candidate;
+            }
+        };
+        self.seek_to(StreamPosition {
+            offset: resume_at,
+            encoding,
+        })?;
+        Ok(failed_at..resume_at)
+    }
+
+    // If the reader found the header of the top-level value that begins at `failed_at`, returns
+    // the offset just past the end of that value according to the header, as long as it is
+    // within the stream.
+    fn failed_value_end(&self, failed_at: usize, stream_length: usize) -> Option<usize> {
+        let value = match self.cursor.parents.first() {
+            Some(parent) => parent,
+            None if self.ion_type().is_some() => &self.cursor.value,
+            None => return None,
+        };
+        // At the top level, the cursor may still describe the value before the one that failed.
+        if value.header_offset() < failed_at {
+            return None;
+        }
+        let end = value.value_end_exclusive();
+        if end > failed_at && end <= stream_length {
+            Some(end)
+        } else {
+            None
+        }
+    }
+
+    // Returns true if an IVM or a structurally valid top-level value that ends within the stream
+    // begins at `offset`. I/O errors are returned as-is.
+    fn is_plausible_item_at(
+        &mut self,
+        offset: usize,
+        encoding: BinaryEncoding,
+        stream_length: usize,
+    ) -> IonResult<bool> {
+        self.seek_to(StreamPosition { offset, encoding })?;
+        let result = match self.next() {
+            Ok(Some(StreamItem::Value(_, _))) => self.validate_current_value(stream_length),
+            Ok(Some(StreamItem::VersionMarker(_, _))) => Ok(()),
+            // Only padding follows `offset`
+            Ok(None) => return Ok(false),
+            Err(error) => Err(error),
+        };
+        match result {
+            Ok(()) => Ok(true),
+            Err(IonError::IoError { source }) if source.kind() != io::ErrorKind::UnexpectedEof => {
+                Err(IonError::IoError { source })
+            }
+            Err(_) => Ok(false),
+        }
+    }
+
+    // Reads past the top-level value that the reader is positioned over, stepping into any
+    // containers to check the encodings of the values nested inside them.
+    fn validate_current_value(&mut self, stream_length: usize) -> IonResult<()> {
+        if self.cursor.value.value_end_exclusive() > stream_length {
+            return decoding_error("The value extends past the end of the stream.");
+        }
+        if !self.ion_type().is_some_and(|t| t.is_container()) || self.is_null() {
+            return Ok(());
+        }
+        self.step_in()?;
+        loop {
+            match self.next()? {
+                Some(StreamItem::Value(ion_type, is_null)) => {
+                    let parent_end = self.cursor.parents.last().unwrap().value_end_exclusive();
+                    if self.cursor.value.value_end_exclusive() > parent_end {
+                        return decoding_error("A value overruns its parent container.");
+                    }
+                    if ion_type.is_container() && !is_null {
+                        self.step_in()?;
+                    }
+                }
+                Some(StreamItem::VersionMarker(_, _)) => {
+                    return decoding_error("Found an IVM inside a container.");
+                }
+                None if self.depth() > 1 => self.step_out()?,
+                None => return self.step_out(),
+            }
+        }
+    }
 }
 
 impl<R> RawBinaryReader<R>
@@ -932,32 +1597,194 @@ where
     R: IonDataSource,
 {
     pub fn new(data_source: R) -> Self {
+        RawBinaryReader::new_in(data_source, Global)
+    }
+}
+
+impl<R, A> RawBinaryReader<R, A>
+where
+    R: IonDataSource,
+    A: Allocator + Clone,
+{
+    fn new_in(data_source: R, allocator: A) -> Self {
+        let mut buffer = AllocVec::new_in(allocator.clone());
+        buffer.resize(4096, 0);
         RawBinaryReader {
             data_source,
-            buffer: vec![0; 4096],
+            buffer,
             cursor: CursorState {
-                ion_version: (1, 0),
+                encoding: BinaryEncoding::default(),
                 bytes_read: 0,
                 depth: 0,
                 index_at_depth: 0,
                 is_in_struct: false,
                 value: Default::default(),
-                parents: Vec::new(),
-                annotations: Vec::new(),
+                parents: AllocVec::new_in(allocator.clone()),
+                annotations: AllocVec::new_in(allocator),
             },
-            header_cache: create_header_byte_jump_table(),
+            header_cache: BinaryEncoding::default().header_table(),
+            header_cache_encoding: BinaryEncoding::default(),
+            max_value_length: None,
+            suppress_mid_stream_ivms: false,
+            top_level_item_start: 0,
+        }
+    }
+
+    /// Replaces the reader's data source with `data_source` and returns the reader to the
+    /// beginning of a new stream at the top level. The reader's scratch buffer and the
+    /// allocations backing its container and annotation bookkeeping are retained, making this
+    /// cheaper than constructing a new reader when decoding many small, independent messages.
+    ///
+    /// Any state from the previous data source (the current value, its annotations, the stack
+    /// of parent containers, and the Ion version) is discarded.
+    pub fn reset(&mut self, data_source: R) {
+        self.data_source = data_source;
+        self.set_encoding(BinaryEncoding::default());
+        self.cursor.bytes_read = 0;
+        self.top_level_item_start = 0;
+        self.cursor.depth = 0;
+        self.cursor.index_at_depth = 0;
+        self.cursor.is_in_struct = false;
+        self.cursor.value = Default::default();
+        self.cursor.parents.clear();
+        self.cursor.annotations.clear();
+    }
+
+    /// If the reader is positioned over a top-level value, returns the position of that value in
+    /// the stream. The position can later be passed to [seek_to](Self::seek_to) to return to the
+    /// value without reading the data that precedes it. Returns `None` if the reader is not
+    /// positioned over a value or the current value is nested inside a container.
+    pub fn position(&self) -> Option<StreamPosition> {
+        if self.cursor.depth > 0 || self.ion_type().is_none() {
+            return None;
         }
+        let offset = self
+            .cursor
+            .value
+            .annotations_offset()
+            .unwrap_or(self.cursor.value.header_offset);
+        Some(StreamPosition {
+            offset,
+            encoding: self.cursor.encoding,
+        })
+    }
+
+    /// Returns the range of bytes occupied by the current value's complete encoding: its
+    /// annotations wrapper (if present), its header, and its body. Offsets are counted from the
+    /// beginning of the stream. Unlike [encoded_bytes](Self::encoded_bytes), this is available
+    /// for any data source, so applications can record where each value lives (e.g. to build an
+    /// index) while streaming through a file. Returns `None` if the reader is not positioned
+    /// over a value.
+    pub fn encoded_range(&self) -> Option<Range<usize>> {
+        self.ion_type()?;
+        let start = self
+            .cursor
+            .value
+            .annotations_offset()
+            .unwrap_or(self.cursor.value.header_offset);
+        Some(start..self.cursor.value.value_end_exclusive())
+    }
+
+    /// Returns low-level details of how the current value was encoded: its type descriptor byte,
+    /// its declared length, how that length was encoded, and the size of its annotations
+    /// wrapper (if any). Returns `None` if the reader is not positioned over a value.
+    pub fn value_encoding(&self) -> Option<ValueEncoding> {
+        self.ion_type()?;
+        let value = &self.cursor.value;
+        Some(ValueEncoding {
+            type_descriptor: value.header.ion_type_code.to_u8() << 4 | value.header.length_code,
+            declared_length: value.value_length(),
+            length_field_length: value.header_length(),
+            annotations_wrapper_length: value.annotations_length(),
+        })
     }
 
     pub fn is_null(&self) -> bool {
         self.cursor.value.is_null
     }
 
+    /// Walks the remainder of the stream, checking that it is structurally well-formed without
+    /// decoding the bodies of any scalar values. Each value's type descriptor, length and
+    /// annotations wrapper are read and validated, containers are stepped into, and lengths are
+    /// checked against both the enclosing container and the end of the stream.
+    ///
+    /// Returns `Ok(())` if the stream is well-formed. Otherwise, returns the first error
+    /// encountered; if it is a decoding error, its [offset](crate::result::IonError::offset)
+    /// will be the byte offset at which the problem was detected.
+    pub fn validate_structure(&mut self) -> IonResult<()> {
+        self.walk_structure()
+            .map_err(|error| error.with_offset(self.cursor.bytes_read))
+    }
+
+    /// If the reader is positioned over a non-null blob, returns an [io::Read] implementation
+    /// that streams the blob's bytes directly out of the data source. This allows arbitrarily
+    /// large blobs to be processed incrementally without buffering the entire value in memory.
+    /// If the reader is not positioned over a non-null blob, returns `Ok(None)`.
+    ///
+    /// The returned reader will not read beyond the end of the blob. It does not need to be read
+    /// to completion; any bytes that remain unread when it is dropped will be skipped on the next
+    /// call to [RawReader::next]. If it is dropped early, calling `blob_reader` again will resume
+    /// at the first unread byte. Other methods for reading the blob (like
+    /// [RawReader::read_blob_bytes]) should not be used once `blob_reader` has been called.
+    pub fn blob_reader(&mut self) -> IonResult<Option<impl io::Read + '_>> {
+        read_safety_checks!(self, IonType::Blob);
+        Ok(Some(self.lob_reader()))
+    }
+
+    /// Like [blob_reader](Self::blob_reader), but for clobs.
+    pub fn clob_reader(&mut self) -> IonResult<Option<impl io::Read + '_>> {
+        read_safety_checks!(self, IonType::Clob);
+        Ok(Some(self.lob_reader()))
+    }
+
+    fn lob_reader(&mut self) -> LobReader<'_, R, A> {
+        let bytes_remaining = self.cursor.value.value_end_exclusive() - self.cursor.bytes_read;
+        LobReader {
+            reader: self,
+            bytes_remaining,
+        }
+    }
+
+    fn walk_structure(&mut self) -> IonResult<()> {
+        loop {
+            match self.next()? {
+                Some(StreamItem::Value(ion_type, is_null)) => {
+                    if let Some(parent) = self.cursor.parents.last() {
+                        let value_end = self.cursor.value.value_end_exclusive();
+                        let parent_end = parent.value_end_exclusive();
+                        if value_end > parent_end {
+                            return decoding_error(format!(
+                                "{:?} value ending at byte {} overruns its parent container, which ends at byte {}",
+                                ion_type, value_end, parent_end
+                            ));
+                        }
+                    }
+                    if ion_type.is_container() && !is_null {
+                        self.step_in()?;
+                    }
+                }
+                Some(StreamItem::VersionMarker(_, _)) => {}
+                None if self.depth() > 0 => self.step_out()?,
+                None => return Ok(()),
+            }
+        }
+    }
+
     fn finished_reading_value(&mut self) -> bool {
         self.cursor.value.value_length > 0
             && self.cursor.bytes_read >= self.cursor.value.value_end_exclusive()
     }
 
+    // Called when `next` finds that there are no more items at the current depth, so that the
+    // reader no longer reports the last value it visited as the current one.
+    fn clear_current_value(&mut self) {
+        self.clear_annotations();
+        self.cursor.value.header.ion_type = None;
+        self.cursor.value.ion_type = IonType::Null;
+        self.cursor.value.is_null = false;
+        self.cursor.value.field_id = None;
+    }
+
     fn clear_annotations(&mut self) {
         if self.cursor.value.number_of_annotations > 0 {
             // Drop the annotations belonging to the last value read from the annotations Vec
@@ -979,24 +1806,73 @@ where
             _ => unreachable!("read_slice did not return the requested number of bytes"),
         })?;
 
-        if !matches!((major, minor), (1, 0)) {
-            decoding_error(format!("Unsupported Ion version {:X}.{:X}", major, minor))
-        } else {
-            self.cursor.ion_version = (major, minor);
-            Ok(StreamItem::VersionMarker(major, minor))
+        match BinaryEncoding::for_version(major, minor) {
+            Some(encoding) => {
+                self.set_encoding(encoding);
+                Ok(StreamItem::VersionMarker(major, minor))
+            }
+            // The IVM's four bytes are now behind the cursor
+            None => decoding_error_at(
+                format!("Unsupported Ion version {:X}.{:X}", major, minor),
+                self.cursor.bytes_read - 4,
+            ),
         }
     }
 
-    #[inline(always)]
-    fn read_var_uint(&mut self) -> IonResult<VarUInt> {
-        let var_uint = VarUInt::read(&mut self.data_source)?;
-        self.cursor.bytes_read += var_uint.size_in_bytes();
+    // Switches the reader to `encoding`, re-parsing the header jump table if it was built for a
+    // different encoding.
+    fn set_encoding(&mut self, encoding: BinaryEncoding) {
+        self.cursor.encoding = encoding;
+        if self.header_cache_encoding != encoding {
+            self.header_cache = encoding.header_table();
+            self.header_cache_encoding = encoding;
+        }
+    }
+
+    // Skips the body of the NOP pad whose header byte was just read, first checking that the pad
+    // doesn't overrun the end of the container (if any) that it is in.
+    fn skip_nop_pad(&mut self) -> IonResult<()> {
+        let number_of_bytes = self.read_standard_length()?;
+
+        // If we're in a container, validate that the NOP pad doesn't overrun the container end
+        if let Some(parent) = self.cursor.parents.last() {
+            // The NOP padding described starts on the byte *after* the NOP header
+            let nop_offset = self.cursor.bytes_read;
+            let nop_range = (nop_offset)..(nop_offset + number_of_bytes);
+            let container_range = parent.value_range();
+
+            if nop_range.end > container_range.end {
+                // This NOP is malformed, let's assemble data for error reporting
+                return decoding_error_at(
+                    format!(
+                        "{bytes}-byte NOP padding on byte range {nop_range:?} is {over} \
+                        byte{s} past container content range {container_range:?}",
+                        bytes = number_of_bytes,
+                        nop_range = nop_range,
+                        over = nop_range.end - container_range.end,
+                        s = if number_of_bytes == 1 { "" } else { "s" },
+                        container_range = container_range,
+                    ),
+                    nop_offset,
+                );
+            }
+        }
+
+        self.skip_bytes(number_of_bytes)
+    }
+
+    #[inline(always)]
+    fn read_var_uint(&mut self) -> IonResult<VarUInt> {
+        let var_uint = VarUInt::read(&mut self.data_source)
+            .map_err(|error| error.with_offset(self.cursor.bytes_read))?;
+        self.cursor.bytes_read += var_uint.size_in_bytes();
         Ok(var_uint)
     }
 
     #[inline(always)]
     fn read_var_int(&mut self) -> IonResult<VarInt> {
-        let var_int = VarInt::read(&mut self.data_source)?;
+        let var_int = VarInt::read(&mut self.data_source)
+            .map_err(|error| error.with_offset(self.cursor.bytes_read))?;
         self.cursor.bytes_read += var_int.size_in_bytes() as usize;
         Ok(var_int)
     }
@@ -1011,25 +1887,46 @@ where
 
     #[inline(always)]
     fn read_uint(&mut self, number_of_bytes: usize) -> IonResult<DecodedUInt> {
-        let uint = DecodedUInt::read(&mut self.data_source, number_of_bytes)?;
+        let uint = DecodedUInt::read(&mut self.data_source, number_of_bytes)
+            .map_err(|error| error.with_offset(self.cursor.bytes_read))?;
         self.cursor.bytes_read += uint.size_in_bytes();
         Ok(uint)
     }
 
     #[inline(always)]
+    // Returns the number of bytes in the current value that follow the first `value_bytes_read`.
+    // Variable-length fields are read without regard for the value's declared length, so this
+    // fails if the fields that have been read extend past the end of the value.
+    fn remaining_value_length(&self, value_bytes_read: usize) -> IonResult<usize> {
+        match self.cursor.value.value_length.checked_sub(value_bytes_read) {
+            Some(remaining) => Ok(remaining),
+            None => decoding_error_at(
+                format!(
+                    "the fields of a {} extend past the end of its declared length",
+                    self.cursor.value.ion_type
+                ),
+                self.cursor.value.header_offset,
+            ),
+        }
+    }
+
     fn read_int(&mut self, number_of_bytes: usize) -> IonResult<Int> {
-        let int = Int::read(&mut self.data_source, number_of_bytes)?;
+        let int = Int::read(&mut self.data_source, number_of_bytes)
+            .map_err(|error| error.with_offset(self.cursor.bytes_read))?;
         self.cursor.bytes_read += int.size_in_bytes();
         Ok(int)
     }
 
     fn process_header_by_type_code(&mut self, header: Header) -> IonResult<()> {
-        self.cursor.value.ion_type = header.ion_type.unwrap(); // TODO: Is cursor.value.ion_type redundant?
         self.cursor.value.header = header;
-        self.cursor.value.is_null = header.length_code == length_codes::NULL;
+        self.cursor.value.is_null = self.cursor.encoding.is_null(&header);
 
         // We've already read the header byte, so it's now behind the cursor.
         self.cursor.value.header_offset = self.cursor.bytes_read - 1;
+        // Until its length has been read, the value has no body; an error below must not leave
+        // behind the lengths of the previous value.
+        self.cursor.value.header_length = 0;
+        self.cursor.value.value_length = 0;
 
         use IonTypeCode::*;
         let length = match header.ion_type_code {
@@ -1038,14 +1935,53 @@ where
             | SExpression | Clob | Blob => self.read_standard_length()?,
             Float => self.read_float_length()?,
             Struct => self.read_struct_length()?,
-            Annotation => return decoding_error("Found an annotation wrapping an annotation."),
-            Reserved => return decoding_error("Found an Ion Value with a Reserved type code."),
+            Annotation => {
+                return Err(
+                    decoding_error_raw("Found an annotation wrapping an annotation.")
+                        .with_offset(self.cursor.value.header_offset)
+                        .with_category(DecodingErrorCategory::IllegalTypeDescriptor),
+                )
+            }
+            Reserved => {
+                return Err(decoding_error_raw(format!(
+                    "Found an Ion Value with a Reserved type code (type descriptor 0xF{:X}).",
+                    header.length_code
+                ))
+                .with_offset(self.cursor.value.header_offset)
+                .with_category(DecodingErrorCategory::IllegalTypeDescriptor))
+            }
         };
+        // Only the Annotation and Reserved type codes lack a corresponding IonType, and both were
+        // rejected above.
+        self.cursor.value.ion_type = header.ion_type.unwrap(); // TODO: Is cursor.value.ion_type redundant?
 
         self.cursor.value.header_length =
             (self.cursor.bytes_read - self.cursor.value.header_offset - 1) as u8;
         self.cursor.value.value_length = length;
-        Ok(())
+        self.check_declared_length(
+            self.cursor.value.ion_type,
+            length,
+            self.cursor.value.header_offset,
+        )
+    }
+
+    // Returns a decoding error if `length` exceeds the configured maximum value length.
+    fn check_declared_length(
+        &self,
+        ion_type: impl core::fmt::Display,
+        length: usize,
+        offset: usize,
+    ) -> IonResult<()> {
+        match self.max_value_length {
+            Some(max_value_length) if length > max_value_length => Err(limit_exceeded_error_raw(
+                format!(
+                    "Found a(n) {} with a declared length of {} bytes, exceeding the maximum of {}.",
+                    ion_type, length, max_value_length
+                ),
+            )
+            .with_offset(offset)),
+            _ => Ok(()),
+        }
     }
 
     #[inline(always)]
@@ -1066,10 +2002,12 @@ where
             8 => 8,
             length_codes::NULL => 0,
             _ => {
-                return decoding_error(format!(
+                return Err(decoding_error_raw(format!(
                     "Found a Float value with an illegal length: {}",
                     self.cursor.value.header.length_code
                 ))
+                .with_offset(self.cursor.value.header_offset)
+                .with_category(DecodingErrorCategory::IllegalTypeDescriptor))
             }
         };
         Ok(length)
@@ -1093,11 +2031,17 @@ where
             Err(error) => return Err(error), // Something went wrong while reading the next byte.
         };
 
-        self.header_cache[next_byte as usize].clone()
+        // The header byte is now behind the cursor
+        self.header_cache[next_byte as usize]
+            .clone()
+            .map_err(|error| error.with_offset(self.cursor.bytes_read - 1))
     }
 
     fn next_byte(&mut self) -> IonResult<Option<u8>> {
-        let byte = self.data_source.next_byte();
+        let byte = self
+            .data_source
+            .next_byte()
+            .map_err(|error| error.with_offset(self.cursor.bytes_read));
         self.cursor.bytes_read += 1;
         byte
     }
@@ -1107,7 +2051,9 @@ where
             return Ok(());
         }
 
-        self.data_source.skip_bytes(number_of_bytes)?;
+        self.data_source
+            .skip_bytes(number_of_bytes)
+            .map_err(|error| error.with_offset(self.cursor.bytes_read))?;
         self.cursor.bytes_read += number_of_bytes;
         Ok(())
     }
@@ -1143,7 +2089,12 @@ where
         // The encoding allows us to skip over the annotations list and the value, but in practice
         // we won't know if we want to skip this value until we've read the type descriptor byte.
         // That means we need to read the length even though we have no intent to use it.
-        let _annotations_and_value_length = self.read_standard_length()?;
+        let annotations_and_value_length = self.read_standard_length()?;
+        self.check_declared_length(
+            "annotations wrapper",
+            annotations_and_value_length,
+            annotations_offset,
+        )?;
         let annotations_length = self.read_var_uint()?;
         let mut bytes_read: usize = 0;
         while bytes_read < annotations_length.value() {
@@ -1163,16 +2114,9 @@ where
     }
 
     fn read_exact(&mut self, number_of_bytes: usize) -> IonResult<()> {
-        // Grow the cursor's reusable Vec<u8> if needed, filling it with zeros
-        let buffer: &mut [u8] = if self.buffer.len() < number_of_bytes {
-            self.buffer.resize(number_of_bytes, 0);
-            self.buffer.as_mut()
-        } else {
-            // Otherwise, split the Vec's underlying storage to get a &mut [u8] slice of the
-            // required size.
-            let (required_buffer, _) = self.buffer.split_at_mut(number_of_bytes);
-            required_buffer
-        };
+        // Grow the cursor's reusable buffer if needed (filling it with zeros) and get a
+        // &mut [u8] slice of the required size.
+        let buffer: &mut [u8] = self.buffer.slice_of_len(number_of_bytes);
 
         // Ask the data source to populate our appropriately-sized slice.
         self.data_source.read_exact(buffer)?;
@@ -1180,14 +2124,42 @@ where
         Ok(())
     }
 
+    // Copies the body of the current value into the reader's scratch buffer, returning a slice
+    // of the buffer that holds it.
+    fn read_value_into_buffer(&mut self) -> IonResult<&[u8]> {
+        let number_of_bytes = self.cursor.value.value_length;
+        let offset = self.cursor.bytes_read;
+        self.read_exact(number_of_bytes)
+            .map_err(|error| error.with_offset(offset))?;
+        Ok(&self.buffer[..number_of_bytes])
+    }
+
+    // Passes the body of the current value to `f` in chunks of at most `chunk_size` bytes. Each
+    // chunk is read from the data source only after the previous one has been processed, so the
+    // scratch buffer never needs to hold more than `chunk_size` bytes.
+    fn read_value_in_chunks<F>(&mut self, chunk_size: usize, mut f: F) -> IonResult<()>
+    where
+        F: FnMut(&[u8]) -> IonResult<()>,
+    {
+        let mut bytes_remaining = self.cursor.value.value_length;
+        while bytes_remaining > 0 {
+            let chunk_length = bytes_remaining.min(chunk_size);
+            self.read_slice(chunk_length, &mut f)?;
+            bytes_remaining -= chunk_length;
+        }
+        Ok(())
+    }
+
     /// See IonDataSource#read_slice.
     fn read_slice<T, F>(&mut self, number_of_bytes: usize, slice_processor: F) -> IonResult<T>
     where
         F: FnOnce(&[u8]) -> IonResult<T>,
     {
+        let offset = self.cursor.bytes_read;
         self.cursor.bytes_read += number_of_bytes;
         self.data_source
             .read_slice(number_of_bytes, &mut self.buffer, slice_processor)
+            .map_err(|error| error.with_offset(offset))
     }
 }
 
@@ -1196,15 +2168,19 @@ mod tests {
     use std::io;
 
     use bigdecimal::BigDecimal;
-    use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use chrono::{Datelike, FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
 
+    use crate::allocator::Allocator;
     use crate::binary::constants::v1_0::IVM;
-    use crate::binary::raw_binary_reader::RawBinaryReader;
+    use crate::binary::raw_binary_reader::{RawBinaryReader, RawBinaryReaderBuilder};
     use crate::raw_reader::{RawReader, StreamItem, StreamItem::*};
     use crate::raw_symbol_token::local_sid_token;
-    use crate::result::{IonError, IonResult};
+    use crate::result::{illegal_operation, DecodingErrorCategory, IonError, IonResult};
     use crate::types::decimal::Decimal;
-    use crate::types::timestamp::Timestamp;
+    use crate::types::float::{Float, FloatKind};
+    use crate::types::integer::Integer;
+    use crate::types::timestamp::{Precision, Timestamp};
     use crate::types::IonType;
     use std::convert::TryInto;
 
@@ -1315,6 +2291,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_next_skips_container_without_decoding_children() -> IonResult<()> {
+        // A list whose contents use the reserved type code 0xF, followed by the integer 5
+        let data = &[0xB2, 0xF0, 0xF0, 0x21, 0x05];
+        let mut cursor = ion_cursor_for(data);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(5));
+
+        // Stepping into the list reveals that its children are invalid
+        let mut cursor = ion_cursor_for(data);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert!(cursor.next().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_integer() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x21, 0x05, // 5
+            0x38, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // i64::MIN
+            0x28, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // u64::MAX
+            0x39, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // -(2^64)
+            0x29, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, // 7, padded
+        ]);
+        let expected = vec![
+            Integer::I64(5),
+            Integer::I64(i64::MIN),
+            Integer::BigInt(BigInt::from(u64::MAX)),
+            Integer::BigInt(-(BigInt::from(u64::MAX) + BigInt::from(1))),
+            Integer::I64(7),
+        ];
+        for value in expected {
+            assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+            assert_eq!(cursor.read_integer()?, Some(value));
+        }
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_i64_out_of_range() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x28, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // i64::MAX + 1
+            0x29, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 2^64
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(matches!(
+            cursor.read_i64(),
+            Err(IonError::DecodingError { .. })
+        ));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(matches!(
+            cursor.read_i64(),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_read_f64_zero() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x40]);
@@ -1323,6 +2359,110 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_float_kind() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x48, 0x7F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Quiet NaN
+            0x48, 0x7F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, // Signaling NaN
+            0x48, 0xFF, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Negative quiet NaN
+            0x44, 0x7F, 0xC0, 0x00, 0x01, // 32-bit NaN with a payload
+            0x48, 0x7F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // +inf
+            0x44, 0xFF, 0x80, 0x00, 0x00, // -inf (32-bit)
+            0x48, 0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 1e0
+            0x4F, // null.float
+        ]);
+        for _ in 0..4 {
+            assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+            assert_eq!(cursor.read_float_kind()?, Some(FloatKind::Nan));
+        }
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_float_kind()?, Some(FloatKind::PositiveInfinity));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_float_kind()?, Some(FloatKind::NegativeInfinity));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_float_kind()?, Some(FloatKind::Finite));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, true)));
+        assert_eq!(cursor.read_float_kind()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_f64_special_values() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x48, 0x7F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte nan
+            0x48, 0x7F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte +inf
+            0x48, 0xFF, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte -inf
+            0x44, 0x7F, 0xC0, 0x00, 0x00, // 4-byte nan
+            0x44, 0x7F, 0x80, 0x00, 0x00, // 4-byte +inf
+            0x44, 0xFF, 0x80, 0x00, 0x00, // 4-byte -inf
+        ]);
+        for _ in 0..2 {
+            assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+            let nan = cursor.read_f64()?.unwrap();
+            // The canonical quiet NaN's bit pattern is preserved
+            assert_eq!(nan.to_bits(), 0x7FF8_0000_0000_0000);
+            assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+            assert_eq!(cursor.read_f64()?, Some(f64::INFINITY));
+            assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+            assert_eq!(cursor.read_f64()?, Some(f64::NEG_INFINITY));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_f32_special_values() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x48, 0x7F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte nan
+            0x48, 0x7F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte +inf
+            0x48, 0xFF, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte -inf
+            0x44, 0x7F, 0xC0, 0x00, 0x00, // 4-byte nan
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f32()?.unwrap().to_bits(), 0x7FC0_0000);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f32()?, Some(f32::INFINITY));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f32()?, Some(f32::NEG_INFINITY));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f32()?.unwrap().to_bits(), 0x7FC0_0000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_float_preserves_width() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x44, 0x3F, 0xC0, 0x00, 0x00, // 4-byte 1.5
+            0x48, 0x3F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte 1.5
+            0x40, // 0e0
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_float()?, Some(Float::F32(1.5)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_float()?, Some(Float::F64(1.5)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_float()?, Some(Float::F64(0.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_f32_exact() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x48, 0x3F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte 1.5
+            0x48, 0x3F, 0xB9, 0x99, 0x99, 0x99, 0x99, 0x99, 0x9A, // 8-byte 0.1
+            0x48, 0x7F, 0xF8, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 8-byte nan
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert_eq!(cursor.read_f32_exact()?, Some(1.5f32));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert!(matches!(
+            cursor.read_f32_exact(),
+            Err(IonError::DecodingError { .. })
+        ));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Float, false)));
+        assert!(cursor.read_f32_exact()?.unwrap().is_nan());
+        Ok(())
+    }
+
     #[test]
     fn test_read_decimal_zero() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x50]);
@@ -1412,6 +2552,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_datetime_invalid_fields() -> IonResult<()> {
+        #![allow(deprecated)] // `read_datetime` is deprecated
+        let mut cursor = ion_cursor_for(&[0x65, 0xC0, 0x0F, 0xE2, 0x8D, 0x81]); // 2018-13-01
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_datetime().is_err());
+        // An offset of 8191 minutes
+        let mut cursor = ion_cursor_for(&[0x64, 0x7F, 0xFF, 0x0F, 0xE2]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_datetime().is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_read_timestamp() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x68, 0x80, 0x0F, 0xD0, 0x81, 0x81, 0x80, 0x80, 0x80]);
@@ -1430,6 +2583,133 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_no_current_value_after_last_item() -> IonResult<()> {
+        // a::{$10: 1}
+        let mut cursor = ion_cursor_for(&[0xE6, 0x81, 0x8A, 0xD3, 0x8A, 0x21, 0x01]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        // At the end of the struct
+        assert_eq!(cursor.next()?, None);
+        assert_eq!(cursor.ion_type(), None);
+        assert_eq!(cursor.field_name(), None);
+        assert_eq!(cursor.read_i64()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.ion_type(), Some(IonType::Struct));
+        // At the end of the stream
+        assert_eq!(cursor.next()?, None);
+        assert_eq!(cursor.ion_type(), None);
+        assert!(cursor.annotations().is_empty());
+        assert!(cursor.step_in().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_in_expecting() -> IonResult<()> {
+        // 5 {$10: 1} [1]
+        let mut cursor = ion_cursor_for(&[0x21, 0x05, 0xD3, 0x8A, 0x21, 0x01, 0xB2, 0x21, 0x01]);
+        // Stepping into a scalar is an error rather than a panic, and leaves the reader in place.
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(matches!(
+            cursor.step_in(),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        assert!(matches!(
+            cursor.step_in_expecting(IonType::List),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        assert_eq!(cursor.depth(), 0);
+        assert_eq!(cursor.read_i64()?, Some(5));
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        assert_eq!(
+            cursor.step_in_expecting(IonType::List),
+            Err(IonError::UnexpectedType {
+                expected: IonType::List,
+                found: IonType::Struct
+            })
+        );
+        assert_eq!(cursor.depth(), 0);
+        cursor.step_in_expecting(IonType::Struct)?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.depth(), 1);
+        cursor.step_out()?;
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in_expecting(IonType::List)?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset() -> IonResult<()> {
+        // a::{$10: [1, 2]}
+        let mut cursor =
+            ion_cursor_for(&[0xE9, 0x81, 0x8A, 0xD6, 0x8A, 0xB4, 0x21, 0x01, 0x21, 0x02]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.depth(), 2);
+
+        // Abandon the first message partway through and start reading a second one
+        cursor.reset(data_source_for(&[0x21, 0x05]));
+        assert_eq!(cursor.depth(), 0);
+        assert_eq!(cursor.ion_type(), None);
+        assert_eq!(cursor.field_name(), None);
+        assert!(cursor.annotations().is_empty());
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert!(cursor.annotations().is_empty());
+        assert_eq!(cursor.read_i64()?, Some(5));
+        assert_eq!(cursor.raw_value_bytes(), Some(&[0x05][..]));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_year_with_offset() -> IonResult<()> {
+        // Offset: +60 minutes, year: 2021
+        let mut cursor = ion_cursor_for(&[0x63, 0xBC, 0x0F, 0xE5]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        let timestamp = cursor.read_timestamp()?.unwrap();
+        assert_eq!(timestamp.precision, Precision::Year);
+        assert_eq!(timestamp.offset, FixedOffset::east_opt(60 * 60));
+        assert_eq!(timestamp.date_time.year(), 2021);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timestamp_without_year() -> IonResult<()> {
+        // Offset only
+        let mut cursor = ion_cursor_for(&[0x61, 0x80]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_timestamp().is_err());
+        // Neither an offset nor a year
+        let mut cursor = ion_cursor_for(&[0x60, 0x21, 0x01]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_timestamp().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fields_extending_past_the_end_of_the_value() -> IonResult<()> {
+        // A decimal of length 1 whose exponent VarInt continues into the next byte
+        let mut cursor = ion_cursor_for(&[0x51, 0x01, 0x80]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Decimal, false)));
+        assert!(cursor.read_decimal().is_err());
+        // A timestamp of length 9 whose fractional second exponent does the same
+        let mut cursor = ion_cursor_for(&[
+            0x69, 0x80, 0x0F, 0xE2, 0x81, 0x81, 0x80, 0x80, 0x80, 0x43, 0xC3,
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Timestamp, false)));
+        assert!(cursor.read_timestamp().is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_read_timestamp_year_month() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x64, 0xC0, 0x0F, 0xE2, 0x86]);
@@ -1508,6 +2788,30 @@ mod tests {
         Ok(())
     }
 
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_read_string_from_mmap_without_copying() -> IonResult<()> {
+        use std::io::Write;
+        let path = std::env::temp_dir().join("ion_rs_test_read_string_from_mmap.10n");
+        let mut file = std::fs::File::create(&path)?;
+        file.write_all(&ion_data(&[0x83, 0x66, 0x6f, 0x6f]))?;
+        drop(file);
+
+        let mut cursor = RawBinaryReader::from_mmap(&path)?;
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        let mapped_range = cursor.data_source.get_ref().as_ptr_range();
+        let text_ptr = cursor.string_ref_map(|text| {
+            assert_eq!(text, "foo");
+            text.as_ptr()
+        })?;
+        // The &str handed to the closure should point into the mapped file, not into a copy.
+        assert!(mapped_range.contains(&text_ptr.unwrap()));
+        drop(cursor);
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
     #[test]
     fn test_read_string_foo_twice_fails() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x83, 0x66, 0x6f, 0x6f]);
@@ -1518,6 +2822,133 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_string_into_reuses_buffer() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x83, 0x66, 0x6f, 0x6f, // "foo"
+            0x82, 0x68, 0x69, // "hi"
+            0x21, 0x01, // Integer 1
+            0x8F, // null.string
+        ]);
+        let mut buffer = String::with_capacity(16);
+        buffer.push_str("stale");
+        let buffer_ptr = buffer.as_ptr();
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string_into(&mut buffer)?, Some(()));
+        assert_eq!(buffer, "foo");
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string_into(&mut buffer)?, Some(()));
+        assert_eq!(buffer, "hi");
+        // The existing allocation was reused
+        assert_eq!(buffer.as_ptr(), buffer_ptr);
+
+        // Values that aren't non-null strings leave the buffer untouched
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_string_into(&mut buffer)?, None);
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, true)));
+        assert_eq!(cursor.read_string_into(&mut buffer)?, None);
+        assert_eq!(buffer, "hi");
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_borrowed_values() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x83, 0x66, 0x6f, 0x6f, // "foo"
+            0xA2, 0x01, 0x02, // Blob {{AQI=}}
+            0x92, 0x68, 0x69, // Clob {{"hi"}}
+            0x8F, // null.string
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_blob_ref()?, None);
+        assert_eq!(cursor.read_str()?, Some("foo"));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        assert_eq!(cursor.read_blob_ref()?, Some(&[0x01, 0x02][..]));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+        assert_eq!(cursor.read_clob_ref()?, Some(&b"hi"[..]));
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, true)));
+        assert_eq!(cursor.read_str()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lobs_into_reused_buffers() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0xA3, 0x01, 0x02, 0x03, // Blob {{AQID}}
+            0x92, 0x68, 0x69, // Clob {{"hi"}}
+            0xAF, // null.blob
+        ]);
+        let mut buffer = Vec::with_capacity(16);
+        buffer.push(0xFF);
+        let buffer_ptr = buffer.as_ptr();
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        assert_eq!(cursor.read_clob_into(&mut buffer)?, None);
+        assert_eq!(cursor.read_blob_into(&mut buffer)?, Some(()));
+        assert_eq!(buffer, vec![0x01, 0x02, 0x03]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+        assert_eq!(cursor.read_clob_into(&mut buffer)?, Some(()));
+        assert_eq!(buffer, b"hi".to_vec());
+        assert_eq!(buffer.as_ptr(), buffer_ptr);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, true)));
+        assert_eq!(cursor.read_blob_into(&mut buffer)?, None);
+        assert_eq!(buffer, b"hi".to_vec());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_lob_chunks() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0xA5, 0x01, 0x02, 0x03, 0x04, 0x05, // Blob {{AQIDBAU=}}
+            0x92, 0x68, 0x69, // Clob {{"hi"}}
+            0xA0, // Empty blob
+            0x21, 0x07, // Integer 7
+        ]);
+        let mut chunks: Vec<Vec<u8>> = Vec::new();
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        assert!(matches!(
+            cursor.read_blob_chunks(0, |_| Ok(())),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        let result = cursor.read_blob_chunks(2, |chunk| {
+            chunks.push(chunk.to_vec());
+            Ok(())
+        })?;
+        assert_eq!(result, Some(()));
+        assert_eq!(chunks, vec![vec![0x01, 0x02], vec![0x03, 0x04], vec![0x05]]);
+
+        // A callback error stops the read, and the rest of the value is skipped by `next`
+        assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+        assert_eq!(cursor.read_blob_chunks(1, |_| Ok(()))?, None);
+        let result = cursor.read_clob_chunks(1, |_| illegal_operation("stop"));
+        assert!(matches!(result, Err(IonError::IllegalOperation { .. })));
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        let mut calls = 0;
+        let result = cursor.read_blob_chunks(4, |_| {
+            calls += 1;
+            Ok(())
+        })?;
+        assert_eq!((result, calls), (Some(()), 0));
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(7));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_string_into_invalid_utf8() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[0x82, 0xC3, 0x28]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        let mut buffer = String::new();
+        assert!(matches!(
+            cursor.read_string_into(&mut buffer),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
     #[test]
     fn test_read_clob_empty() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0x90]);
@@ -1537,6 +2968,66 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_blob_reader_streams_bytes() -> IonResult<()> {
+        use std::io::Read;
+        let blob: Vec<u8> = (0u8..20).collect();
+        let mut data = vec![0xAE, 0x94]; // 20-byte blob
+        data.extend_from_slice(&blob);
+        data.extend_from_slice(&[0x21, 0x07]); // Integer 7
+        let mut cursor = ion_cursor_for(&data);
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        let mut lob_reader = cursor.blob_reader()?.unwrap();
+        let mut chunk = [0u8; 3];
+        let mut bytes = Vec::new();
+        loop {
+            let bytes_read = lob_reader.read(&mut chunk)?;
+            if bytes_read == 0 {
+                break;
+            }
+            assert!(bytes_read <= chunk.len());
+            bytes.extend_from_slice(&chunk[..bytes_read]);
+        }
+        drop(lob_reader);
+        assert_eq!(bytes, blob);
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(7));
+        Ok(())
+    }
+
+    #[test]
+    fn test_blob_reader_dropped_early() -> IonResult<()> {
+        use std::io::Read;
+        let mut cursor = ion_cursor_for(&[
+            0xA6, // 6-byte blob
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, //
+            0x93, 0x61, 0x00, 0x63, // 3-byte clob
+            0x21, 0x07, // Integer 7
+        ]);
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+        let mut first_two = [0u8; 2];
+        cursor.blob_reader()?.unwrap().read_exact(&mut first_two)?;
+        assert_eq!(first_two, [0x01, 0x02]);
+        // A second reader resumes where the first left off.
+        let mut next_two = [0u8; 2];
+        cursor.blob_reader()?.unwrap().read_exact(&mut next_two)?;
+        assert_eq!(next_two, [0x03, 0x04]);
+
+        // The unread bytes are skipped by next().
+        assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+        assert!(cursor.blob_reader()?.is_none());
+        let mut clob = Vec::new();
+        cursor.clob_reader()?.unwrap().read_to_end(&mut clob)?;
+        assert_eq!(clob, vec![0x61, 0x00, 0x63]);
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(7));
+        Ok(())
+    }
+
     #[test]
     fn test_read_blob_empty() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[0xA0]);
@@ -1968,6 +3459,137 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_nop_pads_of_every_kind() -> IonResult<()> {
+        // A long run of 1-byte NOP pads, then [<NOP>, 1, <NOP with a VarUInt length>] and a final
+        // NOP pad at the end of the stream
+        let mut data = vec![0x00; 100_000];
+        data.extend_from_slice(&[
+            0xB8, // 8-byte list
+            0x01, 0xFF, // NOP code with 1 byte of padding
+            0x21, 0x01, // Integer 1
+            0x0E, 0x82, 0xFF, 0xFF, // NOP code, VarUInt length 2, 2 bytes of padding
+            0x03, 0xFF, 0xFF, 0xFF, // NOP code with 3 bytes of padding
+        ]);
+        let mut cursor = ion_cursor_for(&data);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_suppress_mid_stream_ivms() -> IonResult<()> {
+        // true <IVM> false
+        let bytes = &[0x11, 0xE0, 0x01, 0x00, 0xEA, 0x10];
+        let mut cursor = RawBinaryReaderBuilder::new().build(data_source_for(bytes));
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+
+        let mut cursor = RawBinaryReaderBuilder::new()
+            .suppress_mid_stream_ivms(true)
+            .build(data_source_for(bytes));
+        // The IVM at the beginning of the stream is still reported.
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.read_bool()?, Some(true));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.read_bool()?, Some(false));
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_structure_well_formed() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0xDA, // 10-byte struct
+            0x84, // Field ID 4 ('name')
+            0xB6, // 6-byte list
+            0x21, 0x01, // Integer 1
+            0x83, // 3-byte string: the body is not validated as UTF-8
+            0xFF, 0xFF, 0xFF, //
+            0x85, // Field ID 5
+            0x0F, // null.null
+            0x21, 0x02, // Integer 2
+        ]);
+        cursor.validate_structure()?;
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_structure_child_overruns_container() {
+        let mut cursor = ion_cursor_for(&[
+            0xB2, // 2-byte list
+            0x83, 0x66, 0x6f, 0x6f, // 3-byte string "foo"; doesn't fit in the list
+        ]);
+        match cursor.validate_structure() {
+            Err(error @ IonError::DecodingError { .. }) => {
+                assert!(error.offset().is_some(), "{}", error)
+            }
+            other => panic!("Expected a decoding error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_structure_premature_eof_in_container() {
+        let mut cursor = ion_cursor_for(&[
+            0xB4, // 4-byte list
+            0x21, 0x01, // Integer 1; the last two bytes of the list are missing
+        ]);
+        assert!(cursor.validate_structure().is_err());
+    }
+
+    #[test]
+    fn test_validate_structure_malformed_var_uint_length() {
+        let mut cursor = ion_cursor_for(&[
+            0x8E, // String with a VarUInt length that never terminates within 9 bytes
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x81,
+        ]);
+        assert!(matches!(
+            cursor.validate_structure(),
+            Err(IonError::DecodingError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_structure_illegal_type_descriptor() {
+        let mut cursor = ion_cursor_for(&[0x21, 0x01, 0xF0]);
+        assert!(cursor.validate_structure().is_err());
+    }
+
+    #[test]
+    fn test_error_offset_illegal_type_descriptor() -> IonResult<()> {
+        // The 4-byte IVM occupies offsets 0-3
+        let mut cursor = ion_cursor_for(&[0x21, 0x01, 0xF0]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        let error = cursor.next().unwrap_err();
+        assert_eq!(error.offset(), Some(6));
+        let message = error.to_string();
+        assert!(message.contains("type descriptor 0xF0"), "{}", message);
+        assert!(message.ends_with("at byte 6"), "{}", message);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_offset_truncated_value() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x8E, 0x90, // String with a VarUInt length of 16
+            0x68, 0x69, // "hi"; the remaining 14 bytes are missing
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        let error = cursor.read_string().unwrap_err();
+        // The string's body begins after the IVM and its 2-byte header
+        assert_eq!(error.offset(), Some(6));
+        Ok(())
+    }
+
     #[test]
     fn test_nop_pad_not_allowed_inside_annotation_wrapper() -> IonResult<()> {
         let mut cursor = ion_cursor_for(&[
@@ -2030,4 +3652,309 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_field_name_sid_and_annotation_sids() -> IonResult<()> {
+        #[rustfmt::skip]
+        let mut cursor = ion_cursor_for(&[
+            0xD7,                   // {
+            0x8A,                   //   $10:
+            0xE5, 0x82, 0x8B, 0x8C, //   $11::$12::
+            0x21, 0x05,             //   5
+                                    // }
+            0x20,                   // 0
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        assert_eq!(cursor.field_name_sid(), None);
+        assert_eq!(cursor.annotation_sids().count(), 0);
+
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.field_name_sid(), Some(10));
+        assert_eq!(cursor.annotation_sids().collect::<Vec<_>>(), vec![11, 12]);
+        cursor.step_out()?;
+
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.field_name_sid(), None);
+        assert_eq!(cursor.annotation_sids().next(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zero_copy_reads() -> IonResult<()> {
+        let data = ion_data(&[
+            0x83, 0x66, 0x6f, 0x6f, // "foo"
+            0xA2, 0x01, 0x02, // blob
+            0x91, 0x68, // clob
+            0x21, 0x01, // 1
+        ]);
+        let (text, blob, clob) = {
+            let mut cursor = RawBinaryReader::new(io::Cursor::new(&data[..]));
+            assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+            assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+            let text = cursor.read_str_ref()?.unwrap();
+            // the value has been consumed
+            assert!(cursor.read_str_ref().is_err());
+            assert_eq!(cursor.next()?, Some(Value(IonType::Blob, false)));
+            assert_eq!(cursor.read_clob_ref()?, None);
+            let blob = cursor.read_blob_ref()?.unwrap();
+            assert_eq!(cursor.next()?, Some(Value(IonType::Clob, false)));
+            let clob = cursor.read_clob_ref()?.unwrap();
+            assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+            assert_eq!(cursor.read_str_ref()?, None);
+            assert_eq!(cursor.read_i64()?, Some(1));
+            (text, blob, clob)
+        };
+        // the references borrow from `data` rather than from the reader
+        assert_eq!(text, "foo");
+        assert_eq!(blob, &[0x01, 0x02]);
+        assert_eq!(clob, b"h");
+        assert_eq!(text.as_ptr(), data[5..].as_ptr());
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoded_bytes() -> IonResult<()> {
+        #[rustfmt::skip]
+        let ion_data = &[
+            0xDA,                   // {
+            0x8B,                   //   $11:
+            0xE6, 0x81, 0x8C,       //   $12::
+            0xB3, 0x21, 0x01, 0x20, //   [1, 0],
+            0x8A,                   //   $10:
+            0x10,                   //   false
+                                    // }
+            0x11,                   // true
+        ];
+        let mut cursor = ion_cursor_for(ion_data);
+        assert_eq!(cursor.encoded_bytes()?, None);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        assert_eq!(cursor.encoded_bytes()?, Some(&ion_data[0..11]));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        // The field ID is omitted but the annotations wrapper is included
+        let encoded = cursor.encoded_bytes()?.unwrap().to_vec();
+        assert_eq!(encoded.as_slice(), &ion_data[2..9]);
+        // The annotations wrapper can be omitted as well
+        assert_eq!(cursor.raw_header_and_value_bytes(), Some(&ion_data[5..9]));
+        // Calling next() skips over the rest of the value
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.encoded_bytes()?, Some(&ion_data[10..11]));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Boolean, false)));
+        assert_eq!(cursor.read_bool()?, Some(true));
+
+        // The copied bytes can be read as a top-level value in a new stream
+        let mut cursor = ion_cursor_for(&encoded);
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.annotation_sids().collect::<Vec<_>>(), vec![12]);
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoded_bytes_truncated_value() -> IonResult<()> {
+        let mut cursor = ion_cursor_for(&[
+            0x8E, 0x90, // String with a VarUInt length of 16
+            0x68, 0x69, // "hi"; the remaining 14 bytes are missing
+        ]);
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert!(matches!(
+            cursor.encoded_bytes(),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_to_recorded_positions() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data = ion_data(&[
+            0x21, 0x01,             // 1
+            0xE4, 0x81, 0x8A,       // $10::
+            0xB1, 0x20,             // [0]
+            0x81, 0x61,             // "a"
+        ]);
+        // Any seekable data source can be used, not only in-memory ones
+        let mut cursor = RawBinaryReader::new(io::BufReader::new(io::Cursor::new(data)));
+        assert_eq!(cursor.position(), None);
+
+        // Index the top-level values
+        let mut positions = Vec::new();
+        while let Some(item) = cursor.next()? {
+            if let Value(ion_type, _) = item {
+                positions.push((ion_type, cursor.position().unwrap()));
+            }
+        }
+        let offsets: Vec<usize> = positions.iter().map(|(_, p)| p.offset()).collect();
+        assert_eq!(offsets, vec![4, 6, 11]);
+
+        cursor.seek_to(positions[2].1)?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some("a".to_string()));
+        assert_eq!(cursor.next()?, None);
+
+        // Seeking from inside a container returns the reader to the top level
+        cursor.seek_to(positions[1].1)?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.annotation_sids().collect::<Vec<_>>(), vec![10]);
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.position(), None);
+        cursor.seek_to(positions[0].1)?;
+        assert_eq!(cursor.depth(), 0);
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_to_indexed_offsets() -> IonResult<()> {
+        #[rustfmt::skip]
+        let data = ion_data(&[
+            0x21, 0x01,             // 1
+            0xE4, 0x81, 0x8A,       // $10::
+            0xB1, 0x20,             // [0]
+            0x81, 0x61,             // "a"
+        ]);
+        let mut cursor = RawBinaryReader::new(io::BufReader::new(io::Cursor::new(data)));
+        assert_eq!(cursor.encoded_range(), None);
+
+        // Record the offset and length of each top-level value
+        let mut ranges = Vec::new();
+        while let Some(item) = cursor.next()? {
+            if let Value(_, _) = item {
+                ranges.push(cursor.encoded_range().unwrap());
+            }
+        }
+        assert_eq!(ranges, vec![4..6, 6..11, 11..13]);
+
+        cursor.seek_to_offset(ranges[1].start)?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.annotation_sids().collect::<Vec<_>>(), vec![10]);
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        // Nested values have ranges too
+        assert_eq!(cursor.encoded_range(), Some(10..11));
+        cursor.seek_to_offset(ranges[2].start)?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_string()?, Some("a".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_encoding() -> IonResult<()> {
+        #[rustfmt::skip]
+        let mut cursor = ion_cursor_for(&[
+            0x21, 0x01,             // 1
+            0x2E, 0x81, 0x01,       // 1, with an unnecessary VarUInt length
+            0xE6, 0x81, 0x8A,       // $10::
+            0xD1, 0x82, 0x8B, 0x20, // {$11: 0} (sorted)
+        ]);
+        assert_eq!(cursor.value_encoding(), None);
+        cursor.next()?;
+        let encoding = cursor.value_encoding().unwrap();
+        assert_eq!(encoding.type_descriptor(), 0x21);
+        assert_eq!(encoding.declared_length(), 1);
+        assert!(!encoding.has_varuint_length());
+        assert!(!encoding.has_annotations_wrapper());
+        cursor.next()?;
+        let encoding = cursor.value_encoding().unwrap();
+        assert_eq!(encoding.type_descriptor(), 0x2E);
+        assert_eq!(encoding.declared_length(), 1);
+        assert_eq!(encoding.length_field_length(), 1);
+        cursor.next()?;
+        let encoding = cursor.value_encoding().unwrap();
+        assert_eq!(encoding.type_descriptor(), 0xD1);
+        assert_eq!(encoding.declared_length(), 2);
+        assert!(encoding.has_varuint_length());
+        assert_eq!(encoding.annotations_wrapper_length(), Some(3));
+        cursor.step_in()?;
+        cursor.next()?;
+        let encoding = cursor.value_encoding().unwrap();
+        assert_eq!(encoding.type_descriptor(), 0x20);
+        assert_eq!(encoding.declared_length(), 0);
+        assert_eq!(encoding.annotations_wrapper_length(), None);
+        Ok(())
+    }
+
+    #[rustfmt::skip]
+    const ANNOTATED_STRUCT: &[u8] = &[
+        0xEB, 0x81, 0x8A,       // $10::
+        0xD8,                   // {
+        0x8B, 0x82, 0x68, 0x69, //   $11: "hi",
+        0x8C, 0xB2, 0x21, 0x01, //   $12: [1]
+                                // }
+    ];
+
+    // Reads ANNOTATED_STRUCT, which must be the only value in the stream
+    fn read_annotated_struct<A: Allocator + Clone>(
+        cursor: &mut RawBinaryReader<TestDataSource, A>,
+    ) -> IonResult<()> {
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::Struct, false)));
+        assert_eq!(cursor.annotation_sids().collect::<Vec<_>>(), vec![10]);
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.field_name_sid(), Some(11));
+        assert_eq!(cursor.read_string()?, Some("hi".to_string()));
+        assert_eq!(cursor.next()?, Some(Value(IonType::List, false)));
+        assert_eq!(cursor.field_name_sid(), Some(12));
+        cursor.step_in()?;
+        assert_eq!(cursor.next()?, Some(Value(IonType::Integer, false)));
+        assert_eq!(cursor.read_i64()?, Some(1));
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        cursor.step_out()?;
+        assert_eq!(cursor.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_default_allocator() -> IonResult<()> {
+        let mut cursor = RawBinaryReaderBuilder::new().build(data_source_for(ANNOTATED_STRUCT));
+        read_annotated_struct(&mut cursor)
+    }
+
+    #[cfg(feature = "allocator")]
+    #[test]
+    fn test_builder_custom_allocator() -> IonResult<()> {
+        let bump = bumpalo::Bump::new();
+        let mut cursor = RawBinaryReaderBuilder::new()
+            .allocator(&bump)
+            .build(data_source_for(ANNOTATED_STRUCT));
+        // The reader's 4KB scratch buffer is allocated from the bump allocator up front
+        assert!(bump.allocated_bytes() >= 4096);
+        read_annotated_struct(&mut cursor)
+    }
+
+    #[test]
+    fn test_builder_max_value_length() -> IonResult<()> {
+        // "abc" "abcde"
+        let mut cursor = RawBinaryReaderBuilder::new()
+            .max_value_length(4)
+            .build(data_source_for(&[
+                0x83, b'a', b'b', b'c', 0x85, b'a', b'b', b'c', b'd', b'e',
+            ]));
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        assert_eq!(cursor.next()?, Some(Value(IonType::String, false)));
+        assert_eq!(cursor.read_str()?, Some("abc"));
+        let error = cursor.next().unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::LimitExceeded));
+        assert_eq!(error.offset(), Some(8));
+
+        // $4::"abc", whose annotations wrapper declares a length of 6 bytes
+        let mut cursor = RawBinaryReaderBuilder::new()
+            .max_value_length(4)
+            .build(data_source_for(&[0xE6, 0x81, 0x84, 0x83, b'a', b'b', b'c']));
+        assert_eq!(cursor.next()?, Some(VersionMarker(1, 0)));
+        let error = cursor.next().unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::LimitExceeded));
+        Ok(())
+    }
 }
@@ -111,9 +111,23 @@ where
         let mut bytes_written: usize = 0;
 
         // First encode the timestamp. We need to know the encoded length before
-        // we can compute and write out the type descriptor.
-        let mut encoded: ArrayVec<u8, MAX_TIMESTAMP_LENGTH> = ArrayVec::new();
-        encoded.encode_timestamp(timestamp)?;
+        // we can compute and write out the type descriptor. Fractional seconds that are more
+        // precise than nanoseconds can have a coefficient of any size, so those timestamps are
+        // encoded on the heap.
+        let mut encoded_on_heap: Vec<u8>;
+        let mut encoded_on_stack: ArrayVec<u8, MAX_TIMESTAMP_LENGTH>;
+        let encoded: &[u8] = match timestamp.fractional_seconds {
+            Some(Mantissa::Arbitrary(_)) => {
+                encoded_on_heap = Vec::with_capacity(MAX_TIMESTAMP_LENGTH);
+                encoded_on_heap.encode_timestamp(timestamp)?;
+                &encoded_on_heap
+            }
+            _ => {
+                encoded_on_stack = ArrayVec::new();
+                encoded_on_stack.encode_timestamp(timestamp)?;
+                &encoded_on_stack
+            }
+        };
 
         // Write the type descriptor and length.
         let type_descriptor: u8;
@@ -129,8 +143,8 @@ where
         }
 
         // Now we can write out the encoded timestamp!
-        self.write(&encoded[..])?;
-        bytes_written += &encoded[..].len();
+        self.write_all(encoded)?;
+        bytes_written += encoded.len();
 
         Ok(bytes_written)
     }
@@ -139,8 +153,12 @@ where
 #[cfg(test)]
 mod binary_timestamp_tests {
     use super::*;
+    use crate::binary::constants::v1_0::IVM;
+    use crate::value::owned::OwnedElement;
+    use crate::value::Element;
     use chrono::DateTime;
     use rstest::*;
+    use std::str::FromStr;
 
     // These tests show how varying levels of precision affects number of bytes
     // written (for binary encoding of timestamps).
@@ -163,4 +181,30 @@ mod binary_timestamp_tests {
         assert_eq!(written, expected);
         Ok(())
     }
+
+    #[rstest]
+    #[case::picos("2021-01-08T14:12:36.888888888888-05:00", "0.888888888888")]
+    #[case::leading_zeros("2021-01-08T14:12:36.000000000001Z", "0.000000000001")]
+    #[case::beyond_i64(
+        "2021-01-08T14:12:36.123456789012345678901234567890Z",
+        "0.123456789012345678901234567890"
+    )]
+    fn timestamp_with_arbitrary_precision_round_trips(
+        #[case] text: &str,
+        #[case] expected_fractional_seconds: &str,
+    ) -> IonResult<()> {
+        let expected = OwnedElement::read_all(text.as_bytes())?.pop().unwrap();
+        let mut binary = IVM.to_vec();
+        binary.encode_timestamp_value(expected.as_timestamp().unwrap())?;
+        let actual = OwnedElement::read_all(&binary)?.pop().unwrap();
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual
+                .as_timestamp()
+                .unwrap()
+                .fractional_seconds_as_decimal(),
+            Some(Decimal::from_str(expected_fractional_seconds)?)
+        );
+        Ok(())
+    }
 }
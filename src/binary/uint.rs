@@ -1,5 +1,6 @@
-use std::io::Write;
-use std::mem;
+use crate::io::Write;
+use alloc::format;
+use core::mem;
 
 use crate::data_source::IonDataSource;
 use crate::result::{decoding_error, IonResult};
@@ -26,24 +27,34 @@ impl DecodedUInt {
             ));
         }
 
+        // If the data source's input buffer holds the entire UInt, decode it in place.
+        let buffer = data_source.fill_buf()?;
+        if buffer.len() >= length {
+            let uint = Self::decode(&buffer[..length]);
+            data_source.consume(length);
+            return Ok(uint);
+        }
+
         // Create a stack-allocated buffer to hold the data we're going to read in.
         let mut buffer = [0u8; MAX_UINT_SIZE_IN_BYTES];
         // Get a mutable reference to a portion of the buffer just big enough to fit
         // the requested number of bytes.
         let buffer = &mut buffer[0..length];
 
-        let mut magnitude: UIntStorage = 0;
         data_source.read_exact(buffer)?;
-        for &byte in buffer.iter() {
-            let byte = u64::from(byte);
-            magnitude <<= 8;
-            magnitude |= byte;
-        }
+        Ok(Self::decode(buffer))
+    }
 
-        Ok(DecodedUInt {
-            size_in_bytes: length,
-            value: magnitude,
-        })
+    // Decodes a UInt from a slice of at most MAX_UINT_SIZE_IN_BYTES bytes by loading the bytes as
+    // a single big-endian word.
+    #[inline]
+    fn decode(bytes: &[u8]) -> DecodedUInt {
+        let mut word = [0u8; MAX_UINT_SIZE_IN_BYTES];
+        word[MAX_UINT_SIZE_IN_BYTES - bytes.len()..].copy_from_slice(bytes);
+        DecodedUInt {
+            size_in_bytes: bytes.len(),
+            value: UIntStorage::from_be_bytes(word),
+        }
     }
 
     /// Encodes the provided `magnitude` as a UInt and writes it to the provided `sink`.
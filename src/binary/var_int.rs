@@ -1,7 +1,9 @@
+use crate::binary::var_uint::encoded_length;
 use crate::data_source::IonDataSource;
+use crate::io::Write;
 use crate::result::{decoding_error, IonResult};
-use std::io::Write;
-use std::mem;
+use alloc::format;
+use core::mem;
 
 // ion_rust does not currently support reading variable length integers of truly arbitrary size.
 // These type aliases will simplify the process of changing the data types used to represent each
@@ -44,6 +46,33 @@ impl VarInt {
         // bit #6 (0-indexed, from the right) indicates whether the value is positive (0) or
         // negative (1).
 
+        // If the data source's input buffer holds the entire VarInt, decode it in place.
+        let buffer = data_source.fill_buf()?;
+        if let Some(encoded_size_in_bytes) = encoded_length(buffer) {
+            let first_byte = buffer[0];
+            let is_positive: bool = (first_byte & 0b0100_0000) == 0;
+            let sign: VarIntStorage = if is_positive { 1 } else { -1 };
+            let magnitude = buffer[1..encoded_size_in_bytes].iter().fold(
+                (first_byte & LOWER_6_BITMASK) as VarIntStorage,
+                |magnitude, byte| {
+                    (magnitude << BITS_PER_ENCODED_BYTE) | (LOWER_7_BITMASK & byte) as VarIntStorage
+                },
+            );
+            data_source.consume(encoded_size_in_bytes);
+            return Ok(VarInt {
+                size_in_bytes: encoded_size_in_bytes,
+                value: magnitude * sign,
+                is_negative: !is_positive,
+            });
+        }
+        if buffer.len() >= MAX_ENCODED_SIZE_IN_BYTES {
+            return decoding_error(format!(
+                "Found a VarInt longer than the max supported size of {} bytes.",
+                MAX_ENCODED_SIZE_IN_BYTES
+            ));
+        }
+
+        // Otherwise, the VarInt spans the end of the input buffer; read it a byte at a time.
         let first_byte: u8 = data_source.next_byte()?.unwrap();
         let no_more_bytes: bool = first_byte >= 0b1000_0000; // If the first bit is 1, we're done.
         let is_positive: bool = (first_byte & 0b0100_0000) == 0;
@@ -109,7 +138,7 @@ impl VarInt {
         // Saturating subtraction will return 0 instead of underflowing.
         let remaining_bits = occupied_bits.saturating_sub(MAGNITUDE_BITS_IN_FINAL_BYTE);
         // We can encode 7 bits of magnitude in every other byte.
-        bytes_required += f64::ceil(remaining_bits as f64 / 7.0) as usize;
+        bytes_required += remaining_bits.div_ceil(7);
 
         // TODO: The above calculation could be cached for each number of occupied_bits from 0 to 64
 
@@ -1,7 +1,8 @@
 use crate::data_source::IonDataSource;
+use crate::io::Write;
 use crate::result::{decoding_error, IonResult};
-use std::io::Write;
-use std::mem;
+use alloc::format;
+use core::mem;
 
 // ion_rust does not currently support reading variable length integers of truly arbitrary size.
 // These type aliases will simplify the process of changing the data types used to represent each
@@ -12,7 +13,7 @@ pub type VarUIntSizeStorage = usize;
 
 const BITS_PER_ENCODED_BYTE: usize = 7;
 const STORAGE_SIZE_IN_BITS: usize = mem::size_of::<VarUIntStorage>() * 8;
-const MAX_ENCODED_SIZE_IN_BYTES: usize = STORAGE_SIZE_IN_BITS / BITS_PER_ENCODED_BYTE;
+pub(crate) const MAX_ENCODED_SIZE_IN_BYTES: usize = STORAGE_SIZE_IN_BITS / BITS_PER_ENCODED_BYTE;
 
 const LOWER_7_BITMASK: u8 = 0b0111_1111;
 const HIGHEST_BIT_VALUE: u8 = 0b1000_0000;
@@ -29,6 +30,30 @@ pub struct VarUInt {
 impl VarUInt {
     /// Reads a VarUInt from the provided data source.
     pub fn read<R: IonDataSource>(data_source: &mut R) -> IonResult<VarUInt> {
+        // If the data source's input buffer holds the entire VarUInt, decode it in place.
+        let buffer = data_source.fill_buf()?;
+        if let Some(encoded_size_in_bytes) = encoded_length(buffer) {
+            let magnitude = buffer[..encoded_size_in_bytes].iter().fold(
+                0,
+                |magnitude: VarUIntStorage, byte| {
+                    (magnitude << BITS_PER_ENCODED_BYTE)
+                        | (LOWER_7_BITMASK & byte) as VarUIntStorage
+                },
+            );
+            data_source.consume(encoded_size_in_bytes);
+            return Ok(VarUInt {
+                size_in_bytes: encoded_size_in_bytes,
+                value: magnitude,
+            });
+        }
+        if buffer.len() >= MAX_ENCODED_SIZE_IN_BYTES {
+            return decoding_error(format!(
+                "Found a VarUInt longer than the max supported size of {} bytes.",
+                MAX_ENCODED_SIZE_IN_BYTES
+            ));
+        }
+
+        // Otherwise, the VarUInt spans the end of the input buffer; read it a byte at a time.
         let mut magnitude: VarUIntStorage = 0;
 
         let mut byte_processor = |byte: u8| {
@@ -102,6 +127,12 @@ impl VarUInt {
         Ok(encoded_bytes.len())
     }
 
+    /// Returns the number of bytes needed to encode the given unsigned int value as a VarUInt.
+    pub(crate) fn encoded_size(magnitude: u64) -> usize {
+        let bits_used = 64 - magnitude.leading_zeros() as usize;
+        bits_used.div_ceil(BITS_PER_ENCODED_BYTE).max(1)
+    }
+
     /// Returns the magnitude of the unsigned integer
     #[inline(always)]
     pub fn value(&self) -> VarUIntStorage {
@@ -116,9 +147,33 @@ impl VarUInt {
     }
 }
 
+/// Returns the number of bytes in the VarUInt or VarInt at the head of `bytes` if its final byte
+/// (the first one with its high bit set) is among the first [MAX_ENCODED_SIZE_IN_BYTES] bytes.
+/// Otherwise, returns `None`.
+///
+/// When at least 8 bytes are available, their end flags are tested all at once by loading them
+/// as a single word.
+#[inline]
+pub(crate) fn encoded_length(bytes: &[u8]) -> Option<usize> {
+    const END_FLAGS: u64 = 0x8080_8080_8080_8080;
+    if bytes.len() >= mem::size_of::<u64>() {
+        let mut word = [0u8; mem::size_of::<u64>()];
+        word.copy_from_slice(&bytes[..mem::size_of::<u64>()]);
+        let end_flags = u64::from_be_bytes(word) & END_FLAGS;
+        if end_flags != 0 {
+            return Some(end_flags.leading_zeros() as usize / 8 + 1);
+        }
+    }
+    bytes
+        .iter()
+        .take(MAX_ENCODED_SIZE_IN_BYTES)
+        .position(|byte| *byte >= HIGHEST_BIT_VALUE)
+        .map(|index| index + 1)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::VarUInt;
+    use super::{encoded_length, VarUInt};
     use crate::result::IonResult;
     use std::io::{BufReader, Cursor};
 
@@ -144,6 +199,36 @@ mod tests {
         assert_eq!(var_uint.value(), 1_984_385);
     }
 
+    #[test]
+    fn test_read_var_uint_followed_by_other_data() {
+        // The VarUInt's end flag is found by inspecting a word of buffered data at once.
+        let data = &[0x01, 0x81, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF];
+        let mut cursor = Cursor::new(data);
+        let var_uint = VarUInt::read(&mut cursor).expect(ERROR_MESSAGE);
+        assert_eq!(var_uint.size_in_bytes(), 2);
+        assert_eq!(var_uint.value(), 129);
+        assert_eq!(cursor.position(), 2);
+    }
+
+    #[test]
+    fn test_encoded_length() {
+        assert_eq!(encoded_length(&[]), None);
+        assert_eq!(encoded_length(&[0b1000_0000]), Some(1));
+        assert_eq!(encoded_length(&[0b0111_1111, 0b0111_1111]), None);
+        assert_eq!(
+            encoded_length(&[0, 0, 0, 0, 0, 0, 0, 0b1000_0000, 0]),
+            Some(8)
+        );
+        assert_eq!(
+            encoded_length(&[0, 0, 0, 0, 0, 0, 0, 0, 0b1000_0000]),
+            Some(9)
+        );
+        assert_eq!(
+            encoded_length(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 0b1000_0000]),
+            None
+        );
+    }
+
     #[test]
     fn test_read_var_uint_zero() {
         let var_uint = VarUInt::read(&mut Cursor::new(&[0b1000_0000])).expect(ERROR_MESSAGE);
@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::io::Write;
 use std::ops::Range;
 use std::{io, mem};
@@ -5,15 +6,26 @@ use std::{io, mem};
 use bigdecimal::BigDecimal;
 use bytes::BufMut;
 use chrono::{DateTime, FixedOffset};
+use delegate::delegate;
 
+use crate::allocator::Allocator;
 use crate::binary::constants::v1_0::IVM;
+use crate::binary::raw_binary_reader::RawBinaryReader;
+use crate::binary::type_code::IonTypeCode;
 use crate::binary::uint::DecodedUInt;
-use crate::binary::var_uint::VarUInt;
+use crate::binary::var_uint::{VarUInt, MAX_ENCODED_SIZE_IN_BYTES};
+use crate::constants::v1_0::{self, system_symbol_ids};
+use crate::raw_symbol_token::RawSymbolToken;
 use crate::result::{illegal_operation, IonResult};
+use crate::symbol::Symbol;
+use crate::symbol_table::SymbolTable;
+use crate::transcode::transcode_value;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
-use crate::types::SymbolId;
-use crate::IonType;
+use crate::types::{SymbolId, NUM_ION_TYPES};
+use crate::value::writer::write_element_to_binary;
+use crate::value::Element;
+use crate::{IonType, Reader};
 
 use super::decimal::DecimalBinaryEncoder;
 use super::timestamp::TimestampBinaryEncoder;
@@ -58,6 +70,12 @@ type IoRange = Range<usize>;
 // and written to the io::Write sink.
 //
 //     e0 01 00 ea eb 81 81 b8 83 66 6f 6f 83 62 61 72
+//
+// Each value is encoded into the buffer exactly once, no matter how deeply it is nested; stepping
+// out of a container only encodes its header (and annotations wrapper) and patches the IoRanges
+// that were reserved for them when the writer stepped in. Because the body of a container is
+// always the bytes encoded between step_in() and step_out(), its length is known without visiting
+// its children again. This keeps the cost of writing a stream linear in its encoded size.
 
 // Represents a level into which the writer has stepped.
 // A writer that has not yet called step_in() is at the top level.
@@ -83,6 +101,11 @@ struct EncodingLevel {
     // Index of the IoRange for this container's type descriptor and length. When the writer
     // steps out of this level, the type descriptor IoRange will be retrieved and populated.
     td_io_range_index: usize,
+    // The length of the buffer when the writer stepped into this level. Every byte encoded while
+    // the writer is in this level (including the headers and annotations of nested containers)
+    // belongs to this container's body, so its length can be calculated without revisiting its
+    // IoRanges.
+    buffer_start: usize,
 }
 
 impl EncodingLevel {
@@ -91,28 +114,126 @@ impl EncodingLevel {
         field_id: Option<SymbolId>,
         num_annotations: u8,
         td_io_range_index: usize,
+        buffer_start: usize,
     ) -> EncodingLevel {
         EncodingLevel {
             container_type,
             field_id,
             num_annotations,
             td_io_range_index,
+            buffer_start,
         }
     }
 
-    // Visits all of the IoRanges belonging to this EncodingLevel and notes their total length.
-    // This length will be written out as a length prefix for the container.
-    fn calculate_final_size(&self, io_ranges: &mut [Range<usize>]) -> usize {
-        io_ranges[self.td_io_range_index..]
-            .iter()
-            .map(|r| r.len())
-            .sum()
+    // Returns the number of bytes that have been encoded since the writer stepped into this
+    // level. This length will be written out as a length prefix for the container.
+    fn calculate_final_size(&self, buffer: &[u8]) -> usize {
+        buffer.len() - self.buffer_start
+    }
+}
+
+/// Statistics about the data that a [BinarySystemWriter] has encoded, which can help when tuning
+/// a schema for binary size (for example, to decide whether a value should be a symbol or a
+/// string). Obtained from [BinarySystemWriter::stats].
+///
+/// Values, field IDs and annotations are counted when they are written; symbol tables are counted
+/// when [flush](BinarySystemWriter::flush) writes them out. The statistics accumulate over the
+/// writer's lifetime, including across calls to [reset](BinarySystemWriter::reset).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WriterStats {
+    symbols_interned: usize,
+    symbol_references: usize,
+    symbol_table_bytes: usize,
+    field_id_bytes: usize,
+    annotation_bytes: usize,
+    // Indexed by IonType discriminant
+    values: [ValueStats; NUM_ION_TYPES],
+}
+
+/// The number of values of a single Ion type that a writer has encoded, and their total size. See
+/// [WriterStats::values].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ValueStats {
+    /// The number of values written, including nulls of this type.
+    pub count: usize,
+    /// The total encoded size of the values in bytes, including their type descriptors but not
+    /// their field IDs or annotations. The size of a container includes the values inside it.
+    pub encoded_bytes: usize,
+}
+
+impl WriterStats {
+    /// Returns the number of distinct symbols that have been added to the local symbol table.
+    pub fn symbols_interned(&self) -> usize {
+        self.symbols_interned
+    }
+
+    /// Returns the number of times symbol text has been interned, including text that was already
+    /// in the symbol table. Comparing this with [symbols_interned](Self::symbols_interned) shows
+    /// how often each symbol is reused.
+    pub fn symbol_references(&self) -> usize {
+        self.symbol_references
+    }
+
+    /// Returns the number of bytes taken up by the local symbol tables that have been written.
+    pub fn symbol_table_bytes(&self) -> usize {
+        self.symbol_table_bytes
+    }
+
+    /// Returns the number of bytes taken up by the field IDs of struct fields.
+    pub fn field_id_bytes(&self) -> usize {
+        self.field_id_bytes
+    }
+
+    /// Returns the number of bytes taken up by annotation wrappers, not including the values they
+    /// wrap.
+    pub fn annotation_bytes(&self) -> usize {
+        self.annotation_bytes
+    }
+
+    /// Returns the number of values of type `ion_type` that have been written and their size.
+    pub fn values(&self, ion_type: IonType) -> ValueStats {
+        self.values[ion_type as usize]
+    }
+
+    /// Returns the number of values of any type that have been written.
+    pub fn value_count(&self) -> usize {
+        self.values.iter().map(|values| values.count).sum()
     }
+
+    fn record_value(&mut self, ion_type: IonType, encoded_bytes: usize) {
+        let values = &mut self.values[ion_type as usize];
+        values.count += 1;
+        values.encoded_bytes += encoded_bytes;
+    }
+}
+
+/// The order in which [write_element](BinarySystemWriter::write_element) writes the fields of a
+/// struct. See [set_struct_field_order](BinarySystemWriter::set_struct_field_order).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum StructFieldOrder {
+    /// Fields are written in the order in which they were added to the struct (or, for a struct
+    /// that was read from a stream, the order in which they were encountered).
+    #[default]
+    Insertion,
+    /// Fields are sorted by their text. Fields with the same text keep their relative order, and
+    /// fields without text are written last.
+    Text,
+    /// Fields are sorted by the symbol ID that they are written with. Fields with the same symbol
+    /// ID keep their relative order. Field names are interned before any of the struct's values,
+    /// so a struct's new field names are assigned symbol IDs in insertion order.
+    SymbolId,
 }
 
-/// A system-level streaming binary Ion writer. This writer does not provide symbol table
-/// management; symbol-related operations (e.g. setting field IDs and annotations or writing symbol
-/// values) require a valid symbol ID to be provided by the caller.
+/// A system-level streaming binary Ion writer. Symbol-related operations (e.g. setting field IDs
+/// and annotations or writing symbol values) require a valid symbol ID to be provided by the
+/// caller.
+///
+/// Alternatively, symbol text can be passed to [intern](Self::intern) (or to methods like
+/// [set_field_name](Self::set_field_name) that call it) to obtain a symbol ID. The writer will
+/// add each newly interned symbol to a local symbol table and write that table out ahead of the
+/// values that use it the next time [flush](Self::flush) is called. Symbol IDs that the caller
+/// obtained some other way should not be mixed with interned ones. [BinaryWriter] wraps this
+/// writer to accept only symbol text, which rules that out.
 #[derive(Debug)]
 pub struct BinarySystemWriter<W: Write> {
     // Tracks whether the writer has already written an IVM out in this stream.
@@ -136,6 +257,16 @@ pub struct BinarySystemWriter<W: Write> {
     // Scratch space for the flush() method to rearrange the contents of `buffer` before writing
     // the data to `out`.
     contiguous_encoding: Vec<u8>,
+    // Whether NaN floats should be written using a single canonical bit pattern.
+    normalize_nan: bool,
+    // The order in which write_element() writes struct fields.
+    struct_field_order: StructFieldOrder,
+    // The system symbols followed by any symbols that have been passed to `intern()`.
+    symbol_table: SymbolTable,
+    // The number of symbols in `symbol_table` that have already been written out in a local
+    // symbol table. Symbols at or beyond this index will be written in the next flush().
+    num_flushed_symbols: usize,
+    stats: WriterStats,
 }
 
 // The largest possible 'L' (length) value that can be written directly in a type descriptor byte.
@@ -153,29 +284,81 @@ const INITIAL_ENCODING_LEVELS_CAPACITY: usize = 16;
 const INITIAL_IO_RANGE_CAPACITY: usize = 128;
 const INITIAL_ANNOTATIONS_CAPACITY: usize = 4;
 
+// The quiet NaN bit patterns written in place of any NaN when NaN normalization is enabled.
+const CANONICAL_NAN_F32_BITS: u32 = 0x7FC0_0000;
+const CANONICAL_NAN_F64_BITS: u64 = 0x7FF8_0000_0000_0000;
+
 impl<W: Write> BinarySystemWriter<W> {
     /// Creates a new BinarySystemWriter that will write its encoded output to the provided
     /// io::Write sink.
     pub fn new(out: W) -> BinarySystemWriter<W> {
+        BinarySystemWriter::with_capacity(out, INITIAL_ENCODING_BUFFER_CAPACITY)
+    }
+
+    /// Like [new](Self::new), but the writer's internal buffers are sized to hold `capacity`
+    /// bytes of encoded values before they need to grow. Values are buffered until
+    /// [flush](Self::flush) is called, so a writer that is sized using
+    /// [encoded_size_hint](crate::value::writer::encoded_size_hint) can encode a large document
+    /// without reallocating.
+    pub fn with_capacity(out: W, capacity: usize) -> BinarySystemWriter<W> {
         let mut levels = Vec::with_capacity(INITIAL_ENCODING_LEVELS_CAPACITY);
         // Create an EncodingLevel to represent the top level. It has no annotations.
-        levels.push(EncodingLevel::new(ContainerType::TopLevel, None, 0, 0));
+        levels.push(EncodingLevel::new(ContainerType::TopLevel, None, 0, 0, 0));
         // Create an empty IoRange for top-level leading scalar values.
         let mut io_ranges = Vec::with_capacity(INITIAL_IO_RANGE_CAPACITY);
         io_ranges.push(0usize..0);
         BinarySystemWriter {
             ivm_needed: true,
-            buffer: Vec::with_capacity(INITIAL_ENCODING_BUFFER_CAPACITY),
+            buffer: Vec::with_capacity(capacity),
             io_ranges,
             levels,
             out,
             annotations_all_levels: Vec::with_capacity(INITIAL_ANNOTATIONS_CAPACITY),
             num_annotations_current_value: 0,
             field_id: None,
-            contiguous_encoding: Vec::with_capacity(INITIAL_ENCODING_BUFFER_CAPACITY),
+            contiguous_encoding: Vec::with_capacity(capacity),
+            normalize_nan: false,
+            struct_field_order: StructFieldOrder::default(),
+            symbol_table: SymbolTable::new(),
+            num_flushed_symbols: v1_0::SYSTEM_SYMBOLS.len(),
+            stats: WriterStats::default(),
         }
     }
 
+    /// Reserves space in the writer's internal buffers for at least `additional` more bytes of
+    /// encoded values than are currently buffered. See [with_capacity](Self::with_capacity).
+    pub fn reserve(&mut self, additional: usize) {
+        self.buffer.reserve(additional);
+        // flush() copies everything in `buffer` into `contiguous_encoding`, which is otherwise
+        // empty.
+        self.contiguous_encoding
+            .reserve(self.buffer.len() + additional);
+    }
+
+    /// Configures whether NaN floats should be normalized when written. Different producers
+    /// encode NaN using different bit patterns; when normalization is enabled, every NaN is
+    /// written as the canonical quiet NaN (`0x7FC00000` for 32-bit floats and
+    /// `0x7FF8000000000000` for 64-bit floats) so that the writer's output is deterministic.
+    /// Normalization is disabled by default, which preserves each NaN's bit pattern.
+    pub fn normalize_nan(&mut self, normalize: bool) {
+        self.normalize_nan = normalize;
+    }
+
+    /// Configures the order in which [write_element](Self::write_element) writes the fields of
+    /// structs. By default, fields are written in insertion order; canonicalization and hashing
+    /// schemes that require a deterministic field order can use [StructFieldOrder::Text] or
+    /// [StructFieldOrder::SymbolId] instead. Structs written field by field using
+    /// [step_in](Self::step_in) are always written in the order that the fields are provided.
+    pub fn set_struct_field_order(&mut self, order: StructFieldOrder) {
+        self.struct_field_order = order;
+    }
+
+    /// Returns the order in which [write_element](Self::write_element) writes the fields of
+    /// structs.
+    pub fn struct_field_order(&self) -> StructFieldOrder {
+        self.struct_field_order
+    }
+
     // Uses the provided closure to encode data to the buffer. Returns the range of the buffer
     // now occupied by the encoded bytes.
     #[inline]
@@ -221,6 +404,7 @@ impl<W: Write> BinarySystemWriter<W> {
             let field_id = self.expect_field_id()? as u64;
             let bytes_written = VarUInt::write_u64(&mut self.buffer, field_id)?;
             self.extend_last_range(bytes_written);
+            self.stats.field_id_bytes += bytes_written;
             self.field_id = None;
         }
 
@@ -229,11 +413,22 @@ impl<W: Write> BinarySystemWriter<W> {
         }
 
         let encoded_range = self.encode_to_buffer(|writer| write_fn(&mut writer.buffer))?;
+        self.record_scalar(&encoded_range);
         self.extend_last_range(encoded_range.len());
 
         Ok(())
     }
 
+    // Adds an encoded scalar to the writer's statistics, determining its type from its type
+    // descriptor.
+    fn record_scalar(&mut self, encoded_range: &IoRange) {
+        let type_code = IonTypeCode::try_from(self.buffer[encoded_range.start] >> 4)
+            .expect("a type code is only four bits long");
+        let ion_type =
+            IonType::try_from(type_code).expect("the writer only encodes valid type descriptors");
+        self.stats.record_value(ion_type, encoded_range.len());
+    }
+
     // Uses the provided closure to encode a scalar value, then encodes the annotation wrapper
     // based on the encoded value's length and the configured annotations sequence.
     fn encode_annotated_scalar(
@@ -243,6 +438,7 @@ impl<W: Write> BinarySystemWriter<W> {
         // Encode the scalar into the buffer, but do not push the IoRange yet.
         let value_io_range: IoRange =
             self.encode_to_buffer(|writer| scalar_write_fn(&mut writer.buffer))?;
+        self.record_scalar(&value_io_range);
 
         // Create ranges that will ultimately point to the encoded components of the annotations
         // wrapper for the value.
@@ -337,6 +533,8 @@ impl<W: Write> BinarySystemWriter<W> {
             })?,
         );
 
+        self.stats.annotation_bytes +=
+            header_io_range.len() + wrapper_length - wrapped_value_length;
         self.clear_annotations();
         Ok(())
     }
@@ -375,6 +573,67 @@ impl<W: Write> BinarySystemWriter<W> {
         self.num_annotations_current_value = annotation_ids.len() as u8;
     }
 
+    /// Writes `length` bytes of NOP padding, which readers skip over. Inside a struct, each pad is
+    /// preceded by the field ID `$0`; those bytes count towards `length`, which must therefore be
+    /// at least 2. A field name set for the next value is left in place. NOP padding cannot be
+    /// annotated.
+    pub fn write_nop_pad(&mut self, length: usize) -> IonResult<()> {
+        if self.has_annotations() {
+            return illegal_operation("NOP padding cannot be annotated.");
+        }
+        let in_struct = self.is_in_struct();
+        if in_struct && length == 1 {
+            return illegal_operation("NOP padding in a struct must be at least 2 bytes long.");
+        }
+        let start = self.buffer.len();
+        Self::encode_nop_padding(&mut self.buffer, length, in_struct)?;
+        let bytes_written = self.buffer.len() - start;
+        self.extend_last_range(bytes_written);
+        Ok(())
+    }
+
+    // Encodes `length` bytes of NOP padding as one or more NOP pads.
+    fn encode_nop_padding(
+        enc_buffer: &mut Vec<u8>,
+        mut length: usize,
+        in_struct: bool,
+    ) -> IonResult<()> {
+        // Inside a struct, each pad needs a one-byte field ID (`$0`).
+        let field_id_length = if in_struct { 1 } else { 0 };
+        while length > 0 {
+            if in_struct {
+                enc_buffer.push(0x80);
+            }
+            let pad_length = length - field_id_length;
+            if pad_length <= MAX_INLINE_LENGTH + 1 {
+                enc_buffer.push((pad_length - 1) as u8);
+                enc_buffer.resize(enc_buffer.len() + pad_length - 1, 0);
+                return Ok(());
+            }
+            // Longer pads encode their length as a VarUInt after the 0x0E type descriptor. Find a
+            // VarUInt size that can encode the length of the remaining bytes.
+            let length_size = (1..=MAX_ENCODED_SIZE_IN_BYTES)
+                .find(|size| VarUInt::encoded_size((pad_length - 1 - size) as u64) == *size);
+            match length_size {
+                Some(size) => {
+                    let body_length = pad_length - 1 - size;
+                    enc_buffer.push(0x0E);
+                    VarUInt::write_u64(enc_buffer, body_length as u64)?;
+                    enc_buffer.resize(enc_buffer.len() + body_length, 0);
+                    return Ok(());
+                }
+                None => {
+                    // No VarUInt size fits: growing the VarUInt by a byte shrinks the length it
+                    // encodes below what that size requires. Write a two-byte pad and encode the
+                    // rest separately.
+                    enc_buffer.extend_from_slice(&[0x01, 0x00]);
+                    length -= field_id_length + 2;
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Writes an Ion null of the specified type.
     pub fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
@@ -410,7 +669,8 @@ impl<W: Write> BinarySystemWriter<W> {
     /// Writes an Ion integer with the specified value.
     pub fn write_i64(&mut self, value: i64) -> IonResult<()> {
         self.write_scalar(|enc_buffer| {
-            let magnitude = value.abs() as u64;
+            // `unsigned_abs` cannot overflow, even for i64::MIN
+            let magnitude = value.unsigned_abs();
             let encoded = uint::encode_uint(magnitude);
             let bytes_to_write = encoded.as_bytes();
 
@@ -431,8 +691,14 @@ impl<W: Write> BinarySystemWriter<W> {
 
     /// Writes an Ion float with the specified value.
     pub fn write_f32(&mut self, value: f32) -> IonResult<()> {
+        let value = if self.normalize_nan && value.is_nan() {
+            f32::from_bits(CANONICAL_NAN_F32_BITS)
+        } else {
+            value
+        };
         self.write_scalar(|enc_buffer| {
-            if value == 0f32 {
+            // Only positive zero has a zero-length encoding; negative zero is written in full.
+            if value == 0f32 && value.is_sign_positive() {
                 enc_buffer.push(0x40);
                 return Ok(());
             }
@@ -445,8 +711,14 @@ impl<W: Write> BinarySystemWriter<W> {
 
     /// Writes an Ion float with the specified value.
     pub fn write_f64(&mut self, value: f64) -> IonResult<()> {
+        let value = if self.normalize_nan && value.is_nan() {
+            f64::from_bits(CANONICAL_NAN_F64_BITS)
+        } else {
+            value
+        };
         self.write_scalar(|enc_buffer| {
-            if value == 0f64 {
+            // Only positive zero has a zero-length encoding; negative zero is written in full.
+            if value == 0f64 && value.is_sign_positive() {
                 enc_buffer.push(0x40);
                 return Ok(());
             }
@@ -581,6 +853,232 @@ impl<W: Write> BinarySystemWriter<W> {
         self.field_id = Some(field_id);
     }
 
+    /// Returns the symbol ID for `text`, adding it to the writer's local symbol table if it is
+    /// not already defined. Interning the same text again returns the same symbol ID.
+    pub fn intern<A: AsRef<str>>(&mut self, text: A) -> SymbolId {
+        let text = text.as_ref();
+        self.stats.symbol_references += 1;
+        match self.symbol_table.sid_for(&text) {
+            Some(symbol_id) => symbol_id,
+            None => {
+                self.stats.symbols_interned += 1;
+                self.symbol_table.intern(text.to_string())
+            }
+        }
+    }
+
+    /// Returns statistics about the values and symbols that the writer has encoded so far. See
+    /// [WriterStats].
+    pub fn stats(&self) -> &WriterStats {
+        &self.stats
+    }
+
+    /// Returns the symbols that have been defined so far, including any that have been interned
+    /// but not yet written out.
+    pub fn symbol_table(&self) -> &SymbolTable {
+        &self.symbol_table
+    }
+
+    /// Interns `name` and sets the resulting symbol ID as the field ID of the next value.
+    /// Returns the writer so that the value can be written in the same expression, as in
+    /// `writer.set_field_name("id").write_i64(5)`.
+    pub fn set_field_name<A: AsRef<str>>(&mut self, name: A) -> &mut Self {
+        let field_id = self.intern(name);
+        self.set_field_id(field_id);
+        self
+    }
+
+    /// Interns each of the provided annotations and sets the resulting symbol IDs as the
+    /// annotations of the next value. Like [set_field_name](Self::set_field_name), returns the
+    /// writer so that calls can be chained.
+    pub fn set_annotations<I, A>(&mut self, annotations: I) -> &mut Self
+    where
+        I: IntoIterator<Item = A>,
+        A: AsRef<str>,
+    {
+        let annotation_ids: Vec<SymbolId> = annotations
+            .into_iter()
+            .map(|annotation| self.intern(annotation))
+            .collect();
+        self.set_annotation_ids(&annotation_ids);
+        self
+    }
+
+    /// Interns `text` and writes the resulting symbol ID as a symbol value.
+    pub fn write_symbol<A: AsRef<str>>(&mut self, text: A) -> IonResult<()> {
+        let symbol_id = self.intern(text);
+        self.write_symbol_id(symbol_id)
+    }
+
+    /// Sets the field name of the next value to `token`. A symbol ID is written as-is, without
+    /// consulting the writer's symbol table; text is interned as it is by
+    /// [set_field_name](Self::set_field_name).
+    pub fn set_field_name_token(&mut self, token: &RawSymbolToken) -> &mut Self {
+        let field_id = self.resolve_token(token);
+        self.set_field_id(field_id);
+        self
+    }
+
+    /// Sets the annotations of the next value to `tokens`. Like
+    /// [set_field_name_token](Self::set_field_name_token), symbol IDs are written as-is and
+    /// text is interned.
+    pub fn set_annotation_tokens(&mut self, tokens: &[RawSymbolToken]) -> &mut Self {
+        let annotation_ids: Vec<SymbolId> = tokens
+            .iter()
+            .map(|token| self.resolve_token(token))
+            .collect();
+        self.set_annotation_ids(&annotation_ids);
+        self
+    }
+
+    /// Writes `token` as a symbol value. Like [set_field_name_token](Self::set_field_name_token),
+    /// a symbol ID is written as-is and text is interned.
+    pub fn write_symbol_token(&mut self, token: &RawSymbolToken) -> IonResult<()> {
+        let symbol_id = self.resolve_token(token);
+        self.write_symbol_id(symbol_id)
+    }
+
+    /// Sets the field name of the next value to `symbol`. Text is interned, and a symbol with
+    /// unknown text is written as `$0`; its symbol ID is not used, since it may refer to a
+    /// different symbol table than the writer's.
+    pub fn set_field_symbol(&mut self, symbol: &Symbol) -> &mut Self {
+        let field_id = self.resolve_symbol(symbol);
+        self.set_field_id(field_id);
+        self
+    }
+
+    /// Sets the annotations of the next value to `symbols`, which are resolved as they are by
+    /// [set_field_symbol](Self::set_field_symbol).
+    pub fn set_annotation_symbols(&mut self, symbols: &[Symbol]) -> &mut Self {
+        let annotation_ids: Vec<SymbolId> = symbols
+            .iter()
+            .map(|symbol| self.resolve_symbol(symbol))
+            .collect();
+        self.set_annotation_ids(&annotation_ids);
+        self
+    }
+
+    /// Writes `symbol` as a symbol value, resolving it as
+    /// [set_field_symbol](Self::set_field_symbol) does.
+    pub fn write_symbol_value(&mut self, symbol: &Symbol) -> IonResult<()> {
+        let symbol_id = self.resolve_symbol(symbol);
+        self.write_symbol_id(symbol_id)
+    }
+
+    fn resolve_symbol(&mut self, symbol: &Symbol) -> SymbolId {
+        match symbol.text() {
+            Some(text) => self.intern(text),
+            None => 0,
+        }
+    }
+
+    fn resolve_token(&mut self, token: &RawSymbolToken) -> SymbolId {
+        match token {
+            RawSymbolToken::SymbolId(symbol_id) => *symbol_id,
+            RawSymbolToken::Text(text) => self.intern(text),
+        }
+    }
+
+    /// Writes `element`, along with its annotations and any values nested inside of it. Symbols
+    /// with text are interned; symbols without text are written using their symbol IDs as-is,
+    /// like those passed to [write_symbol_id](Self::write_symbol_id). Struct fields are written in
+    /// the order configured by [set_struct_field_order](Self::set_struct_field_order).
+    ///
+    /// Integers outside of the `i64` range cannot be written yet and produce an error.
+    pub fn write_element<E: Element>(&mut self, element: &E) -> IonResult<()> {
+        write_element_to_binary(self, element)
+    }
+
+    // If the writer's symbol table and `symbols` (a reader's symbol table) agree on the text of
+    // every symbol ID that both of them define, adds the symbols that only `symbols` defines to
+    // the end of the writer's table and returns true. Afterward, every symbol ID in `symbols`
+    // has the same text in the writer's table, so values using them can be copied verbatim.
+    fn align_symbol_table(&mut self, symbols: &[String]) -> bool {
+        let defined = self.symbol_table.symbols();
+        let common = defined.len().min(symbols.len());
+        if defined[..common] != symbols[..common] {
+            return false;
+        }
+        for text in &symbols[common..] {
+            self.symbol_table.add_symbol(text.clone());
+        }
+        true
+    }
+
+    // Copies `encoding`, the complete encoding of a value of type `ion_type` (including its
+    // annotations wrapper, if any, and any values nested inside of it), into the buffer as-is.
+    // The writer must not have annotations of its own set.
+    fn write_encoded_value(&mut self, ion_type: IonType, encoding: &[u8]) -> IonResult<()> {
+        if self.is_in_struct() {
+            let field_id = self.expect_field_id()? as u64;
+            let bytes_written = VarUInt::write_u64(&mut self.buffer, field_id)?;
+            self.extend_last_range(bytes_written);
+            self.stats.field_id_bytes += bytes_written;
+            self.field_id = None;
+        }
+        self.buffer.extend_from_slice(encoding);
+        self.stats.record_value(ion_type, encoding.len());
+        self.extend_last_range(encoding.len());
+        Ok(())
+    }
+
+    /// Writes an Ion version marker, which resets the symbol table of anything reading the
+    /// stream to the system symbol table. Any buffered values are flushed first, along with the
+    /// symbols that they use. The writer's own symbol table is reset too, so symbol IDs obtained
+    /// from [intern](Self::intern) before the IVM must not be used after it.
+    ///
+    /// An IVM is always written at the beginning of the stream, so calling this method before
+    /// anything else has been written has no effect. IVMs can only be written at the top level
+    /// and cannot be annotated.
+    ///
+    /// Together with the methods that accept symbol IDs, this allows system values to be written
+    /// verbatim: a local symbol table can be written as a struct annotated with
+    /// `$ion_symbol_table` (symbol ID 3) whose fields are set with [set_field_id](Self::set_field_id).
+    /// The writer does not interpret symbol tables written this way, so text should not be
+    /// interned while they are in effect.
+    pub fn write_ivm(&mut self) -> IonResult<()> {
+        if self.levels.len() > 1 {
+            return illegal_operation("An IVM can only be written at the top level.");
+        }
+        if self.has_annotations() {
+            return illegal_operation("An IVM cannot be annotated.");
+        }
+        let is_stream_start = self.ivm_needed
+            && self.buffer.is_empty()
+            && self.symbol_table.len() == self.num_flushed_symbols;
+        self.flush()?;
+        if !is_stream_start {
+            self.out.write_all(&IVM)?;
+        }
+        self.symbol_table.reset();
+        self.num_flushed_symbols = v1_0::SYSTEM_SYMBOLS.len();
+        Ok(())
+    }
+
+    // Encodes a local symbol table declaring the symbols that have been interned since the last
+    // flush(). If a local symbol table has already been written, the new table is an append.
+    fn encode_pending_symbol_table(&self) -> IonResult<Vec<u8>> {
+        let mut encoded = Vec::new();
+        let mut writer = BinarySystemWriter::new(&mut encoded);
+        // The IVM (if any) is written by the outer writer
+        writer.ivm_needed = false;
+        writer.set_annotation_ids(&[system_symbol_ids::ION_SYMBOL_TABLE]);
+        writer.step_in(IonType::Struct)?;
+        if self.num_flushed_symbols > v1_0::SYSTEM_SYMBOLS.len() {
+            writer.set_field_id(system_symbol_ids::IMPORTS);
+            writer.write_symbol_id(system_symbol_ids::ION_SYMBOL_TABLE)?;
+        }
+        writer.set_field_id(system_symbol_ids::SYMBOLS);
+        writer.step_in(IonType::List)?;
+        for text in self.symbol_table.symbols_tail(self.num_flushed_symbols) {
+            writer.write_string(text)?;
+        }
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.flush()?;
+        Ok(encoded)
+    }
+
     // Called when the writer is in a struct and a missing field ID is an error
     fn expect_field_id(&self) -> IonResult<usize> {
         match self.field_id {
@@ -591,6 +1089,52 @@ impl<W: Write> BinarySystemWriter<W> {
         }
     }
 
+    /// Writes a struct, calling `write_fields` to write its fields and then stepping out.
+    /// For example:
+    ///
+    /// ```
+    /// # use ion_rs::binary::writer::BinarySystemWriter;
+    /// # use ion_rs::result::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// let mut buffer = Vec::new();
+    /// let mut writer = BinarySystemWriter::new(&mut buffer);
+    /// writer.set_annotations(["point"]).write_struct(|s| {
+    ///     s.set_field_name("x").write_i64(1)?;
+    ///     s.set_field_name("y").write_i64(2)
+    /// })?;
+    /// writer.flush()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_struct<F>(&mut self, write_fields: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::Struct)?;
+        write_fields(self)?;
+        self.step_out()
+    }
+
+    /// Writes a list, calling `write_values` to write its values and then stepping out.
+    pub fn write_list<F>(&mut self, write_values: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::List)?;
+        write_values(self)?;
+        self.step_out()
+    }
+
+    /// Writes an s-expression, calling `write_values` to write its values and then stepping out.
+    pub fn write_sexp<F>(&mut self, write_values: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::SExpression)?;
+        write_values(self)?;
+        self.step_out()
+    }
+
     /// Starts a container of the specified Ion type. If `ion_type` is not a List, SExpression,
     /// or Struct, `step_in` will return an Err.
     pub fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
@@ -610,6 +1154,7 @@ impl<W: Write> BinarySystemWriter<W> {
                 Ok(())
             })?;
             self.extend_last_range(field_id_io_range.len());
+            self.stats.field_id_bytes += field_id_io_range.len();
         }
 
         // If the container is annotated, reserve IO ranges to hold the annotations
@@ -628,6 +1173,7 @@ impl<W: Write> BinarySystemWriter<W> {
             self.field_id,
             self.num_annotations_current_value,
             header_io_range_index,
+            self.buffer.len(),
         );
         self.num_annotations_current_value = 0;
         self.levels.push(new_encoding_level);
@@ -647,13 +1193,13 @@ impl<W: Write> BinarySystemWriter<W> {
         let container = self.levels.pop().unwrap();
         self.num_annotations_current_value = container.num_annotations;
         self.field_id = container.field_id;
-        let container_size = container.calculate_final_size(&mut self.io_ranges);
+        let container_size = container.calculate_final_size(&self.buffer);
 
         use ContainerType::*;
-        let mut type_descriptor: u8 = match container.container_type {
-            List => 0xB0,
-            SExpression => 0xC0,
-            Struct => 0xD0,
+        let (mut type_descriptor, ion_type): (u8, IonType) = match container.container_type {
+            List => (0xB0, IonType::List),
+            SExpression => (0xC0, IonType::SExpression),
+            Struct => (0xD0, IonType::Struct),
             _ => return illegal_operation("Cannot step into a scalar Ion type."),
         };
 
@@ -669,6 +1215,8 @@ impl<W: Write> BinarySystemWriter<W> {
             }
             Ok(())
         })?;
+        self.stats
+            .record_value(ion_type, header_io_range.len() + container_size);
 
         // Retrieve this container's header byte range from io_ranges
         let td_io_range = self
@@ -744,6 +1292,48 @@ impl<W: Write> BinarySystemWriter<W> {
         &mut self.out
     }
 
+    /// Replaces the writer's sink with `out` and returns the writer to the beginning of a new
+    /// stream, returning the previous sink. The next [flush](Self::flush) will write an IVM, and
+    /// the symbol table is reset to the system symbol table. The writer's buffers keep their
+    /// capacity, making this cheaper than constructing a new writer when encoding many small,
+    /// independent messages.
+    ///
+    /// Any data that has not been flushed is discarded, as is any container that the writer is
+    /// inside. NaN normalization remains configured as it was.
+    pub fn reset(&mut self, out: W) -> W {
+        self.reset_stream();
+        mem::replace(&mut self.out, out)
+    }
+
+    // Returns the writer to the beginning of a new stream without replacing its sink.
+    fn reset_stream(&mut self) {
+        self.ivm_needed = true;
+        self.buffer.clear();
+        self.io_ranges.clear();
+        self.io_ranges.push(0usize..0);
+        self.levels.clear();
+        self.levels
+            .push(EncodingLevel::new(ContainerType::TopLevel, None, 0, 0, 0));
+        self.field_id = None;
+        self.annotations_all_levels.clear();
+        self.num_annotations_current_value = 0;
+        self.contiguous_encoding.clear();
+        self.symbol_table.reset();
+        self.num_flushed_symbols = v1_0::SYSTEM_SYMBOLS.len();
+    }
+
+    // Returns the writer to the beginning of a message that will be read after a preamble which
+    // has already declared `symbols` (following the system symbols) in a local symbol table.
+    // The message does not begin with an IVM, and symbols that it adds are declared in an append.
+    pub(crate) fn reset_to_preamble(&mut self, symbols: &[String]) {
+        self.reset_stream();
+        self.ivm_needed = false;
+        for text in symbols {
+            self.symbol_table.intern(text.clone());
+        }
+        self.num_flushed_symbols = self.symbol_table.len();
+    }
+
     /// Writes any buffered data to the sink. This method can only be called when the writer is at
     /// the top level.
     pub fn flush(&mut self) -> IonResult<()> {
@@ -775,9 +1365,20 @@ impl<W: Write> BinarySystemWriter<W> {
             self.ivm_needed = false;
         }
 
+        // Any symbols interned since the last flush must be declared before the values that
+        // refer to them.
+        if self.symbol_table.len() > self.num_flushed_symbols {
+            let symbol_table = self.encode_pending_symbol_table()?;
+            self.out.write_all(&symbol_table)?;
+            self.stats.symbol_table_bytes += symbol_table.len();
+            self.num_flushed_symbols = self.symbol_table.len();
+        }
+
         self.out.write_all(self.contiguous_encoding.as_slice())?;
 
+        // Everything in the buffer has now been written out, so its space can be reused.
         self.contiguous_encoding.clear();
+        self.buffer.clear();
         self.push_empty_io_range();
 
         Ok(())
@@ -793,6 +1394,276 @@ impl<W: Write> BinarySystemWriter<W> {
     }
 }
 
+/// An in-memory sink that a writer can drain into another destination, leaving it empty (but with
+/// its capacity intact) to hold the next batch of output. This is implemented for `Vec<u8>` and
+/// for a mutable reference to a caller-owned `Vec<u8>`. See [BinarySystemWriter::flush_to].
+pub trait DrainableOutput: Write {
+    /// Returns everything that has been written to the sink since it was last cleared.
+    fn contents(&self) -> &[u8];
+
+    /// Discards the sink's contents.
+    fn clear(&mut self);
+}
+
+impl DrainableOutput for Vec<u8> {
+    fn contents(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    fn clear(&mut self) {
+        Vec::clear(self)
+    }
+}
+
+impl DrainableOutput for &mut Vec<u8> {
+    fn contents(&self) -> &[u8] {
+        self.as_slice()
+    }
+
+    fn clear(&mut self) {
+        Vec::clear(self)
+    }
+}
+
+/// Draining output, which is only available if the sink is an in-memory buffer.
+impl<W: DrainableOutput> BinarySystemWriter<W> {
+    /// Flushes any buffered data to the sink, then moves everything in the sink to `out` and
+    /// clears the sink so that it can be reused. Returns the number of bytes that were moved.
+    ///
+    /// The writer continues the same stream afterwards, so data drained later relies on the IVM
+    /// and symbols in data drained earlier. This suits writing a large stream to a file in
+    /// chunks; for messages that must each be readable on their own, see
+    /// [flush_message_to](Self::flush_message_to).
+    pub fn flush_to<O: Write + ?Sized>(&mut self, out: &mut O) -> IonResult<usize> {
+        self.flush()?;
+        let contents = self.out.contents();
+        out.write_all(contents)?;
+        let length = contents.len();
+        self.out.clear();
+        Ok(length)
+    }
+
+    /// Like [flush_to](Self::flush_to), but then returns the writer to the beginning of a new
+    /// stream, as [reset](Self::reset) does: the next data that is drained will begin with an IVM
+    /// and declare all of the symbols it uses. Each message drained this way is a complete Ion
+    /// stream, which suits framing messages for a network protocol.
+    pub fn flush_message_to<O: Write + ?Sized>(&mut self, out: &mut O) -> IonResult<usize> {
+        let length = self.flush_to(out)?;
+        self.reset_stream();
+        Ok(length)
+    }
+}
+
+/// A user-level streaming binary Ion writer. Field names, annotations and symbol values are
+/// always specified as text; the writer interns them into its local symbol table and writes
+/// that table out ahead of the values that use it. Each [flush](Self::flush) declares any
+/// symbols that were added since the previous one as an append to the existing table, so
+/// streaming producers never need to handle symbol IDs.
+///
+/// For direct control over the symbol IDs that are written, use [BinarySystemWriter].
+#[derive(Debug)]
+pub struct BinaryWriter<W: Write> {
+    system_writer: BinarySystemWriter<W>,
+}
+
+impl<W: Write> BinaryWriter<W> {
+    /// Creates a new BinaryWriter that will write its encoded output to the provided io::Write
+    /// sink.
+    pub fn new(out: W) -> BinaryWriter<W> {
+        BinaryWriter {
+            system_writer: BinarySystemWriter::new(out),
+        }
+    }
+
+    /// See [BinarySystemWriter::with_capacity].
+    pub fn with_capacity(out: W, capacity: usize) -> BinaryWriter<W> {
+        BinaryWriter {
+            system_writer: BinarySystemWriter::with_capacity(out, capacity),
+        }
+    }
+
+    /// Consumes the writer, returning the underlying [BinarySystemWriter]. Any data that has not
+    /// been flushed remains buffered in the returned writer.
+    pub fn into_system_writer(self) -> BinarySystemWriter<W> {
+        self.system_writer
+    }
+
+    /// See [BinarySystemWriter::set_field_name].
+    pub fn set_field_name<A: AsRef<str>>(&mut self, name: A) -> &mut Self {
+        self.system_writer.set_field_name(name);
+        self
+    }
+
+    /// See [BinarySystemWriter::set_annotations].
+    pub fn set_annotations<I, A>(&mut self, annotations: I) -> &mut Self
+    where
+        I: IntoIterator<Item = A>,
+        A: AsRef<str>,
+    {
+        self.system_writer.set_annotations(annotations);
+        self
+    }
+
+    /// See [BinarySystemWriter::set_field_symbol].
+    pub fn set_field_symbol(&mut self, symbol: &Symbol) -> &mut Self {
+        self.system_writer.set_field_symbol(symbol);
+        self
+    }
+
+    /// See [BinarySystemWriter::set_annotation_symbols].
+    pub fn set_annotation_symbols(&mut self, symbols: &[Symbol]) -> &mut Self {
+        self.system_writer.set_annotation_symbols(symbols);
+        self
+    }
+
+    /// See [BinarySystemWriter::write_struct].
+    pub fn write_struct<F>(&mut self, write_fields: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::Struct)?;
+        write_fields(self)?;
+        self.step_out()
+    }
+
+    /// See [BinarySystemWriter::write_list].
+    pub fn write_list<F>(&mut self, write_values: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::List)?;
+        write_values(self)?;
+        self.step_out()
+    }
+
+    /// See [BinarySystemWriter::write_sexp].
+    pub fn write_sexp<F>(&mut self, write_values: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::SExpression)?;
+        write_values(self)?;
+        self.step_out()
+    }
+
+    /// Copies the value that `reader` is positioned on (that is, the value most recently returned
+    /// by [Reader::next]) to the writer, along with its annotations, its field name (if the writer
+    /// is in a struct) and any values nested inside of it. Afterward, the next call to
+    /// [Reader::next] advances to the value that follows it.
+    ///
+    /// If the writer's symbol table agrees with the reader's on the text of every symbol ID that
+    /// both define, the reader's remaining symbols are added to the writer's table and the
+    /// value's encoding is copied byte for byte, without being decoded. The comparison takes time
+    /// proportional to the size of the symbol tables. Otherwise, or if annotations have been set
+    /// on the writer, the value is copied one component at a time by
+    /// [transcode_value](crate::transcode::transcode_value), which works with any reader.
+    ///
+    /// ```
+    /// use ion_rs::binary::writer::BinaryWriter;
+    /// use ion_rs::value::owned::OwnedElement;
+    /// use ion_rs::{IonType, RawBinaryReader, Reader};
+    /// use std::io;
+    /// # fn main() -> ion_rs::result::IonResult<()> {
+    ///
+    /// let mut input = Vec::new();
+    /// let mut writer = BinaryWriter::new(&mut input);
+    /// for element in OwnedElement::read_all(&b"{a: [b, c]} d {e: f}"[..])? {
+    ///     writer.write_element(&element)?;
+    /// }
+    /// writer.flush()?;
+    /// drop(writer);
+    ///
+    /// // Copies every value that is not a symbol
+    /// let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(&input)));
+    /// let mut output = Vec::new();
+    /// let mut writer = BinaryWriter::new(&mut output);
+    /// while let Some((ion_type, _)) = reader.next()? {
+    ///     if ion_type != IonType::Symbol {
+    ///         writer.write_current_value(&mut reader)?;
+    ///     }
+    /// }
+    /// writer.flush()?;
+    /// drop(writer);
+    /// assert_eq!(
+    ///     OwnedElement::read_all(&output)?,
+    ///     OwnedElement::read_all(&b"{a: [b, c]} {e: f}"[..])?
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_current_value<T: AsRef<[u8]>, A: Allocator + Clone>(
+        &mut self,
+        reader: &mut Reader<RawBinaryReader<io::Cursor<T>, A>>,
+    ) -> IonResult<()> {
+        let ion_type = match reader.ion_type() {
+            Some(ion_type) => ion_type,
+            None => return illegal_operation("The reader is not positioned on a value."),
+        };
+        if self.system_writer.has_annotations()
+            || !self
+                .system_writer
+                .align_symbol_table(reader.symbol_table().symbols())
+        {
+            return transcode_value(reader, self);
+        }
+        if self.system_writer.is_in_struct() {
+            // The tables agree, so the reader's field ID can be used as-is.
+            match reader.field_name_sid() {
+                Some(field_id) => self.system_writer.set_field_id(field_id),
+                None => return transcode_value(reader, self),
+            }
+        }
+        let encoding = match reader.encoded_bytes()? {
+            Some(encoding) => encoding,
+            None => return illegal_operation("The reader is not positioned on a value."),
+        };
+        self.system_writer.write_encoded_value(ion_type, encoding)
+    }
+
+    delegate! {
+        to self.system_writer {
+            pub fn reserve(&mut self, additional: usize);
+            pub fn normalize_nan(&mut self, normalize: bool);
+            pub fn set_struct_field_order(&mut self, order: StructFieldOrder);
+            pub fn struct_field_order(&self) -> StructFieldOrder;
+            pub fn clear_annotations(&mut self);
+            pub fn has_annotations(&self) -> bool;
+            pub fn symbol_table(&self) -> &SymbolTable;
+            pub fn stats(&self) -> &WriterStats;
+            pub fn write_nop_pad(&mut self, length: usize) -> IonResult<()>;
+            pub fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
+            pub fn write_bool(&mut self, value: bool) -> IonResult<()>;
+            pub fn write_i64(&mut self, value: i64) -> IonResult<()>;
+            pub fn write_f32(&mut self, value: f32) -> IonResult<()>;
+            pub fn write_f64(&mut self, value: f64) -> IonResult<()>;
+            pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
+            pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()>;
+            pub fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()>;
+            pub fn write_symbol<A: AsRef<str>>(&mut self, text: A) -> IonResult<()>;
+            pub fn write_symbol_value(&mut self, symbol: &Symbol) -> IonResult<()>;
+            pub fn write_element<E: Element>(&mut self, element: &E) -> IonResult<()>;
+            pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()>;
+            pub fn write_clob(&mut self, value: &[u8]) -> IonResult<()>;
+            pub fn write_blob(&mut self, value: &[u8]) -> IonResult<()>;
+            pub fn step_in(&mut self, ion_type: IonType) -> IonResult<()>;
+            pub fn step_out(&mut self) -> IonResult<()>;
+            pub fn output(&self) -> &W;
+            pub fn reset(&mut self, out: W) -> W;
+            pub fn flush(&mut self) -> IonResult<()>;
+        }
+    }
+}
+
+impl<W: DrainableOutput> BinaryWriter<W> {
+    delegate! {
+        to self.system_writer {
+            pub fn flush_to<O: Write + ?Sized>(&mut self, out: &mut O) -> IonResult<usize>;
+            pub fn flush_message_to<O: Write + ?Sized>(&mut self, out: &mut O) -> IonResult<usize>;
+            pub(crate) fn reset_to_preamble(&mut self, symbols: &[String]);
+        }
+    }
+}
+
 #[cfg(test)]
 mod writer_tests {
     use std::fmt::Debug;
@@ -802,7 +1673,10 @@ mod writer_tests {
     use rstest::*;
 
     use super::*;
+    use crate::raw_reader::{RawReader, StreamItem::Value};
     use crate::raw_symbol_token::{local_sid_token, RawSymbolToken};
+    use crate::test_support::binary_ion;
+    use crate::value::owned::OwnedElement;
     use num_traits::Float;
     use std::convert::TryInto;
 
@@ -911,7 +1785,7 @@ mod writer_tests {
     #[test]
     fn binary_writer_ints() -> IonResult<()> {
         binary_writer_scalar_test(
-            &[-24_601, -17, -1, 0, 1, 17, 24_601],
+            &[i64::MIN, -24_601, -17, -1, 0, 1, 17, 24_601, i64::MAX],
             IonType::Integer,
             |writer, v| writer.write_i64(*v),
             |reader| reader.read_i64(),
@@ -928,6 +1802,80 @@ mod writer_tests {
         )
     }
 
+    #[test]
+    fn binary_writer_negative_zero_floats() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        writer.write_f64(-0.0)?;
+        writer.write_f32(-0.0)?;
+        writer.write_f64(0.0)?;
+        writer.flush()?;
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        for expect_negative in &[true, true, false] {
+            assert_eq!(reader.next()?, Some((IonType::Float, false)));
+            let value = reader.read_f64()?.unwrap();
+            assert_eq!(value, 0.0);
+            assert_eq!(value.is_sign_negative(), *expect_negative);
+        }
+        Ok(())
+    }
+
+    // Writes each NaN bit pattern with the provided normalization setting and returns the
+    // encoded bytes that follow the IVM.
+    fn write_nans(normalize: bool, f64_bits: &[u64], f32_bits: &[u32]) -> IonResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        writer.normalize_nan(normalize);
+        for bits in f64_bits {
+            writer.write_f64(f64::from_bits(*bits))?;
+        }
+        for bits in f32_bits {
+            writer.write_f32(f32::from_bits(*bits))?;
+        }
+        writer.flush()?;
+        drop(writer);
+        Ok(buffer[IVM.len()..].to_vec())
+    }
+
+    const NAN_F64_BITS: &[u64] = &[
+        0x7FF8_0000_0000_0000, // Canonical quiet NaN
+        0x7FF0_0000_0000_0001, // Signaling NaN
+        0xFFF8_0000_0000_0000, // Negative quiet NaN
+        0x7FFF_FFFF_FFFF_FFFF, // Quiet NaN with a payload
+    ];
+
+    const NAN_F32_BITS: &[u32] = &[0x7FC0_0000, 0x7F80_0001, 0xFFC0_0000];
+
+    #[test]
+    fn binary_writer_normalizes_nan() -> IonResult<()> {
+        let mut expected = Vec::new();
+        for _ in NAN_F64_BITS {
+            expected.push(0x48);
+            expected.extend_from_slice(&CANONICAL_NAN_F64_BITS.to_be_bytes());
+        }
+        for _ in NAN_F32_BITS {
+            expected.push(0x44);
+            expected.extend_from_slice(&CANONICAL_NAN_F32_BITS.to_be_bytes());
+        }
+        assert_eq!(write_nans(true, NAN_F64_BITS, NAN_F32_BITS)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_preserves_nan_bits_by_default() -> IonResult<()> {
+        let mut expected = Vec::new();
+        for bits in NAN_F64_BITS {
+            expected.push(0x48);
+            expected.extend_from_slice(&bits.to_be_bytes());
+        }
+        for bits in NAN_F32_BITS {
+            expected.push(0x44);
+            expected.extend_from_slice(&bits.to_be_bytes());
+        }
+        assert_eq!(write_nans(false, NAN_F64_BITS, NAN_F32_BITS)?, expected);
+        Ok(())
+    }
+
     #[rstest]
     #[case("2000-01-01T00:00:00+00:00")]
     #[case("2021-01-08T14:12:36+00:00")]
@@ -1052,6 +2000,33 @@ mod writer_tests {
         )
     }
 
+    #[test]
+    fn binary_writer_lobs_with_embedded_nulls_and_high_bytes() -> IonResult<()> {
+        let values: Vec<&[u8]> = vec![
+            &[0x00],
+            &[0x00, 0x00, 0x00],
+            &[b'a', 0x00, b'b'],
+            &[0x00, 0x7F, 0x80, 0xFE, 0xFF],
+            &[
+                0xFF, 0x00, 0x01, 0x00, 0xFF, 0x00, 0x01, 0x00, 0xFF, 0x00, 0x01, 0x00, 0xFF, 0x00,
+            ],
+        ];
+
+        binary_writer_scalar_test(
+            &values,
+            IonType::Clob,
+            |writer, v| writer.write_clob(*v),
+            |reader| reader.read_clob_bytes(),
+        )?;
+
+        binary_writer_scalar_test(
+            &values,
+            IonType::Clob,
+            |writer, v| writer.write_clob(*v),
+            |reader| reader.clob_ref_map(|bytes| bytes.to_vec()),
+        )
+    }
+
     fn expect_scalar<T: Debug, U: PartialEq<T> + Debug>(
         reader: &mut TestReader,
         ion_type: IonType,
@@ -1372,4 +2347,560 @@ mod writer_tests {
             },
         )
     }
+
+    #[test]
+    fn binary_writer_deeply_nested_structs() -> IonResult<()> {
+        // {foo: {foo: ... quux::{foo: {bar: 0}} ...}}, with every tenth level annotated. The
+        // outer levels are long enough to need VarUInt length prefixes.
+        const DEPTH: usize = 200;
+        binary_writer_test(
+            |writer| {
+                write_lst(writer, &["foo", "bar", "baz", "quux"])?;
+                writer.step_in(IonType::Struct)?;
+                for depth in 1..DEPTH {
+                    writer.set_field_id(10);
+                    if depth % 10 == 0 {
+                        writer.set_annotation_ids(&[13]);
+                    }
+                    writer.step_in(IonType::Struct)?;
+                }
+                writer.set_field_id(11);
+                writer.write_i64(0)?;
+                for _ in 0..DEPTH {
+                    writer.step_out()?;
+                }
+                Ok(())
+            },
+            |reader| {
+                expect_struct(reader);
+                reader.step_in()?;
+                for depth in 1..DEPTH {
+                    expect_struct(reader);
+                    expect_field_name(reader, "foo");
+                    if depth % 10 == 0 {
+                        expect_annotations(reader, &["quux"]);
+                    }
+                    reader.step_in()?;
+                }
+                expect_integer(reader, 0);
+                expect_field_name(reader, "bar");
+                assert_eq!(reader.next()?, None);
+                for _ in 0..DEPTH {
+                    reader.step_out()?;
+                }
+                assert_eq!(reader.next()?, None);
+                Ok(())
+            },
+        )
+    }
+
+    #[test]
+    fn binary_writer_reuses_its_buffer_after_flush() -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        writer.step_in(IonType::List)?;
+        writer.write_string("foo")?;
+        writer.step_out()?;
+        writer.flush()?;
+        assert!(writer.buffer.is_empty());
+
+        writer.write_i64(7)?;
+        writer.flush()?;
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        expect_list(&mut reader);
+        expect_integer(&mut reader, 7);
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_with_capacity() -> IonResult<()> {
+        let blob = vec![0xA5u8; 100_000];
+        let mut writer = BinarySystemWriter::with_capacity(Vec::new(), 50_000);
+        assert!(writer.buffer.capacity() >= 50_000);
+        writer.reserve(blob.len() + 16);
+        let capacities = (
+            writer.buffer.capacity(),
+            writer.contiguous_encoding.capacity(),
+        );
+        writer.step_in(IonType::List)?;
+        writer.write_blob(&blob)?;
+        writer.step_out()?;
+        writer.flush()?;
+        // Neither buffer had to grow
+        assert_eq!(
+            (
+                writer.buffer.capacity(),
+                writer.contiguous_encoding.capacity()
+            ),
+            capacities
+        );
+        // IVM, list header, blob header, blob
+        assert_eq!(
+            writer.output().len(),
+            IVM.len() + 1 + 3 + 1 + 3 + blob.len()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_write_current_value_copies_bytes() -> IonResult<()> {
+        let input = binary_ion("a::{b: [c, 1.5], d: null.sexp} e {f: g}");
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(&input)));
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        while reader.next()?.is_some() {
+            writer.write_current_value(&mut reader)?;
+        }
+        writer.flush()?;
+        assert_eq!(writer.stats().value_count(), 3);
+        drop(writer);
+        // The writer adopted the reader's symbol table and copied each value's encoding.
+        assert_eq!(output, input);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_write_current_value_transcribes() -> IonResult<()> {
+        let input = binary_ion("{a: b, c: [d]}");
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(&input)));
+        let mut output = Vec::new();
+        let mut writer = BinaryWriter::new(&mut output);
+        // After this, symbol ID 10 means `x` to the writer but `a` to the reader.
+        writer.write_symbol("x")?;
+        writer.step_in(IonType::Struct)?;
+        reader.next()?;
+        reader.step_in()?;
+        while reader.next()?.is_some() {
+            writer.write_current_value(&mut reader)?;
+        }
+        reader.step_out()?;
+        writer.step_out()?;
+        // The reader is at the end of the stream.
+        assert_eq!(reader.next()?, None);
+        assert!(writer.write_current_value(&mut reader).is_err());
+        writer.flush()?;
+        drop(writer);
+        let expected = OwnedElement::read_all(&b"x {a: b, c: [d]}"[..])?;
+        assert_eq!(OwnedElement::read_all(&output)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_interned_symbols() -> IonResult<()> {
+        binary_writer_test(
+            |writer| {
+                // foo::[{name: bar, id: 1}, {name: baz, id: 2}]
+                writer.set_annotations(&["foo"]);
+                writer.step_in(IonType::List)?;
+                for (index, symbol) in ["bar", "baz"].iter().enumerate() {
+                    writer.step_in(IonType::Struct)?;
+                    writer.set_field_name("name");
+                    writer.write_symbol(symbol)?;
+                    writer.set_field_name("id");
+                    writer.write_i64(index as i64 + 1)?;
+                    writer.step_out()?;
+                }
+                writer.step_out()?;
+
+                // System symbols are not added to the local symbol table
+                assert_eq!(writer.intern("name"), 4);
+                // Re-interning returns the same symbol ID
+                assert_eq!(writer.intern("foo"), 10);
+                assert_eq!(writer.intern("id"), 12);
+                assert_eq!(writer.symbol_table().len(), 14);
+                Ok(())
+            },
+            |reader| {
+                expect_list(reader);
+                expect_annotations(reader, &["foo"]);
+                reader.step_in()?;
+                for (index, symbol_id) in [11, 13].iter().enumerate() {
+                    expect_struct(reader);
+                    reader.step_in()?;
+                    expect_symbol_id(reader, *symbol_id);
+                    expect_field_name(reader, "name");
+                    expect_integer(reader, index as i64 + 1);
+                    expect_field_name(reader, "id");
+                    reader.step_out()?;
+                }
+                reader.step_out()?;
+                assert_eq!(reader.symbol_table().text_for(11), Some("bar"));
+                assert_eq!(reader.symbol_table().text_for(13), Some("baz"));
+                Ok(())
+            },
+        )
+    }
+
+    #[test]
+    fn binary_writer_interned_symbols_are_appended_on_each_flush() -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        writer.write_symbol("foo")?;
+        writer.flush()?;
+        // Nothing new to declare
+        writer.write_symbol("foo")?;
+        writer.flush()?;
+        writer.write_symbol("bar")?;
+        writer.flush()?;
+
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        expect_symbol_id(&mut reader, 10);
+        expect_symbol_id(&mut reader, 10);
+        expect_symbol_id(&mut reader, 11);
+        assert_eq!(reader.symbol_table().text_for(10), Some("foo"));
+        assert_eq!(reader.symbol_table().text_for(11), Some("bar"));
+        assert_eq!(reader.next()?, None);
+
+        // The stream contains exactly two local symbol tables, the second of which is an append
+        let mut raw_reader = RawBinaryReader::new(io::Cursor::new(buffer.as_slice()));
+        let mut symbol_tables = 0;
+        while let Some(item) = raw_reader.next()? {
+            if item == Value(IonType::Struct, false) {
+                symbol_tables += 1;
+                assert_eq!(raw_reader.annotation_sids().collect::<Vec<_>>(), vec![3]);
+                raw_reader.step_in()?;
+                raw_reader.next()?;
+                let has_imports = raw_reader.field_name_sid() == Some(6);
+                assert_eq!(has_imports, symbol_tables == 2);
+                raw_reader.step_out()?;
+            }
+        }
+        assert_eq!(symbol_tables, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_symbol_values() -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = BinaryWriter::new(&mut buffer);
+        // foo::$0::{bar: baz, $0: $0}
+        writer.set_annotation_symbols(&[Symbol::from("foo"), Symbol::with_unknown_text(10)]);
+        writer.write_struct(|s| {
+            s.set_field_symbol(&Symbol::with_text_and_sid("bar", 99));
+            s.write_symbol_value(&Symbol::from("baz"))?;
+            s.set_field_symbol(&Symbol::unknown_text());
+            s.write_symbol_value(&Symbol::with_unknown_text(10))
+        })?;
+        writer.flush()?;
+        drop(writer);
+
+        let mut reader = Reader::new(RawBinaryReader::new(std::io::Cursor::new(&buffer[..])));
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        let annotations: Vec<Symbol> = reader.annotation_symbols().collect();
+        assert_eq!(
+            annotations,
+            vec![Symbol::from("foo"), Symbol::unknown_text()]
+        );
+        assert_eq!(annotations[1].local_sid(), Some(0));
+        reader.step_in()?;
+        reader.next()?;
+        assert_eq!(reader.field_name_symbol(), Some(Symbol::from("bar")));
+        assert_eq!(reader.read_symbol_value()?, Some(Symbol::from("baz")));
+        reader.next()?;
+        assert_eq!(reader.field_name_symbol(), Some(Symbol::unknown_text()));
+        assert_eq!(reader.read_symbol_value()?, Some(Symbol::unknown_text()));
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_raw_symbol_ids_and_system_values() -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        // The stream already begins with an IVM
+        writer.write_ivm()?;
+        // foo::{name: 10}
+        writer.set_annotation_tokens(&[RawSymbolToken::Text("foo".to_string())]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name_token(&RawSymbolToken::SymbolId(4));
+        writer.write_symbol_token(&RawSymbolToken::SymbolId(10))?;
+        assert!(writer.write_ivm().is_err());
+        writer.step_out()?;
+        writer.set_annotations(&["foo"]);
+        assert!(writer.write_ivm().is_err());
+        writer.clear_annotations();
+
+        // $ion_symbol_table::{symbols: ["bar"]}
+        writer.write_ivm()?;
+        writer.set_annotation_ids(&[system_symbol_ids::ION_SYMBOL_TABLE]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_id(system_symbol_ids::SYMBOLS);
+        writer.step_in(IonType::List)?;
+        writer.write_string("bar")?;
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.write_symbol_id(10)?;
+        writer.flush()?;
+        drop(writer);
+
+        // The stream begins with a single IVM
+        assert_eq!(&buffer[..4], &IVM);
+        assert_ne!(&buffer[4..8], &IVM);
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        expect_struct(&mut reader);
+        expect_annotations(&reader, &["foo"]);
+        reader.step_in()?;
+        expect_symbol_id(&mut reader, 10);
+        expect_field_name(&reader, "name");
+        assert_eq!(reader.symbol_table().text_for(10), Some("foo"));
+        reader.step_out()?;
+        expect_symbol_id(&mut reader, 10);
+        assert_eq!(reader.symbol_table().text_for(10), Some("bar"));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn user_level_binary_writer() -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = BinaryWriter::new(&mut buffer);
+        writer.set_annotations(&["event"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("kind");
+        writer.write_symbol("start")?;
+        writer.step_out()?;
+        writer.flush()?;
+        writer.set_annotations(&["event"]);
+        writer.write_symbol("stop")?;
+        writer.flush()?;
+
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        expect_struct(&mut reader);
+        expect_annotations(&mut reader, &["event"]);
+        reader.step_in()?;
+        expect_symbol_id(&mut reader, 12);
+        expect_field_name(&mut reader, "kind");
+        reader.step_out()?;
+        expect_symbol_id(&mut reader, 13);
+        expect_annotations(&mut reader, &["event"]);
+        assert_eq!(reader.symbol_table().text_for(13), Some("stop"));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_chained_setters_and_closures() -> IonResult<()> {
+        let mut expected = vec![];
+        let mut writer = BinaryWriter::new(&mut expected);
+        writer.set_annotations(&["a", "b"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("c");
+        writer.write_bool(true)?;
+        writer.set_field_name("d");
+        writer.step_in(IonType::List)?;
+        writer.write_i64(1)?;
+        writer.step_in(IonType::SExpression)?;
+        writer.write_symbol("e")?;
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.flush()?;
+
+        let mut buffer = vec![];
+        let mut writer = BinaryWriter::new(&mut buffer);
+        writer.set_annotations(["a", "b"]).write_struct(|s| {
+            s.set_field_name("c").write_bool(true)?;
+            s.set_field_name("d").write_list(|l| {
+                l.write_i64(1)?;
+                l.write_sexp(|e| e.write_symbol("e"))
+            })
+        })?;
+        writer.flush()?;
+        assert_eq!(buffer, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_reset() -> IonResult<()> {
+        let mut writer = BinaryWriter::new(Vec::new());
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("foo").write_symbol("baz")?;
+        // Unflushed data and the open container are discarded.
+        let discarded = writer.reset(Vec::new());
+        assert!(discarded.is_empty());
+
+        writer.write_symbol("bar")?;
+        writer.flush()?;
+        let first = writer.reset(Vec::new());
+        writer.write_symbol("bar")?;
+        writer.flush()?;
+        let second = writer.reset(Vec::new());
+
+        // Each stream is self-contained: it begins with an IVM and declares its own symbols.
+        let mut expected = vec![];
+        let mut fresh_writer = BinaryWriter::new(&mut expected);
+        fresh_writer.write_symbol("bar")?;
+        fresh_writer.flush()?;
+        assert_eq!(first, expected);
+        assert_eq!(second, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_nop_pads() -> IonResult<()> {
+        let mut buffer = vec![];
+        let mut writer = BinaryWriter::new(&mut buffer);
+        for length in &[1, 14, 15, 130, 16_385] {
+            writer.write_nop_pad(*length)?;
+        }
+        writer.write_i64(1)?;
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("foo");
+        writer.write_nop_pad(2)?;
+        writer.write_nop_pad(131)?;
+        writer.write_i64(2)?;
+        assert!(writer.write_nop_pad(1).is_err());
+        writer.step_out()?;
+        writer.step_in(IonType::List)?;
+        writer.write_nop_pad(3)?;
+        writer.write_i64(3)?;
+        writer.step_out()?;
+        writer.set_annotations(&["foo"]);
+        assert!(writer.write_nop_pad(2).is_err());
+        writer.flush()?;
+        drop(writer);
+
+        // The padding accounts for the bulk of the stream, but readers skip over all of it.
+        assert!(buffer.len() > 1 + 14 + 15 + 130 + 16_385 + 2 + 131 + 3);
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(1));
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("foo"));
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(3));
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_stats() -> IonResult<()> {
+        let mut buffer = Vec::new();
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        writer.set_annotations(&["point"]).write_struct(|s| {
+            s.set_field_name("x").write_i64(1)?;
+            s.set_field_name("label").write_string("hello")?;
+            s.set_field_name("kind").write_symbol("point")
+        })?;
+        writer.write_null(IonType::String)?;
+        // Symbol tables are only counted once they have been written
+        assert_eq!(writer.stats().symbol_table_bytes(), 0);
+        writer.flush()?;
+
+        let stats = writer.stats().clone();
+        drop(writer);
+        // `point` was interned twice
+        assert_eq!(stats.symbols_interned(), 4);
+        assert_eq!(stats.symbol_references(), 5);
+        assert_eq!(stats.field_id_bytes(), 3);
+        // The wrapper's 2-byte header, the annotations' length and the annotation `point`
+        assert_eq!(stats.annotation_bytes(), 4);
+        assert_eq!(stats.value_count(), 5);
+        let values = |ion_type| {
+            let values = stats.values(ion_type);
+            (values.count, values.encoded_bytes)
+        };
+        // The struct's size includes its header and fields.
+        assert_eq!(values(IonType::Struct), (1, 14));
+        assert_eq!(values(IonType::Integer), (1, 2));
+        assert_eq!(values(IonType::String), (2, 7));
+        assert_eq!(values(IonType::Symbol), (1, 2));
+        assert_eq!(values(IonType::Blob), (0, 0));
+        // Everything but the IVM, the annotated struct and the null is the symbol table.
+        assert_eq!(stats.symbol_table_bytes(), buffer.len() - 4 - 18 - 1);
+        Ok(())
+    }
+
+    #[test]
+    fn binary_writer_flush_to() -> IonResult<()> {
+        use crate::value::SymbolToken;
+
+        let read_strings = |data: &[u8]| -> IonResult<Vec<String>> {
+            let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data)));
+            let mut strings = Vec::new();
+            while reader.next()?.is_some() {
+                strings.push(reader.read_symbol()?.unwrap().text().unwrap().to_string());
+            }
+            Ok(strings)
+        };
+
+        // Draining a writer in chunks produces a single stream
+        let mut file = Vec::new();
+        let mut writer = BinaryWriter::new(Vec::new());
+        writer.write_symbol("foo")?;
+        assert!(writer.flush_to(&mut file)? > 0);
+        assert!(writer.output().is_empty());
+        writer.write_symbol("foo")?;
+        writer.write_symbol("bar")?;
+        writer.flush_to(&mut file)?;
+        assert_eq!(read_strings(&file)?, vec!["foo", "foo", "bar"]);
+
+        // Each message drained from a caller-owned buffer is a stream of its own
+        let mut buffer = Vec::with_capacity(64);
+        let mut writer = BinaryWriter::new(&mut buffer);
+        let mut messages: Vec<Vec<u8>> = Vec::new();
+        for text in &["foo", "bar", "foo"] {
+            writer.write_symbol(text)?;
+            let mut message = Vec::new();
+            writer.flush_message_to(&mut message)?;
+            messages.push(message);
+        }
+        drop(writer);
+        assert!(buffer.is_empty());
+        assert!(buffer.capacity() >= 64);
+        assert_eq!(messages[0], messages[2]);
+        for (message, text) in messages.iter().zip(&["foo", "bar", "foo"]) {
+            assert_eq!(read_strings(message)?, vec![*text]);
+        }
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::insertion(StructFieldOrder::Insertion, &["b", "a", "c"])]
+    #[case::text(StructFieldOrder::Text, &["a", "b", "c"])]
+    // `c` and `a` were interned by the preceding list
+    #[case::symbol_id(StructFieldOrder::SymbolId, &["c", "a", "b"])]
+    fn binary_writer_struct_field_order(
+        #[case] order: StructFieldOrder,
+        #[case] expected: &[&str],
+    ) -> IonResult<()> {
+        use crate::value::owned::OwnedElement;
+
+        let mut elements = OwnedElement::read_all(b"[c, a] {b: 1, a: 2, c: 3}")?;
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriter::new(&mut buffer);
+        assert_eq!(writer.struct_field_order(), StructFieldOrder::Insertion);
+        writer.set_struct_field_order(order);
+        for element in &elements {
+            writer.write_element(element)?;
+        }
+        writer.flush()?;
+        drop(writer);
+
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        reader.next()?;
+        reader.next()?;
+        reader.step_in()?;
+        let mut field_names = Vec::new();
+        while reader.next()?.is_some() {
+            field_names.push(reader.field_name().unwrap().to_string());
+        }
+        reader.step_out()?;
+        assert_eq!(field_names, expected);
+
+        // Field order is not significant when comparing the struct read back
+        reader.seek_to_offset(0)?;
+        reader.next()?;
+        reader.next()?;
+        assert_eq!(reader.read_element()?, elements.pop());
+        Ok(())
+    }
 }
@@ -0,0 +1,347 @@
+use std::collections::{BTreeMap, HashMap};
+
+use crate::result::{decoding_error, IonResult};
+use crate::value::owned::{text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedValue};
+use crate::value::{Element, IntAccess, Sequence, Struct, SymbolToken};
+
+/// A named, versioned list of symbols that streams can import into their local symbol tables
+/// instead of declaring each symbol themselves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SharedSymbolTable {
+    name: String,
+    version: usize,
+    symbols: Vec<String>,
+}
+
+impl SharedSymbolTable {
+    pub fn new<S: Into<String>>(name: S, version: usize, symbols: Vec<String>) -> Self {
+        SharedSymbolTable {
+            name: name.into(),
+            version,
+            symbols,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Returns the symbols defined by this table, in the order in which they are imported.
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// Reads a table from its serialized form: a struct annotated with
+    /// `$ion_shared_symbol_table` whose `name`, `version`, `imports` and `symbols` fields
+    /// describe the table. Any tables that it imports are looked up in `catalog`; their symbols
+    /// precede the table's own.
+    ///
+    /// Every entry in `symbols` must be a string. Symbols with unknown text are not supported.
+    pub fn from_element(
+        element: &OwnedElement,
+        catalog: &dyn Catalog,
+    ) -> IonResult<SharedSymbolTable> {
+        let is_shared_table = element
+            .annotations()
+            .next()
+            .map(|annotation| annotation.text() == Some("$ion_shared_symbol_table"))
+            .unwrap_or(false);
+        let fields = match element.as_struct() {
+            Some(fields) if is_shared_table => fields,
+            _ => {
+                return decoding_error(format!(
+                    "Expected a struct annotated with $ion_shared_symbol_table, found: {}",
+                    element
+                ))
+            }
+        };
+
+        let name = match fields.get("name").map(|name| name.value()) {
+            Some(OwnedValue::String(name)) if !name.is_empty() => name.clone(),
+            _ => return decoding_error("A shared symbol table must have a non-empty name."),
+        };
+        let version = match fields.get("version").map(|version| version.value()) {
+            Some(OwnedValue::Integer(version)) => version.as_i64(),
+            _ => None,
+        };
+        let version = match version {
+            Some(version) if version >= 1 => version as usize,
+            _ => 1,
+        };
+
+        let mut symbols = vec![];
+        if let Some(OwnedValue::List(imports)) = fields.get("imports").map(|i| i.value()) {
+            for import in imports.iter() {
+                // Per the spec, imports that are not structs are ignored.
+                let import = match import.value() {
+                    OwnedValue::Struct(import) => import,
+                    _ => continue,
+                };
+                let import_name = match import.get("name").map(|name| name.value()) {
+                    Some(OwnedValue::String(name)) if name != "$ion" => name,
+                    _ => continue,
+                };
+                let import_version = match import.get("version").map(|v| v.value()) {
+                    Some(OwnedValue::Integer(version)) => version.as_i64(),
+                    _ => None,
+                };
+                let import_version = match import_version {
+                    Some(version) if version >= 1 => version as usize,
+                    _ => 1,
+                };
+                let max_id = match import.get("max_id").map(|max_id| max_id.value()) {
+                    Some(OwnedValue::Integer(max_id)) => max_id.as_i64(),
+                    _ => None,
+                };
+                symbols.extend_from_slice(resolve_import(
+                    catalog,
+                    import_name,
+                    import_version,
+                    max_id,
+                )?);
+            }
+        }
+
+        if let Some(OwnedValue::List(declared)) = fields.get("symbols").map(|s| s.value()) {
+            for symbol in declared.iter() {
+                match symbol.value() {
+                    OwnedValue::String(text) => symbols.push(text.clone()),
+                    _ => {
+                        return decoding_error(format!(
+                            "Shared symbol table '{}' declares a symbol that is not a string: {}",
+                            name, symbol
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(SharedSymbolTable::new(name, version, symbols))
+    }
+
+    /// Returns the serialized form of this table, which can be written with an
+    /// [ElementWriter](crate::value::writer::ElementWriter) or formatted as Ion text with
+    /// `to_string()`. The table's symbols are all written in its `symbols` field, so the element
+    /// does not import any other tables.
+    pub fn to_element(&self) -> OwnedElement {
+        let symbols: OwnedSequence = self
+            .symbols
+            .iter()
+            .map(|symbol| OwnedElement::from(symbol.clone()))
+            .collect();
+        let fields: OwnedStruct = vec![
+            ("name", OwnedElement::from(self.name.clone())),
+            ("version", OwnedElement::from(self.version as i64)),
+            ("symbols", OwnedValue::List(symbols).into()),
+        ]
+        .into_iter()
+        .collect();
+        OwnedElement::from(fields).with_annotations(vec![text_token("$ion_shared_symbol_table")])
+    }
+}
+
+// Looks up the table that an import with the given name, version and `max_id` refers to, returning
+// the symbols that it imports. If the requested version isn't in the catalog, the spec allows the
+// highest version in the catalog to be used instead as long as the import specifies a `max_id`.
+pub(crate) fn resolve_import<'a>(
+    catalog: &'a dyn Catalog,
+    name: &str,
+    version: usize,
+    max_id: Option<i64>,
+) -> IonResult<&'a [String]> {
+    let table = match catalog
+        .get_table_with_version(name, version)
+        .or_else(|| catalog.get_table(name))
+    {
+        Some(table) => table,
+        None => {
+            return decoding_error(format!(
+                "Cannot import version {} of shared symbol table '{}': it is not in \
+                the catalog.",
+                version, name
+            ))
+        }
+    };
+    let max_id = match max_id {
+        Some(max_id) if max_id >= 0 => max_id as usize,
+        _ if table.version() == version => table.symbols().len(),
+        _ => {
+            return decoding_error(format!(
+                "The import of version {} of shared symbol table '{}' has no max_id, \
+                but the catalog only contains version {}.",
+                version,
+                name,
+                table.version()
+            ))
+        }
+    };
+    if max_id > table.symbols().len() {
+        return decoding_error(format!(
+            "The import of shared symbol table '{}' declares a max_id of {}, but \
+            version {} in the catalog only defines {} symbols.",
+            name,
+            max_id,
+            table.version(),
+            table.symbols().len()
+        ));
+    }
+    Ok(&table.symbols()[..max_id])
+}
+
+/// A collection of [SharedSymbolTable]s that a [Reader](crate::Reader) can consult when a local
+/// symbol table imports a shared table by name.
+pub trait Catalog {
+    /// Returns the table with the given name and exact version, if the catalog contains it.
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable>;
+
+    /// Returns the table with the given name and the highest version in the catalog.
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable>;
+}
+
+/// An in-memory [Catalog] of the tables that have been added to it with
+/// [insert_table](MapCatalog::insert_table).
+#[derive(Clone, Debug, Default)]
+pub struct MapCatalog {
+    tables_by_name: HashMap<String, BTreeMap<usize, SharedSymbolTable>>,
+}
+
+impl MapCatalog {
+    pub fn new() -> Self {
+        MapCatalog::default()
+    }
+
+    /// Adds `table` to the catalog, replacing any table that has the same name and version.
+    pub fn insert_table(&mut self, table: SharedSymbolTable) {
+        self.tables_by_name
+            .entry(table.name.clone())
+            .or_default()
+            .insert(table.version, table);
+    }
+
+    /// Reads each of the serialized shared symbol tables in `data`, which may be either text or
+    /// binary Ion, and adds them to the catalog. Tables may import tables that are already in the
+    /// catalog, including those that appear earlier in `data`.
+    pub fn insert_tables_from(&mut self, data: &[u8]) -> IonResult<()> {
+        for element in OwnedElement::read_all(data)? {
+            let table = SharedSymbolTable::from_element(&element, &*self)?;
+            self.insert_table(table);
+        }
+        Ok(())
+    }
+}
+
+impl Catalog for MapCatalog {
+    fn get_table_with_version(&self, name: &str, version: usize) -> Option<&SharedSymbolTable> {
+        self.tables_by_name.get(name)?.get(&version)
+    }
+
+    fn get_table(&self, name: &str) -> Option<&SharedSymbolTable> {
+        self.tables_by_name.get(name)?.values().next_back()
+    }
+}
+
+#[cfg(test)]
+mod catalog_tests {
+    use super::*;
+
+    fn table(name: &str, version: usize) -> SharedSymbolTable {
+        SharedSymbolTable::new(name, version, vec![format!("{}_{}", name, version)])
+    }
+
+    #[test]
+    fn map_catalog_lookups() {
+        let mut catalog = MapCatalog::new();
+        catalog.insert_table(table("foo", 2));
+        catalog.insert_table(table("foo", 1));
+        catalog.insert_table(table("bar", 1));
+
+        assert_eq!(
+            Some(&table("foo", 1)),
+            catalog.get_table_with_version("foo", 1)
+        );
+        assert_eq!(Some(&table("foo", 2)), catalog.get_table("foo"));
+        assert_eq!(None, catalog.get_table_with_version("foo", 3));
+        assert_eq!(None, catalog.get_table("baz"));
+    }
+
+    #[test]
+    fn shared_symbol_table_round_trip() -> IonResult<()> {
+        let table = SharedSymbolTable::new(
+            "greetings",
+            2,
+            vec!["hello".to_string(), "goodbye".to_string()],
+        );
+        let text = table.to_element().to_string();
+        let elements = OwnedElement::read_all(text.as_bytes())?;
+        assert_eq!(1, elements.len());
+        assert_eq!(
+            table,
+            SharedSymbolTable::from_element(&elements[0], &MapCatalog::new())?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn insert_tables_from_resolves_imports() -> IonResult<()> {
+        let data = br#"
+            $ion_shared_symbol_table::{
+                name: "greetings",
+                symbols: ["hello", "goodbye"],
+            }
+            $ion_shared_symbol_table::{
+                name: "farewells",
+                version: 3,
+                imports: [{name: "greetings", version: 1, max_id: 1}, {name: "$ion"}],
+                symbols: ["bye", "ciao"],
+            }
+        "#;
+        let mut catalog = MapCatalog::new();
+        catalog.insert_tables_from(data)?;
+        assert_eq!(
+            Some(&SharedSymbolTable::new(
+                "greetings",
+                1,
+                vec!["hello".to_string(), "goodbye".to_string()]
+            )),
+            catalog.get_table("greetings")
+        );
+        assert_eq!(
+            Some(&SharedSymbolTable::new(
+                "farewells",
+                3,
+                vec!["hello".to_string(), "bye".to_string(), "ciao".to_string()]
+            )),
+            catalog.get_table_with_version("farewells", 3)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_shared_symbol_tables() {
+        let catalog = MapCatalog::new();
+        let read = |text: &str| OwnedElement::read_all(text.as_bytes()).unwrap().remove(0);
+        let elements = vec![
+            read(r#"{name: "greetings", symbols: ["hello"]}"#),
+            // A reader would consume a top-level local symbol table, so the annotation is added
+            // after reading.
+            read(r#"{name: "greetings", symbols: ["hello"]}"#)
+                .with_annotations(vec!["$ion_symbol_table"]),
+            read(r#"$ion_shared_symbol_table::{symbols: ["hello"]}"#),
+            read(r#"$ion_shared_symbol_table::{name: "greetings", symbols: [hello]}"#),
+            read(
+                r#"$ion_shared_symbol_table::{name: "farewells", imports: [{name: "greetings"}]}"#,
+            ),
+        ];
+        for element in &elements {
+            assert!(
+                SharedSymbolTable::from_element(element, &catalog).is_err(),
+                "{}",
+                element
+            );
+        }
+    }
+}
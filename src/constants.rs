@@ -1,4 +1,7 @@
-pub(crate) mod v1_0 {
+/// Constants defined by the Ion 1.0 specification.
+pub mod v1_0 {
+    /// The text of the Ion 1.0 system symbols, indexed by symbol ID. Symbol ID 0 has no text;
+    /// its entry is a placeholder.
     pub const SYSTEM_SYMBOLS: &[&str] = &[
         "$0",                       // $0
         "$ion",                     // $1
@@ -12,7 +15,8 @@ pub(crate) mod v1_0 {
         "$ion_shared_symbol_table", // $9
     ];
 
-    pub(crate) mod system_symbol_ids {
+    /// The symbol IDs of the Ion 1.0 system symbols.
+    pub mod system_symbol_ids {
         pub const ION: usize = 1;
         pub const ION_1_0: usize = 2;
         pub const ION_SYMBOL_TABLE: usize = 3;
@@ -0,0 +1,451 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Converts Rust types to and from Ion without going through serde's data model.
+//!
+//! Types implement [`IonSerialize`] and [`IonDeserialize`] by converting themselves to and from
+//! an [`OwnedElement`], which can then be written with an
+//! [`ElementWriter`](crate::value::writer::ElementWriter), formatted as Ion text with
+//! `to_string()`, or read with [`Reader::read_element`](crate::Reader::read_element). Unlike
+//! serde, an element can carry annotations, distinguish symbols from strings and record a
+//! timestamp's precision.
+//!
+//! With the `derive` feature, both traits can be derived for structs with named fields. Each
+//! field is converted to and from the struct field of the same name, and the following
+//! attributes adjust the mapping:
+//!
+//! * `#[ion(annotation = "...")]` on the struct or on a field annotates the struct or the field's
+//!   value. It can be repeated. When deserializing, every annotation must be present.
+//! * `#[ion(rename = "...")]` on a field uses a different Ion field name.
+//! * `#[ion(symbol)]` on a `String` field writes it as a symbol instead of a string. When
+//!   deserializing, the value must be a symbol.
+//! * `#[ion(precision = "...")]` on a timestamp field writes the timestamp at no finer than the
+//!   given precision: one of `year`, `month`, `day`, `minute`, `second` or `fractional_seconds`.
+//!
+//! Fields of type `Option<T>` are written as `null` when they are `None`, and are `None` when the
+//! Ion field is missing or null.
+//!
+//! ```
+//! # #[cfg(feature = "derive")]
+//! # fn main() -> ion_rs::result::IonResult<()> {
+//! use ion_rs::convert::{IonDeserialize, IonSerialize};
+//! use ion_rs::types::timestamp::Timestamp;
+//!
+//! #[derive(Debug, PartialEq, IonSerialize, IonDeserialize)]
+//! #[ion(annotation = "event")]
+//! struct Event {
+//!     #[ion(symbol)]
+//!     kind: String,
+//!     #[ion(rename = "at", precision = "day")]
+//!     time: Timestamp,
+//!     detail: Option<String>,
+//! }
+//!
+//! let event = Event {
+//!     kind: "deploy".to_string(),
+//!     time: Timestamp::with_ymd(2022, 1, 2).build()?,
+//!     detail: None,
+//! };
+//! // event::{kind: deploy, at: 2022-01-02T, detail: null}
+//! let element = event.to_ion();
+//! assert_eq!(event, Event::from_ion(&element)?);
+//! let text = element.to_string();
+//! let elements = ion_rs::value::owned::OwnedElement::read_all(text.as_bytes())?;
+//! assert_eq!(event, Event::from_ion(&elements[0])?);
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "derive"))]
+//! # fn main() {}
+//! ```
+
+use std::convert::TryFrom;
+
+use chrono::{DateTime, FixedOffset};
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::result::{decoding_error, IonResult};
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::{Precision, Timestamp};
+use crate::value::owned::{text_token, OwnedElement, OwnedStruct, OwnedValue};
+use crate::value::{AnyInt, Element, Sequence, Struct, SymbolToken};
+use crate::IonType;
+
+#[cfg(feature = "derive")]
+pub use ion_rs_derive::{IonDeserialize, IonSerialize};
+
+/// A type that can be converted to an Ion [`OwnedElement`].
+pub trait IonSerialize {
+    fn to_ion(&self) -> OwnedElement;
+}
+
+/// A type that can be constructed from an Ion [`OwnedElement`].
+pub trait IonDeserialize: Sized {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self>;
+
+    /// Returns the value of a struct field of this type that is missing from the Ion struct being
+    /// deserialized. By default, missing fields are an error.
+    fn from_missing_field(name: &str) -> IonResult<Self> {
+        decoding_error(format!("The struct has no field named '{}'.", name))
+    }
+}
+
+impl IonSerialize for OwnedElement {
+    fn to_ion(&self) -> OwnedElement {
+        self.clone()
+    }
+}
+
+impl IonDeserialize for OwnedElement {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        Ok(element.clone())
+    }
+}
+
+impl IonSerialize for bool {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedElement::from(*self)
+    }
+}
+
+impl IonDeserialize for bool {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        match element.value() {
+            OwnedValue::Boolean(value) => Ok(*value),
+            _ => expected("a bool", element),
+        }
+    }
+}
+
+macro_rules! impl_integer_conversions {
+    ($($int_type:ty),*) => {$(
+        impl IonSerialize for $int_type {
+            fn to_ion(&self) -> OwnedElement {
+                match i64::try_from(*self) {
+                    Ok(value) => OwnedElement::from(value),
+                    Err(_) => OwnedElement::from(BigInt::from(*self)),
+                }
+            }
+        }
+
+        impl IonDeserialize for $int_type {
+            fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+                let value = match element.value() {
+                    OwnedValue::Integer(AnyInt::I64(value)) => {
+                        <$int_type>::try_from(*value).ok()
+                    }
+                    OwnedValue::Integer(AnyInt::BigInt(value)) => value
+                        .to_i128()
+                        .and_then(|value| <$int_type>::try_from(value).ok()),
+                    _ => return expected("an integer", element),
+                };
+                match value {
+                    Some(value) => Ok(value),
+                    None => expected(
+                        concat!("an integer in the range of ", stringify!($int_type)),
+                        element,
+                    ),
+                }
+            }
+        }
+    )*};
+}
+
+impl_integer_conversions!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl IonSerialize for f32 {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedElement::from(*self as f64)
+    }
+}
+
+impl IonDeserialize for f32 {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        f64::from_ion(element).map(|value| value as f32)
+    }
+}
+
+impl IonSerialize for f64 {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedElement::from(*self)
+    }
+}
+
+impl IonDeserialize for f64 {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        match element.value() {
+            OwnedValue::Float(value) => Ok(*value),
+            _ => expected("a float", element),
+        }
+    }
+}
+
+impl IonSerialize for Decimal {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedElement::from(self.clone())
+    }
+}
+
+impl IonDeserialize for Decimal {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        match element.value() {
+            OwnedValue::Decimal(value) => Ok(value.clone()),
+            _ => expected("a decimal", element),
+        }
+    }
+}
+
+impl IonSerialize for String {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedElement::from(self.clone())
+    }
+}
+
+impl IonDeserialize for String {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        match element.value() {
+            OwnedValue::String(value) => Ok(value.clone()),
+            _ => expected("a string", element),
+        }
+    }
+}
+
+impl IonSerialize for Timestamp {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedElement::from(self.clone())
+    }
+}
+
+impl IonDeserialize for Timestamp {
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        match element.value() {
+            OwnedValue::Timestamp(value) => Ok(value.clone()),
+            _ => expected("a timestamp", element),
+        }
+    }
+}
+
+impl IonSerialize for DateTime<FixedOffset> {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedElement::from(Timestamp::from(*self))
+    }
+}
+
+impl IonDeserialize for DateTime<FixedOffset> {
+    /// Reads a timestamp with a known offset and at least [Precision::HourAndMinute].
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        match Timestamp::from_ion(element)?.to_chrono_datetime() {
+            Some(value) => Ok(value),
+            None => expected("a timestamp with a known offset and time", element),
+        }
+    }
+}
+
+impl<T: IonSerialize> IonSerialize for Vec<T> {
+    fn to_ion(&self) -> OwnedElement {
+        OwnedValue::List(self.iter().map(T::to_ion).collect()).into()
+    }
+}
+
+impl<T: IonDeserialize> IonDeserialize for Vec<T> {
+    /// Reads each of the values in a list or s-expression.
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        match element.value() {
+            OwnedValue::List(values) | OwnedValue::SExpression(values) => {
+                values.iter().map(T::from_ion).collect()
+            }
+            _ => expected("a list", element),
+        }
+    }
+}
+
+impl<T: IonSerialize> IonSerialize for Option<T> {
+    /// Converts `None` to an untyped `null`.
+    fn to_ion(&self) -> OwnedElement {
+        match self {
+            Some(value) => value.to_ion(),
+            None => OwnedElement::null(IonType::Null),
+        }
+    }
+}
+
+impl<T: IonDeserialize> IonDeserialize for Option<T> {
+    /// Converts a null of any type to `None`.
+    fn from_ion(element: &OwnedElement) -> IonResult<Self> {
+        if element.is_null() {
+            return Ok(None);
+        }
+        T::from_ion(element).map(Some)
+    }
+
+    fn from_missing_field(_name: &str) -> IonResult<Self> {
+        Ok(None)
+    }
+}
+
+fn expected<T>(description: &str, element: &OwnedElement) -> IonResult<T> {
+    decoding_error(format!("Expected {}, found: {}", description, element))
+}
+
+// The functions below are called by the code generated by `#[derive(IonSerialize)]` and
+// `#[derive(IonDeserialize)]`. They are public so that the generated code can call them, but they
+// are not part of the crate's API.
+
+#[doc(hidden)]
+pub fn annotate(element: OwnedElement, annotations: &[&str]) -> OwnedElement {
+    if annotations.is_empty() {
+        return element;
+    }
+    let (mut existing, value) = element.into_parts();
+    existing.extend(annotations.iter().map(|annotation| text_token(*annotation)));
+    OwnedElement::new(existing, value)
+}
+
+#[doc(hidden)]
+pub fn expect_annotations(element: &OwnedElement, annotations: &[&str]) -> IonResult<()> {
+    for annotation in annotations {
+        if !element.has_annotation(annotation) {
+            return decoding_error(format!(
+                "Expected a value annotated with '{}', found: {}",
+                annotation, element
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[doc(hidden)]
+pub fn symbol_to_ion<S: AsRef<str>>(text: S) -> OwnedElement {
+    OwnedElement::from(text_token(text.as_ref()))
+}
+
+#[doc(hidden)]
+pub fn symbol_from_ion(element: &OwnedElement) -> IonResult<String> {
+    match element.as_sym().and_then(|symbol| symbol.text()) {
+        Some(text) => Ok(text.to_string()),
+        None => expected("a symbol with known text", element),
+    }
+}
+
+#[doc(hidden)]
+pub fn truncate_timestamp(element: OwnedElement, precision: Precision) -> OwnedElement {
+    let truncated = match element.value() {
+        // Truncating a valid timestamp always produces a valid date and time.
+        OwnedValue::Timestamp(timestamp) if timestamp.precision() > precision => Some(
+            timestamp
+                .truncated_to(precision)
+                .expect("truncating a timestamp produced an invalid one"),
+        ),
+        _ => None,
+    };
+    match truncated {
+        Some(timestamp) => {
+            let (annotations, _) = element.into_parts();
+            OwnedElement::new(annotations, OwnedValue::Timestamp(timestamp))
+        }
+        None => element,
+    }
+}
+
+#[doc(hidden)]
+pub fn struct_to_ion(fields: Vec<(&str, OwnedElement)>) -> OwnedElement {
+    OwnedElement::from(fields.into_iter().collect::<OwnedStruct>())
+}
+
+#[doc(hidden)]
+pub fn struct_fields(element: &OwnedElement) -> IonResult<&OwnedStruct> {
+    match element.as_struct() {
+        Some(fields) => Ok(fields),
+        None => expected("a struct", element),
+    }
+}
+
+#[doc(hidden)]
+pub fn field_from_ion<T, F>(fields: &OwnedStruct, name: &str, from_ion: F) -> IonResult<T>
+where
+    T: IonDeserialize,
+    F: FnOnce(&OwnedElement) -> IonResult<T>,
+{
+    match fields.get(name) {
+        Some(value) => from_ion(value),
+        None => T::from_missing_field(name),
+    }
+}
+
+#[cfg(test)]
+mod convert_tests {
+    use super::*;
+    use crate::ion;
+    use crate::value::Builder;
+    use rstest::*;
+
+    fn round_trip<T>(value: T, text: &str) -> IonResult<()>
+    where
+        T: IonSerialize + IonDeserialize + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(ion!(text), value.to_ion());
+        assert_eq!(value, T::from_ion(&ion!(text))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trips() -> IonResult<()> {
+        round_trip(true, "true")?;
+        round_trip(-5i8, "-5")?;
+        round_trip(u64::MAX, "18446744073709551615")?;
+        round_trip(1.5f64, "1.5e0")?;
+        round_trip(Decimal::new(15, -1), "1.5")?;
+        round_trip("foo".to_string(), "\"foo\"")?;
+        round_trip(Timestamp::with_year(2021).build()?, "2021T")?;
+        round_trip(vec![1u8, 2, 3], "[1, 2, 3]")?;
+        round_trip(Some(1i32), "1")?;
+        round_trip(None::<i32>, "null")?;
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::wrong_type("\"foo\"")]
+    #[case::out_of_range("256")]
+    #[case::negative("-1")]
+    #[case::null("null.int")]
+    fn test_u8_from_ion_errors(#[case] text: &str) {
+        assert!(u8::from_ion(&ion!(text)).is_err());
+    }
+
+    #[test]
+    fn test_missing_fields() -> IonResult<()> {
+        let fields: OwnedStruct = vec![("foo", OwnedElement::from(1i64))]
+            .into_iter()
+            .collect();
+        assert_eq!(1, field_from_ion(&fields, "foo", i64::from_ion)?);
+        assert_eq!(
+            None,
+            field_from_ion(&fields, "bar", Option::<i64>::from_ion)?
+        );
+        assert!(field_from_ion(&fields, "bar", i64::from_ion).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbols_and_annotations() -> IonResult<()> {
+        let symbol = annotate(symbol_to_ion("foo"), &["a", "b"]);
+        assert_eq!(ion!("a::b::foo"), symbol);
+        assert_eq!("foo", symbol_from_ion(&symbol)?);
+        expect_annotations(&symbol, &["b"])?;
+        assert!(expect_annotations(&symbol, &["c"]).is_err());
+        assert!(symbol_from_ion(&OwnedElement::new_string("foo")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncate_timestamp() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 2, 3)
+            .with_hms(4, 5, 6)
+            .build_at_offset(0)?;
+        let truncated = truncate_timestamp(timestamp.to_ion(), Precision::Day);
+        assert_eq!(ion!("2021-02-03T"), truncated);
+        // Timestamps are never made more precise.
+        let truncated = truncate_timestamp(truncated, Precision::Second);
+        assert_eq!(ion!("2021-02-03T"), truncated);
+        Ok(())
+    }
+}
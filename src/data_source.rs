@@ -1,6 +1,8 @@
-use std::io::BufRead;
+use crate::io;
+use crate::io::BufRead;
+use alloc::vec::Vec;
 
-use crate::result::{decoding_error, IonError, IonResult};
+use crate::result::{incomplete_data_error, IonError, IonResult};
 
 /// Optimized read operations for parsing Ion.
 ///
@@ -38,16 +40,36 @@ pub trait IonDataSource: BufRead {
     /// into `fallback_buffer` and that will be used instead. If `fallback_buffer` does not have
     /// enough capacity to store the requested data, it will be resized. It will never be shrunk,
     /// however--it is the caller's responsibility to manage this memory.
-    fn read_slice<T, F>(
+    fn read_slice<T, B, F>(
         &mut self,
         length: usize,
-        fallback_buffer: &mut Vec<u8>,
+        fallback_buffer: &mut B,
         slice_processor: F,
     ) -> IonResult<T>
     where
+        B: FallbackBuffer + ?Sized,
         F: FnOnce(&[u8]) -> IonResult<T>;
 }
 
+/// A growable byte buffer that [IonDataSource::read_slice] can copy the requested bytes into when
+/// they are not available as a single slice of the input buffer. This is implemented for
+/// `Vec<u8>` and for the buffers that the binary reader obtains from a custom
+/// [Allocator](crate::allocator::Allocator).
+pub trait FallbackBuffer {
+    /// Returns a slice of exactly `length` bytes from the start of the buffer, growing the buffer
+    /// (and zero-filling the new space) if it is too short. The buffer is never shrunk.
+    fn slice_of_len(&mut self, length: usize) -> &mut [u8];
+}
+
+impl FallbackBuffer for Vec<u8> {
+    fn slice_of_len(&mut self, length: usize) -> &mut [u8] {
+        if self.len() < length {
+            self.resize(length, 0);
+        }
+        &mut self[..length]
+    }
+}
+
 // Allows all implementations of `BufRead` to be used as an IonDataSource, including BufReader
 // and io::Cursor.
 impl<T: BufRead> IonDataSource for T {
@@ -58,9 +80,7 @@ impl<T: BufRead> IonDataSource for T {
         while bytes_skipped < number_of_bytes {
             let buffer = self.fill_buf()?;
             if buffer.is_empty() {
-                // TODO: IonResult should have a distinct `IncompleteData` error case
-                //       https://github.com/amzn/ion-rust/issues/299
-                return decoding_error("Unexpected end of stream.");
+                return incomplete_data_error("Unexpected end of stream.");
             }
             let bytes_in_buffer = buffer.len();
             let bytes_to_skip = (number_of_bytes - bytes_skipped).min(bytes_in_buffer);
@@ -117,9 +137,7 @@ impl<T: BufRead> IonDataSource for T {
             number_of_buffered_bytes = buffer.len();
 
             if number_of_buffered_bytes == 0 {
-                // TODO: IonResult should have a distinct `IncompleteData` error case
-                //       https://github.com/amzn/ion-rust/issues/299
-                return decoding_error("Unexpected end of stream.");
+                return incomplete_data_error("Unexpected end of stream.");
             }
 
             // Iterate over the bytes already in the buffer, calling the provided lambda on each
@@ -144,13 +162,14 @@ impl<T: BufRead> IonDataSource for T {
     // Like `read_next_byte_while`, this method will prefer to process the next `number_of_bytes`
     // bytes without copying them out of the input buffer. It can be used to process any Ion value
     // of a known size.
-    fn read_slice<V, F>(
+    fn read_slice<V, B, F>(
         &mut self,
         number_of_bytes: usize,
-        fallback_buffer: &mut Vec<u8>,
+        fallback_buffer: &mut B,
         slice_processor: F,
     ) -> IonResult<V>
     where
+        B: FallbackBuffer + ?Sized,
         F: FnOnce(&[u8]) -> IonResult<V>,
     {
         // Get a reference to the data source's input buffer, refilling it if it's empty.
@@ -158,9 +177,7 @@ impl<T: BufRead> IonDataSource for T {
 
         // If the buffer is still empty, we've run out of data.
         if buffer.is_empty() && number_of_bytes > 0 {
-            // TODO: IonResult should have a distinct `IncompleteData` error case
-            //       https://github.com/amzn/ion-rust/issues/299
-            return decoding_error("Unexpected end of stream.");
+            return incomplete_data_error("Unexpected end of stream.");
         }
 
         // If the requested value is already in our input buffer, there's no need to copy it out
@@ -172,36 +189,180 @@ impl<T: BufRead> IonDataSource for T {
             return result;
         }
 
-        // Grow the Vec to accommodate the requested data if needed
-        let buffer: &mut [u8] = if fallback_buffer.len() < number_of_bytes {
-            fallback_buffer.resize(number_of_bytes, 0);
-            // Get a mutable reference to the underlying byte array
-            fallback_buffer.as_mut()
-        } else {
-            // Otherwise, split the Vec's underlying storage to get a &mut [u8] slice of the
-            // required size
-            let (required_buffer, _) = fallback_buffer.split_at_mut(number_of_bytes);
-            required_buffer
-        };
+        // Grow the fallback buffer to accommodate the requested data if needed and get a
+        // &mut [u8] slice of the required size
+        let buffer: &mut [u8] = fallback_buffer.slice_of_len(number_of_bytes);
 
         // Fill the fallback buffer with bytes from the data source
         match self.read_exact(buffer) {
             Ok(()) => slice_processor(buffer),
-            Err(ref e) if e.kind() == std::io::ErrorKind::UnexpectedEof =>
-            // TODO: IonResult should have a distinct `IncompleteData` error case
-            //       https://github.com/amzn/ion-rust/issues/299
-            {
-                decoding_error("Unexpected end of stream.")
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                incomplete_data_error("Unexpected end of stream.")
             }
             Err(io_error) => Err(IonError::IoError { source: io_error }),
         }
     }
 }
 
+/// Types that can be converted into an [IonDataSource].
+///
+/// Input that is already entirely in memory (`&[u8]`, `Vec<u8>`, [bytes::Bytes] and, with the
+/// `mmap` feature, [memmap2::Mmap]) is wrapped in an [io::Cursor] rather than a
+/// [std::io::BufReader]. A cursor's input buffer is the whole of the remaining input, so every
+/// read is served from a slice of the original bytes without first being copied into an
+/// intermediate buffer. Readers over a cursor also provide access to the raw bytes of each value;
+/// see [RawBinaryReader::raw_bytes](crate::RawBinaryReader::raw_bytes).
+///
+/// With the `std` feature, other input is wrapped in a [std::io::BufReader] if it does not
+/// already provide one.
+pub trait ToIonDataSource {
+    type DataSource: IonDataSource;
+
+    fn to_ion_data_source(self) -> Self::DataSource;
+}
+
+impl<'a> ToIonDataSource for &'a [u8] {
+    type DataSource = io::Cursor<&'a [u8]>;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        io::Cursor::new(self)
+    }
+}
+
+impl ToIonDataSource for Vec<u8> {
+    type DataSource = io::Cursor<Vec<u8>>;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        io::Cursor::new(self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl ToIonDataSource for bytes::Bytes {
+    type DataSource = io::Cursor<bytes::Bytes>;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        io::Cursor::new(self)
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl ToIonDataSource for memmap2::Mmap {
+    type DataSource = io::Cursor<memmap2::Mmap>;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        io::Cursor::new(self)
+    }
+}
+
+impl<T: AsRef<[u8]>> ToIonDataSource for io::Cursor<T> {
+    type DataSource = Self;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: io::Read> ToIonDataSource for std::io::BufReader<R> {
+    type DataSource = Self;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl ToIonDataSource for std::fs::File {
+    type DataSource = std::io::BufReader<std::fs::File>;
+
+    fn to_ion_data_source(self) -> Self::DataSource {
+        std::io::BufReader::new(self)
+    }
+}
+
+/// The magic bytes that begin a gzip stream.
+#[cfg(feature = "std")]
+const GZIP_MAGIC: [u8; 2] = [0x1F, 0x8B];
+/// The magic bytes that begin a zstd frame.
+#[cfg(feature = "std")]
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// Returns a [BufRead] over the decompressed contents of `input` if it begins with the magic bytes
+/// of a supported compression format, and over `input` as-is otherwise. Decompression happens
+/// incrementally as the returned reader is read, so a compressed archive never needs to be held
+/// in memory. The result can be used as the data source of any reader; see also
+/// [Reader::from_compressed_input](crate::Reader::from_compressed_input).
+///
+/// Gzip input requires the `gzip` feature and zstd input requires the `zstd` feature. Gzip
+/// input may contain several concatenated members, as produced by appending to a `.gz` log
+/// file; they are decompressed as one stream. If the input is compressed in a format whose
+/// feature is not enabled, a decoding error is returned instead of passing the compressed bytes
+/// through. Otherwise, an error is returned only if reading the magic bytes fails.
+#[cfg(feature = "std")]
+pub fn from_compressed_read<'a, R: io::Read + 'a>(
+    mut input: R,
+) -> IonResult<Box<dyn BufRead + 'a>> {
+    use std::io::Read;
+    let mut prefix = Vec::with_capacity(ZSTD_MAGIC.len());
+    (&mut input)
+        .take(ZSTD_MAGIC.len() as u64)
+        .read_to_end(&mut prefix)?;
+    let is_gzip = prefix.starts_with(&GZIP_MAGIC);
+    let is_zstd = prefix.starts_with(&ZSTD_MAGIC);
+    let input = io::Cursor::new(prefix).chain(input);
+    if is_gzip {
+        gzip_decoder(input)
+    } else if is_zstd {
+        zstd_decoder(input)
+    } else {
+        Ok(Box::new(std::io::BufReader::new(input)))
+    }
+}
+
+#[cfg(feature = "gzip")]
+fn gzip_decoder<'a, R: io::Read + 'a>(input: R) -> IonResult<Box<dyn BufRead + 'a>> {
+    let decoder = flate2::read::MultiGzDecoder::new(input);
+    Ok(Box::new(std::io::BufReader::new(decoder)))
+}
+
+#[cfg(all(feature = "std", not(feature = "gzip")))]
+fn gzip_decoder<'a, R: io::Read + 'a>(_input: R) -> IonResult<Box<dyn BufRead + 'a>> {
+    crate::result::decoding_error(
+        "The input is compressed with gzip, which requires the `gzip` feature.",
+    )
+}
+
+#[cfg(feature = "zstd")]
+fn zstd_decoder<'a, R: io::Read + 'a>(input: R) -> IonResult<Box<dyn BufRead + 'a>> {
+    let decoder = zstd::stream::read::Decoder::new(input)?;
+    Ok(Box::new(std::io::BufReader::new(decoder)))
+}
+
+#[cfg(all(feature = "std", not(feature = "zstd")))]
+fn zstd_decoder<'a, R: io::Read + 'a>(_input: R) -> IonResult<Box<dyn BufRead + 'a>> {
+    crate::result::decoding_error(
+        "The input is compressed with zstd, which requires the `zstd` feature.",
+    )
+}
+
+/// A data source for the [AsyncRawBinaryReader](crate::binary::async_reader::AsyncRawBinaryReader),
+/// which reads its input without blocking the current thread. This is implemented for every
+/// [tokio::io::AsyncRead] that is also `Unpin`, including tokio's `TcpStream` and `File`.
+#[cfg(feature = "tokio")]
+pub trait AsyncIonDataSource: tokio::io::AsyncRead + Unpin {}
+
+#[cfg(feature = "tokio")]
+impl<T: tokio::io::AsyncRead + Unpin> AsyncIonDataSource for T {}
+
 #[cfg(test)]
 mod tests {
-    use super::IonDataSource;
-    use crate::result::IonError;
+    use super::{from_compressed_read, IonDataSource, ToIonDataSource};
+    #[cfg(feature = "zstd")]
+    use crate::binary::writer::BinaryWriter;
+    use crate::result::{DecodingErrorCategory, IonResult};
+    #[cfg(any(feature = "gzip", feature = "zstd"))]
+    use crate::{IonType, Reader};
     use std::io::BufReader;
 
     fn test_data(buffer_size: usize, data: &'static [u8]) -> impl IonDataSource {
@@ -244,7 +405,7 @@ mod tests {
     #[test]
     fn test_read_slice() {
         let mut data_source = test_data(2, &[1, 2, 3, 4, 5]);
-        let processor = &mut |data: &[u8]| Ok(data.iter().map(|byte| *byte as i32).sum());
+        let processor = &mut |data: &[u8]| Ok(data.iter().map(|byte| *byte as i32).sum::<i32>());
         let sum = data_source
             .read_slice(4, &mut Vec::new(), processor)
             .unwrap();
@@ -259,9 +420,8 @@ mod tests {
         // we have in input.
         let result = data_source.skip_bytes(42);
 
-        // TODO: IonResult should have a distinct `IncompleteData` error case
-        //       https://github.com/amzn/ion-rust/issues/299
-        assert!(matches!(result, Err(IonError::DecodingError { .. })));
+        let error = result.unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::Incomplete));
     }
 
     #[test]
@@ -277,9 +437,8 @@ mod tests {
         // is satisfied.
         let result = data_source.read_next_byte_while(processor);
 
-        // TODO: IonResult should have a distinct `IncompleteData` error case
-        //       https://github.com/amzn/ion-rust/issues/299
-        assert!(matches!(result, Err(IonError::DecodingError { .. })));
+        let error = result.unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::Incomplete));
     }
 
     #[test]
@@ -297,8 +456,100 @@ mod tests {
             processor,
         );
 
-        // TODO: IonResult should have a distinct `IncompleteData` error case
-        //       https://github.com/amzn/ion-rust/issues/299
-        assert!(matches!(result, Err(IonError::DecodingError { .. })));
+        let error = result.unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::Incomplete));
+    }
+
+    #[test]
+    fn test_in_memory_data_sources_do_not_copy() {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5];
+        let address = data.as_ptr();
+        let mut data_source = data.to_ion_data_source();
+        data_source.skip_bytes(1).unwrap();
+        let mut fallback_buffer = vec![];
+        // The requested bytes are a slice of the original Vec rather than a copy.
+        data_source
+            .read_slice(4, &mut fallback_buffer, |bytes| {
+                assert_eq!(bytes, &[2, 3, 4, 5]);
+                assert_eq!(bytes.as_ptr(), address.wrapping_add(1));
+                Ok(())
+            })
+            .unwrap();
+        assert!(fallback_buffer.is_empty());
+
+        let mut data_source = bytes::Bytes::from_static(&[1, 2, 3]).to_ion_data_source();
+        assert_eq!(Some(1), data_source.next_byte().unwrap());
+        let mut data_source = (&[1u8, 2, 3][..]).to_ion_data_source();
+        data_source.skip_bytes(2).unwrap();
+        assert_eq!(Some(3), data_source.next_byte().unwrap());
+    }
+
+    fn read_all_from(input: &[u8]) -> IonResult<Vec<u8>> {
+        use std::io::Read;
+        let mut decompressed = vec![];
+        from_compressed_read(input)?.read_to_end(&mut decompressed)?;
+        Ok(decompressed)
+    }
+
+    #[test]
+    fn test_uncompressed_input_passes_through() -> IonResult<()> {
+        assert_eq!(read_all_from(b"{a: 1} {b: 2}")?, b"{a: 1} {b: 2}");
+        // Input shorter than the longest magic number
+        assert_eq!(read_all_from(&[0x1F])?, &[0x1F]);
+        assert_eq!(read_all_from(&[])?, b"");
+        Ok(())
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_gzip_input_is_decompressed() -> IonResult<()> {
+        use flate2::write::GzEncoder;
+        use flate2::Compression;
+        use std::io::Write;
+        // Two concatenated members, as produced by appending to a compressed log
+        let mut compressed = vec![];
+        for text in &["{a: 1} ", "{b: 2}"] {
+            let mut encoder = GzEncoder::new(vec![], Compression::default());
+            encoder.write_all(text.as_bytes())?;
+            compressed.extend_from_slice(&encoder.finish()?);
+        }
+        assert_eq!(read_all_from(&compressed)?, b"{a: 1} {b: 2}");
+
+        let mut reader = Reader::from_compressed_input(compressed.as_slice())?;
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "gzip"))]
+    #[test]
+    fn test_gzip_input_requires_feature() {
+        let error = read_all_from(&[0x1F, 0x8B, 0x08, 0x00]).unwrap_err();
+        assert!(error.to_string().contains("`gzip` feature"));
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_zstd_input_is_decompressed() -> IonResult<()> {
+        let mut binary = vec![];
+        let mut writer = BinaryWriter::new(&mut binary);
+        writer.write_string("hello")?;
+        writer.flush()?;
+        drop(writer);
+        let compressed = zstd::stream::encode_all(binary.as_slice(), 0)?;
+        assert_eq!(read_all_from(&compressed)?, binary);
+
+        let mut reader = Reader::from_compressed_input(compressed.as_slice())?;
+        assert_eq!(reader.next()?, Some((IonType::String, false)));
+        assert_eq!(reader.read_string()?, Some("hello".to_string()));
+        Ok(())
+    }
+
+    #[cfg(not(feature = "zstd"))]
+    #[test]
+    fn test_zstd_input_requires_feature() {
+        let error = read_all_from(&[0x28, 0xB5, 0x2F, 0xFD, 0x00]).unwrap_err();
+        assert!(error.to_string().contains("`zstd` feature"));
     }
 }
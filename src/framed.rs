@@ -0,0 +1,369 @@
+use std::io;
+use std::io::{Read, Write};
+
+use crate::binary::constants::v1_0::IVM;
+use crate::binary::writer::{BinarySystemWriter, BinaryWriter};
+use crate::constants::v1_0;
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::value::owned::OwnedElement;
+use crate::value::Element;
+use crate::{RawBinaryReader, Reader};
+
+// The number of bytes in the big-endian length that precedes each frame
+const FRAME_LENGTH_SIZE: usize = 4;
+
+/// Writes binary Ion messages to a byte stream, such as a network connection, each in a frame of
+/// its own: a four-byte, big-endian length followed by that many bytes of Ion.
+///
+/// By default, each frame is a complete Ion stream, beginning with an IVM and a local symbol
+/// table declaring only the symbols that its values use, so any frame can be read without the
+/// others. When most messages share the same field names and symbols, declaring them again in
+/// every frame is wasteful; [with_preamble](Self::with_preamble) declares them once in a
+/// preamble, which is written as the first frame, and the frames that follow only declare the
+/// symbols that the preamble does not. [FramedReader::with_preamble] reads such streams.
+///
+/// ```
+/// use ion_rs::{FramedReader, FramedWriter};
+/// use ion_rs::value::owned::OwnedElement;
+///
+/// let messages = OwnedElement::read_all(br#"{method: ping} {method: echo, args: ["hi"]}"#)?;
+/// let mut connection = Vec::new();
+/// let mut writer = FramedWriter::with_preamble(&mut connection, &["method", "args"]);
+/// for message in &messages {
+///     writer.write_element(message)?;
+/// }
+///
+/// let mut reader = FramedReader::with_preamble(connection.as_slice());
+/// assert_eq!(reader.read_message()?, Some(vec![messages[0].clone()]));
+/// assert_eq!(reader.read_message()?, Some(vec![messages[1].clone()]));
+/// assert_eq!(reader.read_message()?, None);
+/// # Ok::<(), ion_rs::result::IonError>(())
+/// ```
+#[derive(Debug)]
+pub struct FramedWriter<W: Write> {
+    out: W,
+    writer: BinaryWriter<Vec<u8>>,
+    // The symbols declared by the preamble (if any), following the system symbols
+    preamble_symbols: Option<Vec<String>>,
+    // The preamble's frame, until it has been written
+    pending_preamble: Option<Vec<u8>>,
+}
+
+impl<W: Write> FramedWriter<W> {
+    /// Creates a FramedWriter that writes each message as a complete Ion stream.
+    pub fn new(out: W) -> FramedWriter<W> {
+        FramedWriter {
+            out,
+            writer: BinaryWriter::new(Vec::new()),
+            preamble_symbols: None,
+            pending_preamble: None,
+        }
+    }
+
+    /// Creates a FramedWriter that begins by writing a preamble declaring `symbols`, which the
+    /// following messages rely on instead of declaring the symbols themselves. The preamble is
+    /// written along with the first message.
+    pub fn with_preamble<I, A>(out: W, symbols: I) -> FramedWriter<W>
+    where
+        I: IntoIterator<Item = A>,
+        A: AsRef<str>,
+    {
+        let mut preamble_writer = BinarySystemWriter::new(Vec::new());
+        for text in symbols {
+            preamble_writer.intern(text);
+        }
+        let preamble_symbols = preamble_writer
+            .symbol_table()
+            .symbols_tail(v1_0::SYSTEM_SYMBOLS.len())
+            .to_vec();
+        // Writing to a Vec cannot fail, and the writer is at the top level.
+        preamble_writer.flush().unwrap();
+        let mut writer = FramedWriter::new(out);
+        writer.pending_preamble = Some(std::mem::take(preamble_writer.output_mut()));
+        writer.preamble_symbols = Some(preamble_symbols);
+        writer
+    }
+
+    /// Calls `write_values` to write the values of a message, then writes the message as a
+    /// frame. If `write_values` fails, nothing is written and the values are discarded.
+    pub fn write_message<F>(&mut self, write_values: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut BinaryWriter<Vec<u8>>) -> IonResult<()>,
+    {
+        if let Some(symbols) = &self.preamble_symbols {
+            self.writer.reset_to_preamble(symbols);
+        }
+        let mut message = Vec::new();
+        let result =
+            write_values(&mut self.writer).and_then(|_| self.writer.flush_message_to(&mut message));
+        if let Err(error) = result {
+            // Discard anything that was written before the error
+            let mut buffer = self.writer.reset(Vec::new());
+            buffer.clear();
+            self.writer.reset(buffer);
+            return Err(error);
+        }
+        if let Some(preamble) = self.pending_preamble.take() {
+            write_frame(&mut self.out, &preamble)?;
+        }
+        write_frame(&mut self.out, &message)
+    }
+
+    /// Writes `element` as a message of its own.
+    pub fn write_element<E: Element>(&mut self, element: &E) -> IonResult<()> {
+        self.write_message(|writer| writer.write_element(element))
+    }
+
+    /// Flushes the underlying sink.
+    pub fn flush(&mut self) -> IonResult<()> {
+        self.out.flush()?;
+        Ok(())
+    }
+
+    pub fn output(&self) -> &W {
+        &self.out
+    }
+
+    pub fn output_mut(&mut self) -> &mut W {
+        &mut self.out
+    }
+}
+
+fn write_frame<W: Write>(out: &mut W, frame: &[u8]) -> IonResult<()> {
+    if frame.len() > u32::MAX as usize {
+        return illegal_operation(format!(
+            "A frame of {} bytes is too large to be written.",
+            frame.len()
+        ));
+    }
+    out.write_all(&(frame.len() as u32).to_be_bytes())?;
+    out.write_all(frame)?;
+    Ok(())
+}
+
+/// Reads the messages written by a [FramedWriter] from a byte stream, one frame at a time.
+#[derive(Debug)]
+pub struct FramedReader<R: Read> {
+    input: R,
+    // The preamble, once it has been read, followed by the current frame
+    buffer: Vec<u8>,
+    preamble_length: usize,
+    expects_preamble: bool,
+    max_frame_length: usize,
+}
+
+// Limits the memory that a corrupt or malicious length prefix can cause the reader to allocate.
+const DEFAULT_MAX_FRAME_LENGTH: usize = 16 * 1024 * 1024;
+
+impl<R: Read> FramedReader<R> {
+    /// Creates a FramedReader for messages that are each a complete Ion stream, as written by
+    /// [FramedWriter::new].
+    pub fn new(input: R) -> FramedReader<R> {
+        FramedReader {
+            input,
+            buffer: Vec::new(),
+            preamble_length: 0,
+            expects_preamble: false,
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+        }
+    }
+
+    /// Creates a FramedReader for messages that follow a preamble in the first frame, as written
+    /// by [FramedWriter::with_preamble].
+    pub fn with_preamble(input: R) -> FramedReader<R> {
+        FramedReader {
+            expects_preamble: true,
+            ..FramedReader::new(input)
+        }
+    }
+
+    /// Sets the length of the largest frame that the reader will accept. Longer frames produce a
+    /// decoding error. The default is 16 MiB.
+    pub fn with_max_frame_length(mut self, max_frame_length: usize) -> Self {
+        self.max_frame_length = max_frame_length;
+        self
+    }
+
+    /// Reads the next frame, returning a binary Ion stream containing the values of its message
+    /// (preceded by the preamble, if there is one). Returns `None` if the input ends between
+    /// frames; ending within a frame produces a decoding error.
+    pub fn next_frame(&mut self) -> IonResult<Option<&[u8]>> {
+        if self.expects_preamble {
+            if !self.read_frame(0)? {
+                return Ok(None);
+            }
+            if !self.buffer.starts_with(&IVM) {
+                return decoding_error("The preamble does not begin with an IVM.");
+            }
+            self.preamble_length = self.buffer.len();
+            self.expects_preamble = false;
+        }
+        if !self.read_frame(self.preamble_length)? {
+            return Ok(None);
+        }
+        Ok(Some(&self.buffer))
+    }
+
+    /// Reads the next frame and decodes the values of its message. Returns `None` if the input
+    /// ends between frames.
+    pub fn read_message(&mut self) -> IonResult<Option<Vec<OwnedElement>>> {
+        let frame = match self.next_frame()? {
+            Some(frame) => frame,
+            None => return Ok(None),
+        };
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(frame)));
+        let mut elements = Vec::new();
+        while reader.next()?.is_some() {
+            elements.push(reader.read_element()?.unwrap());
+        }
+        Ok(Some(elements))
+    }
+
+    // Replaces the contents of the buffer after `start` with the next frame. Returns false if the
+    // input ends before the frame begins.
+    fn read_frame(&mut self, start: usize) -> IonResult<bool> {
+        let mut length = [0u8; FRAME_LENGTH_SIZE];
+        let mut bytes_read = 0;
+        while bytes_read < FRAME_LENGTH_SIZE {
+            match self.input.read(&mut length[bytes_read..]) {
+                Ok(0) if bytes_read == 0 => return Ok(false),
+                Ok(0) => return decoding_error("The input ended within a frame's length."),
+                Ok(n) => bytes_read += n,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(error.into()),
+            }
+        }
+        let length = u32::from_be_bytes(length) as usize;
+        if length > self.max_frame_length {
+            return decoding_error(format!(
+                "The frame's length ({} bytes) exceeds the maximum of {} bytes.",
+                length, self.max_frame_length
+            ));
+        }
+        self.buffer.truncate(start);
+        self.buffer.resize(start + length, 0);
+        if let Err(error) = self.input.read_exact(&mut self.buffer[start..]) {
+            self.buffer.truncate(start);
+            return match error.kind() {
+                io::ErrorKind::UnexpectedEof => decoding_error("The input ended within a frame."),
+                _ => Err(error.into()),
+            };
+        }
+        // A message following a preamble must not reset the symbol table that it declared.
+        if start > 0 && self.buffer[start..].starts_with(&IVM) {
+            return decoding_error("A message following a preamble cannot begin with an IVM.");
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod framed_tests {
+    use super::*;
+
+    fn messages() -> Vec<OwnedElement> {
+        OwnedElement::read_all(
+            br#"
+            {method: ping, id: 1}
+            {method: echo, id: 2, args: ["hi", there]}
+            request::{method: ping, id: 3}
+            "#,
+        )
+        .unwrap()
+    }
+
+    fn write_messages(writer: &mut FramedWriter<&mut Vec<u8>>) -> IonResult<()> {
+        for message in &messages() {
+            writer.write_element(message)?;
+        }
+        Ok(())
+    }
+
+    fn read_messages(reader: &mut FramedReader<&[u8]>) -> IonResult<Vec<OwnedElement>> {
+        let mut elements = Vec::new();
+        while let Some(message) = reader.read_message()? {
+            assert_eq!(message.len(), 1);
+            elements.extend(message);
+        }
+        Ok(elements)
+    }
+
+    #[test]
+    fn self_contained_frames() -> IonResult<()> {
+        let mut data = Vec::new();
+        write_messages(&mut FramedWriter::new(&mut data))?;
+        assert_eq!(
+            read_messages(&mut FramedReader::new(data.as_slice()))?,
+            messages()
+        );
+
+        // Each frame can be read on its own
+        let mut reader = FramedReader::new(data.as_slice());
+        reader.next_frame()?;
+        let second = reader.next_frame()?.unwrap();
+        assert!(second.starts_with(&IVM));
+        assert_eq!(OwnedElement::read_all(second)?, vec![messages().remove(1)]);
+        Ok(())
+    }
+
+    #[test]
+    fn frames_after_a_preamble() -> IonResult<()> {
+        let mut independent = Vec::new();
+        write_messages(&mut FramedWriter::new(&mut independent))?;
+        let mut data = Vec::new();
+        write_messages(&mut FramedWriter::with_preamble(
+            &mut data,
+            &["method", "ping", "id", "method"],
+        ))?;
+        // Declaring the shared symbols once makes the stream smaller
+        assert!(data.len() < independent.len());
+        assert_eq!(
+            read_messages(&mut FramedReader::with_preamble(data.as_slice()))?,
+            messages()
+        );
+
+        // Messages still declare the symbols that the preamble does not
+        let mut reader = FramedReader::with_preamble(data.as_slice());
+        for _ in 0..2 {
+            reader.next_frame()?;
+        }
+        let third = reader.next_frame()?.unwrap();
+        assert_eq!(OwnedElement::read_all(third)?, vec![messages().remove(2)]);
+        Ok(())
+    }
+
+    #[test]
+    fn failed_messages_are_discarded() -> IonResult<()> {
+        let mut data = Vec::new();
+        let mut writer = FramedWriter::new(&mut data);
+        let result = writer.write_message(|writer| {
+            writer.write_symbol("partial")?;
+            writer.step_out()
+        });
+        assert!(result.is_err());
+        writer.write_message(|writer| writer.write_i64(5))?;
+        let mut reader = FramedReader::new(data.as_slice());
+        assert_eq!(reader.read_message()?, Some(vec![OwnedElement::from(5i64)]));
+        assert_eq!(reader.read_message()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_frames() -> IonResult<()> {
+        let mut data = Vec::new();
+        FramedWriter::new(&mut data).write_element(&OwnedElement::from(5i64))?;
+        // Truncated within the length and within the frame
+        for end in &[2, data.len() - 1] {
+            assert!(FramedReader::new(&data[..*end]).next_frame().is_err());
+        }
+        let mut reader = FramedReader::new(data.as_slice()).with_max_frame_length(2);
+        assert!(reader.next_frame().is_err());
+        // A self-contained frame is not a valid message after a preamble
+        let mut doubled = data.clone();
+        doubled.extend_from_slice(&data);
+        assert!(FramedReader::with_preamble(doubled.as_slice())
+            .next_frame()
+            .is_err());
+        Ok(())
+    }
+}
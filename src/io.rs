@@ -0,0 +1,335 @@
+//! The I/O traits and types that the binary reader and the binary encoding primitives are built
+//! on.
+//!
+//! With the `std` feature (which is enabled by default), this module simply re-exports the
+//! corresponding items from [std::io], so any [std::io::BufRead] can be used as an
+//! [IonDataSource](crate::data_source::IonDataSource).
+//!
+//! Without `std`, it provides minimal replacements that have the same names and signatures: the
+//! [Read], [BufRead], [Write] and [Seek] traits, an in-memory [Cursor], and an [Error] that
+//! carries only an [ErrorKind] and a static message. They are implemented for `&[u8]`,
+//! `Cursor<T: AsRef<[u8]>>` and `Vec<u8>`, which is enough to read binary Ion that is already
+//! in memory and to encode values into a `Vec<u8>`.
+
+#[cfg(feature = "std")]
+pub use std::io::{BufRead, Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+
+#[cfg(not(feature = "std"))]
+pub use self::no_std_io::{BufRead, Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+
+// Also built by the tests, which always have `std`, so that they can exercise it.
+#[cfg(any(test, not(feature = "std")))]
+mod no_std_io {
+    use alloc::vec::Vec;
+    use core::convert::TryFrom;
+    use core::fmt;
+
+    /// A subset of [std::io::ErrorKind](https://doc.rust-lang.org/std/io/enum.ErrorKind.html).
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[non_exhaustive]
+    pub enum ErrorKind {
+        InvalidInput,
+        InvalidData,
+        UnexpectedEof,
+        WriteZero,
+        Other,
+    }
+
+    impl ErrorKind {
+        fn as_str(&self) -> &'static str {
+            use ErrorKind::*;
+            match self {
+                InvalidInput => "invalid input parameter",
+                InvalidData => "invalid data",
+                UnexpectedEof => "unexpected end of file",
+                WriteZero => "write zero",
+                Other => "other error",
+            }
+        }
+    }
+
+    /// An I/O error, made up of an [ErrorKind] and a static description.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct Error {
+        kind: ErrorKind,
+        message: &'static str,
+    }
+
+    impl Error {
+        pub fn new(kind: ErrorKind, message: &'static str) -> Self {
+            Error { kind, message }
+        }
+
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl From<ErrorKind> for Error {
+        fn from(kind: ErrorKind) -> Self {
+            Error::new(kind, kind.as_str())
+        }
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    impl core::error::Error for Error {}
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// A source of bytes. See [std::io::Read](https://doc.rust-lang.org/std/io/trait.Read.html).
+    pub trait Read {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize>;
+
+        fn read_exact(&mut self, mut buf: &mut [u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.read(buf)? {
+                    0 => {
+                        return Err(Error::new(
+                            ErrorKind::UnexpectedEof,
+                            "failed to fill buffer",
+                        ))
+                    }
+                    n => buf = &mut buf[n..],
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// A [Read] with an internal buffer. See
+    /// [std::io::BufRead](https://doc.rust-lang.org/std/io/trait.BufRead.html).
+    pub trait BufRead: Read {
+        fn fill_buf(&mut self) -> Result<&[u8]>;
+
+        fn consume(&mut self, amount: usize);
+    }
+
+    /// A sink for bytes. See [std::io::Write](https://doc.rust-lang.org/std/io/trait.Write.html).
+    pub trait Write {
+        fn write(&mut self, buf: &[u8]) -> Result<usize>;
+
+        fn flush(&mut self) -> Result<()>;
+
+        fn write_all(&mut self, mut buf: &[u8]) -> Result<()> {
+            while !buf.is_empty() {
+                match self.write(buf)? {
+                    0 => {
+                        return Err(Error::new(
+                            ErrorKind::WriteZero,
+                            "failed to write whole buffer",
+                        ))
+                    }
+                    n => buf = &buf[n..],
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// A position to [Seek] to.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    pub enum SeekFrom {
+        Start(u64),
+        End(i64),
+        Current(i64),
+    }
+
+    /// A byte source that can be repositioned. See
+    /// [std::io::Seek](https://doc.rust-lang.org/std/io/trait.Seek.html).
+    pub trait Seek {
+        fn seek(&mut self, position: SeekFrom) -> Result<u64>;
+    }
+
+    /// Wraps an in-memory buffer and keeps track of the current position within it. See
+    /// [std::io::Cursor](https://doc.rust-lang.org/std/io/struct.Cursor.html).
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Cursor<T> {
+        inner: T,
+        position: u64,
+    }
+
+    impl<T> Cursor<T> {
+        pub fn new(inner: T) -> Self {
+            Cursor { inner, position: 0 }
+        }
+
+        pub fn into_inner(self) -> T {
+            self.inner
+        }
+
+        pub fn get_ref(&self) -> &T {
+            &self.inner
+        }
+
+        pub fn get_mut(&mut self) -> &mut T {
+            &mut self.inner
+        }
+
+        pub fn position(&self) -> u64 {
+            self.position
+        }
+
+        pub fn set_position(&mut self, position: u64) {
+            self.position = position;
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Cursor<T> {
+        // The bytes after the current position, which is allowed to be past the end of the buffer.
+        fn remaining(&self) -> &[u8] {
+            let bytes = self.inner.as_ref();
+            let start = usize::try_from(self.position).map_or(bytes.len(), |p| p.min(bytes.len()));
+            &bytes[start..]
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Read for Cursor<T> {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let bytes_read = self.remaining().read(buf)?;
+            self.position += bytes_read as u64;
+            Ok(bytes_read)
+        }
+    }
+
+    impl<T: AsRef<[u8]>> BufRead for Cursor<T> {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            Ok(self.remaining())
+        }
+
+        fn consume(&mut self, amount: usize) {
+            self.position += amount as u64;
+        }
+    }
+
+    impl<T: AsRef<[u8]>> Seek for Cursor<T> {
+        fn seek(&mut self, position: SeekFrom) -> Result<u64> {
+            let (base, offset) = match position {
+                SeekFrom::Start(offset) => {
+                    self.position = offset;
+                    return Ok(offset);
+                }
+                SeekFrom::End(offset) => (self.inner.as_ref().len() as u64, offset),
+                SeekFrom::Current(offset) => (self.position, offset),
+            };
+            match base.checked_add_signed(offset) {
+                Some(position) => {
+                    self.position = position;
+                    Ok(position)
+                }
+                None => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "invalid seek to a negative or overflowing position",
+                )),
+            }
+        }
+    }
+
+    impl Read for &[u8] {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            let length = buf.len().min(self.len());
+            let (head, tail) = self.split_at(length);
+            buf[..length].copy_from_slice(head);
+            *self = tail;
+            Ok(length)
+        }
+    }
+
+    impl BufRead for &[u8] {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            Ok(*self)
+        }
+
+        fn consume(&mut self, amount: usize) {
+            *self = &self[amount..];
+        }
+    }
+
+    impl<R: Read + ?Sized> Read for &mut R {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+            (**self).read(buf)
+        }
+    }
+
+    impl<B: BufRead + ?Sized> BufRead for &mut B {
+        fn fill_buf(&mut self) -> Result<&[u8]> {
+            (**self).fill_buf()
+        }
+
+        fn consume(&mut self, amount: usize) {
+            (**self).consume(amount)
+        }
+    }
+
+    impl Write for Vec<u8> {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            self.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<W: Write + ?Sized> Write for &mut W {
+        fn write(&mut self, buf: &[u8]) -> Result<usize> {
+            (**self).write(buf)
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            (**self).flush()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::no_std_io::*;
+
+    #[test]
+    fn cursor_reads_and_seeks() -> Result<()> {
+        let mut cursor = Cursor::new([1u8, 2, 3, 4, 5]);
+        let mut buffer = [0u8; 2];
+        cursor.read_exact(&mut buffer)?;
+        assert_eq!(buffer, [1, 2]);
+        assert_eq!(cursor.fill_buf()?, &[3, 4, 5]);
+        cursor.consume(1);
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(cursor.seek(SeekFrom::End(-1))?, 4);
+        assert_eq!(cursor.fill_buf()?, &[5]);
+        assert_eq!(cursor.seek(SeekFrom::Current(-4))?, 0);
+        assert!(cursor.seek(SeekFrom::Current(-1)).is_err());
+        // Seeking past the end is allowed; reads then find no data.
+        assert_eq!(cursor.seek(SeekFrom::Start(7))?, 7);
+        assert_eq!(cursor.fill_buf()?, &[]);
+        let error = cursor.read_exact(&mut buffer).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+        Ok(())
+    }
+
+    #[test]
+    fn slice_reads() -> Result<()> {
+        let mut input: &[u8] = &[1, 2, 3];
+        let mut buffer = [0u8; 2];
+        assert_eq!(input.read(&mut buffer)?, 2);
+        assert_eq!(input.fill_buf()?, &[3]);
+        assert_eq!(input.read(&mut buffer)?, 1);
+        assert_eq!(buffer, [3, 2]);
+        assert_eq!(input.read(&mut buffer)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn vec_writes() -> Result<()> {
+        let mut output = Vec::new();
+        output.write_all(&[1, 2])?;
+        (&mut output).write_all(&[3])?;
+        assert_eq!(output, vec![1, 2, 3]);
+        Ok(())
+    }
+}
@@ -0,0 +1,349 @@
+use std::cell::{Ref, RefCell};
+use std::fmt;
+use std::io;
+
+use crate::catalog::{Catalog, MapCatalog};
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::result::IonResult;
+use crate::value::owned::OwnedElement;
+use crate::{IonType, RawBinaryReader, Reader};
+
+type SliceReader<'a> = Reader<RawBinaryReader<io::Cursor<&'a [u8]>>>;
+
+/// Reads the top-level values of an in-memory binary Ion stream as [LazyValue]s, which can be
+/// inspected without decoding them.
+///
+/// Struct fields and list elements are located on demand: the first time that a [LazyStruct]
+/// or [LazyList] is accessed, it scans the headers of its children once and remembers where each
+/// one begins. Only the children that are then asked for are decoded, so reading a single field
+/// of a wide record does not require reading its siblings.
+///
+/// ```
+/// use ion_rs::binary::writer::BinaryWriter;
+/// use ion_rs::value::IntAccess;
+/// use ion_rs::LazyReader;
+///
+/// let mut data = vec![];
+/// let mut writer = BinaryWriter::new(&mut data);
+/// writer.write_struct(|s| {
+///     s.set_field_name("id").write_i64(7)?;
+///     s.set_field_name("payload").write_string("a large value that is never read")
+/// })?;
+/// writer.flush()?;
+/// drop(writer);
+///
+/// let mut reader = LazyReader::new(&data);
+/// let record = reader.next_value()?.unwrap().as_struct().unwrap();
+/// let id = record.get("id")?.unwrap().read()?;
+/// assert_eq!(id.as_i64(), Some(7));
+/// # Ok::<(), ion_rs::result::IonError>(())
+/// ```
+pub struct LazyReader<'a> {
+    reader: RefCell<SliceReader<'a>>,
+    // The offset just past the last top-level value that `next_value()` returned, from which it
+    // will resume. Reading lazy values repositions the underlying reader.
+    resume_offset: Option<usize>,
+}
+
+impl<'a> LazyReader<'a> {
+    /// Creates a LazyReader over `data`. Any shared symbol tables imported by the stream produce
+    /// a decoding error; see [with_catalog](Self::with_catalog).
+    pub fn new(data: &'a [u8]) -> LazyReader<'a> {
+        LazyReader::with_catalog(data, MapCatalog::new())
+    }
+
+    /// Like [new](Self::new), but resolves shared symbol tables imported by the stream using
+    /// `catalog`.
+    pub fn with_catalog<C>(data: &'a [u8], catalog: C) -> LazyReader<'a>
+    where
+        C: 'static + Catalog,
+    {
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data)));
+        reader.set_catalog(catalog);
+        LazyReader {
+            reader: RefCell::new(reader),
+            resume_offset: None,
+        }
+    }
+
+    /// Advances to the next top-level value, processing any symbol tables that precede it, and
+    /// returns it without decoding it. Returns `None` at the end of the stream.
+    ///
+    /// The returned value borrows the reader, so it must be dropped before the reader can
+    /// advance; the symbol table that it was encoded with remains in effect until then.
+    pub fn next_value(&mut self) -> IonResult<Option<LazyValue<'_, 'a>>> {
+        let reader = self.reader.get_mut();
+        if let Some(offset) = self.resume_offset {
+            reader.seek_to_offset(offset)?;
+        }
+        let (ion_type, is_null) = match reader.next()? {
+            Some(item) => item,
+            None => return Ok(None),
+        };
+        let range = reader.encoded_range().unwrap();
+        self.resume_offset = Some(range.end);
+        Ok(Some(LazyValue {
+            reader: &self.reader,
+            offset: range.start,
+            ion_type,
+            is_null,
+        }))
+    }
+}
+
+/// A binary Ion value that has been located but not decoded. See [LazyReader].
+#[derive(Clone, Copy)]
+pub struct LazyValue<'r, 'a> {
+    reader: &'r RefCell<SliceReader<'a>>,
+    // The offset of the value's annotations wrapper (if any) or header
+    offset: usize,
+    ion_type: IonType,
+    is_null: bool,
+}
+
+impl<'r, 'a> LazyValue<'r, 'a> {
+    pub fn ion_type(&self) -> IonType {
+        self.ion_type
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.is_null
+    }
+
+    /// Decodes the value, including its annotations and any values nested inside it.
+    pub fn read(&self) -> IonResult<OwnedElement> {
+        self.reader.borrow_mut().read_element_at(self.offset)
+    }
+
+    /// Returns a lazy view of the value's fields if it is a non-null struct.
+    pub fn as_struct(&self) -> Option<LazyStruct<'r, 'a>> {
+        if self.ion_type != IonType::Struct || self.is_null {
+            return None;
+        }
+        Some(LazyStruct {
+            container: LazyContainer::new(*self),
+        })
+    }
+
+    /// Returns a lazy view of the value's elements if it is a non-null list or s-expression.
+    pub fn as_list(&self) -> Option<LazyList<'r, 'a>> {
+        if !matches!(self.ion_type, IonType::List | IonType::SExpression) || self.is_null {
+            return None;
+        }
+        Some(LazyList {
+            container: LazyContainer::new(*self),
+        })
+    }
+}
+
+impl<'r, 'a> fmt::Debug for LazyValue<'r, 'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyValue")
+            .field("offset", &self.offset)
+            .field("ion_type", &self.ion_type)
+            .field("is_null", &self.is_null)
+            .finish()
+    }
+}
+
+/// A lazy view of a binary Ion struct. See [LazyReader].
+#[derive(Debug)]
+pub struct LazyStruct<'r, 'a> {
+    container: LazyContainer<'r, 'a>,
+}
+
+impl<'r, 'a> LazyStruct<'r, 'a> {
+    /// Returns the first field named `name`, or `None` if the struct does not have one.
+    pub fn get(&self, name: &str) -> IonResult<Option<LazyValue<'r, 'a>>> {
+        Ok(self.get_all(name)?.into_iter().next())
+    }
+
+    /// Returns every field named `name`, in the order in which they appear.
+    pub fn get_all(&self, name: &str) -> IonResult<Vec<LazyValue<'r, 'a>>> {
+        let children = self.container.children()?;
+        let reader = self.container.value.reader.borrow();
+        Ok(children
+            .iter()
+            .filter(|child| match &child.field_name {
+                Some(field_name) => reader.symbol_matches(field_name, name),
+                None => false,
+            })
+            .map(|child| self.container.child_value(child))
+            .collect())
+    }
+
+    /// Returns the number of fields in the struct.
+    pub fn len(&self) -> IonResult<usize> {
+        Ok(self.container.children()?.len())
+    }
+
+    /// Returns true if the struct does not have any fields.
+    pub fn is_empty(&self) -> IonResult<bool> {
+        Ok(self.len()? == 0)
+    }
+}
+
+/// A lazy view of a binary Ion list or s-expression. See [LazyReader].
+#[derive(Debug)]
+pub struct LazyList<'r, 'a> {
+    container: LazyContainer<'r, 'a>,
+}
+
+impl<'r, 'a> LazyList<'r, 'a> {
+    /// Returns the element at `index`, or `None` if the list does not have that many elements.
+    pub fn get(&self, index: usize) -> IonResult<Option<LazyValue<'r, 'a>>> {
+        let children = self.container.children()?;
+        Ok(children
+            .get(index)
+            .map(|child| self.container.child_value(child)))
+    }
+
+    /// Returns the list's elements, in order.
+    pub fn values(&self) -> IonResult<Vec<LazyValue<'r, 'a>>> {
+        let children = self.container.children()?;
+        Ok(children
+            .iter()
+            .map(|child| self.container.child_value(child))
+            .collect())
+    }
+
+    /// Returns the number of elements in the list.
+    pub fn len(&self) -> IonResult<usize> {
+        Ok(self.container.children()?.len())
+    }
+
+    /// Returns true if the list does not have any elements.
+    pub fn is_empty(&self) -> IonResult<bool> {
+        Ok(self.len()? == 0)
+    }
+}
+
+// A container value along with the locations of its children, which are found the first time
+// that they are needed.
+#[derive(Debug)]
+struct LazyContainer<'r, 'a> {
+    value: LazyValue<'r, 'a>,
+    children: RefCell<Option<Vec<EncodedChild>>>,
+}
+
+impl<'r, 'a> LazyContainer<'r, 'a> {
+    fn new(value: LazyValue<'r, 'a>) -> Self {
+        LazyContainer {
+            value,
+            children: RefCell::new(None),
+        }
+    }
+
+    fn children(&self) -> IonResult<Ref<'_, Vec<EncodedChild>>> {
+        if self.children.borrow().is_none() {
+            let children = self
+                .value
+                .reader
+                .borrow_mut()
+                .read_children_at(self.value.offset)?;
+            *self.children.borrow_mut() = Some(children);
+        }
+        Ok(Ref::map(self.children.borrow(), |children| {
+            children.as_ref().unwrap()
+        }))
+    }
+
+    fn child_value(&self, child: &EncodedChild) -> LazyValue<'r, 'a> {
+        LazyValue {
+            reader: self.value.reader,
+            offset: child.offset,
+            ion_type: child.ion_type,
+            is_null: child.is_null,
+        }
+    }
+}
+
+/// The location of a value inside a container, found by [Reader::read_children_at].
+#[derive(Clone, Debug)]
+pub(crate) struct EncodedChild {
+    pub field_name: Option<RawSymbolToken>,
+    // The offset of the value's annotations wrapper (if any) or header
+    pub offset: usize,
+    pub ion_type: IonType,
+    pub is_null: bool,
+}
+
+#[cfg(test)]
+mod lazy_tests {
+    use super::*;
+    use crate::binary::writer::BinaryWriter;
+    use crate::ion;
+
+    // Unlike test_support::binary_ion, flushes after each value, so that the reader has to process
+    // a symbol table between values.
+    fn encode(text: &str) -> IonResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriter::new(&mut buffer);
+        for element in OwnedElement::read_all(text.as_bytes())? {
+            writer.write_element(&element)?;
+            // Each flush declares the new symbols in an appended symbol table
+            writer.flush()?;
+        }
+        drop(writer);
+        Ok(buffer)
+    }
+
+    #[test]
+    fn read_fields_and_elements_on_demand() -> IonResult<()> {
+        let data = encode(
+            r#"
+            a::{id: 1, name: "first", tags: [x, y::z, {deep: true}], id: 2}
+            {other: 3}
+            [1, (2 3), null.struct]
+            "#,
+        )?;
+        let mut reader = LazyReader::new(&data);
+
+        let value = reader.next_value()?.unwrap();
+        assert_eq!(value.ion_type(), IonType::Struct);
+        let record = value.as_struct().unwrap();
+        assert!(value.as_list().is_none());
+        assert_eq!(record.len()?, 4);
+        assert_eq!(record.get("id")?.unwrap().read()?, ion!("1"));
+        let ids: IonResult<Vec<_>> = record.get_all("id")?.iter().map(|v| v.read()).collect();
+        assert_eq!(ids?, vec![ion!("1"), ion!("2")]);
+        assert!(record.get("missing")?.is_none());
+        let tags = record.get("tags")?.unwrap().as_list().unwrap();
+        assert_eq!(tags.len()?, 3);
+        assert_eq!(tags.get(1)?.unwrap().read()?, ion!("y::z"));
+        let deep = tags.get(2)?.unwrap().as_struct().unwrap();
+        assert_eq!(deep.get("deep")?.unwrap().read()?, ion!("true"));
+        assert!(tags.get(3)?.is_none());
+        assert_eq!(
+            value.read()?,
+            ion!(r#"a::{id: 1, name: "first", tags: [x, y::z, {deep: true}], id: 2}"#)
+        );
+
+        // Values that were read lazily do not disturb the top-level position
+        let record = reader.next_value()?.unwrap().as_struct().unwrap();
+        assert_eq!(record.get("other")?.unwrap().read()?, ion!("3"));
+        assert!(record.get("id")?.is_none());
+
+        let list = reader.next_value()?.unwrap().as_list().unwrap();
+        let values = list.values()?;
+        assert_eq!(values.len(), 3);
+        assert_eq!(values[1].ion_type(), IonType::SExpression);
+        assert_eq!(values[1].as_list().unwrap().len()?, 2);
+        assert!(values[2].is_null());
+        assert!(values[2].as_struct().is_none());
+        assert!(reader.next_value()?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn empty_containers() -> IonResult<()> {
+        let data = encode("{} []")?;
+        let mut reader = LazyReader::new(&data);
+        let value = reader.next_value()?.unwrap();
+        assert!(value.as_struct().unwrap().is_empty()?);
+        let value = reader.next_value()?.unwrap();
+        assert!(value.as_list().unwrap().is_empty()?);
+        assert!(reader.next_value()?.is_none());
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(dead_code)]
 
+extern crate alloc;
+
 /// A [`try`]-like macro to workaround the [`Option`]/[`Result`] nested APIs.
 /// These API require checking the type and then calling the appropriate getter function
 /// (which returns a None if you got it wrong). This macro turns the `None` into
 /// an `IonError` which cannot be currently done with `?`.
+#[cfg(feature = "std")]
 macro_rules! try_to {
     ($getter:expr) => {
         match $getter {
@@ -15,24 +19,87 @@ macro_rules! try_to {
 
 pub mod result;
 
+pub mod allocator;
+#[cfg(feature = "std")]
+pub mod analysis;
 pub mod binary;
+#[cfg(feature = "std")]
+pub mod convert;
 pub mod data_source;
+pub mod io;
 pub mod raw_reader;
+#[cfg(feature = "std")]
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "std")]
+pub mod stream;
+#[cfg(any(feature = "test-support", all(test, feature = "std")))]
+pub mod test_support;
+#[cfg(feature = "std")]
 pub mod text;
+#[cfg(feature = "std")]
+pub mod transcode;
 pub mod types;
+#[cfg(feature = "std")]
 pub mod value;
 
+mod annotation_prefix_reader;
+#[cfg(feature = "std")]
+mod catalog;
 pub mod constants;
+#[cfg(feature = "std")]
+mod framed;
+#[cfg(feature = "std")]
+mod lazy;
+#[cfg(feature = "rayon")]
+mod parallel_reader;
+#[cfg(feature = "std")]
+mod path_extractor;
 mod raw_symbol_token;
+#[cfg(feature = "std")]
 mod reader;
+#[cfg(feature = "std")]
+mod symbol;
+#[cfg(feature = "std")]
 mod symbol_table;
+#[cfg(feature = "std")]
 mod system_event_handler;
 
-pub use binary::raw_binary_reader::RawBinaryReader;
+pub use annotation_prefix_reader::AnnotationPrefixReader;
+#[cfg(feature = "tokio")]
+pub use binary::async_reader::AsyncRawBinaryReader;
+#[cfg(feature = "std")]
+pub use binary::incremental_reader::{IncrementalBinaryReader, ReadStatus};
+pub use binary::raw_binary_reader::{
+    RawBinaryReader, RawBinaryReaderBuilder, StreamPosition, ValueEncoding,
+};
+#[cfg(feature = "std")]
+pub use catalog::{Catalog, MapCatalog, SharedSymbolTable};
+#[cfg(feature = "tokio")]
+pub use data_source::AsyncIonDataSource;
 pub use data_source::IonDataSource;
+pub use data_source::ToIonDataSource;
+#[cfg(feature = "std")]
+pub use framed::{FramedReader, FramedWriter};
+#[cfg(feature = "std")]
+pub use lazy::{LazyList, LazyReader, LazyStruct, LazyValue};
+#[cfg(feature = "rayon")]
+pub use parallel_reader::ParallelReader;
+#[cfg(feature = "std")]
+pub use path_extractor::{PathComponent, PathExtractor};
 pub use raw_reader::RawReader;
-pub use reader::Reader;
+pub use raw_symbol_token::RawSymbolToken;
+#[cfg(feature = "std")]
+pub use reader::{
+    DuplicateFieldPolicy, Events, ListIter, Reader, ReaderBuilder, ReaderEvent, Savepoint,
+    SavepointGuard, ScopedContainer, SystemStreamItem, UnknownFieldNamePolicy,
+};
+#[cfg(feature = "std")]
+pub use symbol::Symbol;
+#[cfg(feature = "std")]
 pub use symbol_table::SymbolTable;
+#[cfg(feature = "std")]
 pub use system_event_handler::SystemEventHandler;
 pub use types::IonType;
 
@@ -40,5 +107,6 @@ pub use types::IonType;
 ///
 /// See also: https://github.com/amzn/ion-rust/issues/302.
 pub mod external {
+    #[cfg(feature = "std")]
     pub use bigdecimal;
 }
@@ -0,0 +1,194 @@
+use std::io;
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::catalog::{Catalog, MapCatalog};
+use crate::result::IonResult;
+use crate::symbol_table::SymbolTable;
+use crate::value::owned::OwnedElement;
+use crate::{RawBinaryReader, Reader};
+
+/// Decodes the top-level values of an in-memory binary Ion stream into [OwnedElement]s on
+/// rayon's global thread pool.
+///
+/// Constructing a ParallelReader indexes the stream: it visits each top-level value in order,
+/// skipping over the contents of containers, and records the value's offset along with the
+/// symbol table in effect for it. Decoding then seeks each of the pool's threads directly to the
+/// values that it has been assigned.
+///
+/// The index keeps a copy of the symbol table each time it changes, so streams that declare
+/// many symbols across many symbol tables (e.g. one written by a writer that was flushed after
+/// every value) will use more memory while being indexed.
+///
+/// ```
+/// use ion_rs::binary::writer::BinaryWriter;
+/// use ion_rs::ParallelReader;
+///
+/// let mut data = vec![];
+/// let mut writer = BinaryWriter::new(&mut data);
+/// for i in 0..1_000 {
+///     writer.write_i64(i)?;
+/// }
+/// writer.flush()?;
+/// drop(writer);
+///
+/// let reader = ParallelReader::new(&data)?;
+/// assert_eq!(reader.len(), 1_000);
+/// let elements = reader.read_all()?;
+/// assert_eq!(elements.len(), 1_000);
+/// # Ok::<(), ion_rs::result::IonError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct ParallelReader<'a> {
+    data: &'a [u8],
+    // Each distinct symbol table that was in effect for at least one value.
+    symbol_tables: Vec<Arc<SymbolTable>>,
+    values: Vec<IndexedValue>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct IndexedValue {
+    // The offset of the value (or of its annotations wrapper) from the beginning of `data`
+    offset: usize,
+    // The position of the value's symbol table in `symbol_tables`
+    symbol_table: usize,
+}
+
+impl<'a> ParallelReader<'a> {
+    /// Indexes the top-level values in `data`. Any shared symbol tables imported by the stream
+    /// produce a decoding error; see [with_catalog](Self::with_catalog).
+    pub fn new(data: &'a [u8]) -> IonResult<Self> {
+        ParallelReader::with_catalog(data, MapCatalog::new())
+    }
+
+    /// Like [new](Self::new), but resolves shared symbol tables imported by the stream using
+    /// `catalog`.
+    pub fn with_catalog<C>(data: &'a [u8], catalog: C) -> IonResult<Self>
+    where
+        C: 'static + Catalog,
+    {
+        let mut symbol_tables = Vec::new();
+        let mut values = Vec::new();
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data)));
+        reader.set_catalog(catalog);
+        let mut previous_end = None;
+        while reader.next()?.is_some() {
+            let range = reader.encoded_range().unwrap();
+            // The symbol table can only have changed if there were system values between this
+            // value and the last one.
+            if previous_end != Some(range.start) {
+                symbol_tables.push(Arc::new(reader.symbol_table().clone()));
+            }
+            values.push(IndexedValue {
+                offset: range.start,
+                symbol_table: symbol_tables.len() - 1,
+            });
+            previous_end = Some(range.end);
+        }
+        Ok(ParallelReader {
+            data,
+            symbol_tables,
+            values,
+        })
+    }
+
+    /// Returns the number of top-level values in the stream.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the stream does not contain any top-level values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the offset of each top-level value (or of its annotations wrapper) from the
+    /// beginning of the stream, in order. These can be passed to [Reader::seek_to_offset].
+    pub fn offsets(&self) -> impl Iterator<Item = usize> + '_ {
+        self.values.iter().map(|value| value.offset)
+    }
+
+    /// Decodes every top-level value in the stream, returning them in the order in which they
+    /// appear. If more than one value cannot be decoded, the error for any one of them may be
+    /// returned.
+    pub fn read_all(&self) -> IonResult<Vec<OwnedElement>> {
+        self.values
+            .par_iter()
+            .map_init(
+                || {
+                    (
+                        Reader::new(RawBinaryReader::new(io::Cursor::new(self.data))),
+                        None,
+                    )
+                },
+                |(reader, current_table), value| -> IonResult<OwnedElement> {
+                    if *current_table != Some(value.symbol_table) {
+                        let symbol_table = &self.symbol_tables[value.symbol_table];
+                        reader.set_symbol_table(SymbolTable::clone(symbol_table));
+                        *current_table = Some(value.symbol_table);
+                    }
+                    reader.seek_to_offset(value.offset)?;
+                    reader.next()?;
+                    Ok(reader.read_element()?.unwrap())
+                },
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod parallel_reader_tests {
+    use super::*;
+    use crate::binary::writer::BinaryWriter;
+
+    fn read_sequentially(data: &[u8]) -> IonResult<Vec<OwnedElement>> {
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data)));
+        let mut elements = Vec::new();
+        while reader.next()?.is_some() {
+            elements.push(reader.read_element()?.unwrap());
+        }
+        Ok(elements)
+    }
+
+    #[test]
+    fn read_all_matches_sequential_reading() -> IonResult<()> {
+        let mut data = Vec::new();
+        let mut writer = BinaryWriter::new(&mut data);
+        for i in 0..500 {
+            writer.set_annotations(&[format!("a{}", i % 11)]);
+            writer.write_struct(|s| {
+                s.set_field_name("id").write_i64(i)?;
+                s.set_field_name("tags").write_list(|l| {
+                    l.write_symbol(format!("tag{}", i % 5))?;
+                    l.write_string("text")
+                })
+            })?;
+            // Each flush appends any new symbols to the symbol table
+            if i % 50 == 0 {
+                writer.flush()?;
+            }
+        }
+        writer.flush()?;
+        drop(writer);
+
+        let reader = ParallelReader::new(&data)?;
+        assert_eq!(reader.len(), 500);
+        assert!(reader.symbol_tables.len() > 1);
+        assert_eq!(read_sequentially(&data)?, reader.read_all()?);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_and_invalid_streams() -> IonResult<()> {
+        let reader = ParallelReader::new(&[0xE0, 0x01, 0x00, 0xEA])?;
+        assert!(reader.is_empty());
+        assert!(reader.read_all()?.is_empty());
+
+        // A truncated value is reported while indexing or decoding
+        let result = ParallelReader::new(&[0xE0, 0x01, 0x00, 0xEA, 0x21])
+            .and_then(|reader| reader.read_all());
+        assert!(result.is_err());
+        Ok(())
+    }
+}
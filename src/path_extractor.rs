@@ -0,0 +1,229 @@
+use crate::result::{illegal_operation, IonResult};
+use crate::{RawReader, Reader};
+
+/// A single step in a path registered with a [PathExtractor].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathComponent {
+    /// Matches the struct field with the given name.
+    Field(String),
+    /// Matches the child at the given (zero-based) position within its container. Positions are
+    /// counted in lists, s-expressions and structs alike.
+    Index(usize),
+    /// Matches any child of a container.
+    Wildcard,
+}
+
+impl From<&str> for PathComponent {
+    fn from(name: &str) -> Self {
+        PathComponent::Field(name.to_string())
+    }
+}
+
+impl From<String> for PathComponent {
+    fn from(name: String) -> Self {
+        PathComponent::Field(name)
+    }
+}
+
+impl From<usize> for PathComponent {
+    fn from(index: usize) -> Self {
+        PathComponent::Index(index)
+    }
+}
+
+/// The name and position of one of the containers (or values) on the way to the reader's
+/// current value.
+struct Step {
+    field_name: Option<String>,
+    index: usize,
+}
+
+impl PathComponent {
+    fn matches(&self, step: &Step) -> bool {
+        match self {
+            PathComponent::Field(name) => step.field_name.as_deref() == Some(name.as_str()),
+            PathComponent::Index(index) => step.index == *index,
+            PathComponent::Wildcard => true,
+        }
+    }
+}
+
+type Callback<'a, C> = Box<dyn FnMut(&mut Reader<C>) -> IonResult<()> + 'a>;
+
+/// Invokes callbacks for the values at registered paths within each top-level value of a
+/// stream, e.g. the path `["foo", 2, "bar"]` matches `3` in `{foo: [a, b, {bar: 3}]}`. An empty
+/// path matches the top-level values themselves.
+///
+/// The extractor only steps into containers that could hold a match. Every other value is
+/// skipped without being read, which makes extracting a few fields from large values cheap.
+///
+/// Each callback is passed the reader positioned over a matching value. It may read the value
+/// (including with [Reader::read_element]) or step into it, but must step back out before
+/// returning. The children of a value that matched a path are not searched for further matches.
+pub struct PathExtractor<'a, C: RawReader> {
+    paths: Vec<(Vec<PathComponent>, Callback<'a, C>)>,
+}
+
+impl<'a, C: RawReader> PathExtractor<'a, C> {
+    pub fn new() -> Self {
+        PathExtractor { paths: Vec::new() }
+    }
+
+    /// Registers `callback` to be invoked for each value at `path`. If a value matches more than
+    /// one path, the callbacks are invoked in the order in which they were registered.
+    pub fn register<F>(&mut self, path: Vec<PathComponent>, callback: F)
+    where
+        F: FnMut(&mut Reader<C>) -> IonResult<()> + 'a,
+    {
+        self.paths.push((path, Box::new(callback)));
+    }
+
+    /// Visits each of the remaining values at the reader's current depth, invoking the
+    /// registered callbacks for any matches found within them.
+    pub fn match_stream(&mut self, reader: &mut Reader<C>) -> IonResult<()> {
+        let mut steps = Vec::new();
+        while let Some((ion_type, is_null)) = reader.next()? {
+            self.match_value(reader, &mut steps, ion_type.is_container() && !is_null)?;
+        }
+        Ok(())
+    }
+
+    // Invokes the callbacks whose paths lead to the current value, or otherwise searches the
+    // value's children if it is a container that a longer path could lead into.
+    fn match_value(
+        &mut self,
+        reader: &mut Reader<C>,
+        steps: &mut Vec<Step>,
+        is_container: bool,
+    ) -> IonResult<()> {
+        let mut found_match = false;
+        let mut could_match_child = false;
+        for (path, callback) in self.paths.iter_mut() {
+            if path.len() < steps.len() || !path.iter().zip(steps.iter()).all(|(c, s)| c.matches(s))
+            {
+                continue;
+            }
+            if path.len() > steps.len() {
+                could_match_child = true;
+                continue;
+            }
+            found_match = true;
+            let depth = reader.depth();
+            callback(reader)?;
+            if reader.depth() != depth {
+                return illegal_operation(
+                    "A path extractor callback must step out of any containers it steps into.",
+                );
+            }
+        }
+        if found_match || !could_match_child || !is_container {
+            return Ok(());
+        }
+
+        reader.step_in()?;
+        let mut index = 0;
+        while let Some((ion_type, is_null)) = reader.next()? {
+            steps.push(Step {
+                field_name: reader.field_name().map(|name| name.to_string()),
+                index,
+            });
+            let result = self.match_value(reader, steps, ion_type.is_container() && !is_null);
+            steps.pop();
+            result?;
+            index += 1;
+        }
+        reader.step_out()
+    }
+}
+
+impl<'a, C: RawReader> Default for PathExtractor<'a, C> {
+    fn default() -> Self {
+        PathExtractor::new()
+    }
+}
+
+#[cfg(test)]
+mod path_extractor_tests {
+    use super::*;
+    use crate::result::IonError;
+    use crate::text::raw_text_reader::RawTextReader;
+    use crate::value::{IntAccess, SymbolToken};
+    use crate::IonType;
+
+    type TestExtractor<'a> = PathExtractor<'a, RawTextReader<&'static str>>;
+
+    const TEXT: &str = r#"
+        {foo: [a, b, {bar: 1}], baz: {bar: 2}}
+        {foo: [c, d, {bar: 3, quux: 4}]}
+        {foo: [e]}
+        "top"
+    "#;
+
+    #[test]
+    fn extract_nested_values() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new(TEXT));
+        let mut bars = Vec::new();
+        let mut firsts = Vec::new();
+        let mut extractor = TestExtractor::new();
+        let bar_path = vec!["foo".into(), PathComponent::Index(2), "bar".into()];
+        extractor.register(bar_path, |reader| {
+            bars.push(reader.read_i64()?.unwrap());
+            Ok(())
+        });
+        extractor.register(vec!["foo".into(), PathComponent::Index(0)], |reader| {
+            firsts.push(reader.read_symbol()?.unwrap().text().unwrap().to_string());
+            Ok(())
+        });
+        extractor.match_stream(&mut reader)?;
+        drop(extractor);
+
+        assert_eq!(bars, vec![1, 3]);
+        assert_eq!(firsts, vec!["a", "c", "e"]);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_path_matches_top_level_values() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new(TEXT));
+        let mut ion_types = Vec::new();
+        let mut extractor = TestExtractor::new();
+        extractor.register(vec![], |reader| {
+            ion_types.push(reader.ion_type().unwrap());
+            Ok(())
+        });
+        extractor.match_stream(&mut reader)?;
+        drop(extractor);
+
+        assert_eq!(ion_types.len(), 4);
+        assert_eq!(ion_types[3], IonType::String);
+        Ok(())
+    }
+
+    #[test]
+    fn extract_with_wildcard() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new(TEXT));
+        let mut bars = Vec::new();
+        let mut extractor = TestExtractor::new();
+        extractor.register(vec![PathComponent::Wildcard, "bar".into()], |reader| {
+            bars.push(reader.read_element()?.unwrap());
+            Ok(())
+        });
+        extractor.match_stream(&mut reader)?;
+        drop(extractor);
+
+        let bars: Vec<i64> = bars.iter().map(|e| e.as_i64().unwrap()).collect();
+        assert_eq!(bars, vec![2]);
+        Ok(())
+    }
+
+    #[test]
+    fn callback_must_restore_depth() {
+        let mut reader = Reader::new(RawTextReader::new(TEXT));
+        let mut extractor = TestExtractor::new();
+        extractor.register(vec!["foo".into()], |reader| reader.step_in());
+        assert!(matches!(
+            extractor.match_stream(&mut reader),
+            Err(IonError::IllegalOperation { .. })
+        ));
+    }
+}
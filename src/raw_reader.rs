@@ -1,10 +1,19 @@
+use crate::annotation_prefix_reader::AnnotationPrefixReader;
 use crate::raw_symbol_token::RawSymbolToken;
-use crate::result::IonResult;
+use crate::result::{decoding_error, illegal_operation, IonError, IonResult};
 use crate::types::decimal::Decimal;
+use crate::types::float::{Float, FloatKind};
+use crate::types::integer::Integer;
 use crate::types::timestamp::Timestamp;
-use crate::types::IonType;
+use crate::types::{IonType, SymbolId};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
+use core::slice;
 
 /**
  * This trait captures the format-agnostic parser functionality needed to navigate within an Ion
@@ -15,6 +24,12 @@ use chrono::{DateTime, FixedOffset};
  *
  * Once a value has successfully been read from the stream using one of the read_* functions,
  * calling that function again may return an Err. This is left to the discretion of the implementor.
+ *
+ * The trait is object safe, so a reader for either encoding can be selected at runtime and used
+ * as a `Box<dyn RawReader>`. The generic `*_map` methods cannot be called through a trait object
+ * directly, but `Box<dyn RawReader>` itself implements RawReader (including those methods, in
+ * terms of [read_str](RawReader::read_str) and the other `*_ref` methods) and can be wrapped in a
+ * [Reader](crate::Reader) like any other raw reader.
  */
 pub trait RawReader {
     /// Returns the (major, minor) version of the Ion stream being read. If ion_version is called
@@ -23,6 +38,11 @@ pub trait RawReader {
 
     /// Attempts to advance the cursor to the next value in the stream at the current depth.
     /// If no value is encountered, returns None; otherwise, returns the Ion type of the next value.
+    ///
+    /// Any part of the current value that has not been read is skipped, so calling `next()`
+    /// without calling `step_in()` moves past an entire container. Binary readers do this using
+    /// the container's length prefix without decoding any of its children; text readers must
+    /// still parse the children to find the end of the container.
     fn next(&mut self) -> IonResult<Option<StreamItem>>;
 
     /// Returns the Ion type of the value currently positioned under the cursor. If the cursor
@@ -36,10 +56,49 @@ pub trait RawReader {
     /// If there is no current value, returns an empty slice.
     fn annotations(&self) -> &[RawSymbolToken];
 
+    /// Returns the number of annotations on the current value. If there is no current value,
+    /// returns 0.
+    fn annotations_len(&self) -> usize {
+        self.annotations().len()
+    }
+
+    /// Returns the first annotation on the current value, if there is one.
+    fn first_annotation(&self) -> Option<&RawSymbolToken> {
+        self.annotations().first()
+    }
+
+    /// Returns an iterator over the symbol IDs of the current value's annotations, in order.
+    /// Annotations that are encoded as text are skipped. As with [field_name_sid], the IDs are not
+    /// resolved or validated against any symbol table.
+    ///
+    /// [field_name_sid]: RawReader::field_name_sid
+    fn annotation_sids(&self) -> AnnotationSids<'_> {
+        AnnotationSids {
+            annotations: self.annotations().iter(),
+        }
+    }
+
+    /// Returns true if any of the current value's annotations has the specified text.
+    ///
+    /// Symbol IDs are not resolved at this level, so annotations encoded as symbol IDs never
+    /// match, even if the symbol table would map them to `text`.
+    fn has_annotation(&self, text: &str) -> bool {
+        self.annotations()
+            .iter()
+            .any(|annotation| annotation.text() == Some(text))
+    }
+
     /// If the current value is a field within a struct, returns a [RawSymbolToken] containing
     /// either the text or symbol ID specified for the field's name; otherwise, returns None.
     fn field_name(&self) -> Option<&RawSymbolToken>;
 
+    /// If the current value is a field within a struct and its name is encoded as a symbol ID,
+    /// returns that symbol ID; otherwise, returns None. The ID is not resolved or validated
+    /// against any symbol table.
+    fn field_name_sid(&self) -> Option<SymbolId> {
+        self.field_name().and_then(RawSymbolToken::local_sid)
+    }
+
     /// If the current value is a null, returns the Ion type of the null; otherwise,
     /// returns None.
     fn read_null(&mut self) -> IonResult<Option<IonType>>;
@@ -48,12 +107,62 @@ pub trait RawReader {
     fn read_bool(&mut self) -> IonResult<Option<bool>>;
 
     /// If the current value is an integer, returns its value as an i64; otherwise, returns None.
+    /// If the integer is too large to be represented as an i64, returns a decoding error; use
+    /// [read_integer](RawReader::read_integer) to read integers of any size.
     fn read_i64(&mut self) -> IonResult<Option<i64>>;
 
+    /// If the current value is an integer, returns its value as an [Integer]; otherwise, returns
+    /// None. The default implementation wraps the value returned by
+    /// [read_i64](RawReader::read_i64), so implementations that can encounter integers wider
+    /// than 64 bits should override it.
+    fn read_integer(&mut self) -> IonResult<Option<Integer>> {
+        Ok(self.read_i64()?.map(Integer::I64))
+    }
+
     /// If the current value is a float, returns its value as an f32; otherwise, returns None.
+    /// 64-bit values are narrowed, which preserves `nan`, `+inf`, and `-inf` but may silently
+    /// lose precision; use [read_f32_exact](RawReader::read_f32_exact) to detect that instead.
     fn read_f32(&mut self) -> IonResult<Option<f32>>;
 
+    /// Like [read_f32](RawReader::read_f32), but returns a decoding error if the value cannot
+    /// be represented as an f32 without losing precision. See [Float::as_f32]. The value is read
+    /// either way, so a binary reader cannot then read it again with `read_f32`; call
+    /// [read_float](RawReader::read_float) instead to inspect the value before narrowing it.
+    fn read_f32_exact(&mut self) -> IonResult<Option<f32>> {
+        match self.read_float()? {
+            Some(float) => match float.as_f32() {
+                Some(value) => Ok(Some(value)),
+                None => decoding_error(format!(
+                    "The float {} cannot be represented as an f32 without losing precision.",
+                    float.as_f64()
+                )),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// If the current value is a float, returns its value as a [Float] that records whether it
+    /// was encoded using 32 or 64 bits; otherwise, returns None. The default implementation wraps
+    /// the value returned by [read_f64](RawReader::read_f64) in [Float::F64], so implementations
+    /// that can encounter 32-bit floats should override it.
+    fn read_float(&mut self) -> IonResult<Option<Float>> {
+        Ok(self.read_f64()?.map(Float::F64))
+    }
+
+    /// If the current value is a float, returns whether it is a NaN (with any bit pattern), an
+    /// infinity or a finite value; otherwise, returns None. Like the other `read_` methods, this
+    /// reads the value; to get both the value and its kind, call [read_f64](RawReader::read_f64)
+    /// and then [FloatKind::of].
+    fn read_float_kind(&mut self) -> IonResult<Option<FloatKind>> {
+        Ok(self.read_f64()?.map(FloatKind::of))
+    }
+
     /// If the current value is a float, returns its value as an f64; otherwise, returns None.
+    ///
+    /// The special values `nan`, `+inf`, and `-inf` are returned as [f64::NAN], [f64::INFINITY],
+    /// and [f64::NEG_INFINITY] respectively; they are never reported as a missing value. Binary
+    /// NaNs are returned with the bit pattern that was encoded, so the canonical quiet NaN
+    /// (`0x7FF8_0000_0000_0000`) is preserved on read.
     fn read_f64(&mut self) -> IonResult<Option<f64>>;
 
     /// If the current value is a decimal, returns its value as a [Decimal]; otherwise,
@@ -66,6 +175,7 @@ pub trait RawReader {
         since = "0.6.1",
         note = "Please use the `read_decimal` method instead."
     )]
+    #[cfg(feature = "std")]
     fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
 
     /// If the current value is a string, returns its value as a String; otherwise, returns None.
@@ -76,8 +186,25 @@ pub trait RawReader {
     /// reference directly to the bytes in the input buffer rather than allocating a new string.
     fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
     where
+        Self: Sized,
         F: FnOnce(&str) -> T;
 
+    /// If the current value is a string, returns a reference to its text; otherwise, returns
+    /// None. The text may be stored in the reader's internal buffer, so the reference cannot
+    /// outlive the next call to a method that takes `&mut self`, such as [next](RawReader::next).
+    fn read_str(&mut self) -> IonResult<Option<&str>>;
+
+    /// If the current value is a string, clears `dst` and appends the string's text to it,
+    /// returning `Some(())`; otherwise, leaves `dst` untouched and returns None. This allows a
+    /// single buffer to be reused when reading many strings. If `dst` already has enough
+    /// capacity to hold the text, no allocations will be performed.
+    fn read_string_into(&mut self, dst: &mut String) -> IonResult<Option<()>> {
+        Ok(self.read_str()?.map(|text| {
+            dst.clear();
+            dst.push_str(text);
+        }))
+    }
+
     /// Runs the provided closure, passing in a reference to the unparsed, unvalidated bytes of
     /// the string to be read and allowing a calculated value of any type to be returned. When
     /// possible, string_bytes_map will pass a reference directly to the bytes in the input buffer
@@ -87,6 +214,7 @@ pub trait RawReader {
     /// yet known to be of interest.
     fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
     where
+        Self: Sized,
         F: FnOnce(&[u8]) -> T;
 
     /// If the current value is a symbol, returns its value as a RawSymbolToken; otherwise,
@@ -101,8 +229,47 @@ pub trait RawReader {
     /// reference directly to the bytes in the input buffer rather than allocating a new array.
     fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
     where
+        Self: Sized,
         F: FnOnce(&[u8]) -> U;
 
+    /// If the current value is a blob, returns a reference to its bytes; otherwise, returns None.
+    /// As with [read_str](RawReader::read_str), the reference is only valid until the reader is
+    /// next used mutably.
+    fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+
+    /// If the current value is a blob, clears `dst` and appends the blob's bytes to it,
+    /// returning `Some(())`; otherwise, leaves `dst` untouched and returns None. Like
+    /// [read_string_into](RawReader::read_string_into), this allows a single buffer to be reused
+    /// when reading many blobs.
+    fn read_blob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>> {
+        Ok(self.read_blob_ref()?.map(|bytes| {
+            dst.clear();
+            dst.extend_from_slice(bytes);
+        }))
+    }
+
+    /// If the current value is a blob, passes its bytes to `f` in consecutive chunks of at most
+    /// `chunk_size` bytes and returns `Some(())`; otherwise, returns None without calling `f`.
+    /// If `f` returns an error, no further chunks are read and the error is returned.
+    ///
+    /// Readers that pull their input from an [io::Read](std::io::Read) source can use this to
+    /// copy very large blobs (to a file, for example) without holding the entire value in memory.
+    /// Returns an [IllegalOperation](crate::result::IonError::IllegalOperation) error if
+    /// `chunk_size` is zero.
+    fn read_blob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>>
+    where
+        Self: Sized,
+        F: FnMut(&[u8]) -> IonResult<()>,
+    {
+        if chunk_size == 0 {
+            return illegal_operation("The chunk size for a blob read must be greater than zero.");
+        }
+        match self.read_blob_ref()? {
+            Some(bytes) => bytes.chunks(chunk_size).try_for_each(f).map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// If the current value is a clob, returns its value as a Vec<u8>; otherwise, returns None.
     fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
 
@@ -111,8 +278,37 @@ pub trait RawReader {
     /// reference directly to the bytes in the input buffer rather than allocating a new array.
     fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
     where
+        Self: Sized,
         F: FnOnce(&[u8]) -> U;
 
+    /// If the current value is a clob, returns a reference to its bytes; otherwise, returns None.
+    /// As with [read_str](RawReader::read_str), the reference is only valid until the reader is
+    /// next used mutably.
+    fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+
+    /// Like [read_blob_into](RawReader::read_blob_into), but for clobs.
+    fn read_clob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>> {
+        Ok(self.read_clob_ref()?.map(|bytes| {
+            dst.clear();
+            dst.extend_from_slice(bytes);
+        }))
+    }
+
+    /// Like [read_blob_chunks](RawReader::read_blob_chunks), but for clobs.
+    fn read_clob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>>
+    where
+        Self: Sized,
+        F: FnMut(&[u8]) -> IonResult<()>,
+    {
+        if chunk_size == 0 {
+            return illegal_operation("The chunk size for a clob read must be greater than zero.");
+        }
+        match self.read_clob_ref()? {
+            Some(bytes) => bytes.chunks(chunk_size).try_for_each(f).map(Some),
+            None => Ok(None),
+        }
+    }
+
     /// If the current value is a timestamp, returns its value as a Timestamp;
     /// otherwise, returns None.
     fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
@@ -121,7 +317,7 @@ pub trait RawReader {
     /// otherwise, returns None.
     #[deprecated(
         since = "0.6.1",
-        note = "Please use the `read_timestamp` method instead."
+        note = "Please use the `read_timestamp` method and `Timestamp::to_chrono_datetime` instead."
     )]
     fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
 
@@ -130,12 +326,237 @@ pub trait RawReader {
     /// is not a container, returns Err.
     fn step_in(&mut self) -> IonResult<()>;
 
+    /// Like [step_in](RawReader::step_in), but first checks that the current container's type is
+    /// `expected`. If the reader is positioned over a container of some other type, returns an
+    /// [IonError::UnexpectedType] without stepping in. Values that are not containers are
+    /// rejected by `step_in` as usual.
+    fn step_in_expecting(&mut self, expected: IonType) -> IonResult<()> {
+        match self.ion_type() {
+            Some(found) if found.is_container() && found != expected => {
+                Err(IonError::UnexpectedType { expected, found })
+            }
+            _ => self.step_in(),
+        }
+    }
+
     /// Positions the cursor at the end of the container currently being traversed. Calling next()
     /// will position the cursor over the value that follows the container. If the cursor is not in
     /// a container (i.e. it is already at the top level), returns Err.
     fn step_out(&mut self) -> IonResult<()>;
 
     fn depth(&self) -> usize;
+
+    /// Wraps this reader in an [AnnotationPrefixReader], which returns an Err from `next()` if
+    /// any top-level value does not have an annotation whose text starts with `prefix`.
+    fn require_annotation_prefix(self, prefix: &str) -> AnnotationPrefixReader<Self>
+    where
+        Self: Sized,
+    {
+        AnnotationPrefixReader::new(self, prefix)
+    }
+}
+
+// Forwards each method to the boxed reader, which allows a `Box<dyn RawReader>` to be used
+// wherever a RawReader is expected. The `*_map` methods can't be called on the unsized reader,
+// so they are implemented using the equivalent methods that return references.
+// `read_big_decimal` and `read_datetime` are deprecated, but must still be forwarded.
+#[allow(deprecated)]
+impl<R: RawReader + ?Sized> RawReader for Box<R> {
+    fn ion_version(&self) -> (u8, u8) {
+        (**self).ion_version()
+    }
+
+    fn next(&mut self) -> IonResult<Option<StreamItem>> {
+        (**self).next()
+    }
+
+    fn ion_type(&self) -> Option<IonType> {
+        (**self).ion_type()
+    }
+
+    fn is_null(&self) -> bool {
+        (**self).is_null()
+    }
+
+    fn annotations(&self) -> &[RawSymbolToken] {
+        (**self).annotations()
+    }
+
+    fn annotations_len(&self) -> usize {
+        (**self).annotations_len()
+    }
+
+    fn first_annotation(&self) -> Option<&RawSymbolToken> {
+        (**self).first_annotation()
+    }
+
+    fn annotation_sids(&self) -> AnnotationSids<'_> {
+        (**self).annotation_sids()
+    }
+
+    fn has_annotation(&self, text: &str) -> bool {
+        (**self).has_annotation(text)
+    }
+
+    fn field_name(&self) -> Option<&RawSymbolToken> {
+        (**self).field_name()
+    }
+
+    fn field_name_sid(&self) -> Option<SymbolId> {
+        (**self).field_name_sid()
+    }
+
+    fn read_null(&mut self) -> IonResult<Option<IonType>> {
+        (**self).read_null()
+    }
+
+    fn read_bool(&mut self) -> IonResult<Option<bool>> {
+        (**self).read_bool()
+    }
+
+    fn read_i64(&mut self) -> IonResult<Option<i64>> {
+        (**self).read_i64()
+    }
+
+    fn read_integer(&mut self) -> IonResult<Option<Integer>> {
+        (**self).read_integer()
+    }
+
+    fn read_f32(&mut self) -> IonResult<Option<f32>> {
+        (**self).read_f32()
+    }
+
+    fn read_f32_exact(&mut self) -> IonResult<Option<f32>> {
+        (**self).read_f32_exact()
+    }
+
+    fn read_float(&mut self) -> IonResult<Option<Float>> {
+        (**self).read_float()
+    }
+
+    fn read_float_kind(&mut self) -> IonResult<Option<FloatKind>> {
+        (**self).read_float_kind()
+    }
+
+    fn read_f64(&mut self) -> IonResult<Option<f64>> {
+        (**self).read_f64()
+    }
+
+    fn read_decimal(&mut self) -> IonResult<Option<Decimal>> {
+        (**self).read_decimal()
+    }
+
+    #[cfg(feature = "std")]
+    fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>> {
+        (**self).read_big_decimal()
+    }
+
+    fn read_string(&mut self) -> IonResult<Option<String>> {
+        (**self).read_string()
+    }
+
+    fn string_ref_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&str) -> T,
+    {
+        Ok((**self).read_str()?.map(f))
+    }
+
+    fn read_str(&mut self) -> IonResult<Option<&str>> {
+        (**self).read_str()
+    }
+
+    fn read_string_into(&mut self, dst: &mut String) -> IonResult<Option<()>> {
+        (**self).read_string_into(dst)
+    }
+
+    // Unlike the readers' own implementations, this validates the string's UTF-8 before calling
+    // `f`.
+    fn string_bytes_map<F, T>(&mut self, f: F) -> IonResult<Option<T>>
+    where
+        F: FnOnce(&[u8]) -> T,
+    {
+        Ok((**self).read_str()?.map(|text| f(text.as_bytes())))
+    }
+
+    fn read_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
+        (**self).read_symbol()
+    }
+
+    fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        (**self).read_blob_bytes()
+    }
+
+    fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        Ok((**self).read_blob_ref()?.map(f))
+    }
+
+    fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>> {
+        (**self).read_blob_ref()
+    }
+
+    fn read_blob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>> {
+        (**self).read_blob_into(dst)
+    }
+
+    fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
+        (**self).read_clob_bytes()
+    }
+
+    fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
+    where
+        F: FnOnce(&[u8]) -> U,
+    {
+        Ok((**self).read_clob_ref()?.map(f))
+    }
+
+    fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>> {
+        (**self).read_clob_ref()
+    }
+
+    fn read_clob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>> {
+        (**self).read_clob_into(dst)
+    }
+
+    fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
+        (**self).read_timestamp()
+    }
+
+    fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>> {
+        (**self).read_datetime()
+    }
+
+    fn step_in(&mut self) -> IonResult<()> {
+        (**self).step_in()
+    }
+
+    fn step_in_expecting(&mut self, expected: IonType) -> IonResult<()> {
+        (**self).step_in_expecting(expected)
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        (**self).step_out()
+    }
+
+    fn depth(&self) -> usize {
+        (**self).depth()
+    }
+}
+
+/// An iterator over the symbol IDs of a value's annotations. See [RawReader::annotation_sids].
+pub struct AnnotationSids<'a> {
+    annotations: slice::Iter<'a, RawSymbolToken>,
+}
+
+impl<'a> Iterator for AnnotationSids<'a> {
+    type Item = SymbolId;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.annotations.find_map(RawSymbolToken::local_sid)
+    }
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -150,3 +571,64 @@ pub enum StreamItem {
     /// $ion_symbol_table annotation) are still considered values.
     Value(IonType, bool),
 }
+
+#[cfg(test)]
+mod raw_reader_tests {
+    use super::*;
+    use crate::raw_reader::StreamItem::*;
+    use crate::text::raw_text_reader::RawTextReader;
+    use crate::{RawBinaryReader, Reader};
+    use std::io;
+
+    #[rustfmt::skip]
+    const BINARY: &[u8] = &[
+        0xE0, 0x01, 0x00, 0xEA, // IVM
+        0x83, b'f', b'o', b'o', // "foo"
+        0xB2, 0x21, 0x07,       // [7]
+        0xA2, 0x01, 0x02,       // {{ 0x01 0x02 }}
+    ];
+
+    const TEXT: &str = r#""foo" [7] {{ AQI= }}"#;
+
+    fn boxed_reader(is_binary: bool) -> Box<dyn RawReader> {
+        if is_binary {
+            Box::new(RawBinaryReader::new(io::Cursor::new(BINARY)))
+        } else {
+            Box::new(RawTextReader::new(TEXT))
+        }
+    }
+
+    #[test]
+    fn read_through_trait_object() -> IonResult<()> {
+        for is_binary in [true, false].iter() {
+            let mut reader = boxed_reader(*is_binary);
+            if *is_binary {
+                assert_eq!(Some(VersionMarker(1, 0)), reader.next()?);
+            }
+            assert_eq!(Some(Value(IonType::String, false)), reader.next()?);
+            assert_eq!(Some(3), reader.string_ref_map(|text| text.len())?);
+            assert_eq!(Some(Value(IonType::List, false)), reader.next()?);
+            reader.step_in()?;
+            assert_eq!(Some(Value(IonType::Integer, false)), reader.next()?);
+            assert_eq!(Some(7), reader.read_i64()?);
+            reader.step_out()?;
+            assert_eq!(Some(Value(IonType::Blob, false)), reader.next()?);
+            assert_eq!(
+                Some(vec![1, 2]),
+                reader.blob_ref_map(|bytes| bytes.to_vec())?
+            );
+            assert_eq!(None, reader.next()?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn boxed_reader_can_be_wrapped_in_a_reader() -> IonResult<()> {
+        for is_binary in [true, false].iter() {
+            let mut reader = Reader::new(boxed_reader(*is_binary));
+            assert_eq!(Some((IonType::String, false)), reader.next()?);
+            assert_eq!(Some("foo".to_string()), reader.read_string()?);
+        }
+        Ok(())
+    }
+}
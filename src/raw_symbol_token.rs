@@ -1,4 +1,9 @@
+#[cfg(feature = "std")]
+use crate::text::writer::{is_unquoted_symbol, write_quoted_symbol};
 use crate::types::SymbolId;
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use core::fmt;
 
 /// A symbol token encountered in a text or binary Ion stream.
 /// [RawSymbolToken]s do not store import source information for the token encountered. Similarly,
@@ -65,3 +70,39 @@ impl From<&str> for RawSymbolToken {
         text_token(value.to_string())
     }
 }
+
+/// Formats the token as an Ion symbol: a symbol ID is written as `$10`, and text is written
+/// unquoted if it is an identifier (`foo`) and in single quotes otherwise (`'foo bar'`, `'null'`).
+#[cfg(feature = "std")]
+impl fmt::Display for RawSymbolToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RawSymbolToken::SymbolId(sid) => write!(f, "${}", sid),
+            RawSymbolToken::Text(text) if is_unquoted_symbol(text) => f.write_str(text),
+            RawSymbolToken::Text(text) => {
+                let mut quoted = Vec::with_capacity(text.len() + 2);
+                write_quoted_symbol(&mut quoted, text).map_err(|_| fmt::Error)?;
+                // Escaping only replaces ASCII characters, so the quoted text is still UTF-8.
+                f.write_str(core::str::from_utf8(&quoted).map_err(|_| fmt::Error)?)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod raw_symbol_token_tests {
+    use super::*;
+    use rstest::*;
+
+    #[rstest]
+    #[case(local_sid_token(10), "$10")]
+    #[case(text_token("foo"), "foo")]
+    #[case(text_token("foo bar"), "'foo bar'")]
+    #[case(text_token("null"), "'null'")]
+    #[case(text_token("$10"), "'$10'")]
+    #[case(text_token("it's"), "'it\\'s'")]
+    #[case(text_token(""), "''")]
+    fn display(#[case] token: RawSymbolToken, #[case] expected: &str) {
+        assert_eq!(token.to_string(), expected);
+    }
+}
@@ -1,20 +1,43 @@
 use std::boxed::Box;
+use std::collections::{BTreeSet, HashMap};
 use std::io;
-use std::ops::Range;
+use std::ops::{Deref, DerefMut, Range};
 
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
 use delegate::delegate;
 
-use crate::constants::v1_0::system_symbol_ids;
+use crate::allocator::{Allocator, Global};
+use crate::binary::constants::v1_0::IVM;
+use crate::binary::raw_binary_reader::{
+    CursorState, RawBinaryReaderBuilder, StreamPosition, ValueEncoding,
+};
+use crate::catalog::{resolve_import, Catalog, MapCatalog};
+use crate::constants::v1_0::{self, system_symbol_ids};
+use crate::data_source::from_compressed_read;
+use crate::lazy::EncodedChild;
+use crate::raw_reader::AnnotationSids;
 use crate::raw_reader::StreamItem::*;
 use crate::raw_symbol_token::RawSymbolToken;
-use crate::result::IonResult;
+use crate::result::{
+    decoding_error, illegal_operation, limit_exceeded_error, limit_exceeded_error_raw, IonError,
+    IonResult,
+};
+use crate::symbol::Symbol;
 use crate::symbol_table::SymbolTable;
 use crate::system_event_handler::SystemEventHandler;
+use crate::text::raw_text_reader::RawTextReader;
 use crate::types::decimal::Decimal;
+use crate::types::float::{Float, FloatKind};
+use crate::types::integer::Integer;
 use crate::types::timestamp::Timestamp;
-use crate::{IonType, RawBinaryReader, RawReader};
+use crate::types::SymbolId;
+use crate::value::owned::{
+    local_sid_token, text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedSymbolToken,
+    OwnedValue,
+};
+use crate::value::{AnyInt, Element, SymbolToken};
+use crate::{IonDataSource, IonType, RawBinaryReader, RawReader, ToIonDataSource};
 
 /// A streaming Ion reader that resolves symbol IDs into the appropriate text.
 ///
@@ -24,6 +47,137 @@ pub struct Reader<R: RawReader> {
     raw_reader: R,
     symbol_table: SymbolTable,
     system_event_handler: Option<Box<dyn SystemEventHandler>>,
+    catalog: Box<dyn Catalog>,
+    depth_limits: DepthLimits,
+    resource_limits: ResourceLimits,
+    struct_policies: StructPolicies,
+    value_path: ValuePath,
+}
+
+/// Determines what the methods that read structs into [OwnedElement]s, like
+/// [Reader::read_element], do when a struct has more than one field with the same name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum DuplicateFieldPolicy {
+    /// Every field is kept. This is the default.
+    #[default]
+    KeepAll,
+    /// Only the first field with each name is kept.
+    KeepFirst,
+    /// Only the last field with each name is kept. It takes the place of the first field with
+    /// that name.
+    KeepLast,
+    /// A struct with a repeated field name is a decoding error.
+    Error,
+}
+
+/// Determines what the methods that read structs into [OwnedElement]s, like
+/// [Reader::read_element], do with a field name whose symbol ID has no text in the current
+/// symbol table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum UnknownFieldNamePolicy {
+    /// The field name is a symbol token with the symbol ID and no text. This is the default.
+    #[default]
+    SymbolId,
+    /// The field name is given placeholder text made from its symbol ID, like `$10`.
+    Placeholder,
+    /// A field name with unknown text is a decoding error.
+    Error,
+}
+
+// How the Reader handles duplicate and unknown field names when it reads structs into
+// OwnedElements. See [ReaderBuilder].
+#[derive(Clone, Copy, Debug, Default)]
+struct StructPolicies {
+    duplicate_fields: DuplicateFieldPolicy,
+    unknown_field_names: UnknownFieldNamePolicy,
+}
+
+// Identifies a field name when looking for duplicates: by its text or, if it has none, by its
+// symbol ID.
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum FieldKey {
+    Text(String),
+    SymbolId(SymbolId),
+}
+
+// The maximum depths that the Reader will allow `step_in()` to reach. A limit configured for a
+// specific container type takes precedence over `max_depth`. `None` means there is no limit.
+#[derive(Debug, Default)]
+struct DepthLimits {
+    max_depth: Option<usize>,
+    max_list_depth: Option<usize>,
+    max_s_expression_depth: Option<usize>,
+    max_struct_depth: Option<usize>,
+}
+
+impl DepthLimits {
+    fn max_depth_for(&self, ion_type: IonType) -> Option<usize> {
+        let type_limit = match ion_type {
+            IonType::List => self.max_list_depth,
+            IonType::SExpression => self.max_s_expression_depth,
+            IonType::Struct => self.max_struct_depth,
+            _ => None,
+        };
+        type_limit.or(self.max_depth)
+    }
+}
+
+// The limits that the Reader enforces on the symbol tables and annotations that it encounters.
+// See [ReaderBuilder]. `None` means there is no limit.
+#[derive(Clone, Copy, Debug, Default)]
+struct ResourceLimits {
+    max_symbol_table_size: Option<usize>,
+    max_annotations: Option<usize>,
+}
+
+// The location of the reader's current value, which the Reader adds to the decoding errors that
+// it returns. See [IonError::path] for how it is rendered.
+#[derive(Clone, Debug)]
+struct ValuePath {
+    // The number of values that `next()` has returned at the top level, or None if the reader has
+    // seeked and the count is no longer known.
+    top_level_values: Option<usize>,
+    // The containers that the reader has stepped into, outermost first.
+    containers: Vec<ContainerPath>,
+}
+
+#[derive(Clone, Debug)]
+struct ContainerPath {
+    ion_type: IonType,
+    // The container's own field name, if it is the value of a struct field
+    field_name: Option<RawSymbolToken>,
+    // The number of values that `next()` has returned inside the container
+    values: usize,
+}
+
+impl ValuePath {
+    fn new() -> ValuePath {
+        ValuePath {
+            top_level_values: Some(0),
+            containers: Vec::new(),
+        }
+    }
+
+    // Records that `next()` returned a value at the current depth.
+    fn value_returned(&mut self) {
+        match self.containers.last_mut() {
+            Some(container) => container.values += 1,
+            None => self.top_level_values = self.top_level_values.map(|count| count + 1),
+        }
+    }
+}
+
+// Defines Reader methods that forward to the raw reader method of the same name, adding the type
+// and location of the current value to any decoding error that it returns.
+macro_rules! forward_with_value_context {
+    ($(pub fn $name:ident(&mut self $(, $arg:ident: $arg_type:ty)*) -> $return_type:ty;)*) => {
+        $(
+            pub fn $name(&mut self $(, $arg: $arg_type)*) -> $return_type {
+                let result = self.raw_reader.$name($($arg),*);
+                result.map_err(|error| self.add_value_context(error))
+            }
+        )*
+    };
 }
 
 // FIXME: The `read_datetime` method is deprecated. However, this function body is generated by a
@@ -36,6 +190,103 @@ impl<C: RawReader> Reader<C> {
             raw_reader: raw_reader,
             symbol_table: SymbolTable::new(),
             system_event_handler: None,
+            catalog: Box::new(MapCatalog::new()),
+            depth_limits: DepthLimits::default(),
+            resource_limits: ResourceLimits::default(),
+            struct_policies: StructPolicies::default(),
+            value_path: ValuePath::new(),
+        }
+    }
+
+    /// Sets the maximum depth that the reader can reach by calling `step_in()`. Attempting to
+    /// step into a container that would exceed this depth will return a decoding error. This
+    /// can be used to protect against maliciously deep input. By default, there is no limit.
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        self.depth_limits.max_depth = Some(max_depth);
+    }
+
+    /// Sets the maximum depth that the reader can reach by calling `step_in()` on a container
+    /// of the specified type, overriding any limit configured with [set_max_depth](Self::set_max_depth).
+    /// Depth is measured the same way for every type: stepping into a top-level value reaches
+    /// depth 1. Returns an error if `ion_type` is not a container type.
+    pub fn set_max_depth_for(&mut self, ion_type: IonType, max_depth: usize) -> IonResult<()> {
+        let limit = match ion_type {
+            IonType::List => &mut self.depth_limits.max_list_depth,
+            IonType::SExpression => &mut self.depth_limits.max_s_expression_depth,
+            IonType::Struct => &mut self.depth_limits.max_struct_depth,
+            scalar => {
+                return illegal_operation(format!(
+                    "Cannot set a maximum depth for {}, which is not a container type.",
+                    scalar
+                ))
+            }
+        };
+        *limit = Some(max_depth);
+        Ok(())
+    }
+
+    /// Sets how methods that read structs into [OwnedElement]s, like
+    /// [read_element](Self::read_element), handle repeated field names. By default, every field
+    /// is kept.
+    pub fn set_duplicate_field_policy(&mut self, policy: DuplicateFieldPolicy) {
+        self.struct_policies.duplicate_fields = policy;
+    }
+
+    /// Sets how methods that read structs into [OwnedElement]s, like
+    /// [read_element](Self::read_element), handle field names whose symbol IDs have no text. By
+    /// default, such a field name has the symbol ID and no text.
+    pub fn set_unknown_field_name_policy(&mut self, policy: UnknownFieldNamePolicy) {
+        self.struct_policies.unknown_field_names = policy;
+    }
+
+    /// Steps into the container over which the reader is positioned. If doing so would exceed the
+    /// maximum depth configured for that container type, returns a decoding error instead.
+    pub fn step_in(&mut self) -> IonResult<()> {
+        let ion_type = self.raw_reader.ion_type();
+        if let Some(ion_type) = ion_type {
+            if let Some(max_depth) = self.depth_limits.max_depth_for(ion_type) {
+                let new_depth = self.raw_reader.depth() + 1;
+                if new_depth > max_depth {
+                    let error = limit_exceeded_error_raw(format!(
+                        "Stepping into this {} would reach depth {}, exceeding the maximum of {}.",
+                        ion_type, new_depth, max_depth
+                    ));
+                    return Err(self.add_value_context(error));
+                }
+            }
+        }
+        let field_name = self.raw_reader.field_name().cloned();
+        if let Err(error) = self.raw_reader.step_in() {
+            return Err(self.add_value_context(error));
+        }
+        if let Some(ion_type) = ion_type {
+            self.value_path.containers.push(ContainerPath {
+                ion_type,
+                field_name,
+                values: 0,
+            });
+        }
+        Ok(())
+    }
+
+    /// Steps out of the current container, skipping any of its values that have not been read.
+    pub fn step_out(&mut self) -> IonResult<()> {
+        if let Err(error) = self.raw_reader.step_out() {
+            return Err(self.add_value_context(error));
+        }
+        self.value_path.containers.pop();
+        Ok(())
+    }
+
+    /// Like [step_in](Self::step_in), but first checks that the current container's type is
+    /// `expected`, returning an [IonError::UnexpectedType] if it is a container of some other
+    /// type. Configured depth limits are enforced as they are by `step_in`.
+    pub fn step_in_expecting(&mut self, expected: IonType) -> IonResult<()> {
+        match self.raw_reader.ion_type() {
+            Some(found) if found.is_container() && found != expected => {
+                Err(IonError::UnexpectedType { expected, found })
+            }
+            _ => self.step_in(),
         }
     }
 
@@ -51,40 +302,156 @@ impl<C: RawReader> Reader<C> {
         self.system_event_handler = Some(Box::new(handler));
     }
 
+    /// Sets the [Catalog] that will be used to resolve shared symbol tables imported by local
+    /// symbol tables. By default, the reader uses an empty catalog, so any import of a shared
+    /// symbol table will produce a decoding error.
+    pub fn set_catalog<T>(&mut self, catalog: T)
+    where
+        T: 'static + Catalog,
+    {
+        self.catalog = Box::new(catalog);
+    }
+
     /// Advances the raw reader to the next user-level Ion value, processing any system-level directives
     /// encountered along the way.
     pub fn next(&mut self) -> IonResult<Option<(IonType, bool)>> {
+        match self.next_user_value() {
+            Ok(Some(item)) => {
+                self.value_path.value_returned();
+                Ok(Some(item))
+            }
+            Ok(None) => Ok(None),
+            // The type of the value being advanced to is not known yet.
+            Err(error) => Err(error.with_context(None, || self.value_path_string(true))),
+        }
+    }
+
+    /// Like [Reader::next], but also returns the system values that [Reader::next] processes
+    /// and skips: Ion version markers and local symbol tables. Each one has already been applied
+    /// by the time it is returned, so the reader's [symbol table](Reader::symbol_table) reflects
+    /// it and symbols in the values that follow resolve as usual. This is useful for tools that
+    /// inspect a stream's encoding rather than just its data.
+    ///
+    /// ```
+    /// use ion_rs::result::IonResult;
+    /// use ion_rs::text::raw_text_reader::RawTextReader;
+    /// use ion_rs::value::SymbolToken;
+    /// use ion_rs::{IonType, Reader, SystemStreamItem};
+    /// # fn main() -> IonResult<()> {
+    ///
+    /// let text = "$ion_1_0 $ion_symbol_table::{symbols: [\"foo\"]} $10";
+    /// let mut reader = Reader::new(RawTextReader::new(text));
+    /// assert_eq!(
+    ///     reader.next_system_item()?,
+    ///     Some(SystemStreamItem::VersionMarker(1, 0))
+    /// );
+    /// assert_eq!(
+    ///     reader.next_system_item()?,
+    ///     Some(SystemStreamItem::SymbolTable {
+    ///         is_append: false,
+    ///         new_symbols_start: 10
+    ///     })
+    /// );
+    /// assert_eq!(
+    ///     reader.next_system_item()?,
+    ///     Some(SystemStreamItem::Value(IonType::Symbol, false))
+    /// );
+    /// assert_eq!(reader.read_symbol()?.unwrap().text(), Some("foo"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn next_system_item(&mut self) -> IonResult<Option<SystemStreamItem>> {
+        match self.next_item() {
+            Ok(Some(item)) => {
+                if let SystemStreamItem::Value(_, _) = item {
+                    self.value_path.value_returned();
+                }
+                Ok(Some(item))
+            }
+            Ok(None) => Ok(None),
+            Err(error) => Err(error.with_context(None, || self.value_path_string(true))),
+        }
+    }
+
+    fn next_user_value(&mut self) -> IonResult<Option<(IonType, bool)>> {
         loop {
-            match self.raw_reader.next()? {
-                Some(VersionMarker(major, minor)) => {
-                    self.symbol_table.reset();
-                    self.invoke_on_ivm_handler((major, minor));
-                    self.invoke_on_symbol_table_reset_handler();
+            match self.next_item()? {
+                Some(SystemStreamItem::Value(ion_type, is_null)) => {
+                    return Ok(Some((ion_type, is_null)))
                 }
-                Some(Value(IonType::Struct, false)) => {
-                    // If the first annotation is $ion_symbol_table...
-                    match self.raw_reader.annotations() {
-                        [symbol, ..]
-                            if symbol.matches(
-                                system_symbol_ids::ION_SYMBOL_TABLE,
-                                "$ion_symbol_table",
-                            ) =>
-                        {
-                            self.read_symbol_table()?;
-                        }
-                        _ => return Ok(Some((IonType::Struct, false))),
+                Some(_) => {}
+                None => return Ok(None),
+            }
+        }
+    }
+
+    // Advances the raw reader to the next value or system value, applying any system value.
+    fn next_item(&mut self) -> IonResult<Option<SystemStreamItem>> {
+        let item = self.raw_reader.next()?;
+        if let Some(Value(_, _)) = item {
+            self.check_annotations_count()?;
+        }
+        match item {
+            Some(VersionMarker(major, minor)) => {
+                self.symbol_table.reset();
+                self.invoke_on_ivm_handler((major, minor));
+                self.invoke_on_symbol_table_reset_handler();
+                Ok(Some(SystemStreamItem::VersionMarker(major, minor)))
+            }
+            Some(Value(IonType::Struct, false)) => {
+                // If the first annotation is $ion_symbol_table...
+                match self.raw_reader.annotations() {
+                    [symbol, ..]
+                        if symbol
+                            .matches(system_symbol_ids::ION_SYMBOL_TABLE, "$ion_symbol_table") =>
+                    {
+                        let (is_append, new_symbols_start) = self.read_symbol_table()?;
+                        Ok(Some(SystemStreamItem::SymbolTable {
+                            is_append,
+                            new_symbols_start,
+                        }))
                     }
+                    _ => Ok(Some(SystemStreamItem::Value(IonType::Struct, false))),
                 }
-                Some(Value(ion_type, is_null)) => return Ok(Some((ion_type, is_null))),
-                None => return Ok(None),
             }
+            Some(Value(ion_type, is_null)) => Ok(Some(SystemStreamItem::Value(ion_type, is_null))),
+            None => Ok(None),
+        }
+    }
+
+    // Returns an error if the current value has more annotations than the configured maximum.
+    fn check_annotations_count(&self) -> IonResult<()> {
+        if let Some(max_annotations) = self.resource_limits.max_annotations {
+            let count = self.raw_reader.annotations().len();
+            if count > max_annotations {
+                return limit_exceeded_error(format!(
+                    "Found a value with {} annotations, exceeding the maximum of {}.",
+                    count, max_annotations
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    // Returns an error if a symbol table containing `size` symbols (including the system symbols)
+    // would exceed the configured maximum.
+    fn check_symbol_table_size(&self, size: usize) -> IonResult<()> {
+        match self.resource_limits.max_symbol_table_size {
+            Some(max_size) if size > max_size => limit_exceeded_error(format!(
+                "The symbol table would contain at least {} symbols, exceeding the maximum of {}.",
+                size, max_size
+            )),
+            _ => Ok(()),
         }
     }
 
-    fn read_symbol_table(&mut self) -> IonResult<()> {
+    // Applies the local symbol table that the reader is positioned on, returning whether it was
+    // appended to the current table and the ID of the first symbol in its `symbols` list.
+    fn read_symbol_table(&mut self) -> IonResult<(bool, usize)> {
         self.raw_reader.step_in()?;
 
         let mut is_append = false;
+        let mut imported_symbols = vec![];
         let mut new_symbols = vec![];
 
         while let Some(Value(ion_type, is_null)) = self.raw_reader.next()? {
@@ -98,13 +465,22 @@ impl<C: RawReader> Reader<C> {
                 (symbol, IonType::Symbol, false)
                     if symbol.matches(system_symbol_ids::IMPORTS, "imports") =>
                 {
-                    // TODO: SST imports. This implementation only supports local symbol
-                    //       table imports and appends.
+                    // Importing `$ion_symbol_table` appends the new symbols to the current
+                    // table. Per the spec, any other symbol is treated as if there were no
+                    // imports.
                     let import_symbol = self.raw_reader.read_symbol()?.unwrap();
-                    if !import_symbol.matches(3, "$ion_symbol_table") {
-                        unimplemented!("Can't handle non-$ion_symbol_table imports value.");
-                    }
-                    is_append = true;
+                    is_append = import_symbol
+                        .matches(system_symbol_ids::ION_SYMBOL_TABLE, "$ion_symbol_table");
+                }
+                // The field name is either SID 6 or the text 'imports' and the
+                // field value is a non-null list of shared symbol tables to import
+                (symbol, IonType::List, false)
+                    if symbol.matches(system_symbol_ids::IMPORTS, "imports") =>
+                {
+                    imported_symbols = self.read_imports()?;
+                    self.check_symbol_table_size(
+                        v1_0::SYSTEM_SYMBOLS.len() + imported_symbols.len(),
+                    )?;
                 }
                 // The field name is either SID 7 or the text 'imports' and the
                 // field value is a non-null list
@@ -113,40 +489,112 @@ impl<C: RawReader> Reader<C> {
                 {
                     self.raw_reader.step_in()?;
                     while let Some(Value(IonType::String, false)) = self.raw_reader.next()? {
+                        // Whether or not the symbols are appended, the new table will contain
+                        // at least the system symbols and the symbols declared here.
+                        self.check_symbol_table_size(
+                            v1_0::SYSTEM_SYMBOLS.len() + new_symbols.len() + 1,
+                        )?;
                         let text = self.raw_reader.read_string()?.unwrap();
                         new_symbols.push(text);
                     }
                     self.raw_reader.step_out()?;
                 }
-                something_else => {
-                    unimplemented!("No support for {:?}", something_else);
-                }
+                // Per the spec, any other fields are ignored.
+                _ => {}
             }
         }
 
-        if is_append {
+        let new_ids_start = if is_append {
             // We're adding new symbols to the end of the symbol table.
             let new_ids_start = self.symbol_table.len();
+            self.check_symbol_table_size(new_ids_start + new_symbols.len())?;
             for new_symbol in new_symbols.drain(..) {
-                let _id = self.symbol_table.intern(new_symbol);
+                let _id = self.symbol_table.add_symbol(new_symbol);
             }
             // If a symtab event handler is defined, pass it an immutable reference to the symbol
             // table and the ID of the first new symbol that was added.
             self.invoke_on_append_handler(new_ids_start);
+            new_ids_start
         } else {
             // The symbol table has been set by defining new symbols without importing the current
             // symbol table.
+            self.check_symbol_table_size(
+                v1_0::SYSTEM_SYMBOLS.len() + imported_symbols.len() + new_symbols.len(),
+            )?;
             self.symbol_table.reset();
-            for new_symbol in new_symbols.drain(..) {
-                let _id = self.symbol_table.intern(new_symbol);
+            let new_ids_start = v1_0::SYSTEM_SYMBOLS.len() + imported_symbols.len();
+            for new_symbol in imported_symbols.drain(..).chain(new_symbols.drain(..)) {
+                let _id = self.symbol_table.add_symbol(new_symbol);
             }
             // If a symtab event handler is defined, pass it an immutable reference to the symbol
             // table so it can be inspected.
             self.invoke_on_symbol_table_reset_handler();
-        }
+            new_ids_start
+        };
 
         self.raw_reader.step_out()?;
-        Ok(())
+        Ok((is_append, new_ids_start))
+    }
+
+    // Reads the list of shared symbol tables in a local symbol table's `imports` field, returning
+    // the symbols that they import in order. Each table is looked up in the reader's catalog.
+    fn read_imports(&mut self) -> IonResult<Vec<String>> {
+        let mut imported_symbols = vec![];
+        self.raw_reader.step_in()?;
+        while let Some(Value(ion_type, is_null)) = self.raw_reader.next()? {
+            // Per the spec, imports that are not structs are ignored.
+            if ion_type != IonType::Struct || is_null {
+                continue;
+            }
+            let mut name = None;
+            let mut version = None;
+            let mut max_id = None;
+            self.raw_reader.step_in()?;
+            while let Some(Value(ion_type, is_null)) = self.raw_reader.next()? {
+                let field = self.raw_reader.field_name().and_then(|field| {
+                    [
+                        system_symbol_ids::NAME,
+                        system_symbol_ids::VERSION,
+                        system_symbol_ids::MAX_ID,
+                    ]
+                    .iter()
+                    .copied()
+                    .find(|sid| field.matches(*sid, v1_0::SYSTEM_SYMBOLS[*sid]))
+                });
+                match (field, ion_type, is_null) {
+                    (Some(system_symbol_ids::NAME), IonType::String, false) => {
+                        name = self.raw_reader.read_string()?
+                    }
+                    (Some(system_symbol_ids::VERSION), IonType::Integer, false) => {
+                        version = self.raw_reader.read_i64()?
+                    }
+                    (Some(system_symbol_ids::MAX_ID), IonType::Integer, false) => {
+                        max_id = self.raw_reader.read_i64()?
+                    }
+                    // Any other fields are ignored.
+                    _ => {}
+                }
+            }
+            self.raw_reader.step_out()?;
+
+            // Imports without a name (or that name the system symbol table) are ignored.
+            let name = match name {
+                Some(name) if name != "$ion" => name,
+                _ => continue,
+            };
+            let version = match version {
+                Some(version) if version >= 1 => version as usize,
+                _ => 1,
+            };
+            imported_symbols.extend_from_slice(resolve_import(
+                self.catalog.as_ref(),
+                &name,
+                version,
+                max_id,
+            )?);
+        }
+        self.raw_reader.step_out()?;
+        Ok(imported_symbols)
     }
 
     fn invoke_on_ivm_handler(&mut self, ion_version: (u8, u8)) {
@@ -181,87 +629,1001 @@ impl<C: RawReader> Reader<C> {
             .map(|h| h.on_symbol_table_append(symbol_table, new_ids_start));
     }
 
+    // Adds the type and location of the current value to `error` if it is a decoding error.
+    fn add_value_context(&self, error: IonError) -> IonError {
+        error.with_context(self.raw_reader.ion_type(), || self.value_path_string(false))
+    }
+
+    // Renders the location of the current value, or of the value that `next()` was advancing to
+    // if `advancing` is true. If the reader is inside a container but has not yet read any of
+    // its values, the location of the container is returned instead.
+    fn value_path_string(&self, advancing: bool) -> String {
+        let mut path = String::new();
+        let mut parent = None;
+        let mut values = self.value_path.top_level_values;
+        // Each container that the reader has stepped into is the current value of its parent.
+        for container in &self.value_path.containers {
+            let index = values.map(|count| count.saturating_sub(1));
+            self.push_path_step(&mut path, parent, index, container.field_name.as_ref());
+            parent = Some(container.ion_type);
+            values = Some(container.values);
+        }
+        if advancing {
+            self.push_path_step(&mut path, parent, values, None);
+        } else if parent.is_none() || values != Some(0) {
+            let index = values.map(|count| count.saturating_sub(1));
+            self.push_path_step(&mut path, parent, index, self.raw_reader.field_name());
+        }
+        path
+    }
+
+    // Appends the step that locates a value within its parent container to `path`. `parent` is
+    // None for top-level values. Struct fields are identified by name when it is known, and by
+    // their position otherwise.
+    fn push_path_step(
+        &self,
+        path: &mut String,
+        parent: Option<IonType>,
+        index: Option<usize>,
+        field_name: Option<&RawSymbolToken>,
+    ) {
+        use std::fmt::Write;
+        // Writing to a String cannot fail.
+        let _ = match (parent, field_name, index) {
+            (None, _, Some(index)) => write!(path, "${}", index),
+            (None, _, None) => write!(path, "$?"),
+            (Some(IonType::Struct), Some(token), _) => match (self.token_text(token), token) {
+                (Some(text), _) => write!(path, ".{}", text),
+                (None, RawSymbolToken::SymbolId(sid)) => write!(path, ".${}", sid),
+                (None, RawSymbolToken::Text(text)) => write!(path, ".{}", text),
+            },
+            (Some(_), _, Some(index)) => write!(path, "[{}]", index),
+            (Some(_), _, None) => write!(path, "[?]"),
+        };
+    }
+
     pub fn field_name(&self) -> Option<&str> {
-        match self.raw_reader.field_name() {
-            Some(RawSymbolToken::SymbolId(sid)) => self.symbol_table.text_for(*sid),
-            Some(RawSymbolToken::Text(text)) => Some(text.as_str()),
-            None => None,
+        self.raw_reader
+            .field_name()
+            .and_then(|raw_token| self.token_text(raw_token))
+    }
+
+    /// Returns the current field's name as a [Symbol], which has the name's text (if the
+    /// current symbol table defines it) and the symbol ID that it was encoded as (if any).
+    pub fn field_name_symbol(&self) -> Option<Symbol> {
+        self.raw_reader
+            .field_name()
+            .map(|raw_token| self.resolve_symbol(raw_token))
+    }
+
+    // Returns the text of `raw_token`, looking up its symbol ID in the current symbol table if
+    // the token does not have inline text.
+    fn token_text<'a>(&'a self, raw_token: &'a RawSymbolToken) -> Option<&'a str> {
+        match raw_token {
+            RawSymbolToken::SymbolId(sid) => self.symbol_table.text_for(*sid),
+            RawSymbolToken::Text(text) => Some(text.as_str()),
+        }
+    }
+
+    /// Returns true if `raw_token` has the specified text, whether that text was encoded inline
+    /// or as a symbol ID that the current symbol table maps to `text`.
+    pub fn symbol_matches(&self, raw_token: &RawSymbolToken, text: &str) -> bool {
+        self.token_text(raw_token) == Some(text)
+    }
+
+    /// Advances through the struct that the reader is currently inside until it reaches a field
+    /// whose name is `name`, leaving the reader positioned over that field's value. Returns the
+    /// field value's type, or `None` if the end of the struct was reached without finding the
+    /// field. The values of any fields that are passed over are skipped without being read.
+    ///
+    /// Calling `find_field` again will continue searching from the current field, so repeated
+    /// calls will find each occurrence of a field name that appears more than once. Returns an
+    /// Err if the reader is at the top level.
+    pub fn find_field(&mut self, name: &str) -> IonResult<Option<IonType>> {
+        if self.depth() == 0 {
+            return illegal_operation("find_field() can only be called inside a struct");
+        }
+        while let Some((ion_type, _)) = self.next()? {
+            if self.field_name() == Some(name) {
+                return Ok(Some(ion_type));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Advances through the remaining fields of the struct that the reader is currently inside,
+    /// calling `f` for each field whose name is one of `names`. `f` is passed the index of the
+    /// field's name in `names` along with the reader, which is positioned over the field's value
+    /// so that `f` can read it or step into it. `f` must leave the reader at the same depth. The
+    /// other fields are skipped without being decoded; in binary Ion, this only requires reading
+    /// each value's header. When the end of the struct is reached, the reader remains inside it.
+    ///
+    /// Field names are matched by their text. The symbol IDs that the current symbol table
+    /// assigns to `names` are looked up once, so a field whose name is encoded as one of those
+    /// symbol IDs is matched without resolving its text. Returns an Err if the reader is at the
+    /// top level.
+    ///
+    /// ```
+    /// use ion_rs::{IonType, Reader};
+    /// # fn main() -> ion_rs::result::IonResult<()> {
+    /// let mut reader = Reader::from_input(&b"{id: 7, body: [1, 2, 3], status: ok}"[..])?;
+    /// reader.next()?;
+    /// reader.step_in()?;
+    /// let mut id = None;
+    /// let mut status = None;
+    /// reader.read_struct_fields(&["id", "status"], |index, reader| {
+    ///     match index {
+    ///         0 => id = reader.read_i64()?,
+    ///         _ => status = reader.read_symbol_value()?,
+    ///     }
+    ///     Ok(())
+    /// })?;
+    /// reader.step_out()?;
+    /// assert_eq!(id, Some(7));
+    /// assert_eq!(status.unwrap().text(), Some("ok"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_struct_fields<F>(&mut self, names: &[&str], mut f: F) -> IonResult<()>
+    where
+        F: FnMut(usize, &mut Self) -> IonResult<()>,
+    {
+        let depth = self.depth();
+        if depth == 0 {
+            return illegal_operation("read_struct_fields() can only be called inside a struct");
+        }
+        // Symbol tables can only change at the top level, so these remain valid for the struct.
+        let sids: Vec<Option<SymbolId>> = names
+            .iter()
+            .map(|name| self.symbol_table.sid_for(name))
+            .collect();
+        while self.next()?.is_some() {
+            let index = match self.raw_reader.field_name() {
+                Some(RawSymbolToken::SymbolId(sid)) => {
+                    match sids.iter().position(|s| *s == Some(*sid)) {
+                        Some(index) => Some(index),
+                        // The symbol table may define the same text more than once
+                        None => self
+                            .symbol_table
+                            .text_for(*sid)
+                            .and_then(|text| names.iter().position(|name| *name == text)),
+                    }
+                }
+                Some(RawSymbolToken::Text(text)) => {
+                    names.iter().position(|name| *name == text.as_str())
+                }
+                None => None,
+            };
+            if let Some(index) = index {
+                f(index, self)?;
+                if self.depth() != depth {
+                    return illegal_operation(
+                        "The read_struct_fields() callback must leave the reader at the same depth",
+                    );
+                }
+            }
         }
+        Ok(())
     }
 
     pub fn raw_annotations(&mut self) -> impl Iterator<Item = &RawSymbolToken> {
         self.raw_reader.annotations().iter()
     }
 
-    // TODO: The iterator should return a resolved symbol (OwnedSymbolToken?) that has
-    //       the symbol's ID, text, and import source (if available).
+    /// Returns an iterator over the text of the current value's annotations, in order. An
+    /// annotation whose symbol ID has no text in the current symbol table is `None`; use
+    /// [annotation_symbols](Self::annotation_symbols) to see its symbol ID as well.
     pub fn annotations(&self) -> impl Iterator<Item = Option<&str>> {
         self.raw_reader
             .annotations()
             .iter()
-            .map(move |raw_token| match raw_token {
-                RawSymbolToken::SymbolId(sid) => self.symbol_table.text_for(*sid),
-                RawSymbolToken::Text(text) => Some(text.as_str()),
-            })
+            .map(move |raw_token| self.token_text(raw_token))
+    }
+
+    /// Returns an iterator over the current value's annotations as [Symbol]s, in order.
+    pub fn annotation_symbols(&self) -> impl Iterator<Item = Symbol> + '_ {
+        self.raw_reader
+            .annotations()
+            .iter()
+            .map(move |raw_token| self.resolve_symbol(raw_token))
+    }
+
+    /// Returns an iterator over the text of the current value's annotations, in order.
+    /// Annotations whose symbol ID has no text in the current symbol table are skipped; use
+    /// [annotations](Self::annotations) to see every annotation.
+    pub fn annotations_iter(&self) -> impl Iterator<Item = &str> {
+        self.annotations().flatten()
+    }
+
+    /// Returns true if any of the current value's annotations has the specified text. Unlike
+    /// [RawReader::has_annotation], annotations encoded as symbol IDs are resolved using the
+    /// current symbol table.
+    pub fn has_annotation(&self, text: &str) -> bool {
+        self.raw_reader
+            .annotations()
+            .iter()
+            .any(|raw_token| self.symbol_matches(raw_token, text))
     }
 
     pub fn symbol_table(&self) -> &SymbolTable {
         &self.symbol_table
     }
 
-    // TODO: Offer other flavors of this method, including:
-    //       * a version that returns a resolved token (OwnedSymbolToken?) that can provide both
-    //         text and a SID if available
-    //       * a version that returns just the symbol's text, since that's what most users will want
+    // Replaces the symbol table, typically before seeking to a value that was encoded using
+    // `symbol_table` in another reader.
+    pub(crate) fn set_symbol_table(&mut self, symbol_table: SymbolTable) {
+        self.symbol_table = symbol_table;
+    }
+
+    /// Visits every remaining value in the stream, including those nested inside of containers,
+    /// and returns the set of distinct annotation texts that were encountered. Annotations whose
+    /// text is unknown are not included. Scalar values are skipped rather than read.
+    ///
+    /// When this method returns, the reader will be positioned at the end of the stream.
+    pub fn collect_annotations(&mut self) -> IonResult<BTreeSet<String>> {
+        let mut annotations = BTreeSet::new();
+        loop {
+            match self.next()? {
+                Some((ion_type, is_null)) => {
+                    for text in self.annotations_iter() {
+                        if !annotations.contains(text) {
+                            annotations.insert(text.to_owned());
+                        }
+                    }
+                    if ion_type.is_container() && !is_null {
+                        self.step_in()?;
+                    }
+                }
+                None if self.depth() > 0 => self.step_out()?,
+                None => return Ok(annotations),
+            }
+        }
+    }
+
+    /// If the reader is positioned over a value, reads it into an [OwnedElement] and returns it;
+    /// otherwise, returns None. Containers are read in their entirety. Symbol IDs in annotations,
+    /// field names, and symbol values are resolved using the current symbol table; those with
+    /// unknown text are represented by their local symbol ID.
+    ///
+    /// Integers that do not fit in an `i64` are read as arbitrary-size integers.
+    pub fn read_element(&mut self) -> IonResult<Option<OwnedElement>> {
+        match self.ion_type() {
+            Some(ion_type) => match self.materialize(ion_type) {
+                Ok(element) => Ok(Some(element)),
+                Err(error) => Err(self.add_value_context(error)),
+            },
+            None => Ok(None),
+        }
+    }
+
+    /// Reads the current value into an [OwnedElement] in the same way as [read_element]. The
+    /// element owns all of its data and is `Send` and `Sync`, so a thread that is parsing a
+    /// stream can hand complete values to other threads (for example, over a
+    /// [channel](std::sync::mpsc::channel)) while it continues reading.
+    ///
+    /// [read_element]: Self::read_element
+    pub fn read_value(&mut self) -> IonResult<Option<OwnedElement>> {
+        self.read_element()
+    }
+
+    /// Advances through the remaining values at the current depth, reading each one into an
+    /// [OwnedElement] and returning the first for which `predicate` returns `true`. Values that
+    /// do not satisfy the predicate are skipped. Returns None if the end of the stream (or of the
+    /// current container) is reached without finding a match.
+    pub fn next_matching<P>(&mut self, predicate: P) -> IonResult<Option<OwnedElement>>
+    where
+        P: Fn(&OwnedElement) -> bool,
+    {
+        while let Some((ion_type, _)) = self.next()? {
+            let element = self.materialize(ion_type)?;
+            if predicate(&element) {
+                return Ok(Some(element));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Steps into the list over which the reader is positioned and returns a [ListIter] that
+    /// reads each of its elements into an [OwnedElement] on demand. Unlike [read_element], the
+    /// list is never held in memory as a whole.
+    ///
+    /// When the ListIter is dropped, the reader steps back out of the list, whether or not
+    /// every element was visited; the next call to [next] will advance past the list.
+    /// Returns an Err if the reader is not positioned over a non-null list.
+    ///
+    /// [read_element]: Self::read_element
+    /// [next]: Self::next
+    pub fn iter_list(&mut self) -> IonResult<ListIter<'_, C>> {
+        match self.ion_type() {
+            Some(IonType::List) if !self.is_null() => {}
+            Some(IonType::List) => return illegal_operation("Cannot iterate over a null list."),
+            Some(ion_type) => {
+                return illegal_operation(format!("Cannot iterate over a {} as a list.", ion_type))
+            }
+            None => return illegal_operation("The reader is not positioned over a list."),
+        }
+        self.step_in()?;
+        let depth = self.depth();
+        Ok(ListIter {
+            reader: self,
+            depth,
+            is_done: false,
+        })
+    }
+
+    /// Steps into the container over which the reader is positioned and returns a
+    /// [ScopedContainer] that reads its values. The reader steps back out of the container when
+    /// the ScopedContainer is dropped or [closed](ScopedContainer::close), so each step in is
+    /// matched by a step out, even when the caller returns early:
+    ///
+    /// ```
+    /// use ion_rs::result::IonResult;
+    /// use ion_rs::{IonType, Reader};
+    /// # fn main() -> IonResult<()> {
+    ///
+    /// let mut reader = Reader::from_input(&b"[1, [2, 3], 4] 5"[..])?;
+    /// reader.next()?;
+    /// let mut sum = 0;
+    /// let mut list = reader.step_in_scoped()?;
+    /// while let Some((ion_type, _)) = list.next_value()? {
+    ///     if ion_type == IonType::List {
+    ///         let mut inner = list.step_in_scoped()?;
+    ///         while inner.next_value()?.is_some() {
+    ///             sum += inner.read_i64()?.unwrap();
+    ///         }
+    ///     } else {
+    ///         sum += list.read_i64()?.unwrap();
+    ///     }
+    /// }
+    /// list.close()?;
+    /// assert_eq!(sum, 10);
+    /// assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns an Err if the reader is not positioned over a non-null container, or if stepping
+    /// in would exceed a configured depth limit.
+    pub fn step_in_scoped(&mut self) -> IonResult<ScopedContainer<'_, C>> {
+        let container_type = match self.ion_type() {
+            Some(ion_type) if ion_type.is_container() && !self.is_null() => ion_type,
+            Some(ion_type) if ion_type.is_container() => {
+                return illegal_operation(format!("Cannot step into a null {}.", ion_type))
+            }
+            Some(ion_type) => {
+                return illegal_operation(format!("Cannot step into a {}.", ion_type))
+            }
+            None => return illegal_operation("The reader is not positioned over a container."),
+        };
+        self.step_in()?;
+        let depth = self.depth();
+        Ok(ScopedContainer {
+            reader: self,
+            container_type,
+            depth,
+        })
+    }
+
+    /// Returns an iterator that visits the remaining values at the current depth depth-first,
+    /// producing a [ReaderEvent] for each scalar and for the start and end of each container.
+    /// This allows a stream to be processed with iterator combinators instead of a hand-written
+    /// loop that calls [next](Self::next), [step_in](Self::step_in) and
+    /// [step_out](Self::step_out).
+    ///
+    /// If the [Events] iterator is dropped partway through a container, the reader steps back
+    /// out to the depth at which the iterator was created.
+    pub fn events(&mut self) -> Events<'_, C> {
+        let depth = self.depth();
+        Events {
+            reader: self,
+            depth,
+            containers: Vec::new(),
+            is_done: false,
+        }
+    }
+
+    /// Advances to the next value at the current depth and reads it into `reuse`, replacing its
+    /// previous contents. Returns `false` (leaving `reuse` untouched) if the end of the stream
+    /// or of the current container has been reached. Values are resolved as in [read_element].
+    ///
+    /// Wherever the new value has the same shape as the old one, `reuse`'s existing allocations
+    /// are kept: the annotations `Vec`, string and lob buffers, and the child `Vec` of a list or
+    /// s-expression (whose elements are reused recursively). Struct fields are always allocated
+    /// afresh. Reading many similar values into the same OwnedElement therefore performs few
+    /// allocations once the buffers have grown to fit.
+    ///
+    /// [read_element]: Self::read_element
+    pub fn read_element_into(&mut self, reuse: &mut OwnedElement) -> IonResult<bool> {
+        match self.next()? {
+            Some((ion_type, _)) => {
+                self.materialize_into(ion_type, reuse)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Reads the remaining values at the current depth of both this reader and `other`, and
+    /// returns whether the two sequences are equivalent under the Ion data model. Values are
+    /// compared pairwise with [Element::ion_eq], so only one value from each reader is held in
+    /// memory at a time; comparison stops at the first pair that differs.
+    ///
+    /// The readers can be over different encodings, since only the values they resolve to are
+    /// compared. System values such as symbol tables and version markers are not themselves
+    /// compared.
+    pub fn ion_eq<D: RawReader>(&mut self, other: &mut Reader<D>) -> IonResult<bool> {
+        let mut value: OwnedElement = OwnedValue::Null(IonType::Null).into();
+        let mut other_value = value.clone();
+        loop {
+            match (
+                self.read_element_into(&mut value)?,
+                other.read_element_into(&mut other_value)?,
+            ) {
+                (false, false) => return Ok(true),
+                (true, true) if value.ion_eq(&other_value) => {}
+                _ => return Ok(false),
+            }
+        }
+    }
+
+    fn materialize(&mut self, ion_type: IonType) -> IonResult<OwnedElement> {
+        let annotations: Vec<OwnedSymbolToken> = self
+            .raw_reader
+            .annotations()
+            .iter()
+            .map(|raw_token| self.resolve_token(raw_token))
+            .collect();
+        let value = self.materialize_value(ion_type)?;
+        Ok(OwnedElement::new(annotations, value))
+    }
+
+    fn materialize_into(&mut self, ion_type: IonType, target: &mut OwnedElement) -> IonResult<()> {
+        let (annotations, value) = target.parts_mut();
+        annotations.clear();
+        for raw_token in self.raw_reader.annotations() {
+            annotations.push(self.resolve_token(raw_token));
+        }
+
+        if self.is_null() {
+            *value = OwnedValue::Null(ion_type);
+            return Ok(());
+        }
+        match value {
+            OwnedValue::String(text) if ion_type == IonType::String => {
+                try_to!(self.read_string_into(text)?);
+            }
+            OwnedValue::Blob(bytes) if ion_type == IonType::Blob => {
+                try_to!(self.blob_ref_map(|blob| {
+                    bytes.clear();
+                    bytes.extend_from_slice(blob);
+                })?);
+            }
+            OwnedValue::Clob(bytes) if ion_type == IonType::Clob => {
+                try_to!(self.clob_ref_map(|clob| {
+                    bytes.clear();
+                    bytes.extend_from_slice(clob);
+                })?);
+            }
+            OwnedValue::List(sequence) if ion_type == IonType::List => {
+                self.materialize_sequence_into(sequence.children_mut())?;
+            }
+            OwnedValue::SExpression(sequence) if ion_type == IonType::SExpression => {
+                self.materialize_sequence_into(sequence.children_mut())?;
+            }
+            _ => *value = self.materialize_value(ion_type)?,
+        }
+        Ok(())
+    }
+
+    fn materialize_value(&mut self, ion_type: IonType) -> IonResult<OwnedValue> {
+        let value = if self.is_null() {
+            OwnedValue::Null(ion_type)
+        } else {
+            match ion_type {
+                IonType::Null => OwnedValue::Null(ion_type),
+                IonType::Boolean => OwnedValue::Boolean(try_to!(self.read_bool()?)),
+                IonType::Integer => OwnedValue::Integer(match try_to!(self.read_integer()?) {
+                    Integer::I64(value) => AnyInt::I64(value),
+                    Integer::BigInt(value) => AnyInt::BigInt(value),
+                }),
+                IonType::Float => OwnedValue::Float(try_to!(self.read_f64()?)),
+                IonType::Decimal => OwnedValue::Decimal(try_to!(self.read_decimal()?)),
+                IonType::Timestamp => OwnedValue::Timestamp(try_to!(self.read_timestamp()?)),
+                IonType::Symbol => {
+                    let raw_token = try_to!(self.read_raw_symbol()?);
+                    OwnedValue::Symbol(self.resolve_token(&raw_token))
+                }
+                IonType::String => OwnedValue::String(try_to!(self.read_string()?)),
+                IonType::Clob => OwnedValue::Clob(try_to!(self.read_clob_bytes()?)),
+                IonType::Blob => OwnedValue::Blob(try_to!(self.read_blob_bytes()?)),
+                IonType::List => OwnedValue::List(self.materialize_sequence()?),
+                IonType::SExpression => OwnedValue::SExpression(self.materialize_sequence()?),
+                IonType::Struct => OwnedValue::Struct(self.materialize_struct()?),
+            }
+        };
+        Ok(value)
+    }
+
+    fn materialize_sequence(&mut self) -> IonResult<OwnedSequence> {
+        let mut children = Vec::new();
+        self.step_in()?;
+        while let Some((ion_type, _)) = self.next()? {
+            children.push(self.materialize(ion_type)?);
+        }
+        self.step_out()?;
+        Ok(OwnedSequence::new(children))
+    }
+
+    // Reads the children of the current list or s-expression into `children`, reusing the
+    // elements that are already there and dropping any that are left over.
+    fn materialize_sequence_into(&mut self, children: &mut Vec<OwnedElement>) -> IonResult<()> {
+        self.step_in()?;
+        let mut index = 0;
+        while let Some((ion_type, _)) = self.next()? {
+            match children.get_mut(index) {
+                Some(child) => self.materialize_into(ion_type, child)?,
+                None => children.push(self.materialize(ion_type)?),
+            }
+            index += 1;
+        }
+        children.truncate(index);
+        self.step_out()
+    }
+
+    fn materialize_struct(&mut self) -> IonResult<OwnedStruct> {
+        let mut fields: Vec<(OwnedSymbolToken, OwnedElement)> = Vec::new();
+        // The index in `fields` of the first field with each name. This is only needed if
+        // duplicate fields are not all kept.
+        let mut field_indexes: HashMap<FieldKey, usize> = HashMap::new();
+        let duplicate_policy = self.struct_policies.duplicate_fields;
+        self.step_in()?;
+        while let Some((ion_type, _)) = self.next()? {
+            let raw_field_name = try_to!(self.raw_reader.field_name()).clone();
+            let field_name = self.resolve_field_name(&raw_field_name)?;
+            if duplicate_policy == DuplicateFieldPolicy::KeepAll {
+                fields.push((field_name, self.materialize(ion_type)?));
+                continue;
+            }
+            let key = match field_name.text() {
+                Some(text) => FieldKey::Text(text.to_string()),
+                None => FieldKey::SymbolId(field_name.local_sid().unwrap_or(0)),
+            };
+            match (field_indexes.get(&key), duplicate_policy) {
+                (None, _) => {
+                    field_indexes.insert(key, fields.len());
+                    fields.push((field_name, self.materialize(ion_type)?));
+                }
+                (Some(_), DuplicateFieldPolicy::KeepFirst) => {}
+                (Some(&index), DuplicateFieldPolicy::KeepLast) => {
+                    fields[index].1 = self.materialize(ion_type)?;
+                }
+                (Some(_), _) => {
+                    let name = match field_name.text() {
+                        Some(text) => text.to_string(),
+                        None => raw_field_name.to_string(),
+                    };
+                    return decoding_error(format!(
+                        "The struct has more than one field named '{}'.",
+                        name
+                    ));
+                }
+            }
+        }
+        self.step_out()?;
+        Ok(fields.into_iter().collect())
+    }
+
+    // Resolves a field name for `materialize_struct`, applying the reader's policy for field
+    // names whose symbol IDs have no text.
+    fn resolve_field_name(&self, raw_token: &RawSymbolToken) -> IonResult<OwnedSymbolToken> {
+        let token = self.resolve_token(raw_token);
+        if token.text().is_some() {
+            return Ok(token);
+        }
+        let sid = token.local_sid().unwrap_or(0);
+        match self.struct_policies.unknown_field_names {
+            UnknownFieldNamePolicy::SymbolId => Ok(token),
+            UnknownFieldNamePolicy::Placeholder => Ok(text_token(format!("${}", sid))),
+            UnknownFieldNamePolicy::Error => decoding_error(format!(
+                "Field name ${} has unknown text; it is not defined in the symbol table.",
+                sid
+            )),
+        }
+    }
+
+    fn resolve_token(&self, raw_token: &RawSymbolToken) -> OwnedSymbolToken {
+        match raw_token {
+            // The system symbol table's entry for $0 is a placeholder; $0 never has text.
+            RawSymbolToken::SymbolId(0) => local_sid_token(0),
+            RawSymbolToken::SymbolId(sid) => match self.symbol_table.text_for(*sid) {
+                Some(text) => text_token(text),
+                None => local_sid_token(*sid),
+            },
+            RawSymbolToken::Text(text) => text_token(text.as_str()),
+        }
+    }
+
+    // Like `resolve_token`, but keeps the symbol ID that the token was encoded as.
+    fn resolve_symbol(&self, raw_token: &RawSymbolToken) -> Symbol {
+        match raw_token {
+            RawSymbolToken::SymbolId(0) => Symbol::unknown_text(),
+            RawSymbolToken::SymbolId(sid) => match self.symbol_table.text_for(*sid) {
+                Some(text) => Symbol::with_text_and_sid(text, *sid),
+                None => Symbol::with_unknown_text(*sid),
+            },
+            RawSymbolToken::Text(text) => Symbol::with_text(text.as_str()),
+        }
+    }
+
+    /// Reads the current symbol value as a [Symbol], resolving its symbol ID (if it has one)
+    /// using the current symbol table. If the symbol ID is not defined in the symbol table, the
+    /// returned symbol will have the symbol ID but no text.
+    pub fn read_symbol_value(&mut self) -> IonResult<Option<Symbol>> {
+        Ok(self
+            .read_raw_symbol()?
+            .map(|raw_token| self.resolve_symbol(&raw_token)))
+    }
+
+    /// Reads the current symbol value, resolving its symbol ID (if it has one) using the current
+    /// symbol table. If the symbol ID is not defined in the symbol table, the returned token will
+    /// have the symbol ID but no text.
+    pub fn read_symbol(&mut self) -> IonResult<Option<OwnedSymbolToken>> {
+        Ok(self
+            .read_raw_symbol()?
+            .map(|raw_token| self.resolve_token(&raw_token)))
+    }
+
+    /// Like [read_symbol](Self::read_symbol), but copies the symbol's text into `dst`, replacing
+    /// its contents, so that a single buffer can be reused for many symbols. Returns `None`
+    /// (leaving `dst` untouched) if the current value is not a symbol, and a decoding error if
+    /// the symbol's text is unknown.
+    pub fn read_symbol_text_into(&mut self, dst: &mut String) -> IonResult<Option<()>> {
+        let raw_token = match self.read_raw_symbol()? {
+            Some(raw_token) => raw_token,
+            None => return Ok(None),
+        };
+        match self.token_text(&raw_token) {
+            Some(text) => {
+                dst.clear();
+                dst.push_str(text);
+                Ok(Some(()))
+            }
+            None => decoding_error(format!("Symbol {:?} has unknown text.", raw_token))
+                .map_err(|error| self.add_value_context(error)),
+        }
+    }
+
     pub fn read_raw_symbol(&mut self) -> IonResult<Option<RawSymbolToken>> {
-        self.raw_reader.read_symbol()
+        let result = self.raw_reader.read_symbol();
+        result.map_err(|error| self.add_value_context(error))
     }
 
     pub fn raw_field_name_token(&mut self) -> Option<&RawSymbolToken> {
         self.raw_reader.field_name()
     }
 
+    // Errors returned by these methods describe the value that was being read, as do those
+    // returned by `next`, `step_in` and `step_out`.
+    forward_with_value_context! {
+        pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
+        pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
+        pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
+        pub fn read_integer(&mut self) -> IonResult<Option<Integer>>;
+        pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
+        pub fn read_f32_exact(&mut self) -> IonResult<Option<f32>>;
+        pub fn read_float(&mut self) -> IonResult<Option<Float>>;
+        pub fn read_float_kind(&mut self) -> IonResult<Option<FloatKind>>;
+        pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
+        pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
+        pub fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
+        pub fn read_string(&mut self) -> IonResult<Option<String>>;
+        pub fn read_string_into(&mut self, dst: &mut String) -> IonResult<Option<()>>;
+        pub fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+        pub fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
+        pub fn read_blob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+        pub fn read_clob_into(&mut self, dst: &mut Vec<u8>) -> IonResult<Option<()>>;
+        pub fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
+        pub fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
+    }
+
     // The Reader needs to expose many of the same functions as the Cursor, but only some of those
     // need to be re-defined to allow for system value processing. Any method listed here will be
-    // delegated to self.raw_reader directly.
+    // delegated to self.raw_reader directly. Methods that return references into the raw reader's
+    // buffer or that take closures are delegated as-is, so their errors do not describe the value
+    // that was being read.
     delegate! {
         to self.raw_reader {
             pub fn is_null(&self) -> bool;
             pub fn ion_version(&self) -> (u8, u8);
             pub fn ion_type(&self) -> Option<IonType>;
-            pub fn read_null(&mut self) -> IonResult<Option<IonType>>;
-            pub fn read_bool(&mut self) -> IonResult<Option<bool>>;
-            pub fn read_i64(&mut self) -> IonResult<Option<i64>>;
-            pub fn read_f32(&mut self) -> IonResult<Option<f32>>;
-            pub fn read_f64(&mut self) -> IonResult<Option<f64>>;
-            pub fn read_decimal(&mut self) -> IonResult<Option<Decimal>>;
-            pub fn read_big_decimal(&mut self) -> IonResult<Option<BigDecimal>>;
-            pub fn read_string(&mut self) -> IonResult<Option<String>>;
-            pub fn read_blob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
-            pub fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>>;
-            pub fn read_datetime(&mut self) -> IonResult<Option<DateTime<FixedOffset>>>;
-            pub fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>>;
-            pub fn step_in(&mut self) -> IonResult<()>;
-            pub fn step_out(&mut self) -> IonResult<()>;
+            pub fn field_name_sid(&self) -> Option<SymbolId>;
+            pub fn annotation_sids(&self) -> AnnotationSids<'_>;
+            pub fn read_str(&mut self) -> IonResult<Option<&str>>;
+            pub fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>>;
+            pub fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>>;
             pub fn depth(&self) -> usize;
 
             pub fn string_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&str) -> U;
             pub fn string_bytes_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
 
             pub fn clob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
+            pub fn read_blob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
+            pub fn read_clob_chunks<F>(&mut self, chunk_size: usize, f: F) -> IonResult<Option<()>> where F: FnMut(&[u8]) -> IonResult<()>;
             pub fn blob_ref_map<F, U>(&mut self, f: F) -> IonResult<Option<U>> where F: FnOnce(&[u8]) -> U;
         }
     }
 }
 
-/// Functionality that is only available if the data source we're reading from is in-memory, like
-/// a Vec<u8> or &[u8].
-impl<T: AsRef<[u8]>> Reader<RawBinaryReader<io::Cursor<T>>> {
-    delegate! {
+impl<R: IonDataSource, A: Allocator + Clone> Reader<RawBinaryReader<R, A>> {
+    /// Replaces the reader's data source with `data_source` and returns the reader to the
+    /// beginning of a new stream. The symbol table is reset to the system symbol table. See
+    /// [RawBinaryReader::reset] for details.
+    pub fn reset(&mut self, data_source: R) {
+        self.raw_reader.reset(data_source);
+        self.symbol_table.reset();
+        self.value_path = ValuePath::new();
+    }
+
+    delegate! {
+        to self.raw_reader {
+            pub fn position(&self) -> Option<StreamPosition>;
+            pub fn encoded_range(&self) -> Option<Range<usize>>;
+            pub fn value_encoding(&self) -> Option<ValueEncoding>;
+        }
+    }
+}
+
+/// Random access, which is only available if the data source implements [io::Seek].
+impl<R: IonDataSource + io::Seek, A: Allocator + Clone> Reader<RawBinaryReader<R, A>> {
+    /// Repositions the reader so that the next call to [next](Self::next) will return the
+    /// top-level value at `position`. See [RawBinaryReader::seek_to] for details.
+    ///
+    /// The reader's symbol table is left as it is, so the value at `position` must have been
+    /// encoded using the symbol table that is currently in effect. This is always the case in
+    /// streams that only define one local symbol table (or only append to it), once the reader
+    /// has read past all of its symbol declarations; for example, after indexing the stream.
+    ///
+    /// Once the reader has seeked, decoding errors can no longer report the position of the
+    /// top-level value being read, so their paths begin with `$?`.
+    pub fn seek_to(&mut self, position: StreamPosition) -> IonResult<()> {
+        self.raw_reader.seek_to(position)?;
+        self.value_path.top_level_values = None;
+        self.value_path.containers.clear();
+        Ok(())
+    }
+
+    /// Like [seek_to](Self::seek_to), but takes a raw offset such as the start of a range
+    /// returned by [encoded_range](Self::encoded_range). See [RawBinaryReader::seek_to_offset].
+    pub fn seek_to_offset(&mut self, offset: usize) -> IonResult<()> {
+        self.raw_reader.seek_to_offset(offset)?;
+        self.value_path.top_level_values = None;
+        self.value_path.containers.clear();
+        Ok(())
+    }
+
+    /// After a method has returned a decoding error, skips the top-level value that could not
+    /// be read so that the next call to [next](Self::next) will return the first plausible
+    /// top-level value after it. Returns the range of bytes that were skipped. See
+    /// [RawBinaryReader::recover] for how the next value is found.
+    ///
+    /// Recovery is opt-in: an error is returned as usual, and reading can only continue past it
+    /// by calling this method. This allows a pipeline that ingests logs to record each error
+    /// (along with the offset of the value that caused it, which is the start of the returned
+    /// range) and salvage the rest of a partially corrupted file.
+    ///
+    /// The reader's symbol table is left as it is. If the value that could not be read was a
+    /// local symbol table, values that rely on it may have symbols with unknown text.
+    pub fn recover(&mut self) -> IonResult<Range<usize>> {
+        let skipped = self.raw_reader.recover()?;
+        self.value_path.top_level_values = None;
+        self.value_path.containers.clear();
+        Ok(skipped)
+    }
+
+    /// Reads the value (or annotations wrapper) beginning at `offset` into an [OwnedElement]. The
+    /// value may be nested inside a container, and is read as-is even if it looks like a system
+    /// value. The reader is left positioned over it.
+    pub(crate) fn read_element_at(&mut self, offset: usize) -> IonResult<OwnedElement> {
+        let ion_type = self.next_raw_value_at(offset)?;
+        self.materialize(ion_type)
+            .map_err(|error| self.add_value_context(error))
+    }
+
+    /// Returns the location of each child of the container beginning at `offset` without reading
+    /// the children themselves.
+    pub(crate) fn read_children_at(&mut self, offset: usize) -> IonResult<Vec<EncodedChild>> {
+        self.next_raw_value_at(offset)?;
+        let mut children = Vec::new();
+        self.raw_reader.step_in()?;
+        while let Some(item) = self.raw_reader.next()? {
+            if let Value(ion_type, is_null) = item {
+                children.push(EncodedChild {
+                    field_name: self.raw_reader.field_name().cloned(),
+                    offset: self.raw_reader.encoded_range().unwrap().start,
+                    ion_type,
+                    is_null,
+                });
+            }
+        }
+        self.raw_reader.step_out()?;
+        Ok(children)
+    }
+
+    fn next_raw_value_at(&mut self, offset: usize) -> IonResult<IonType> {
+        self.seek_to_offset(offset)?;
+        match self.raw_reader.next()? {
+            Some(Value(ion_type, _)) => Ok(ion_type),
+            _ => decoding_error(format!("Expected a value at offset {}.", offset)),
+        }
+    }
+}
+
+/// A Reader over input whose encoding was detected at runtime. See [Reader::from_input].
+impl<'a> Reader<Box<dyn RawReader + 'a>> {
+    /// Creates a Reader for `input`, which may contain either binary or text Ion. If the input
+    /// begins with the binary Ion 1.0 version marker (`0xE0 0x01 0x00 0xEA`), it is read as
+    /// binary Ion; otherwise, it is read as text. Only the first four bytes are inspected, and
+    /// they are not consumed, so the version marker is still reported by the raw reader.
+    ///
+    /// Returns an error only if reading those bytes from `input` fails.
+    pub fn from_input<I: io::Read + 'a>(input: I) -> IonResult<Self> {
+        ReaderBuilder::new().build_from_input(input)
+    }
+
+    /// Like [from_input](Self::from_input), but first decompresses `input` if it is compressed
+    /// with gzip or zstd, which requires the `gzip` or `zstd` feature respectively. See
+    /// [from_compressed_read](crate::data_source::from_compressed_read).
+    pub fn from_compressed_input<I: io::Read + 'a>(input: I) -> IonResult<Self> {
+        Reader::from_input(from_compressed_read(input)?)
+    }
+}
+
+/// Configures the resource limits of a [Reader] and then constructs it. Each limit guards against
+/// a different way that untrusted input could exhaust memory or the stack; by default, none of
+/// them is enforced. Input that exceeds a limit produces an [IonError::DecodingError] in the
+/// [DecodingErrorCategory::LimitExceeded](crate::result::DecodingErrorCategory::LimitExceeded)
+/// category.
+///
+/// ```
+/// use ion_rs::result::DecodingErrorCategory;
+/// use ion_rs::ReaderBuilder;
+///
+/// let mut reader = ReaderBuilder::new()
+///     .max_annotations(2)
+///     .build_from_input("a::b::1 a::b::c::2".as_bytes())?;
+/// assert!(reader.next()?.is_some());
+/// let error = reader.next().unwrap_err();
+/// assert_eq!(error.category(), Some(DecodingErrorCategory::LimitExceeded));
+/// # Ok::<(), ion_rs::result::IonError>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ReaderBuilder {
+    max_depth: Option<usize>,
+    max_value_length: Option<usize>,
+    resource_limits: ResourceLimits,
+    struct_policies: StructPolicies,
+}
+
+impl ReaderBuilder {
+    /// Creates a ReaderBuilder that does not enforce any limits.
+    pub fn new() -> Self {
+        ReaderBuilder::default()
+    }
+
+    /// Sets the maximum depth that the reader can reach by calling `step_in()`. See
+    /// [Reader::set_max_depth]. Containers that are skipped rather than stepped into do not count
+    /// toward the limit; the readers skip nested containers without recursing.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets the largest length in bytes that a binary Ion value may declare. See
+    /// [RawBinaryReaderBuilder::max_value_length].
+    ///
+    /// Text Ion does not declare the lengths of its values, so this limit is only enforced by
+    /// readers constructed with [build_binary](Self::build_binary), or with
+    /// [build_from_input](Self::build_from_input) when the input is binary. To enforce it with
+    /// [build](Self::build), configure the raw reader with a [RawBinaryReaderBuilder] instead.
+    pub fn max_value_length(mut self, max_value_length: usize) -> Self {
+        self.max_value_length = Some(max_value_length);
+        self
+    }
+
+    /// Sets the largest number of symbols, including the system symbols, that a local symbol
+    /// table may define. The limit is checked as the reader processes each symbol table, so an
+    /// oversized table is rejected before all of its symbols have been read.
+    pub fn max_symbol_table_size(mut self, max_symbol_table_size: usize) -> Self {
+        self.resource_limits.max_symbol_table_size = Some(max_symbol_table_size);
+        self
+    }
+
+    /// Sets the largest number of annotations that a user-level value may have.
+    pub fn max_annotations(mut self, max_annotations: usize) -> Self {
+        self.resource_limits.max_annotations = Some(max_annotations);
+        self
+    }
+
+    /// Sets how the reader handles repeated field names when it reads structs into
+    /// [OwnedElement]s. See [Reader::set_duplicate_field_policy].
+    pub fn duplicate_field_policy(mut self, policy: DuplicateFieldPolicy) -> Self {
+        self.struct_policies.duplicate_fields = policy;
+        self
+    }
+
+    /// Sets how the reader handles field names with unknown text when it reads structs into
+    /// [OwnedElement]s. See [Reader::set_unknown_field_name_policy].
+    pub fn unknown_field_name_policy(mut self, policy: UnknownFieldNamePolicy) -> Self {
+        self.struct_policies.unknown_field_names = policy;
+        self
+    }
+
+    /// Constructs a Reader that enforces the configured limits over `raw_reader`. The
+    /// [max_value_length](Self::max_value_length) limit cannot be applied to a raw reader that
+    /// has already been constructed, and is ignored.
+    pub fn build<C: RawReader>(self, raw_reader: C) -> Reader<C> {
+        let mut reader = Reader::new(raw_reader);
+        if let Some(max_depth) = self.max_depth {
+            reader.set_max_depth(max_depth);
+        }
+        reader.resource_limits = self.resource_limits;
+        reader.struct_policies = self.struct_policies;
+        reader
+    }
+
+    /// Constructs a Reader over binary Ion input that enforces all of the configured limits.
+    /// Input that is already in memory, like a `Vec<u8>` or `&[u8]`, is read in place; see
+    /// [ToIonDataSource].
+    pub fn build_binary<I: ToIonDataSource>(
+        self,
+        input: I,
+    ) -> Reader<RawBinaryReader<I::DataSource>> {
+        let raw_reader = self
+            .raw_binary_reader_builder()
+            .build(input.to_ion_data_source());
+        self.build(raw_reader)
+    }
+
+    /// Like [Reader::from_input], but the Reader enforces the configured limits.
+    pub fn build_from_input<'a, I: io::Read + 'a>(
+        self,
+        mut input: I,
+    ) -> IonResult<Reader<Box<dyn RawReader + 'a>>> {
+        use std::io::Read;
+        let mut prefix = Vec::with_capacity(IVM.len());
+        (&mut input)
+            .take(IVM.len() as u64)
+            .read_to_end(&mut prefix)?;
+        let is_binary = prefix == IVM;
+        let input = io::BufReader::new(io::Cursor::new(prefix).chain(input));
+        let raw_reader: Box<dyn RawReader + 'a> = if is_binary {
+            Box::new(self.raw_binary_reader_builder().build(input))
+        } else {
+            Box::new(RawTextReader::new(input))
+        };
+        Ok(self.build(raw_reader))
+    }
+
+    fn raw_binary_reader_builder(&self) -> RawBinaryReaderBuilder {
+        match self.max_value_length {
+            Some(max_value_length) => {
+                RawBinaryReaderBuilder::new().max_value_length(max_value_length)
+            }
+            None => RawBinaryReaderBuilder::new(),
+        }
+    }
+}
+
+/// Functionality that is only available if the data source we're reading from is in-memory, like
+/// a Vec<u8> or &[u8].
+impl<T: AsRef<[u8]>, A: Allocator + Clone> Reader<RawBinaryReader<io::Cursor<T>, A>> {
+    delegate! {
         to self.raw_reader {
             pub fn raw_bytes(&self) -> Option<&[u8]>;
+            pub fn encoded_bytes(&self) -> IonResult<Option<&[u8]>>;
             pub fn raw_field_id_bytes(&self) -> Option<&[u8]>;
             pub fn raw_header_bytes(&self) -> Option<&[u8]>;
+            pub fn raw_header_and_value_bytes(&self) -> Option<&[u8]>;
             pub fn raw_value_bytes(&self) -> Option<&[u8]>;
             pub fn raw_annotations_bytes(&self) -> Option<&[u8]>;
 
@@ -282,19 +1644,358 @@ impl<T: AsRef<[u8]>> Reader<RawBinaryReader<io::Cursor<T>>> {
             pub fn value_range(&self) -> Range<usize>;
         }
     }
+
+    /// Captures the reader's current position, depth and symbol table in a [Savepoint].
+    /// Calling [Savepoint::rollback] will return the reader to this state, allowing callers
+    /// to speculatively read a value and try again with a different interpretation on failure.
+    /// Dropping the Savepoint without rolling back leaves the reader where it is.
+    pub fn savepoint(&self) -> Savepoint<A> {
+        let (cursor, position) = self.raw_reader.save_state();
+        Savepoint {
+            cursor,
+            position,
+            symbol_table: self.symbol_table.clone(),
+            value_path: self.value_path.clone(),
+        }
+    }
+
+    /// Like [savepoint](Self::savepoint), but returns a [SavepointGuard] that rolls the reader
+    /// back when it is dropped unless [SavepointGuard::commit] is called first. The reader is
+    /// available through the guard, so a speculative read can return early with `?` and the
+    /// reader will be where it started.
+    pub fn savepoint_guard(&mut self) -> SavepointGuard<'_, T, A> {
+        let savepoint = self.savepoint();
+        SavepointGuard {
+            reader: self,
+            savepoint: Some(savepoint),
+        }
+    }
+}
+
+/// Lazily reads the elements of a list. See [Reader::iter_list].
+///
+/// Iteration ends after the last element or after the first Err, whichever comes first.
+pub struct ListIter<'a, C: RawReader> {
+    reader: &'a mut Reader<C>,
+    // The depth of the list's elements
+    depth: usize,
+    is_done: bool,
+}
+
+impl<'a, C: RawReader> Iterator for ListIter<'a, C> {
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        let result = match self.reader.next() {
+            Ok(Some((ion_type, _))) => self.reader.materialize(ion_type),
+            Ok(None) => {
+                self.is_done = true;
+                return None;
+            }
+            Err(error) => Err(error),
+        };
+        if result.is_err() {
+            self.is_done = true;
+        }
+        Some(result)
+    }
+}
+
+impl<'a, C: RawReader> Drop for ListIter<'a, C> {
+    fn drop(&mut self) {
+        // An Err encountered while reading a nested container can leave the reader below the
+        // list's depth, so step out as many times as it takes to return to the list's parent.
+        // Errors cannot be surfaced from `drop`; if stepping out fails, stop trying.
+        while self.reader.depth() >= self.depth {
+            if self.reader.step_out().is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// A container that a [Reader] has stepped into with [Reader::step_in_scoped]. The reader steps
+/// out of the container when this is dropped, skipping any values that have not been read; use
+/// [close](Self::close) instead to find out whether that succeeded.
+///
+/// The reader's other methods (such as [read_str](Reader::read_str) or
+/// [step_in_scoped](Reader::step_in_scoped) for a nested container) are available through
+/// `Deref`. [next_value](Self::next_value) only visits this container's values: if the caller
+/// stepped into one of them without stepping back out, `next_value` steps out first.
+pub struct ScopedContainer<'a, C: RawReader> {
+    reader: &'a mut Reader<C>,
+    container_type: IonType,
+    // The depth of the container's values
+    depth: usize,
+}
+
+impl<'a, C: RawReader> ScopedContainer<'a, C> {
+    /// Advances to the container's next value, returning its type and whether it is null, or
+    /// returns `None` after the last value. Unlike [Reader::next], which is also available through
+    /// `Deref`, this first steps out of any of the container's values that the caller stepped into.
+    pub fn next_value(&mut self) -> IonResult<Option<(IonType, bool)>> {
+        if self.reader.depth() < self.depth {
+            return illegal_operation("The reader has already stepped out of this container.");
+        }
+        while self.reader.depth() > self.depth {
+            self.reader.step_out()?;
+        }
+        self.reader.next()
+    }
+
+    /// The type of the container: a list, s-expression or struct.
+    pub fn container_type(&self) -> IonType {
+        self.container_type
+    }
+
+    /// Steps out of the container, as dropping it would, but returns any error that occurs.
+    pub fn close(mut self) -> IonResult<()> {
+        self.step_out_to_parent()
+    }
+
+    // Steps out as many times as it takes to return to the container's parent, which can be more
+    // than once if the caller stepped into a nested container and did not step back out.
+    fn step_out_to_parent(&mut self) -> IonResult<()> {
+        while self.reader.depth() >= self.depth {
+            self.reader.step_out()?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, C: RawReader> Deref for ScopedContainer<'a, C> {
+    type Target = Reader<C>;
+
+    fn deref(&self) -> &Reader<C> {
+        self.reader
+    }
+}
+
+impl<'a, C: RawReader> DerefMut for ScopedContainer<'a, C> {
+    fn deref_mut(&mut self) -> &mut Reader<C> {
+        self.reader
+    }
+}
+
+impl<'a, C: RawReader> Drop for ScopedContainer<'a, C> {
+    fn drop(&mut self) {
+        // Errors cannot be surfaced from `drop`. After `close`, there is nothing left to do.
+        let _ = self.step_out_to_parent();
+    }
+}
+
+/// An item returned by [Reader::next_system_item].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemStreamItem {
+    /// An Ion version marker with the given major and minor versions. The symbol table has been
+    /// reset to the system symbol table.
+    VersionMarker(u8, u8),
+    /// A local symbol table, which has been applied. If `is_append` is true, its symbols were
+    /// added to the end of the existing table; otherwise, the existing table was replaced by the
+    /// system symbols, followed by those of any imported shared tables and then its own symbols.
+    /// Either way, the symbols it declared have IDs starting at `new_symbols_start`.
+    SymbolTable {
+        is_append: bool,
+        new_symbols_start: usize,
+    },
+    /// A user value of the given type, and whether it is null, as returned by [Reader::next].
+    Value(IonType, bool),
+}
+
+/// An item produced by [Events], the depth-first iterator returned by [Reader::events]. Symbols
+/// are resolved as in [Reader::read_element].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReaderEvent {
+    /// The reader stepped into a list, s-expression or struct. Events for each of its children
+    /// follow, and then a matching [ReaderEvent::EndContainer].
+    StartContainer {
+        ion_type: IonType,
+        field_name: Option<OwnedSymbolToken>,
+        annotations: Vec<OwnedSymbolToken>,
+    },
+    /// The reader stepped out of a container of the given type after visiting all of its children.
+    EndContainer(IonType),
+    /// A scalar value. Null containers (e.g. `null.list`) have no children to visit, so they are
+    /// also reported as scalars.
+    Scalar {
+        field_name: Option<OwnedSymbolToken>,
+        value: OwnedElement,
+    },
+}
+
+/// A depth-first iterator over the values in a [Reader]. See [Reader::events].
+pub struct Events<'a, C: RawReader> {
+    reader: &'a mut Reader<C>,
+    // The depth at which the iterator was created
+    depth: usize,
+    // The types of the containers the iterator has stepped into, innermost last
+    containers: Vec<IonType>,
+    is_done: bool,
+}
+
+impl<'a, C: RawReader> Events<'a, C> {
+    fn next_event(&mut self) -> IonResult<Option<ReaderEvent>> {
+        let (ion_type, is_null) = match self.reader.next()? {
+            Some(item) => item,
+            None => {
+                return match self.containers.pop() {
+                    Some(ion_type) => {
+                        self.reader.step_out()?;
+                        Ok(Some(ReaderEvent::EndContainer(ion_type)))
+                    }
+                    None => Ok(None),
+                }
+            }
+        };
+        let field_name = self
+            .reader
+            .raw_reader
+            .field_name()
+            .map(|raw_token| self.reader.resolve_token(raw_token));
+        if !ion_type.is_container() || is_null {
+            let value = self.reader.materialize(ion_type)?;
+            return Ok(Some(ReaderEvent::Scalar { field_name, value }));
+        }
+        let annotations = self
+            .reader
+            .raw_reader
+            .annotations()
+            .iter()
+            .map(|raw_token| self.reader.resolve_token(raw_token))
+            .collect();
+        self.reader.step_in()?;
+        self.containers.push(ion_type);
+        Ok(Some(ReaderEvent::StartContainer {
+            ion_type,
+            field_name,
+            annotations,
+        }))
+    }
+}
+
+impl<'a, C: RawReader> Iterator for Events<'a, C> {
+    type Item = IonResult<ReaderEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_done {
+            return None;
+        }
+        match self.next_event() {
+            Ok(Some(event)) => Some(Ok(event)),
+            Ok(None) => {
+                self.is_done = true;
+                None
+            }
+            Err(error) => {
+                self.is_done = true;
+                Some(Err(error))
+            }
+        }
+    }
+}
+
+impl<'a, C: RawReader> Drop for Events<'a, C> {
+    fn drop(&mut self) {
+        // As with ListIter, errors cannot be surfaced from `drop`; if stepping out fails, stop
+        // trying.
+        while self.reader.depth() > self.depth {
+            if self.reader.step_out().is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// A snapshot of an in-memory binary [Reader]'s state. See [Reader::savepoint].
+pub struct Savepoint<A: Allocator + Clone = Global> {
+    cursor: CursorState<A>,
+    position: u64,
+    symbol_table: SymbolTable,
+    value_path: ValuePath,
+}
+
+impl<A: Allocator + Clone> Savepoint<A> {
+    /// Restores `reader` to the state it was in when this Savepoint was created.
+    ///
+    /// The Savepoint should only be used with the Reader that created it; restoring it on a
+    /// Reader over different data will leave that Reader in an inconsistent state.
+    pub fn rollback<T: AsRef<[u8]>>(self, reader: &mut Reader<RawBinaryReader<io::Cursor<T>, A>>) {
+        reader.raw_reader.restore_state(self.cursor, self.position);
+        reader.symbol_table = self.symbol_table;
+        reader.value_path = self.value_path;
+    }
+}
+
+/// Rolls an in-memory binary [Reader] back to a [Savepoint] when dropped, unless the changes
+/// were committed. See [Reader::savepoint_guard].
+///
+/// The reader's methods are available through `Deref`.
+pub struct SavepointGuard<'a, T: AsRef<[u8]>, A: Allocator + Clone = Global> {
+    reader: &'a mut Reader<RawBinaryReader<io::Cursor<T>, A>>,
+    // None once the guard has been committed
+    savepoint: Option<Savepoint<A>>,
+}
+
+impl<'a, T: AsRef<[u8]>, A: Allocator + Clone> SavepointGuard<'a, T, A> {
+    /// Keeps the reader where it is; dropping the guard will no longer roll it back.
+    pub fn commit(mut self) {
+        self.savepoint = None;
+    }
+
+    /// Rolls the reader back to the savepoint now, as dropping the guard would.
+    pub fn rollback(self) {
+        drop(self)
+    }
+}
+
+impl<'a, T: AsRef<[u8]>, A: Allocator + Clone> Deref for SavepointGuard<'a, T, A> {
+    type Target = Reader<RawBinaryReader<io::Cursor<T>, A>>;
+
+    fn deref(&self) -> &Self::Target {
+        self.reader
+    }
+}
+
+impl<'a, T: AsRef<[u8]>, A: Allocator + Clone> DerefMut for SavepointGuard<'a, T, A> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.reader
+    }
+}
+
+impl<'a, T: AsRef<[u8]>, A: Allocator + Clone> Drop for SavepointGuard<'a, T, A> {
+    fn drop(&mut self) {
+        if let Some(savepoint) = self.savepoint.take() {
+            savepoint.rollback(self.reader);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::io;
+    use std::ops::Range;
 
     use crate::binary::constants::v1_0::IVM;
     use crate::binary::raw_binary_reader::RawBinaryReader;
+    use crate::binary::writer::BinaryWriter;
+    use crate::catalog::{MapCatalog, SharedSymbolTable};
     use crate::raw_reader::{RawReader, StreamItem::*};
-    use crate::result::IonResult;
+    use crate::raw_symbol_token::RawSymbolToken;
+    use crate::result::{illegal_operation, DecodingErrorCategory, IonError, IonResult};
     use crate::system_event_handler::SystemEventHandler;
+    use crate::text::raw_text_reader::RawTextReader;
     use crate::types::IonType;
-    use crate::{Reader, SymbolTable};
+    use crate::value::owned::OwnedElement;
+    use crate::value::{Element, IntAccess, Sequence, Struct, SymbolToken};
+    use crate::{
+        DuplicateFieldPolicy, Reader, ReaderBuilder, ReaderEvent, Symbol, SymbolTable,
+        SystemStreamItem, UnknownFieldNamePolicy,
+    };
+    use rstest::*;
+    use std::collections::BTreeSet;
 
     type TestDataSource = io::Cursor<Vec<u8>>;
 
@@ -328,7 +2029,7 @@ mod tests {
     const EXAMPLE_STREAM: &[u8] = &[
         // $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["foo", "bar", "baz"]}
         0xEE, // Var len annotations
-        0x94, // Annotations + Value length: 20 bytes
+        0x95, // Annotations + Value length: 21 bytes
         0x81, // Annotations length: 1
         0x83, // Annotation 3 ('$ion_symbol_table')
         0xDE, // Var len struct
@@ -351,6 +2052,10 @@ mod tests {
         0x21, 0x03, // Integer 3
     ];
 
+    // The length of the local symbol table at the beginning of EXAMPLE_STREAM: its two-byte
+    // header followed by 21 bytes of annotations and value.
+    const EXAMPLE_SYMBOL_TABLE_LENGTH: usize = 23;
+
     struct Handler;
     impl SystemEventHandler for Handler {
         fn on_symbol_table_append<'a>(
@@ -386,4 +2091,1021 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_max_depth_for_container_type() -> IonResult<()> {
+        let ion_data = "{a: {b: {c: {d: [[[1]]]}}}}";
+        let mut reader = Reader::new(RawTextReader::new(ion_data));
+        reader.set_max_depth(3);
+        reader.set_max_depth_for(IonType::Struct, 10)?;
+        reader.set_max_depth_for(IonType::List, 6)?;
+        assert!(reader.set_max_depth_for(IonType::Integer, 1).is_err());
+
+        // The struct-specific limit allows stepping beyond the global maximum depth.
+        for _ in 0..4 {
+            assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+            reader.step_in()?;
+        }
+        assert_eq!(reader.depth(), 4);
+        // The list limit allows two more levels of nesting...
+        for _ in 0..2 {
+            assert_eq!(reader.next()?, Some((IonType::List, false)));
+            reader.step_in()?;
+        }
+        assert_eq!(reader.depth(), 6);
+        // ...but not a third.
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        assert!(matches!(
+            reader.step_in(),
+            Err(IonError::DecodingError { .. })
+        ));
+        assert_eq!(reader.depth(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_collect_annotations() -> IonResult<()> {
+        let ion_data = r#"
+            foo::1
+            bar::baz::[quux::2, 3, foo::(corge::4)]
+            {a: grault::{b: garply::null.int}, c: 'hello world'::"x"}
+            5
+        "#;
+        let mut reader = Reader::new(RawTextReader::new(ion_data));
+        let annotations = reader.collect_annotations()?;
+        let expected: BTreeSet<String> = [
+            "bar",
+            "baz",
+            "corge",
+            "foo",
+            "garply",
+            "grault",
+            "hello world",
+            "quux",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+        assert_eq!(annotations, expected);
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_text_is_resolved() -> IonResult<()> {
+        let text = r#"$ion_symbol_table::{symbols: ["foo"]} $10::bar::$99::5"#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert!(reader.has_annotation("foo"));
+        assert!(reader.has_annotation("bar"));
+        assert!(!reader.has_annotation("baz"));
+        let annotations: Vec<&str> = reader.annotations_iter().collect();
+        assert_eq!(annotations, vec!["foo", "bar"]);
+        assert!(reader.symbol_matches(&RawSymbolToken::SymbolId(10), "foo"));
+        assert!(reader.symbol_matches(&RawSymbolToken::Text("foo".to_string()), "foo"));
+        assert!(!reader.symbol_matches(&RawSymbolToken::SymbolId(99), "foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_savepoint_rollback() -> IonResult<()> {
+        let mut reader = ion_reader_for(EXAMPLE_STREAM);
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        let savepoint = reader.savepoint();
+
+        // Speculatively try to read the first field as a string. It's an integer, so there is
+        // no string to read.
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_string()?, None);
+
+        // Roll back to the struct and read it again, this time successfully.
+        savepoint.rollback(&mut reader);
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(reader.ion_type(), Some(IonType::Struct));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("foo"));
+        assert_eq!(reader.read_i64()?, Some(1));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("bar"));
+        assert_eq!(reader.read_i64()?, Some(2));
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::invalid_utf8(&[0x21, 0x01, 0x82, 0xFF, 0xFE, 0x21, 0x02], "1 2", 6..9)]
+    #[case::reserved_type_code(&[0x21, 0x01, 0xF0, 0x21, 0x02], "1 2", 6..7)]
+    #[case::corrupt_child(&[0x21, 0x01, 0xB3, 0x21, 0x01, 0x12, 0x21, 0x02], "1 2", 6..10)]
+    #[case::garbage_before_ivm(
+        &[0x21, 0x01, 0xF0, 0xF0, 0xE0, 0x01, 0x00, 0xEA, 0x21, 0x02],
+        "1 2",
+        6..8
+    )]
+    #[case::truncated(&[0x21, 0x01, 0x8A, 0x61], "1", 6..8)]
+    fn test_recover_from_corrupt_value(
+        #[case] data: &[u8],
+        #[case] expected_values: &str,
+        #[case] expected_skipped: Range<usize>,
+    ) -> IonResult<()> {
+        let mut reader = ion_reader_for(data);
+        let mut values = vec![];
+        let mut skipped = vec![];
+        loop {
+            let result = match reader.next() {
+                Ok(Some(_)) => reader.read_element(),
+                Ok(None) => break,
+                Err(error) => Err(error),
+            };
+            match result {
+                Ok(element) => values.extend(element),
+                Err(_) => skipped.push(reader.recover()?),
+            }
+        }
+        assert_eq!(values, OwnedElement::read_all(expected_values.as_bytes())?);
+        assert_eq!(skipped, vec![expected_skipped]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_to_offset_keeps_symbol_table() -> IonResult<()> {
+        let mut reader = ion_reader_for(EXAMPLE_STREAM);
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        let range = reader.encoded_range().unwrap();
+        // The struct follows the IVM and the local symbol table, and ends the stream.
+        let start = IVM.len() + EXAMPLE_SYMBOL_TABLE_LENGTH;
+        assert_eq!(range, start..IVM.len() + EXAMPLE_STREAM.len());
+        assert_eq!(reader.position().unwrap().offset(), range.start);
+        assert_eq!(reader.next()?, None);
+
+        reader.seek_to_offset(range.start)?;
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_input_detects_binary() -> IonResult<()> {
+        let mut data = IVM.to_vec();
+        data.extend_from_slice(EXAMPLE_STREAM);
+        let mut reader = Reader::from_input(data.as_slice())?;
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_input_detects_text() -> IonResult<()> {
+        let mut reader = Reader::from_input("{foo: 1} 2".as_bytes())?;
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.field_name(), Some("foo"));
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(2));
+        assert_eq!(reader.next()?, None);
+
+        // Input shorter than a version marker is read as text too.
+        let mut reader = Reader::from_input("7".as_bytes())?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(7));
+        let mut reader = Reader::from_input(io::empty())?;
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ion_eq_streams() -> IonResult<()> {
+        let text_reader = |text: &'static str| Reader::new(RawTextReader::new(text));
+        // The binary stream's only user value is {foo: 1, bar: 2, baz: 3}
+        let mut data = IVM.to_vec();
+        data.extend_from_slice(EXAMPLE_STREAM);
+        let binary_reader = || Reader::new(RawBinaryReader::new(io::Cursor::new(data.clone())));
+        assert!(binary_reader().ion_eq(&mut text_reader("{baz: 3, foo: 1, bar: 2}"))?);
+        assert!(!binary_reader().ion_eq(&mut text_reader("{foo: 1, bar: 2, baz: 3} 4"))?);
+        assert!(!binary_reader().ion_eq(&mut text_reader("{foo: 1, bar: 2, baz: 3.0}"))?);
+        assert!(!binary_reader().ion_eq(&mut text_reader(""))?);
+
+        assert!(text_reader("a::nan 2001T").ion_eq(&mut text_reader("a::nan 2001T"))?);
+        assert!(!text_reader("nan").ion_eq(&mut text_reader("a::nan"))?);
+        assert!(!text_reader("2001T").ion_eq(&mut text_reader("2001-01T"))?);
+        assert!(text_reader("").ion_eq(&mut text_reader("$ion_1_0"))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_savepoint_guard() -> IonResult<()> {
+        let mut reader = ion_reader_for(EXAMPLE_STREAM);
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+
+        // A guard that is dropped without being committed rolls the reader back.
+        {
+            let mut guard = reader.savepoint_guard();
+            guard.step_in()?;
+            assert_eq!(guard.next()?, Some((IonType::Integer, false)));
+            assert_eq!(guard.depth(), 1);
+        }
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(reader.ion_type(), Some(IonType::Struct));
+
+        // A committed guard leaves the reader where it is.
+        let mut guard = reader.savepoint_guard();
+        guard.step_in()?;
+        assert_eq!(guard.next()?, Some((IonType::Integer, false)));
+        guard.commit();
+        assert_eq!(reader.depth(), 1);
+        assert_eq!(reader.field_name(), Some("foo"));
+
+        reader.savepoint_guard().rollback();
+        assert_eq!(reader.field_name(), Some("foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_savepoint_rollback_restores_symbol_table() -> IonResult<()> {
+        let mut reader = ion_reader_for(EXAMPLE_STREAM);
+        let system_symbol_count = reader.symbol_table().len();
+        let savepoint = reader.savepoint();
+
+        // Reading the first user value processes the local symbol table that precedes it.
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        assert_eq!(reader.symbol_table().len(), system_symbol_count + 3);
+
+        savepoint.rollback(&mut reader);
+        assert_eq!(reader.symbol_table().len(), system_symbol_count);
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        assert_eq!(reader.symbol_table().len(), system_symbol_count + 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reset_clears_symbol_table() -> IonResult<()> {
+        let mut reader = ion_reader_for(EXAMPLE_STREAM);
+        let system_symbol_count = reader.symbol_table().len();
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        assert_eq!(reader.symbol_table().len(), system_symbol_count + 3);
+
+        // {name: 1}
+        reader.reset(data_source_for(&[0xD3, 0x84, 0x21, 0x01]));
+        assert_eq!(reader.symbol_table().len(), system_symbol_count);
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        reader.step_in()?;
+        assert_eq!(Some((IonType::Integer, false)), reader.next()?);
+        assert_eq!(reader.field_name(), Some("name"));
+        assert_eq!(reader.read_i64()?, Some(1));
+        reader.step_out()?;
+        assert_eq!(None, reader.next()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_symbols_as_symbol_values() -> IonResult<()> {
+        // The local symbol table from EXAMPLE_STREAM, followed by `bar::$99::{$11: $99}`
+        let mut data = EXAMPLE_STREAM[..EXAMPLE_SYMBOL_TABLE_LENGTH].to_vec();
+        data.extend_from_slice(&[0xE7, 0x82, 0x8B, 0xE3, 0xD3, 0x8B, 0x71, 0x63]);
+        let mut reader = ion_reader_for(&data);
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        let annotations: Vec<Symbol> = reader.annotation_symbols().collect();
+        assert_eq!(
+            annotations,
+            vec![Symbol::from("bar"), Symbol::unknown_text()]
+        );
+        assert_eq!(annotations[0].local_sid(), Some(11));
+        assert_eq!(annotations[1].local_sid(), Some(99));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let field_name = reader.field_name_symbol().unwrap();
+        assert_eq!(field_name.text(), Some("bar"));
+        assert_eq!(field_name.local_sid(), Some(11));
+        let value = reader.read_symbol_value()?.unwrap();
+        assert!(value.is_unknown_text());
+        assert_eq!(value.to_string(), "$99");
+        reader.step_out()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_symbol_resolves_symbol_ids() -> IonResult<()> {
+        // The local symbol table from EXAMPLE_STREAM, followed by the symbols $11 and $99
+        let mut data = EXAMPLE_STREAM[..EXAMPLE_SYMBOL_TABLE_LENGTH].to_vec();
+        data.extend_from_slice(&[0x71, 0x0B, 0x71, 0x63]);
+        let mut reader = ion_reader_for(&data);
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let symbol = reader.read_symbol()?.unwrap();
+        assert_eq!(symbol.text(), Some("bar"));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        let symbol = reader.read_symbol()?.unwrap();
+        assert_eq!(symbol.text(), None);
+        assert_eq!(symbol.local_sid(), Some(99));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_symbol_text_into_reuses_buffer() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{symbols: ["a_longer_symbol"]}
+            $10 short "string" $99
+        "#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        let mut buffer = String::with_capacity(32);
+        let capacity = buffer.capacity();
+        reader.next()?;
+        assert_eq!(reader.read_symbol_text_into(&mut buffer)?, Some(()));
+        assert_eq!(buffer, "a_longer_symbol");
+        reader.next()?;
+        assert_eq!(reader.read_symbol_text_into(&mut buffer)?, Some(()));
+        assert_eq!(buffer, "short");
+        assert_eq!(buffer.capacity(), capacity);
+        // Other types leave the buffer untouched
+        reader.next()?;
+        assert_eq!(reader.read_symbol_text_into(&mut buffer)?, None);
+        assert_eq!(buffer, "short");
+        // $99 is not defined
+        reader.next()?;
+        assert!(matches!(
+            reader.read_symbol_text_into(&mut buffer),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_symbol_table_appends() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{symbols: ["foo"], open_content: true}
+            $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["bar"]}
+            $10 $11
+            $ion_symbol_table::{imports: not_a_table, symbols: ["baz"]}
+            $10
+        "#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.symbol_table().max_id(), 10);
+
+        // The appended symbol is added after the existing ones
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?.unwrap().text(), Some("foo"));
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?.unwrap().text(), Some("bar"));
+        assert_eq!(reader.symbol_table().max_id(), 11);
+
+        // Importing anything other than $ion_symbol_table replaces the current table
+        assert_eq!(reader.next()?, Some((IonType::Symbol, false)));
+        assert_eq!(reader.read_symbol()?.unwrap().text(), Some("baz"));
+        assert_eq!(reader.symbol_table().max_id(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_symbol_table_imports() -> IonResult<()> {
+        let mut catalog = MapCatalog::new();
+        let symbols = vec![
+            "hello".to_string(),
+            "goodbye".to_string(),
+            "unused".to_string(),
+        ];
+        catalog.insert_table(SharedSymbolTable::new("greetings", 2, symbols));
+        let text = r#"
+            $ion_symbol_table::{
+                imports: [{name: "greetings", version: 2, max_id: 2}, {name: "$ion"}],
+                symbols: ["name"],
+            }
+            $10 $11 $12 $13
+        "#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        reader.set_catalog(catalog);
+        let mut texts = vec![];
+        while let Some((IonType::Symbol, false)) = reader.next()? {
+            texts.push(reader.read_symbol()?.unwrap().text().map(|t| t.to_string()));
+        }
+        let expected = vec![Some("hello"), Some("goodbye"), Some("name"), None];
+        let expected: Vec<_> = expected.iter().map(|t| t.map(|t| t.to_string())).collect();
+        assert_eq!(expected, texts);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_symbol_table_missing_from_catalog() {
+        let text = r#"$ion_symbol_table::{imports: [{name: "greetings", max_id: 2}]} $10"#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        assert!(matches!(reader.next(), Err(IonError::DecodingError { .. })));
+    }
+
+    #[test]
+    fn test_read_element_resolves_symbols() -> IonResult<()> {
+        let mut reader = ion_reader_for(EXAMPLE_STREAM);
+        assert_eq!(reader.read_element()?, None);
+        assert_eq!(Some((IonType::Struct, false)), reader.next()?);
+        let element = reader.read_element()?.unwrap();
+        let fields = element.as_struct().unwrap();
+        assert_eq!(fields.get("foo").and_then(|e| e.as_i64()), Some(1));
+        assert_eq!(fields.get("bar").and_then(|e| e.as_i64()), Some(2));
+        assert_eq!(fields.get("baz").and_then(|e| e.as_i64()), Some(3));
+        // The reader is positioned after the struct
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(None, reader.next()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_value_sends_elements_to_other_threads() -> IonResult<()> {
+        let text = r#"{id: 1, tags: [a, b]} {id: 2, tags: [c]} {id: 3, tags: []}"#;
+        let (sender, receiver) = std::sync::mpsc::channel::<OwnedElement>();
+        let worker = std::thread::spawn(move || {
+            receiver
+                .iter()
+                .map(|element| {
+                    let fields = element.as_struct().unwrap();
+                    let tags = fields.get("tags").and_then(|tags| tags.as_sequence());
+                    tags.map(|tags| tags.len()).unwrap_or(0)
+                })
+                .sum::<usize>()
+        });
+        let mut reader = Reader::new(RawTextReader::new(text));
+        while reader.next()?.is_some() {
+            sender.send(reader.read_value()?.unwrap()).unwrap();
+        }
+        drop(sender);
+        assert_eq!(3, worker.join().unwrap());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::keep_all(DuplicateFieldPolicy::KeepAll, "{a: 1, b: 2, a: 3, a: 4}")]
+    #[case::keep_first(DuplicateFieldPolicy::KeepFirst, "{a: 1, b: 2}")]
+    #[case::keep_last(DuplicateFieldPolicy::KeepLast, "{a: 4, b: 2}")]
+    fn test_duplicate_field_policy(
+        #[case] policy: DuplicateFieldPolicy,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let mut reader = ReaderBuilder::new()
+            .duplicate_field_policy(policy)
+            .build(RawTextReader::new("{a: 1, b: 2, a: 3, a: 4}"));
+        reader.next()?;
+        let element = reader.read_element()?.unwrap();
+        assert_eq!(OwnedElement::read_all(expected.as_bytes())?[0], element);
+        let field_names: Vec<_> = element
+            .as_struct()
+            .unwrap()
+            .iter_in_order()
+            .map(|(name, _)| name.text().unwrap().to_string())
+            .collect();
+        assert_eq!(field_names[..2], ["a", "b"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_field_policy_error() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("{a: 1, b: {c: 2, c: 3}}"));
+        reader.set_duplicate_field_policy(DuplicateFieldPolicy::Error);
+        reader.next()?;
+        assert!(matches!(
+            reader.read_element(),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::symbol_id(UnknownFieldNamePolicy::SymbolId, None)]
+    #[case::placeholder(UnknownFieldNamePolicy::Placeholder, Some("$99"))]
+    fn test_unknown_field_name_policy(
+        #[case] policy: UnknownFieldNamePolicy,
+        #[case] expected_text: Option<&str>,
+    ) -> IonResult<()> {
+        let mut reader = ReaderBuilder::new()
+            .unknown_field_name_policy(policy)
+            .build(RawTextReader::new("{$99: 1}"));
+        reader.next()?;
+        let element = reader.read_element()?.unwrap();
+        let (name, value) = element.as_struct().unwrap().iter().next().unwrap();
+        assert_eq!(expected_text, name.text());
+        assert_eq!(Some(1), value.as_i64());
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_field_name_policy_error() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("{foo: 1, $99: 2}"));
+        reader.set_unknown_field_name_policy(UnknownFieldNamePolicy::Error);
+        reader.next()?;
+        assert!(matches!(
+            reader.read_element(),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_matching() -> IonResult<()> {
+        let text = r#"
+            1 "two" {id: 1, kind: a} foo::[{kind: b}] {id: 2, kind: b} {id: 3, kind: b} null.struct
+        "#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        let is_kind_b = |element: &OwnedElement| {
+            element
+                .as_struct()
+                .and_then(|s| s.get("kind"))
+                .and_then(|kind| kind.as_sym())
+                .and_then(|kind| kind.text())
+                == Some("b")
+        };
+
+        let element = reader.next_matching(is_kind_b)?.unwrap();
+        let id = element.as_struct().unwrap().get("id").unwrap();
+        assert_eq!(id.as_i64(), Some(2));
+
+        let element = reader.next_matching(is_kind_b)?.unwrap();
+        let id = element.as_struct().unwrap().get("id").unwrap();
+        assert_eq!(id.as_i64(), Some(3));
+
+        assert_eq!(reader.next_matching(is_kind_b)?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_element_into() -> IonResult<()> {
+        let text = r#"
+            "hello" "a longer string" foo::[1, "two", [3]] bar::[4] (5 6) {a: 7} null.list 8
+        "#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        let mut element = OwnedElement::null(IonType::Null);
+        let mut elements_read = Vec::new();
+        while reader.read_element_into(&mut element)? {
+            elements_read.push(element.clone());
+        }
+        assert!(!reader.read_element_into(&mut element)?);
+
+        // Reusing the element must produce the same results as reading each value afresh
+        let mut reader = Reader::new(RawTextReader::new(text));
+        let mut expected = Vec::new();
+        while reader.next()?.is_some() {
+            expected.push(reader.read_element()?.unwrap());
+        }
+        assert_eq!(elements_read, expected);
+        assert_eq!(elements_read.len(), 8);
+
+        // The shorter list replaced both the children and the annotations of the longer one
+        let list = &elements_read[3];
+        assert_eq!(list.annotations().next().unwrap().text(), Some("bar"));
+        assert_eq!(list.annotations().count(), 1);
+        assert_eq!(list.as_sequence().unwrap().iter().count(), 1);
+        assert_eq!(elements_read[6].ion_type(), IonType::List);
+        assert!(elements_read[6].is_null());
+        Ok(())
+    }
+
+    #[test]
+    fn test_decoding_errors_report_value_path() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new(r#"1 {foo: [1, 2, "abc"#));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        let error = reader.next().unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::Incomplete));
+        assert_eq!(error.path(), Some("$1.foo[2]"));
+
+        // 1 {name: <a string that is not valid UTF-8>}
+        let mut reader = ion_reader_for(&[0x21, 0x01, 0xD3, 0x84, 0x81, 0xFF]);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::String, false)));
+        let error = reader.read_string().unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::InvalidUtf8));
+        assert_eq!(error.ion_type(), Some(IonType::String));
+        assert_eq!(error.path(), Some("$1.name"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reader_builder_limits() -> IonResult<()> {
+        let builder = ReaderBuilder::new()
+            .max_depth(1)
+            .max_symbol_table_size(12)
+            .max_annotations(1);
+
+        let mut reader = builder.clone().build(RawTextReader::new("[[1]]"));
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        reader.step_in()?;
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        let error = reader.step_in().unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::LimitExceeded));
+
+        let mut reader = builder.clone().build(RawTextReader::new("a::1 a::b::2"));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        let error = reader.next().unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::LimitExceeded));
+
+        // The system symbol table already contains 10 symbols, leaving room for two more.
+        let mut reader = builder.build(RawTextReader::new(
+            r#"
+                $ion_symbol_table::{symbols: ["a", "b"]}
+                1
+                $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+                2
+            "#,
+        ));
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.symbol_table().len(), 12);
+        let error = reader.next().unwrap_err();
+        assert_eq!(error.category(), Some(DecodingErrorCategory::LimitExceeded));
+        Ok(())
+    }
+
+    #[test]
+    fn test_reader_builder_reads_in_memory_input_in_place() -> IonResult<()> {
+        // An IVM followed by the int 5
+        let data: Vec<u8> = vec![0xE0, 0x01, 0x00, 0xEA, 0x21, 0x05];
+        let mut reader = ReaderBuilder::new().build_binary(data);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.raw_bytes(), Some(&[0x21, 0x05][..]));
+        assert_eq!(reader.read_i64()?, Some(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_in_expecting() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("{a: 1} [[2]]"));
+        reader.set_max_depth(1);
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        assert_eq!(
+            reader.step_in_expecting(IonType::List),
+            Err(IonError::UnexpectedType {
+                expected: IonType::List,
+                found: IonType::Struct
+            })
+        );
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        reader.step_in_expecting(IonType::List)?;
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        // The depth limit still applies
+        assert!(matches!(
+            reader.step_in_expecting(IonType::List),
+            Err(IonError::DecodingError { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_field() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new(
+            "{id: 1, name: \"x\", nested: {id: 0}, id: two} 3",
+        ));
+        assert!(reader.find_field("id").is_err());
+        assert_eq!(reader.next()?, Some((IonType::Struct, false)));
+        reader.step_in()?;
+        assert_eq!(reader.find_field("id")?, Some(IonType::Integer));
+        assert_eq!(reader.read_i64()?, Some(1));
+        // The nested struct's `id` field is not visited
+        assert_eq!(reader.find_field("id")?, Some(IonType::Symbol));
+        assert_eq!(reader.depth(), 1);
+        assert_eq!(reader.find_field("id")?, None);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(3));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::text(false)]
+    #[case::binary(true)]
+    fn test_read_struct_fields(#[case] binary: bool) -> IonResult<()> {
+        let text = "{id: 1, body: [1, {status: nested}], status: ok, time: 2021T, id: 2} 3";
+        let mut data = text.as_bytes().to_vec();
+        if binary {
+            data = Vec::new();
+            let mut writer = BinaryWriter::new(&mut data);
+            for element in OwnedElement::read_all(text.as_bytes())? {
+                writer.write_element(&element)?;
+            }
+            writer.flush()?;
+            drop(writer);
+        }
+        let mut reader = Reader::from_input(data.as_slice())?;
+        assert!(reader.read_struct_fields(&["id"], |_, _| Ok(())).is_err());
+        reader.next()?;
+        reader.step_in()?;
+        let mut fields = Vec::new();
+        reader.read_struct_fields(&["status", "id", "missing"], |index, reader| {
+            fields.push((index, reader.read_element()?.unwrap()));
+            Ok(())
+        })?;
+        // The nested struct's `status` field is not visited
+        let expected = OwnedElement::read_all(&b"1 ok 2"[..])?;
+        assert_eq!(
+            fields,
+            vec![
+                (1, expected[0].clone()),
+                (0, expected[1].clone()),
+                (1, expected[2].clone())
+            ]
+        );
+        assert_eq!(reader.depth(), 1);
+        reader.step_out()?;
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_struct_fields_callback_must_not_change_depth() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("{a: [1], b: 2}"));
+        reader.next()?;
+        reader.step_in()?;
+        let result = reader.read_struct_fields(&["a"], |_, reader| reader.step_in());
+        assert!(matches!(result, Err(IonError::IllegalOperation { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_list() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("[1, foo::two, [3], {four: 4}] 5"));
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        let elements = reader
+            .iter_list()?
+            .collect::<IonResult<Vec<OwnedElement>>>()?;
+        assert_eq!(elements.len(), 4);
+        assert_eq!(elements[0].as_i64(), Some(1));
+        assert_eq!(
+            elements[1].annotations().next().unwrap().text(),
+            Some("foo")
+        );
+        assert_eq!(elements[1].as_sym().unwrap().text(), Some("two"));
+        assert_eq!(elements[2].as_sequence().unwrap().iter().count(), 1);
+        let four = elements[3].as_struct().unwrap().get("four").unwrap();
+        assert_eq!(four.as_i64(), Some(4));
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_list_dropped_early() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("[1, 2, 3, 4] [5] 6"));
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        for element in reader.iter_list()? {
+            if element?.as_i64() == Some(2) {
+                break;
+            }
+        }
+        // Dropping the iterator stepped out of the first list
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(reader.next()?, Some((IonType::List, false)));
+        let mut iter = reader.iter_list()?;
+        assert_eq!(iter.next().unwrap()?.as_i64(), Some(5));
+        assert!(iter.next().is_none());
+        drop(iter);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(6));
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_list_requires_list() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("(1 2) null.list"));
+        assert_eq!(reader.next()?, Some((IonType::SExpression, false)));
+        assert!(reader.iter_list().is_err());
+        assert_eq!(reader.next()?, Some((IonType::List, true)));
+        assert!(reader.iter_list().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_in_scoped() -> IonResult<()> {
+        // Sums the integers in a struct's `values` field, returning early on any other type
+        fn sum_values<C: RawReader>(reader: &mut Reader<C>) -> IonResult<i64> {
+            let mut fields = reader.step_in_scoped()?;
+            assert_eq!(fields.container_type(), IonType::Struct);
+            let mut sum = 0;
+            while fields.next_value()?.is_some() {
+                if fields.field_name() != Some("values") {
+                    continue;
+                }
+                let mut values = fields.step_in_scoped()?;
+                while let Some((ion_type, _)) = values.next_value()? {
+                    if ion_type != IonType::Integer {
+                        return illegal_operation("not an integer");
+                    }
+                    sum += values.read_i64()?.unwrap();
+                }
+            }
+            Ok(sum)
+        }
+
+        let mut reader = Reader::new(RawTextReader::new(
+            "{a: 1, values: [2, 3]} {values: [4, five, 6]} {values: (7)} 8",
+        ));
+        reader.next()?;
+        assert_eq!(sum_values(&mut reader)?, 5);
+        assert_eq!(reader.depth(), 0);
+        reader.next()?;
+        // The error was returned from two levels down, and both containers were stepped out of
+        assert!(sum_values(&mut reader).is_err());
+        assert_eq!(reader.depth(), 0);
+        reader.next()?;
+        assert_eq!(sum_values(&mut reader)?, 7);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(8));
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_in_scoped_recovers_from_unmatched_step_in() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("[[1, [2]], 3] 4"));
+        reader.next()?;
+        let mut list = reader.step_in_scoped()?;
+        assert_eq!(list.next_value()?, Some((IonType::List, false)));
+        // Step into the nested lists with the underlying reader and don't step back out
+        let nested: &mut Reader<_> = &mut list;
+        nested.step_in()?;
+        nested.next()?;
+        nested.next()?;
+        nested.step_in()?;
+        assert_eq!(list.depth(), 3);
+        // `next_value` returns to the list's depth before advancing
+        assert_eq!(list.next_value()?, Some((IonType::Integer, false)));
+        assert_eq!(list.read_i64()?, Some(3));
+        assert!(list.step_in_scoped().is_err());
+        assert_eq!(list.next_value()?, None);
+        list.close()?;
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_in_scoped_requires_container() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("1 null.list"));
+        assert!(reader.step_in_scoped().is_err());
+        reader.next()?;
+        assert!(reader.step_in_scoped().is_err());
+        reader.next()?;
+        assert!(reader.step_in_scoped().is_err());
+        assert_eq!(reader.depth(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_system_item_binary() -> IonResult<()> {
+        let mut reader = Reader::new(RawBinaryReader::new(data_source_for(EXAMPLE_STREAM)));
+        assert_eq!(
+            reader.next_system_item()?,
+            Some(SystemStreamItem::VersionMarker(1, 0))
+        );
+        assert_eq!(
+            reader.next_system_item()?,
+            Some(SystemStreamItem::SymbolTable {
+                is_append: true,
+                new_symbols_start: 10
+            })
+        );
+        assert_eq!(
+            reader.next_system_item()?,
+            Some(SystemStreamItem::Value(IonType::Struct, false))
+        );
+        let expected = OwnedElement::read_all(&b"{foo: 1, bar: 2, baz: 3}"[..])?;
+        assert_eq!(reader.materialize(IonType::Struct)?, expected[0]);
+        assert_eq!(reader.next_system_item()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_system_item_text() -> IonResult<()> {
+        let text = r#"
+            $ion_symbol_table::{symbols: ["a", "b"]}
+            $10
+            $ion_symbol_table::{imports: $ion_symbol_table, symbols: ["c"]}
+            [$11, $12]
+            $ion_1_0
+            foo
+        "#;
+        let mut reader = Reader::new(RawTextReader::new(text));
+        let mut items = vec![];
+        while let Some(item) = reader.next_system_item()? {
+            if let SystemStreamItem::Value(ion_type, false) = item {
+                items.push((item, Some(reader.materialize(ion_type)?)));
+            } else {
+                items.push((item, None));
+            }
+        }
+        use SystemStreamItem::*;
+        let element = |text: &str| OwnedElement::read_all(text.as_bytes()).unwrap().pop();
+        assert_eq!(
+            items,
+            vec![
+                (
+                    SymbolTable {
+                        is_append: false,
+                        new_symbols_start: 10
+                    },
+                    None
+                ),
+                (Value(IonType::Symbol, false), element("a")),
+                (
+                    SymbolTable {
+                        is_append: true,
+                        new_symbols_start: 12
+                    },
+                    None
+                ),
+                (Value(IonType::List, false), element("[b, c]")),
+                (VersionMarker(1, 0), None),
+                (Value(IonType::Symbol, false), element("foo")),
+            ]
+        );
+        assert_eq!(reader.symbol_table().len(), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_events() -> IonResult<()> {
+        use ReaderEvent::*;
+        let mut reader = Reader::new(RawTextReader::new(r#"{a: foo::[1, null.list], b: "x"} 2"#));
+        let events = reader.events().collect::<IonResult<Vec<ReaderEvent>>>()?;
+        assert_eq!(events.len(), 8);
+        assert!(matches!(
+            &events[0],
+            StartContainer {
+                ion_type: IonType::Struct,
+                field_name: None,
+                ..
+            }
+        ));
+        match &events[1] {
+            StartContainer {
+                ion_type,
+                field_name,
+                annotations,
+            } => {
+                assert_eq!(*ion_type, IonType::List);
+                assert_eq!(field_name.as_ref().unwrap().text(), Some("a"));
+                assert_eq!(annotations[0].text(), Some("foo"));
+            }
+            other => panic!("expected the start of a list, found {:?}", other),
+        }
+        match (&events[2], &events[3]) {
+            (
+                Scalar { value: one, .. },
+                Scalar {
+                    value: null_list, ..
+                },
+            ) => {
+                assert_eq!(one.as_i64(), Some(1));
+                assert!(null_list.is_null());
+            }
+            other => panic!("expected two scalars, found {:?}", other),
+        }
+        assert_eq!(events[4], EndContainer(IonType::List));
+        match &events[5] {
+            Scalar { field_name, value } => {
+                assert_eq!(field_name.as_ref().unwrap().text(), Some("b"));
+                assert_eq!(value.as_str(), Some("x"));
+            }
+            other => panic!("expected a scalar, found {:?}", other),
+        }
+        assert_eq!(events[6], EndContainer(IonType::Struct));
+        assert!(matches!(
+            &events[7],
+            Scalar {
+                field_name: None,
+                ..
+            }
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_events_dropped_early() -> IonResult<()> {
+        let mut reader = Reader::new(RawTextReader::new("[[1, 2], 3] 4"));
+        let scalars = reader
+            .events()
+            .filter_map(|event| match event {
+                Ok(ReaderEvent::Scalar { value, .. }) => value.as_i64(),
+                _ => None,
+            })
+            .take_while(|value| *value < 2)
+            .count();
+        assert_eq!(scalars, 1);
+        // Dropping the iterator stepped out of both lists
+        assert_eq!(reader.depth(), 0);
+        assert_eq!(reader.next()?, Some((IonType::Integer, false)));
+        assert_eq!(reader.read_i64()?, Some(4));
+        Ok(())
+    }
 }
@@ -1,7 +1,12 @@
+use alloc::format;
+use alloc::string::{String, ToString};
 use thiserror::Error;
 
-use std::convert::From;
-use std::{fmt, io};
+use crate::io;
+use core::convert::From;
+use core::fmt;
+
+use crate::types::IonType;
 
 /// A unified Result type representing the outcome of method calls that may fail.
 pub type IonResult<T> = Result<T, IonError>;
@@ -26,8 +31,26 @@ pub enum IonError {
     },
 
     /// Indicates that the data stream being read contained illegal or otherwise unreadable data.
-    #[error("{description}")]
-    DecodingError { description: String },
+    /// If it is known, `offset` is the position in the stream (in bytes) at which the problem
+    /// was detected. `category` classifies the problem so that it can be handled without
+    /// inspecting `description`.
+    ///
+    /// Errors returned by a [Reader](crate::Reader) also describe the value that was being read
+    /// when the problem was detected: `ion_type` is its type (if the reader was positioned over
+    /// a value) and `path` is its location in the stream. See [IonError::path] for the
+    /// path syntax.
+    #[error(
+        "{description}{}{}",
+        display_offset(.offset),
+        display_context(.ion_type, .path)
+    )]
+    DecodingError {
+        description: String,
+        offset: Option<usize>,
+        category: DecodingErrorCategory,
+        ion_type: Option<IonType>,
+        path: Option<String>,
+    },
 
     /// Returned when the user has performed an illegal operation (for example: calling stepOut()
     /// on the cursor at the top level.)
@@ -36,7 +59,13 @@ pub enum IonError {
     )]
     IllegalOperation { operation: String },
 
+    /// Returned when the current value does not have the type that the user said to expect (for
+    /// example: calling `step_in_expecting(IonType::List)` while positioned over a struct).
+    #[error("Expected a(n) {expected} but found a(n) {found}.")]
+    UnexpectedType { expected: IonType, found: IonType },
+
     /// Indicates that the underlying failure is due to a problem in [`ion_c_sys`].
+    #[cfg(feature = "ion-c")]
     #[error("{source:?}")]
     IonCError {
         #[from]
@@ -59,12 +88,27 @@ impl Clone for IonError {
             FmtError { source } => FmtError {
                 source: source.clone(),
             },
-            DecodingError { description } => DecodingError {
+            DecodingError {
+                description,
+                offset,
+                category,
+                ion_type,
+                path,
+            } => DecodingError {
                 description: description.clone(),
+                offset: *offset,
+                category: *category,
+                ion_type: *ion_type,
+                path: path.clone(),
             },
             IllegalOperation { operation } => IllegalOperation {
                 operation: operation.clone(),
             },
+            UnexpectedType { expected, found } => UnexpectedType {
+                expected: *expected,
+                found: *found,
+            },
+            #[cfg(feature = "ion-c")]
             IonCError { source } => IonCError {
                 source: source.clone(),
             },
@@ -82,14 +126,163 @@ impl PartialEq for IonError {
             // We can compare the io::Errors' ErrorKinds, offering a weak definition of equality.
             (IoError { source: s1 }, IoError { source: s2 }) => s1.kind() == s2.kind(),
             (FmtError { source: s1 }, FmtError { source: s2 }) => s1 == s2,
-            (DecodingError { description: s1 }, DecodingError { description: s2 }) => s1 == s2,
+            (
+                DecodingError {
+                    description: s1,
+                    offset: o1,
+                    category: c1,
+                    ion_type: t1,
+                    path: p1,
+                },
+                DecodingError {
+                    description: s2,
+                    offset: o2,
+                    category: c2,
+                    ion_type: t2,
+                    path: p2,
+                },
+            ) => s1 == s2 && o1 == o2 && c1 == c2 && t1 == t2 && p1 == p2,
             (IllegalOperation { operation: s1 }, IllegalOperation { operation: s2 }) => s1 == s2,
+            (
+                UnexpectedType {
+                    expected: e1,
+                    found: f1,
+                },
+                UnexpectedType {
+                    expected: e2,
+                    found: f2,
+                },
+            ) => e1 == e2 && f1 == f2,
+            #[cfg(feature = "ion-c")]
             (IonCError { source: s1 }, IonCError { source: s2 }) => s1 == s2,
             _ => false,
         }
     }
 }
 
+impl IonError {
+    /// If this is a [IonError::DecodingError] whose position in the stream is known, returns the
+    /// offset (in bytes) at which the problem was detected. Otherwise, returns None.
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            IonError::DecodingError { offset, .. } => *offset,
+            _ => None,
+        }
+    }
+
+    /// If this is a [IonError::DecodingError], returns its category. Otherwise, returns None.
+    pub fn category(&self) -> Option<DecodingErrorCategory> {
+        match self {
+            IonError::DecodingError { category, .. } => Some(*category),
+            _ => None,
+        }
+    }
+
+    /// If this is a [IonError::DecodingError] that was returned by a [Reader](crate::Reader)
+    /// while it was positioned over a value, returns that value's type. Otherwise, returns None.
+    pub fn ion_type(&self) -> Option<IonType> {
+        match self {
+            IonError::DecodingError { ion_type, .. } => *ion_type,
+            _ => None,
+        }
+    }
+
+    /// If this is a [IonError::DecodingError] that was returned by a [Reader](crate::Reader),
+    /// returns the location of the value that was being read when the problem was detected.
+    /// Otherwise, returns None.
+    ///
+    /// The path begins with `$` and the zero-based position of the top-level value within the
+    /// stream (or `$?` if the position is not known because the reader has seeked), followed by
+    /// a step for each level of nesting: `.name` for a struct field and `[index]` for a value
+    /// in a list or s-expression. For example, `$3.foo[2]` is the third value in the list
+    /// `foo`, which is a field of the fourth top-level value.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            IonError::DecodingError { path, .. } => path.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// If this is a [IonError::DecodingError] that does not yet have an offset, sets its offset
+    /// to the one provided. Any other error is returned unchanged.
+    pub(crate) fn with_offset(mut self, offset: usize) -> IonError {
+        if let IonError::DecodingError {
+            offset: current, ..
+        } = &mut self
+        {
+            current.get_or_insert(offset);
+        }
+        self
+    }
+
+    /// If this is a [IonError::DecodingError], sets its category to the one provided. Any other
+    /// error is returned unchanged.
+    pub(crate) fn with_category(mut self, category: DecodingErrorCategory) -> IonError {
+        if let IonError::DecodingError {
+            category: current, ..
+        } = &mut self
+        {
+            *current = category;
+        }
+        self
+    }
+
+    /// If this is a [IonError::DecodingError] that does not yet describe the value being read,
+    /// sets its type and path to the ones provided. The path is only computed if it will be used.
+    /// Any other error is returned unchanged.
+    pub(crate) fn with_context<F>(mut self, ion_type: Option<IonType>, path: F) -> IonError
+    where
+        F: FnOnce() -> String,
+    {
+        if let IonError::DecodingError {
+            ion_type: current_type,
+            path: current_path,
+            ..
+        } = &mut self
+        {
+            if current_path.is_none() {
+                *current_type = ion_type;
+                *current_path = Some(path());
+            }
+        }
+        self
+    }
+}
+
+/// A machine-readable classification of an [IonError::DecodingError].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecodingErrorCategory {
+    /// The input ended before the value being read was complete. Reading the same data again
+    /// after more input has arrived may succeed.
+    Incomplete,
+    /// The text of a string, symbol or field name was not valid UTF-8.
+    InvalidUtf8,
+    /// A binary type descriptor byte did not describe a legal value, either because its type
+    /// code is reserved or because its length code is not allowed for that type.
+    IllegalTypeDescriptor,
+    /// The input exceeded one of the resource limits configured with a
+    /// [ReaderBuilder](crate::ReaderBuilder) or a
+    /// [RawBinaryReaderBuilder](crate::RawBinaryReaderBuilder).
+    LimitExceeded,
+    /// Any other problem with the input.
+    Other,
+}
+
+fn display_offset(offset: &Option<usize>) -> String {
+    match offset {
+        Some(offset) => format!(" at byte {}", offset),
+        None => String::new(),
+    }
+}
+
+fn display_context(ion_type: &Option<IonType>, path: &Option<String>) -> String {
+    match (ion_type, path) {
+        (Some(ion_type), Some(path)) => format!(" (reading a(n) {} at {})", ion_type, path),
+        (None, Some(path)) => format!(" (at {})", path),
+        _ => String::new(),
+    }
+}
+
 /// A convenience method for creating an IonResult containing an IonError::DecodingError with the
 /// provided description text.
 pub fn decoding_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
@@ -101,9 +294,44 @@ pub fn decoding_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
 pub fn decoding_error_raw<S: AsRef<str>>(description: S) -> IonError {
     IonError::DecodingError {
         description: description.as_ref().to_string(),
+        offset: None,
+        category: DecodingErrorCategory::Other,
+        ion_type: None,
+        path: None,
     }
 }
 
+/// A convenience method for creating an IonResult containing an IonError::DecodingError with the
+/// provided description text and the offset (in bytes) at which the problem was detected.
+pub fn decoding_error_at<T, S: AsRef<str>>(description: S, offset: usize) -> IonResult<T> {
+    Err(decoding_error_raw(description).with_offset(offset))
+}
+
+/// A convenience method for creating an IonResult containing an IonError::DecodingError in the
+/// [DecodingErrorCategory::Incomplete] category, indicating that the input ended unexpectedly.
+pub fn incomplete_data_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
+    Err(incomplete_data_error_raw(description))
+}
+
+/// Like [incomplete_data_error], but returns the IonError itself. Useful for calling
+/// Option#ok_or_else.
+pub fn incomplete_data_error_raw<S: AsRef<str>>(description: S) -> IonError {
+    decoding_error_raw(description).with_category(DecodingErrorCategory::Incomplete)
+}
+
+/// A convenience method for creating an IonResult containing an IonError::DecodingError in the
+/// [DecodingErrorCategory::LimitExceeded] category, indicating that the input exceeded a
+/// configured resource limit.
+pub fn limit_exceeded_error<T, S: AsRef<str>>(description: S) -> IonResult<T> {
+    Err(limit_exceeded_error_raw(description))
+}
+
+/// Like [limit_exceeded_error], but returns the IonError itself. Useful for calling
+/// Option#ok_or_else.
+pub fn limit_exceeded_error_raw<S: AsRef<str>>(description: S) -> IonError {
+    decoding_error_raw(description).with_category(DecodingErrorCategory::LimitExceeded)
+}
+
 /// A convenience method for creating an IonResult containing an IonError::IllegalOperation with the
 /// provided operation text.
 pub fn illegal_operation<T, S: AsRef<str>>(operation: S) -> IonResult<T> {
@@ -121,11 +349,13 @@ pub fn illegal_operation_raw<S: AsRef<str>>(operation: S) -> IonError {
 #[cfg(test)]
 mod test {
     use super::*;
-    use ion_c_sys::result::*;
-    use ion_c_sys::{ion_error_code_IERR_EOF, ion_error_code_IERR_INVALID_ARG};
 
     #[test]
+    #[cfg(feature = "ion-c")]
     fn ion_c_error_eq() {
+        use ion_c_sys::result::*;
+        use ion_c_sys::{ion_error_code_IERR_EOF, ion_error_code_IERR_INVALID_ARG};
+
         // make sure we can actually convert an Ion C error
         let e1: IonError = IonCError::from(ion_error_code_IERR_EOF).into();
         let e2: IonError = IonCError::from(ion_error_code_IERR_INVALID_ARG).into();
@@ -134,4 +364,52 @@ mod test {
         assert_eq!(e1, e1.clone());
         assert_ne!(e1, e2);
     }
+
+    #[test]
+    fn decoding_error_offset() {
+        let error = decoding_error_raw("VarUInt length overruns stream");
+        assert_eq!(error.offset(), None);
+        assert_eq!(error.to_string(), "VarUInt length overruns stream");
+
+        let error = error.with_offset(88);
+        assert_eq!(error.offset(), Some(88));
+        assert_eq!(
+            error.to_string(),
+            "VarUInt length overruns stream at byte 88"
+        );
+        assert_eq!(error, error.clone());
+        assert_ne!(error, decoding_error_raw("VarUInt length overruns stream"));
+
+        // An offset that has already been set is not replaced
+        assert_eq!(error.with_offset(90).offset(), Some(88));
+    }
+
+    #[test]
+    fn decoding_error_category_and_context() {
+        let error = incomplete_data_error_raw("Unexpected end of stream.");
+        assert_eq!(error.category(), Some(DecodingErrorCategory::Incomplete));
+        assert_eq!(
+            decoding_error_raw("oops").category(),
+            Some(DecodingErrorCategory::Other)
+        );
+        assert_eq!(illegal_operation_raw("oops").category(), None);
+
+        let error = error
+            .with_offset(12)
+            .with_context(Some(IonType::String), || "$3.foo[2]".to_string());
+        assert_eq!(error.ion_type(), Some(IonType::String));
+        assert_eq!(error.path(), Some("$3.foo[2]"));
+        assert_eq!(
+            error.to_string(),
+            "Unexpected end of stream. at byte 12 (reading a(n) string at $3.foo[2])"
+        );
+
+        // Context that has already been set is not replaced
+        let error = error.with_context(None, || unreachable!());
+        assert_eq!(error.path(), Some("$3.foo[2]"));
+        assert_ne!(
+            error,
+            incomplete_data_error_raw("Unexpected end of stream.")
+        );
+    }
 }
@@ -0,0 +1,1101 @@
+//! Validates [OwnedElement]s against the type definitions of an
+//! [Ion Schema](https://amzn.github.io/ion-schema/docs/spec.html) 1.0 document.
+//!
+//! A [Schema] is loaded from the elements of an ISL document. Each top-level value annotated with
+//! `type` defines a named type; the `$ion_schema_1_0` version marker, the `schema_header` and the
+//! `schema_footer` are optional, and any other top-level values are ignored. Type references may
+//! name a core type (`int`, `$string`, `nullable::decimal`, ...), one of the `any`, `number`,
+//! `text`, `lob` and `nothing` types, a type defined in the same document, or an inline type
+//! definition.
+//!
+//! The following constraints are supported:
+//!
+//! * `type`, which every type definition that does not specify one has as `type: any`.
+//! * `annotations`, including the `required`, `optional`, `ordered` and `closed` annotations on
+//!   the list and its symbols.
+//! * `valid_values`, including numeric `range::[min, max]` values with `exclusive` bounds.
+//! * `fields`, with `occurs` in the inline definitions of the fields, and `content: closed`.
+//! * `element`.
+//!
+//! Loading a schema that imports other schemas or uses any other ISL constraint produces an
+//! error, rather than silently accepting data that the constraint would reject. Fields that are
+//! not part of ISL are ignored, as the specification requires.
+//!
+//! ```
+//! use ion_rs::schema::Schema;
+//! use ion_rs::value::owned::OwnedElement;
+//!
+//! let schema = Schema::read(
+//!     br#"
+//!     $ion_schema_1_0
+//!     type::{
+//!         name: person,
+//!         type: struct,
+//!         fields: {
+//!             name: { type: string, occurs: required },
+//!             age: int,
+//!             role: { valid_values: [admin, user] },
+//!         },
+//!     }
+//!     "#,
+//! )?;
+//! let people = OwnedElement::read_all(br#"{name: "Ann", age: 31} {age: 2.5, role: guest}"#)?;
+//! assert!(schema.validate("person", &people[0])?.is_empty());
+//! let violations = schema.validate("person", &people[1])?;
+//! assert_eq!(violations.len(), 3);
+//! assert_eq!(violations[0].constraint(), "occurs");
+//! # Ok::<(), ion_rs::result::IonError>(())
+//! ```
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Bound;
+
+use bigdecimal::BigDecimal;
+
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::types::decimal::Decimal;
+use crate::value::owned::{OwnedElement, OwnedStruct};
+use crate::value::{AnyInt, Element, IntAccess, Sequence, Struct, SymbolToken};
+use crate::{IonType, PathComponent};
+
+const ALL_TYPES: &[IonType] = &[
+    IonType::Null,
+    IonType::Boolean,
+    IonType::Integer,
+    IonType::Float,
+    IonType::Decimal,
+    IonType::Timestamp,
+    IonType::Symbol,
+    IonType::String,
+    IonType::Clob,
+    IonType::Blob,
+    IonType::List,
+    IonType::SExpression,
+    IonType::Struct,
+];
+
+// ISL constraints that this module does not implement. Schemas that use them are rejected.
+const UNSUPPORTED_CONSTRAINTS: &[&str] = &[
+    "all_of",
+    "any_of",
+    "byte_length",
+    "codepoint_length",
+    "container_length",
+    "contains",
+    "not",
+    "one_of",
+    "ordered_elements",
+    "precision",
+    "regex",
+    "scale",
+    "timestamp_offset",
+    "timestamp_precision",
+    "utf8_byte_length",
+];
+
+/// The type definitions of an Ion Schema document. See the [module documentation](self).
+#[derive(Clone, Debug)]
+pub struct Schema {
+    types: HashMap<String, TypeDefinition>,
+}
+
+/// A way in which a value does not conform to a type. See [Schema::validate].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    path: Vec<PathComponent>,
+    constraint: &'static str,
+    message: String,
+}
+
+impl Violation {
+    /// Returns the location of the offending value, relative to the value that was validated.
+    /// Struct fields are identified by name and the elements of lists and s-expressions by their
+    /// position.
+    pub fn path(&self) -> &[PathComponent] {
+        &self.path
+    }
+
+    /// Returns the name of the constraint that was violated, like `type` or `valid_values`.
+    pub fn constraint(&self) -> &str {
+        self.constraint
+    }
+
+    /// Returns a description of the violation.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// Formats the violation as its path followed by its message, like `tags[1]: expected type
+/// symbol, found string`.
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, component) in self.path.iter().enumerate() {
+            match component {
+                PathComponent::Field(name) if index == 0 => write!(f, "{}", name)?,
+                PathComponent::Field(name) => write!(f, ".{}", name)?,
+                PathComponent::Index(index) => write!(f, "[{}]", index)?,
+                PathComponent::Wildcard => write!(f, "[*]")?,
+            }
+        }
+        if !self.path.is_empty() {
+            write!(f, ": ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct TypeDefinition {
+    constraints: Vec<Constraint>,
+}
+
+#[derive(Clone, Debug)]
+enum Constraint {
+    Type(TypeReference),
+    Annotations(AnnotationsConstraint),
+    ValidValues(Vec<ValidValue>),
+    Fields {
+        fields: Vec<FieldDefinition>,
+        // Whether fields other than those in `fields` are prohibited
+        closed: bool,
+    },
+    Element(TypeReference),
+}
+
+#[derive(Clone, Debug)]
+enum TypeReference {
+    // A core type or one of the other types defined by ISL. Nulls are accepted if the name begins
+    // with `$` or if the reference is annotated with `nullable`.
+    BuiltIn {
+        name: String,
+        types: &'static [IonType],
+        accepts_nulls: bool,
+    },
+    Named(String),
+    Inline(Box<TypeDefinition>),
+}
+
+#[derive(Clone, Debug)]
+struct AnnotationsConstraint {
+    // Each annotation, along with whether it is required
+    annotations: Vec<(String, bool)>,
+    ordered: bool,
+    closed: bool,
+}
+
+#[derive(Clone, Debug)]
+enum ValidValue {
+    // A value without annotations
+    Value(OwnedElement),
+    Range(Bound<Decimal>, Bound<Decimal>),
+}
+
+#[derive(Clone, Debug)]
+struct FieldDefinition {
+    name: String,
+    type_reference: TypeReference,
+    // The minimum and maximum number of times that the field can appear
+    occurs: (usize, usize),
+}
+
+impl Schema {
+    /// Loads the type definitions in the ISL document `data`, which may be Ion text or binary.
+    pub fn read(data: &[u8]) -> IonResult<Schema> {
+        Schema::from_elements(&OwnedElement::read_all(data)?)
+    }
+
+    /// Loads the type definitions in `elements`, the top-level values of an ISL document.
+    pub fn from_elements<'a, I>(elements: I) -> IonResult<Schema>
+    where
+        I: IntoIterator<Item = &'a OwnedElement>,
+    {
+        let mut types = HashMap::new();
+        for element in elements {
+            if let Some(text) = symbol_text(element) {
+                if text.starts_with("$ion_schema_") && text != "$ion_schema_1_0" {
+                    return decoding_error(format!("Unsupported Ion Schema version: {}", text));
+                }
+                continue;
+            }
+            if element.has_annotation("schema_header") {
+                let imports = element
+                    .as_struct()
+                    .and_then(|header| header.get("imports"))
+                    .and_then(|imports| imports.as_sequence());
+                if imports.is_some_and(|imports| !imports.is_empty()) {
+                    return decoding_error("Schemas that import other schemas are not supported.");
+                }
+            } else if element.has_annotation("type") {
+                let definition = match element.as_struct() {
+                    Some(definition) => definition,
+                    None => return decoding_error("A type definition must be a struct."),
+                };
+                let name = match definition.get("name").and_then(symbol_text) {
+                    Some(name) => name,
+                    None => return decoding_error("A type definition must have a symbol name."),
+                };
+                if builtin_type(name).is_some() || types.contains_key(name) {
+                    return decoding_error(format!("The type '{}' is already defined.", name));
+                }
+                let (definition, _) = parse_type_definition(definition, false)?;
+                types.insert(name.to_string(), definition);
+            }
+        }
+        let schema = Schema { types };
+        for definition in schema.types.values() {
+            schema.check_references(definition)?;
+        }
+        for (name, definition) in &schema.types {
+            schema.check_type_cycles(definition, &mut vec![name.as_str()])?;
+        }
+        Ok(schema)
+    }
+
+    /// Returns true if the schema defines a type named `name`.
+    pub fn contains_type(&self, name: &str) -> bool {
+        self.types.contains_key(name)
+    }
+
+    /// Returns the names of the types that the schema defines, in no particular order.
+    pub fn type_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.types.keys().map(|name| name.as_str())
+    }
+
+    /// Validates `value` against the type named `type_name`, which may be defined by the schema
+    /// or be one of the types defined by ISL. Returns the ways in which the value does not
+    /// conform to the type, which is empty if it is valid.
+    pub fn validate(&self, type_name: &str, value: &OwnedElement) -> IonResult<Vec<Violation>> {
+        let type_reference = self.named_reference(type_name)?;
+        let mut violations = Vec::new();
+        self.validate_reference(&type_reference, value, &mut Vec::new(), &mut violations);
+        Ok(violations)
+    }
+
+    /// Validates each of `values` (for example, the top-level values of a stream) against the
+    /// type named `type_name`. The path of each violation begins with the position of the value
+    /// that it belongs to.
+    pub fn validate_all<'a, I>(&self, type_name: &str, values: I) -> IonResult<Vec<Violation>>
+    where
+        I: IntoIterator<Item = &'a OwnedElement>,
+    {
+        let type_reference = self.named_reference(type_name)?;
+        let mut violations = Vec::new();
+        for (index, value) in values.into_iter().enumerate() {
+            let mut path = vec![PathComponent::Index(index)];
+            self.validate_reference(&type_reference, value, &mut path, &mut violations);
+        }
+        Ok(violations)
+    }
+
+    fn named_reference(&self, type_name: &str) -> IonResult<TypeReference> {
+        if let Some(type_reference) = builtin_type(type_name) {
+            return Ok(type_reference);
+        }
+        if !self.types.contains_key(type_name) {
+            return illegal_operation(format!(
+                "The schema does not define a type '{}'.",
+                type_name
+            ));
+        }
+        Ok(TypeReference::Named(type_name.to_string()))
+    }
+
+    // Returns an error if `definition` refers to a type that the schema does not define.
+    fn check_references(&self, definition: &TypeDefinition) -> IonResult<()> {
+        for constraint in &definition.constraints {
+            match constraint {
+                Constraint::Type(type_reference) | Constraint::Element(type_reference) => {
+                    self.check_reference(type_reference)?
+                }
+                Constraint::Fields { fields, .. } => {
+                    for field in fields {
+                        self.check_reference(&field.type_reference)?;
+                    }
+                }
+                Constraint::Annotations(_) | Constraint::ValidValues(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn check_reference(&self, type_reference: &TypeReference) -> IonResult<()> {
+        match type_reference {
+            TypeReference::BuiltIn { .. } => Ok(()),
+            TypeReference::Named(name) if self.types.contains_key(name) => Ok(()),
+            TypeReference::Named(name) => {
+                decoding_error(format!("The schema does not define a type '{}'.", name))
+            }
+            TypeReference::Inline(definition) => self.check_references(definition),
+        }
+    }
+
+    // Returns an error if validating a value against `definition` would validate the same value
+    // against one of the types in `visiting` again, which would never finish. Types may refer to
+    // themselves through `element` and `fields`, which apply to the child values.
+    fn check_type_cycles<'a>(
+        &'a self,
+        definition: &'a TypeDefinition,
+        visiting: &mut Vec<&'a str>,
+    ) -> IonResult<()> {
+        for constraint in &definition.constraints {
+            match constraint {
+                Constraint::Type(TypeReference::Named(name)) => {
+                    if visiting.contains(&name.as_str()) {
+                        return decoding_error(format!(
+                            "The type '{}' is defined in terms of itself.",
+                            name
+                        ));
+                    }
+                    visiting.push(name);
+                    self.check_type_cycles(&self.types[name], visiting)?;
+                    visiting.pop();
+                }
+                Constraint::Type(TypeReference::Inline(definition)) => {
+                    self.check_type_cycles(definition, visiting)?
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_reference(
+        &self,
+        type_reference: &TypeReference,
+        value: &OwnedElement,
+        path: &mut Vec<PathComponent>,
+        violations: &mut Vec<Violation>,
+    ) {
+        match type_reference {
+            TypeReference::BuiltIn {
+                name,
+                types,
+                accepts_nulls,
+            } => {
+                let matches = if value.is_null() {
+                    *accepts_nulls
+                        && (value.ion_type() == IonType::Null || types.contains(&value.ion_type()))
+                } else {
+                    types.contains(&value.ion_type())
+                };
+                if !matches {
+                    let message = format!("expected type {}, found {}", name, describe(value));
+                    violations.push(violation(path, "type", message));
+                }
+            }
+            TypeReference::Named(name) => {
+                // References are checked when the schema is loaded.
+                let definition = &self.types[name];
+                self.validate_definition(definition, value, path, violations)
+            }
+            TypeReference::Inline(definition) => {
+                self.validate_definition(definition, value, path, violations)
+            }
+        }
+    }
+
+    fn validate_definition(
+        &self,
+        definition: &TypeDefinition,
+        value: &OwnedElement,
+        path: &mut Vec<PathComponent>,
+        violations: &mut Vec<Violation>,
+    ) {
+        for constraint in &definition.constraints {
+            match constraint {
+                Constraint::Type(type_reference) => {
+                    self.validate_reference(type_reference, value, path, violations)
+                }
+                Constraint::Annotations(constraint) => {
+                    validate_annotations(constraint, value, path, violations)
+                }
+                Constraint::ValidValues(valid_values) => {
+                    if !valid_values
+                        .iter()
+                        .any(|valid| is_valid_value(valid, value))
+                    {
+                        let message = format!("{} is not one of the valid values", value);
+                        violations.push(violation(path, "valid_values", message));
+                    }
+                }
+                Constraint::Fields { fields, closed } => {
+                    self.validate_fields(fields, *closed, value, path, violations)
+                }
+                Constraint::Element(type_reference) => {
+                    let sequence = match value.ion_type() {
+                        IonType::List | IonType::SExpression => value.as_sequence(),
+                        _ => None,
+                    };
+                    let sequence = match sequence {
+                        Some(sequence) => sequence,
+                        None => {
+                            let message = format!(
+                                "expected a list or s-expression, found {}",
+                                describe(value)
+                            );
+                            violations.push(violation(path, "element", message));
+                            continue;
+                        }
+                    };
+                    for (index, child) in sequence.iter().enumerate() {
+                        path.push(PathComponent::Index(index));
+                        self.validate_reference(type_reference, child, path, violations);
+                        path.pop();
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_fields(
+        &self,
+        fields: &[FieldDefinition],
+        closed: bool,
+        value: &OwnedElement,
+        path: &mut Vec<PathComponent>,
+        violations: &mut Vec<Violation>,
+    ) {
+        let value_struct = match value.as_struct() {
+            Some(value_struct) => value_struct,
+            None => {
+                let message = format!("expected a struct, found {}", describe(value));
+                violations.push(violation(path, "fields", message));
+                return;
+            }
+        };
+        for field in fields {
+            let (min, max) = field.occurs;
+            let count = value_struct.get_all(&field.name).count();
+            if count < min || count > max {
+                let message = format!(
+                    "expected the field '{}' to occur {}, found {}",
+                    field.name,
+                    describe_occurs(field.occurs),
+                    count
+                );
+                violations.push(violation(path, "occurs", message));
+            }
+            path.push(PathComponent::Field(field.name.clone()));
+            for child in value_struct.get_all(&field.name) {
+                self.validate_reference(&field.type_reference, child, path, violations);
+            }
+            path.pop();
+        }
+        if closed {
+            for (name, _) in value_struct.iter_in_order() {
+                let declared = match name.text() {
+                    Some(text) => fields.iter().any(|field| field.name == text),
+                    None => false,
+                };
+                if !declared {
+                    let name = name.text().unwrap_or("$0");
+                    let message = format!("unexpected field '{}'", name);
+                    violations.push(violation(path, "content", message));
+                }
+            }
+        }
+    }
+}
+
+fn violation(path: &[PathComponent], constraint: &'static str, message: String) -> Violation {
+    Violation {
+        path: path.to_vec(),
+        constraint,
+        message,
+    }
+}
+
+// Describes the type of `value` for use in violation messages.
+fn describe(value: &OwnedElement) -> String {
+    if value.is_null() {
+        format!("null.{}", value.ion_type())
+    } else {
+        value.ion_type().to_string()
+    }
+}
+
+fn describe_occurs(occurs: (usize, usize)) -> String {
+    match occurs {
+        (min, max) if min == max => format!("exactly {} time(s)", min),
+        (min, max) if max == usize::MAX => format!("at least {} time(s)", min),
+        (min, max) => format!("between {} and {} times", min, max),
+    }
+}
+
+fn validate_annotations(
+    constraint: &AnnotationsConstraint,
+    value: &OwnedElement,
+    path: &[PathComponent],
+    violations: &mut Vec<Violation>,
+) {
+    let actual: Vec<Option<&str>> = value.annotations().map(|token| token.text()).collect();
+    for (annotation, required) in &constraint.annotations {
+        if *required && !actual.contains(&Some(annotation.as_str())) {
+            let message = format!("missing the required annotation '{}'", annotation);
+            violations.push(violation(path, "annotations", message));
+        }
+    }
+    // The position of each of the value's annotations in the constraint's list, if it has one
+    let positions: Vec<Option<usize>> = actual
+        .iter()
+        .map(|text| {
+            constraint
+                .annotations
+                .iter()
+                .position(|(annotation, _)| Some(annotation.as_str()) == *text)
+        })
+        .collect();
+    if constraint.closed {
+        for (text, position) in actual.iter().zip(&positions) {
+            if position.is_none() {
+                let message = format!("unexpected annotation '{}'", text.unwrap_or("$0"));
+                violations.push(violation(path, "annotations", message));
+            }
+        }
+    }
+    if constraint.ordered {
+        let listed: Vec<usize> = positions.iter().flatten().copied().collect();
+        if listed.windows(2).any(|pair| pair[0] > pair[1]) {
+            let message = "the annotations are not in the required order".to_string();
+            violations.push(violation(path, "annotations", message));
+        }
+    }
+}
+
+fn is_valid_value(valid_value: &ValidValue, value: &OwnedElement) -> bool {
+    match valid_value {
+        ValidValue::Value(expected) => {
+            // The value's annotations are not considered.
+            expected.ion_eq(&OwnedElement::new(Vec::new(), value.value().clone()))
+        }
+        ValidValue::Range(lower, upper) => match number_as_decimal(value) {
+            Some(number) => {
+                let above_lower = match lower {
+                    Bound::Included(lower) => number >= *lower,
+                    Bound::Excluded(lower) => number > *lower,
+                    Bound::Unbounded => true,
+                };
+                let below_upper = match upper {
+                    Bound::Included(upper) => number <= *upper,
+                    Bound::Excluded(upper) => number < *upper,
+                    Bound::Unbounded => true,
+                };
+                above_lower && below_upper
+            }
+            None => false,
+        },
+    }
+}
+
+// Returns the value of a non-null int, decimal or finite float as a Decimal.
+fn number_as_decimal(value: &OwnedElement) -> Option<Decimal> {
+    if let Some(int) = value.as_any_int() {
+        return Some(match int {
+            AnyInt::I64(int) => Decimal::from(*int),
+            AnyInt::BigInt(int) => Decimal::from(BigDecimal::new(int.clone(), 0)),
+        });
+    }
+    if let Some(decimal) = value.as_decimal() {
+        return Some(decimal.clone());
+    }
+    value
+        .as_f64()
+        .and_then(|float| Decimal::try_from(float).ok())
+}
+
+fn symbol_text(element: &OwnedElement) -> Option<&str> {
+    element.as_sym().and_then(|token| token.text())
+}
+
+// Returns a reference to the type named `name` if it is one of the types defined by ISL.
+fn builtin_type(name: &str) -> Option<TypeReference> {
+    let (core_name, accepts_nulls) = match name.strip_prefix('$') {
+        Some(core_name) => (core_name, true),
+        None => (name, false),
+    };
+    let types: &'static [IonType] = match core_name {
+        "bool" => &[IonType::Boolean],
+        "int" => &[IonType::Integer],
+        "float" => &[IonType::Float],
+        "decimal" => &[IonType::Decimal],
+        "timestamp" => &[IonType::Timestamp],
+        "symbol" => &[IonType::Symbol],
+        "string" => &[IonType::String],
+        "clob" => &[IonType::Clob],
+        "blob" => &[IonType::Blob],
+        "list" => &[IonType::List],
+        "sexp" => &[IonType::SExpression],
+        "struct" => &[IonType::Struct],
+        "number" => &[IonType::Integer, IonType::Decimal, IonType::Float],
+        "text" => &[IonType::String, IonType::Symbol],
+        "lob" => &[IonType::Clob, IonType::Blob],
+        "any" => ALL_TYPES,
+        "null" if accepts_nulls => &[IonType::Null],
+        "nothing" if !accepts_nulls => &[],
+        _ => return None,
+    };
+    Some(TypeReference::BuiltIn {
+        name: name.to_string(),
+        types,
+        accepts_nulls,
+    })
+}
+
+// Parses an ISL type definition. If `is_field` is true, the definition may include `occurs`,
+// which is returned as the minimum and maximum number of occurrences.
+fn parse_type_definition(
+    definition: &OwnedStruct,
+    is_field: bool,
+) -> IonResult<(TypeDefinition, Option<(usize, usize)>)> {
+    let mut constraints = Vec::new();
+    let mut has_type = false;
+    let mut fields = None;
+    let mut closed = false;
+    let mut occurs = None;
+    for (name, value) in definition.iter_in_order() {
+        let name = match name.text() {
+            Some(name) => name,
+            None => continue,
+        };
+        match name {
+            "type" => {
+                has_type = true;
+                constraints.push(Constraint::Type(parse_type_reference(value)?));
+            }
+            "annotations" => constraints.push(Constraint::Annotations(parse_annotations(value)?)),
+            "valid_values" => constraints.push(Constraint::ValidValues(parse_valid_values(value)?)),
+            "fields" => {
+                if fields.is_some() {
+                    return decoding_error("A type definition can only have one 'fields'.");
+                }
+                fields = Some(parse_fields(value)?);
+            }
+            "content" => {
+                if symbol_text(value) != Some("closed") {
+                    return decoding_error("The value of 'content' must be the symbol 'closed'.");
+                }
+                closed = true;
+            }
+            "element" => constraints.push(Constraint::Element(parse_type_reference(value)?)),
+            "occurs" if is_field => occurs = Some(parse_occurs(value)?),
+            "occurs" => {
+                return decoding_error("'occurs' can only be used in the definition of a field.")
+            }
+            unsupported if UNSUPPORTED_CONSTRAINTS.contains(&unsupported) => {
+                return decoding_error(format!(
+                    "The '{}' constraint is not supported.",
+                    unsupported
+                ));
+            }
+            // Open content, which is ignored
+            _ => {}
+        }
+    }
+    if fields.is_some() || closed {
+        constraints.push(Constraint::Fields {
+            fields: fields.unwrap_or_default(),
+            closed,
+        });
+    }
+    if !has_type {
+        constraints.insert(0, Constraint::Type(builtin_type("any").unwrap()));
+    }
+    Ok((TypeDefinition { constraints }, occurs))
+}
+
+fn parse_type_reference(value: &OwnedElement) -> IonResult<TypeReference> {
+    let nullable = value.has_annotation("nullable");
+    if let Some(name) = symbol_text(value) {
+        return match builtin_type(name) {
+            Some(TypeReference::BuiltIn { name, types, .. }) if nullable => {
+                Ok(TypeReference::BuiltIn {
+                    name: format!("nullable::{}", name),
+                    types,
+                    accepts_nulls: true,
+                })
+            }
+            Some(type_reference) => Ok(type_reference),
+            None if nullable => decoding_error(format!(
+                "'nullable' can only annotate core types, not '{}'.",
+                name
+            )),
+            None => Ok(TypeReference::Named(name.to_string())),
+        };
+    }
+    match value.as_struct() {
+        Some(_) if nullable => decoding_error("'nullable' can only annotate core types."),
+        Some(definition) if definition.get("id").is_some() => {
+            decoding_error("References to types in other schemas are not supported.")
+        }
+        Some(definition) => {
+            let (definition, _) = parse_type_definition(definition, false)?;
+            Ok(TypeReference::Inline(Box::new(definition)))
+        }
+        None => decoding_error(format!("Invalid type reference: {}", value)),
+    }
+}
+
+fn parse_fields(value: &OwnedElement) -> IonResult<Vec<FieldDefinition>> {
+    let definitions = match value.as_struct() {
+        Some(definitions) => definitions,
+        None => return decoding_error("The value of 'fields' must be a struct."),
+    };
+    let mut fields = Vec::new();
+    for (name, value) in definitions.iter_in_order() {
+        let name = match name.text() {
+            Some(name) => name.to_string(),
+            None => return decoding_error("The fields of 'fields' must have text."),
+        };
+        if fields
+            .iter()
+            .any(|field: &FieldDefinition| field.name == name)
+        {
+            return decoding_error(format!("The field '{}' is defined more than once.", name));
+        }
+        // Fields are optional unless their inline definition says otherwise.
+        let (type_reference, occurs) = match value.as_struct() {
+            Some(definition) if !value.has_annotation("nullable") => {
+                let (definition, occurs) = parse_type_definition(definition, true)?;
+                (TypeReference::Inline(Box::new(definition)), occurs)
+            }
+            _ => (parse_type_reference(value)?, None),
+        };
+        fields.push(FieldDefinition {
+            name,
+            type_reference,
+            occurs: occurs.unwrap_or((0, 1)),
+        });
+    }
+    Ok(fields)
+}
+
+fn parse_occurs(value: &OwnedElement) -> IonResult<(usize, usize)> {
+    match symbol_text(value) {
+        Some("optional") => return Ok((0, 1)),
+        Some("required") => return Ok((1, 1)),
+        _ => {}
+    }
+    if let Some(count) = value.as_i64() {
+        if value.annotations().next().is_none() && count >= 0 {
+            return Ok((count as usize, count as usize));
+        }
+    }
+    let (lower, upper) = parse_range(value)?;
+    let count = |count: &OwnedElement| -> IonResult<usize> {
+        match count.as_i64() {
+            Some(count) if count >= 0 => Ok(count as usize),
+            _ => decoding_error(format!("Invalid 'occurs' bound: {}", count)),
+        }
+    };
+    let min = match lower {
+        Bound::Unbounded => 0,
+        Bound::Included(lower) => count(lower)?,
+        Bound::Excluded(lower) => count(lower)?.saturating_add(1),
+    };
+    let max = match upper {
+        Bound::Unbounded => usize::MAX,
+        Bound::Included(upper) => count(upper)?,
+        Bound::Excluded(upper) => count(upper)?.saturating_sub(1),
+    };
+    let occurs = (min, max);
+    if occurs.0 > occurs.1 || occurs.1 == 0 {
+        return decoding_error(format!("Invalid 'occurs' range: {}", value));
+    }
+    Ok(occurs)
+}
+
+fn parse_annotations(value: &OwnedElement) -> IonResult<AnnotationsConstraint> {
+    let list = match value.ion_type() {
+        IonType::List => value.as_sequence(),
+        _ => None,
+    };
+    let list = match list {
+        Some(list) => list,
+        None => return decoding_error("The value of 'annotations' must be a list."),
+    };
+    let all_required = value.has_annotation("required");
+    let mut annotations = Vec::new();
+    for annotation in list.iter() {
+        let text = match symbol_text(annotation) {
+            Some(text) => text.to_string(),
+            None => return decoding_error("Each annotation in 'annotations' must be a symbol."),
+        };
+        let required = annotation.has_annotation("required")
+            || (all_required && !annotation.has_annotation("optional"));
+        annotations.push((text, required));
+    }
+    Ok(AnnotationsConstraint {
+        annotations,
+        ordered: value.has_annotation("ordered"),
+        closed: value.has_annotation("closed"),
+    })
+}
+
+fn parse_valid_values(value: &OwnedElement) -> IonResult<Vec<ValidValue>> {
+    if value.has_annotation("range") {
+        return Ok(vec![parse_valid_values_range(value)?]);
+    }
+    let list = match value.ion_type() {
+        IonType::List => value.as_sequence(),
+        _ => None,
+    };
+    let list = match list {
+        Some(list) => list,
+        None => return decoding_error("The value of 'valid_values' must be a list or a range."),
+    };
+    list.iter()
+        .map(|valid_value| {
+            if valid_value.has_annotation("range") {
+                return parse_valid_values_range(valid_value);
+            }
+            if valid_value.annotations().next().is_some() {
+                return decoding_error(format!(
+                    "The values in 'valid_values' cannot be annotated: {}",
+                    valid_value
+                ));
+            }
+            Ok(ValidValue::Value(valid_value.clone()))
+        })
+        .collect()
+}
+
+fn parse_valid_values_range(value: &OwnedElement) -> IonResult<ValidValue> {
+    let (lower, upper) = parse_range(value)?;
+    let bound = |bound: Bound<&OwnedElement>| -> IonResult<Bound<Decimal>> {
+        let number = match bound {
+            Bound::Unbounded => return Ok(Bound::Unbounded),
+            Bound::Included(number) | Bound::Excluded(number) => number,
+        };
+        let number = match number_as_decimal(number) {
+            Some(number) => number,
+            None => {
+                return decoding_error(format!(
+                    "Only numeric ranges are supported in 'valid_values', found: {}",
+                    number
+                ))
+            }
+        };
+        Ok(match bound {
+            Bound::Excluded(_) => Bound::Excluded(number),
+            _ => Bound::Included(number),
+        })
+    };
+    Ok(ValidValue::Range(bound(lower)?, bound(upper)?))
+}
+
+// Parses `range::[lower, upper]`, in which either bound may be annotated with `exclusive` and
+// the symbols `min` and `max` stand for unbounded ranges.
+fn parse_range(value: &OwnedElement) -> IonResult<(Bound<&OwnedElement>, Bound<&OwnedElement>)> {
+    let bounds = match value.ion_type() {
+        IonType::List if value.has_annotation("range") => value.as_sequence(),
+        _ => None,
+    };
+    let bounds: Vec<&OwnedElement> = match bounds {
+        Some(bounds) if bounds.len() == 2 => bounds.iter().collect(),
+        _ => return decoding_error(format!("Invalid range: {}", value)),
+    };
+    Ok((
+        parse_range_bound(value, bounds[0], "min")?,
+        parse_range_bound(value, bounds[1], "max")?,
+    ))
+}
+
+fn parse_range_bound<'a>(
+    range: &OwnedElement,
+    bound: &'a OwnedElement,
+    unbounded: &str,
+) -> IonResult<Bound<&'a OwnedElement>> {
+    let exclusive = bound.has_annotation("exclusive");
+    match symbol_text(bound) {
+        Some(text) if text == unbounded && !exclusive => Ok(Bound::Unbounded),
+        Some(_) => decoding_error(format!("Invalid range: {}", range)),
+        None if exclusive => Ok(Bound::Excluded(bound)),
+        None => Ok(Bound::Included(bound)),
+    }
+}
+
+#[cfg(test)]
+mod schema_tests {
+    use super::*;
+    use crate::ion;
+    use rstest::*;
+
+    fn schema() -> Schema {
+        Schema::read(
+            br#"
+            $ion_schema_1_0
+            schema_header::{}
+            type::{
+                name: order,
+                type: struct,
+                annotations: required::ordered::[priority, urgent],
+                fields: {
+                    id: { type: int, occurs: required, valid_values: range::[1, max] },
+                    status: { valid_values: [open, closed, "unknown"] },
+                    items: { type: list, element: item },
+                    notes: nullable::string,
+                    tags: { type: symbol, occurs: range::[0, 2] },
+                },
+                content: closed,
+            }
+            type::{
+                name: item,
+                fields: {
+                    sku: { type: text, occurs: required },
+                    quantity: { type: $int, valid_values: [range::[exclusive::0, 100], null.int] },
+                },
+            }
+            schema_footer::{}
+            "#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn valid_values_have_no_violations() -> IonResult<()> {
+        let schema = schema();
+        let mut type_names: Vec<_> = schema.type_names().collect();
+        type_names.sort_unstable();
+        assert_eq!(type_names, vec!["item", "order"]);
+        let order = ion!(
+            r#"priority::urgent::{
+                id: 7,
+                status: "unknown",
+                items: [{sku: "a", quantity: 3}, {sku: b, quantity: null.int}],
+                notes: null,
+                tags: x,
+                tags: y,
+            }"#
+        );
+        assert_eq!(schema.validate("order", &order)?, vec![]);
+        assert!(schema.validate("$any", &ion!("null"))?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_values_have_violations() -> IonResult<()> {
+        let schema = schema();
+        let order = ion!(
+            r#"urgent::priority::{
+                status: pending,
+                items: [{sku: "a", quantity: 0}, {quantity: 1.5}, "c"],
+                notes: null.int,
+                tags: x, tags: y, tags: z,
+                extra: true,
+            }"#
+        );
+        let violations: Vec<(String, &str)> = schema
+            .validate("order", &order)?
+            .iter()
+            .map(|violation| (violation.to_string(), violation.constraint))
+            .collect();
+        let expected = vec![
+            (
+                "the annotations are not in the required order",
+                "annotations",
+            ),
+            (
+                "expected the field 'id' to occur exactly 1 time(s), found 0",
+                "occurs",
+            ),
+            (
+                "status: 'pending' is not one of the valid values",
+                "valid_values",
+            ),
+            (
+                "items[0].quantity: 0 is not one of the valid values",
+                "valid_values",
+            ),
+            (
+                "items[1]: expected the field 'sku' to occur exactly 1 time(s), found 0",
+                "occurs",
+            ),
+            (
+                "items[1].quantity: expected type $int, found decimal",
+                "type",
+            ),
+            ("items[2]: expected a struct, found string", "fields"),
+            (
+                "notes: expected type nullable::string, found null.integer",
+                "type",
+            ),
+            (
+                "expected the field 'tags' to occur between 0 and 2 times, found 3",
+                "occurs",
+            ),
+            ("unexpected field 'extra'", "content"),
+        ];
+        let expected: Vec<(String, &str)> = expected
+            .into_iter()
+            .map(|(message, constraint)| (message.to_string(), constraint))
+            .collect();
+        assert_eq!(violations, expected);
+
+        let missing = schema.validate("order", &ion!("priority::{id: 1}"))?;
+        assert_eq!(missing.len(), 1);
+        assert_eq!(
+            missing[0].message(),
+            "missing the required annotation 'urgent'"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn validate_all_reports_positions() -> IonResult<()> {
+        let schema = schema();
+        let values = OwnedElement::read_all(b"{sku: a} {sku: 1} {sku: b}")?;
+        let violations = schema.validate_all("item", &values)?;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].path(),
+            &[
+                PathComponent::Index(1),
+                PathComponent::Field("sku".to_string())
+            ]
+        );
+        assert!(schema.validate("missing", &values[0]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn types_can_refer_to_themselves_through_child_values() -> IonResult<()> {
+        let schema = Schema::read(
+            br#"
+            type::{name: tree, type: list, element: tree}
+            type::{name: node, fields: {next: node, value: int}}
+            "#,
+        )?;
+        assert!(schema.validate("tree", &ion!("[[], [[]]]"))?.is_empty());
+        let violations = schema.validate("node", &ion!("{value: 1, next: {value: a}}"))?;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].to_string(),
+            "next.value: expected type int, found symbol"
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unknown_type("type::{name: a, type: b}")]
+    #[case::duplicate_type("type::{name: a} type::{name: a}")]
+    #[case::builtin_type("type::{name: int}")]
+    #[case::unnamed_type("type::{type: int}")]
+    #[case::unsupported_constraint("type::{name: a, regex: \"a+\"}")]
+    #[case::unsupported_version("$ion_schema_2_0")]
+    #[case::imports("schema_header::{imports: [{id: \"other.isl\"}]}")]
+    #[case::imported_type("type::{name: a, type: {id: \"other.isl\", type: b}}")]
+    #[case::nullable_named_type("type::{name: a} type::{name: b, type: nullable::a}")]
+    #[case::occurs_outside_field("type::{name: a, occurs: required}")]
+    #[case::invalid_occurs("type::{name: a, fields: {b: {occurs: range::[2, 1]}}}")]
+    #[case::annotated_valid_value("type::{name: a, valid_values: [b::c]}")]
+    #[case::timestamp_range("type::{name: a, valid_values: range::[2020T, max]}")]
+    #[case::self_reference("type::{name: a, type: a}")]
+    #[case::indirect_self_reference("type::{name: a, type: b} type::{name: b, type: a}")]
+    #[case::inline_self_reference("type::{name: a, type: {type: {type: a}}}")]
+    fn invalid_schemas(#[case] text: &str) {
+        assert!(Schema::read(text.as_bytes()).is_err(), "{}", text);
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+use ::serde::de::value::{MapDeserializer, SeqDeserializer, StringDeserializer};
+use ::serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, VariantAccess, Visitor,
+};
+use ::serde::forward_to_deserialize_any;
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+use crate::result::{decoding_error, IonError, IonResult};
+use crate::value::owned::{OwnedElement, OwnedStruct, OwnedSymbolToken, OwnedValue};
+use crate::value::reader::{element_reader, ElementReader};
+use crate::value::{AnyInt, Element, SymbolToken};
+
+/// Deserializes an instance of `T` from a single top-level value of text or binary Ion.
+pub fn from_slice<T: DeserializeOwned>(data: &[u8]) -> IonResult<T> {
+    from_element(element_reader().read_one(data)?)
+}
+
+/// Deserializes an instance of `T` from an [`OwnedElement`].
+pub fn from_element<T: DeserializeOwned>(element: OwnedElement) -> IonResult<T> {
+    T::deserialize(Deserializer::new(element))
+}
+
+/// A serde [`Deserializer`](de::Deserializer) that consumes an [`OwnedElement`].
+pub struct Deserializer {
+    element: OwnedElement,
+}
+
+impl Deserializer {
+    pub fn new(element: OwnedElement) -> Self {
+        Deserializer { element }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, IonError> for OwnedElement {
+    type Deserializer = Deserializer;
+
+    fn into_deserializer(self) -> Deserializer {
+        Deserializer::new(self)
+    }
+}
+
+fn symbol_text(symbol: OwnedSymbolToken) -> IonResult<String> {
+    match symbol.text() {
+        Some(text) => Ok(text.to_string()),
+        None => decoding_error(format!(
+            "Cannot deserialize a symbol with no text: {:?}",
+            symbol
+        )),
+    }
+}
+
+fn visit_big_int<'de, V: Visitor<'de>>(value: BigInt, visitor: V) -> IonResult<V::Value> {
+    if let Some(value) = value.to_i64() {
+        visitor.visit_i64(value)
+    } else if let Some(value) = value.to_u64() {
+        visitor.visit_u64(value)
+    } else if let Some(value) = value.to_i128() {
+        visitor.visit_i128(value)
+    } else if let Some(value) = value.to_u128() {
+        visitor.visit_u128(value)
+    } else {
+        decoding_error(format!("Integer {} is too large to deserialize", value))
+    }
+}
+
+// Visits the items of a list, s-expression, blob or clob as a serde sequence.
+fn visit_sequence<'de, I, V>(items: I, visitor: V) -> IonResult<V::Value>
+where
+    I: Iterator,
+    I::Item: IntoDeserializer<'de, IonError>,
+    V: Visitor<'de>,
+{
+    let mut access: SeqDeserializer<I, IonError> = SeqDeserializer::new(items);
+    let value = visitor.visit_seq(&mut access)?;
+    access.end()?;
+    Ok(value)
+}
+
+fn visit_struct<'de, V: Visitor<'de>>(structure: OwnedStruct, visitor: V) -> IonResult<V::Value> {
+    let fields = structure
+        .into_iter()
+        .map(|(name, value)| Ok((symbol_text(name)?, value)))
+        .collect::<IonResult<Vec<_>>>()?;
+    let mut access: MapDeserializer<'de, _, IonError> = MapDeserializer::new(fields.into_iter());
+    let value = visitor.visit_map(&mut access)?;
+    access.end()?;
+    Ok(value)
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = IonError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        let (_annotations, value) = self.element.into_parts();
+        match value {
+            OwnedValue::Null(_) => visitor.visit_unit(),
+            OwnedValue::Boolean(value) => visitor.visit_bool(value),
+            OwnedValue::Integer(AnyInt::I64(value)) => visitor.visit_i64(value),
+            OwnedValue::Integer(AnyInt::BigInt(value)) => visit_big_int(value, visitor),
+            OwnedValue::Float(value) => visitor.visit_f64(value),
+            OwnedValue::Decimal(value) => visitor.visit_f64(value.to_f64_lossy()),
+            OwnedValue::Timestamp(value) => {
+                visitor.visit_string(OwnedElement::from(value).to_string())
+            }
+            OwnedValue::String(value) => visitor.visit_string(value),
+            OwnedValue::Symbol(value) => visitor.visit_string(symbol_text(value)?),
+            OwnedValue::Blob(value) | OwnedValue::Clob(value) => visitor.visit_byte_buf(value),
+            OwnedValue::List(value) | OwnedValue::SExpression(value) => {
+                visit_sequence(value.into_iter(), visitor)
+            }
+            OwnedValue::Struct(value) => visit_struct(value, visitor),
+        }
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        if let OwnedValue::Decimal(value) = self.element.value() {
+            return visitor.visit_string(value.to_string());
+        }
+        self.deserialize_any(visitor)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        match self.element.into_parts() {
+            (_, OwnedValue::Blob(bytes)) | (_, OwnedValue::Clob(bytes)) => {
+                visit_sequence(bytes.into_iter(), visitor)
+            }
+            (annotations, value) => {
+                Deserializer::new(OwnedElement::new(annotations, value)).deserialize_any(visitor)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> IonResult<V::Value> {
+        if self.element.is_null() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        let (mut annotations, value) = self.element.into_parts();
+        if !annotations.is_empty() {
+            // The first annotation is the variant name; the rest belong to its content.
+            let variant = symbol_text(annotations.remove(0))?;
+            let content = Some(OwnedElement::new(annotations, value));
+            return visitor.visit_enum(EnumDeserializer { variant, content });
+        }
+        let variant = match value {
+            OwnedValue::Symbol(symbol) => symbol_text(symbol)?,
+            OwnedValue::String(text) => text,
+            other => {
+                return decoding_error(format!(
+                    "Expected a symbol or an annotated value for an enum variant, found {:?}",
+                    other
+                ))
+            }
+        };
+        visitor.visit_enum(EnumDeserializer {
+            variant,
+            content: None,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf unit
+        unit_struct tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+// Provides the variant name of an enum and, unless it was written as a symbol, its content.
+struct EnumDeserializer {
+    variant: String,
+    content: Option<OwnedElement>,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer {
+    type Error = IonError;
+    type Variant = VariantDeserializer;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> IonResult<(S::Value, VariantDeserializer)> {
+        let variant: StringDeserializer<IonError> = self.variant.into_deserializer();
+        let value = seed.deserialize(variant)?;
+        Ok((
+            value,
+            VariantDeserializer {
+                content: self.content,
+            },
+        ))
+    }
+}
+
+struct VariantDeserializer {
+    content: Option<OwnedElement>,
+}
+
+impl VariantDeserializer {
+    fn into_content(self) -> IonResult<Deserializer> {
+        match self.content {
+            Some(content) => Ok(Deserializer::new(content)),
+            None => decoding_error("Expected an annotated value for a non-unit enum variant"),
+        }
+    }
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer {
+    type Error = IonError;
+
+    fn unit_variant(self) -> IonResult<()> {
+        match self.content {
+            Some(content) if !content.is_null() => {
+                decoding_error(format!("Expected a unit enum variant, found {}", content))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> IonResult<S::Value> {
+        seed.deserialize(self.into_content()?)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> IonResult<V::Value> {
+        de::Deserializer::deserialize_seq(self.into_content()?, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> IonResult<V::Value> {
+        de::Deserializer::deserialize_map(self.into_content()?, visitor)
+    }
+}
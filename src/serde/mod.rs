@@ -0,0 +1,175 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Converts Rust data structures to and from Ion using [serde](https://serde.rs).
+//!
+//! Values are converted through the [`OwnedElement`](crate::value::owned::OwnedElement) DOM, so
+//! [`to_element`] and [`from_element`] are also available for callers that want to inspect or
+//! modify the data in between. The serde data model maps onto Ion as follows:
+//!
+//! * `bool`, integers, floats, strings and `char`s map to the Ion type of the same name.
+//!   Integers that do not fit in an `i64` are written as arbitrary-size Ion integers.
+//! * Byte arrays (e.g. via `serde_bytes`) are written as blobs. When deserializing, a blob or
+//!   clob can be read into byte arrays or into a `Vec<u8>`.
+//! * `None`, `()` and unit structs are written as `null`. Any Ion null deserializes as `None`.
+//! * Sequences and tuples are written as lists. Lists and s-expressions can be read as either.
+//! * Maps and structs are written as structs. Map keys must serialize as strings.
+//! * Enum variants are identified by their name: a unit variant is written as a symbol, and
+//!   every other variant is written as its content annotated with the variant's name, e.g.
+//!   `Circle::{radius: 1.5}`.
+//!
+//! Ion decimals deserialize as `f64`, or as their (lossless) Ion text when the target type
+//! expects a string. Ion timestamps deserialize as their Ion text, which is RFC 3339 compatible
+//! for timestamps with at least second precision, so they can be read into chrono's
+//! `DateTime<FixedOffset>`.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! # fn main() -> ion_rs::result::IonResult<()> {
+//! let point = Point { x: 1, y: -2 };
+//! let binary = ion_rs::serde::to_binary(&point)?;
+//! assert_eq!(point, ion_rs::serde::from_slice::<Point>(&binary)?);
+//!
+//! let text = ion_rs::serde::to_text(&point)?;
+//! assert_eq!(point, ion_rs::serde::from_slice::<Point>(text.as_bytes())?);
+//! # Ok(())
+//! # }
+//! ```
+
+mod de;
+mod ser;
+
+use std::fmt::Display;
+
+use crate::result::{decoding_error_raw, illegal_operation_raw, IonError};
+
+pub use de::{from_element, from_slice, Deserializer};
+pub use ser::{to_binary, to_element, to_text, Serializer};
+
+impl ::serde::ser::Error for IonError {
+    fn custom<T: Display>(msg: T) -> Self {
+        illegal_operation_raw(msg.to_string())
+    }
+}
+
+impl ::serde::de::Error for IonError {
+    fn custom<T: Display>(msg: T) -> Self {
+        decoding_error_raw(msg.to_string())
+    }
+}
+
+#[cfg(test)]
+mod serde_tests {
+    use super::*;
+    use crate::result::IonResult;
+    use ::serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Line(i64, i64),
+        Rectangle { width: u32, height: u32 },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Drawing {
+        name: String,
+        scale: Option<f32>,
+        layers: Vec<u8>,
+        shapes: Vec<Shape>,
+        labels: BTreeMap<String, char>,
+        big: u64,
+        unit: (),
+    }
+
+    fn drawing() -> Drawing {
+        let mut labels = BTreeMap::new();
+        labels.insert("first".to_string(), 'a');
+        labels.insert("needs quotes".to_string(), '\n');
+        Drawing {
+            name: "sketch".to_string(),
+            scale: None,
+            layers: vec![1, 2, 3],
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(1.5),
+                Shape::Line(-1, 1),
+                Shape::Rectangle {
+                    width: 3,
+                    height: 4,
+                },
+            ],
+            labels,
+            big: u64::MAX,
+            unit: (),
+        }
+    }
+
+    #[test]
+    fn round_trip_text() -> IonResult<()> {
+        let text = to_text(&drawing())?;
+        assert_eq!(drawing(), from_slice::<Drawing>(text.as_bytes())?);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_binary() -> IonResult<()> {
+        // u64::MAX is out of range for the binary writer
+        let drawing = Drawing {
+            big: 7,
+            ..drawing()
+        };
+        let binary = to_binary(&drawing)?;
+        assert_eq!(drawing, from_slice::<Drawing>(&binary)?);
+        Ok(())
+    }
+
+    #[test]
+    fn enum_encoding() -> IonResult<()> {
+        assert_eq!("'Empty'", to_text(&Shape::Empty)?);
+        assert_eq!("'Circle'::1.5e0", to_text(&Shape::Circle(1.5))?);
+        assert_eq!("'Line'::[1,2,]", to_text(&Shape::Line(1, 2))?);
+        assert_eq!(
+            Shape::Rectangle {
+                width: 3,
+                height: 4
+            },
+            from_slice::<Shape>(b"Rectangle::{height: 4, width: 3}")?
+        );
+        assert!(from_slice::<Shape>(b"Circle").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn ion_types_to_rust_types() -> IonResult<()> {
+        assert_eq!(1.25f64, from_slice::<f64>(b"1.25")?);
+        assert_eq!("1.250".to_string(), from_slice::<String>(b"1.250")?);
+        assert_eq!(b"hello".to_vec(), from_slice::<Vec<u8>>(b"{{aGVsbG8=}}")?);
+        assert_eq!(b"hi".to_vec(), from_slice::<Vec<u8>>(br#"{{"hi"}}"#)?);
+        assert_eq!(None, from_slice::<Option<i32>>(b"null.int")?);
+        assert_eq!(Some(5), from_slice::<Option<i32>>(b"5")?);
+        assert_eq!(vec![1, 2], from_slice::<Vec<i32>>(b"(1 2)")?);
+        let timestamp: String = from_slice(b"2021-02-03T04:05:06Z")?;
+        assert!(
+            timestamp.starts_with("2021-02-03T04:05:06"),
+            "{}",
+            timestamp
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn map_keys_must_be_strings() {
+        let mut map = BTreeMap::new();
+        map.insert(1, 2);
+        assert!(to_element(&map).is_err());
+    }
+}
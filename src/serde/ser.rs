@@ -0,0 +1,390 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+use std::convert::TryFrom;
+use std::iter::FromIterator;
+
+use ::serde::ser::{self, Serialize};
+use num_bigint::BigInt;
+
+use crate::binary::writer::BinarySystemWriter;
+use crate::result::{illegal_operation, IonError, IonResult};
+use crate::value::owned::{
+    text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedSymbolToken, OwnedValue,
+};
+use crate::value::writer::write_element_to_binary;
+use crate::value::Element;
+use crate::IonType;
+
+/// Converts `value` into an [`OwnedElement`].
+pub fn to_element<T: Serialize + ?Sized>(value: &T) -> IonResult<OwnedElement> {
+    value.serialize(Serializer)
+}
+
+/// Serializes `value` as a single top-level Ion text value.
+pub fn to_text<T: Serialize + ?Sized>(value: &T) -> IonResult<String> {
+    Ok(to_element(value)?.to_string())
+}
+
+/// Serializes `value` as a binary Ion stream containing a single top-level value.
+pub fn to_binary<T: Serialize + ?Sized>(value: &T) -> IonResult<Vec<u8>> {
+    let element = to_element(value)?;
+    let mut writer = BinarySystemWriter::new(Vec::new());
+    write_element_to_binary(&mut writer, &element)?;
+    writer.flush()?;
+    Ok(std::mem::take(writer.output_mut()))
+}
+
+// Adds the name of an enum variant as the first annotation of its content.
+fn annotate(element: OwnedElement, variant: Option<&'static str>) -> OwnedElement {
+    match variant {
+        Some(variant) => {
+            let (mut annotations, value) = element.into_parts();
+            annotations.insert(0, text_token(variant));
+            OwnedElement::new(annotations, value)
+        }
+        None => element,
+    }
+}
+
+/// A serde [`Serializer`](ser::Serializer) that produces an [`OwnedElement`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    type SerializeSeq = SequenceSerializer;
+    type SerializeTuple = SequenceSerializer;
+    type SerializeTupleStruct = SequenceSerializer;
+    type SerializeTupleVariant = SequenceSerializer;
+    type SerializeMap = StructSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructSerializer;
+
+    fn serialize_bool(self, v: bool) -> IonResult<OwnedElement> {
+        Ok(v.into())
+    }
+
+    fn serialize_i8(self, v: i8) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> IonResult<OwnedElement> {
+        Ok(v.into())
+    }
+
+    fn serialize_i128(self, v: i128) -> IonResult<OwnedElement> {
+        match i64::try_from(v) {
+            Ok(v) => self.serialize_i64(v),
+            Err(_) => Ok(BigInt::from(v).into()),
+        }
+    }
+
+    fn serialize_u8(self, v: u8) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> IonResult<OwnedElement> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> IonResult<OwnedElement> {
+        self.serialize_u128(v as u128)
+    }
+
+    fn serialize_u128(self, v: u128) -> IonResult<OwnedElement> {
+        match i64::try_from(v) {
+            Ok(v) => self.serialize_i64(v),
+            Err(_) => Ok(BigInt::from(v).into()),
+        }
+    }
+
+    fn serialize_f32(self, v: f32) -> IonResult<OwnedElement> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> IonResult<OwnedElement> {
+        Ok(v.into())
+    }
+
+    fn serialize_char(self, v: char) -> IonResult<OwnedElement> {
+        Ok(v.to_string().into())
+    }
+
+    fn serialize_str(self, v: &str) -> IonResult<OwnedElement> {
+        Ok(v.to_string().into())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> IonResult<OwnedElement> {
+        Ok(OwnedValue::Blob(v.to_vec()).into())
+    }
+
+    fn serialize_none(self) -> IonResult<OwnedElement> {
+        self.serialize_unit()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> IonResult<OwnedElement> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::null(IonType::Null))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> IonResult<OwnedElement> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> IonResult<OwnedElement> {
+        Ok(text_token(variant).into())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> IonResult<OwnedElement> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> IonResult<OwnedElement> {
+        Ok(annotate(value.serialize(self)?, Some(variant)))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> IonResult<SequenceSerializer> {
+        Ok(SequenceSerializer::new(None, len))
+    }
+
+    fn serialize_tuple(self, len: usize) -> IonResult<SequenceSerializer> {
+        Ok(SequenceSerializer::new(None, Some(len)))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> IonResult<SequenceSerializer> {
+        Ok(SequenceSerializer::new(None, Some(len)))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> IonResult<SequenceSerializer> {
+        Ok(SequenceSerializer::new(Some(variant), Some(len)))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> IonResult<StructSerializer> {
+        Ok(StructSerializer::new(None, len))
+    }
+
+    fn serialize_struct(self, _name: &'static str, len: usize) -> IonResult<StructSerializer> {
+        Ok(StructSerializer::new(None, Some(len)))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> IonResult<StructSerializer> {
+        Ok(StructSerializer::new(Some(variant), Some(len)))
+    }
+}
+
+/// Collects the elements of a sequence, tuple or tuple variant into an Ion list.
+pub struct SequenceSerializer {
+    variant: Option<&'static str>,
+    children: Vec<OwnedElement>,
+}
+
+impl SequenceSerializer {
+    fn new(variant: Option<&'static str>, len: Option<usize>) -> Self {
+        SequenceSerializer {
+            variant,
+            children: Vec::with_capacity(len.unwrap_or(0)),
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.children.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> IonResult<OwnedElement> {
+        let list = OwnedValue::List(OwnedSequence::new(self.children)).into();
+        Ok(annotate(list, self.variant))
+    }
+}
+
+impl ser::SerializeSeq for SequenceSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SequenceSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SequenceSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SequenceSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        self.push(value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+/// Collects the fields of a map, struct or struct variant into an Ion struct.
+pub struct StructSerializer {
+    variant: Option<&'static str>,
+    fields: Vec<(OwnedSymbolToken, OwnedElement)>,
+    // The key passed to the most recent call to `serialize_key`
+    pending_key: Option<OwnedSymbolToken>,
+}
+
+impl StructSerializer {
+    fn new(variant: Option<&'static str>, len: Option<usize>) -> Self {
+        StructSerializer {
+            variant,
+            fields: Vec::with_capacity(len.unwrap_or(0)),
+            pending_key: None,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, name: OwnedSymbolToken, value: &T) -> IonResult<()> {
+        self.fields.push((name, value.serialize(Serializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> IonResult<OwnedElement> {
+        let structure = OwnedStruct::from_iter(self.fields).into();
+        Ok(annotate(structure, self.variant))
+    }
+}
+
+impl ser::SerializeMap for StructSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> IonResult<()> {
+        let key = key.serialize(Serializer)?;
+        match key.ion_type() {
+            IonType::String | IonType::Symbol if !key.is_null() => {
+                self.pending_key = key.as_str().map(text_token);
+                Ok(())
+            }
+            ion_type => illegal_operation(format!(
+                "Struct field names must be strings, but a map key was a(n) {}",
+                ion_type
+            )),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> IonResult<()> {
+        match self.pending_key.take() {
+            Some(name) => self.push(name, value),
+            None => illegal_operation("`serialize_key()` must be called before each map value."),
+        }
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        self.push(text_token(key), value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for StructSerializer {
+    type Ok = OwnedElement;
+    type Error = IonError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> IonResult<()> {
+        self.push(text_token(key), value)
+    }
+
+    fn end(self) -> IonResult<OwnedElement> {
+        self.finish()
+    }
+}
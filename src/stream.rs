@@ -0,0 +1,219 @@
+//! Utilities for rearranging binary Ion streams without decoding the values in them.
+//!
+//! [split] divides a stream into chunks that can each be read on their own, which allows a large
+//! file to be processed in parallel, and [concatenate] joins streams back into one.
+
+use std::io;
+
+use crate::binary::constants::v1_0::IVM;
+use crate::binary::writer::BinarySystemWriter;
+use crate::catalog::{Catalog, MapCatalog};
+use crate::constants::v1_0::{self, system_symbol_ids};
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::symbol_table::SymbolTable;
+use crate::{IonType, RawBinaryReader, Reader};
+
+/// Splits the binary Ion stream in `data` into at most `num_chunks` independent streams of
+/// roughly equal size. Each chunk begins with a top-level value, and reading the chunks one
+/// after the other produces the same values as reading `data`.
+///
+/// The values in each chunk are copied verbatim, along with any system values between them.
+/// Each chunk is prefixed with an IVM and, if the stream had defined any symbols by the chunk's
+/// first value, a local symbol table declaring them, so a chunk can be read without the data
+/// that precedes it. Fewer than `num_chunks` chunks are returned if the stream contains fewer
+/// values than that, or if its values are too large to distribute evenly; if it contains no
+/// values at all, no chunks are returned.
+///
+/// Any shared symbol tables imported by the stream produce a decoding error; see
+/// [split_with_catalog] to read streams that import them.
+pub fn split(data: &[u8], num_chunks: usize) -> IonResult<Vec<Vec<u8>>> {
+    split_with_catalog(data, num_chunks, MapCatalog::new())
+}
+
+/// Like [split], but resolves shared symbol tables imported by the stream using `catalog`. The
+/// symbol tables that prefix the chunks declare the imported symbols themselves, so the chunks
+/// can be read without the catalog.
+pub fn split_with_catalog<C>(data: &[u8], num_chunks: usize, catalog: C) -> IonResult<Vec<Vec<u8>>>
+where
+    C: 'static + Catalog,
+{
+    if num_chunks == 0 {
+        return illegal_operation("Cannot split a stream into zero chunks.");
+    }
+
+    // The offset at which each chunk begins, along with its prefix.
+    let mut boundaries: Vec<(usize, Vec<u8>)> = Vec::with_capacity(num_chunks);
+    let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data)));
+    reader.set_catalog(catalog);
+    while reader.next()?.is_some() {
+        // The reader does not step into containers, so every value that it visits is at the top
+        // level.
+        let offset = reader.encoded_range().unwrap().start;
+        let target = boundaries.len() * data.len() / num_chunks;
+        if boundaries.len() < num_chunks && offset >= target {
+            boundaries.push((offset, encode_prefix(reader.symbol_table())?));
+        }
+    }
+
+    let ends = boundaries
+        .iter()
+        .skip(1)
+        .map(|(offset, _)| *offset)
+        .chain(std::iter::once(data.len()));
+    let chunks = boundaries
+        .iter()
+        .zip(ends)
+        .map(|((start, prefix), end)| {
+            let mut chunk = Vec::with_capacity(prefix.len() + end - start);
+            chunk.extend_from_slice(prefix);
+            chunk.extend_from_slice(&data[*start..end]);
+            chunk
+        })
+        .collect();
+    Ok(chunks)
+}
+
+// Encodes an IVM followed by a local symbol table declaring every symbol in `symbol_table` that
+// is not a system symbol.
+fn encode_prefix(symbol_table: &SymbolTable) -> IonResult<Vec<u8>> {
+    let mut prefix = Vec::new();
+    let mut writer = BinarySystemWriter::new(&mut prefix);
+    let local_symbols = symbol_table.symbols_tail(v1_0::SYSTEM_SYMBOLS.len());
+    if !local_symbols.is_empty() {
+        writer.set_annotation_ids(&[system_symbol_ids::ION_SYMBOL_TABLE]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_id(system_symbol_ids::SYMBOLS);
+        writer.step_in(IonType::List)?;
+        for text in local_symbols {
+            writer.write_string(text)?;
+        }
+        writer.step_out()?;
+        writer.step_out()?;
+    }
+    // The writer begins its output with an IVM.
+    writer.flush()?;
+    drop(writer);
+    Ok(prefix)
+}
+
+/// Joins binary Ion streams into a single stream containing each of their values in order.
+///
+/// Every non-empty stream must begin with an IVM, which is kept: an IVM resets the symbol table,
+/// so the symbols defined by one stream cannot change the meaning of the next. Empty streams are
+/// skipped, and if every stream is empty, the result consists of a lone IVM. The streams are
+/// otherwise copied verbatim; in particular, they are not checked for truncated values, which
+/// would corrupt the streams that follow them.
+pub fn concatenate<I, S>(streams: I) -> IonResult<Vec<u8>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<[u8]>,
+{
+    let mut output = Vec::new();
+    for (index, stream) in streams.into_iter().enumerate() {
+        let stream = stream.as_ref();
+        if stream.is_empty() {
+            continue;
+        }
+        if !stream.starts_with(&IVM) {
+            return decoding_error(format!(
+                "Stream {} does not begin with a binary Ion 1.0 version marker.",
+                index
+            ));
+        }
+        output.extend_from_slice(stream);
+    }
+    if output.is_empty() {
+        output.extend_from_slice(&IVM);
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+    use crate::binary::writer::BinaryWriter;
+    use crate::value::owned::OwnedElement;
+
+    // Writes `count` annotated structs of symbols, flushing every `flush_every` values so that
+    // the stream contains several symbol table appends.
+    fn write_stream(prefix: &str, count: usize, flush_every: usize) -> IonResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        let mut writer = BinaryWriter::new(&mut buffer);
+        for i in 0..count {
+            writer.set_annotations(&[format!("{}_{}", prefix, i % 7)]);
+            writer.write_struct(|s| {
+                s.set_field_name(format!("{}_field_{}", prefix, i % 3))
+                    .write_symbol(format!("{}_value_{}", prefix, i))?;
+                s.set_field_name("id").write_i64(i as i64)
+            })?;
+            if (i + 1) % flush_every == 0 {
+                writer.flush()?;
+            }
+        }
+        writer.flush()?;
+        drop(writer);
+        Ok(buffer)
+    }
+
+    fn read_elements(data: &[u8]) -> IonResult<Vec<OwnedElement>> {
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data)));
+        let mut elements = Vec::new();
+        while reader.next()?.is_some() {
+            elements.push(reader.read_element()?.unwrap());
+        }
+        Ok(elements)
+    }
+
+    #[test]
+    fn split_produces_independent_chunks() -> IonResult<()> {
+        let data = write_stream("a", 100, 9)?;
+        let expected = read_elements(&data)?;
+        for num_chunks in &[1, 3, 8, 100] {
+            let chunks = split(&data, *num_chunks)?;
+            // Values are not split, so there may not be a boundary close enough to every target
+            assert!(chunks.len() <= *num_chunks);
+            assert!(chunks.len() > *num_chunks / 2);
+            let mut actual = Vec::new();
+            for chunk in &chunks {
+                assert!(chunk.starts_with(&IVM));
+                let elements = read_elements(chunk)?;
+                assert!(!elements.is_empty());
+                actual.extend(elements);
+            }
+            assert_eq!(expected, actual);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn split_small_streams() -> IonResult<()> {
+        // There are fewer values than chunks
+        let data = write_stream("a", 2, 10)?;
+        let chunks = split(&data, 5)?;
+        assert_eq!(chunks.len(), 2);
+        // A stream without values has no chunks
+        assert!(split(&IVM, 5)?.is_empty());
+        assert!(split(&data, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn concatenate_streams() -> IonResult<()> {
+        let first = write_stream("a", 10, 4)?;
+        let second = write_stream("b", 10, 3)?;
+        let mut expected = read_elements(&first)?;
+        expected.extend(read_elements(&second)?);
+
+        let empty: &[u8] = &[];
+        let data = concatenate(&[first.as_slice(), empty, second.as_slice()])?;
+        assert_eq!(expected, read_elements(&data)?);
+
+        // Splitting and concatenating a stream preserves its values
+        let chunks = split(&data, 4)?;
+        assert_eq!(expected, read_elements(&concatenate(&chunks)?)?);
+
+        assert_eq!(concatenate(Vec::<Vec<u8>>::new())?, IVM.to_vec());
+        assert!(concatenate(&[&first[..], &second[4..]]).is_err());
+        Ok(())
+    }
+}
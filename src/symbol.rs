@@ -0,0 +1,225 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::raw_symbol_token::RawSymbolToken;
+use crate::types::SymbolId;
+use crate::value::owned::{local_sid_token, text_token, OwnedSymbolToken};
+use crate::value::SymbolToken;
+
+/// A symbol whose text has been resolved using the symbol table in effect where it appeared.
+///
+/// Unlike a [RawSymbolToken], a `Symbol` always carries its text when that text is known, and
+/// may also remember the local symbol ID that it was encoded as. The symbol ID is informational:
+/// two symbols are equal if they have the same text, however they were encoded. Symbols whose
+/// text is unknown (`$0`, or a symbol ID that the symbol table does not define) are all equal to
+/// one another and unequal to every symbol with text, as the Ion data model specifies.
+///
+/// ```
+/// use ion_rs::Symbol;
+///
+/// assert_eq!(Symbol::from("foo"), Symbol::with_text_and_sid("foo", 10));
+/// assert_eq!(Symbol::unknown_text(), Symbol::with_unknown_text(99));
+/// assert_ne!(Symbol::from("$0"), Symbol::unknown_text());
+/// assert_eq!(Symbol::from("foo bar").to_string(), "'foo bar'");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Symbol {
+    text: Option<Arc<str>>,
+    local_sid: Option<SymbolId>,
+}
+
+impl Symbol {
+    /// Creates a symbol with the provided text and no symbol ID.
+    pub fn with_text<T: Into<Arc<str>>>(text: T) -> Symbol {
+        Symbol {
+            text: Some(text.into()),
+            local_sid: None,
+        }
+    }
+
+    /// Creates a symbol with the provided text that was encoded as the local symbol ID `sid`.
+    pub fn with_text_and_sid<T: Into<Arc<str>>>(text: T, sid: SymbolId) -> Symbol {
+        Symbol {
+            text: Some(text.into()),
+            local_sid: Some(sid),
+        }
+    }
+
+    /// Creates a symbol with unknown text that was encoded as the local symbol ID `sid`.
+    pub fn with_unknown_text(sid: SymbolId) -> Symbol {
+        Symbol {
+            text: None,
+            local_sid: Some(sid),
+        }
+    }
+
+    /// Returns the symbol `$0`, which never has text.
+    pub fn unknown_text() -> Symbol {
+        Symbol::with_unknown_text(0)
+    }
+
+    /// Returns the symbol's text, or `None` if it is unknown.
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+
+    /// Returns the local symbol ID that the symbol was encoded as, if any. Symbols read from
+    /// text Ion and symbols created from text do not have one.
+    pub fn local_sid(&self) -> Option<SymbolId> {
+        self.local_sid
+    }
+
+    /// Returns true if the symbol's text is unknown.
+    pub fn is_unknown_text(&self) -> bool {
+        self.text.is_none()
+    }
+
+    /// Returns the token to write for this symbol: its text if known, and otherwise its symbol
+    /// ID, or `$0` if it does not have one.
+    pub(crate) fn to_raw_token(&self) -> RawSymbolToken {
+        match (&self.text, self.local_sid) {
+            (Some(text), _) => RawSymbolToken::Text(text.to_string()),
+            (None, Some(sid)) => RawSymbolToken::SymbolId(sid),
+            (None, None) => RawSymbolToken::SymbolId(0),
+        }
+    }
+}
+
+impl PartialEq for Symbol {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for Symbol {}
+
+impl Hash for Symbol {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.text.hash(state)
+    }
+}
+
+/// Symbols with unknown text sort before all others; the rest are ordered by their text.
+impl PartialOrd for Symbol {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Symbol {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.text.cmp(&other.text)
+    }
+}
+
+/// Formats the symbol as an Ion symbol, quoting its text if necessary. A symbol with unknown
+/// text is written as its symbol ID (`$10`), or as `$0` if it does not have one.
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_raw_token(), f)
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(text: &str) -> Self {
+        Symbol::with_text(text)
+    }
+}
+
+impl From<String> for Symbol {
+    fn from(text: String) -> Self {
+        Symbol::with_text(text)
+    }
+}
+
+impl From<&OwnedSymbolToken> for Symbol {
+    fn from(token: &OwnedSymbolToken) -> Self {
+        Symbol {
+            text: token.text().map(Arc::from),
+            local_sid: token.local_sid(),
+        }
+    }
+}
+
+impl From<OwnedSymbolToken> for Symbol {
+    fn from(token: OwnedSymbolToken) -> Self {
+        Symbol::from(&token)
+    }
+}
+
+impl From<Symbol> for OwnedSymbolToken {
+    fn from(symbol: Symbol) -> Self {
+        match (symbol.text, symbol.local_sid) {
+            (Some(text), Some(sid)) => text_token(text).with_local_sid(sid),
+            (Some(text), None) => text_token(text),
+            (None, sid) => local_sid_token(sid.unwrap_or(0)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod symbol_tests {
+    use super::*;
+    use rstest::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn equality_ignores_symbol_ids() {
+        let symbols: HashSet<Symbol> = vec![
+            Symbol::from("foo"),
+            Symbol::with_text_and_sid("foo", 10),
+            Symbol::with_text_and_sid("bar", 10),
+            Symbol::unknown_text(),
+            Symbol::with_unknown_text(10),
+            Symbol::with_unknown_text(99),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(symbols.len(), 3);
+        assert!(symbols.contains(&Symbol::from("foo")));
+        assert!(symbols.contains(&Symbol::from("bar")));
+        assert!(symbols.contains(&Symbol::unknown_text()));
+        assert!(!symbols.contains(&Symbol::from("$0")));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut symbols = vec![
+            Symbol::from("b"),
+            Symbol::with_unknown_text(5),
+            Symbol::from("a"),
+        ];
+        symbols.sort();
+        assert_eq!(
+            symbols,
+            vec![Symbol::unknown_text(), Symbol::from("a"), Symbol::from("b")]
+        );
+    }
+
+    #[rstest]
+    #[case(Symbol::from("foo"), "foo")]
+    #[case(Symbol::with_text_and_sid("foo bar", 10), "'foo bar'")]
+    #[case(Symbol::from("$10"), "'$10'")]
+    #[case(Symbol::with_unknown_text(10), "$10")]
+    #[case(Symbol::unknown_text(), "$0")]
+    fn display(#[case] symbol: Symbol, #[case] expected: &str) {
+        assert_eq!(symbol.to_string(), expected);
+    }
+
+    #[test]
+    fn owned_symbol_token_round_trip() {
+        let token: OwnedSymbolToken = Symbol::with_text_and_sid("foo", 10).into();
+        assert_eq!(token.text(), Some("foo"));
+        assert_eq!(token.local_sid(), Some(10));
+        let symbol = Symbol::from(&token);
+        assert_eq!(symbol.text(), Some("foo"));
+        assert_eq!(symbol.local_sid(), Some(10));
+
+        let token: OwnedSymbolToken = Symbol::with_unknown_text(99).into();
+        assert_eq!(token.text(), None);
+        assert_eq!(token.local_sid(), Some(99));
+        assert!(Symbol::from(token).is_unknown_text());
+    }
+}
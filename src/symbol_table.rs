@@ -4,6 +4,13 @@ use crate::constants::v1_0;
 use crate::types::SymbolId;
 
 /// Stores mappings from Symbol IDs to text and vice-versa.
+///
+/// Every table begins with the Ion 1.0 system symbols (`$ion`, `$ion_1_0`, `$ion_symbol_table`,
+/// `name`, `version`, `imports`, `symbols`, `max_id` and `$ion_shared_symbol_table`), which
+/// occupy symbol IDs 1 through 9; their IDs are also available as constants in
+/// [system_symbol_ids](crate::constants::v1_0::system_symbol_ids). The same type is used by the
+/// [Reader](crate::Reader) to resolve symbol IDs and by the binary writer to assign them.
+#[derive(Clone, Debug)]
 pub struct SymbolTable {
     symbols_by_id: Vec<String>,
     ids_by_text: HashMap<String, SymbolId>,
@@ -28,10 +35,13 @@ impl SymbolTable {
         }
     }
 
+    /// Removes every symbol that is not a system symbol. The system symbols are always the first
+    /// entries in the table, so they are kept rather than interned again, and the table's storage
+    /// is retained; resetting a table does not allocate.
     pub fn reset(&mut self) {
-        self.symbols_by_id.clear();
-        self.ids_by_text.clear();
-        self.initialize();
+        let num_system_symbols = v1_0::SYSTEM_SYMBOLS.len();
+        self.symbols_by_id.truncate(num_system_symbols);
+        self.ids_by_text.retain(|_, id| *id < num_system_symbols);
     }
 
     pub fn intern(&mut self, text: String) -> SymbolId {
@@ -47,16 +57,42 @@ impl SymbolTable {
         id
     }
 
+    /// Adds `text` to the end of the table and returns its new symbol ID, even if the text is
+    /// already defined. This is how symbols are assigned IDs when a symbol table is read: when
+    /// text appears more than once, each occurrence has its own ID, and looking the text up
+    /// returns the lowest of them.
+    pub(crate) fn add_symbol(&mut self, text: String) -> SymbolId {
+        let id = self.symbols_by_id.len();
+        self.symbols_by_id.push(text.clone());
+        self.ids_by_text.entry(text).or_insert(id);
+        id
+    }
+
     /// If defined, returns the Symbol ID associated with the provided text.
     pub fn sid_for<A: AsRef<str>>(&self, text: &A) -> Option<SymbolId> {
         self.ids_by_text.get(text.as_ref()).copied()
     }
 
     /// If defined, returns the text associated with the provided Symbol ID.
-    pub fn text_for(&self, sid: usize) -> Option<&str> {
+    pub fn text_for(&self, sid: SymbolId) -> Option<&str> {
         self.symbols_by_id.get(sid).map(|text| text.as_str())
     }
 
+    /// Returns true if `sid` is one of the system symbols that every symbol table begins with.
+    pub fn is_system_symbol(&self, sid: SymbolId) -> bool {
+        sid > 0 && sid < v1_0::SYSTEM_SYMBOLS.len()
+    }
+
+    /// Returns an iterator over the symbol IDs defined in the table and their text, in ascending
+    /// order of symbol ID. Symbol ID 0, which has no text, is not included.
+    pub fn iter(&self) -> impl Iterator<Item = (SymbolId, &str)> {
+        self.symbols_by_id
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(sid, text)| (sid, text.as_str()))
+    }
+
     // Returns a slice of references to the symbol text stored in the table.
     pub fn symbols(&self) -> &[String] {
         &self.symbols_by_id
@@ -73,4 +109,59 @@ impl SymbolTable {
     pub fn len(&self) -> usize {
         self.symbols_by_id.len()
     }
+
+    /// Returns the highest symbol ID defined in the table.
+    pub fn max_id(&self) -> SymbolId {
+        self.symbols_by_id.len() - 1
+    }
+}
+
+impl Default for SymbolTable {
+    fn default() -> Self {
+        SymbolTable::new()
+    }
+}
+
+#[cfg(test)]
+mod symbol_table_tests {
+    use super::*;
+    use crate::constants::v1_0::system_symbol_ids;
+
+    #[test]
+    fn system_symbols() {
+        let table = SymbolTable::new();
+        assert_eq!(table.max_id(), system_symbol_ids::ION_SHARED_SYMBOL_TABLE);
+        assert_eq!(table.text_for(system_symbol_ids::ION), Some("$ion"));
+        assert_eq!(table.text_for(system_symbol_ids::MAX_ID), Some("max_id"));
+        assert_eq!(
+            table.sid_for(&"$ion_symbol_table"),
+            Some(system_symbol_ids::ION_SYMBOL_TABLE)
+        );
+        assert!(table.is_system_symbol(system_symbol_ids::IMPORTS));
+        assert!(!table.is_system_symbol(0));
+        assert!(!table.is_system_symbol(10));
+    }
+
+    #[test]
+    fn intern_and_iterate() {
+        let mut table = SymbolTable::new();
+        let foo = table.intern("foo".to_string());
+        assert_eq!(foo, 10);
+        assert_eq!(table.intern("foo".to_string()), foo);
+        assert_eq!(table.intern("name".to_string()), system_symbol_ids::NAME);
+        assert_eq!(table.text_for(foo), Some("foo"));
+        assert_eq!(table.sid_for(&"foo"), Some(foo));
+        assert_eq!(table.sid_for(&"bar"), None);
+
+        let symbols: Vec<(SymbolId, &str)> = table.iter().collect();
+        assert_eq!(symbols.len(), 10);
+        assert_eq!(symbols[0], (1, "$ion"));
+        assert_eq!(symbols[9], (foo, "foo"));
+
+        table.reset();
+        assert_eq!(table.sid_for(&"foo"), None);
+        assert_eq!(table.iter().count(), 9);
+        assert_eq!(table.sid_for(&"name"), Some(system_symbol_ids::NAME));
+        assert_eq!(table.intern("bar".to_string()), 10);
+    }
 }
@@ -0,0 +1,283 @@
+//! Helpers for testing code that reads or writes Ion, enabled by the `test-support` feature.
+//!
+//! The [ion!](crate::ion), [ion_list!](crate::ion_list), [ion_sexp!](crate::ion_sexp) and
+//! [ion_struct!](crate::ion_struct) macros construct the [OwnedElement]s that a test expects,
+//! and the assertions in this module compare Ion data by [Ion equivalence](crate::value::Element::ion_eq)
+//! rather than byte for byte, so that a test does not depend on details like the order in which
+//! a writer assigns symbol IDs or whether a decimal is written as text or binary:
+//!
+//! ```
+//! use ion_rs::binary::writer::BinaryWriter;
+//! use ion_rs::test_support::assert_ion_eq;
+//! use ion_rs::{ion, ion_list, ion_struct};
+//! # fn main() -> ion_rs::result::IonResult<()> {
+//!
+//! let expected = ion!("widget::{name: \"gear\", sizes: [1, 2]}");
+//! assert_eq!(
+//!     expected,
+//!     ion_struct! {"name" => "gear", "sizes" => ion_list![1, 2]}.with_annotations(["widget"])
+//! );
+//!
+//! let mut output = Vec::new();
+//! let mut writer = BinaryWriter::new(&mut output);
+//! writer.write_element(&expected)?;
+//! writer.flush()?;
+//! drop(writer);
+//! assert_ion_eq(&output, b"widget::{sizes: [1, 2], name: \"gear\"}");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [assert_golden_file] compares data with the contents of a file in the same way. Setting the
+//! `ION_UPDATE_GOLDEN_FILES` environment variable when running the tests writes the actual data
+//! to each golden file instead, which is how they are created and updated.
+
+use std::fs;
+use std::path::Path;
+
+use crate::binary::writer::BinaryWriter;
+use crate::result::IonResult;
+use crate::text::writer::{TextFormat, TextWriterBuilder};
+use crate::value::owned::OwnedElement;
+use crate::value::streams_ion_eq;
+use crate::value::writer::write_element_to_text;
+
+/// The environment variable that makes [assert_golden_file] write golden files instead of
+/// comparing against them.
+pub const UPDATE_GOLDEN_FILES_VAR: &str = "ION_UPDATE_GOLDEN_FILES";
+
+/// Constructs an [OwnedElement] from Ion text. For example, `ion!("a::{b: [1, 2]}")`.
+///
+/// Panics if the text is not a single Ion value.
+#[macro_export]
+macro_rules! ion {
+    ($text:expr) => {
+        $crate::test_support::parse_element($text)
+    };
+}
+
+/// Constructs a list [OwnedElement] from values that can be converted into elements. For
+/// example, `ion_list![1, "two", ion!("three")]`.
+#[macro_export]
+macro_rules! ion_list {
+    ($($element:expr),* $(,)?) => {
+        $crate::value::owned::list_builder()$(.push($element))*.build()
+    };
+}
+
+/// Like [ion_list!](crate::ion_list), but constructs an s-expression.
+#[macro_export]
+macro_rules! ion_sexp {
+    ($($element:expr),* $(,)?) => {
+        $crate::value::owned::sexp_builder()$(.push($element))*.build()
+    };
+}
+
+/// Constructs a struct [OwnedElement] from `name => value` pairs. For example,
+/// `ion_struct! {"id" => 7, "tags" => ion_list!["a", "b"]}`. Fields keep the order in which they
+/// are given, and repeated names are kept as separate fields.
+#[macro_export]
+macro_rules! ion_struct {
+    ($($name:expr => $value:expr),* $(,)?) => {
+        $crate::value::owned::struct_builder()$(.field($name, $value))*.build()
+    };
+}
+
+/// Parses `text` as a single Ion value. This is what [ion!](crate::ion) expands to.
+///
+/// Panics if the text cannot be parsed or holds anything other than exactly one value.
+pub fn parse_element(text: &str) -> OwnedElement {
+    let mut elements = OwnedElement::read_all(text.as_bytes())
+        .unwrap_or_else(|error| panic!("invalid Ion text {:?}: {}", text, error));
+    if elements.len() != 1 {
+        panic!(
+            "expected a single Ion value, found {}: {:?}",
+            elements.len(),
+            text
+        );
+    }
+    elements.remove(0)
+}
+
+/// Encodes the Ion values in `text` as a binary Ion stream, for tests that read binary Ion but
+/// are easier to write with text.
+///
+/// Panics if the text cannot be parsed or encoded.
+pub fn binary_ion(text: &str) -> Vec<u8> {
+    let elements = OwnedElement::read_all(text.as_bytes())
+        .unwrap_or_else(|error| panic!("invalid Ion text {:?}: {}", text, error));
+    to_binary(&elements)
+        .unwrap_or_else(|error| panic!("could not encode {:?} as binary Ion: {}", text, error))
+}
+
+/// Asserts that `actual` and `expected`, each of which may be either text or binary Ion, hold
+/// equivalent streams of values. When they do not, the panic message shows both streams as
+/// pretty-printed text.
+#[track_caller]
+pub fn assert_ion_eq(actual: &[u8], expected: &[u8]) {
+    let actual = read_stream(actual, "actual");
+    let expected = read_stream(expected, "expected");
+    if !streams_ion_eq(&actual, &expected) {
+        panic!(
+            "Ion streams are not equivalent\n--- actual ---\n{}--- expected ---\n{}",
+            to_pretty_text(&actual),
+            to_pretty_text(&expected)
+        );
+    }
+}
+
+/// Asserts that `actual`, which may be either text or binary Ion, is equivalent to the contents
+/// of the golden file at `path`, as [assert_ion_eq] does.
+///
+/// If the [UPDATE_GOLDEN_FILES_VAR] environment variable is set, `actual` is written to the file
+/// instead (replacing it if it exists), as binary Ion if the file's extension is `10n` and as
+/// pretty-printed text Ion otherwise.
+#[track_caller]
+pub fn assert_golden_file<P: AsRef<Path>>(actual: &[u8], path: P) {
+    let update = std::env::var_os(UPDATE_GOLDEN_FILES_VAR).is_some();
+    check_golden_file(actual, path.as_ref(), update);
+}
+
+#[track_caller]
+fn check_golden_file(actual: &[u8], path: &Path, update: bool) {
+    if update {
+        let elements = read_stream(actual, "actual");
+        if let Err(error) = write_golden_file(&elements, path) {
+            panic!("could not write golden file {}: {}", path.display(), error);
+        }
+        return;
+    }
+    let expected = fs::read(path).unwrap_or_else(|error| {
+        panic!(
+            "could not read golden file {} ({}); set {} to create it",
+            path.display(),
+            error,
+            UPDATE_GOLDEN_FILES_VAR
+        )
+    });
+    assert_ion_eq(actual, &expected);
+}
+
+fn write_golden_file(elements: &[OwnedElement], path: &Path) -> IonResult<()> {
+    let is_binary = path
+        .extension()
+        .map_or(false, |extension| extension == "10n");
+    let data = if is_binary {
+        to_binary(elements)?
+    } else {
+        to_pretty_text(elements).into_bytes()
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, data)?;
+    Ok(())
+}
+
+fn to_binary(elements: &[OwnedElement]) -> IonResult<Vec<u8>> {
+    let mut data = Vec::new();
+    let mut writer = BinaryWriter::new(&mut data);
+    for element in elements {
+        writer.write_element(element)?;
+    }
+    writer.flush()?;
+    drop(writer);
+    Ok(data)
+}
+
+#[track_caller]
+fn read_stream(data: &[u8], description: &str) -> Vec<OwnedElement> {
+    OwnedElement::read_all(data)
+        .unwrap_or_else(|error| panic!("the {} data is not valid Ion: {}", description, error))
+}
+
+// Elements that cannot be written as text (such as those with annotations that have no text) are
+// shown using their Debug representation.
+fn to_pretty_text(elements: &[OwnedElement]) -> String {
+    let mut text = String::new();
+    for element in elements {
+        let mut writer = TextWriterBuilder::new()
+            .format(TextFormat::Pretty)
+            .build(Vec::new());
+        let written = write_element_to_text(&mut writer, element).and_then(|_| writer.flush());
+        match written {
+            Ok(()) => text.push_str(&String::from_utf8_lossy(writer.output())),
+            Err(_) => text.push_str(&format!("{:?}\n", element)),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod test_support_tests {
+    use super::*;
+    use crate::binary::constants::v1_0::IVM;
+    use crate::types::IonType;
+    use crate::{ion, ion_list, ion_sexp, ion_struct};
+
+    #[test]
+    fn macros_build_elements() {
+        assert_eq!(ion!("null.struct"), OwnedElement::null(IonType::Struct));
+        assert_eq!(ion_list![1, "two", true], ion!("[1, \"two\", true]"));
+        assert_eq!(ion_list![], ion!("[]"));
+        assert_eq!(ion_sexp![ion!("'+'"), 1, 2], ion!("(+ 1 2)"));
+        assert_eq!(
+            ion_struct! {"a" => 1, "b" => ion_list![2.5f64], "a" => "x",},
+            ion!("{a: 1, b: [2.5e0], a: \"x\"}")
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a single Ion value, found 2")]
+    fn ion_macro_rejects_multiple_values() {
+        ion!("1 2");
+    }
+
+    #[test]
+    fn ion_eq_ignores_encoding_details() {
+        let mut binary = Vec::new();
+        let mut writer = BinaryWriter::new(&mut binary);
+        writer.write_element(&ion!("{a: 2.0, b: nan}")).unwrap();
+        writer.flush().unwrap();
+        drop(writer);
+        // Fields in a different order, a decimal with the same precision and text field names
+        assert_ion_eq(&binary, b"{'b': nan, a: 20d-1}");
+    }
+
+    #[test]
+    fn binary_ion_encodes_text() {
+        let binary = binary_ion("a::{b: [c, 1.5]} null.sexp");
+        assert!(binary.starts_with(&IVM));
+        assert_eq!(
+            OwnedElement::read_all(&binary).unwrap(),
+            vec![ion!("a::{b: [c, 1.5]}"), ion!("null.sexp")]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Ion streams are not equivalent")]
+    fn ion_eq_detects_differences() {
+        // A decimal's precision is significant
+        assert_ion_eq(b"{a: 2.0}", b"{a: 2.00}");
+    }
+
+    #[test]
+    fn golden_files_round_trip() {
+        let directory = std::env::temp_dir().join(format!("ion-golden-{}", std::process::id()));
+        let actual = b"point::{x: 1, y: [a, \"b\"]}";
+        for name in &["golden.ion", "golden.10n"] {
+            let path = directory.join(name);
+            check_golden_file(actual, &path, true);
+            check_golden_file(actual, &path, false);
+            let is_binary = fs::read(&path).unwrap().starts_with(&IVM);
+            assert_eq!(is_binary, *name == "golden.10n");
+        }
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "set ION_UPDATE_GOLDEN_FILES to create it")]
+    fn missing_golden_file() {
+        check_golden_file(b"1", Path::new("does/not/exist.ion"), false);
+    }
+}
@@ -1,7 +1,7 @@
 mod parent_container;
-pub(in crate::text) mod parsers;
+pub(crate) mod parsers;
 pub mod raw_text_reader;
 mod text_buffer;
 mod text_data_source;
-mod text_value;
+pub(crate) mod text_value;
 pub mod writer;
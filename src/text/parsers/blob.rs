@@ -21,15 +21,27 @@ pub(crate) fn parse_blob(input: &str) -> IResult<&str, TextValue> {
             recognize_base64_data,
             pair(opt(whitespace), tag("}}")),
         ),
-        |base64_text| Ok(TextValue::Blob(base64::decode(base64_text)?)),
+        |base64_text| {
+            // Whitespace may appear anywhere in the base64 text (for example, to wrap a long blob
+            // across several lines), but the decoder does not accept it.
+            if base64_text.contains(is_whitespace) {
+                let base64_text: String = base64_text.split(is_whitespace).collect();
+                return Ok(TextValue::Blob(base64::decode(base64_text)?));
+            }
+            Ok(TextValue::Blob(base64::decode(base64_text)?))
+        },
     )(input)
 }
 
-/// Matches a series of valid base64-encoded characters. (This function does not attempt to decode
-/// the matched value.)
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\r' | '\n')
+}
+
+/// Matches a series of valid base64-encoded characters, which may be separated by whitespace.
+/// (This function does not attempt to decode the matched value.)
 fn recognize_base64_data(input: &str) -> IResult<&str, &str> {
     recognize(pair(
-        many0_count(alt((alphanumeric1, is_a("+/")))),
+        many0_count(alt((alphanumeric1, is_a("+/"), whitespace))),
         pair(opt(char('=')), opt(char('='))),
     ))(input)
 }
@@ -69,6 +81,12 @@ mod blob_parsing_tests {
         parse_equals("{{   aGVsbG8h}} ", "hello!");
         parse_equals("{{aGVsbG8h   }} ", "hello!");
         parse_equals("{{   aGVsbG8h   }} ", "hello!");
+        // Whitespace between the base64 characters
+        parse_equals("{{aGVs\n  bG8h}} ", "hello!");
+        parse_equals(
+            "{{\n  Zm9vLCBi\n  YXIsIGJheiwgcXV1eA==\n}} ",
+            "foo, bar, baz, quux",
+        );
 
         // Delimiting {{ has spaces in it
         parse_fails("{ {Zm9vLCBiYXIsIGJheiwgcXV1eA==}} ");
@@ -72,7 +72,7 @@ fn long_clob_body(input: &str) -> IResult<&str, Vec<u8>> {
 }
 
 /// Matches an escaped character or a substring without any escapes in a long clob.
-fn long_clob_fragment(input: &str) -> IResult<&str, StringFragment> {
+fn long_clob_fragment(input: &str) -> IResult<&str, StringFragment<'_>> {
     alt((
         escaped_newline,
         escaped_char,
@@ -81,7 +81,7 @@ fn long_clob_fragment(input: &str) -> IResult<&str, StringFragment> {
 }
 
 /// Matches the next string fragment while respecting the long clob delimiter (`'''`).
-fn long_clob_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment> {
+fn long_clob_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment<'_>> {
     map(verify(take_until("'''"), |s: &str| !s.is_empty()), |text| {
         StringFragment::Substring(text)
     })(input)
@@ -104,7 +104,7 @@ fn short_clob_body(input: &str) -> IResult<&str, Vec<u8>> {
 }
 
 /// Matches an escaped character or a substring without any escapes in a short clob.
-fn short_clob_fragment(input: &str) -> IResult<&str, StringFragment> {
+fn short_clob_fragment(input: &str) -> IResult<&str, StringFragment<'_>> {
     alt((
         escaped_newline,
         escaped_char,
@@ -113,7 +113,7 @@ fn short_clob_fragment(input: &str) -> IResult<&str, StringFragment> {
 }
 
 /// Matches the next string fragment while respecting the short clob delimiter (`"`).
-fn short_clob_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment> {
+fn short_clob_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment<'_>> {
     map(verify(is_not("\"\\\""), |s: &str| !s.is_empty()), |text| {
         StringFragment::Substring(text)
     })(input)
@@ -1,6 +1,6 @@
+use crate::text::parsers::scanning::{multiline_comment_text, rest_of_line_text, whitespace_run};
 use nom::branch::alt;
-use nom::bytes::streaming::{is_not, tag, take_until};
-use nom::character::streaming::multispace1;
+use nom::bytes::streaming::tag;
 use nom::combinator::recognize;
 use nom::multi::many0_count;
 use nom::sequence::{delimited, preceded};
@@ -11,7 +11,7 @@ use nom::IResult;
 pub(crate) fn whitespace_or_comments(input: &str) -> IResult<&str, &str> {
     recognize(many0_count(alt((
         // At least one character of whitespace...
-        multispace1,
+        whitespace_run,
         // ...or a comment of any format.
         comment,
     ))))(input)
@@ -29,7 +29,7 @@ fn rest_of_line_comment(input: &str) -> IResult<&str, &str> {
         // Matches a leading "//"...
         tag("//"),
         // ...followed by any characters that are not a '\r' or '\n'.
-        is_not("\r\n"), // Note that this will not consume the newline.
+        rest_of_line_text, // Note that this will not consume the newline.
     )(input)
 }
 
@@ -40,7 +40,7 @@ fn multiline_comment(input: &str) -> IResult<&str, &str> {
         // Matches a leading "/*"
         tag("/*"),
         // Any number of non-"*/" characters
-        take_until("*/"),
+        multiline_comment_text,
         // and then a closing "*/"
         tag("*/"),
     )(input)
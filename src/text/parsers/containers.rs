@@ -2,14 +2,14 @@ use crate::raw_symbol_token::RawSymbolToken;
 use nom::branch::alt;
 use nom::bytes::streaming::tag;
 use nom::character::streaming::one_of;
-use nom::combinator::{map, peek, recognize, value};
+use nom::combinator::{map, opt, peek, recognize, value};
 use nom::sequence::{delimited, pair, preceded, terminated};
 use nom::{IResult, Parser};
 
 use crate::text::parsers::annotations::parse_annotations;
 use crate::text::parsers::comments::whitespace_or_comments;
 use crate::text::parsers::string::parse_string;
-use crate::text::parsers::symbol::{parse_operator, parse_symbol};
+use crate::text::parsers::symbol::{parse_operator, parse_symbol, OPERATOR_CHARACTERS};
 use crate::text::parsers::top_level::top_level_value;
 use crate::text::parsers::value::{annotated_container_start, annotated_scalar};
 use crate::text::text_value::{AnnotatedTextValue, TextValue};
@@ -109,28 +109,42 @@ pub(crate) fn s_expression_value(input: &str) -> IResult<&str, AnnotatedTextValu
 }
 
 /// Matches a (possibly annotated) non-container value in an s-expression followed by a delimiter
-/// or end-of-container.
+/// or end-of-container. Operators and the values next to them need no delimiter between them; for
+/// example, `(a==b)` is equivalent to `(a == b)`.
 pub(crate) fn s_expression_scalar(input: &str) -> IResult<&str, AnnotatedTextValue> {
-    delimited(
+    preceded(
         whitespace_or_comments,
         // An s-expression value can be either...
         alt((
             // ...an annotated operator (`foo::++`)...
-            pair(parse_annotations, parse_operator)
-                .map(|(annotations, value)| AnnotatedTextValue::new(annotations, value)),
+            terminated(
+                pair(parse_annotations, parse_operator),
+                opt(s_expression_whitespace),
+            )
+            .map(|(annotations, value)| AnnotatedTextValue::new(annotations, value)),
             // ...an un-annotated operator (`++`) paired with an empty annotations Vec...
-            parse_operator.map(|op| op.without_annotations()),
+            terminated(parse_operator, opt(s_expression_whitespace))
+                .map(|op| op.without_annotations()),
             // ...or some other kind of value (`5`, `"hello"`, etc).
-            top_level_value,
-        )),
-        // Check for a whitespace character or an end-of-s-expression delimiter.
-        alt((
-            recognize(one_of(" \t\r\n")),
-            peek(recognize(s_expression_end)),
+            terminated(
+                top_level_value,
+                // Check for a whitespace character, an end-of-s-expression delimiter or the
+                // start of an operator.
+                alt((
+                    s_expression_whitespace,
+                    peek(recognize(s_expression_end)),
+                    peek(recognize(one_of(OPERATOR_CHARACTERS))),
+                )),
+            ),
         )),
     )(input)
 }
 
+// Matches a single whitespace character, which separates values in an s-expression.
+fn s_expression_whitespace(input: &str) -> IResult<&str, &str> {
+    recognize(one_of(" \t\r\n"))(input)
+}
+
 /// Returns [None] if the next token in input is an end-of-s-expression delimiter (`)`).
 /// Otherwise, matches and returns the next value in the s-expression using [s_expression_stream_value].
 pub(crate) fn s_expression_value_or_end(input: &str) -> IResult<&str, Option<AnnotatedTextValue>> {
@@ -141,7 +155,7 @@ pub(crate) fn s_expression_value_or_end(input: &str) -> IResult<&str, Option<Ann
 
 /// Matches a whitespace character (which is consumed) or an end-of-container (which is not consumed).
 pub(crate) fn s_expression_delimiter(input: &str) -> IResult<&str, ()> {
-    alt((recognize(one_of(" \t\r\n")), peek(list_end)))
+    alt((recognize(one_of(" \t\r\n")), peek(s_expression_end)))
         // TODO: This parser discards the matched &str as a workaround to a limitation in RawTextReader.
         //       See: https://github.com/amzn/ion-rust/issues/337
         .map(|_| ())
@@ -281,6 +295,11 @@ mod container_parsing_tests {
     #[case("foo::bar 0", TextValue::Symbol(text_token("bar")).with_annotations("foo"))]
     #[case("bar)", TextValue::Symbol(text_token("bar")).without_annotations())]
     #[case("7.)", TextValue::Decimal(Decimal::new(7, 0)).without_annotations())]
+    #[case("==b)", TextValue::Symbol(text_token("==")).without_annotations())]
+    #[case("a==b)", TextValue::Symbol(text_token("a")).without_annotations())]
+    #[case("'a b'.c)", TextValue::Symbol(text_token("a b")).without_annotations())]
+    #[case("\"a\"+)", TextValue::String(String::from("a")).without_annotations())]
+    #[case("-1)", TextValue::Integer(-1).without_annotations())]
     #[should_panic]
     //       v---- Comma instead of whitespace
     #[case("5, ", TextValue::String(String::from("<should panic>")).without_annotations())]
@@ -79,6 +79,16 @@ mod float_parsing_tests {
         } else {
             panic!("Expected NaN, but got: {:?}", value);
         }
+
+        // Negative zero keeps its sign
+        let value = parse_unwrap(parse_float, "-0e0 ");
+        assert!(matches!(value, TextValue::Float(f) if f == 0f64 && f.is_sign_negative()));
+
+        // Special values must be followed by a stop character
+        parse_fails("nanx ");
+        parse_fails("+info ");
+        // Infinity requires an explicit sign
+        parse_fails("inf ");
     }
 
     #[test]
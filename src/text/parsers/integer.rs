@@ -8,14 +8,16 @@ use nom::combinator::{map_res, opt, recognize};
 use nom::multi::many0_count;
 use nom::sequence::{pair, preceded, separated_pair, terminated};
 use nom::IResult;
-use std::num::ParseIntError;
+use num_bigint::{BigInt, ParseBigIntError};
+use num_traits::Num;
 
 // This module uses the phrase "base 10" to avoid potentially confusing references to "decimal",
 // a phrase which is heavily overloaded in the context of parsing Ion. It may refer to the Ion type
 // decimal, the base-10 notation, or the fractional delimiter of a floating-point number.
 
 /// Matches the text representation of an integer in any supported notation (base-2, base-10, or
-/// base-16) and returns the resulting [i64] as a [TextValue::Integer].
+/// base-16) and returns the resulting value as a [TextValue::Integer] if it fits in an [i64] or as
+/// a [TextValue::BigInteger] if it does not.
 pub(crate) fn parse_integer(input: &str) -> IResult<&str, TextValue> {
     terminated(
         alt((base_16_integer, base_2_integer, base_10_integer)),
@@ -24,7 +26,7 @@ pub(crate) fn parse_integer(input: &str) -> IResult<&str, TextValue> {
 }
 
 /// Matches a base-16 notation integer (e.g. `0xCAFE`, `0Xcafe`, or `-0xCa_Fe`) and returns the
-/// resulting [i64] as a [TextValue::Integer].
+/// resulting [TextValue].
 fn base_16_integer(input: &str) -> IResult<&str, TextValue> {
     map_res(
         separated_pair(
@@ -33,9 +35,7 @@ fn base_16_integer(input: &str) -> IResult<&str, TextValue> {
             base_16_integer_digits,
         ),
         |(maybe_sign, text_digits)| {
-            parse_i64_with_radix(text_digits, 16)
-                .map(|i| if maybe_sign.is_some() { -i } else { i })
-                .map(|i| TextValue::Integer(i))
+            parse_signed_integer_with_radix(maybe_sign.is_some(), text_digits, 16)
         },
     )(input)
 }
@@ -57,7 +57,7 @@ fn take_base_16_digits1(input: &str) -> IResult<&str, &str> {
 }
 
 /// Matches a base-2 notation integer (e.g. `0b0`, `0B1`, or `-0b10_10`) and returns the resulting
-/// [i64] as a [TextValue::Integer].
+/// [TextValue].
 fn base_2_integer(input: &str) -> IResult<&str, TextValue> {
     map_res(
         separated_pair(
@@ -66,9 +66,7 @@ fn base_2_integer(input: &str) -> IResult<&str, TextValue> {
             base_2_integer_digits,
         ),
         |(maybe_sign, text_digits)| {
-            parse_i64_with_radix(text_digits, 2)
-                .map(|i| if maybe_sign.is_some() { -i } else { i })
-                .map(|i| TextValue::Integer(i))
+            parse_signed_integer_with_radix(maybe_sign.is_some(), text_digits, 2)
         },
     )(input)
 }
@@ -84,22 +82,42 @@ fn base_2_integer_digits(input: &str) -> IResult<&str, &str> {
 }
 
 /// Matches a base-10 notation integer (e.g. `0`, `255`, or `-1_024`) and returns the resulting
-/// [i64] as a [TextValue::Integer].
+/// [TextValue].
 fn base_10_integer(input: &str) -> IResult<&str, TextValue> {
     map_res(
         recognize(preceded(opt(char('-')), base_10_integer_digits)),
-        |text| parse_i64_with_radix(text, 10).map(|i| TextValue::Integer(i)),
+        |text| parse_integer_with_radix(text, 10),
     )(input)
 }
 
 /// Strips any underscores out of the provided text and then parses it according to the specified
-/// radix.
-fn parse_i64_with_radix(text: &str, radix: u32) -> Result<i64, ParseIntError> {
-    if text.contains('_') {
-        let sanitized = text.replace("_", "");
-        return i64::from_str_radix(&sanitized, radix);
+/// radix. Integers that are out of range for an [i64] are parsed as a [BigInt].
+fn parse_integer_with_radix(text: &str, radix: u32) -> Result<TextValue, ParseBigIntError> {
+    let sanitized;
+    let text = if text.contains('_') {
+        sanitized = text.replace("_", "");
+        sanitized.as_str()
+    } else {
+        text
+    };
+    if let Ok(value) = i64::from_str_radix(text, radix) {
+        return Ok(TextValue::Integer(value));
     }
-    i64::from_str_radix(text, radix)
+    BigInt::from_str_radix(text, radix).map(TextValue::BigInteger)
+}
+
+/// Parses the provided digits (which follow the sign and the `0x` or `0b` prefix) according to
+/// the specified radix and then applies the sign. The digits are parsed with the sign so that
+/// the most negative i64 (e.g. `-0x8000000000000000`) is in range.
+fn parse_signed_integer_with_radix(
+    is_negative: bool,
+    digits: &str,
+    radix: u32,
+) -> Result<TextValue, ParseBigIntError> {
+    if is_negative {
+        return parse_integer_with_radix(&format!("-{}", digits), radix);
+    }
+    parse_integer_with_radix(digits, radix)
 }
 
 #[cfg(test)]
@@ -107,6 +125,7 @@ mod integer_parsing_tests {
     use crate::text::parsers::integer::parse_integer;
     use crate::text::parsers::unit_test_support::{parse_test_err, parse_test_ok};
     use crate::text::text_value::TextValue;
+    use num_bigint::BigInt;
 
     fn parse_equals(text: &str, expected: i64) {
         parse_test_ok(parse_integer, text, TextValue::Integer(expected))
@@ -155,6 +174,7 @@ mod integer_parsing_tests {
         parse_equals("-0b11110000 ", -240);
         parse_equals("0B11111111 ", 255);
         parse_equals("-0B11111111 ", -255);
+        parse_equals(&format!("-0b1{} ", "0".repeat(63)), i64::MIN);
 
         // Doesn't consume leading whitespace
         parse_fails(" 0b0011_0001 ");
@@ -190,6 +210,8 @@ mod integer_parsing_tests {
         parse_equals("0xff ", 255);
         parse_equals("0XfF ", 255);
         parse_equals("-0xDECAF ", -912559);
+        parse_equals("0x7FFF_FFFF_FFFF_FFFF ", i64::MAX);
+        parse_equals("-0x8000_0000_0000_0000 ", i64::MIN);
 
         // Doesn't consume leading whitespace
         parse_fails(" 0xCAFE ");
@@ -214,4 +236,20 @@ mod integer_parsing_tests {
         // Doesn't accept multiple consecutive underscores
         parse_fails("0xCA__FE ");
     }
+
+    #[test]
+    fn test_parse_integers_beyond_i64() {
+        let parse_big_equals = |text: &str, expected: &str| {
+            let expected = expected.parse::<BigInt>().unwrap();
+            parse_test_ok(parse_integer, text, TextValue::BigInteger(expected))
+        };
+        parse_big_equals("9223372036854775808 ", "9223372036854775808");
+        parse_big_equals("-9_223_372_036_854_775_809 ", "-9223372036854775809");
+        parse_big_equals(
+            "123456789012345678901234567890 ",
+            "123456789012345678901234567890",
+        );
+        parse_big_equals("0x1_0000_0000_0000_0000 ", "18446744073709551616");
+        parse_big_equals(&format!("-0b1{} ", "0".repeat(64)), "-18446744073709551616");
+    }
 }
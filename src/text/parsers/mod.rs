@@ -3,7 +3,6 @@
 
 use std::str::FromStr;
 
-use nom::bytes::streaming::is_a;
 use nom::character::streaming::{one_of, satisfy};
 use nom::combinator::peek;
 use nom::IResult;
@@ -12,13 +11,14 @@ pub(crate) mod annotations;
 pub(crate) mod blob;
 pub(crate) mod boolean;
 pub(crate) mod clob;
-mod comments;
+pub(crate) mod comments;
 pub(crate) mod containers;
 pub(crate) mod decimal;
 pub(crate) mod float;
 pub(crate) mod integer;
 pub(crate) mod null;
 pub(crate) mod numeric_support;
+pub(crate) mod scanning;
 pub(crate) mod string;
 pub(crate) mod symbol;
 pub(crate) mod text_support;
@@ -70,7 +70,7 @@ pub(crate) fn digit(input: &str) -> IResult<&str, char> {
 
 /// Matches one or more whitespace characters.
 pub(crate) fn whitespace(input: &str) -> IResult<&str, &str> {
-    is_a(" \r\n\t")(input)
+    scanning::whitespace_run(input)
 }
 
 /// Helper functions used in the unit tests for each parsing module.
@@ -0,0 +1,181 @@
+// Text parsing spends most of its time finding the end of runs of uninteresting bytes: the text
+// of a string up to its closing delimiter or next escape, the body of a comment, or the whitespace
+// between values. Matching those runs a character at a time with nom's combinators (`is_not`,
+// `take_until`, `multispace1`) is the dominant cost when reading large amounts of text Ion. The
+// parsers in this module locate the end of each run using `memchr`, which searches many bytes at
+// a time with SIMD instructions where they are available.
+//
+// Each parser has the same streaming semantics as the nom combinator it replaces: if the end of
+// the run is not found before the end of the input, it returns `Incomplete` so the reader can load
+// more data and try again. Every delimiter being searched for is ASCII, so the offsets found are
+// always valid `char` boundaries.
+
+use memchr::{memchr2, memmem};
+use nom::error::{Error, ErrorKind};
+use nom::{Err, IResult, Needed};
+
+/// Matches one or more whitespace characters (` `, `\t`, `\r` or `\n`).
+pub(crate) fn whitespace_run(input: &str) -> IResult<&str, &str> {
+    let length = input
+        .as_bytes()
+        .iter()
+        .position(|byte| !matches!(byte, b' ' | b'\t' | b'\r' | b'\n'));
+    match length {
+        None => Err(Err::Incomplete(Needed::new(1))),
+        Some(0) => Err(Err::Error(Error::new(input, ErrorKind::MultiSpace))),
+        Some(length) => Ok((&input[length..], &input[..length])),
+    }
+}
+
+/// Matches the text of a short string up to (but not including) the closing `"` or the next
+/// escape sequence. The matched text cannot be empty.
+pub(crate) fn short_string_text(input: &str) -> IResult<&str, &str> {
+    match memchr2(b'"', b'\\', input.as_bytes()) {
+        None => Err(Err::Incomplete(Needed::new(1))),
+        Some(0) => Err(Err::Error(Error::new(input, ErrorKind::IsNot))),
+        Some(length) => Ok((&input[length..], &input[..length])),
+    }
+}
+
+/// Matches the text of a long string segment up to (but not including) the closing `'''` or the
+/// next escape sequence. The matched text cannot be empty.
+pub(crate) fn long_string_text(input: &str) -> IResult<&str, &str> {
+    let bytes = input.as_bytes();
+    let mut position = 0;
+    let length = loop {
+        let index = match memchr2(b'\'', b'\\', &bytes[position..]) {
+            Some(offset) => position + offset,
+            None => return Err(Err::Incomplete(Needed::new(3))),
+        };
+        if bytes[index] == b'\\' {
+            break index;
+        }
+        // A single quote only ends the text if it begins a `'''` delimiter.
+        let rest = &bytes[index..];
+        if rest.starts_with(b"'''") {
+            break index;
+        }
+        if b"'''".starts_with(rest) {
+            // The input ends partway through what may be a delimiter.
+            return Err(Err::Incomplete(Needed::new(3 - rest.len())));
+        }
+        position = index + 1;
+    };
+    if length == 0 {
+        return Err(Err::Error(Error::new(input, ErrorKind::TakeUntil)));
+    }
+    Ok((&input[length..], &input[..length]))
+}
+
+/// Matches the text of a rest-of-line comment up to (but not including) the next `\r` or `\n`.
+/// The matched text cannot be empty.
+pub(crate) fn rest_of_line_text(input: &str) -> IResult<&str, &str> {
+    match memchr2(b'\r', b'\n', input.as_bytes()) {
+        None => Err(Err::Incomplete(Needed::new(1))),
+        Some(0) => Err(Err::Error(Error::new(input, ErrorKind::IsNot))),
+        Some(length) => Ok((&input[length..], &input[..length])),
+    }
+}
+
+/// Matches the text of a multiline comment up to (but not including) the closing `*/`. The
+/// matched text may be empty.
+pub(crate) fn multiline_comment_text(input: &str) -> IResult<&str, &str> {
+    match memmem::find(input.as_bytes(), b"*/") {
+        None => Err(Err::Incomplete(Needed::new(2))),
+        Some(length) => Ok((&input[length..], &input[..length])),
+    }
+}
+
+#[cfg(test)]
+mod scanning_tests {
+    use super::*;
+    use nom::bytes::streaming::{is_a, is_not, take_until};
+    use rstest::*;
+
+    // Asserts that `parser` and the nom combinator it replaces produce the same remaining input
+    // and output, or that both fail in the same way.
+    fn assert_matches_baseline<'a>(
+        parser: impl Fn(&'a str) -> IResult<&'a str, &'a str>,
+        baseline: impl Fn(&'a str) -> IResult<&'a str, &'a str>,
+        input: &'a str,
+    ) {
+        match (parser(input), baseline(input)) {
+            (Ok(actual), Ok(expected)) => assert_eq!(actual, expected, "input: {:?}", input),
+            (Err(Err::Incomplete(_)), Err(Err::Incomplete(_))) => {}
+            (Err(Err::Error(_)), Err(Err::Error(_))) => {}
+            (actual, expected) => panic!(
+                "input: {:?}, expected {:?}, found {:?}",
+                input, expected, actual
+            ),
+        }
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case(" ")]
+    #[case("foo")]
+    #[case(" \t\r\n foo")]
+    #[case("\u{0b}foo")]
+    #[case("    😎")]
+    fn test_whitespace_run(#[case] input: &str) {
+        assert_matches_baseline(whitespace_run, is_a(" \t\r\n"), input);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("foo")]
+    #[case("foo\"")]
+    #[case("\"")]
+    #[case("foo\\nbar\"")]
+    #[case("😎 😎 😎\" ")]
+    fn test_short_string_text(#[case] input: &str) {
+        assert_matches_baseline(short_string_text, is_not("\"\\"), input);
+    }
+
+    #[rstest]
+    #[case("foo'''", "'''", "foo")]
+    #[case("foo'bar''baz''' ", "''' ", "foo'bar''baz")]
+    #[case("foo\\x62ar'''", "\\x62ar'''", "foo")]
+    #[case("😎'😎'''", "'''", "😎'😎")]
+    fn test_long_string_text(#[case] input: &str, #[case] remaining: &str, #[case] text: &str) {
+        assert_eq!(long_string_text(input), Ok((remaining, text)));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("foo")]
+    #[case("foo'")]
+    #[case("foo''")]
+    fn test_long_string_text_incomplete(#[case] input: &str) {
+        assert!(matches!(long_string_text(input), Err(Err::Incomplete(_))));
+    }
+
+    #[rstest]
+    #[case("'''")]
+    #[case("\\x62")]
+    fn test_long_string_text_empty(#[case] input: &str) {
+        assert!(matches!(long_string_text(input), Err(Err::Error(_))));
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("foo")]
+    #[case("foo\n")]
+    #[case("foo\r\n")]
+    #[case("\n")]
+    #[case("😎 😎 😎\nbar")]
+    fn test_rest_of_line_text(#[case] input: &str) {
+        assert_matches_baseline(rest_of_line_text, is_not("\r\n"), input);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("foo")]
+    #[case("foo*")]
+    #[case("*/")]
+    #[case("foo\nbar*/ baz")]
+    #[case("foo * / bar */*/")]
+    fn test_multiline_comment_text(#[case] input: &str) {
+        assert_matches_baseline(multiline_comment_text, take_until("*/"), input);
+    }
+}
@@ -1,10 +1,11 @@
+use crate::text::parsers::scanning::{long_string_text, short_string_text};
 use crate::text::parsers::text_support::{escaped_char, escaped_newline, StringFragment};
 use crate::text::parsers::whitespace;
 use crate::text::text_value::TextValue;
 use nom::branch::alt;
-use nom::bytes::streaming::{is_not, tag, take_until};
+use nom::bytes::streaming::tag;
 use nom::character::streaming::char;
-use nom::combinator::{map, not, opt, peek, verify};
+use nom::combinator::{map, not, opt, peek};
 use nom::multi::{fold_many0, many1};
 use nom::sequence::{delimited, terminated};
 use nom::IResult;
@@ -58,7 +59,7 @@ fn long_string_body(input: &str) -> IResult<&str, String> {
 }
 
 /// Matches an escaped character or a substring without any escapes in a long string.
-fn long_string_fragment(input: &str) -> IResult<&str, StringFragment> {
+fn long_string_fragment(input: &str) -> IResult<&str, StringFragment<'_>> {
     alt((
         escaped_newline,
         escaped_char,
@@ -66,11 +67,10 @@ fn long_string_fragment(input: &str) -> IResult<&str, StringFragment> {
     ))(input)
 }
 
-/// Matches the next string fragment while respecting the long string delimiter (`'''`).
-fn long_string_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment> {
-    map(verify(take_until("'''"), |s: &str| !s.is_empty()), |text| {
-        StringFragment::Substring(text)
-    })(input)
+/// Matches the next string fragment while respecting the long string delimiter (`'''`). The
+/// fragment ends before the next escape sequence so that it can be decoded.
+fn long_string_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment<'_>> {
+    map(long_string_text, StringFragment::Substring)(input)
 }
 
 /// Matches the body of a short string. (The `hello` in `"hello"`.)
@@ -90,7 +90,7 @@ fn short_string_body(input: &str) -> IResult<&str, String> {
 }
 
 /// Matches an escaped character or a substring without any escapes in a short string.
-fn short_string_fragment(input: &str) -> IResult<&str, StringFragment> {
+fn short_string_fragment(input: &str) -> IResult<&str, StringFragment<'_>> {
     alt((
         escaped_newline,
         escaped_char,
@@ -99,10 +99,8 @@ fn short_string_fragment(input: &str) -> IResult<&str, StringFragment> {
 }
 
 /// Matches the next string fragment while respecting the short string delimiter (`"`).
-fn short_string_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment> {
-    map(verify(is_not("\"\\\""), |s: &str| !s.is_empty()), |text| {
-        StringFragment::Substring(text)
-    })(input)
+fn short_string_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment<'_>> {
+    map(short_string_text, StringFragment::Substring)(input)
 }
 
 #[cfg(test)]
@@ -156,5 +154,7 @@ mod string_parsing_tests {
             "'''\\x66oo''' '''\\u0062\\U00000061r''' '''\\x62\\U00000061z''' 1",
             "foobarbaz",
         );
+        // Escapes are decoded wherever they appear in a segment.
+        parse_equals("'''f\\x6Fo''' '''b\\u0061r\\n''' 1", "foobar\n");
     }
 }
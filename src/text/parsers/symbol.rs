@@ -1,5 +1,4 @@
 use crate::raw_symbol_token::{local_sid_token, text_token};
-use crate::text::parsers::containers::s_expression_end;
 use crate::text::parsers::stop_character;
 use crate::text::parsers::text_support::{escaped_char, escaped_newline, StringFragment};
 use crate::text::text_value::TextValue;
@@ -8,9 +7,10 @@ use nom::bytes::streaming::tag;
 use nom::bytes::streaming::{is_a, is_not};
 use nom::character::streaming::{char, digit1, multispace0, one_of, satisfy};
 use nom::combinator::{map, map_opt, map_res, not, peek, recognize, verify};
+use nom::error::{Error, ErrorKind};
 use nom::multi::{fold_many0, many0_count};
 use nom::sequence::{delimited, pair, preceded, terminated};
-use nom::IResult;
+use nom::{Err, IResult};
 
 /// Matches the text representation of a symbol value and returns the resulting [String]
 /// as a [TextValue::Symbol].
@@ -44,7 +44,7 @@ fn quoted_symbol_body(input: &str) -> IResult<&str, String> {
 }
 
 /// Matches an escaped character or a substring without any escapes in a long string.
-fn quoted_symbol_string_fragment(input: &str) -> IResult<&str, StringFragment> {
+fn quoted_symbol_string_fragment(input: &str) -> IResult<&str, StringFragment<'_>> {
     alt((
         escaped_newline,
         escaped_char,
@@ -53,7 +53,7 @@ fn quoted_symbol_string_fragment(input: &str) -> IResult<&str, StringFragment> {
 }
 
 /// Matches the next quoted symbol string fragment while respecting the symbol delimiter (`'`).
-fn quoted_symbol_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment> {
+fn quoted_symbol_fragment_without_escaped_text(input: &str) -> IResult<&str, StringFragment<'_>> {
     map(verify(is_not("'\\'"), |s: &str| !s.is_empty()), |text| {
         StringFragment::Substring(text)
     })(input)
@@ -99,30 +99,42 @@ fn identifier_trailing_characters(input: &str) -> IResult<&str, &str> {
     recognize(many0_count(identifier_trailing_character))(input)
 }
 
+/// The characters that can make up an operator. See [parse_operator].
+pub(crate) const OPERATOR_CHARACTERS: &str = "!#%&*+-./;<=>?@^`|~";
+
 /// Matches an operator (e.g. `++` or `@`) and returns the resulting [String]
 /// as a [TextValue::Symbol]. This symbol syntax is only recognized inside of an s-expression.
 pub(crate) fn parse_operator(input: &str) -> IResult<&str, TextValue> {
     // This function is used by the [s_expression_value] parser in the [containers] module.
-
-    // The 'recognizer' below  is a parser responsible for identifying the &str slice at the
-    // beginning of input that represents an operator. The `map` operation that follows uses
-    // this parser's output to construct the necessary TextValue.
-    let recognizer = delimited(
+    let (remaining, op_text) = preceded(
         // Other parsers don't have their own leading whitespace matcher because the overarching
         // top_level_stream_value parser takes care of this. When matching an s-expression, this
         // parser is given precedence over the other parsers; because of this, it must consume
         // the whitespace on its own.
         multispace0,
         // `is_a` matches the longest leading string comprised of one or more of the given characters
-        is_a("!#%&*+-./;<=>?@^`|~"),
-        // The operator must be followed either by whitespace or the end of the s-expression.
-        alt((
-            peek(recognize(one_of(" \r\n\t"))),
-            peek(recognize(s_expression_end)),
-        )),
-    );
-    // The above `recognizer` outputs a &str; this operation turns that &str into a Symbol.
-    map(recognizer, |op_text| TextValue::Symbol(text_token(op_text)))(input)
+        is_a(OPERATOR_CHARACTERS),
+    )(input)?;
+    // Because `is_a` stops at the first character that cannot be part of an operator, no
+    // delimiter is needed between an operator and the next value: `(a+b)` holds three symbols.
+    // The sign at the beginning of a number (`-1`, `+inf`) is part of the number instead.
+    if begins_number(op_text, remaining) {
+        return Err(Err::Error(Error::new(input, ErrorKind::Verify)));
+    }
+    Ok((remaining, TextValue::Symbol(text_token(op_text))))
+}
+
+/// Returns true if `op_text` is the sign of a number that begins at `remaining`.
+fn begins_number(op_text: &str, remaining: &str) -> bool {
+    let is_infinity = || {
+        remaining.starts_with("inf")
+            && !remaining[3..].starts_with(|c: char| c == '$' || c == '_' || c.is_alphanumeric())
+    };
+    match op_text {
+        "-" => remaining.starts_with(|c: char| c.is_ascii_digit()) || is_infinity(),
+        "+" => is_infinity(),
+        _ => false,
+    }
 }
 
 /// Matches a symbol ID in the format `$ID` (For example, `$0` or `$42`.)
@@ -145,6 +157,8 @@ fn symbol_id(input: &str) -> IResult<&str, TextValue> {
                 // returns a char instead of a &str, so we use `recognize()` to get a &str instead.
                 recognize(stop_character),
                 peek(tag(":")), // Field delimiter (":") or annotation delimiter ("::")
+                // The start of an operator, which can follow a symbol ID in an s-expression
+                peek(recognize(one_of(OPERATOR_CHARACTERS))),
             )),
         ),
         |text| {
@@ -246,6 +260,13 @@ mod symbol_parsing_tests {
     #[case("...)", "...")]
     #[case("// ", "//")]
     #[case("//)", "//")]
+    #[case("+b", "+")]
+    #[case("==1", "==")]
+    #[case(".\"a\"", ".")]
+    #[case("- 1", "-")]
+    #[case("-(", "-")]
+    #[case("+1", "+")]
+    #[case("-info", "-")]
     fn test_parse_operators(#[case] text: &str, #[case] expected: &str) {
         parse_test_ok(
             parse_operator,
@@ -253,4 +274,13 @@ mod symbol_parsing_tests {
             TextValue::Symbol(text_token(expected)),
         )
     }
+
+    #[rstest]
+    #[case("-1 ")]
+    #[case("-inf ")]
+    #[case("+inf)")]
+    #[case("a ")]
+    fn test_parse_operators_fail(#[case] text: &str) {
+        parse_test_err(parse_operator, text);
+    }
 }
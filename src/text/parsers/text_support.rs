@@ -23,13 +23,13 @@ pub(crate) enum StringFragment<'a> {
 }
 
 /// Matches an escaped newline, returning [StringFragment::EscapedNewline].
-pub(crate) fn escaped_newline(input: &str) -> IResult<&str, StringFragment> {
+pub(crate) fn escaped_newline(input: &str) -> IResult<&str, StringFragment<'_>> {
     value(StringFragment::EscapedNewline, tag("\\\n"))(input)
 }
 
 /// Matches an escaped literal (like '\n') or a Unicode escape (starting with '\x', '\u', or '\U'),
 /// returning the appropriate substitute character as a [StringFragment::EscapedChar].
-pub(crate) fn escaped_char(input: &str) -> IResult<&str, StringFragment> {
+pub(crate) fn escaped_char(input: &str) -> IResult<&str, StringFragment<'_>> {
     map(
         preceded(
             char('\\'),
@@ -1,4 +1,3 @@
-use std::ops::DivAssign;
 use std::str::FromStr;
 
 use nom::branch::alt;
@@ -161,16 +160,10 @@ fn assign_fractional_seconds(
     } else {
         // Otherwise, the number's precision is great enough that we'll need to construct a Decimal
         // to store it without loss of fidelity.
+        // Every digit (including any leading zeros) is after the decimal point.
         let coefficient =
             BigUint::from_str(fractional).expect("parsing fractional seconds as BigUint failed");
-        let mut digit_count = 1i64;
-        let mut tmp_coefficient = coefficient.clone();
-        let ten = BigUint::from(10u32);
-        while tmp_coefficient > ten {
-            tmp_coefficient.div_assign(&ten);
-            digit_count += 1;
-        }
-        let decimal = Decimal::new(coefficient, -1 * digit_count);
+        let decimal = Decimal::new(coefficient, -(number_of_digits as i64));
         setter = setter.with_fractional_seconds(decimal);
     }
     setter
@@ -449,6 +442,13 @@ mod reader_tests {
                 .with_fractional_seconds(Decimal::new(193193193193193i64, -15))
                 .build_at_unknown_offset()?,
         );
+        parse_equals(
+            "2021-12-25T14:30:31.0000000000193-00:00 ",
+            builder
+                .clone()
+                .with_fractional_seconds(Decimal::new(193, -13))
+                .build_at_unknown_offset()?,
+        );
         Ok(())
     }
 }
@@ -1,21 +1,36 @@
+use std::io;
+use std::ops::Range;
+
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, FixedOffset};
+use nom::branch::alt;
+use nom::character::streaming::multispace1;
+use nom::combinator::{map, opt, recognize};
+use nom::sequence::pair;
 use nom::Err::Incomplete;
 use nom::IResult;
 
 use crate::raw_reader::StreamItem;
 use crate::raw_symbol_token::RawSymbolToken;
-use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::result::{
+    decoding_error, decoding_error_at, decoding_error_raw, illegal_operation,
+    incomplete_data_error, DecodingErrorCategory, IonError, IonResult,
+};
 use crate::text::parent_container::ParentContainer;
+use crate::text::parsers::annotations::parse_annotations;
+use crate::text::parsers::comments::{comment, whitespace_or_comments};
 use crate::text::parsers::containers::{
     list_delimiter, list_value_or_end, s_expression_delimiter, s_expression_value_or_end,
     struct_delimiter, struct_field_name_or_end, struct_field_value,
 };
+use crate::text::parsers::symbol::parse_operator;
 use crate::text::parsers::top_level::{ion_1_0_version_marker, top_level_value};
+use crate::text::parsers::value::annotated_scalar;
 use crate::text::text_buffer::TextBuffer;
 use crate::text::text_data_source::TextIonDataSource;
 use crate::text::text_value::{AnnotatedTextValue, TextValue};
 use crate::types::decimal::Decimal;
+use crate::types::integer::Integer;
 use crate::types::timestamp::Timestamp;
 use crate::{IonType, RawReader};
 
@@ -32,9 +47,17 @@ pub struct RawTextReader<T: TextIonDataSource> {
     //     Some(major_version, minor_version)
     // Otherwise, it is None.
     current_ivm: Option<(u8, u8)>,
+    // If the reader is positioned over a scalar value, the location of its source text in `buffer`.
+    current_value_source: Option<Range<usize>>,
+    // The position in `buffer` at which the text most recently matched by `parse_next` began.
+    last_parse_offset: usize,
     bytes_read: usize,
     is_eof: bool,
     parents: Vec<ParentContainer>,
+    // Whether comments should be recorded in `comments` rather than silently skipped.
+    surface_comments: bool,
+    // The comments that appeared between the previous value and the current one.
+    comments: Vec<String>,
 }
 
 impl<T: TextIonDataSource> RawTextReader<T> {
@@ -45,35 +68,95 @@ impl<T: TextIonDataSource> RawTextReader<T> {
             current_field_name: None,
             current_value: None,
             current_ivm: None,
+            current_value_source: None,
+            last_parse_offset: 0,
             bytes_read: 0,
             is_eof: false,
             parents: Vec::with_capacity(INITIAL_PARENTS_CAPACITY),
+            surface_comments: false,
+            comments: Vec::new(),
         }
     }
 
+    /// Constructs a RawTextReader that records the text of any `// rest-of-line` or
+    /// `/* multiline */` comments it encounters instead of discarding them. After each call to
+    /// [RawReader::next], the comments that preceded the new position can be inspected via
+    /// [comments](Self::comments).
+    pub fn with_comments(input: T) -> RawTextReader<T> {
+        let mut reader = RawTextReader::new(input);
+        reader.surface_comments = true;
+        reader
+    }
+
+    /// Returns the text of each comment (without its `//` or `/* */` delimiters) that was
+    /// found between the previous value and the reader's current position. If the most recent
+    /// call to `next()` returned `None`, these are the comments found before the end of the
+    /// container or stream. Comments inside of containers that were skipped are not included.
+    ///
+    /// This is always empty unless the reader was constructed with [with_comments](Self::with_comments).
+    pub fn comments(&self) -> &[String] {
+        &self.comments
+    }
+
     pub fn bytes_read(&self) -> usize {
         self.bytes_read
     }
 
-    fn load_next_value(&mut self) -> IonResult<()> {
-        // If the reader's current value is the beginning of a container and the user calls `next()`,
-        // we need to skip the entire container. We can do this by stepping into and then out of
-        // that container; `step_out()` has logic that will exhaust the remaining values.
-        let need_to_skip_container = self
-            .current_value
+    /// If the reader is positioned over a scalar value, returns the exact text from the input
+    /// that represents it, including any annotations (for example: `foo::'bar'`, `0x1F` or
+    /// `2021-08-01T`). Whitespace, comments, and delimiters surrounding the value are not included.
+    /// Returns None if the reader is not positioned over a value or if the value is a container;
+    /// containers are read incrementally, so their complete text is not available.
+    pub fn current_value_source(&self) -> Option<&str> {
+        self.current_value_source
             .as_ref()
-            .map(|v| v.value().ion_type().is_container())
-            .unwrap_or(false);
+            .map(|range| &self.buffer.text_from(range.start)[..range.len()])
+    }
 
-        if need_to_skip_container {
-            self.step_in()?;
-            self.step_out()?;
+    // Returns true if the reader is positioned over a container that has contents to skip. Null
+    // containers (e.g. `null.list`) have none.
+    fn is_on_non_null_container(&self) -> bool {
+        self.current_value
+            .as_ref()
+            .map(|v| v.value().ion_type().is_container() && !self.is_null())
+            .unwrap_or(false)
+    }
+
+    // Skips the container over which the reader is positioned, including any containers nested
+    // inside of it. Rather than letting `step_out()` call `next()` (which would skip each nested
+    // container the same way), this steps into every nested container and out again in a loop,
+    // so that skipping arbitrarily deep input does not exhaust the stack.
+    fn skip_container(&mut self) -> IonResult<()> {
+        let depth = self.parents.len();
+        self.step_in()?;
+        while self.parents.len() > depth {
+            if self.parents.last().unwrap().is_exhausted() {
+                // `step_out()` only calls `next()` if the container has values left to visit.
+                self.step_out()?;
+            } else {
+                // The current value is never a container here, so this will not skip one.
+                self.load_next_value()?;
+                if self.is_on_non_null_container() {
+                    self.step_in()?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn load_next_value(&mut self) -> IonResult<()> {
+        // If the reader's current value is the beginning of a container and the user calls `next()`,
+        // we need to skip the entire container.
+        if self.is_on_non_null_container() {
+            self.skip_container()?;
         }
 
         // Unset variables holding onto information about the previous position.
         self.current_ivm = None;
         self.current_value = None;
+        self.current_value_source = None;
         self.current_field_name = None;
+        self.comments.clear();
 
         if self.parents.is_empty() {
             // The `parents` stack is empty. We're at the top level.
@@ -85,6 +168,8 @@ impl<T: TextIonDataSource> RawTextReader<T> {
                 return Ok(());
             }
 
+            self.collect_comments()?;
+
             // Otherwise, see if the next token in the stream is an Ion Version Marker.
             if let Ok(Some(_)) = self.parse_next(ion_1_0_version_marker) {
                 // We found an IVM; we currently only support Ion 1.0.
@@ -105,6 +190,7 @@ impl<T: TextIonDataSource> RawTextReader<T> {
                     // TODO: This currently clones the loaded value. This will not be necessary
                     //       when `next()` returns an IonType instead of an AnnotatedTextValue.
                     self.current_value = Some(value.clone());
+                    self.locate_current_value_source();
                 }
                 _ => {}
             };
@@ -119,6 +205,7 @@ impl<T: TextIonDataSource> RawTextReader<T> {
             self.current_value = None;
             return Ok(());
         }
+        self.collect_comments()?;
         // Otherwise, try to read the next value. The syntax we expect will depend on the
         // IonType of the parent container.
         let value = match parent.ion_type() {
@@ -129,6 +216,7 @@ impl<T: TextIonDataSource> RawTextReader<T> {
                 if let Some(field_name) = self.next_struct_field_name()? {
                     // ...remember it and return the field value that follows.
                     self.current_field_name = Some(field_name);
+                    self.collect_comments()?;
                     let field_value_result = self.next_struct_field_value()?;
                     Ok(Some(field_value_result))
                 } else {
@@ -155,6 +243,7 @@ impl<T: TextIonDataSource> RawTextReader<T> {
             Ok(Some(value)) => {
                 // We successfully read a value. Set it as the current value.
                 self.current_value = Some(value);
+                self.locate_current_value_source();
             }
             Err(e) => return Err(e),
         };
@@ -162,6 +251,63 @@ impl<T: TextIonDataSource> RawTextReader<T> {
         Ok(())
     }
 
+    /// If the current value is a scalar, finds its source text in the buffer and stores its
+    /// location in `current_value_source`. The text that the value's parser matched began at
+    /// `last_parse_offset`; it may include leading whitespace and comments, as well as a trailing
+    /// delimiter. The matched text (and whatever follows it) remains in the buffer until more
+    /// text is loaded, which will not happen until the reader advances.
+    fn locate_current_value_source(&mut self) {
+        let is_scalar = self
+            .current_value
+            .as_ref()
+            .map(|value| !value.ion_type().is_container())
+            .unwrap_or(false);
+        if !is_scalar {
+            return;
+        }
+        let parsed_text = self.buffer.text_from(self.last_parse_offset);
+        let value_text = match whitespace_or_comments(parsed_text) {
+            Ok((remaining_text, _)) => remaining_text,
+            Err(_) => return,
+        };
+        // Operators are only values inside s-expressions, where they are matched first.
+        let source_parser = match self.parents.last().map(|parent| parent.ion_type()) {
+            Some(IonType::SExpression) => s_expression_scalar_source,
+            _ => scalar_source,
+        };
+        let value_length = match source_parser(value_text) {
+            Ok((_remaining_text, source)) => source.len(),
+            // At the end of the stream, the value's parser cannot tell whether the value is
+            // complete. As in `parse_value_at_eof`, append a sentinel value and try again.
+            Err(Incomplete(_needed)) if self.is_eof => {
+                let terminated_text = format!("{}\n0\n", value_text);
+                match source_parser(&terminated_text) {
+                    Ok((_remaining_text, source)) => source.len(),
+                    Err(_) => return,
+                }
+            }
+            Err(_) => return,
+        };
+        let start = self.last_parse_offset + (parsed_text.len() - value_text.len());
+        self.current_value_source = Some(start..start + value_length);
+    }
+
+    /// If the reader was constructed using [with_comments](Self::with_comments), consumes any
+    /// whitespace and comments at the head of the input, storing the text of each comment in
+    /// `self.comments`. Otherwise, does nothing; the value parsers will skip them as usual.
+    fn collect_comments(&mut self) -> IonResult<()> {
+        if !self.surface_comments {
+            return Ok(());
+        }
+        // If the parser didn't match (`Some(None)`) or the reader hit EOF (`None`), we're done.
+        while let Some(Some(comment)) = self.parse_next(whitespace_or_comment)? {
+            if let Some(text) = comment {
+                self.comments.push(text);
+            }
+        }
+        Ok(())
+    }
+
     /// Assumes that the reader is at the top level and attempts to parse the next value or IVM in
     /// the stream.
     fn next_top_level_value(&mut self) -> IonResult<Option<AnnotatedTextValue>> {
@@ -216,7 +362,7 @@ impl<T: TextIonDataSource> RawTextReader<T> {
     {
         match self.parse_next(parser) {
             Ok(Some(value)) => Ok(value),
-            Ok(None) => decoding_error(format!(
+            Ok(None) => incomplete_data_error(format!(
                 "Unexpected end of input while reading {} on line {}: '{}'",
                 entity_name,
                 self.buffer.lines_loaded(),
@@ -259,7 +405,17 @@ impl<T: TextIonDataSource> RawTextReader<T> {
                     // TODO: Currently this loads a single line at a time for easier testing.
                     //       We may wish to bump it to a higher number of lines at a time (8?)
                     //       for efficiency once we're confident in the correctness.
-                    if self.buffer.load_next_line()? == 0 {
+                    let bytes_loaded = self.buffer.load_next_line().map_err(|error| {
+                        match error.kind() {
+                            // `BufRead::read_line` reports invalid UTF-8 as `InvalidData`
+                            io::ErrorKind::InvalidData => {
+                                decoding_error_raw("The input was not valid UTF-8.")
+                                    .with_category(DecodingErrorCategory::InvalidUtf8)
+                            }
+                            _ => IonError::from(error),
+                        }
+                    })?;
+                    if bytes_loaded == 0 {
                         // If load_next_line() returns Ok(0), we've reached the end of our input.
                         self.is_eof = true;
                         // The buffer had an `Incomplete` value in it; now that we know we're at EOF,
@@ -276,7 +432,8 @@ impl<T: TextIonDataSource> RawTextReader<T> {
                     // The difference in length tells us how many bytes were part of the
                     // text representation of the value that we found.
                     let bytes_consumed = length_before_parse - length_after_parse;
-                    // Discard `bytes_consumed` bytes from the TextBuffer.
+                    // Discard `bytes_consumed` bytes from the TextBuffer, noting where they began.
+                    self.last_parse_offset = self.buffer.consumed_len();
                     self.buffer.consume(bytes_consumed);
                     self.bytes_read += bytes_consumed;
                     // Break out of the read/parse loop, returning the value that we matched.
@@ -287,12 +444,15 @@ impl<T: TextIonDataSource> RawTextReader<T> {
                     // were attempting to parse with `top_level_value`.)
                     // TODO: We probably don't want to surface the nom error (`e`) directly, but it's
                     //       useful for debugging.
-                    return decoding_error(format!(
-                        "Parsing error occurred near line {}: '{}': '{}'",
-                        self.buffer.lines_loaded(),
-                        self.buffer.remaining_text(),
-                        e
-                    ));
+                    return decoding_error_at(
+                        format!(
+                            "Parsing error occurred near line {}: '{}': '{}'",
+                            self.buffer.lines_loaded(),
+                            self.buffer.remaining_text(),
+                            e
+                        ),
+                        self.bytes_read,
+                    );
                 }
             };
         };
@@ -312,6 +472,8 @@ impl<T: TextIonDataSource> RawTextReader<T> {
         const SENTINEL_ION_TEXT: &str = "\n0\n";
         // Make a note of the buffer's length; we're about to modify it.
         let original_length = self.buffer.remaining_text().len();
+        // The text parsed below is never consumed, but any value it contains begins here.
+        self.last_parse_offset = self.buffer.consumed_len();
         // Append our sentinel value to the end of the input buffer.
         self.buffer.inner().push_str(SENTINEL_ION_TEXT);
         // If the buffer contained a value, the newline will indicate that the contents of the
@@ -344,7 +506,7 @@ impl<T: TextIonDataSource> RawTextReader<T> {
                 Ok(Some(value))
             }
             Err(Incomplete(_needed)) => {
-                decoding_error(format!(
+                incomplete_data_error(format!(
                     "Unexpected end of input on line {}: '{}'",
                     self.buffer.lines_loaded(),
                     &self.buffer.remaining_text()[..original_length] // Don't show the extra `\n0\n`
@@ -373,6 +535,32 @@ impl<T: TextIonDataSource> RawTextReader<T> {
     }
 }
 
+// Matches a (possibly annotated) scalar, returning the text it spans.
+// Used by `RawTextReader::locate_current_value_source`.
+fn scalar_source(input: &str) -> IResult<&str, &str> {
+    recognize(annotated_scalar)(input)
+}
+
+// Matches a (possibly annotated) s-expression operator or scalar, returning the text it spans.
+// Operators are tried first, as in `s_expression_scalar`; otherwise, the `c` in `c::--` would be
+// matched as an unannotated symbol. Used by `RawTextReader::locate_current_value_source`.
+fn s_expression_scalar_source(input: &str) -> IResult<&str, &str> {
+    recognize(alt((
+        map(pair(parse_annotations, parse_operator), |_| ()),
+        map(parse_operator, |_| ()),
+        map(annotated_scalar, |_| ()),
+    )))(input)
+}
+
+// Matches a run of whitespace (`Some(None)`), a comment (`Some(Some(text))`), or nothing (`None`).
+// Used by `RawTextReader::collect_comments`.
+fn whitespace_or_comment(input: &str) -> IResult<&str, Option<Option<String>>> {
+    opt(alt((
+        map(multispace1, |_| None),
+        map(comment, |text: &str| Some(text.to_owned())),
+    )))(input)
+}
+
 // Returned by the `annotations()` method below if there is no current value.
 const EMPTY_SLICE_RAW_SYMBOL_TOKEN: &[RawSymbolToken] = &[];
 
@@ -448,6 +636,18 @@ impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
     fn read_i64(&mut self) -> IonResult<Option<i64>> {
         match self.current_value.as_ref().map(|current| current.value()) {
             Some(TextValue::Integer(value)) => Ok(Some(*value)),
+            Some(TextValue::BigInteger(value)) => decoding_error(format!(
+                "Integer {} is out of range for an i64; use read_integer() instead.",
+                value
+            )),
+            _ => Ok(None),
+        }
+    }
+
+    fn read_integer(&mut self) -> IonResult<Option<Integer>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Integer(value)) => Ok(Some(Integer::I64(*value))),
+            Some(TextValue::BigInteger(value)) => Ok(Some(Integer::BigInt(value.clone()))),
             _ => Ok(None),
         }
     }
@@ -497,6 +697,13 @@ impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
         }
     }
 
+    fn read_str(&mut self) -> IonResult<Option<&str>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::String(ref value)) => Ok(Some(value.as_str())),
+            _ => Ok(None),
+        }
+    }
+
     fn string_bytes_map<F, U>(&mut self, f: F) -> IonResult<Option<U>>
     where
         F: FnOnce(&[u8]) -> U,
@@ -535,6 +742,13 @@ impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
         }
     }
 
+    fn read_blob_ref(&mut self) -> IonResult<Option<&[u8]>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Blob(ref value)) => Ok(Some(value.as_slice())),
+            _ => Ok(None),
+        }
+    }
+
     fn read_clob_bytes(&mut self) -> IonResult<Option<Vec<u8>>> {
         match self.current_value.as_ref().map(|current| current.value()) {
             Some(TextValue::Clob(ref value)) => Ok(Some(value.clone())),
@@ -554,6 +768,13 @@ impl<T: TextIonDataSource> RawReader for RawTextReader<T> {
         }
     }
 
+    fn read_clob_ref(&mut self) -> IonResult<Option<&[u8]>> {
+        match self.current_value.as_ref().map(|current| current.value()) {
+            Some(TextValue::Clob(ref value)) => Ok(Some(value.as_slice())),
+            _ => Ok(None),
+        }
+    }
+
     fn read_timestamp(&mut self) -> IonResult<Option<Timestamp>> {
         match self.current_value.as_ref().map(|current| current.value()) {
             Some(TextValue::Timestamp(ref value)) => Ok(Some(value.clone())),
@@ -642,12 +863,14 @@ mod reader_tests {
 
     use crate::raw_reader::StreamItem;
     use crate::raw_symbol_token::{local_sid_token, text_token};
-    use crate::result::IonResult;
+    use crate::result::{IonError, IonResult};
     use crate::text::raw_text_reader::RawTextReader;
     use crate::text::text_value::{IntoAnnotations, TextValue};
     use crate::types::decimal::Decimal;
+    use crate::types::integer::Integer;
     use crate::types::timestamp::Timestamp;
     use crate::{IonType, RawReader};
+    use num_bigint::BigInt;
 
     fn next_type(reader: &mut RawTextReader<&str>, ion_type: IonType, is_null: bool) {
         assert_eq!(
@@ -683,6 +906,167 @@ mod reader_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_skipping_deeply_nested_containers() -> IonResult<()> {
+        let depth = 10_000;
+        let ion_data = format!(
+            "{}{} [a, {}{}, b] 7",
+            "[".repeat(depth),
+            "]".repeat(depth),
+            "{x: (".repeat(depth),
+            ")}".repeat(depth)
+        );
+        let reader = &mut RawTextReader::new(ion_data.as_str());
+        // Skip the first list without stepping into it...
+        next_type(reader, IonType::List, false);
+        next_type(reader, IonType::List, false);
+        // ...and step out of the second one before its nested struct has been visited.
+        reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        next_type(reader, IonType::Struct, false);
+        reader.step_out()?;
+        assert_eq!(reader.depth(), 0);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(7));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_comments_are_skipped_by_default() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("// hello\n1 /* world */ 2");
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.comments().is_empty());
+        next_type(reader, IonType::Integer, false);
+        assert!(reader.comments().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_current_value_source() -> IonResult<()> {
+        let ion_data = r#"
+            0x1F  foo::'bar'  /* comment */ 2021-08-01T
+            [1.50, "hi" , a::b::null.int]
+            {a: 1e0, 'b' : '''x''' '''y'''}
+            (+ 1 c::--)
+            5d-1
+        "#;
+        let reader = &mut RawTextReader::new(ion_data);
+        assert_eq!(reader.current_value_source(), None);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.current_value_source(), Some("0x1F"));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.current_value_source(), Some("foo::'bar'"));
+        next_type(reader, IonType::Timestamp, false);
+        assert_eq!(reader.current_value_source(), Some("2021-08-01T"));
+
+        // Containers don't have source text, but their children do
+        next_type(reader, IonType::List, false);
+        assert_eq!(reader.current_value_source(), None);
+        reader.step_in()?;
+        next_type(reader, IonType::Decimal, false);
+        assert_eq!(reader.current_value_source(), Some("1.50"));
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.current_value_source(), Some("\"hi\""));
+        next_type(reader, IonType::Integer, true);
+        assert_eq!(reader.current_value_source(), Some("a::b::null.int"));
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.current_value_source(), None);
+        reader.step_out()?;
+
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.current_value_source(), Some("1e0"));
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.current_value_source(), Some("'''x''' '''y'''"));
+        reader.step_out()?;
+
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.current_value_source(), Some("+"));
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.current_value_source(), Some("1"));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.current_value_source(), Some("c::--"));
+        reader.step_out()?;
+
+        next_type(reader, IonType::Decimal, false);
+        assert_eq!(reader.current_value_source(), Some("5d-1"));
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.current_value_source(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_current_value_source_at_end_of_stream() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("1 foo::bar");
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.current_value_source(), Some("1"));
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.current_value_source(), Some("foo::bar"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_surface_comments() -> IonResult<()> {
+        let ion_data = r#"
+            // Before the annotations
+            /* and a second comment */
+            foo::bar::1
+            /* A comment
+               spanning
+               multiple lines */
+            [
+                // First element
+                2,
+                /* Second element */ 3,
+                // Before the end of the list
+            ]
+            {
+                // Field 'a'
+                a: /* a's value */ 4,
+            }
+            // At the end of the stream
+            5
+        "#;
+        let reader = &mut RawTextReader::with_comments(ion_data);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(
+            reader.comments(),
+            &[" Before the annotations", " and a second comment "]
+        );
+        annotations_eq(reader, &["foo", "bar"]);
+
+        next_type(reader, IonType::List, false);
+        assert_eq!(
+            reader.comments(),
+            &[" A comment\n               spanning\n               multiple lines "]
+        );
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.comments(), &[" First element"]);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.comments(), &[" Second element "]);
+        assert_eq!(reader.next()?, None);
+        assert_eq!(reader.comments(), &[" Before the end of the list"]);
+        reader.step_out()?;
+
+        next_type(reader, IonType::Struct, false);
+        assert!(reader.comments().is_empty());
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.comments(), &[" Field 'a'", " a's value "]);
+        reader.step_out()?;
+
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.comments(), &[" At the end of the stream"]);
+        assert_eq!(reader.read_i64()?, Some(5));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
     #[test]
     fn test_read_nested_containers() -> IonResult<()> {
         let ion_data = r#"
@@ -727,6 +1111,27 @@ mod reader_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_step_out_of_container_at_end_of_s_expression() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("(a [1] {b: 2}) 3");
+        next_type(reader, IonType::SExpression, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Symbol, false);
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        reader.step_out()?;
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        // The struct is the last child of the s-expression; stepping out of it must accept the
+        // s-expression's closing parenthesis as the delimiter that follows it.
+        reader.step_out()?;
+        assert_eq!(reader.next()?, None);
+        reader.step_out()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?.unwrap(), 3);
+        Ok(())
+    }
+
     #[test]
     fn test_read_container_with_mixed_scalars_and_containers() -> IonResult<()> {
         let ion_data = r#"
@@ -906,6 +1311,46 @@ mod reader_tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_integers_beyond_i64() -> IonResult<()> {
+        let reader =
+            &mut RawTextReader::new("123456789012345678901234567890 -0x8000_0000_0000_0000");
+        next_type(reader, IonType::Integer, false);
+        let expected = "123456789012345678901234567890".parse::<BigInt>().unwrap();
+        assert_eq!(reader.read_integer()?, Some(Integer::BigInt(expected)));
+        assert!(reader.read_i64().is_err());
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_integer()?, Some(Integer::I64(i64::MIN)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_special_floats() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("nan +inf -inf [nan, -inf] nan_symbol");
+        next_type(reader, IonType::Float, false);
+        assert!(reader.read_f64()?.unwrap().is_nan());
+        assert!(reader.read_f32()?.unwrap().is_nan());
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?, Some(f64::INFINITY));
+        assert_eq!(reader.read_f32()?, Some(f32::INFINITY));
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?, Some(f64::NEG_INFINITY));
+        assert_eq!(reader.read_f32()?, Some(f32::NEG_INFINITY));
+
+        next_type(reader, IonType::List, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Float, false);
+        assert!(reader.read_f64()?.unwrap().is_nan());
+        next_type(reader, IonType::Float, false);
+        assert_eq!(reader.read_f64()?, Some(f64::NEG_INFINITY));
+        reader.step_out()?;
+
+        // Identifiers that begin with a special float keyword are symbols
+        next_type(reader, IonType::Symbol, false);
+        assert_eq!(reader.read_symbol()?, Some(text_token("nan_symbol")));
+        Ok(())
+    }
+
     #[test]
     fn test_text_read_multiple_annotated_top_level_values() -> IonResult<()> {
         let ion_data = r#"
@@ -1007,4 +1452,81 @@ mod reader_tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_annotation_queries() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("foo::$10::'bar baz'::1 $4::2 3");
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.annotations_len(), 3);
+        assert_eq!(reader.first_annotation(), Some(&text_token("foo")));
+        assert!(reader.has_annotation("foo"));
+        assert!(reader.has_annotation("bar baz"));
+        assert!(!reader.has_annotation("bar"));
+        // Symbol IDs are not resolved, so `$10` does not match its own text
+        assert!(!reader.has_annotation("$10"));
+
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.first_annotation(), Some(&local_sid_token(4)));
+        assert!(!reader.has_annotation("name"));
+
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.annotations_len(), 0);
+        assert_eq!(reader.first_annotation(), None);
+        assert!(!reader.has_annotation("foo"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_text_field_names_and_annotations_have_no_sids() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("{foo: $10::bar::$11::1, $12: 2}");
+        next_type(reader, IonType::Struct, false);
+        reader.step_in()?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name_sid(), None);
+        // Text annotations are skipped
+        assert_eq!(reader.annotation_sids().collect::<Vec<_>>(), vec![10, 11]);
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.field_name_sid(), Some(12));
+        Ok(())
+    }
+
+    #[test]
+    fn test_step_in_expecting() -> IonResult<()> {
+        let reader = &mut RawTextReader::new("(1) [2] 3");
+        next_type(reader, IonType::SExpression, false);
+        match reader.step_in_expecting(IonType::List) {
+            Err(error) => assert_eq!(error.to_string(), "Expected a(n) list but found a(n) sexp."),
+            Ok(()) => panic!("stepped into an s-expression when expecting a list"),
+        }
+        assert_eq!(reader.depth(), 0);
+
+        next_type(reader, IonType::List, false);
+        reader.step_in_expecting(IonType::List)?;
+        next_type(reader, IonType::Integer, false);
+        assert_eq!(reader.read_i64()?, Some(2));
+        reader.step_out()?;
+
+        // Non-container values are rejected by `step_in` itself
+        next_type(reader, IonType::Integer, false);
+        assert!(matches!(
+            reader.step_in_expecting(IonType::List),
+            Err(IonError::IllegalOperation { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_borrowed_values() -> IonResult<()> {
+        let reader = &mut RawTextReader::new(r#""foo" {{AQI=}} {{"hi"}} null.string"#);
+        next_type(reader, IonType::String, false);
+        assert_eq!(reader.read_blob_ref()?, None);
+        assert_eq!(reader.read_str()?, Some("foo"));
+        next_type(reader, IonType::Blob, false);
+        assert_eq!(reader.read_blob_ref()?, Some(&[0x01, 0x02][..]));
+        next_type(reader, IonType::Clob, false);
+        assert_eq!(reader.read_clob_ref()?, Some(&b"hi"[..]));
+        next_type(reader, IonType::String, true);
+        assert_eq!(reader.read_str()?, None);
+        Ok(())
+    }
 }
@@ -50,6 +50,20 @@ impl<R: BufRead> TextBuffer<R> {
         self.line_number
     }
 
+    /// Returns the number of bytes at the beginning of the buffer that have been marked as read via
+    /// the [consume] method. Consumed text remains in the buffer (and can be viewed using
+    /// [text_from]) until more text is loaded.
+    pub fn consumed_len(&self) -> usize {
+        self.line_offset
+    }
+
+    /// Returns the text in the buffer beginning at `offset`, including any text that has already
+    /// been consumed. If `offset` is greater than the length of the buffer or does not fall on a
+    /// character boundary, this method will panic.
+    pub fn text_from(&self, offset: usize) -> &str {
+        &self.line[offset..]
+    }
+
     /// Returns [true] if the buffer is empty and the end of the input source has been reached;
     /// otherwise, returns false.
     pub fn is_exhausted(&self) -> bool {
@@ -3,6 +3,7 @@ use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
 use crate::IonType;
+use num_bigint::BigInt;
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) struct AnnotatedTextValue {
@@ -44,6 +45,8 @@ pub(crate) enum TextValue {
     Null(IonType),
     Boolean(bool),
     Integer(i64),
+    // Integers that are out of range for an i64.
+    BigInteger(BigInt),
     Float(f64),
     Decimal(Decimal),
     Timestamp(Timestamp),
@@ -64,7 +67,7 @@ impl TextValue {
         match self {
             TextValue::Null(ion_type) => *ion_type,
             TextValue::Boolean(_) => IonType::Boolean,
-            TextValue::Integer(_) => IonType::Integer,
+            TextValue::Integer(_) | TextValue::BigInteger(_) => IonType::Integer,
             TextValue::Float(_) => IonType::Float,
             TextValue::Decimal(_) => IonType::Decimal,
             TextValue::Timestamp(_) => IonType::Timestamp,
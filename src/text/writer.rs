@@ -1,17 +1,149 @@
 use crate::result::{illegal_operation, IonResult};
-use crate::types::timestamp::{Precision, Timestamp};
+use crate::symbol::Symbol;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
 use crate::IonType;
 use bigdecimal::BigDecimal;
-use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, TimeZone, Timelike};
-use std::convert::TryInto;
+use chrono::{DateTime, FixedOffset};
+use num_bigint::{BigInt, Sign};
 use std::io::{BufWriter, Write};
 
+pub use crate::types::decimal::DecimalNotation;
+
+/// Controls how a [TextWriter] renders integer values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntegerNotation {
+    /// Writes the digits in base 10 (e.g. `255`, `-16`).
+    #[default]
+    Base10,
+    /// Writes the digits in base 16 with a `0x` prefix (e.g. `0xff`, `-0x10`).
+    Hexadecimal,
+    /// Writes the digits in base 2 with a `0b` prefix (e.g. `0b11111111`, `-0b10000`).
+    Binary,
+}
+
+/// Controls the layout of a [TextWriter]'s output. In both formats, each top-level value is
+/// followed by a newline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextFormat {
+    /// Writes each top-level value on a single line with no whitespace between the values
+    /// inside a list or struct (e.g. `{a:1,b:[2,3,],}`).
+    #[default]
+    Compact,
+    /// Writes each value inside a container on its own line, indented two spaces per level
+    /// of nesting, with a space after each field name's colon.
+    Pretty,
+}
+
+/// Configures and constructs a [TextWriter].
+///
+/// ```
+/// use ion_rs::text::writer::{DecimalNotation, TextWriterBuilder};
+/// use ion_rs::types::decimal::Decimal;
+/// # fn main() -> ion_rs::result::IonResult<()> {
+/// let mut output = Vec::new();
+/// let mut writer = TextWriterBuilder::new()
+///     .decimal_notation(DecimalNotation::Canonical)
+///     .build(&mut output);
+/// writer.write_decimal(&Decimal::new(15, -1))?;
+/// writer.flush()?;
+/// drop(writer);
+/// assert_eq!(output, b"15d-1\n");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct TextWriterBuilder {
+    decimal_notation: DecimalNotation,
+    integer_notation: IntegerNotation,
+    format: TextFormat,
+    escape_non_ascii: bool,
+    blob_line_width: Option<usize>,
+    multiline_long_strings: bool,
+}
+
+impl TextWriterBuilder {
+    /// Creates a TextWriterBuilder with the default settings; building it is equivalent to
+    /// calling [TextWriter::new].
+    pub fn new() -> TextWriterBuilder {
+        TextWriterBuilder::default()
+    }
+
+    /// Sets the notation used to write decimal values. The default is [DecimalNotation::Plain].
+    pub fn decimal_notation(mut self, notation: DecimalNotation) -> TextWriterBuilder {
+        self.decimal_notation = notation;
+        self
+    }
+
+    /// Sets the notation used to write integer values. The default is [IntegerNotation::Base10].
+    pub fn integer_notation(mut self, notation: IntegerNotation) -> TextWriterBuilder {
+        self.integer_notation = notation;
+        self
+    }
+
+    /// Sets the layout of the writer's output. The default is [TextFormat::Compact].
+    pub fn format(mut self, format: TextFormat) -> TextWriterBuilder {
+        self.format = format;
+        self
+    }
+
+    /// Sets whether non-ASCII characters in strings and symbols are written as escapes
+    /// (`\u00e9`, or `\U0001f600` outside the Basic Multilingual Plane) so that the output is
+    /// pure ASCII. By default, they are written as UTF-8.
+    pub fn escape_non_ascii(mut self, escape_non_ascii: bool) -> TextWriterBuilder {
+        self.escape_non_ascii = escape_non_ascii;
+        self
+    }
+
+    /// Sets the maximum number of base64 characters written on each line of a blob. Longer blobs
+    /// are split across several lines, which is valid because whitespace inside a blob is
+    /// ignored. By default (or if the width is `None` or zero), each blob is written on one line.
+    pub fn blob_line_width(mut self, width: Option<usize>) -> TextWriterBuilder {
+        self.blob_line_width = width.filter(|width| *width > 0);
+        self
+    }
+
+    /// Sets whether strings that contain a line break are written as long strings
+    /// (`'''...'''`) with the line break intact, rather than as a short string with the line
+    /// break escaped (`"...\n..."`). The default is `false`.
+    pub fn multiline_long_strings(mut self, multiline_long_strings: bool) -> TextWriterBuilder {
+        self.multiline_long_strings = multiline_long_strings;
+        self
+    }
+
+    /// Constructs a TextWriter with this builder's settings that writes values to the provided
+    /// io::Write implementation.
+    pub fn build<W: Write>(self, sink: W) -> TextWriter<W> {
+        let mut writer = TextWriter::new(sink);
+        writer.decimal_notation = self.decimal_notation;
+        writer.integer_notation = self.integer_notation;
+        writer.format = self.format;
+        writer.escape_non_ascii = self.escape_non_ascii;
+        writer.blob_line_width = self.blob_line_width;
+        writer.multiline_long_strings = self.multiline_long_strings;
+        writer
+    }
+}
+
+// A container into which the TextWriter has stepped.
+struct Container {
+    ion_type: IonType,
+    // Whether any values have been written in the container yet.
+    has_values: bool,
+}
+
 pub struct TextWriter<W: Write> {
     output: BufWriter<W>,
-    annotations: Vec<String>,
-    field_name: Option<String>,
-    containers: Vec<IonType>,
+    annotations: Vec<Symbol>,
+    field_name: Option<Symbol>,
+    containers: Vec<Container>,
     string_escape_codes: Vec<String>,
+    decimal_notation: DecimalNotation,
+    integer_notation: IntegerNotation,
+    format: TextFormat,
+    escape_non_ascii: bool,
+    blob_line_width: Option<usize>,
+    multiline_long_strings: bool,
 }
 
 /**
@@ -40,6 +172,94 @@ fn string_escape_code_init() -> Vec<String> {
     return string_escape_codes;
 }
 
+// Writes `text` to `output`, escaping any characters that cannot appear verbatim between a pair
+// of `delimiter`s (`"`, `'` or `'''`) in a text Ion string or symbol, as well as any non-ASCII
+// characters if `escape_non_ascii` is true.
+fn write_escaped_text<W: Write>(
+    output: &mut W,
+    text: &str,
+    delimiter: &str,
+    escape_non_ascii: bool,
+) -> IonResult<()> {
+    let is_long_string = delimiter == "'''";
+    let mut unescaped_start = 0;
+    for (index, c) in text.char_indices() {
+        let escape = match c {
+            '\\' => "\\\\",
+            '\n' if !is_long_string => "\\n",
+            '\n' => continue,
+            '\r' => "\\r",
+            '\t' => "\\t",
+            '"' if delimiter == "\"" => "\\\"",
+            '\'' if delimiter == "'" => "\\'",
+            // A quote in a long string only needs escaping if it could form part of a `'''`,
+            // including the closing delimiter.
+            '\'' if is_long_string
+                && matches!(text[index + 1..].chars().next(), None | Some('\'')) =>
+            {
+                "\\'"
+            }
+            c if c < ' ' || c == '\u{7F}' => "",
+            c if escape_non_ascii && !c.is_ascii() => "",
+            _ => continue,
+        };
+        output.write_all(&text.as_bytes()[unescaped_start..index])?;
+        if !escape.is_empty() {
+            output.write_all(escape.as_bytes())?;
+        } else if c.is_ascii() {
+            write!(output, "\\x{:02x}", c as u32)?;
+        } else if c <= '\u{FFFF}' {
+            write!(output, "\\u{:04x}", c as u32)?;
+        } else {
+            write!(output, "\\U{:08x}", c as u32)?;
+        }
+        unescaped_start = index + c.len_utf8();
+    }
+    output.write_all(&text.as_bytes()[unescaped_start..])?;
+    Ok(())
+}
+
+// Returns true if `text` can be written as an unquoted symbol: it is an identifier that is not
+// a keyword and does not look like a symbol ID (e.g. `$10`).
+pub(crate) fn is_unquoted_symbol(text: &str) -> bool {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    if !chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$') {
+        return false;
+    }
+    let is_keyword = matches!(text, "null" | "true" | "false" | "nan");
+    let is_symbol_id =
+        text.starts_with('$') && text.len() > 1 && text[1..].bytes().all(|b| b.is_ascii_digit());
+    !(is_keyword || is_symbol_id)
+}
+
+// Writes `text` as a quoted symbol.
+pub(crate) fn write_quoted_symbol<W: Write>(output: &mut W, text: &str) -> IonResult<()> {
+    write_quoted_symbol_text(output, text, false)
+}
+
+// Writes `text` as a quoted symbol, escaping any non-ASCII characters if `escape_non_ascii` is
+// true.
+fn write_quoted_symbol_text<W: Write>(
+    output: &mut W,
+    text: &str,
+    escape_non_ascii: bool,
+) -> IonResult<()> {
+    write!(output, "'")?;
+    write_escaped_text(output, text, "'", escape_non_ascii)?;
+    write!(output, "'")?;
+    Ok(())
+}
+
+// Writes a symbol with unknown text as its symbol ID (e.g. `$10`), or as `$0` if it has none.
+fn write_symbol_id<W: Write>(output: &mut W, symbol: &Symbol) -> IonResult<()> {
+    write!(output, "${}", symbol.local_sid().unwrap_or(0))?;
+    Ok(())
+}
+
 impl<W: Write> TextWriter<W> {
     /// Constructs a new instance of TextWriter that writes values to the provided io::Write
     /// implementation.
@@ -50,6 +270,12 @@ impl<W: Write> TextWriter<W> {
             field_name: None,
             containers: vec![],
             string_escape_codes: string_escape_code_init(),
+            decimal_notation: DecimalNotation::default(),
+            integer_notation: IntegerNotation::default(),
+            format: TextFormat::default(),
+            escape_non_ascii: false,
+            blob_line_width: None,
+            multiline_long_strings: false,
         }
     }
 
@@ -73,15 +299,91 @@ impl<W: Write> TextWriter<W> {
 
     /// Sets the current field name to `name`. If the TextWriter is currently positioned inside
     /// of a struct, the field name will be written before the next value. Otherwise, it will be
-    /// ignored.
-    pub fn set_field_name(&mut self, name: &str) {
-        self.field_name = Some(name.to_string());
+    /// ignored. Returns the writer so that the value can be written in the same expression, as in
+    /// `writer.set_field_name("id").write_i64(5)`.
+    pub fn set_field_name(&mut self, name: &str) -> &mut Self {
+        self.field_name = Some(Symbol::with_text(name));
+        self
+    }
+
+    /// Sets the current field name to `symbol`, like [set_field_name](Self::set_field_name). A
+    /// symbol with unknown text is written as its symbol ID (e.g. `$10`), or as `$0` if it has
+    /// none, so that it can be resolved by a reader with the same symbol tables in effect.
+    pub fn set_field_symbol(&mut self, symbol: &Symbol) -> &mut Self {
+        self.field_name = Some(symbol.clone());
+        self
     }
 
     /// Sets a list of annotations that will be applied to the next value that is written.
-    pub fn set_annotations(&mut self, annotations: &[&str]) {
-        self.annotations
-            .extend(annotations.iter().map(|s| s.to_string()));
+    /// Like [set_field_name](Self::set_field_name), returns the writer so that calls can be
+    /// chained.
+    pub fn set_annotations<I, A>(&mut self, annotations: I) -> &mut Self
+    where
+        I: IntoIterator<Item = A>,
+        A: AsRef<str>,
+    {
+        self.annotations.extend(
+            annotations
+                .into_iter()
+                .map(|annotation| Symbol::with_text(annotation.as_ref())),
+        );
+        self
+    }
+
+    /// Adds `symbols` to the annotations that will be applied to the next value that is written.
+    /// Symbols with unknown text are written as they are by
+    /// [set_field_symbol](Self::set_field_symbol).
+    pub fn set_annotation_symbols(&mut self, symbols: &[Symbol]) -> &mut Self {
+        self.annotations.extend(symbols.iter().cloned());
+        self
+    }
+
+    /// Writes a struct, calling `write_fields` to write its fields and then stepping out.
+    /// For example:
+    ///
+    /// ```
+    /// # use ion_rs::text::writer::TextWriter;
+    /// # use ion_rs::result::IonResult;
+    /// # fn main() -> IonResult<()> {
+    /// let mut buffer = Vec::new();
+    /// let mut writer = TextWriter::new(&mut buffer);
+    /// writer.set_annotations(["point"]).write_struct(|s| {
+    ///     s.set_field_name("x").write_i64(1)?;
+    ///     s.set_field_name("y").write_i64(2)
+    /// })?;
+    /// writer.flush()?;
+    /// drop(writer);
+    /// assert_eq!(buffer, b"'point'::{x:1,y:2,}\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_struct<F>(&mut self, write_fields: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::Struct)?;
+        write_fields(self)?;
+        self.step_out()
+    }
+
+    /// Writes a list, calling `write_values` to write its values and then stepping out.
+    pub fn write_list<F>(&mut self, write_values: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::List)?;
+        write_values(self)?;
+        self.step_out()
+    }
+
+    /// Writes an s-expression, calling `write_values` to write its values and then stepping out.
+    pub fn write_sexp<F>(&mut self, write_values: F) -> IonResult<()>
+    where
+        F: FnOnce(&mut Self) -> IonResult<()>,
+    {
+        self.step_in(IonType::SExpression)?;
+        write_values(self)?;
+        self.step_out()
     }
 
     /// Begins a container (List, S-Expression, or Struct). If `ion_type` is not a container type,
@@ -95,13 +397,20 @@ impl<W: Write> TextWriter<W> {
             SExpression => write!(self.output, "(")?,
             _ => return illegal_operation(format!("Cannot step into a(n) {:?}", ion_type)),
         }
-        self.containers.push(ion_type);
+        self.containers.push(Container {
+            ion_type,
+            has_values: false,
+        });
         Ok(())
     }
 
     /// Returns true if the TextWriter is currently positioned within a Struct.
     pub fn is_in_struct(&self) -> bool {
-        if let Some(IonType::Struct) = self.containers.last() {
+        if let Some(Container {
+            ion_type: IonType::Struct,
+            ..
+        }) = self.containers.last()
+        {
             return true;
         }
         false
@@ -111,13 +420,19 @@ impl<W: Write> TextWriter<W> {
     // container, `step_out` will return an Err(IllegalOperation).
     pub fn step_out(&mut self) -> IonResult<()> {
         use IonType::*;
-        let end_delimiter = match self.containers.pop() {
-            Some(Struct) => "}",
-            Some(List) => "]",
-            Some(SExpression) => ")",
-            Some(scalar) => unreachable!("Inside a non-container type: {:?}", scalar),
+        let container = match self.containers.pop() {
+            Some(container) => container,
             None => return illegal_operation("Cannot step out of the top level."),
         };
+        let end_delimiter = match container.ion_type {
+            Struct => "}",
+            List => "]",
+            SExpression => ")",
+            scalar => unreachable!("Inside a non-container type: {:?}", scalar),
+        };
+        if self.format == TextFormat::Pretty && container.has_values {
+            self.write_newline_and_indentation()?;
+        }
         write!(self.output, "{}", end_delimiter)?;
         self.write_value_delimiter()?;
         Ok(())
@@ -126,7 +441,9 @@ impl<W: Write> TextWriter<W> {
     // Called after each value is written to emit an appropriate delimiter before the next value.
     fn write_value_delimiter(&mut self) -> IonResult<()> {
         use IonType::*;
-        let delimiter = match self.containers.last() {
+        let delimiter = match self.containers.last().map(|c| c.ion_type) {
+            // In pretty mode, delimiters are written before the next value instead
+            Some(_) if self.format == TextFormat::Pretty => "",
             Some(Struct) | Some(List) => ",",
             Some(SExpression) => " ",
             Some(scalar) => unreachable!("Inside a non-container type: {:?}", scalar),
@@ -136,16 +453,52 @@ impl<W: Write> TextWriter<W> {
         Ok(())
     }
 
+    // Writes a newline followed by two spaces of indentation for each level of nesting.
+    fn write_newline_and_indentation(&mut self) -> IonResult<()> {
+        writeln!(self.output)?;
+        for _ in 0..self.containers.len() {
+            write!(self.output, "  ")?;
+        }
+        Ok(())
+    }
+
     // Write the field name and annotations if set
     fn write_value_metadata(&mut self) -> IonResult<()> {
-        if let Some(field_name) = &self.field_name.take() {
-            write!(self.output, "{}:", field_name)?;
-        } else if self.is_in_struct() {
+        let field_name = self.field_name.take();
+        if field_name.is_none() && self.is_in_struct() {
             return illegal_operation(format!("Values inside a struct must have a field name."));
         }
+        if let Some(container) = self.containers.last_mut() {
+            let is_first_value = !container.has_values;
+            container.has_values = true;
+            if self.format == TextFormat::Pretty {
+                if !is_first_value && container.ion_type != IonType::SExpression {
+                    write!(self.output, ",")?;
+                }
+                self.write_newline_and_indentation()?;
+            }
+        }
+        let escape_non_ascii = self.escape_non_ascii;
+        if let Some(field_name) = &field_name {
+            match field_name.text() {
+                Some(text) if is_unquoted_symbol(text) => write!(self.output, "{}", text)?,
+                Some(text) => write_quoted_symbol_text(&mut self.output, text, escape_non_ascii)?,
+                None => write_symbol_id(&mut self.output, field_name)?,
+            }
+            match self.format {
+                TextFormat::Compact => write!(self.output, ":")?,
+                TextFormat::Pretty => write!(self.output, ": ")?,
+            }
+        }
         if !self.annotations.is_empty() {
             for annotation in &self.annotations {
-                write!(self.output, "'{}'::", annotation)?;
+                match annotation.text() {
+                    Some(text) => {
+                        write_quoted_symbol_text(&mut self.output, text, escape_non_ascii)?
+                    }
+                    None => write_symbol_id(&mut self.output, annotation)?,
+                }
+                write!(self.output, "::")?;
             }
             self.annotations.clear();
         }
@@ -203,10 +556,34 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
-    /// Writes the provided i64 value as an Ion integer.
+    /// Writes the provided i64 value as an Ion integer using the writer's [IntegerNotation].
     pub fn write_i64(&mut self, value: i64) -> IonResult<()> {
+        let notation = self.integer_notation;
         self.write_scalar(|output| {
-            write!(output, "{}", value)?;
+            // The hexadecimal and binary formatters write negative numbers in two's complement,
+            // so the sign and the magnitude are written separately.
+            let sign = if value < 0 { "-" } else { "" };
+            let magnitude = value.unsigned_abs();
+            match notation {
+                IntegerNotation::Base10 => write!(output, "{}", value)?,
+                IntegerNotation::Hexadecimal => write!(output, "{}{:#x}", sign, magnitude)?,
+                IntegerNotation::Binary => write!(output, "{}{:#b}", sign, magnitude)?,
+            }
+            Ok(())
+        })
+    }
+
+    /// Writes the provided BigInt value as an Ion integer using the writer's [IntegerNotation].
+    pub fn write_big_int(&mut self, value: &BigInt) -> IonResult<()> {
+        let notation = self.integer_notation;
+        self.write_scalar(|output| {
+            let sign = if value.sign() == Sign::Minus { "-" } else { "" };
+            let magnitude = value.magnitude();
+            match notation {
+                IntegerNotation::Base10 => write!(output, "{}", value)?,
+                IntegerNotation::Hexadecimal => write!(output, "{}{:#x}", sign, magnitude)?,
+                IntegerNotation::Binary => write!(output, "{}{:#b}", sign, magnitude)?,
+            }
             Ok(())
         })
     }
@@ -241,96 +618,25 @@ impl<W: Write> TextWriter<W> {
         })
     }
 
-    /// Writes the provided BigDecimal value as an Ion decimal.
-    pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+    /// Writes the provided Decimal value as an Ion decimal using the writer's [DecimalNotation].
+    pub fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        let notation = self.decimal_notation;
         self.write_scalar(|output| {
-            write!(output, "{}", &value)?;
+            write!(output, "{}", value.display_with(notation))?;
             Ok(())
         })
     }
 
-    // Helper method for [write_timestamp]. Writes the timestamp to output using +/-HH:MM format.
-    fn write_offset(output: &mut BufWriter<W>, offset_minutes: Option<i32>) -> IonResult<()> {
-        if offset_minutes.is_none() {
-            write!(output, "-00:00")?;
-            return Ok(());
-        }
-        let offset_minutes = offset_minutes.unwrap();
-
-        const MINUTES_PER_HOUR: i32 = 60;
-        // Split the offset into a sign and magnitude for formatting
-        let sign = if offset_minutes >= 0 { "+" } else { "-" };
-        let offset_minutes = offset_minutes.abs();
-        let hours = offset_minutes / MINUTES_PER_HOUR;
-        let minutes = offset_minutes % MINUTES_PER_HOUR;
-        write!(output, "{}{:0>2}:{:0>2}", sign, hours, minutes)?;
-        Ok(())
+    /// Writes the provided BigDecimal value as an Ion decimal using the writer's
+    /// [DecimalNotation].
+    pub fn write_big_decimal(&mut self, value: &BigDecimal) -> IonResult<()> {
+        self.write_decimal(&Decimal::from(value.clone()))
     }
 
     /// Writes the provided Timestamp as an Ion timestamp.
     pub fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
-        // A space to format the offset
         self.write_scalar(|output| {
-            let (offset_minutes, datetime) = if let Some(minutes) = value.offset {
-                // Create a datetime with the appropriate offset that we can use for formatting.
-                let datetime: DateTime<FixedOffset> = value.clone().try_into()?;
-                // Convert the offset to minutes --v
-                (Some(minutes.local_minus_utc() / 60), datetime)
-            } else {
-                // Our timestamp has an unknown offset. Per the spec, this means it makes no
-                // assertions about *where* it was recorded, but its fields are still in UTC.
-                // Create a UTC datetime that we can use for formatting.
-                let datetime: NaiveDateTime = value.clone().try_into()?;
-                let datetime: DateTime<FixedOffset> =
-                    FixedOffset::east(0).from_utc_datetime(&datetime);
-                (None, datetime)
-            };
-
-            write!(output, "{:0>4}", datetime.year())?;
-            //                     ^-- 0-padded, right aligned, 4-digit year
-            if value.precision == Precision::Year {
-                write!(output, "T")?;
-                return Ok(());
-            }
-
-            write!(output, "-{:0>2}", datetime.month())?;
-            //                   ^-- delimiting hyphen and 0-padded, right aligned, 2-digit month
-            if value.precision == Precision::Month {
-                write!(output, "T")?;
-                return Ok(());
-            }
-
-            write!(output, "-{:0>2}", datetime.day())?;
-            //                   ^-- delimiting hyphen and 0-padded, right aligned, 2-digit day
-            if value.precision == Precision::Day {
-                write!(output, "T")?;
-                return Ok(());
-            }
-
-            write!(output, "T{:0>2}:{:0>2}", datetime.hour(), datetime.minute())?;
-            //                   ^-- delimiting T, formatted hour, delimiting colon, formatted minute
-            if value.precision == Precision::HourAndMinute {
-                Self::write_offset(output, offset_minutes)?;
-                return Ok(());
-            }
-
-            write!(output, ":{:0>2}", datetime.second())?;
-            //                   ^-- delimiting colon, formatted second
-            if value.precision == Precision::Second {
-                Self::write_offset(output, offset_minutes)?;
-                return Ok(());
-            }
-
-            // We want the fractional seconds to show as many decimal places as are interesting.
-            // Here we format the string as nanoseconds and then eliminate any trailing zeros.
-            //TODO: The initial conversion to datetime will discard precision greater than nanoseconds.
-            //TODO: Eliminate this allocation.
-            let fractional_text = format!("{:0>9}", datetime.nanosecond());
-            let fractional_text = fractional_text.trim_end_matches("0");
-
-            write!(output, ".{}", fractional_text)?;
-            //               ^-- delimiting decimal point, formatted fractional seconds
-            Self::write_offset(output, offset_minutes)?;
+            write!(output, "{}", value)?;
             Ok(())
         })
     }
@@ -349,30 +655,79 @@ impl<W: Write> TextWriter<W> {
 
     /// Writes the provided &str value as an Ion symbol.
     pub fn write_symbol<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let escape_non_ascii = self.escape_non_ascii;
         self.write_scalar(|output| {
-            write!(output, "'{}'", value.as_ref())?;
-            Ok(())
+            write_quoted_symbol_text(output, value.as_ref(), escape_non_ascii)
+        })
+    }
+
+    /// Writes `symbol` as an Ion symbol. A symbol with unknown text is written as it is by
+    /// [set_field_symbol](Self::set_field_symbol).
+    pub fn write_symbol_value(&mut self, symbol: &Symbol) -> IonResult<()> {
+        let escape_non_ascii = self.escape_non_ascii;
+        self.write_scalar(|output| match symbol.text() {
+            Some(text) => write_quoted_symbol_text(output, text, escape_non_ascii),
+            None => write_symbol_id(output, symbol),
         })
     }
 
-    /// Writes the provided &str value as an Ion string.
+    /// Writes the provided &str value as an Ion string. If the writer was configured with
+    /// [multiline_long_strings](TextWriterBuilder::multiline_long_strings), text containing a
+    /// line break is written as a long string.
     pub fn write_string<S: AsRef<str>>(&mut self, value: S) -> IonResult<()> {
+        let escape_non_ascii = self.escape_non_ascii;
+        let text = value.as_ref();
+        let delimiter = if self.multiline_long_strings && text.contains('\n') {
+            "'''"
+        } else {
+            "\""
+        };
         self.write_scalar(|output| {
-            write!(output, "\"{}\"", value.as_ref())?;
+            write!(output, "{}", delimiter)?;
+            write_escaped_text(output, text, delimiter, escape_non_ascii)?;
+            write!(output, "{}", delimiter)?;
             Ok(())
         })
     }
 
-    /// Writes the provided byte array slice as an Ion blob.
+    /// Writes the provided byte array slice as an Ion blob, wrapping its base64 text at the
+    /// writer's [blob_line_width](TextWriterBuilder::blob_line_width), if any.
     pub fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        let line_width = self.blob_line_width;
+        // In pretty mode, continuation lines are indented one level deeper than the blob.
+        let indentation = match self.format {
+            TextFormat::Compact => 0,
+            TextFormat::Pretty => self.containers.len() + 1,
+        };
         self.write_scalar(|output| {
-            // Rust format strings escape curly braces by doubling them. The following string is:
-            // * The opening {{ from a text Ion blob, with each brace doubled to escape it.
-            // * A {} pair used by the format string to indicate where the base64-encoded bytes
-            //   should be inserted.
-            // * The closing }} from a text Ion blob, with each brace doubled to escape it.
             // TODO: Provide a re-usable encoding buffer instead of allocating a String each time.
-            write!(output, "{{{{{}}}}}", base64::encode(value))?;
+            let base64_text = base64::encode(value);
+            let line_width = match line_width {
+                Some(width) if base64_text.len() > width => width,
+                _ => {
+                    // Rust format strings escape curly braces by doubling them. The following
+                    // string is:
+                    // * The opening {{ from a text Ion blob, with each brace doubled to escape it.
+                    // * A {} pair used by the format string to indicate where the base64-encoded
+                    //   bytes should be inserted.
+                    // * The closing }} from a text Ion blob, with each brace doubled to escape it.
+                    write!(output, "{{{{{}}}}}", base64_text)?;
+                    return Ok(());
+                }
+            };
+            write!(output, "{{{{")?;
+            for line in base64_text.as_bytes().chunks(line_width) {
+                writeln!(output)?;
+                for _ in 0..indentation {
+                    write!(output, "  ")?;
+                }
+                output.write_all(line)?;
+            }
+            writeln!(output)?;
+            for _ in 0..indentation.saturating_sub(1) {
+                write!(output, "  ")?;
+            }
+            write!(output, "}}}}")?;
             Ok(())
         })
     }
@@ -405,12 +760,19 @@ impl<W: Write> TextWriter<W> {
 
 #[cfg(test)]
 mod tests {
+    use crate::raw_reader::{RawReader, StreamItem};
     use crate::result::IonResult;
-    use crate::text::writer::TextWriter;
+    use crate::text::raw_text_reader::RawTextReader;
+    use crate::text::writer::{
+        DecimalNotation, IntegerNotation, TextFormat, TextWriter, TextWriterBuilder,
+    };
+    use crate::types::decimal::Decimal;
     use crate::types::timestamp::Timestamp;
     use crate::IonType;
     use bigdecimal::BigDecimal;
     use chrono::{FixedOffset, NaiveDate, TimeZone};
+    use num_bigint::BigInt;
+    use rstest::*;
     use std::str;
     use std::str::FromStr;
 
@@ -455,6 +817,100 @@ mod tests {
         writer_test(|w| w.write_f64(700f64), "7e2\n");
     }
 
+    #[test]
+    fn write_special_f64s() {
+        writer_test(
+            |w| {
+                w.write_f64(f64::NAN)?;
+                w.write_f64(f64::INFINITY)?;
+                w.write_f64(f64::NEG_INFINITY)
+            },
+            "nan\n+inf\n-inf\n",
+        );
+    }
+
+    #[test]
+    fn special_f64s_round_trip() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output);
+        writer.write_f64(f64::NAN)?;
+        writer.write_f64(f64::INFINITY)?;
+        writer.write_f64(f64::NEG_INFINITY)?;
+        drop(writer);
+
+        let text = str::from_utf8(&output).unwrap();
+        let mut reader = RawTextReader::new(text);
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::Float, false))
+        );
+        assert!(reader.read_f64()?.unwrap().is_nan());
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::Float, false))
+        );
+        assert_eq!(reader.read_f64()?, Some(f64::INFINITY));
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::Float, false))
+        );
+        assert_eq!(reader.read_f64()?, Some(f64::NEG_INFINITY));
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn negative_zero_f64_round_trips() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = TextWriter::new(&mut output);
+        writer.write_f64(-0f64)?;
+        drop(writer);
+        assert_eq!(output, b"-0e0\n");
+
+        let mut reader = RawTextReader::new(str::from_utf8(&output).unwrap());
+        reader.next()?;
+        let value = reader.read_f64()?.unwrap();
+        assert!(value == 0f64 && value.is_sign_negative());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::zero(0, "0", "0x0", "0b0")]
+    #[case::positive(255, "255", "0xff", "0b11111111")]
+    #[case::negative(-16, "-16", "-0x10", "-0b10000")]
+    #[case::min(i64::MIN, "-9223372036854775808", "-0x8000000000000000", &format!("-0b1{}", "0".repeat(63)))]
+    fn write_integer_notations(
+        #[case] value: i64,
+        #[case] base_10: &str,
+        #[case] hexadecimal: &str,
+        #[case] binary: &str,
+    ) -> IonResult<()> {
+        use IntegerNotation::*;
+        for (notation, expected) in &[
+            (Base10, base_10),
+            (Hexadecimal, hexadecimal),
+            (Binary, binary),
+        ] {
+            let mut output = Vec::new();
+            let mut writer = TextWriterBuilder::new()
+                .integer_notation(*notation)
+                .build(&mut output);
+            writer.write_i64(value)?;
+            writer.write_big_int(&BigInt::from(value))?;
+            drop(writer);
+            let text = String::from_utf8(output).unwrap();
+            assert_eq!(text, format!("{}\n{}\n", expected, expected));
+
+            let mut reader = RawTextReader::new(text.as_str());
+            assert_eq!(
+                reader.next()?,
+                Some(StreamItem::Value(IonType::Integer, false))
+            );
+            assert_eq!(reader.read_i64()?, Some(value));
+        }
+        Ok(())
+    }
+
     #[test]
     fn write_annotated_i64() {
         writer_test(
@@ -475,6 +931,59 @@ mod tests {
         );
     }
 
+    fn write_decimal_with_notation(notation: DecimalNotation, decimal: &Decimal) -> String {
+        let mut output = Vec::new();
+        let mut writer = TextWriterBuilder::new()
+            .decimal_notation(notation)
+            .build(&mut output);
+        writer.write_decimal(decimal).unwrap();
+        drop(writer);
+        String::from_utf8(output).unwrap()
+    }
+
+    #[rstest]
+    #[case::fraction(Decimal::new(15, -1), "1.5", "1.5d0", "15d-1")]
+    #[case::zero_exponent(Decimal::new(12, 0), "12.", "1.2d1", "12d0")]
+    #[case::trailing_zeros(Decimal::new(1500, -3), "1.500", "1.500d0", "1500d-3")]
+    #[case::leading_zeros(Decimal::new(125, -6), "0.000125", "1.25d-4", "125d-6")]
+    #[case::too_many_leading_zeros(Decimal::new(5, -10), "5d-10", "5d-10", "5d-10")]
+    #[case::positive_exponent(Decimal::new(15, 1), "15d1", "1.5d2", "15d1")]
+    #[case::negative(Decimal::new(-305, -1), "-30.5", "-3.05d1", "-305d-1")]
+    #[case::zero(Decimal::new(0, -2), "0.00", "0d-2", "0d-2")]
+    #[case::negative_zero(Decimal::negative_zero(), "-0.", "-0d0", "-0d0")]
+    fn write_decimal_notations(
+        #[case] decimal: Decimal,
+        #[case] plain: &str,
+        #[case] exponential: &str,
+        #[case] canonical: &str,
+    ) -> IonResult<()> {
+        use DecimalNotation::*;
+        for (notation, expected) in &[
+            (Plain, plain),
+            (Exponential, exponential),
+            (Canonical, canonical),
+        ] {
+            let text = write_decimal_with_notation(*notation, &decimal);
+            assert_eq!(text, format!("{}\n", expected));
+
+            // Reading the text back should produce the same coefficient and exponent
+            let mut reader = RawTextReader::new(text.as_str());
+            assert_eq!(
+                reader.next()?,
+                Some(StreamItem::Value(IonType::Decimal, false))
+            );
+            let read_back = reader.read_decimal()?.unwrap();
+            assert!(
+                read_back.ion_eq(&decimal),
+                "{:?} was written as {} and read back as {:?}",
+                decimal,
+                expected,
+                read_back
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn write_datetime_epoch() {
         #![allow(deprecated)] // `write_datetime` is deprecated
@@ -580,6 +1089,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn write_clob_with_embedded_nulls_and_high_bytes() -> IonResult<()> {
+        let clob: &[u8] = &[0x00, b'a', 0x00, 0x01, 0x7F, 0x80, 0xFF, 0x00];
+        let expected = "{{\"\\0a\\0\\x01\\x7f\\x80\\xff\\0\"}}\n";
+        writer_test(|w| w.write_clob(clob), expected);
+
+        // Reading the escaped text back should produce exactly the bytes we started with.
+        let mut reader = RawTextReader::new(expected);
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::Clob, false))
+        );
+        assert_eq!(reader.read_clob_bytes()?, Some(clob.to_vec()));
+        Ok(())
+    }
+
     #[test]
     fn write_list() {
         writer_test(
@@ -641,4 +1166,221 @@ mod tests {
             "{a:\"foo\",b:21,c:'qux'::'bar',}\n",
         );
     }
+
+    #[test]
+    fn write_escaped_strings_and_symbols() -> IonResult<()> {
+        let text = "a\"b'c\\d\n\u{1}\u{7f}é";
+        let expected = "\"a\\\"b'c\\\\d\\n\\x01\\x7fé\"\n'a\"b\\'c\\\\d\\n\\x01\\x7fé'\n";
+        writer_test(
+            |w| {
+                w.write_string(text)?;
+                w.write_symbol(text)
+            },
+            expected,
+        );
+
+        // Reading the escaped text back should produce the original text.
+        let mut reader = RawTextReader::new(expected);
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::String, false))
+        );
+        assert_eq!(reader.read_string()?, Some(text.to_string()));
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::Symbol, false))
+        );
+        assert_eq!(
+            reader
+                .read_symbol()?
+                .and_then(|s| s.text().map(|t| t.to_string())),
+            Some(text.to_string())
+        );
+        Ok(())
+    }
+
+    fn write_with_builder<F>(builder: TextWriterBuilder, commands: F) -> IonResult<String>
+    where
+        F: FnOnce(&mut TextWriter<&mut Vec<u8>>) -> IonResult<()>,
+    {
+        let mut output = Vec::new();
+        let mut writer = builder.build(&mut output);
+        commands(&mut writer)?;
+        drop(writer);
+        Ok(String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn write_non_ascii_escapes() -> IonResult<()> {
+        let text = "café 😀";
+        let builder = TextWriterBuilder::new().escape_non_ascii(true);
+        let output = write_with_builder(builder, |w| {
+            w.set_annotations(&[text]);
+            w.step_in(IonType::Struct)?;
+            w.set_field_name(text);
+            w.write_string(text)?;
+            w.step_out()?;
+            w.write_symbol(text)
+        })?;
+        let escaped = r"caf\u00e9 \U0001f600";
+        let expected = format!("'{0}'::{{'{0}':\"{0}\",}}\n'{0}'\n", escaped);
+        assert_eq!(output, expected);
+
+        let mut reader = RawTextReader::new(output.as_str());
+        reader.next()?;
+        reader.step_in()?;
+        reader.next()?;
+        assert_eq!(reader.read_string()?, Some(text.to_string()));
+        reader.step_out()?;
+        reader.next()?;
+        assert_eq!(
+            reader
+                .read_symbol()?
+                .and_then(|s| s.text().map(|t| t.to_string())),
+            Some(text.to_string())
+        );
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::one_line("one line", "\"one line\"\n")]
+    #[case::multiline("one\ntwo\n", "'''one\ntwo\n'''\n")]
+    #[case::quotes("it's\n''' ''", "'''it's\n\\'\\'' \\'\\''''\n")]
+    fn write_multiline_long_strings(#[case] text: &str, #[case] expected: &str) -> IonResult<()> {
+        let builder = TextWriterBuilder::new().multiline_long_strings(true);
+        let output = write_with_builder(builder, |w| w.write_string(text))?;
+        assert_eq!(output, expected);
+
+        let mut reader = RawTextReader::new(output.as_str());
+        reader.next()?;
+        assert_eq!(reader.read_string()?, Some(text.to_string()));
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::unwrapped(TextFormat::Compact, None, "{{aGVsbG8gd29ybGQ=}}\n")]
+    #[case::wider_than_blob(TextFormat::Compact, Some(16), "{{aGVsbG8gd29ybGQ=}}\n")]
+    #[case::wrapped(TextFormat::Compact, Some(8), "[{{\naGVsbG8g\nd29ybGQ=\n}},]\n")]
+    #[case::wrapped_pretty(
+        TextFormat::Pretty,
+        Some(8),
+        "[\n  {{\n    aGVsbG8g\n    d29ybGQ=\n  }}\n]\n"
+    )]
+    fn write_blob_line_width(
+        #[case] format: TextFormat,
+        #[case] width: Option<usize>,
+        #[case] expected: &str,
+    ) -> IonResult<()> {
+        let value = b"hello world";
+        let builder = TextWriterBuilder::new()
+            .format(format)
+            .blob_line_width(width);
+        let output = write_with_builder(builder, |w| {
+            if width == Some(8) {
+                w.write_list(|w| w.write_blob(value))
+            } else {
+                w.write_blob(value)
+            }
+        })?;
+        assert_eq!(output, expected);
+
+        let mut reader = RawTextReader::new(output.as_str());
+        if reader.next()? == Some(StreamItem::Value(IonType::List, false)) {
+            reader.step_in()?;
+            reader.next()?;
+        }
+        assert_eq!(reader.read_blob_bytes()?, Some(value.to_vec()));
+        Ok(())
+    }
+
+    #[test]
+    fn write_field_names_that_need_quotes() {
+        writer_test(
+            |w| {
+                w.step_in(IonType::Struct)?;
+                for field_name in &["ok", "_$9", "a b", "null", "$10", "", "it's"] {
+                    w.set_field_name(field_name);
+                    w.set_annotations(&["x y"]);
+                    w.write_i64(1)?;
+                }
+                w.step_out()
+            },
+            "{ok:'x y'::1,_$9:'x y'::1,'a b':'x y'::1,'null':'x y'::1,'$10':'x y'::1,'':'x y'::1,\
+            'it\\'s':'x y'::1,}\n",
+        );
+    }
+
+    #[test]
+    fn write_with_chained_setters_and_closures() {
+        writer_test(
+            |w| {
+                w.set_annotations(["a", "b"]).write_struct(|s| {
+                    s.set_field_name("c").write_bool(true)?;
+                    s.set_field_name("d").write_list(|l| {
+                        l.write_i64(1)?;
+                        l.write_sexp(|e| e.write_symbol("e"))
+                    })
+                })?;
+                w.set_annotations(&vec!["f".to_string()]).write_i64(2)
+            },
+            "'a'::'b'::{c:true,d:[1,('e' ),],}\n'f'::2\n",
+        );
+    }
+
+    #[test]
+    fn write_pretty() -> IonResult<()> {
+        let mut output = Vec::new();
+        let mut writer = TextWriterBuilder::new()
+            .format(TextFormat::Pretty)
+            .build(&mut output);
+        writer.set_annotations(&["a"]);
+        writer.step_in(IonType::Struct)?;
+        writer.set_field_name("b");
+        writer.write_string("foo")?;
+        writer.set_field_name("c");
+        writer.step_in(IonType::List)?;
+        writer.write_i64(1)?;
+        writer.step_in(IonType::SExpression)?;
+        writer.write_symbol("d")?;
+        writer.write_symbol("e")?;
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.set_field_name("f");
+        writer.step_in(IonType::Struct)?;
+        writer.step_out()?;
+        writer.step_out()?;
+        writer.write_i64(2)?;
+        writer.flush()?;
+        drop(writer);
+
+        let expected = "\
+'a'::{
+  b: \"foo\",
+  c: [
+    1,
+    (
+      'd'
+      'e'
+    )
+  ],
+  f: {}
+}
+2
+";
+        let text = str::from_utf8(&output).unwrap();
+        assert_eq!(text, expected);
+
+        // The pretty-printed text contains the same values as the compact text
+        let mut reader = RawTextReader::new(text);
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::Struct, false))
+        );
+        reader.step_in()?;
+        assert_eq!(
+            reader.next()?,
+            Some(StreamItem::Value(IonType::String, false))
+        );
+        Ok(())
+    }
 }
@@ -0,0 +1,337 @@
+//! Copies Ion values from a [Reader] to a writer one event at a time, without reading them into
+//! [OwnedElement](crate::value::owned::OwnedElement)s first.
+//!
+//! Because only the value being copied (and the containers it is nested in) is held in memory,
+//! [transcode] can convert arbitrarily large streams between binary and text Ion in constant
+//! memory:
+//!
+//! ```
+//! use ion_rs::binary::writer::BinaryWriter;
+//! use ion_rs::transcode::transcode;
+//! use ion_rs::value::owned::OwnedElement;
+//! use ion_rs::Reader;
+//! # fn main() -> ion_rs::result::IonResult<()> {
+//!
+//! let text = "point::{x: 1, y: 2.5e0} [a, \"b\", {{YmxvYg==}}]";
+//! let mut binary = Vec::new();
+//! let mut writer = BinaryWriter::new(&mut binary);
+//! transcode(&mut Reader::from_input(text.as_bytes())?, &mut writer)?;
+//! writer.flush()?;
+//! drop(writer);
+//! assert_eq!(
+//!     OwnedElement::read_all(&binary)?,
+//!     OwnedElement::read_all(text.as_bytes())?
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use crate::binary::writer::BinaryWriter;
+use crate::result::{illegal_operation, IonResult};
+use crate::text::writer::TextWriter;
+use crate::types::decimal::Decimal;
+use crate::types::float::Float;
+use crate::types::integer::Integer;
+use crate::types::timestamp::Timestamp;
+use crate::{IonType, RawReader, Reader, Symbol};
+
+/// The writer methods that [transcode] needs, which are implemented by both the
+/// [TextWriter] and the [BinaryWriter].
+pub trait ValueWriter {
+    fn set_annotations(&mut self, annotations: &[Symbol]);
+    fn set_field_name(&mut self, name: &Symbol);
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()>;
+    fn write_bool(&mut self, value: bool) -> IonResult<()>;
+    fn write_integer(&mut self, value: &Integer) -> IonResult<()>;
+    fn write_float(&mut self, value: Float) -> IonResult<()>;
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()>;
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()>;
+    fn write_symbol(&mut self, value: &Symbol) -> IonResult<()>;
+    fn write_string(&mut self, text: &str) -> IonResult<()>;
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()>;
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()>;
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()>;
+    fn step_out(&mut self) -> IonResult<()>;
+}
+
+/// Symbols with unknown text are written as their symbol IDs (e.g. `$10`). See
+/// [TextWriter::set_field_symbol].
+impl<W: Write> ValueWriter for TextWriter<W> {
+    fn set_annotations(&mut self, annotations: &[Symbol]) {
+        TextWriter::set_annotation_symbols(self, annotations);
+    }
+
+    fn set_field_name(&mut self, name: &Symbol) {
+        TextWriter::set_field_symbol(self, name);
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        TextWriter::write_null(self, ion_type)
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        TextWriter::write_bool(self, value)
+    }
+
+    fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+        match value {
+            Integer::I64(value) => TextWriter::write_i64(self, *value),
+            Integer::BigInt(value) => TextWriter::write_big_int(self, value),
+        }
+    }
+
+    fn write_float(&mut self, value: Float) -> IonResult<()> {
+        // Text has no notion of float width
+        TextWriter::write_f64(self, value.as_f64())
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        TextWriter::write_decimal(self, value)
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        TextWriter::write_timestamp(self, value)
+    }
+
+    fn write_symbol(&mut self, value: &Symbol) -> IonResult<()> {
+        TextWriter::write_symbol_value(self, value)
+    }
+
+    fn write_string(&mut self, text: &str) -> IonResult<()> {
+        TextWriter::write_string(self, text)
+    }
+
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        TextWriter::write_clob(self, value)
+    }
+
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        TextWriter::write_blob(self, value)
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+        TextWriter::step_in(self, ion_type)
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        TextWriter::step_out(self)
+    }
+}
+
+/// Symbols with unknown text are written as `$0`, since their symbol IDs would refer to the
+/// writer's symbol table rather than the reader's. See [BinaryWriter::set_field_symbol].
+impl<W: Write> ValueWriter for BinaryWriter<W> {
+    fn set_annotations(&mut self, annotations: &[Symbol]) {
+        BinaryWriter::set_annotation_symbols(self, annotations);
+    }
+
+    fn set_field_name(&mut self, name: &Symbol) {
+        BinaryWriter::set_field_symbol(self, name);
+    }
+
+    fn write_null(&mut self, ion_type: IonType) -> IonResult<()> {
+        BinaryWriter::write_null(self, ion_type)
+    }
+
+    fn write_bool(&mut self, value: bool) -> IonResult<()> {
+        BinaryWriter::write_bool(self, value)
+    }
+
+    fn write_integer(&mut self, value: &Integer) -> IonResult<()> {
+        match value {
+            Integer::I64(value) => BinaryWriter::write_i64(self, *value),
+            Integer::BigInt(value) => illegal_operation(format!(
+                "The binary writer does not support integers that do not fit in an i64: {}",
+                value
+            )),
+        }
+    }
+
+    fn write_float(&mut self, value: Float) -> IonResult<()> {
+        // Preserve the width of floats that were read from binary Ion
+        match value {
+            Float::F32(value) => BinaryWriter::write_f32(self, value),
+            Float::F64(value) => BinaryWriter::write_f64(self, value),
+        }
+    }
+
+    fn write_decimal(&mut self, value: &Decimal) -> IonResult<()> {
+        BinaryWriter::write_decimal(self, value)
+    }
+
+    fn write_timestamp(&mut self, value: &Timestamp) -> IonResult<()> {
+        BinaryWriter::write_timestamp(self, value)
+    }
+
+    fn write_symbol(&mut self, value: &Symbol) -> IonResult<()> {
+        BinaryWriter::write_symbol_value(self, value)
+    }
+
+    fn write_string(&mut self, text: &str) -> IonResult<()> {
+        BinaryWriter::write_string(self, text)
+    }
+
+    fn write_clob(&mut self, value: &[u8]) -> IonResult<()> {
+        BinaryWriter::write_clob(self, value)
+    }
+
+    fn write_blob(&mut self, value: &[u8]) -> IonResult<()> {
+        BinaryWriter::write_blob(self, value)
+    }
+
+    fn step_in(&mut self, ion_type: IonType) -> IonResult<()> {
+        BinaryWriter::step_in(self, ion_type)
+    }
+
+    fn step_out(&mut self) -> IonResult<()> {
+        BinaryWriter::step_out(self)
+    }
+}
+
+/// Copies each of the remaining values at the reader's current depth to `writer`, stepping into
+/// any containers along the way, along with their annotations and field names. The writer
+/// should be at the same depth as the reader.
+///
+/// Symbol tables are resolved by the reader and are not copied, so symbols are written using
+/// their text. Symbols whose text is unknown (for example, those imported from a shared symbol
+/// table that is not in the reader's [Catalog](crate::Catalog)) are written with their symbol
+/// IDs intact by the [TextWriter]; see each writer's [ValueWriter] implementation. Floats keep
+/// their width when both the reader and the writer are binary.
+///
+/// The writer is not flushed.
+pub fn transcode<R: RawReader, W: ValueWriter>(
+    reader: &mut Reader<R>,
+    writer: &mut W,
+) -> IonResult<()> {
+    while reader.next()?.is_some() {
+        transcode_value(reader, writer)?;
+    }
+    Ok(())
+}
+
+/// Copies the value that the reader is positioned on (that is, the value most recently returned
+/// by [Reader::next]) to `writer` as [transcode] does, including any values nested inside of it.
+/// Afterward, the next call to [Reader::next] advances to the value that follows it.
+///
+/// [BinaryWriter::write_current_value] does the same, but copies values from an in-memory
+/// binary reader without re-encoding them when it can.
+pub fn transcode_value<R: RawReader, W: ValueWriter>(
+    reader: &mut Reader<R>,
+    writer: &mut W,
+) -> IonResult<()> {
+    let ion_type = match reader.ion_type() {
+        Some(ion_type) => ion_type,
+        None => return illegal_operation("The reader is not positioned on a value."),
+    };
+    let annotations: Vec<Symbol> = reader.annotation_symbols().collect();
+    if !annotations.is_empty() {
+        writer.set_annotations(&annotations);
+    }
+    if let Some(name) = reader.field_name_symbol() {
+        writer.set_field_name(&name);
+    }
+    if reader.is_null() {
+        return writer.write_null(ion_type);
+    }
+    match ion_type {
+        IonType::Null => unreachable!("non-null value with a null type"),
+        IonType::Boolean => writer.write_bool(reader.read_bool()?.unwrap()),
+        IonType::Integer => writer.write_integer(&reader.read_integer()?.unwrap()),
+        IonType::Float => writer.write_float(reader.read_float()?.unwrap()),
+        IonType::Decimal => writer.write_decimal(&reader.read_decimal()?.unwrap()),
+        IonType::Timestamp => writer.write_timestamp(&reader.read_timestamp()?.unwrap()),
+        IonType::Symbol => writer.write_symbol(&reader.read_symbol_value()?.unwrap()),
+        IonType::String => writer.write_string(reader.read_str()?.unwrap()),
+        IonType::Clob => writer.write_clob(reader.read_clob_ref()?.unwrap()),
+        IonType::Blob => writer.write_blob(reader.read_blob_ref()?.unwrap()),
+        IonType::List | IonType::SExpression | IonType::Struct => {
+            writer.step_in(ion_type)?;
+            reader.step_in()?;
+            transcode(reader, writer)?;
+            reader.step_out()?;
+            writer.step_out()
+        }
+    }
+}
+
+#[cfg(test)]
+mod transcode_tests {
+    use super::*;
+    use crate::binary::constants::v1_0::IVM;
+    use crate::value::owned::OwnedElement;
+
+    const TEXT: &[u8] = br#"
+        a::b::{name: "widget", 'needs quotes': [1, -0x10, 2.5e0, 1.50, 2021-02-03T, null.int]}
+        (sum {{"clob"}} {{YmxvYg==}} true $ion_1_0)
+    "#;
+
+    fn to_binary(input: &[u8]) -> IonResult<Vec<u8>> {
+        let mut binary = Vec::new();
+        let mut writer = BinaryWriter::new(&mut binary);
+        transcode(&mut Reader::from_input(input)?, &mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        Ok(binary)
+    }
+
+    fn to_text(input: &[u8]) -> IonResult<String> {
+        let mut text = Vec::new();
+        let mut writer = TextWriter::new(&mut text);
+        transcode(&mut Reader::from_input(input)?, &mut writer)?;
+        writer.flush()?;
+        drop(writer);
+        Ok(String::from_utf8(text).unwrap())
+    }
+
+    #[test]
+    fn test_transcode_round_trip() -> IonResult<()> {
+        let expected = OwnedElement::read_all(TEXT)?;
+        let binary = to_binary(TEXT)?;
+        assert_eq!(OwnedElement::read_all(&binary)?, expected);
+        let text = to_text(&binary)?;
+        assert_eq!(OwnedElement::read_all(text.as_bytes())?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transcode_symbols_with_unknown_text() -> IonResult<()> {
+        let mut binary = IVM.to_vec();
+        #[rustfmt::skip]
+        binary.extend_from_slice(&[
+            0xE3, 0x81, 0x8C, 0x20, // $12::0
+            0x71, 0x0C,             // $12
+            0xD2, 0x8C, 0x70,       // {$12: $0}
+        ]);
+        // The symbol IDs are not defined by the stream's symbol table
+        assert_eq!(to_text(&binary)?, "$12::0\n$12\n{$12:$0,}\n");
+        // The binary writer's symbol table is unrelated to the reader's
+        let expected = OwnedElement::read_all(&b"$0::0 $0 {$0: $0}"[..])?;
+        assert_eq!(OwnedElement::read_all(&to_binary(&binary)?)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_transcode_value() -> IonResult<()> {
+        let mut reader = Reader::from_input(&b"{a: 1, b: c::[2, 3], d: 4}"[..])?;
+        let mut text = Vec::new();
+        let mut writer = TextWriter::new(&mut text);
+        assert!(transcode_value(&mut reader, &mut writer).is_err());
+        reader.next()?;
+        reader.step_in()?;
+        writer.step_in(IonType::Struct)?;
+        while reader.next()?.is_some() {
+            if reader.field_name_symbol().unwrap().text() != Some("a") {
+                transcode_value(&mut reader, &mut writer)?;
+            }
+        }
+        reader.step_out()?;
+        writer.step_out()?;
+        writer.flush()?;
+        drop(writer);
+        let expected = OwnedElement::read_all(&b"{b: c::[2, 3], d: 4}"[..])?;
+        assert_eq!(OwnedElement::read_all(&text)?, expected);
+        Ok(())
+    }
+}
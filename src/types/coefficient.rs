@@ -3,8 +3,8 @@ use num_traits::Zero;
 
 use crate::result::{illegal_operation, IonError};
 use crate::types::magnitude::Magnitude;
-use std::convert::TryFrom;
-use std::ops::{MulAssign, Neg};
+use core::convert::TryFrom;
+use core::ops::{MulAssign, Neg};
 
 /// Indicates whether the Coefficient's magnitude is less than 0 (negative) or not (positive).
 /// When the magnitude is zero, the Sign can be used to distinguish between -0 and 0.
@@ -30,11 +30,11 @@ impl Coefficient {
         Coefficient { sign, magnitude }
     }
 
-    pub(crate) fn sign(&self) -> Sign {
+    pub fn sign(&self) -> Sign {
         self.sign
     }
 
-    pub(crate) fn magnitude(&self) -> &Magnitude {
+    pub fn magnitude(&self) -> &Magnitude {
         &self.magnitude
     }
 
@@ -45,7 +45,7 @@ impl Coefficient {
         }
     }
 
-    pub(crate) fn is_negative_zero(&self) -> bool {
+    pub fn is_negative_zero(&self) -> bool {
         match (self.sign, &self.magnitude) {
             (Sign::Negative, Magnitude::U64(0)) => true,
             (Sign::Negative, Magnitude::BigUInt(b)) if b.is_zero() => true,
@@ -55,7 +55,7 @@ impl Coefficient {
 
     /// If the value can fit in an i64, return it as such. This is useful for
     /// inline representations.
-    pub(crate) fn as_i64(&self) -> Option<i64> {
+    pub fn as_i64(&self) -> Option<i64> {
         match self.magnitude {
             Magnitude::U64(unsigned) => match i64::try_from(unsigned) {
                 Ok(signed) => match self.sign {
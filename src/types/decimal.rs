@@ -1,12 +1,28 @@
-use std::cmp::Ordering;
-
-use bigdecimal::{BigDecimal, Signed};
+use alloc::format;
+use alloc::string::ToString;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Neg, Sub};
+#[cfg(feature = "std")]
+use core::str::FromStr;
+#[cfg(not(feature = "std"))]
+use num_traits::float::FloatCore;
+
+#[cfg(feature = "std")]
+use bigdecimal::BigDecimal;
 use num_bigint::{BigInt, BigUint, ToBigUint};
+use num_traits::{Signed, ToPrimitive, Zero};
 
+#[cfg(feature = "std")]
+use crate::result::decoding_error_raw;
 use crate::result::{illegal_operation, IonError};
+#[cfg(feature = "std")]
+use crate::text::parsers::decimal::parse_decimal;
+#[cfg(feature = "std")]
+use crate::text::text_value::TextValue;
 use crate::types::coefficient::{Coefficient, Sign};
 use crate::types::magnitude::Magnitude;
-use std::convert::{TryFrom, TryInto};
+use core::convert::{TryFrom, TryInto};
 
 /// An arbitrary-precision Decimal type with a distinct representation of negative zero (`-0`).
 #[derive(Clone, Debug)]
@@ -44,13 +60,200 @@ impl Decimal {
         }
     }
 
+    /// Returns a value that formats this Decimal as Ion text using the specified notation.
+    pub(crate) fn display_with(&self, notation: DecimalNotation) -> DecimalText<'_> {
+        DecimalText {
+            decimal: self,
+            notation,
+        }
+    }
+
+    /// Returns `true` if this Decimal has exactly the same coefficient (including the sign of a
+    /// zero coefficient) and exponent as `other`. Unlike `==`, this does not consider `1.0` and
+    /// `1.00` to be equal because they have different precisions.
+    pub fn ion_eq(&self, other: &Decimal) -> bool {
+        self.exponent == other.exponent && self.coefficient == other.coefficient
+    }
+
+    /// Returns the Decimal's coefficient. For example, `1.25` has a coefficient of `125`.
+    pub fn coefficient(&self) -> &Coefficient {
+        &self.coefficient
+    }
+
+    /// Returns the Decimal's exponent. For example, `1.25` has an exponent of `-2`.
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Returns `true` if the Decimal's coefficient is zero, regardless of its sign or exponent.
+    pub fn is_zero(&self) -> bool {
+        match self.coefficient.magnitude() {
+            Magnitude::U64(magnitude) => *magnitude == 0,
+            Magnitude::BigUInt(magnitude) => magnitude.is_zero(),
+        }
+    }
+
+    /// Returns `true` if the Decimal is a zero with a negative sign, like `-0d0` or `-0.00`.
+    pub fn is_negative_zero(&self) -> bool {
+        self.coefficient.is_negative_zero()
+    }
+
+    /// Returns the value of this Decimal multiplied by `10^power_of_ten`. This only changes the
+    /// exponent, so it never loses precision. For example, scaling `1.25` by `2` produces `125`
+    /// and scaling it by `-1` produces `0.125`.
+    pub fn scale(&self, power_of_ten: i64) -> Decimal {
+        Decimal::new(self.coefficient.clone(), self.exponent + power_of_ten)
+    }
+
+    // Returns this Decimal's coefficient as a BigInt, scaled up so that its value is correct when
+    // combined with `exponent`. `exponent` must not be greater than this Decimal's exponent.
+    // Returns None if the exponents are so far apart that the scaled coefficient would have more
+    // than u32::MAX digits.
+    fn coefficient_at_exponent(&self, exponent: i64) -> Option<BigInt> {
+        let mut magnitude: BigUint = self.coefficient.magnitude().to_biguint().unwrap();
+        // Zero is zero at any exponent, so there is nothing to scale.
+        if !magnitude.is_zero() {
+            let scale = u32::try_from(self.exponent.checked_sub(exponent)?).ok()?;
+            magnitude *= num_traits::checked_pow(BigUint::from(10u32), scale as usize)?;
+        }
+        let sign = match self.coefficient.sign() {
+            Sign::Negative => num_bigint::Sign::Minus,
+            Sign::Positive => num_bigint::Sign::Plus,
+        };
+        Some(BigInt::from_biguint(sign, magnitude))
+    }
+
+    /// Adds two Decimals like [Add], but returns None instead of panicking if their exponents are
+    /// too far apart to represent the sum exactly. (For example, the sum of `1d9223372036854775807`
+    /// and `1d0` would have to be written out with 9223372036854775807 zeros.)
+    pub fn checked_add(self, rhs: Decimal) -> Option<Decimal> {
+        let exponent = self.exponent.min(rhs.exponent);
+        let sum =
+            self.coefficient_at_exponent(exponent)? + rhs.coefficient_at_exponent(exponent)?;
+        let sign = if sum.is_negative()
+            || (sum.is_zero()
+                && self.coefficient.sign() == Sign::Negative
+                && rhs.coefficient.sign() == Sign::Negative)
+        {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let magnitude: BigUint = sum.abs().to_biguint().unwrap();
+        // Prefer the more compact representation when the sum is small enough.
+        let coefficient = match magnitude.to_u64() {
+            Some(magnitude) => Coefficient::new(sign, magnitude),
+            None => Coefficient::new(sign, magnitude),
+        };
+        Some(Decimal::new(coefficient, exponent))
+    }
+
+    /// Subtracts `rhs` from this Decimal like [Sub], but returns None instead of panicking if
+    /// their exponents are too far apart. See [checked_add](Self::checked_add).
+    pub fn checked_sub(self, rhs: Decimal) -> Option<Decimal> {
+        self.checked_add(-rhs)
+    }
+
+    /// Constructs the Decimal with the fewest significant digits that will convert back to
+    /// exactly the same f64. For example, `0.1f64` becomes `1d-1` rather than the exact binary
+    /// value `1000000000000000055511151231257827d-34`. Negative zero is preserved.
+    ///
+    /// Returns `None` if `value` is NaN, infinity, or negative infinity.
+    pub fn from_f64(value: f64) -> Option<Decimal> {
+        if !value.is_finite() {
+            return None;
+        }
+        // Rust's scientific notation formatting produces the shortest sequence of digits that
+        // parses back to the same f64. For example: `1e-1`, `-1.5e0`, `1.7976931348623157e308`.
+        let text = format!("{:e}", value);
+        let (mantissa, exponent) = text.split_at(text.find('e')?);
+        let exponent: i64 = exponent[1..].parse().ok()?;
+        let (sign, mantissa) = match mantissa.strip_prefix('-') {
+            Some(unsigned) => (Sign::Negative, unsigned),
+            None => (Sign::Positive, mantissa),
+        };
+        let (integer_digits, fractional_digits) = match mantissa.find('.') {
+            Some(index) => (&mantissa[..index], &mantissa[index + 1..]),
+            None => (mantissa, ""),
+        };
+        // An f64 has at most 17 significant decimal digits, so the coefficient will fit in a u64.
+        let magnitude: u64 = format!("{}{}", integer_digits, fractional_digits)
+            .parse()
+            .ok()?;
+        let exponent = exponent - fractional_digits.len() as i64;
+        Some(Decimal::new(Coefficient::new(sign, magnitude), exponent))
+    }
+
+    /// Converts the Decimal to the nearest f64, rounding ties to even. Precision that an f64
+    /// cannot represent is silently discarded. Values whose magnitude is too large for an f64
+    /// become positive or negative infinity, while values too small to be represented become
+    /// positive or negative zero. The sign of a negative zero is preserved.
+    pub fn to_f64_lossy(&self) -> f64 {
+        let magnitude = match self.coefficient.magnitude() {
+            Magnitude::U64(magnitude) => magnitude.to_string(),
+            Magnitude::BigUInt(magnitude) => magnitude.to_string(),
+        };
+        let sign = if self.coefficient.sign() == Sign::Negative {
+            "-"
+        } else {
+            ""
+        };
+        // The standard library's float parser is correctly rounded, so we can lean on it once
+        // the decimal has been rendered as text.
+        format!("{}{}e{}", sign, magnitude, self.exponent)
+            .parse()
+            .expect("a Decimal's text representation is always a valid f64")
+    }
+
+    /// Returns the value of the Decimal as an i64 if it is an integer (that is, it has no
+    /// non-zero fractional digits) in the range of an i64. Otherwise, returns `None`.
+    /// Negative zero is converted to `0`.
+    pub fn try_to_i64(&self) -> Option<i64> {
+        self.try_to_i128()
+            .and_then(|value| i64::try_from(value).ok())
+    }
+
+    /// Returns the value of the Decimal as an i128 if it is an integer (that is, it has no
+    /// non-zero fractional digits) in the range of an i128. Otherwise, returns `None`.
+    /// Negative zero is converted to `0`.
+    pub fn try_to_i128(&self) -> Option<i128> {
+        // The number of decimal digits in i128::MAX.
+        const MAX_I128_DIGITS: i64 = 39;
+        let mut magnitude: BigUint = self.coefficient.magnitude().to_biguint().unwrap();
+        if magnitude.is_zero() {
+            return Some(0);
+        }
+        let power_of_ten = |exponent: i64| num_traits::pow(BigUint::from(10u32), exponent as usize);
+        if self.exponent >= 0 {
+            // Any non-zero coefficient scaled by 10^39 or more is out of range.
+            if self.exponent >= MAX_I128_DIGITS {
+                return None;
+            }
+            magnitude *= power_of_ten(self.exponent);
+        } else {
+            let digits_to_remove = self.exponent.checked_neg().unwrap_or(i64::MAX);
+            // If we'd be removing every digit of a non-zero coefficient, it isn't an integer.
+            if digits_to_remove >= magnitude.to_string().len() as i64 {
+                return None;
+            }
+            let divisor = power_of_ten(digits_to_remove);
+            if !(&magnitude % &divisor).is_zero() {
+                return None;
+            }
+            magnitude /= divisor;
+        }
+        let sign = match self.coefficient.sign() {
+            Sign::Negative => num_bigint::Sign::Minus,
+            Sign::Positive => num_bigint::Sign::Plus,
+        };
+        BigInt::from_biguint(sign, magnitude).to_i128()
+    }
+
     // Determines whether the first decimal value is greater than, equal to, or less than
-    // the second decimal value.
-    // TODO: This currently uses the rules for Ion equivalence to determine if two values are equal.
-    //       This leads to potentially surprising behavior around zeros; in particular, -0 and 0
-    //       are not equal, and zeros with different exponents are not equal. We need to offer a
-    //       separate method for testing Ion equivalence.
-    //       See: https://github.com/amzn/ion-rust/issues/220
+    // the second decimal value. Non-zero values are compared numerically, so `1.0` and `1.00` are
+    // equal. Zeros are ordered by their signs and then by their exponents, so -0 and 0 are not
+    // equal, and neither are zeros with different exponents. Use [Decimal::ion_eq] to test two
+    // values for Ion equivalence.
     fn compare(d1: &Decimal, d2: &Decimal) -> Ordering {
         // Even if the exponents are wildly different, disagreement in the coefficient's signs
         // still tells us which value is bigger. (This approach causes `-0` to be considered less
@@ -110,17 +313,38 @@ impl Decimal {
     // Scales up the coefficient associated with a greater exponent and compares it with the
     // other coefficient. `d1` must have a larger exponent than `d2`.
     fn compare_scaled_coefficients(d1: &Decimal, d2: &Decimal) -> Ordering {
-        let exponent_delta = d1.exponent - d2.exponent;
-        // d1 has a larger exponent, so scale up its coefficient to match d2's exponent.
-        // For example, when comparing these values of d1 and d2:
+        let mut scaled_coefficient: BigUint = d1.coefficient.magnitude().to_biguint().unwrap();
+        let other_coefficient: BigUint = d2.coefficient.magnitude().to_biguint().unwrap();
+        // A zero is smaller than any other magnitude, however far apart the exponents are.
+        if scaled_coefficient.is_zero() || other_coefficient.is_zero() {
+            return scaled_coefficient.cmp(&other_coefficient);
+        }
+        // The exponents can be arbitrarily far apart, so compare the positions of the most
+        // significant digits before scaling anything. For example, 5 * 10^20 (whose leading
+        // digit is in the 10^20 place) is greater than 99 * 10^3 (10^4 place).
+        let leading_digit_position = |magnitude: &BigUint, exponent: i64| {
+            exponent as i128 + magnitude.to_string().len() as i128
+        };
+        let position_cmp = leading_digit_position(&scaled_coefficient, d1.exponent)
+            .cmp(&leading_digit_position(&other_coefficient, d2.exponent));
+        if position_cmp != Ordering::Equal {
+            return position_cmp;
+        }
+        // The leading digits line up, so d1's coefficient has fewer digits than d2's and the
+        // exponents are fewer than that many digits apart. Scale up d1's coefficient to match
+        // d2's exponent. For example, when comparing these values of d1 and d2:
         //     d1 =  8 * 10^3
         //     d2 = 80 * 10^2
         // d1 has the larger exponent (3). We need to scale its coefficient up to d2's 10^2 scale.
         // We do this by multiplying it times 10^exponent_delta, which is 1 in this case.
         // This lets us compare 80 and 80, determining that the decimals are equal.
-        let mut scaled_coefficient: BigUint = d1.coefficient.magnitude().to_biguint().unwrap();
-        scaled_coefficient *= 10u64.pow(exponent_delta as u32);
-        Magnitude::BigUInt(scaled_coefficient).cmp(&d2.coefficient.magnitude())
+        let exponent_delta = d1
+            .exponent
+            .checked_sub(d2.exponent)
+            .and_then(|delta| u32::try_from(delta).ok())
+            .expect("exponents with aligned leading digits are close together");
+        scaled_coefficient *= BigUint::from(10u64).pow(exponent_delta);
+        scaled_coefficient.cmp(&other_coefficient)
     }
 }
 
@@ -144,6 +368,151 @@ impl Ord for Decimal {
     }
 }
 
+/// Adds two Decimals without losing precision; the sum has the smaller of the two exponents.
+/// For example, `1.5 + 0.25` is `1.75`. A sum of zero is only negative if both operands are
+/// negative (e.g. `-0 + -0`).
+///
+/// Panics if the exponents of two non-zero Decimals are more than `u32::MAX` apart; use
+/// [Decimal::checked_add] to handle that case.
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, rhs: Decimal) -> Decimal {
+        self.checked_add(rhs)
+            .expect("the exponents were too far apart to add the decimals")
+    }
+}
+
+/// Subtracts one Decimal from another without losing precision. See [Decimal]'s implementation
+/// of [Add].
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, rhs: Decimal) -> Decimal {
+        self + -rhs
+    }
+}
+
+/// Negates a Decimal, including zeros: `-(0d0)` is `-0d0` and vice versa.
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        let sign = match self.coefficient.sign() {
+            Sign::Negative => Sign::Positive,
+            Sign::Positive => Sign::Negative,
+        };
+        let magnitude = self.coefficient.magnitude;
+        Decimal::new(Coefficient::new(sign, magnitude), self.exponent)
+    }
+}
+
+/// Controls how a [TextWriter](crate::text::writer::TextWriter) renders decimal values. Every
+/// notation produces text that reads back as a decimal with exactly the same coefficient and
+/// exponent, so `1.50` is never shortened to `1.5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DecimalNotation {
+    /// Writes the digits with a decimal point and no exponent (e.g. `1.5`, `12.`, `0.000125`).
+    /// Values that would need a positive exponent or more than six leading zeros after the
+    /// decimal point are written in [Canonical](DecimalNotation::Canonical) notation instead.
+    #[default]
+    Plain,
+    /// Writes a single digit before the decimal point followed by an exponent
+    /// (e.g. `1.5d0`, `1.25d-4`).
+    Exponential,
+    /// Writes the coefficient followed by the exponent (e.g. `15d-1`, `125d-6`).
+    Canonical,
+}
+
+/// Formats a [Decimal] as Ion text in a given [DecimalNotation]. See [Decimal::display_with].
+pub(crate) struct DecimalText<'a> {
+    decimal: &'a Decimal,
+    notation: DecimalNotation,
+}
+
+impl<'a> fmt::Display for DecimalText<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.decimal.coefficient.sign() == Sign::Negative {
+            "-"
+        } else {
+            ""
+        };
+        // TODO: Eliminate this allocation.
+        let digits = match self.decimal.coefficient.magnitude() {
+            Magnitude::U64(magnitude) => magnitude.to_string(),
+            Magnitude::BigUInt(magnitude) => magnitude.to_string(),
+        };
+        let exponent = self.decimal.exponent;
+        // The exponent the value would have if it were written with a single leading digit
+        let adjusted_exponent = exponent + digits.len() as i64 - 1;
+
+        match self.notation {
+            DecimalNotation::Plain if exponent <= 0 && adjusted_exponent >= -6 => {
+                // The number of digits that appear before the decimal point
+                let whole_digits = digits.len() as i64 + exponent;
+                if whole_digits > 0 {
+                    let (whole, fractional) = digits.split_at(whole_digits as usize);
+                    write!(f, "{}{}.{}", sign, whole, fractional)
+                } else {
+                    let fractional_digits = -exponent as usize;
+                    write!(f, "{}0.{:0>2$}", sign, digits, fractional_digits)
+                }
+            }
+            DecimalNotation::Exponential => {
+                let (first, rest) = digits.split_at(1);
+                if rest.is_empty() {
+                    write!(f, "{}{}d{}", sign, first, adjusted_exponent)
+                } else {
+                    write!(f, "{}{}.{}d{}", sign, first, rest, adjusted_exponent)
+                }
+            }
+            _ => write!(f, "{}{}d{}", sign, digits, exponent),
+        }
+    }
+}
+
+/// Formats the Decimal as Ion text. Values with a non-positive exponent are written with a
+/// decimal point and no exponent (`1.5`, `12.`, `-0.`, `0.000125`); other values are written as
+/// the coefficient followed by a `d` exponent (`1d3`, `125d-10`). Every digit of the coefficient
+/// is written, so the text can be parsed back into a Decimal with the same precision.
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display_with(DecimalNotation::Plain).fmt(f)
+    }
+}
+
+/// Parses Ion decimal text like `1.5`, `-0.`, `15d-1` or `1.5D0`. Underscores are permitted
+/// between digits as in Ion text. Integers like `15` (which have neither a decimal point nor an
+/// exponent) and float notation like `1.5e0` are rejected, as Ion does not read them as decimals.
+/// The precision of the text is kept exactly: `1.50` parses as 150 with an exponent of -2 (not as
+/// 1.5), and `-0.` parses as negative zero.
+#[cfg(feature = "std")]
+impl FromStr for Decimal {
+    type Err = IonError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        // The text parser only accepts a number that is followed by a stop character.
+        let terminated = format!("{} ", text);
+        match parse_decimal(&terminated) {
+            Ok((" ", TextValue::Decimal(decimal))) => Ok(decimal),
+            _ => Err(decoding_error_raw(format!(
+                "'{}' is not a valid Ion decimal",
+                text
+            ))),
+        }
+    }
+}
+
+/// Parses Ion decimal text. See [Decimal]'s implementation of [FromStr].
+#[cfg(feature = "std")]
+impl TryFrom<&str> for Decimal {
+    type Error = IonError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        Decimal::from_str(text)
+    }
+}
+
 macro_rules! impl_decimal_from_unsigned_primitive_integer {
     ($($t:ty),*) => ($(
         impl From<$t> for Decimal {
@@ -243,6 +612,7 @@ impl TryFrom<f64> for Decimal {
 }
 
 /// Make a Decimal from a BigDecimal. This is a lossless operation.
+#[cfg(feature = "std")]
 impl From<BigDecimal> for Decimal {
     fn from(value: BigDecimal) -> Self {
         let sign = if value.sign() == num_bigint::Sign::Minus {
@@ -261,6 +631,7 @@ impl From<BigDecimal> for Decimal {
     }
 }
 
+#[cfg(feature = "std")]
 impl TryFrom<Decimal> for BigDecimal {
     type Error = IonError;
     /// Attempts to create a BigDecimal from a Decimal. Returns an Error if the Decimal being
@@ -272,6 +643,19 @@ impl TryFrom<Decimal> for BigDecimal {
     }
 }
 
+// Generates decimals with `i64` coefficients, including negative zeros. Exponents are kept small
+// enough that every generated decimal can be written as Ion text without running out of memory.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Decimal {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let exponent = u.int_in_range(-1000..=1000)?;
+        if u.ratio(1, 16)? {
+            return Ok(Decimal::negative_zero_with_exponent(exponent));
+        }
+        Ok(Decimal::new(u.arbitrary::<i64>()?, exponent))
+    }
+}
+
 #[cfg(test)]
 mod decimal_tests {
     use crate::result::IonResult;
@@ -280,7 +664,8 @@ mod decimal_tests {
     use bigdecimal::BigDecimal;
     use num_traits::{Float, ToPrimitive};
     use std::cmp::Ordering;
-    use std::convert::TryInto;
+    use std::convert::{TryFrom, TryInto};
+    use std::str::FromStr;
 
     use rstest::*;
 
@@ -326,6 +711,14 @@ mod decimal_tests {
     #[case((-80, 4), Ordering::Equal, (-8, 5))]
     #[case((-1000, -1), Ordering::Less, (-99_999_999_999i64, -9))]
     #[case((1000, -1), Ordering::Greater, (99_999_999_999i64, -9))]
+    #[case((1, 0), Ordering::Greater, (123_456_789, -30))]
+    #[case((1, 25), Ordering::Equal, (10_000_000_000i64, 15))]
+    // Exponents too far apart for the difference to fit in a u32 or an i64
+    #[case((1, 1 << 32), Ordering::Greater, (1, 0))]
+    #[case((1, i64::MAX), Ordering::Greater, (u64::MAX, i64::MIN))]
+    #[case((-1, i64::MAX), Ordering::Less, (-1, i64::MIN))]
+    #[case((123, 5), Ordering::Less, (99_999_999, 0))]
+    #[case((0, i64::MAX), Ordering::Less, (1, i64::MIN))]
     fn test_decimal_ord<I: Into<Coefficient>>(
         #[case] components1: (I, i64),
         #[case] ordering: Ordering,
@@ -363,6 +756,79 @@ mod decimal_tests {
         assert!(conversion_result.is_err());
     }
 
+    #[rstest]
+    #[case(0.1f64, 1, -1)]
+    #[case(0.2f64, 2, -1)]
+    #[case(0.3f64, 3, -1)]
+    #[case(0.1f64 + 0.2f64, 30000000000000004i64, -17)]
+    #[case(1f64, 1, 0)]
+    #[case(-1.5f64, -15, -1)]
+    #[case(100f64, 1, 2)]
+    #[case(123.456f64, 123456, -3)]
+    #[case(1e300f64, 1, 300)]
+    #[case(std::f64::consts::PI, 3141592653589793i64, -15)]
+    #[case(f64::MAX, 17976931348623157i64, 292)]
+    #[case(f64::MIN_POSITIVE, 22250738585072014i64, -324)]
+    #[case(5e-324f64, 5, -324)]
+    fn test_decimal_from_f64(
+        #[case] value: f64,
+        #[case] expected_coefficient: i64,
+        #[case] expected_exponent: i64,
+    ) {
+        let decimal = Decimal::from_f64(value).unwrap();
+        // Check the exact representation rather than using `==`, which would consider
+        // `1d-1` and `10d-2` to be equal.
+        assert_eq!(decimal.coefficient, expected_coefficient.into());
+        assert_eq!(decimal.exponent, expected_exponent);
+        // Every conversion should round-trip.
+        assert_eq!(decimal.to_f64_lossy(), value);
+    }
+
+    #[test]
+    fn test_decimal_from_f64_zeros_and_special_values() {
+        let zero = Decimal::from_f64(0f64).unwrap();
+        assert_eq!(zero, Decimal::new(0, 0));
+        let negative_zero = Decimal::from_f64(f64::neg_zero()).unwrap();
+        assert_eq!(negative_zero, Decimal::negative_zero());
+        assert!(negative_zero.to_f64_lossy().is_sign_negative());
+
+        assert_eq!(Decimal::from_f64(f64::nan()), None);
+        assert_eq!(Decimal::from_f64(f64::infinity()), None);
+        assert_eq!(Decimal::from_f64(f64::neg_infinity()), None);
+    }
+
+    #[rstest]
+    #[case(Decimal::new(1, -1), 0.1f64)]
+    #[case(Decimal::new(-24601, -3), -24.601f64)]
+    // More digits than an f64 can hold; rounds to the nearest f64.
+    #[case(Decimal::new(1000000000000000055511151231257827u128, -34), 0.1f64)]
+    #[case(Decimal::new(1, 400), f64::infinity())]
+    #[case(Decimal::new(-1, 400), f64::neg_infinity())]
+    #[case(Decimal::new(1, -400), 0f64)]
+    fn test_decimal_to_f64_lossy(#[case] decimal: Decimal, #[case] expected: f64) {
+        assert_eq!(decimal.to_f64_lossy(), expected);
+    }
+
+    #[rstest]
+    #[case(Decimal::new(0, 0), Some(0))]
+    #[case(Decimal::negative_zero_with_exponent(-5), Some(0))]
+    #[case(Decimal::new(0, 1_000_000), Some(0))]
+    #[case(Decimal::new(42, 0), Some(42))]
+    #[case(Decimal::new(-42, 3), Some(-42_000))]
+    #[case(Decimal::new(42_000, -3), Some(42))]
+    #[case(Decimal::new(42_001, -3), None)]
+    #[case(Decimal::new(42, -1_000_000), None)]
+    #[case(Decimal::new(i64::MAX, 0), Some(i64::MAX as i128))]
+    #[case(Decimal::new(i64::MIN, 0), Some(i64::MIN as i128))]
+    #[case(Decimal::new(1, 38), Some(10i128.pow(38)))]
+    #[case(Decimal::new(1, 39), None)]
+    #[case(Decimal::new(i128::MIN + 1, 0), Some(i128::MIN + 1))]
+    fn test_decimal_try_to_i128(#[case] decimal: Decimal, #[case] expected: Option<i128>) {
+        assert_eq!(decimal.try_to_i128(), expected);
+        let expected_i64 = expected.and_then(|value| i64::try_from(value).ok());
+        assert_eq!(decimal.try_to_i64(), expected_i64);
+    }
+
     #[test]
     fn test_convert_to_big_decimal() {
         let decimal = Decimal::new(-24601, -3);
@@ -392,4 +858,151 @@ mod decimal_tests {
         let expected = Decimal::new(-24601, -3);
         assert_eq!(actual, expected);
     }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), "1.5")]
+    #[case(Decimal::new(150, -2), "1.50")]
+    #[case(Decimal::new(12, 0), "12.")]
+    #[case(Decimal::new(1, 3), "1d3")]
+    #[case(Decimal::new(0, 0), "0.")]
+    #[case(Decimal::new(0, -2), "0.00")]
+    #[case(Decimal::negative_zero(), "-0.")]
+    #[case(Decimal::negative_zero_with_exponent(2), "-0d2")]
+    #[case(Decimal::negative_zero_with_exponent(-3), "-0.000")]
+    #[case(Decimal::new(1200, 1), "1200d1")]
+    #[case(Decimal::new(-125, -6), "-0.000125")]
+    #[case(Decimal::new(125, -10), "125d-10")]
+    fn test_decimal_display_round_trip(#[case] decimal: Decimal, #[case] expected: &str) {
+        let text = decimal.to_string();
+        assert_eq!(text, expected);
+        let parsed = Decimal::from_str(&text).unwrap();
+        assert!(parsed.ion_eq(&decimal), "{:?} != {:?}", parsed, decimal);
+    }
+
+    #[rstest]
+    #[case("1d3", Decimal::new(1, 3))]
+    #[case("1.5D-2", Decimal::new(15, -3))]
+    #[case("-0d0", Decimal::negative_zero())]
+    #[case("1_000.0", Decimal::new(10000, -1))]
+    #[case("-0.00", Decimal::negative_zero_with_exponent(-2))]
+    #[case("2.500d1", Decimal::new(2500, -2))]
+    fn test_decimal_from_str(#[case] text: &str, #[case] expected: Decimal) {
+        let parsed: Decimal = text.parse().unwrap();
+        assert!(parsed.ion_eq(&expected), "{:?} != {:?}", parsed, expected);
+        let converted = Decimal::try_from(text).unwrap();
+        assert!(converted.ion_eq(&parsed));
+    }
+
+    #[rstest]
+    #[case("1.5e0")]
+    #[case("1e3")]
+    #[case("15")]
+    #[case("")]
+    #[case(" 1.5")]
+    #[case("1.5 ")]
+    #[case("1.5d")]
+    #[case("+1.5")]
+    #[case("1.5foo")]
+    fn test_decimal_from_str_rejects(#[case] text: &str) {
+        assert!(Decimal::from_str(text).is_err());
+        assert!(Decimal::try_from(text).is_err());
+    }
+
+    #[test]
+    fn test_decimal_ion_eq() {
+        assert!(Decimal::new(10, -1) == Decimal::new(100, -2));
+        assert!(!Decimal::new(10, -1).ion_eq(&Decimal::new(100, -2)));
+        assert!(Decimal::new(10, -1).ion_eq(&Decimal::new(10, -1)));
+        assert!(!Decimal::new(0, 0).ion_eq(&Decimal::negative_zero()));
+    }
+
+    #[test]
+    fn test_decimal_components() {
+        let decimal = Decimal::new(-125, -2);
+        assert_eq!(decimal.coefficient().as_i64(), Some(-125));
+        assert_eq!(decimal.coefficient().sign(), Sign::Negative);
+        assert_eq!(decimal.exponent(), -2);
+        assert!(!decimal.is_zero());
+        assert!(Decimal::new(0, 3).is_zero());
+        assert!(!Decimal::new(0, 3).is_negative_zero());
+        assert!(Decimal::negative_zero_with_exponent(3).is_zero());
+        assert!(Decimal::negative_zero_with_exponent(3).is_negative_zero());
+    }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), Decimal::new(25, -2), Decimal::new(175, -2))]
+    #[case(Decimal::new(15, -1), Decimal::new(-15, -1), Decimal::new(0, -1))]
+    #[case(Decimal::new(1, 3), Decimal::new(1, 0), Decimal::new(1001, 0))]
+    #[case(Decimal::new(-1, 0), Decimal::new(-25, -1), Decimal::new(-35, -1))]
+    #[case(Decimal::new(u64::MAX, 0), Decimal::new(1, 0), Decimal::new(u64::MAX as u128 + 1, 0))]
+    #[case(Decimal::negative_zero(), Decimal::new(0, 0), Decimal::new(0, 0))]
+    fn test_decimal_add(#[case] d1: Decimal, #[case] d2: Decimal, #[case] expected: Decimal) {
+        let sum = d1.clone() + d2.clone();
+        assert!(sum.ion_eq(&expected), "{:?} != {:?}", sum, expected);
+        let sum = d2 + d1;
+        assert!(sum.ion_eq(&expected), "{:?} != {:?}", sum, expected);
+    }
+
+    #[rstest]
+    #[case(Decimal::new(1, i64::MAX), Decimal::new(1, i64::MIN), None)]
+    #[case(Decimal::new(1, 1 << 32), Decimal::new(1, 0), None)]
+    #[case(Decimal::new(1, 100), Decimal::new(-1, 100), Some(Decimal::new(0, 100)))]
+    #[case(Decimal::new(0, i64::MAX), Decimal::new(5, -2), Some(Decimal::new(5, -2)))]
+    #[case(
+        Decimal::new(0, i64::MAX),
+        Decimal::new(0, i64::MIN),
+        Some(Decimal::new(0, i64::MIN))
+    )]
+    fn test_decimal_checked_add(
+        #[case] d1: Decimal,
+        #[case] d2: Decimal,
+        #[case] expected: Option<Decimal>,
+    ) {
+        for sum in [d1.clone().checked_add(d2.clone()), d2.checked_add(d1)] {
+            match (&sum, &expected) {
+                (Some(sum), Some(expected)) => {
+                    assert!(sum.ion_eq(expected), "{:?} != {:?}", sum, expected)
+                }
+                (None, None) => {}
+                _ => panic!("{:?} != {:?}", sum, expected),
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decimal_add_far_apart_exponents() {
+        let _ = Decimal::new(1, i64::MAX) + Decimal::new(1, 0);
+    }
+
+    #[test]
+    fn test_decimal_add_negative_zeros() {
+        let sum = Decimal::negative_zero() + Decimal::negative_zero_with_exponent(-1);
+        assert!(sum.ion_eq(&Decimal::negative_zero_with_exponent(-1)));
+    }
+
+    #[rstest]
+    #[case(Decimal::new(15, -1), Decimal::new(25, -2), Decimal::new(125, -2))]
+    #[case(Decimal::new(1, 0), Decimal::new(3, 0), Decimal::new(-2, 0))]
+    #[case(Decimal::new(1, 0), Decimal::new(1, 0), Decimal::new(0, 0))]
+    #[case(Decimal::negative_zero(), Decimal::new(0, 0), Decimal::negative_zero())]
+    fn test_decimal_sub(#[case] d1: Decimal, #[case] d2: Decimal, #[case] expected: Decimal) {
+        let difference = d1 - d2;
+        assert!(
+            difference.ion_eq(&expected),
+            "{:?} != {:?}",
+            difference,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_decimal_neg_and_scale() {
+        assert!((-Decimal::new(15, -1)).ion_eq(&Decimal::new(-15, -1)));
+        assert!((-Decimal::new(0, 2)).ion_eq(&Decimal::negative_zero_with_exponent(2)));
+        assert!((-Decimal::negative_zero()).ion_eq(&Decimal::new(0, 0)));
+        let decimal = Decimal::new(125, -2);
+        assert!(decimal.scale(2).ion_eq(&Decimal::new(125, 0)));
+        assert!(decimal.scale(-1).ion_eq(&Decimal::new(125, -3)));
+    }
 }
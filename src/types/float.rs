@@ -0,0 +1,138 @@
+/// An Ion float along with the width it was encoded with.
+///
+/// Binary Ion encodes floats as either 32 or 64 bits. Readers report binary floats using the
+/// variant that matches their encoding, so writing a `Float` back out with the corresponding
+/// `write_f32`/`write_f64` method reproduces the original representation, including the bit
+/// pattern of any NaN. Text Ion has no notion of width, so text floats are always [Float::F64].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Float {
+    F32(f32),
+    F64(f64),
+}
+
+impl Float {
+    /// Returns the value of this float as an [f64]. Widening an [f32] is always exact.
+    pub fn as_f64(&self) -> f64 {
+        match self {
+            Float::F32(value) => f64::from(*value),
+            Float::F64(value) => *value,
+        }
+    }
+
+    /// Returns the value of this float as an [f32] if it can be represented as one without
+    /// losing precision; otherwise, returns None. NaNs and infinities are always representable,
+    /// although narrowing a 64-bit NaN does not preserve its payload.
+    pub fn as_f32(&self) -> Option<f32> {
+        match self {
+            Float::F32(value) => Some(*value),
+            Float::F64(value) => {
+                let narrowed = *value as f32;
+                if value.is_nan() || f64::from(narrowed) == *value {
+                    Some(narrowed)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns whether this float is a NaN, an infinity or a finite value.
+    pub fn kind(&self) -> FloatKind {
+        FloatKind::of(self.as_f64())
+    }
+
+    /// Returns the bits of this float's encoded representation, widened to a [u64] if the
+    /// float is an [f32].
+    pub fn to_bits(&self) -> u64 {
+        match self {
+            Float::F32(value) => u64::from(value.to_bits()),
+            Float::F64(value) => value.to_bits(),
+        }
+    }
+}
+
+impl From<f32> for Float {
+    fn from(value: f32) -> Self {
+        Float::F32(value)
+    }
+}
+
+impl From<f64> for Float {
+    fn from(value: f64) -> Self {
+        Float::F64(value)
+    }
+}
+
+/// The kind of value that an Ion float holds. Every NaN bit pattern, whatever its sign or
+/// payload, is [FloatKind::Nan]. See [FloatKind::of].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FloatKind {
+    Nan,
+    PositiveInfinity,
+    NegativeInfinity,
+    /// Any other value, including zero and subnormal values.
+    Finite,
+}
+
+impl FloatKind {
+    /// Returns whether `value` is a NaN, an infinity or a finite value. Widening an f32 to an
+    /// f64 preserves its kind, so this classifies floats of either width.
+    pub fn of(value: f64) -> FloatKind {
+        if value.is_nan() {
+            FloatKind::Nan
+        } else if value == f64::INFINITY {
+            FloatKind::PositiveInfinity
+        } else if value == f64::NEG_INFINITY {
+            FloatKind::NegativeInfinity
+        } else {
+            FloatKind::Finite
+        }
+    }
+}
+
+#[cfg(test)]
+mod float_tests {
+    use super::*;
+
+    #[test]
+    fn narrowing() {
+        assert_eq!(Some(1.5f32), Float::F64(1.5).as_f32());
+        assert_eq!(Some(f32::INFINITY), Float::F64(f64::INFINITY).as_f32());
+        assert!(Float::F64(f64::NAN).as_f32().unwrap().is_nan());
+        assert_eq!(None, Float::F64(0.1).as_f32());
+        assert_eq!(None, Float::F64(1e300).as_f32());
+        assert_eq!(Some(0.1f32), Float::F32(0.1).as_f32());
+    }
+
+    #[test]
+    fn bits_preserve_the_encoded_width() {
+        assert_eq!(0x3FC0_0000, Float::F32(1.5).to_bits());
+        assert_eq!(0x3FF8_0000_0000_0000, Float::F64(1.5).to_bits());
+        assert_eq!(1.5, Float::F32(1.5).as_f64());
+    }
+
+    #[test]
+    fn kinds() {
+        assert_eq!(FloatKind::Nan, FloatKind::of(f64::NAN));
+        assert_eq!(
+            FloatKind::Nan,
+            FloatKind::of(f64::from_bits(0xFFF0_0000_0000_0001))
+        );
+        assert_eq!(
+            FloatKind::Nan,
+            FloatKind::of(f32::from_bits(0x7FC0_0001).into())
+        );
+        assert_eq!(
+            FloatKind::PositiveInfinity,
+            FloatKind::of(f32::INFINITY.into())
+        );
+        assert_eq!(
+            FloatKind::NegativeInfinity,
+            FloatKind::of(f64::NEG_INFINITY)
+        );
+        assert_eq!(FloatKind::Finite, FloatKind::of(-0.0));
+        assert_eq!(FloatKind::Finite, FloatKind::of(f32::MAX.into()));
+        assert_eq!(FloatKind::Nan, Float::F32(f32::NAN).kind());
+        assert_eq!(FloatKind::Finite, Float::F64(1.5).kind());
+    }
+}
@@ -0,0 +1,64 @@
+use num_bigint::BigInt;
+use num_traits::ToPrimitive;
+
+/// An Ion integer of any size.
+///
+/// Readers represent integers that fit in an [i64] as [Integer::I64] and only fall back to a
+/// [BigInt] for integers that are too large, but the two representations of the same number are
+/// considered equal.
+#[derive(Clone, Debug)]
+pub enum Integer {
+    I64(i64),
+    BigInt(BigInt),
+}
+
+impl Integer {
+    /// Returns the value of this integer as an [i64] if it is in range; otherwise, returns None.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Integer::I64(value) => Some(*value),
+            Integer::BigInt(value) => value.to_i64(),
+        }
+    }
+}
+
+impl PartialEq for Integer {
+    fn eq(&self, other: &Self) -> bool {
+        use Integer::*;
+        match (self, other) {
+            (I64(i1), I64(i2)) => i1 == i2,
+            (BigInt(b1), BigInt(b2)) => b1 == b2,
+            (I64(i), BigInt(b)) | (BigInt(b), I64(i)) => b.to_i64() == Some(*i),
+        }
+    }
+}
+
+impl Eq for Integer {}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Self {
+        Integer::I64(value)
+    }
+}
+
+impl From<BigInt> for Integer {
+    fn from(value: BigInt) -> Self {
+        Integer::BigInt(value)
+    }
+}
+
+#[cfg(test)]
+mod integer_tests {
+    use super::*;
+
+    #[test]
+    fn cross_representation_eq() {
+        assert_eq!(Integer::I64(5), Integer::BigInt(BigInt::from(5)));
+        assert_eq!(Integer::BigInt(BigInt::from(-5)), Integer::I64(-5));
+        assert_ne!(
+            Integer::I64(i64::MAX),
+            Integer::BigInt(BigInt::from(u64::MAX))
+        );
+        assert_eq!(None, Integer::BigInt(BigInt::from(u64::MAX)).as_i64());
+    }
+}
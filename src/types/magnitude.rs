@@ -1,7 +1,7 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 
-use bigdecimal::ToPrimitive;
 use num_bigint::{BigUint, ToBigUint};
+use num_traits::ToPrimitive;
 
 /// An unsigned integer that can be combined with a [Sign](crate::types::coefficient::Sign)
 /// to act as the coefficient of a [Decimal](crate::types::decimal::Decimal).
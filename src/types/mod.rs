@@ -6,12 +6,18 @@ pub type SymbolId = usize;
 
 pub mod coefficient;
 pub mod decimal;
+pub mod float;
+pub mod integer;
 pub mod magnitude;
 pub mod timestamp;
 
+#[cfg(feature = "ion-c")]
 use crate::result::{illegal_operation, IonError};
+#[cfg(feature = "ion-c")]
+use core::convert::TryFrom;
+use core::fmt;
+#[cfg(feature = "ion-c")]
 use ion_c_sys::ION_TYPE;
-use std::{convert::TryFrom, fmt};
 
 /// Represents the Ion data type of a given value. To learn more about each data type,
 /// read [the Ion Data Model](http://amzn.github.io/ion-docs/docs/spec.html#the-ion-data-model)
@@ -33,6 +39,9 @@ pub enum IonType {
     Struct,
 }
 
+// The number of variants of IonType, for arrays indexed by an IonType's discriminant
+pub(crate) const NUM_ION_TYPES: usize = 13;
+
 impl fmt::Display for IonType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -67,6 +76,7 @@ impl IonType {
     }
 }
 
+#[cfg(feature = "ion-c")]
 impl TryFrom<ION_TYPE> for IonType {
     type Error = IonError;
 
@@ -91,6 +101,7 @@ impl TryFrom<ION_TYPE> for IonType {
     }
 }
 
+#[cfg(feature = "ion-c")]
 impl Into<ION_TYPE> for IonType {
     fn into(self) -> ION_TYPE {
         use IonType::*;
@@ -112,7 +123,7 @@ impl Into<ION_TYPE> for IonType {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ion-c"))]
 mod type_test {
     use super::*;
     use crate::result::IonResult;
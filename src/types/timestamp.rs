@@ -1,12 +1,22 @@
 use crate::result::{illegal_operation, illegal_operation_raw, IonError, IonResult};
+use crate::types::coefficient::Sign;
 use crate::types::decimal::Decimal;
 use crate::types::magnitude::Magnitude;
+use alloc::format;
+use alloc::string::ToString;
 use chrono::{
-    DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Timelike,
+    DateTime, Datelike, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone,
+    Timelike, Utc,
 };
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::fmt::Debug;
+#[cfg(feature = "ion-c")]
 use ion_c_sys::timestamp::{IonDateTime, TSOffsetKind, TSPrecision};
-use std::convert::TryInto;
-use std::fmt::Debug;
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Indicates the most precise time unit that has been specified in the accompanying [Timestamp].
 #[derive(Debug, Clone, Copy, Eq, PartialEq, PartialOrd)]
@@ -53,29 +63,27 @@ pub enum Mantissa {
     Arbitrary(Decimal),
 }
 
-/// Returns the first `num_digits` digits of the specified `value`.
-// This is used in Timestamp's implementation of [PartialEq].
-fn first_n_digits_of(num_digits: u32, value: u32) -> u32 {
-    let total_digits = number_of_digits(value);
-    if total_digits <= num_digits {
-        return value;
-    }
-    // Truncate the trailing digits
-    value / 10u32.pow(total_digits - num_digits)
-}
-
-/// Returns the number of base-10 digits needed to represent `value`.
-fn number_of_digits(value: u32) -> u32 {
-    match value {
-        0 => 1,
-        1 => 1,
-        i => (i as f64).log10().ceil() as u32,
-    }
-}
-
 /// Represents a point in time to a specified degree of precision. Unlike `chrono`'s [NaiveDateTime]
 /// and [DateTime], a `Timestamp` has variable precision ranging from a year to fractional seconds
 /// of an arbitrary unit.
+///
+/// ## Conversions
+///
+/// A Timestamp can be converted to and from [DateTime], [NaiveDateTime], [SystemTime] and, with
+/// the `time` feature, `time::OffsetDateTime`. None of these types can represent everything a
+/// Timestamp can, so conversions follow these rules:
+///
+/// * **Out.** Converting to a type that identifies an instant ([Timestamp::to_utc],
+///   [Timestamp::to_chrono_datetime], [Timestamp::to_system_time]) requires a known offset and at
+///   least [Precision::HourAndMinute]; otherwise, `None` is returned. Fields finer than the
+///   Timestamp's precision are zero, and fractional seconds beyond nanoseconds are truncated.
+///   [Timestamp::to_chrono_datetime] keeps the Timestamp's offset; the others are in UTC.
+/// * **In.** Converting from one of these types produces a Timestamp with
+///   [Precision::FractionalSeconds] at nanosecond precision (9 digits), even if the nanoseconds
+///   are zero. A [DateTime] keeps its offset; a [NaiveDateTime] has an unknown offset; a
+///   [SystemTime] or `DateTime<Utc>` is at offset `+00:00`. Conversions fail if the year is
+///   outside of the range `0001-9999`, if the value is a leap second, or if the offset is not a
+///   whole number of minutes.
 #[derive(Debug, Clone)]
 pub struct Timestamp {
     pub(crate) date_time: NaiveDateTime,
@@ -84,9 +92,6 @@ pub struct Timestamp {
     pub(crate) fractional_seconds: Option<Mantissa>,
 }
 
-// TODO: Timestamp does not yet provide useful accessors for its individual fields. It can be
-//       instantiated and tested for equality, but will not very useful as a general purpose
-//       datetime until these methods are added.
 impl Timestamp {
     /// Converts a [NaiveDateTime] or [DateTime<FixedOffset>] to a Timestamp with the specified
     /// precision. If the precision is [Precision::FractionalSeconds], nanosecond precision is
@@ -103,21 +108,229 @@ impl Timestamp {
         timestamp
     }
 
+    /// Returns the instant this Timestamp represents as a [DateTime<Utc>].
+    ///
+    /// Returns `None` if the Timestamp's offset is unknown (`-00:00`) or if its precision is
+    /// coarser than [Precision::HourAndMinute]; a year-, month- or day-precision Timestamp does
+    /// not identify a single instant.
+    ///
+    /// [DateTime] always stores nanoseconds. Fractional seconds with fewer digits (milliseconds,
+    /// microseconds) are represented exactly; any digits beyond the ninth are truncated.
+    pub fn to_utc(&self) -> Option<DateTime<Utc>> {
+        if self.offset.is_none() || self.precision < Precision::HourAndMinute {
+            return None;
+        }
+        let date_time = downconvert_to_naive_datetime_with_nanoseconds(self);
+        Some(Utc.from_utc_datetime(&date_time))
+    }
+
+    /// Returns the instant this Timestamp represents as a [DateTime] at the Timestamp's offset.
+    /// For example, `2021-02-03T04:05+07:00` returns `2021-02-03 04:05:00 +07:00`.
+    ///
+    /// Returns `None` under the same conditions as [Timestamp::to_utc]. This replaces the
+    /// deprecated `read_datetime` reader method: call `read_timestamp` and then convert the
+    /// result.
+    pub fn to_chrono_datetime(&self) -> Option<DateTime<FixedOffset>> {
+        let offset = self.offset?;
+        self.to_utc()
+            .map(|utc| offset.from_utc_datetime(&utc.naive_utc()))
+    }
+
+    /// Returns the instant this Timestamp represents as a [SystemTime].
+    ///
+    /// Returns `None` under the same conditions as [Timestamp::to_utc], or if the instant cannot
+    /// be represented by this platform's [SystemTime].
+    #[cfg(feature = "std")]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let utc = self.to_utc()?;
+        let seconds = utc.timestamp();
+        let nanoseconds = Duration::from_nanos(utc.timestamp_subsec_nanos() as u64);
+        if seconds >= 0 {
+            UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64) + nanoseconds)
+        } else {
+            UNIX_EPOCH
+                .checked_sub(Duration::from_secs(seconds.unsigned_abs()))?
+                .checked_add(nanoseconds)
+        }
+    }
+
+    /// Returns the instant this Timestamp represents as a `time::OffsetDateTime` at the
+    /// Timestamp's offset.
+    ///
+    /// Returns `None` under the same conditions as [Timestamp::to_utc].
+    #[cfg(feature = "time")]
+    pub fn to_time_offset_datetime(&self) -> Option<time::OffsetDateTime> {
+        let offset = time::UtcOffset::from_whole_seconds(self.offset?.local_minus_utc()).ok()?;
+        let utc = self.to_utc()?;
+        let nanoseconds =
+            utc.timestamp() as i128 * 1_000_000_000 + utc.timestamp_subsec_nanos() as i128;
+        time::OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)
+            .ok()
+            .map(|date_time| date_time.to_offset(offset))
+    }
+
+    /// Returns this Timestamp's local date and time as a [NaiveDateTime], discarding its offset.
+    /// For example, both `2021-02-03T04:05+07:00` and `2021-02-03T04:05-00:00` return
+    /// `2021-02-03 04:05:00`.
+    ///
+    /// Returns `None` if the Timestamp's precision is coarser than [Precision::Day]. Time fields
+    /// finer than the Timestamp's precision are zero. As with [Timestamp::to_utc], fractional
+    /// seconds are truncated to nanoseconds.
+    pub fn to_naive(&self) -> Option<NaiveDateTime> {
+        if self.precision < Precision::Day {
+            return None;
+        }
+        let date_time = downconvert_to_naive_datetime_with_nanoseconds(self);
+        match self.offset {
+            Some(offset) => Some(offset.from_utc_datetime(&date_time).naive_local()),
+            None => Some(date_time),
+        }
+    }
+
+    /// Returns the most precise time unit that was specified for this Timestamp.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Returns the Timestamp's offset from UTC in minutes, or `None` if the offset is unknown
+    /// (`-00:00`). A positive value indicates the Eastern Hemisphere, a negative value the
+    /// Western Hemisphere.
+    pub fn offset(&self) -> Option<i32> {
+        const SECONDS_PER_MINUTE: i32 = 60;
+        self.offset
+            .map(|offset| offset.local_minus_utc() / SECONDS_PER_MINUTE)
+    }
+
+    /// Returns the year in the Timestamp's local time.
+    pub fn year(&self) -> u32 {
+        self.local_date_time().year() as u32
+    }
+
+    /// Returns the 1-based month in the Timestamp's local time, or `None` if the Timestamp's
+    /// precision is coarser than [Precision::Month].
+    pub fn month(&self) -> Option<u32> {
+        self.field_at(Precision::Month, |dt| dt.month())
+    }
+
+    /// Returns the 1-based day of the month in the Timestamp's local time, or `None` if the
+    /// Timestamp's precision is coarser than [Precision::Day].
+    pub fn day(&self) -> Option<u32> {
+        self.field_at(Precision::Day, |dt| dt.day())
+    }
+
+    /// Returns the hour in the Timestamp's local time, or `None` if the Timestamp's precision
+    /// is coarser than [Precision::HourAndMinute].
+    pub fn hour(&self) -> Option<u32> {
+        self.field_at(Precision::HourAndMinute, |dt| dt.hour())
+    }
+
+    /// Returns the minute in the Timestamp's local time, or `None` if the Timestamp's precision
+    /// is coarser than [Precision::HourAndMinute].
+    pub fn minute(&self) -> Option<u32> {
+        self.field_at(Precision::HourAndMinute, |dt| dt.minute())
+    }
+
+    /// Returns the second, or `None` if the Timestamp's precision is coarser than
+    /// [Precision::Second].
+    pub fn second(&self) -> Option<u32> {
+        self.field_at(Precision::Second, |dt| dt.second())
+    }
+
+    /// If the precision is [Precision::FractionalSeconds], returns the Timestamp's fractional
+    /// seconds as a Decimal with exactly the precision that was specified; otherwise, returns
+    /// None. For example, `2021-02-03T04:05:06.070Z` has fractional seconds of `0.070`.
+    pub fn fractional_seconds(&self) -> Option<Decimal> {
+        self.fractional_seconds_as_decimal()
+    }
+
+    /// Returns a copy of this Timestamp with its precision reduced to `precision`; fields finer
+    /// than `precision` are discarded. For example, truncating `2021-02-03T04:05:06.789+07:00` to
+    /// [Precision::Second] produces `2021-02-03T04:05:06+07:00`. Ion timestamps coarser than
+    /// [Precision::HourAndMinute] have no offset, so truncating to [Precision::Day] produces
+    /// `2021-02-03T`, keeping the local date. If the Timestamp's precision is already `precision`
+    /// or coarser, it is returned unchanged.
+    pub fn truncated_to(&self, precision: Precision) -> IonResult<Timestamp> {
+        if precision >= self.precision {
+            return Ok(self.clone());
+        }
+        let mut timestamp = self.clone();
+        timestamp.precision = precision;
+        timestamp.fractional_seconds = None;
+        if precision >= Precision::HourAndMinute {
+            // Offsets are a whole number of minutes, so the seconds are the same in UTC and in
+            // local time.
+            let second = if precision == Precision::Second {
+                self.date_time.second()
+            } else {
+                0
+            };
+            timestamp.date_time = timestamp
+                .date_time
+                .date()
+                .and_hms_opt(self.date_time.hour(), self.date_time.minute(), second)
+                .ok_or_else(|| illegal_operation_raw("the truncated time is invalid"))?;
+            return Ok(timestamp);
+        }
+        let local = self.local_date_time();
+        let month = if precision >= Precision::Month {
+            local.month()
+        } else {
+            1
+        };
+        let day = if precision == Precision::Day {
+            local.day()
+        } else {
+            1
+        };
+        timestamp.date_time = NaiveDate::from_ymd_opt(local.year(), month, day)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .ok_or_else(|| illegal_operation_raw("the truncated date is invalid"))?;
+        timestamp.offset = None;
+        Ok(timestamp)
+    }
+
+    // Returns `self.date_time` (which is stored in UTC) in the Timestamp's local time.
+    fn local_date_time(&self) -> NaiveDateTime {
+        match self.offset {
+            Some(offset) => offset.from_utc_datetime(&self.date_time).naive_local(),
+            None => self.date_time,
+        }
+    }
+
+    // Returns the specified field of the local date time if the Timestamp's precision includes it.
+    fn field_at<F>(&self, precision: Precision, field: F) -> Option<u32>
+    where
+        F: FnOnce(&NaiveDateTime) -> u32,
+    {
+        if self.precision < precision {
+            return None;
+        }
+        Some(field(&self.local_date_time()))
+    }
+
+    /// Returns the first `number_of_digits` digits of the fractional seconds stored in
+    /// `self.date_time`. For example, 70 milliseconds (70,000,000 nanoseconds) has the value
+    /// `70` at 3 digits of precision.
+    fn fractional_seconds_digits(&self, number_of_digits: u32) -> u32 {
+        self.date_time.nanosecond() / 10u32.pow(9 - number_of_digits)
+    }
+
     /// If the precision is [Precision::FractionalSeconds], returns a Decimal representation
     /// of this Timestamp's fractional seconds; otherwise, returns None.
     ///
     /// For example, a Timestamp with 553 milliseconds would return a Decimal with
-    /// coefficient 553, exponent -3.
-    fn fractional_seconds_as_decimal(&self) -> Option<Decimal> {
-        // This function is used when comparing two Timestamps with different Mantissa representations.
+    /// coefficient 553, exponent -3. Unlike the Timestamp's conversions to chrono types, this is
+    /// lossless for fractional seconds that are more precise than nanoseconds.
+    pub fn fractional_seconds_as_decimal(&self) -> Option<Decimal> {
+        // This function is also used when comparing two Timestamps with different Mantissa
+        // representations.
         use Mantissa::*;
         match self.fractional_seconds.as_ref() {
             // This timestamp stores its fractional seconds in its `date_time` field.
             // We'll need to convert the date_time's nanoseconds to a Decimal and return it.
             Some(Digits(number_of_digits)) => {
-                let coefficient = first_n_digits_of(*number_of_digits, self.date_time.nanosecond());
-                let exponent = -1 * ((coefficient as f64).log10().ceil() as i64);
-                Some(Decimal::new(coefficient, exponent))
+                let coefficient = self.fractional_seconds_digits(*number_of_digits);
+                Some(Decimal::new(coefficient, -(*number_of_digits as i64)))
             }
             // This timestamp already stores its fractional seconds as a Decimal; return a clone.
             Some(Arbitrary(decimal)) => Some(decimal.clone()),
@@ -130,8 +343,8 @@ impl Timestamp {
     /// this Timestamp's fractional seconds in nanoseconds; otherwise, returns None.
     ///
     /// NOTE: This is a potentially lossy operation. A Timestamp with picoseconds would return a
-    /// number of nanoseconds, losing precision. Similarly, a Timestamp with milliseconds would
-    /// also return a number of nanoseconds, erroneously gaining precision.
+    /// number of nanoseconds, truncating the digits beyond the ninth. Similarly, a Timestamp with
+    /// milliseconds would also return a number of nanoseconds, erroneously gaining precision.
     fn fractional_seconds_as_nanoseconds(&self) -> Option<u32> {
         // This function is used when converting a Timestamp to a DateTime<FixedOffset> or
         // NaiveDateTime.
@@ -143,16 +356,34 @@ impl Timestamp {
             Some(Digits(_number_of_digits)) => Some(self.date_time.nanosecond()),
             // This timestamp stores its fractional seconds as a Decimal. Down-convert it to a u32.
             Some(Arbitrary(decimal)) => {
+                if decimal.is_zero() {
+                    return Some(0);
+                }
+                // A non-zero Decimal with a non-negative exponent is at least one second, which
+                // the builder does not accept.
+                if decimal.exponent >= 0 {
+                    return None;
+                }
                 const NANOSECONDS_EXPONENT: i64 = -9;
                 let exponent_delta = decimal.exponent - NANOSECONDS_EXPONENT;
-                let magnitude = match decimal.coefficient.magnitude() {
-                    Magnitude::U64(magnitude) => magnitude,
-                    Magnitude::BigUInt(_magnitude) => {
-                        todo!("convert decimal fractional seconds with BigUInt magnitudes to nanoseconds")
+                let magnitude: BigUint = decimal.coefficient.magnitude().clone().into();
+                // The builder only accepts fractional seconds in the range [0, 1), so the number
+                // of nanoseconds always fits in a u32.
+                let ten = BigUint::from(10u32);
+                let nanoseconds = if exponent_delta >= 0 {
+                    // The exponent is in the range [-9, -1], so this is at most 10^8.
+                    magnitude * ten.pow(exponent_delta as u32)
+                } else {
+                    // Removing at least as many digits as the magnitude has leaves nothing, and
+                    // the exponent may be far too small to raise ten to.
+                    let digits_to_remove = exponent_delta.unsigned_abs();
+                    let number_of_digits = magnitude.to_string().len() as u64;
+                    if digits_to_remove >= number_of_digits {
+                        return Some(0);
                     }
+                    magnitude / ten.pow(digits_to_remove as u32)
                 };
-                let nanoseconds = (*magnitude as f64 * 10f64.powi(exponent_delta as i32)) as u32;
-                Some(nanoseconds)
+                nanoseconds.to_u32()
             }
             // This Timestamp's precision is too low to have a fractional seconds field.
             None => None,
@@ -175,9 +406,7 @@ impl Timestamp {
                     // Different precisions
                     return false;
                 }
-                let d1 = first_n_digits_of(*d1, self.date_time.nanosecond());
-                let d2 = first_n_digits_of(*d2, other.date_time.nanosecond());
-                d1 == d2
+                self.fractional_seconds_digits(*d1) == other.fractional_seconds_digits(*d2)
             }
             (Some(Arbitrary(d1)), Some(Arbitrary(d2))) => d1.eq(d2),
             (Some(Digits(_d1)), Some(Arbitrary(d2))) => {
@@ -287,6 +516,68 @@ impl PartialEq for Timestamp {
     }
 }
 
+/// Formats the Timestamp as Ion text with exactly its precision: `2021T`, `2021-01T`,
+/// `2021-01-01T`, `2021-01-01T12:30-00:00`, `2021-01-01T12:30:15+01:00` or
+/// `2021-01-01T12:30:15.000+00:00`. Fields are written in the Timestamp's local time, and every
+/// digit of its fractional seconds is written, including trailing zeros. An unknown offset is
+/// written as `-00:00`.
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let offset_seconds = self.offset.map(|offset| offset.local_minus_utc());
+        // The Timestamp's fields are stored in UTC; shift them to its local time.
+        let local = self.date_time + chrono::Duration::seconds(offset_seconds.unwrap_or(0) as i64);
+
+        write!(f, "{:0>4}", local.year())?;
+        if self.precision == Precision::Year {
+            return write!(f, "T");
+        }
+        write!(f, "-{:0>2}", local.month())?;
+        if self.precision == Precision::Month {
+            return write!(f, "T");
+        }
+        write!(f, "-{:0>2}", local.day())?;
+        if self.precision == Precision::Day {
+            return write!(f, "T");
+        }
+        write!(f, "T{:0>2}:{:0>2}", local.hour(), local.minute())?;
+        if self.precision >= Precision::Second {
+            write!(f, ":{:0>2}", local.second())?;
+        }
+        if self.precision == Precision::FractionalSeconds {
+            match self.fractional_seconds.as_ref() {
+                Some(Mantissa::Digits(0)) | None => {}
+                Some(Mantissa::Digits(number_of_digits)) => {
+                    let nanoseconds = format!("{:0>9}", local.nanosecond());
+                    let number_of_digits = (*number_of_digits as usize).min(nanoseconds.len());
+                    write!(f, ".{}", &nanoseconds[..number_of_digits])?;
+                }
+                // The Decimal is in the range [0, 1), so one with a non-negative exponent is a
+                // zero with no digits after the decimal point.
+                Some(Mantissa::Arbitrary(decimal)) if decimal.exponent >= 0 => {}
+                Some(Mantissa::Arbitrary(decimal)) => {
+                    // Its digits can be written after the decimal point, left-padded with as many
+                    // zeros as its exponent requires.
+                    let digits = match decimal.coefficient.magnitude() {
+                        Magnitude::U64(magnitude) => magnitude.to_string(),
+                        Magnitude::BigUInt(magnitude) => magnitude.to_string(),
+                    };
+                    let number_of_digits = decimal.exponent.unsigned_abs() as usize;
+                    write!(f, ".{:0>1$}", digits, number_of_digits)?;
+                }
+            }
+        }
+
+        match offset_seconds {
+            None => write!(f, "-00:00"),
+            Some(seconds) => {
+                let sign = if seconds >= 0 { "+" } else { "-" };
+                let minutes = seconds.abs() / 60;
+                write!(f, "{}{:0>2}:{:0>2}", sign, minutes / 60, minutes % 60)
+            }
+        }
+    }
+}
+
 // We cannot provide an implementation of [Ord] for [Timestamp] because many instances cannot be
 // meaningfully compared to each other to derive an ordering. For example, a Timestamp with
 // Precision::Year cannot be compared to a timestamp with Precision::Day, and a Timestamp with
@@ -371,6 +662,16 @@ impl TimestampBuilder {
         }
 
         // If precision == FractionalSecond, the fractional_second must be set.
+        if let Some(Mantissa::Arbitrary(decimal)) = self.fractional_seconds.as_ref() {
+            if decimal.coefficient.sign() == Sign::Negative && !decimal.is_zero()
+                || *decimal >= Decimal::new(1, 0)
+            {
+                return illegal_operation(format!(
+                    "provided fractional seconds ('{}') must be >= 0 and < 1",
+                    decimal
+                ));
+            }
+        }
         // If fractional seconds is Digit, self.nanoseconds will be Some(_).
         // If it's Arbitrary, self.nanoseconds will be None and we should set the nanoseconds
         // field to 0. The real value will be stored in the Timestamp alongside the DateTime
@@ -642,7 +943,15 @@ impl FractionalSecondSetter {
         FractionalSecondSetter { builder }
     }
 
+    /// Sets the fractional seconds to the given Decimal, which must be `>= 0` and `< 1`. Values
+    /// with at most nine digits after the decimal point (for example, `0.553`) are stored as a
+    /// number of nanoseconds with the given number of digits of precision. More precise values
+    /// (for example, `0.553000000001`) are stored as the Decimal itself, and so round trip
+    /// through [Timestamp::fractional_seconds_as_decimal] without losing any digits.
     pub fn with_fractional_seconds(self, fractional_seconds: Decimal) -> FractionalSecondSetter {
+        if let Some((nanoseconds, precision_digits)) = as_nanoseconds(&fractional_seconds) {
+            return self.with_nanoseconds_and_precision(nanoseconds, precision_digits);
+        }
         let mut builder = self.builder;
         builder.precision = Precision::FractionalSeconds;
         builder.fractional_seconds = Some(Mantissa::Arbitrary(fractional_seconds));
@@ -668,6 +977,29 @@ impl FractionalSecondSetter {
     }
 }
 
+/// If the given fractional seconds can be represented exactly as a number of nanoseconds, returns
+/// that number along with the number of digits after the decimal point.
+fn as_nanoseconds(fractional_seconds: &Decimal) -> Option<(u32, u32)> {
+    if !(-9..=-1).contains(&fractional_seconds.exponent)
+        || fractional_seconds.coefficient.sign() == Sign::Negative
+    {
+        return None;
+    }
+    let coefficient = match fractional_seconds.coefficient.magnitude() {
+        Magnitude::U64(coefficient) => u32::try_from(*coefficient).ok()?,
+        Magnitude::BigUInt(_) => return None,
+    };
+    let precision_digits = fractional_seconds.exponent.unsigned_abs() as u32;
+    if coefficient >= 10u32.pow(precision_digits) {
+        // The value is >= 1; let the builder report it.
+        return None;
+    }
+    Some((
+        coefficient * 10u32.pow(9 - precision_digits),
+        precision_digits,
+    ))
+}
+
 trait TimeUnitSetter {
     fn into_builder(self) -> TimestampBuilder;
 }
@@ -762,6 +1094,125 @@ impl From<DateTime<FixedOffset>> for Timestamp {
     }
 }
 
+// Ion timestamps can only represent years in the range 0001-9999.
+fn check_timestamp_year(year: i32) -> IonResult<()> {
+    if !(1..=9999).contains(&year) {
+        return illegal_operation(format!(
+            "cannot convert a date with year {} into a Timestamp; years must be in the range 1-9999",
+            year
+        ));
+    }
+    Ok(())
+}
+
+// Allows a DateTime<Utc> to be converted to a Timestamp with nanosecond precision at offset
+// `+00:00`. Ion cannot represent leap seconds, so a DateTime<Utc> that chrono is using to
+// represent one (a nanosecond value of 1,000,000,000 or more) is rejected, as is a year outside
+// of the range Ion supports.
+impl TryFrom<DateTime<Utc>> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(utc_date_time: DateTime<Utc>) -> Result<Self, Self::Error> {
+        check_timestamp_year(utc_date_time.year())?;
+        if utc_date_time.nanosecond() >= 1_000_000_000 {
+            return illegal_operation("cannot convert a leap second into a Timestamp");
+        }
+        Ok(Timestamp {
+            date_time: utc_date_time.naive_utc(),
+            offset: Some(Utc.fix()),
+            precision: Precision::FractionalSeconds,
+            fractional_seconds: Some(Mantissa::Digits(9)),
+        })
+    }
+}
+
+// Allows a SystemTime to be converted to a Timestamp with nanosecond precision at offset `+00:00`.
+// Fails if the year is outside of the range Ion supports.
+#[cfg(feature = "std")]
+impl TryFrom<SystemTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(system_time: SystemTime) -> Result<Self, Self::Error> {
+        let out_of_range =
+            || illegal_operation_raw("the SystemTime is out of the range of a Timestamp");
+        let (seconds, nanoseconds) = match system_time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => (
+                i64::try_from(duration.as_secs()).map_err(|_| out_of_range())?,
+                duration.subsec_nanos(),
+            ),
+            // The SystemTime is before the epoch. Round down to the previous whole second and
+            // count the nanoseconds forward from there.
+            Err(error) => {
+                let duration = error.duration();
+                let seconds = i64::try_from(duration.as_secs()).map_err(|_| out_of_range())?;
+                match duration.subsec_nanos() {
+                    0 => (-seconds, 0),
+                    nanoseconds => (-seconds - 1, 1_000_000_000 - nanoseconds),
+                }
+            }
+        };
+        let date_time = DateTime::from_timestamp(seconds, nanoseconds).ok_or_else(out_of_range)?;
+        Timestamp::try_from(date_time)
+    }
+}
+
+// Allows a time::OffsetDateTime to be converted to a Timestamp with nanosecond precision and the
+// same offset. Fails if the year is outside of the range Ion supports or if the offset is not a
+// whole number of minutes.
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(offset_date_time: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        check_timestamp_year(offset_date_time.year())?;
+        let offset_seconds = offset_date_time.offset().whole_seconds();
+        if offset_seconds % 60 != 0 {
+            return illegal_operation(
+                "cannot convert an offset that is not a whole number of minutes into a Timestamp",
+            );
+        }
+        // The time crate accepts offsets of up to 25:59:59, but chrono's are less than a day.
+        let offset = FixedOffset::east_opt(offset_seconds).ok_or_else(|| {
+            illegal_operation_raw("the OffsetDateTime's offset is out of the range of a Timestamp")
+        })?;
+        let nanoseconds = offset_date_time.unix_timestamp_nanos();
+        let date_time = DateTime::from_timestamp(
+            nanoseconds.div_euclid(1_000_000_000) as i64,
+            nanoseconds.rem_euclid(1_000_000_000) as u32,
+        )
+        .ok_or_else(|| {
+            illegal_operation_raw("the OffsetDateTime is out of the range of a Timestamp")
+        })?
+        .naive_utc();
+        Ok(Timestamp {
+            date_time,
+            offset: Some(offset),
+            precision: Precision::FractionalSeconds,
+            fractional_seconds: Some(Mantissa::Digits(9)),
+        })
+    }
+}
+
+// Allows a NaiveDate to be converted to a Timestamp with [Precision::Day] and an unknown offset.
+// Fails if the date's year is outside of the range Ion supports.
+impl TryFrom<NaiveDate> for Timestamp {
+    type Error = IonError;
+
+    fn try_from(date: NaiveDate) -> Result<Self, Self::Error> {
+        check_timestamp_year(date.year())?;
+        let date_time = date
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(|| illegal_operation_raw("midnight is not a valid time on this date"))?;
+        Ok(Timestamp {
+            date_time,
+            offset: None,
+            precision: Precision::Day,
+            fractional_seconds: None,
+        })
+    }
+}
+
+#[cfg(feature = "ion-c")]
 impl From<ion_c_sys::timestamp::IonDateTime> for Timestamp {
     fn from(ionc_dt: IonDateTime) -> Self {
         use ion_c_sys::timestamp::Mantissa as IonCMantissa;
@@ -799,6 +1250,7 @@ impl From<ion_c_sys::timestamp::IonDateTime> for Timestamp {
 /// In general there should be 1-to-1 fidelity between these types, but there
 /// is no static way to guarantee this because of [`Decimal`] and the public constructor for
 /// [`IonDateTime`](ion_c_sys::timestamp::IonDateTime).
+#[cfg(feature = "ion-c")]
 impl TryInto<ion_c_sys::timestamp::IonDateTime> for Timestamp {
     type Error = IonError;
 
@@ -834,12 +1286,61 @@ impl TryInto<ion_c_sys::timestamp::IonDateTime> for Timestamp {
     }
 }
 
+// Generates valid timestamps of every precision. Days are limited to the first 28 of each month
+// so that every generated date exists.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Timestamp {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let year = u.int_in_range(1..=9999)?;
+        let month = u.int_in_range(1..=12)?;
+        let day = u.int_in_range(1..=28)?;
+        let hour = u.int_in_range(0..=23)?;
+        let minute = u.int_in_range(0..=59)?;
+        let second = u.int_in_range(0..=59)?;
+        let offset = if u.arbitrary()? {
+            Some(u.int_in_range(-1439..=1439)?)
+        } else {
+            None
+        };
+        let date = Timestamp::with_ymd(year, month, day);
+        let mut builder = match u.int_in_range(0..=5)? {
+            0 => Timestamp::with_year(year).into_builder(),
+            1 => Timestamp::with_year(year).with_month(month).into_builder(),
+            2 => date.into_builder(),
+            3 => date.with_hour_and_minute(hour, minute).into_builder(),
+            4 => date.with_hms(hour, minute, second).into_builder(),
+            _ => {
+                let digits = u.int_in_range(1..=9)?;
+                let nanoseconds = u.int_in_range(0..=999_999_999)?;
+                let nanoseconds = nanoseconds - nanoseconds % 10u32.pow(9 - digits);
+                date.with_hms(hour, minute, second)
+                    .with_nanoseconds_and_precision(nanoseconds, digits)
+                    .into_builder()
+            }
+        };
+        // Only timestamps with a time component have an offset
+        if builder.precision >= Precision::HourAndMinute {
+            builder.offset = offset;
+        }
+        builder
+            .build()
+            .map_err(|_| arbitrary::Error::IncorrectFormat)
+    }
+}
+
 #[cfg(test)]
 mod timestamp_tests {
     use crate::result::IonResult;
+    use crate::types::decimal::Decimal;
     use crate::types::timestamp::{Mantissa, Precision, Timestamp};
-    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike};
-    use std::convert::TryInto;
+    use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Timelike, Utc};
+    use num_bigint::BigUint;
+    use std::convert::{TryFrom, TryInto};
+    use std::str::FromStr;
+
+    fn naive_date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
 
     #[test]
     fn test_timestamps_with_same_ymd_hms_millis_at_known_offset_are_equal() -> IonResult<()> {
@@ -1178,32 +1679,364 @@ mod timestamp_tests {
     }
 
     #[test]
-    fn test_first_n_digits_of() {
-        assert_eq!(0, super::first_n_digits_of(1, 0));
-        assert_eq!(1, super::first_n_digits_of(1, 1));
-        assert_eq!(2, super::first_n_digits_of(1, 2));
-
-        assert_eq!(0, super::first_n_digits_of(3, 0));
-        assert_eq!(1, super::first_n_digits_of(3, 1));
-        assert_eq!(2, super::first_n_digits_of(3, 2));
-        assert_eq!(99, super::first_n_digits_of(9, 99));
-        assert_eq!(999, super::first_n_digits_of(9, 999));
-        assert_eq!(9999, super::first_n_digits_of(9, 9999));
-
-        assert_eq!(0, super::first_n_digits_of(0, 123456789));
-        assert_eq!(1, super::first_n_digits_of(1, 123456789));
-        assert_eq!(12, super::first_n_digits_of(2, 123456789));
-        assert_eq!(123, super::first_n_digits_of(3, 123456789));
-        assert_eq!(1234, super::first_n_digits_of(4, 123456789));
-        assert_eq!(12345, super::first_n_digits_of(5, 123456789));
-        assert_eq!(123456, super::first_n_digits_of(6, 123456789));
-        assert_eq!(1234567, super::first_n_digits_of(7, 123456789));
-        assert_eq!(12345678, super::first_n_digits_of(8, 123456789));
-        assert_eq!(123456789, super::first_n_digits_of(9, 123456789));
+    fn test_timestamp_to_utc() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 2, 3)
+            .with_hms(4, 5, 6)
+            .with_milliseconds(789)
+            .build_at_offset(7 * 60)?;
+        let expected = Utc.from_utc_datetime(
+            &naive_date(2021, 2, 2)
+                .and_hms_milli_opt(21, 5, 6, 789)
+                .unwrap(),
+        );
+        assert_eq!(timestamp.to_utc(), Some(expected));
+
+        // The instant is unknown without an offset or at low precision
+        let unknown_offset =
+            Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6).build_at_unknown_offset()?;
+        assert_eq!(unknown_offset.to_utc(), None);
+        let year_only = Timestamp::with_year(2021).build()?;
+        assert_eq!(year_only.to_utc(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_to_naive() -> IonResult<()> {
+        let expected = naive_date(2021, 2, 3).and_hms_opt(4, 5, 6).unwrap();
+        let known_offset = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6).build_at_offset(7 * 60)?;
+        assert_eq!(known_offset.to_naive(), Some(expected));
+        let unknown_offset =
+            Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6).build_at_unknown_offset()?;
+        assert_eq!(unknown_offset.to_naive(), Some(expected));
+
+        let day = Timestamp::with_ymd(2021, 2, 3).build()?;
+        assert_eq!(
+            day.to_naive(),
+            Some(naive_date(2021, 2, 3).and_hms_opt(0, 0, 0).unwrap())
+        );
+        let month = Timestamp::with_year(2021).with_month(2).build()?;
+        assert_eq!(month.to_naive(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_try_from_utc_datetime() -> IonResult<()> {
+        let utc_date_time = Utc.from_utc_datetime(
+            &naive_date(2021, 2, 3)
+                .and_hms_nano_opt(4, 5, 6, 123_456_789)
+                .unwrap(),
+        );
+        let timestamp = Timestamp::try_from(utc_date_time)?;
+        let expected = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6)
+            .with_nanoseconds(123_456_789)
+            .build_at_offset(0)?;
+        assert_eq!(timestamp, expected);
+        assert_eq!(timestamp.to_utc(), Some(utc_date_time));
+
+        let leap_second = Utc.from_utc_datetime(
+            &naive_date(2016, 12, 31)
+                .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+                .unwrap(),
+        );
+        assert!(Timestamp::try_from(leap_second).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_to_chrono_datetime() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 2, 3)
+            .with_hour_and_minute(4, 5)
+            .build_at_offset(7 * 60)?;
+        let expected = FixedOffset::east_opt(7 * 60 * 60)
+            .unwrap()
+            .with_ymd_and_hms(2021, 2, 3, 4, 5, 0)
+            .unwrap();
+        let date_time = timestamp.to_chrono_datetime().unwrap();
+        assert_eq!(date_time, expected);
+        assert_eq!(date_time.offset(), expected.offset());
+
+        let unknown_offset = Timestamp::with_ymd(2021, 2, 3)
+            .with_hour_and_minute(4, 5)
+            .build_at_unknown_offset()?;
+        assert_eq!(unknown_offset.to_chrono_datetime(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_system_time_round_trip() -> IonResult<()> {
+        use std::time::{Duration, UNIX_EPOCH};
+        for system_time in &[
+            UNIX_EPOCH,
+            UNIX_EPOCH + Duration::new(1_612_325_106, 123_456_789),
+            UNIX_EPOCH - Duration::new(86_400, 250_000_000),
+        ] {
+            let timestamp = Timestamp::try_from(*system_time)?;
+            assert_eq!(timestamp.offset(), Some(0));
+            assert_eq!(timestamp.to_system_time(), Some(*system_time));
+        }
+        let before_epoch = Timestamp::try_from(UNIX_EPOCH - Duration::new(0, 250_000_000))?;
+        let expected = Timestamp::with_ymd_hms(1969, 12, 31, 23, 59, 59)
+            .with_nanoseconds(750_000_000)
+            .build_at_offset(0)?;
+        assert_eq!(before_epoch, expected);
+
+        // Ion timestamps cannot represent years after 9999.
+        let far_future = UNIX_EPOCH + Duration::from_secs(400_000_000_000);
+        assert!(Timestamp::try_from(far_future).is_err());
+        let year_only = Timestamp::with_year(2021).build()?;
+        assert_eq!(year_only.to_system_time(), None);
+        Ok(())
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_timestamp_time_offset_datetime_round_trip() -> IonResult<()> {
+        let offset_date_time =
+            time::OffsetDateTime::from_unix_timestamp_nanos(1_612_325_106_123_456_789)
+                .unwrap()
+                .to_offset(time::UtcOffset::from_whole_seconds(-5 * 60 * 60).unwrap());
+        let timestamp = Timestamp::try_from(offset_date_time)?;
+        let expected = Timestamp::with_ymd_hms(2021, 2, 2, 23, 5, 6)
+            .with_nanoseconds(123_456_789)
+            .build_at_offset(-5 * 60)?;
+        assert_eq!(timestamp, expected);
+        assert_eq!(timestamp.to_time_offset_datetime(), Some(offset_date_time));
+
+        let odd_offset =
+            offset_date_time.to_offset(time::UtcOffset::from_whole_seconds(30).unwrap());
+        assert!(Timestamp::try_from(odd_offset).is_err());
+        // Offsets of a day or more are valid in the time crate but not in chrono.
+        let large_offset =
+            offset_date_time.to_offset(time::UtcOffset::from_whole_seconds(25 * 60 * 60).unwrap());
+        assert!(Timestamp::try_from(large_offset).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_try_from_naive_date() -> IonResult<()> {
+        let timestamp = Timestamp::try_from(naive_date(2021, 2, 3))?;
+        assert_eq!(timestamp.precision, Precision::Day);
+        assert_eq!(timestamp, Timestamp::with_ymd(2021, 2, 3).build()?);
+
+        assert!(Timestamp::try_from(naive_date(10_000, 1, 1)).is_err());
+        assert!(Timestamp::try_from(naive_date(0, 1, 1)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_accessors() -> IonResult<()> {
+        let timestamp = Timestamp::with_year(2021).build()?;
+        assert_eq!(timestamp.precision(), Precision::Year);
+        assert_eq!(timestamp.year(), 2021);
+        assert_eq!(timestamp.month(), None);
+        assert_eq!(timestamp.offset(), None);
+
+        let timestamp = Timestamp::with_year(2021).with_month(2).build()?;
+        assert_eq!(timestamp.precision(), Precision::Month);
+        assert_eq!(timestamp.month(), Some(2));
+        assert_eq!(timestamp.day(), None);
+
+        // The time fields are reported in local time, not in UTC.
+        let timestamp = Timestamp::with_ymd(2021, 2, 3)
+            .with_hour_and_minute(23, 30)
+            .build_at_offset(-5 * 60)?;
+        assert_eq!(timestamp.precision(), Precision::HourAndMinute);
+        assert_eq!(timestamp.offset(), Some(-5 * 60));
+        assert_eq!(timestamp.day(), Some(3));
+        assert_eq!(timestamp.hour(), Some(23));
+        assert_eq!(timestamp.minute(), Some(30));
+        assert_eq!(timestamp.second(), None);
+
+        let timestamp = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6).build_at_unknown_offset()?;
+        assert_eq!(timestamp.precision(), Precision::Second);
+        assert_eq!(timestamp.offset(), None);
+        assert_eq!(timestamp.second(), Some(6));
+        assert_eq!(timestamp.fractional_seconds(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_fractional_seconds() -> IonResult<()> {
+        let builder = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6);
+        let timestamp = builder.clone().with_milliseconds(70).build_at_offset(0)?;
+        assert_eq!(timestamp.precision(), Precision::FractionalSeconds);
+        assert_eq!(timestamp.fractional_seconds(), Some(Decimal::new(70, -3)));
+
+        let timestamp = builder
+            .clone()
+            .with_nanoseconds_and_precision(500_000_000, 1)
+            .build_at_offset(0)?;
+        assert_eq!(timestamp.fractional_seconds(), Some(Decimal::new(5, -1)));
+
+        let picoseconds = Decimal::new(123_456_789_012u64, -12);
+        let timestamp = builder
+            .with_fractional_seconds(picoseconds.clone())
+            .build_at_offset(0)?;
+        assert_eq!(timestamp.fractional_seconds(), Some(picoseconds));
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamp_fractional_seconds_beyond_nanoseconds() -> IonResult<()> {
+        let builder = Timestamp::with_ymd_hms(2021, 2, 3, 4, 5, 6);
+        // Decimals with at most nine digits are stored as nanoseconds...
+        let timestamp = builder
+            .clone()
+            .with_fractional_seconds(Decimal::new(70, -3))
+            .build_at_offset(0)?;
+        assert_eq!(timestamp.fractional_seconds, Some(Mantissa::Digits(3)));
+        assert_eq!(
+            timestamp,
+            builder.clone().with_milliseconds(70).build_at_offset(0)?
+        );
+
+        // ...while more precise Decimals are kept as-is.
+        let coefficient = BigUint::from_str("123456789012345678901234567890").unwrap();
+        let fractional_seconds = Decimal::new(coefficient, -30);
+        let timestamp = builder
+            .clone()
+            .with_fractional_seconds(fractional_seconds.clone())
+            .build_at_offset(0)?;
+        assert_eq!(
+            timestamp.fractional_seconds_as_decimal(),
+            Some(fractional_seconds)
+        );
+        // Conversions to chrono types truncate the fractional seconds to nanoseconds.
+        assert_eq!(
+            timestamp.fractional_seconds_as_nanoseconds(),
+            Some(123_456_789)
+        );
+        let timestamp = builder
+            .clone()
+            .with_fractional_seconds(Decimal::new(999_999_999_999u64, -12))
+            .build_at_offset(0)?;
+        assert_eq!(
+            timestamp.fractional_seconds_as_nanoseconds(),
+            Some(999_999_999)
+        );
+        // Digits far beyond the nanoseconds place truncate to zero nanoseconds.
+        for exponent in &[-40, -(1 << 40), i64::MIN] {
+            let timestamp = builder
+                .clone()
+                .with_fractional_seconds(Decimal::new(5, *exponent))
+                .build_at_offset(0)?;
+            assert_eq!(timestamp.fractional_seconds_as_nanoseconds(), Some(0));
+        }
+        // A zero with a positive exponent has no digits after the decimal point.
+        let timestamp = builder
+            .clone()
+            .with_fractional_seconds(Decimal::new(0, 3))
+            .build_at_offset(0)?;
+        assert_eq!(timestamp.fractional_seconds_as_nanoseconds(), Some(0));
+        assert_eq!(timestamp.to_string(), "2021-02-03T04:05:06+00:00");
+
+        // Fractional seconds must be in the range [0, 1).
+        for invalid in &[
+            Decimal::new(1, 0),
+            Decimal::new(-1, -12),
+            Decimal::new(10, -1),
+        ] {
+            assert!(builder
+                .clone()
+                .with_fractional_seconds(invalid.clone())
+                .build_at_offset(0)
+                .is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_timestamps_with_leading_zeros_in_fractional_seconds() -> IonResult<()> {
+        // 0.005 and 0.050 share their significant digits, but are different amounts of time.
+        let builder = Timestamp::with_ymd_hms(2021, 2, 5, 16, 43, 51);
+        let timestamp1 = builder.clone().with_milliseconds(5).build_at_offset(0)?;
+        let timestamp2 = builder.clone().with_milliseconds(50).build_at_offset(0)?;
+        assert_ne!(timestamp1, timestamp2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_truncated_to() -> IonResult<()> {
+        let timestamp = Timestamp::with_ymd(2021, 2, 3)
+            .with_hms(4, 5, 6)
+            .with_milliseconds(789)
+            .build_at_offset(7 * 60)?;
+        let truncated = |precision| timestamp.truncated_to(precision).unwrap().to_string();
+        assert_eq!(
+            "2021-02-03T04:05:06.789+07:00",
+            truncated(Precision::FractionalSeconds)
+        );
+        assert_eq!("2021-02-03T04:05:06+07:00", truncated(Precision::Second));
+        assert_eq!(
+            "2021-02-03T04:05+07:00",
+            truncated(Precision::HourAndMinute)
+        );
+        assert_eq!("2021-02-03T", truncated(Precision::Day));
+        assert_eq!("2021-02T", truncated(Precision::Month));
+        assert_eq!("2021T", truncated(Precision::Year));
+        assert_eq!(
+            Timestamp::with_ymd(2021, 2, 3).build()?,
+            timestamp.truncated_to(Precision::Day)?
+        );
+        // The local date is kept even when it differs from the date in UTC.
+        let timestamp = Timestamp::with_ymd(2021, 2, 3)
+            .with_hour_and_minute(1, 0)
+            .build_at_offset(7 * 60)?;
+        assert_eq!(
+            "2021-02-03T",
+            timestamp.truncated_to(Precision::Day)?.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_display() -> IonResult<()> {
+        let display = |timestamp: IonResult<Timestamp>| timestamp.unwrap().to_string();
+        assert_eq!(display(Timestamp::with_year(2021).build()), "2021T");
+        assert_eq!(
+            display(Timestamp::with_year(2021).with_month(1).build()),
+            "2021-01T"
+        );
+        assert_eq!(
+            display(Timestamp::with_ymd(2021, 1, 1).build()),
+            "2021-01-01T"
+        );
+        let builder = Timestamp::with_ymd(2021, 1, 1).with_hour_and_minute(23, 30);
+        assert_eq!(
+            display(builder.clone().build_at_unknown_offset()),
+            "2021-01-01T23:30-00:00"
+        );
+        // Fields are written in local time, even if they were specified in UTC.
+        assert_eq!(
+            display(builder.build_utc_fields_at_offset(-90)),
+            "2021-01-01T22:00-01:30"
+        );
+        let builder = Timestamp::with_ymd_hms(2021, 1, 1, 0, 0, 0);
+        assert_eq!(
+            display(builder.clone().build_at_offset(0)),
+            "2021-01-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            display(builder.clone().with_milliseconds(0).build_at_offset(0)),
+            "2021-01-01T00:00:00.000+00:00"
+        );
+        assert_eq!(
+            display(builder.clone().with_microseconds(120).build_at_offset(0)),
+            "2021-01-01T00:00:00.000120+00:00"
+        );
+        assert_eq!(
+            display(
+                builder
+                    .with_fractional_seconds(Decimal::new(123_456_789_012u64, -13))
+                    .build_at_offset(5 * 60)
+            ),
+            "2021-01-01T00:00:00.0123456789012+05:00"
+        );
+        Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "ion-c"))]
 mod ionc_tests {
     use super::*;
     use bigdecimal::BigDecimal;
@@ -11,10 +11,12 @@ use super::{Element, ImportSource, Sequence, Struct, SymbolToken};
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
+use crate::value::writer::fmt_element;
 use crate::value::{AnyInt, Builder};
 use crate::IonType;
 use num_bigint::BigInt;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::fmt;
 use std::iter::FromIterator;
 
 /// A borrowed implementation of [`ImportSource`].
@@ -80,7 +82,7 @@ pub fn local_sid_token<'val>(local_sid: SymbolId) -> BorrowedSymbolToken<'val> {
 /// Constructs an [`BorrowedSymbolToken`] with just text.
 /// A common case for text and synthesizing tokens.
 #[inline]
-pub fn text_token(text: &str) -> BorrowedSymbolToken {
+pub fn text_token(text: &str) -> BorrowedSymbolToken<'_> {
     BorrowedSymbolToken::new(Some(text), None, None)
 }
 
@@ -267,10 +269,18 @@ impl<'val> PartialEq for BorrowedSequence<'val> {
 impl<'val> Eq for BorrowedSequence<'val> {}
 
 /// A borrowed implementation of [`Struct`]
+///
+/// Fields are iterated in a deterministic order that depends only on the struct's contents:
+/// fields with text are ordered by their text (repeated field names in insertion order),
+/// followed by any fields without text in insertion order. Use [`Struct::iter_in_order`] to
+/// visit the fields in the order in which they were added instead.
 #[derive(Debug, Clone)]
 pub struct BorrowedStruct<'val> {
-    text_fields: HashMap<String, Vec<(BorrowedSymbolToken<'val>, BorrowedElement<'val>)>>,
+    text_fields: BTreeMap<String, Vec<(BorrowedSymbolToken<'val>, BorrowedElement<'val>)>>,
     no_text_fields: Vec<(BorrowedSymbolToken<'val>, BorrowedElement<'val>)>,
+    // The location of each field in the order in which the fields were added: its text and
+    // index within `text_fields`, or its index within `no_text_fields` if it has no text.
+    field_order: Vec<(Option<&'val str>, usize)>,
 }
 
 impl<'val> BorrowedStruct<'val> {
@@ -305,20 +315,24 @@ where
 {
     /// Returns a borrowed struct from the given iterator of field names/values.
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut text_fields: HashMap<String, Vec<(BorrowedSymbolToken, BorrowedElement)>> =
-            HashMap::new();
+        let mut text_fields: BTreeMap<String, Vec<(BorrowedSymbolToken, BorrowedElement)>> =
+            BTreeMap::new();
         let mut no_text_fields: Vec<(BorrowedSymbolToken, BorrowedElement)> = Vec::new();
 
+        let mut field_order: Vec<(Option<&'val str>, usize)> = Vec::new();
+
         for (k, v) in iter {
             let key = k.into();
             let val = v.into();
 
-            match key.text() {
+            match key.text {
                 Some(text) => {
                     let vals = text_fields.entry(text.into()).or_insert(Vec::new());
+                    field_order.push((Some(text), vals.len()));
                     vals.push((key, val));
                 }
                 None => {
+                    field_order.push((None, no_text_fields.len()));
                     no_text_fields.push((key, val));
                 }
             }
@@ -327,6 +341,7 @@ where
         Self {
             text_fields,
             no_text_fields,
+            field_order,
         }
     }
 }
@@ -338,7 +353,7 @@ impl<'val> Struct for BorrowedStruct<'val> {
     fn iter<'a>(
         &'a self,
     ) -> Box<dyn Iterator<Item = (&'a Self::FieldName, &'a Self::Element)> + 'a> {
-        // flattens the text_fields HashMap and chains with no_text_fields
+        // flattens the text_fields BTreeMap and chains with no_text_fields
         // to return all fields with iterator
         Box::new(
             self.text_fields
@@ -369,6 +384,18 @@ impl<'val> Struct for BorrowedStruct<'val> {
                 .map(|(_s, v)| v),
         )
     }
+
+    fn iter_in_order<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = (&'a Self::FieldName, &'a Self::Element)> + 'a> {
+        Box::new(self.field_order.iter().map(move |(text, index)| {
+            let (s, v) = match text {
+                Some(text) => &self.text_fields[*text][*index],
+                None => &self.no_text_fields[*index],
+            };
+            (s, v)
+        }))
+    }
 }
 
 impl<'val> PartialEq for BorrowedStruct<'val> {
@@ -419,6 +446,21 @@ impl<'val> BorrowedElement<'val> {
     pub fn new(annotations: Vec<BorrowedSymbolToken<'val>>, value: BorrowedValue<'val>) -> Self {
         Self { annotations, value }
     }
+
+    /// Constructs a `null` of the given type without annotations (e.g. `null.timestamp` for
+    /// `IonType::Timestamp`).
+    ///
+    /// Note that a `null.list`, `null.sexp`, or `null.struct` is distinct from an empty container.
+    pub fn null(ion_type: IonType) -> Self {
+        BorrowedValue::Null(ion_type).into()
+    }
+}
+
+impl<'val> fmt::Display for BorrowedElement<'val> {
+    /// Formats this element as Ion text.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_element(self, f)
+    }
 }
 
 impl<'val> PartialEq for BorrowedElement<'val> {
@@ -0,0 +1,733 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Converts [`Element`]s to JSON, performing the standard Ion-to-JSON down-conversion, and loads
+//! JSON data as Ion with a [`JsonLoader`].
+//!
+//! JSON's data model is a subset of Ion's, so the down-conversion is lossy:
+//!
+//! * Annotations are discarded.
+//! * Nulls of every type become `null`.
+//! * Integers and decimals become JSON numbers. Floats also become numbers, except for `nan`,
+//!   `+inf` and `-inf`, which become `null`.
+//! * Timestamps become strings containing their Ion text, e.g. `"2021-02-03T04:05+00:00"`.
+//! * Symbols become strings containing their text.
+//! * Blobs become strings containing their base64 encoding. Clobs become strings in which each
+//!   byte is read as the Unicode code point of the same value.
+//! * S-expressions become arrays, just like lists. Struct fields are written in the order in
+//!   which the struct iterates over them.
+//!
+//! ```
+//! use ion_rs::result::IonResult;
+//! use ion_rs::value::json::to_json;
+//! use ion_rs::value::reader::{element_reader, ElementReader};
+//!
+//! # fn main() -> IonResult<()> {
+//! let element = element_reader().read_one(b"point::{x: 1.5, tags: (a b), data: {{aGk=}}}")?;
+//! assert_eq!(r#"{"data":"aGk=","tags":["a","b"],"x":1.5}"#, to_json(&element)?);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::io::Write;
+
+use num_bigint::{BigInt, BigUint};
+
+use super::owned::{text_token, OwnedElement, OwnedSequence, OwnedStruct, OwnedValue};
+use super::writer::ElementWriter;
+use super::{AnyInt, Element, Sequence, Struct, SymbolToken};
+use crate::result::{decoding_error, illegal_operation, limit_exceeded_error, IonResult};
+use crate::text::writer::TextWriter;
+use crate::types::coefficient::{Coefficient, Sign};
+use crate::types::decimal::Decimal;
+use crate::types::magnitude::Magnitude;
+use crate::types::timestamp::Timestamp;
+use crate::IonType;
+
+/// An [`ElementWriter`] that down-converts each [`Element`] to JSON. Each top-level value is
+/// followed by a newline, so a stream of several values is written as
+/// [JSON Lines](https://jsonlines.org).
+pub struct JsonWriter<W: Write> {
+    output: W,
+}
+
+impl<W: Write> JsonWriter<W> {
+    pub fn new(output: W) -> Self {
+        JsonWriter { output }
+    }
+}
+
+impl<W: Write> ElementWriter for JsonWriter<W> {
+    type Output = W;
+
+    fn write<E: Element>(&mut self, element: &E) -> IonResult<()> {
+        write_element_to_json(&mut self.output, element)?;
+        writeln!(self.output)?;
+        Ok(())
+    }
+
+    fn finish(mut self) -> IonResult<Self::Output> {
+        self.output.flush()?;
+        Ok(self.output)
+    }
+}
+
+/// Down-converts `element` to a JSON string. Unlike [`JsonWriter`], no trailing newline is added.
+pub fn to_json<E: Element>(element: &E) -> IonResult<String> {
+    let mut output = Vec::new();
+    write_element_to_json(&mut output, element)?;
+    // JSON output is built from Rust strings and ASCII punctuation, so it is always valid UTF-8.
+    Ok(String::from_utf8(output).expect("JSON output was not valid UTF-8"))
+}
+
+/// Writes an [`Element`] (and any children it has) to `output` as a single JSON value.
+fn write_element_to_json<E: Element, W: Write>(output: &mut W, element: &E) -> IonResult<()> {
+    if element.is_null() {
+        write!(output, "null")?;
+        return Ok(());
+    }
+    match element.ion_type() {
+        IonType::Null => unreachable!("non-null element with a null type"),
+        IonType::Boolean => write!(output, "{}", try_to!(element.as_bool()))?,
+        IonType::Integer => match try_to!(element.as_any_int()) {
+            AnyInt::I64(i64_val) => write!(output, "{}", i64_val)?,
+            AnyInt::BigInt(big_val) => write!(output, "{}", big_val)?,
+        },
+        IonType::Float => {
+            let value = try_to!(element.as_f64());
+            if value.is_finite() {
+                // The Debug representation always includes a fractional part or an exponent
+                // (e.g. `1.0`, `1e300`), all of which are valid JSON numbers.
+                write!(output, "{:?}", value)?;
+            } else {
+                write!(output, "null")?;
+            }
+        }
+        IonType::Decimal => write_decimal(output, try_to!(element.as_decimal()))?,
+        IonType::Timestamp => write_timestamp(output, try_to!(element.as_timestamp()))?,
+        IonType::Symbol | IonType::String => write_string(output, try_to!(element.as_str()))?,
+        IonType::Clob => {
+            let text: String = try_to!(element.as_bytes())
+                .iter()
+                .map(|byte| *byte as char)
+                .collect();
+            write_string(output, &text)?;
+        }
+        IonType::Blob => {
+            let base64_text = base64::encode(try_to!(element.as_bytes()));
+            write!(output, "\"{}\"", base64_text)?;
+        }
+        IonType::List | IonType::SExpression => {
+            write!(output, "[")?;
+            for (index, child) in try_to!(element.as_sequence()).iter().enumerate() {
+                if index > 0 {
+                    write!(output, ",")?;
+                }
+                write_element_to_json(output, child)?;
+            }
+            write!(output, "]")?;
+        }
+        IonType::Struct => {
+            write!(output, "{{")?;
+            for (index, (field_name_token, child)) in
+                try_to!(element.as_struct()).iter().enumerate()
+            {
+                if index > 0 {
+                    write!(output, ",")?;
+                }
+                let field_name = match field_name_token.text() {
+                    Some(text) => text,
+                    None => {
+                        return illegal_operation(format!(
+                            "Could not convert a field name with no text to JSON: {:?}",
+                            field_name_token
+                        ))
+                    }
+                };
+                write_string(output, field_name)?;
+                write!(output, ":")?;
+                write_element_to_json(output, child)?;
+            }
+            write!(output, "}}")?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes `text` as a JSON string, escaping quotes, backslashes and control characters.
+fn write_string<W: Write>(output: &mut W, text: &str) -> IonResult<()> {
+    write!(output, "\"")?;
+    for c in text.chars() {
+        match c {
+            '"' => write!(output, "\\\"")?,
+            '\\' => write!(output, "\\\\")?,
+            '\n' => write!(output, "\\n")?,
+            '\r' => write!(output, "\\r")?,
+            '\t' => write!(output, "\\t")?,
+            c if (c as u32) < 0x20 => write!(output, "\\u{:04x}", c as u32)?,
+            c => write!(output, "{}", c)?,
+        }
+    }
+    write!(output, "\"")?;
+    Ok(())
+}
+
+/// Writes a decimal as a JSON number. Ion text notation (e.g. `1.5d3`) is not valid JSON, so
+/// decimals with a negative exponent are written with a decimal point and decimals with a
+/// positive exponent are written in `e` notation. For example, `1.50` is written as `1.50` and `15d3` as `15e3`.
+/// Like [DecimalNotation::Plain](crate::types::decimal::DecimalNotation::Plain), decimals that
+/// would need more than six leading zeros after the decimal point are written in `e` notation
+/// too, so `125d-10` is written as `125e-10` rather than `0.0000000125`.
+fn write_decimal<W: Write>(output: &mut W, decimal: &Decimal) -> IonResult<()> {
+    const MAX_LEADING_ZEROS: usize = 6;
+    if decimal.coefficient().sign() == Sign::Negative {
+        write!(output, "-")?;
+    }
+    let digits = match decimal.coefficient().magnitude() {
+        Magnitude::U64(magnitude) => magnitude.to_string(),
+        Magnitude::BigUInt(magnitude) => magnitude.to_string(),
+    };
+    let exponent = decimal.exponent();
+    if exponent > 0 {
+        write!(output, "{}e{}", digits, exponent)?;
+    } else if exponent == 0 {
+        write!(output, "{}", digits)?;
+    } else {
+        let fractional_digits = exponent.unsigned_abs() as usize;
+        if digits.len() > fractional_digits {
+            let (integer, fraction) = digits.split_at(digits.len() - fractional_digits);
+            write!(output, "{}.{}", integer, fraction)?;
+        } else if fractional_digits - digits.len() <= MAX_LEADING_ZEROS {
+            let leading_zeros = fractional_digits - digits.len();
+            write!(output, "0.{}{}", "0".repeat(leading_zeros), digits)?;
+        } else {
+            write!(output, "{}e{}", digits, exponent)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes a timestamp as a JSON string containing its Ion text.
+fn write_timestamp<W: Write>(output: &mut W, timestamp: &Timestamp) -> IonResult<()> {
+    let mut writer = TextWriter::new(Vec::new());
+    writer.write_timestamp(timestamp)?;
+    writer.flush()?;
+    // The text writer only produces valid UTF-8 and terminates top-level values with a newline.
+    let text = std::str::from_utf8(writer.output()).expect("Ion text was not valid UTF-8");
+    write_string(output, text.trim_end_matches('\n'))
+}
+
+/// How a [`JsonLoader`] converts JSON numbers that have a fractional part or an exponent.
+/// Numbers with neither (e.g. `-17`) always become integers, regardless of their size.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum NumberPolicy {
+    /// Convert the number to a decimal, preserving its exact value and precision. For example,
+    /// `1.50` becomes `1.50` and `1e3` becomes `1d3`.
+    #[default]
+    Decimal,
+    /// Convert the number to the nearest float.
+    Float,
+}
+
+/// Parses JSON text into [`OwnedElement`]s, up-converting each JSON value to the Ion value with
+/// the same meaning. Objects become structs, arrays become lists, strings become strings, and
+/// `true`, `false` and `null` become the Ion values of the same names. Numbers are converted
+/// according to the loader's [`NumberPolicy`].
+///
+/// Combined with an [`ElementWriter`], this can be used to convert JSON data to binary Ion.
+#[derive(Clone, Debug)]
+pub struct JsonLoader {
+    number_policy: NumberPolicy,
+    max_depth: usize,
+}
+
+impl Default for JsonLoader {
+    fn default() -> Self {
+        JsonLoader {
+            number_policy: NumberPolicy::default(),
+            max_depth: JsonLoader::DEFAULT_MAX_DEPTH,
+        }
+    }
+}
+
+impl JsonLoader {
+    /// The maximum depth of nested objects and arrays that a new loader accepts.
+    pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+    pub fn new() -> Self {
+        JsonLoader::default()
+    }
+
+    /// Sets the policy used to convert JSON numbers with a fractional part or an exponent.
+    /// The default is [`NumberPolicy::Decimal`].
+    pub fn with_number_policy(mut self, number_policy: NumberPolicy) -> Self {
+        self.number_policy = number_policy;
+        self
+    }
+
+    /// Sets the maximum depth of nested objects and arrays. A top-level array is at depth 1, so
+    /// `[[]]` reaches depth 2. Input that is nested more deeply produces a decoding error instead
+    /// of exhausting the stack. The default is [`DEFAULT_MAX_DEPTH`](Self::DEFAULT_MAX_DEPTH).
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Parses `json`, which must contain exactly one JSON value.
+    pub fn read_one(&self, json: &str) -> IonResult<OwnedElement> {
+        let mut parser = JsonParser::new(json, self.number_policy, self.max_depth);
+        let element = parser.parse_value()?;
+        parser.skip_whitespace();
+        if !parser.is_at_end() {
+            return parser.error("expected the end of the input after a single value");
+        }
+        Ok(element)
+    }
+
+    /// Parses every JSON value in `json`. The values may be separated by any amount of
+    /// whitespace, which makes this suitable for [JSON Lines](https://jsonlines.org) input.
+    pub fn read_all(&self, json: &str) -> IonResult<Vec<OwnedElement>> {
+        let mut parser = JsonParser::new(json, self.number_policy, self.max_depth);
+        let mut elements = Vec::new();
+        loop {
+            parser.skip_whitespace();
+            if parser.is_at_end() {
+                return Ok(elements);
+            }
+            elements.push(parser.parse_value()?);
+        }
+    }
+}
+
+// A recursive descent parser for the JSON grammar described in RFC 8259.
+struct JsonParser<'a> {
+    text: &'a str,
+    // The byte offset of the next unread character in `text`
+    position: usize,
+    number_policy: NumberPolicy,
+    // The number of objects and arrays that the parser is inside of
+    depth: usize,
+    max_depth: usize,
+}
+
+impl<'a> JsonParser<'a> {
+    fn new(text: &'a str, number_policy: NumberPolicy, max_depth: usize) -> Self {
+        JsonParser {
+            text,
+            position: 0,
+            number_policy,
+            depth: 0,
+            max_depth,
+        }
+    }
+
+    fn error<T>(&self, description: &str) -> IonResult<T> {
+        decoding_error(format!(
+            "Invalid JSON at byte offset {}: {}",
+            self.position, description
+        ))
+    }
+
+    fn is_at_end(&self) -> bool {
+        self.position >= self.text.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    // Consumes `expected` if it is the next byte in the input.
+    fn consume(&mut self, expected: u8) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            return true;
+        }
+        false
+    }
+
+    fn parse_value(&mut self) -> IonResult<OwnedElement> {
+        self.skip_whitespace();
+        let value = match self.peek() {
+            Some(b'{') => OwnedValue::Struct(self.parse_container(Self::parse_object)?),
+            Some(b'[') => OwnedValue::List(self.parse_container(Self::parse_array)?),
+            Some(b'"') => OwnedValue::String(self.parse_string()?),
+            Some(b't') => self.parse_literal("true", OwnedValue::Boolean(true))?,
+            Some(b'f') => self.parse_literal("false", OwnedValue::Boolean(false))?,
+            Some(b'n') => self.parse_literal("null", OwnedValue::Null(IonType::Null))?,
+            Some(b'-') | Some(b'0'..=b'9') => self.parse_number()?,
+            Some(_) => return self.error("expected a value"),
+            None => return self.error("unexpected end of input"),
+        };
+        Ok(value.into())
+    }
+
+    // Parses an object or an array with `parse`, unless doing so would exceed the maximum depth.
+    fn parse_container<T>(&mut self, parse: fn(&mut Self) -> IonResult<T>) -> IonResult<T> {
+        if self.depth >= self.max_depth {
+            return limit_exceeded_error(format!(
+                "Invalid JSON at byte offset {}: nesting exceeds the maximum depth of {}",
+                self.position, self.max_depth
+            ));
+        }
+        self.depth += 1;
+        let container = parse(self);
+        self.depth -= 1;
+        container
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: OwnedValue) -> IonResult<OwnedValue> {
+        if !self.text[self.position..].starts_with(literal) {
+            return self.error("expected `true`, `false` or `null`");
+        }
+        self.position += literal.len();
+        Ok(value)
+    }
+
+    fn parse_object(&mut self) -> IonResult<OwnedStruct> {
+        self.position += 1; // The opening '{'
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.consume(b'}') {
+            return Ok(fields.into_iter().collect());
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some(b'"') {
+                return self.error("expected a field name");
+            }
+            let field_name = self.parse_string()?;
+            self.skip_whitespace();
+            if !self.consume(b':') {
+                return self.error("expected a ':' after the field name");
+            }
+            fields.push((text_token(field_name), self.parse_value()?));
+            self.skip_whitespace();
+            if self.consume(b'}') {
+                return Ok(fields.into_iter().collect());
+            }
+            if !self.consume(b',') {
+                return self.error("expected a ',' or '}' after the field value");
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> IonResult<OwnedSequence> {
+        self.position += 1; // The opening '['
+        let mut children = Vec::new();
+        self.skip_whitespace();
+        if self.consume(b']') {
+            return Ok(OwnedSequence::new(children));
+        }
+        loop {
+            children.push(self.parse_value()?);
+            self.skip_whitespace();
+            if self.consume(b']') {
+                return Ok(OwnedSequence::new(children));
+            }
+            if !self.consume(b',') {
+                return self.error("expected a ',' or ']' after the array element");
+            }
+        }
+    }
+
+    fn parse_string(&mut self) -> IonResult<String> {
+        self.position += 1; // The opening '"'
+        let mut text = String::new();
+        loop {
+            let c = match self.text[self.position..].chars().next() {
+                Some(c) => c,
+                None => return self.error("unterminated string"),
+            };
+            self.position += c.len_utf8();
+            match c {
+                '"' => return Ok(text),
+                '\\' => text.push(self.parse_escape()?),
+                c if (c as u32) < 0x20 => {
+                    return self.error("control characters in strings must be escaped")
+                }
+                c => text.push(c),
+            }
+        }
+    }
+
+    // Parses the remainder of an escape sequence whose leading '\' has been consumed.
+    fn parse_escape(&mut self) -> IonResult<char> {
+        let escaped = match self.peek() {
+            Some(escaped) => escaped,
+            None => return self.error("unterminated string"),
+        };
+        self.position += 1;
+        let c = match escaped {
+            b'"' => '"',
+            b'\\' => '\\',
+            b'/' => '/',
+            b'b' => '\u{08}',
+            b'f' => '\u{0C}',
+            b'n' => '\n',
+            b'r' => '\r',
+            b't' => '\t',
+            b'u' => {
+                let code_unit = self.parse_hex_code_unit()?;
+                let code_point = match code_unit {
+                    // A high surrogate must be followed by an escaped low surrogate; together
+                    // they encode a code point outside of the Basic Multilingual Plane.
+                    0xD800..=0xDBFF => {
+                        if !self.text[self.position..].starts_with("\\u") {
+                            return self.error("expected a low surrogate after a high surrogate");
+                        }
+                        self.position += 2;
+                        let low = self.parse_hex_code_unit()?;
+                        if !(0xDC00..=0xDFFF).contains(&low) {
+                            return self.error("expected a low surrogate after a high surrogate");
+                        }
+                        0x10000 + ((code_unit - 0xD800) << 10) + (low - 0xDC00)
+                    }
+                    code_unit => code_unit,
+                };
+                match std::char::from_u32(code_point) {
+                    Some(c) => c,
+                    None => return self.error("found an unpaired surrogate"),
+                }
+            }
+            _ => return self.error("invalid escape sequence"),
+        };
+        Ok(c)
+    }
+
+    // Parses the four hex digits of a `\u` escape.
+    fn parse_hex_code_unit(&mut self) -> IonResult<u32> {
+        let digits = match self.text.get(self.position..self.position + 4) {
+            Some(digits) if digits.bytes().all(|b| b.is_ascii_hexdigit()) => digits,
+            _ => return self.error("expected four hex digits after '\\u'"),
+        };
+        self.position += 4;
+        Ok(u32::from_str_radix(digits, 16).unwrap())
+    }
+
+    fn parse_number(&mut self) -> IonResult<OwnedValue> {
+        let start = self.position;
+        let is_negative = self.consume(b'-');
+        let integer_start = self.position;
+        match self.peek() {
+            Some(b'0') => self.position += 1,
+            Some(b'1'..=b'9') => self.skip_digits(),
+            _ => return self.error("expected a digit"),
+        }
+        let integer_digits = &self.text[integer_start..self.position];
+
+        let mut fractional_digits = "";
+        if self.consume(b'.') {
+            let fraction_start = self.position;
+            self.skip_digits();
+            if self.position == fraction_start {
+                return self.error("expected a digit after the decimal point");
+            }
+            fractional_digits = &self.text[fraction_start..self.position];
+        }
+
+        let mut exponent_text = None;
+        if self.consume(b'e') || self.consume(b'E') {
+            let exponent_start = self.position;
+            if !self.consume(b'+') {
+                self.consume(b'-');
+            }
+            let digits_start = self.position;
+            self.skip_digits();
+            if self.position == digits_start {
+                return self.error("expected a digit in the exponent");
+            }
+            exponent_text = Some(&self.text[exponent_start..self.position]);
+        }
+
+        let text = &self.text[start..self.position];
+        if fractional_digits.is_empty() && exponent_text.is_none() {
+            let integer = match text.parse::<i64>() {
+                Ok(value) => AnyInt::I64(value),
+                Err(_) => AnyInt::BigInt(text.parse::<BigInt>().unwrap()),
+            };
+            return Ok(OwnedValue::Integer(integer));
+        }
+        if self.number_policy == NumberPolicy::Float {
+            // The JSON number grammar is a subset of the grammar accepted by Rust's float parser.
+            return Ok(OwnedValue::Float(text.parse::<f64>().unwrap()));
+        }
+
+        let exponent = match exponent_text.map(|text| text.trim_start_matches('+').parse::<i64>()) {
+            None => 0,
+            Some(Ok(exponent)) => exponent,
+            Some(Err(_)) => return self.error("the exponent is too large"),
+        };
+        let exponent = match exponent.checked_sub(fractional_digits.len() as i64) {
+            Some(exponent) => exponent,
+            None => return self.error("the exponent is too large"),
+        };
+        let sign = if is_negative {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        };
+        let digits = format!("{}{}", integer_digits, fractional_digits);
+        let coefficient = match digits.parse::<u64>() {
+            Ok(magnitude) => Coefficient::new(sign, magnitude),
+            Err(_) => Coefficient::new(sign, digits.parse::<BigUint>().unwrap()),
+        };
+        Ok(OwnedValue::Decimal(Decimal::new(coefficient, exponent)))
+    }
+
+    fn skip_digits(&mut self) {
+        while let Some(b'0'..=b'9') = self.peek() {
+            self.position += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod json_tests {
+    use super::*;
+    use crate::result::DecodingErrorCategory;
+    use crate::value::reader::{element_reader, ElementReader};
+    use rstest::*;
+
+    #[rstest]
+    #[case("null.struct", "null")]
+    #[case("true", "true")]
+    #[case("-17", "-17")]
+    #[case("123456789012345678901234567890", "123456789012345678901234567890")]
+    #[case("1.5e0", "1.5")]
+    #[case("nan", "null")]
+    #[case("-inf", "null")]
+    #[case("1.50", "1.50")]
+    #[case("0.005", "0.005")]
+    #[case("-0.", "-0")]
+    #[case("15d3", "15e3")]
+    #[case("125d-9", "0.000000125")]
+    #[case("125d-10", "125e-10")]
+    #[case("-1d-9223372036854775808", "-1e-9223372036854775808")]
+    #[case("0d-20", "0e-20")]
+    #[case("2021-02-03T04:05Z", r#""2021-02-03T04:05+00:00""#)]
+    #[case("2021T", r#""2021T""#)]
+    #[case("'hello world'", r#""hello world""#)]
+    #[case(r#""a\"b\\c\nd\x01""#, r#""a\"b\\c\nd\u0001""#)]
+    #[case("{{aGk=}}", r#""aGk=""#)]
+    #[case(r#"{{"hi"}}"#, r#""hi""#)]
+    #[case("(+ 1 2)", r#"["+",1,2]"#)]
+    #[case("foo::[bar::1, [], {}]", "[1,[],{}]")]
+    #[case("{a: 1, 'b c': [true]}", r#"{"a":1,"b c":[true]}"#)]
+    fn test_to_json(#[case] ion: &str, #[case] expected: &str) -> IonResult<()> {
+        let element = element_reader().read_one(ion.as_bytes())?;
+        assert_eq!(expected, to_json(&element)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_writer_writes_lines() -> IonResult<()> {
+        let elements = element_reader().read_all(b"1 two {three: 3}")?;
+        let mut writer = JsonWriter::new(Vec::new());
+        writer.write_all(elements.iter())?;
+        let output = writer.finish()?;
+        assert_eq!(b"1\n\"two\"\n{\"three\":3}\n".to_vec(), output);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("null", "null")]
+    #[case("true", "true")]
+    #[case("-17", "-17")]
+    #[case("123456789012345678901234567890", "123456789012345678901234567890")]
+    #[case("1.50", "1.50")]
+    #[case("-0.0", "-0.0")]
+    #[case("2.5E-3", "2.5d-3")]
+    #[case("1e+3", "1d3")]
+    #[case(r#""a\"b\\c\/dé😀""#, r#""a\"b\\c/dé😀""#)]
+    #[case(r#"[1, "two", [], {}]"#, r#"[1, "two", [], {}]"#)]
+    #[case(r#" {"a": {"b c": [true, null]}} "#, r#"{a: {'b c': [true, null]}}"#)]
+    fn test_read_json(#[case] json: &str, #[case] ion: &str) -> IonResult<()> {
+        let expected = element_reader().read_one(ion.as_bytes())?;
+        let element = JsonLoader::new().read_one(json)?;
+        assert_eq!(expected, element);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_json_decimals_keep_their_precision() -> IonResult<()> {
+        let element = JsonLoader::new().read_one("1.50")?;
+        assert!(element.as_decimal().unwrap().ion_eq(&Decimal::new(150, -2)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_json_with_float_policy() -> IonResult<()> {
+        let loader = JsonLoader::new().with_number_policy(NumberPolicy::Float);
+        let elements = loader.read_all("1.5 2e3 7")?;
+        assert_eq!(elements[0].as_f64(), Some(1.5));
+        assert_eq!(elements[1].as_f64(), Some(2000.0));
+        assert_eq!(elements[2].ion_type(), IonType::Integer);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_json_lines() -> IonResult<()> {
+        let elements = JsonLoader::new().read_all("{\"a\": 1}\n[2]\n\n\"three\"\n")?;
+        assert_eq!(elements.len(), 3);
+        assert!(JsonLoader::new().read_one("1 2").is_err());
+        assert!(JsonLoader::new().read_all("").unwrap().is_empty());
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("[1, 2")]
+    #[case("[1,]")]
+    #[case("{\"a\" 1}")]
+    #[case("{a: 1}")]
+    #[case("01")]
+    #[case("1.")]
+    #[case("1e")]
+    #[case("+1")]
+    #[case("tru")]
+    #[case("\"unterminated")]
+    #[case("\"tab\there\"")]
+    #[case(r#""\x41""#)]
+    #[case(r#""\ud83d""#)]
+    #[case(r#""\ude00""#)]
+    fn test_read_invalid_json(#[case] json: &str) {
+        assert!(JsonLoader::new().read_one(json).is_err());
+    }
+
+    #[test]
+    fn test_read_json_max_depth() -> IonResult<()> {
+        let loader = JsonLoader::new().with_max_depth(2);
+        assert_eq!(
+            loader.read_one(r#"[{"a": []}]"#).unwrap_err().category(),
+            Some(DecodingErrorCategory::LimitExceeded)
+        );
+        assert!(loader.read_one(r#"[{"a": 1}, []]"#).is_ok());
+        assert!(JsonLoader::new().with_max_depth(0).read_one("[]").is_err());
+        // Deeply nested input is rejected by the default limit rather than overflowing the stack.
+        let deep = "[".repeat(100_000);
+        assert!(JsonLoader::new().read_one(&deep).is_err());
+        let nested = format!(
+            "{}{}",
+            "[".repeat(JsonLoader::DEFAULT_MAX_DEPTH),
+            "]".repeat(JsonLoader::DEFAULT_MAX_DEPTH)
+        );
+        assert!(JsonLoader::new().read_one(&nested).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_round_trip() -> IonResult<()> {
+        let json = r#"{"a":[1,2.50,"three",true,null],"b":{"c":-4.5e-7}}"#;
+        let element = JsonLoader::new().read_one(json)?;
+        assert_eq!(
+            r#"{"a":[1,2.50,"three",true,null],"b":{"c":-0.00000045}}"#,
+            to_json(&element)?
+        );
+        Ok(())
+    }
+}
@@ -14,6 +14,7 @@
 //!   instances.
 //! * The [`writer`] module provides API and implementation to write Ion data from [`Element`]
 //!   instances.
+//! * The [`json`] module down-converts [`Element`] instances to JSON.
 //!
 //! ## Examples
 //! In general, users will use the [`ElementReader`](reader::ElementReader) trait to read in data:
@@ -105,6 +106,10 @@
 //! // a fixed buffer length to write to
 //! const BUF_SIZE: usize = 8 * 1024 * 1024;
 //!
+//! # #[cfg(not(feature = "ion-c"))]
+//! # fn main() {}
+//! // Writing to a slice requires the `ion-c` feature
+//! # #[cfg(feature = "ion-c")]
 //! fn main() -> IonResult<()> {
 //!     let elems = element_reader().read_all(b"null true 1")?;
 //!
@@ -212,8 +217,10 @@ use num_traits::ToPrimitive;
 use std::fmt::Debug;
 
 pub mod borrowed;
+pub mod json;
 pub mod owned;
 pub mod reader;
+pub mod tabular;
 pub mod writer;
 
 /// The shared symbol table source of a given [`SymbolToken`].
@@ -472,12 +479,109 @@ where
     /// [gat]: https://rust-lang.github.io/rfcs/1598-generic_associated_types.html
     fn annotations<'a>(&'a self) -> Box<dyn Iterator<Item = &'a Self::SymbolToken> + 'a>;
 
+    /// Returns whether any of this element's annotations has the given text.
+    fn has_annotation(&self, annotation: &str) -> bool {
+        self.annotations()
+            .any(|token| token.text() == Some(annotation))
+    }
+
     /// Return an `Element` with given annotations
     fn with_annotations<I: IntoIterator<Item = Self::SymbolToken>>(self, annotations: I) -> Self;
 
     /// Returns whether this element is a `null` value
     fn is_null(&self) -> bool;
 
+    /// Returns the type of this element if it is a `null` value (e.g. `IonType::Timestamp` for
+    /// `null.timestamp`) or `None` if the element is not `null`.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::IonType;
+    /// # use ion_rs::value::*;
+    /// # use ion_rs::value::owned::*;
+    /// let null_list = OwnedElement::null(IonType::List);
+    /// assert_eq!(Some(IonType::List), null_list.null_type());
+    ///
+    /// // an empty list is not a null list
+    /// let empty_list: OwnedElement = OwnedValue::List(OwnedSequence::new(vec![])).into();
+    /// assert_eq!(None, empty_list.null_type());
+    /// ```
+    fn null_type(&self) -> Option<IonType> {
+        if self.is_null() {
+            Some(self.ion_type())
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether this element is equivalent to `other` under the
+    /// [Ion data model][equivalence].
+    ///
+    /// This is stricter than [`PartialEq`] in the ways that matter to the data model:
+    /// * annotations must match in order.
+    /// * struct fields are compared as a multiset, ignoring order but not duplicates.
+    /// * decimals must have the same precision (`1.0` is not equivalent to `1.00`) and the
+    ///   sign of a zero is significant.
+    /// * floats are compared by value except that `nan` is equivalent to `nan` and
+    ///   `-0e0` is not equivalent to `0e0`.
+    /// * timestamps must have the same precision and offset, not just the same instant.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::result::IonResult;
+    /// # use ion_rs::value::Element;
+    /// # use ion_rs::value::reader::{element_reader, ElementReader};
+    /// # fn main() -> IonResult<()> {
+    /// let a = element_reader().read_one(b"{a: 1.0, b: nan}")?;
+    /// let b = element_reader().read_one(b"{b: nan, a: 1.0}")?;
+    /// assert!(a.ion_eq(&b));
+    ///
+    /// let c = element_reader().read_one(b"{a: 1.00, b: nan}")?;
+    /// assert!(!a.ion_eq(&c));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [equivalence]: https://amzn.github.io/ion-docs/docs/spec.html
+    fn ion_eq(&self, other: &Self) -> bool {
+        elements_ion_eq(self, other)
+    }
+
+    /// Interprets this element as an operation written as an s-expression, such as `(+ 1 2)` or
+    /// `(and (> a 1) (< a 5))`. If this is a non-null s-expression whose first value is a symbol
+    /// with text, returns that text (the operator) and the s-expression's other values (the
+    /// operands), any of which may be an operation of its own. Otherwise, returns `None`.
+    ///
+    /// In text Ion, operators like `+` and `==` do not need to be quoted or separated from
+    /// their operands by whitespace within an s-expression, so `(a==b)` is `(== a b)` written
+    /// infix; this method only looks at the first value.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::result::IonResult;
+    /// # use ion_rs::value::{Element, IntAccess};
+    /// # use ion_rs::value::reader::{native_element_reader, ElementReader};
+    /// # fn main() -> IonResult<()> {
+    /// let expression = native_element_reader().read_one(b"(+ 1 (* 2 3))")?;
+    /// let (operator, operands) = expression.as_operation().unwrap();
+    /// assert_eq!(operator, "+");
+    /// assert_eq!(operands[0].as_i64(), Some(1));
+    /// assert_eq!(operands[1].as_operation().unwrap().0, "*");
+    ///
+    /// let list = native_element_reader().read_one(b"['+', 1, 2]")?;
+    /// assert!(list.as_operation().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn as_operation(&self) -> Option<(&str, Vec<&Self>)> {
+        if self.ion_type() != IonType::SExpression {
+            return None;
+        }
+        let mut values = self.as_sequence()?.iter();
+        let operator = values.next()?.as_sym()?.text()?;
+        Some((operator, values.collect()))
+    }
+
     /// Returns a reference to the underlying [`AnyInt`] for this element.
     ///
     /// This will return `None` if the type is not `int` or the value is any `null`.
@@ -539,6 +643,102 @@ where
     // TODO add mutation methods to the trait
 }
 
+/// Implements [`Element::ion_eq`].
+fn elements_ion_eq<E: Element>(e1: &E, e2: &E) -> bool {
+    if e1.ion_type() != e2.ion_type() || e1.is_null() != e2.is_null() {
+        return false;
+    }
+    if !e1.annotations().eq(e2.annotations()) {
+        return false;
+    }
+    if e1.is_null() {
+        return true;
+    }
+    match e1.ion_type() {
+        IonType::Null => true,
+        IonType::Boolean => e1.as_bool() == e2.as_bool(),
+        IonType::Integer => e1.as_any_int() == e2.as_any_int(),
+        IonType::Float => match (e1.as_f64(), e2.as_f64()) {
+            // comparing the bits keeps `0e0` and `-0e0` distinct
+            (Some(f1), Some(f2)) => (f1.is_nan() && f2.is_nan()) || f1.to_bits() == f2.to_bits(),
+            _ => false,
+        },
+        IonType::Decimal => match (e1.as_decimal(), e2.as_decimal()) {
+            (Some(d1), Some(d2)) => d1.ion_eq(d2),
+            _ => false,
+        },
+        // timestamp equality already takes precision and offset into account
+        IonType::Timestamp => e1.as_timestamp() == e2.as_timestamp(),
+        IonType::Symbol => e1.as_sym() == e2.as_sym(),
+        IonType::String => e1.as_str() == e2.as_str(),
+        IonType::Clob | IonType::Blob => e1.as_bytes() == e2.as_bytes(),
+        IonType::List | IonType::SExpression => match (e1.as_sequence(), e2.as_sequence()) {
+            (Some(seq1), Some(seq2)) => {
+                seq1.len() == seq2.len()
+                    && seq1
+                        .iter()
+                        .zip(seq2.iter())
+                        .all(|(child1, child2)| child1.ion_eq(child2))
+            }
+            _ => false,
+        },
+        IonType::Struct => match (e1.as_struct(), e2.as_struct()) {
+            (Some(struct1), Some(struct2)) => structs_ion_eq(struct1, struct2),
+            _ => false,
+        },
+    }
+}
+
+/// Returns whether two streams of elements are equivalent under the Ion data model: they must
+/// contain the same number of values, and each pair of values in the same position must be
+/// equivalent according to [`Element::ion_eq`].
+///
+/// ## Usage
+/// ```
+/// # use ion_rs::result::IonResult;
+/// # use ion_rs::value::streams_ion_eq;
+/// # use ion_rs::value::reader::{element_reader, ElementReader};
+/// # fn main() -> IonResult<()> {
+/// let stream1 = element_reader().read_all(b"a::1 nan {b: 2.0}")?;
+/// let stream2 = element_reader().read_all(b"'a'::1 nan {'b': 20d-1}")?;
+/// let stream3 = element_reader().read_all(b"a::1 nan {b: 2.00}")?;
+/// assert!(streams_ion_eq(&stream1, &stream2));
+/// assert!(!streams_ion_eq(&stream1, &stream3));
+/// assert!(!streams_ion_eq(&stream1, &stream1[..2]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn streams_ion_eq<E: Element>(stream1: &[E], stream2: &[E]) -> bool {
+    stream1.len() == stream2.len()
+        && stream1
+            .iter()
+            .zip(stream2.iter())
+            .all(|(e1, e2)| e1.ion_eq(e2))
+}
+
+/// Compares the fields of two structs as multisets of (field name, value) pairs.
+fn structs_ion_eq<S: Struct + ?Sized>(struct1: &S, struct2: &S) -> bool {
+    let fields1: Vec<_> = struct1.iter().collect();
+    // each field of `struct2` can only be matched once so that duplicates are accounted for
+    let mut unmatched: Vec<_> = struct2.iter().map(Some).collect();
+    if fields1.len() != unmatched.len() {
+        return false;
+    }
+    fields1.into_iter().all(|(name1, value1)| {
+        let matched = unmatched.iter_mut().find(|field| match field {
+            Some((name2, value2)) => name1 == *name2 && value1.ion_eq(value2),
+            None => false,
+        });
+        match matched {
+            Some(field) => {
+                *field = None;
+                true
+            }
+            None => false,
+        }
+    })
+}
+
 impl<T> IntAccess for T
 where
     T: Element,
@@ -656,6 +856,64 @@ pub trait Struct: Debug + PartialEq {
         &'a self,
         field_name: T,
     ) -> Box<dyn Iterator<Item = &'a Self::Element> + 'a>;
+
+    /// The fields of the structure, in the order in which they were added to it. For a struct
+    /// that was read from a stream, this is the order in which they were encountered.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::value::*;
+    /// # use ion_rs::value::owned::*;
+    /// let fields: Vec<(&str, OwnedValue)>= vec![("b", "c"), ("a", "d"), ("b", "e")]
+    ///     .into_iter().map(|(k, v)| (k, OwnedValue::String(v.into()))).collect();
+    /// let owned: OwnedStruct = fields.into_iter().collect();
+    /// assert_eq!(
+    ///     vec![("b", "c"), ("a", "d"), ("b", "e")],
+    ///     owned.iter_in_order()
+    ///         .map(|(k, v)| (k.text().unwrap(), v.as_str().unwrap()))
+    ///         .collect::<Vec<(&str, &str)>>()
+    /// );
+    /// ```
+    fn iter_in_order<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = (&'a Self::FieldName, &'a Self::Element)> + 'a>;
+
+    /// Returns the last value corresponding to the field_name that has the given annotation, or
+    /// returns `None` if there is no such value.
+    ///
+    /// ## Usage
+    /// ```
+    /// # use ion_rs::value::*;
+    /// # use ion_rs::value::owned::*;
+    /// let owned: OwnedStruct = vec![
+    ///     ("a", OwnedElement::new_i64(1).with_annotations(vec![text_token("x")])),
+    ///     ("a", OwnedElement::new_i64(2)),
+    /// ].into_iter().collect();
+    /// assert_eq!(Some(1), owned.get_annotated("a", "x").and_then(|e| e.as_i64()));
+    /// assert_eq!(Some(2), owned.get("a").and_then(|e| e.as_i64()));
+    /// ```
+    fn get_annotated<T: AsRef<str>, A: AsRef<str>>(
+        &self,
+        field_name: T,
+        annotation: A,
+    ) -> Option<&Self::Element> {
+        self.get_all_annotated(field_name, annotation).last()
+    }
+
+    /// Returns an iterator with all the values corresponding to the field_name that have the
+    /// given annotation. As with [get_all](Self::get_all), the iterator is empty if there are
+    /// no such values.
+    fn get_all_annotated<'a, T: AsRef<str>, A: AsRef<str>>(
+        &'a self,
+        field_name: T,
+        annotation: A,
+    ) -> Box<dyn Iterator<Item = &'a Self::Element> + 'a> {
+        let annotation = annotation.as_ref().to_string();
+        Box::new(
+            self.get_all(field_name)
+                .filter(move |value| value.has_annotation(&annotation)),
+        )
+    }
 }
 
 pub trait Builder {
@@ -718,6 +976,7 @@ mod generic_value_tests {
     use crate::types::timestamp::Timestamp;
     use crate::value::borrowed::*;
     use crate::value::owned::*;
+    use crate::value::reader::{element_reader, native_element_reader, ElementReader};
     use crate::value::{Element, IntAccess};
     use crate::{value, IonType};
     use chrono::*;
@@ -1316,6 +1575,67 @@ mod generic_value_tests {
         }
     }
 
+    fn struct_field_lookups_case<E: Element>() -> E
+    where
+        E::Builder: Builder<SymbolToken = E::SymbolToken>,
+    {
+        // {b: 1, a: foo::2, $21: 3, a: 4, a: foo::5}
+        let foo = || vec![E::SymbolToken::text_token("foo")];
+        E::Builder::new_struct(
+            vec![
+                (E::SymbolToken::text_token("b"), E::Builder::new_i64(1)),
+                (
+                    E::SymbolToken::text_token("a"),
+                    E::Builder::new_i64(2).with_annotations(foo()),
+                ),
+                (E::SymbolToken::local_sid_token(21), E::Builder::new_i64(3)),
+                (E::SymbolToken::text_token("a"), E::Builder::new_i64(4)),
+                (
+                    E::SymbolToken::text_token("a"),
+                    E::Builder::new_i64(5).with_annotations(foo()),
+                ),
+            ]
+            .into_iter(),
+        )
+    }
+
+    #[rstest]
+    #[case::owned(struct_field_lookups_case::<OwnedElement>())]
+    #[case::borrowed(struct_field_lookups_case::<BorrowedElement>())]
+    fn struct_field_lookups<E: Element>(#[case] element: E) {
+        let struct_value = element.as_struct().unwrap();
+        let ints = |values: Vec<&E>| -> Vec<i64> {
+            values.iter().map(|value| value.as_i64().unwrap()).collect()
+        };
+
+        assert_eq!(Some(5), struct_value.get("a").and_then(|v| v.as_i64()));
+        assert_eq!(vec![2, 4, 5], ints(struct_value.get_all("a").collect()));
+        assert_eq!(None, struct_value.get("c"));
+
+        let (names, values): (Vec<_>, Vec<_>) = struct_value
+            .iter_in_order()
+            .map(|(name, value)| (name.text(), value))
+            .unzip();
+        assert_eq!(
+            vec![Some("b"), Some("a"), None, Some("a"), Some("a")],
+            names
+        );
+        assert_eq!(vec![1, 2, 3, 4, 5], ints(values));
+
+        assert_eq!(
+            Some(5),
+            struct_value
+                .get_annotated("a", "foo")
+                .and_then(|v| v.as_i64())
+        );
+        assert_eq!(
+            vec![2, 5],
+            ints(struct_value.get_all_annotated("a", "foo").collect())
+        );
+        assert_eq!(None, struct_value.get_annotated("a", "bar"));
+        assert_eq!(None, struct_value.get_annotated("b", "foo"));
+    }
+
     /// Models the operations on `Element` that we want to test.
     #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
     enum ElemOp {
@@ -1359,7 +1679,10 @@ mod generic_value_tests {
             elem: E::Builder::new_null(IonType::Null),
             ion_type: IonType::Null,
             ops: vec![IsNull],
-            op_assert: Box::new(|e: &E| assert_eq!(true, e.is_null())),
+            op_assert: Box::new(|e: &E| {
+                assert_eq!(true, e.is_null());
+                assert_eq!(Some(IonType::Null), e.null_type());
+            }),
         }
     }
 
@@ -1655,7 +1978,10 @@ mod generic_value_tests {
     fn element_accessors<E: Element>(#[case] input_case: Case<E>) {
         // table of negative assertions for each operation
         let neg_table: Vec<(ElemOp, &ElemAssertFunc<E>)> = vec![
-            (IsNull, &|e| assert_eq!(false, e.is_null())),
+            (IsNull, &|e| {
+                assert_eq!(false, e.is_null());
+                assert_eq!(None, e.null_type());
+            }),
             (AsBool, &|e| assert_eq!(None, e.as_bool())),
             (AsAnyInt, &|e| {
                 assert_eq!(None, e.as_any_int());
@@ -1692,4 +2018,72 @@ mod generic_value_tests {
         // assert that a value element as-is is equal to itself
         assert_eq!(input_case.elem, input_case.elem);
     }
+
+    #[rstest]
+    #[case::prefix(b"(+ 1 2)", "+", &["1", "2"])]
+    #[case::infix(b"(a==b)", "a", &["'=='", "b"])]
+    #[case::adjacent_operators(b"(==a -1)", "==", &["a", "-1"])]
+    #[case::quoted_operator(b"('.' \"a\".\"b\")", ".", &["\"a\"", "'.'", "\"b\""])]
+    #[case::no_operands(b"(nop)", "nop", &[])]
+    #[case::nested(b"(and (> a 1) (<b 5))", "and", &["(> a 1)", "(< b 5)"])]
+    fn as_operation(#[case] text: &[u8], #[case] operator: &str, #[case] operands: &[&str]) {
+        let element = native_element_reader().read_one(text).unwrap();
+        let (actual_operator, actual_operands) = element.as_operation().unwrap();
+        assert_eq!(actual_operator, operator);
+        let operands: Vec<OwnedElement> = operands
+            .iter()
+            .map(|text| native_element_reader().read_one(text.as_bytes()).unwrap())
+            .collect();
+        assert_eq!(actual_operands, operands.iter().collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    #[case::list(b"['+', 1]")]
+    #[case::null_sexp(b"null.sexp")]
+    #[case::empty_sexp(b"()")]
+    #[case::not_a_symbol(b"(1 +)")]
+    #[case::unknown_symbol_text(b"($0 1)")]
+    fn as_operation_none(#[case] text: &[u8]) {
+        let element = native_element_reader().read_one(text).unwrap();
+        assert!(element.as_operation().is_none());
+    }
+
+    #[rstest]
+    #[case::annotations(b"a::b::1", b"a::b::1")]
+    #[case::struct_field_order(b"{a: 1, b: 2}", b"{b: 2, a: 1}")]
+    #[case::struct_duplicate_fields(b"{a: 1, a: 2, b: 3}", b"{a: 2, b: 3, a: 1}")]
+    #[case::nan(b"nan", b"nan")]
+    #[case::decimal(b"1.0", b"10d-1")]
+    #[case::timestamp(b"2001-01-01T00:00Z", b"2001-01-01T00:00+00:00")]
+    #[case::nested(b"[1, (a {b: [c]})]", b"[1, (a {b: [c]})]")]
+    #[case::typed_null(b"null.list", b"null.list")]
+    fn ion_eq(#[case] text1: &[u8], #[case] text2: &[u8]) {
+        let elem1 = element_reader().read_one(text1).unwrap();
+        let elem2 = element_reader().read_one(text2).unwrap();
+        assert!(elem1.ion_eq(&elem2));
+        assert!(elem2.ion_eq(&elem1));
+    }
+
+    #[rstest]
+    #[case::annotation_order(b"a::b::1", b"b::a::1")]
+    #[case::missing_annotation(b"a::1", b"1")]
+    #[case::decimal_precision(b"1.0", b"1.00")]
+    #[case::decimal_negative_zero(b"0d0", b"-0d0")]
+    #[case::float_negative_zero(b"0e0", b"-0e0")]
+    #[case::int_and_float(b"1", b"1e0")]
+    #[case::struct_duplicate_count(b"{a: 1}", b"{a: 1, a: 1}")]
+    #[case::struct_duplicate_values(b"{a: 1, a: 1}", b"{a: 1, a: 2}")]
+    #[case::struct_nested_decimal(b"{a: 1.0}", b"{a: 1.00}")]
+    #[case::list_order(b"[1, 2]", b"[2, 1]")]
+    #[case::timestamp_precision(b"2001T", b"2001-01T")]
+    #[case::timestamp_offset(b"2001-01-01T00:00Z", b"2000-12-31T23:00-01:00")]
+    #[case::timestamp_unknown_offset(b"2001-01-01T00:00Z", b"2001-01-01T00:00-00:00")]
+    #[case::null_and_empty_list(b"null.list", b"[]")]
+    #[case::null_types(b"null", b"null.int")]
+    fn not_ion_eq(#[case] text1: &[u8], #[case] text2: &[u8]) {
+        let elem1 = element_reader().read_one(text1).unwrap();
+        let elem2 = element_reader().read_one(text2).unwrap();
+        assert!(!elem1.ion_eq(&elem2));
+        assert!(!elem2.ion_eq(&elem1));
+    }
 }
@@ -3,28 +3,35 @@
 //! Provides owned implementations of [`SymbolToken`], [`Element`] and its dependents.
 //!
 //! This API is simpler to manage with respect to borrowing lifetimes, but requires full
-//! ownership of data to do so.
+//! ownership of data to do so. Symbol text is reference counted with [`Arc`], so every type in
+//! this module is `Send` and `Sync` and can be passed between threads.
 
 use super::{AnyInt, Element, ImportSource, Sequence, Struct, SymbolToken};
+use crate::result::{decoding_error, IonError, IonResult};
+use crate::symbol::Symbol;
 use crate::types::decimal::Decimal;
 use crate::types::timestamp::Timestamp;
 use crate::types::SymbolId;
+use crate::value::reader::{element_reader, ElementReader};
+use crate::value::writer::{fmt_element, ElementWriter};
 use crate::value::Builder;
 use crate::IonType;
 use num_bigint::BigInt;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::TryFrom;
+use std::fmt;
 use std::iter::FromIterator;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// An owned implementation of  [`ImportSource`].
 #[derive(Debug, Clone)]
 pub struct OwnedImportSource {
-    table: Rc<str>,
+    table: Arc<str>,
     sid: SymbolId,
 }
 
 impl OwnedImportSource {
-    pub fn new<T: Into<Rc<str>>>(table: T, sid: SymbolId) -> Self {
+    pub fn new<T: Into<Arc<str>>>(table: T, sid: SymbolId) -> Self {
         Self {
             table: table.into(),
             sid,
@@ -53,14 +60,14 @@ impl ImportSource for OwnedImportSource {
 /// An owned implementation of [`SymbolToken`].
 #[derive(Debug, Clone)]
 pub struct OwnedSymbolToken {
-    text: Option<Rc<str>>,
+    text: Option<Arc<str>>,
     local_sid: Option<SymbolId>,
     source: Option<OwnedImportSource>,
 }
 
 impl OwnedSymbolToken {
     fn new(
-        text: Option<Rc<str>>,
+        text: Option<Arc<str>>,
         local_sid: Option<SymbolId>,
         source: Option<OwnedImportSource>,
     ) -> Self {
@@ -82,7 +89,7 @@ pub fn local_sid_token(local_sid: SymbolId) -> OwnedSymbolToken {
 /// Constructs an [`OwnedSymbolToken`] with just text.
 /// A common case for text and synthesizing tokens.
 #[inline]
-pub fn text_token<T: Into<Rc<str>>>(text: T) -> OwnedSymbolToken {
+pub fn text_token<T: Into<Arc<str>>>(text: T) -> OwnedSymbolToken {
     OwnedSymbolToken::new(Some(text.into()), None, None)
 }
 
@@ -100,7 +107,7 @@ impl PartialEq for OwnedSymbolToken {
 
 impl Eq for OwnedSymbolToken {}
 
-impl<T: Into<Rc<str>>> From<T> for OwnedSymbolToken {
+impl<T: Into<Arc<str>>> From<T> for OwnedSymbolToken {
     /// Constructs an owned token that has only text.
     fn from(text: T) -> Self {
         text_token(text)
@@ -123,7 +130,7 @@ impl SymbolToken for OwnedSymbolToken {
     }
 
     fn with_text(self, text: &'static str) -> Self {
-        OwnedSymbolToken::new(Some(Rc::from(text)), self.local_sid, self.source)
+        OwnedSymbolToken::new(Some(Arc::from(text)), self.local_sid, self.source)
     }
 
     fn with_local_sid(self, local_sid: SymbolId) -> Self {
@@ -139,7 +146,7 @@ impl SymbolToken for OwnedSymbolToken {
     }
 
     fn text_token(text: &'static str) -> Self {
-        OwnedSymbolToken::new(Some(Rc::from(text)), None, None)
+        OwnedSymbolToken::new(Some(Arc::from(text)), None, None)
     }
 
     fn local_sid_token(local_sid: usize) -> Self {
@@ -228,6 +235,42 @@ impl OwnedSequence {
     pub fn new(children: Vec<OwnedElement>) -> Self {
         Self { children }
     }
+
+    /// Appends `element` to the end of the sequence.
+    pub fn push<E: Into<OwnedElement>>(&mut self, element: E) {
+        self.children.push(element.into());
+    }
+
+    /// Inserts `element` at `index`, shifting the elements after it. Panics if `index` is
+    /// greater than the sequence's length.
+    pub fn insert<E: Into<OwnedElement>>(&mut self, index: usize, element: E) {
+        self.children.insert(index, element.into());
+    }
+
+    /// Removes and returns the element at `index`, or returns `None` if the sequence does not
+    /// have that many elements.
+    pub fn remove(&mut self, index: usize) -> Option<OwnedElement> {
+        if index < self.children.len() {
+            Some(self.children.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, if there is one.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut OwnedElement> {
+        self.children.get_mut(index)
+    }
+
+    /// Returns an iterator over mutable references to the elements, in order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut OwnedElement> {
+        self.children.iter_mut()
+    }
+
+    /// Provides mutable access to the children so that their allocations can be reused.
+    pub(crate) fn children_mut(&mut self) -> &mut Vec<OwnedElement> {
+        &mut self.children
+    }
 }
 
 impl FromIterator<OwnedElement> for OwnedSequence {
@@ -241,6 +284,16 @@ impl FromIterator<OwnedElement> for OwnedSequence {
     }
 }
 
+impl IntoIterator for OwnedSequence {
+    type Item = OwnedElement;
+    type IntoIter = std::vec::IntoIter<OwnedElement>;
+
+    /// Consumes the sequence, yielding its children in order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.children.into_iter()
+    }
+}
+
 impl Sequence for OwnedSequence {
     type Element = OwnedElement;
 
@@ -270,13 +323,66 @@ impl PartialEq for OwnedSequence {
 impl Eq for OwnedSequence {}
 
 /// An owned implementation of [`Struct`]
-#[derive(Debug, Clone)]
+///
+/// Fields are iterated in a deterministic order that depends only on the struct's contents:
+/// fields with text are ordered by their text (repeated field names in insertion order),
+/// followed by any fields without text in insertion order. Use [`Struct::iter_in_order`] to
+/// visit the fields in the order in which they were added instead.
+#[derive(Debug, Clone, Default)]
 pub struct OwnedStruct {
-    text_fields: HashMap<Rc<str>, Vec<(OwnedSymbolToken, OwnedElement)>>,
+    text_fields: BTreeMap<Arc<str>, Vec<(OwnedSymbolToken, OwnedElement)>>,
     no_text_fields: Vec<(OwnedSymbolToken, OwnedElement)>,
+    // The location of each field in the order in which the fields were added: its text and
+    // index within `text_fields`, or its index within `no_text_fields` if it has no text.
+    field_order: Vec<(Option<Arc<str>>, usize)>,
 }
 
 impl OwnedStruct {
+    /// Adds a field named `name`. If the struct already has fields with that name, they are
+    /// kept; the new field follows them.
+    pub fn add_field<K, V>(&mut self, name: K, value: V)
+    where
+        K: Into<OwnedSymbolToken>,
+        V: Into<OwnedElement>,
+    {
+        let name = name.into();
+        let value = value.into();
+        match name.text() {
+            Some(text) => {
+                let text: Arc<str> = text.into();
+                let values = self.text_fields.entry(text.clone()).or_default();
+                self.field_order.push((Some(text), values.len()));
+                values.push((name, value));
+            }
+            None => {
+                self.field_order.push((None, self.no_text_fields.len()));
+                self.no_text_fields.push((name, value));
+            }
+        }
+    }
+
+    /// Removes every field named `name`, returning their values in the order in which they were
+    /// added. Returns an empty Vec if the struct has no such field.
+    pub fn remove_field<T: AsRef<str>>(&mut self, name: T) -> Vec<OwnedElement> {
+        let name = name.as_ref();
+        let fields = match self.text_fields.remove(name) {
+            Some(fields) => fields,
+            None => return Vec::new(),
+        };
+        self.field_order
+            .retain(|(text, _)| text.as_deref() != Some(name));
+        fields.into_iter().map(|(_name, value)| value).collect()
+    }
+
+    /// Returns a mutable reference to the value of the last field named `name`, which is the
+    /// field that [`Struct::get`] returns.
+    pub fn get_mut<T: AsRef<str>>(&mut self, name: T) -> Option<&mut OwnedElement> {
+        self.text_fields
+            .get_mut(name.as_ref())?
+            .last_mut()
+            .map(|(_name, value)| value)
+    }
+
     fn eq_text_fields(&self, other: &Self) -> bool {
         // check if both the text_fields have same (field_name,value) pairs
         self.text_fields.iter().all(|(key, value)| {
@@ -308,29 +414,23 @@ where
 {
     /// Returns an owned struct from the given iterator of field names/values.
     fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut text_fields: HashMap<Rc<str>, Vec<(OwnedSymbolToken, OwnedElement)>> =
-            HashMap::new();
-        let mut no_text_fields: Vec<(OwnedSymbolToken, OwnedElement)> = Vec::new();
-
+        let mut owned_struct = OwnedStruct::default();
         for (k, v) in iter {
-            let key = k.into();
-            let val = v.into();
-
-            match key.text() {
-                Some(text) => {
-                    let vals = text_fields.entry(text.into()).or_insert(Vec::new());
-                    vals.push((key, val));
-                }
-                None => {
-                    no_text_fields.push((key, val));
-                }
-            }
+            owned_struct.add_field(k, v);
         }
+        owned_struct
+    }
+}
 
-        Self {
-            text_fields,
-            no_text_fields,
-        }
+impl IntoIterator for OwnedStruct {
+    type Item = (OwnedSymbolToken, OwnedElement);
+    type IntoIter = std::vec::IntoIter<(OwnedSymbolToken, OwnedElement)>;
+
+    /// Consumes the struct, yielding its fields in the same order as [`Struct::iter`].
+    fn into_iter(self) -> Self::IntoIter {
+        let mut fields: Vec<_> = self.text_fields.into_values().flatten().collect();
+        fields.extend(self.no_text_fields);
+        fields.into_iter()
     }
 }
 
@@ -342,7 +442,7 @@ impl Struct for OwnedStruct {
         &'a self,
     ) -> Box<dyn Iterator<Item = (&'a Self::FieldName, &'a Self::Element)> + 'a> {
         // convert &(k, v) -> (&k, &v)
-        // flattens the fields_with_text_key HashMap and chains with fields_with_no_text_key
+        // flattens the fields_with_text_key BTreeMap and chains with fields_with_no_text_key
         // to return all fields with iterator
         Box::new(
             self.text_fields
@@ -373,6 +473,18 @@ impl Struct for OwnedStruct {
                 .map(|(_s, v)| v),
         )
     }
+
+    fn iter_in_order<'a>(
+        &'a self,
+    ) -> Box<dyn Iterator<Item = (&'a Self::FieldName, &'a Self::Element)> + 'a> {
+        Box::new(self.field_order.iter().map(move |(text, index)| {
+            let (s, v) = match text {
+                Some(text) => &self.text_fields[text][*index],
+                None => &self.no_text_fields[*index],
+            };
+            (s, v)
+        }))
+    }
 }
 
 impl PartialEq for OwnedStruct {
@@ -421,6 +533,110 @@ impl OwnedElement {
     pub fn new(annotations: Vec<OwnedSymbolToken>, value: OwnedValue) -> Self {
         Self { annotations, value }
     }
+
+    /// Constructs a `null` of the given type without annotations (e.g. `null.timestamp` for
+    /// `IonType::Timestamp`).
+    ///
+    /// Note that a `null.list`, `null.sexp`, or `null.struct` is distinct from an empty container.
+    pub fn null(ion_type: IonType) -> Self {
+        OwnedValue::Null(ion_type).into()
+    }
+
+    /// Parses all of the top-level values in `data`, which may be either text or binary Ion.
+    ///
+    /// This is shorthand for [`element_reader().read_all(data)`](ElementReader::read_all).
+    pub fn read_all(data: &[u8]) -> IonResult<Vec<OwnedElement>> {
+        element_reader().read_all(data)
+    }
+
+    /// Serializes this element (and any children it has) as a single top-level value.
+    pub fn write_to<W: ElementWriter>(&self, writer: &mut W) -> IonResult<()> {
+        writer.write(self)
+    }
+
+    /// Returns a reference to this element's value.
+    pub fn value(&self) -> &OwnedValue {
+        &self.value
+    }
+
+    /// Returns a mutable reference to this element's value so that it can be modified in place.
+    pub fn value_mut(&mut self) -> &mut OwnedValue {
+        &mut self.value
+    }
+
+    /// Returns this element with its annotations replaced by `annotations`. Unlike
+    /// [`Element::with_annotations`], the annotations can be anything that converts into an
+    /// [`OwnedSymbolToken`], such as `&str`.
+    ///
+    /// ```
+    /// use ion_rs::value::owned::{struct_builder, OwnedElement};
+    ///
+    /// let element = struct_builder().field("a", 1).build().with_annotations(vec!["point"]);
+    /// assert_eq!(element, OwnedElement::read_all(b"point::{a: 1}")?.remove(0));
+    /// # Ok::<(), ion_rs::result::IonError>(())
+    /// ```
+    pub fn with_annotations<I, A>(mut self, annotations: I) -> Self
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<OwnedSymbolToken>,
+    {
+        self.annotations = annotations.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Returns a mutable reference to this element's annotations.
+    pub fn annotations_mut(&mut self) -> &mut Vec<OwnedSymbolToken> {
+        &mut self.annotations
+    }
+
+    /// Returns a mutable reference to this element's struct if it is a non-null struct, so that
+    /// its fields can be edited in place.
+    pub fn as_struct_mut(&mut self) -> Option<&mut OwnedStruct> {
+        match &mut self.value {
+            OwnedValue::Struct(structure) => Some(structure),
+            _ => None,
+        }
+    }
+
+    /// Returns a mutable reference to this element's sequence if it is a non-null list or
+    /// s-expression, so that its elements can be edited in place.
+    pub fn as_sequence_mut(&mut self) -> Option<&mut OwnedSequence> {
+        match &mut self.value {
+            OwnedValue::List(sequence) | OwnedValue::SExpression(sequence) => Some(sequence),
+            _ => None,
+        }
+    }
+
+    /// Returns this element's annotations as [Symbol]s, in order.
+    pub fn annotation_symbols(&self) -> impl Iterator<Item = Symbol> + '_ {
+        self.annotations.iter().map(Symbol::from)
+    }
+
+    /// Returns this element's value as a [Symbol] if it is a non-null symbol.
+    pub fn as_symbol(&self) -> Option<Symbol> {
+        match &self.value {
+            OwnedValue::Symbol(token) => Some(Symbol::from(token)),
+            _ => None,
+        }
+    }
+
+    /// Consumes the element, returning its annotations and value.
+    pub(crate) fn into_parts(self) -> (Vec<OwnedSymbolToken>, OwnedValue) {
+        (self.annotations, self.value)
+    }
+
+    /// Provides mutable access to the annotations and value so that their allocations can be
+    /// reused.
+    pub(crate) fn parts_mut(&mut self) -> (&mut Vec<OwnedSymbolToken>, &mut OwnedValue) {
+        (&mut self.annotations, &mut self.value)
+    }
+}
+
+impl fmt::Display for OwnedElement {
+    /// Formats this element as Ion text.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_element(self, f)
+    }
 }
 
 impl PartialEq for OwnedElement {
@@ -479,6 +695,18 @@ impl From<bool> for OwnedElement {
     }
 }
 
+impl From<i32> for OwnedElement {
+    fn from(i32_val: i32) -> Self {
+        OwnedValue::Integer(AnyInt::I64(i32_val.into())).into()
+    }
+}
+
+impl From<&str> for OwnedElement {
+    fn from(string_val: &str) -> Self {
+        OwnedValue::String(string_val.into()).into()
+    }
+}
+
 impl From<String> for OwnedElement {
     fn from(string_val: String) -> Self {
         OwnedValue::String(string_val).into()
@@ -497,6 +725,243 @@ impl From<OwnedStruct> for OwnedElement {
     }
 }
 
+/// Converts the bytes to a blob.
+impl From<Vec<u8>> for OwnedElement {
+    fn from(bytes_val: Vec<u8>) -> Self {
+        OwnedValue::Blob(bytes_val).into()
+    }
+}
+
+/// Converts the elements to a list.
+impl From<Vec<OwnedElement>> for OwnedElement {
+    fn from(list_val: Vec<OwnedElement>) -> Self {
+        OwnedValue::List(list_val.into_iter().collect()).into()
+    }
+}
+
+/// Converts the entries to the fields of a struct, in an unspecified order.
+impl From<HashMap<String, OwnedElement>> for OwnedElement {
+    fn from(map_val: HashMap<String, OwnedElement>) -> Self {
+        OwnedValue::Struct(map_val.into_iter().collect()).into()
+    }
+}
+
+/// Converts the entries to the fields of a struct, in the map's order.
+impl From<BTreeMap<String, OwnedElement>> for OwnedElement {
+    fn from(map_val: BTreeMap<String, OwnedElement>) -> Self {
+        OwnedValue::Struct(map_val.into_iter().collect()).into()
+    }
+}
+
+// The conversions below discard the element's annotations. A null of any type is an error.
+
+// Returns a decoding error if `element` is a null, or an [IonError::UnexpectedType] otherwise.
+fn conversion_error<T>(expected: IonType, element: &OwnedElement) -> IonResult<T> {
+    if element.is_null() {
+        return decoding_error(format!("Expected a(n) {} but found a null.", expected));
+    }
+    Err(IonError::UnexpectedType {
+        expected,
+        found: element.ion_type(),
+    })
+}
+
+/// Converts an integer, failing if it is outside the range of an `i64`.
+impl TryFrom<OwnedElement> for i64 {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        match element.value {
+            OwnedValue::Integer(AnyInt::I64(value)) => Ok(value),
+            OwnedValue::Integer(AnyInt::BigInt(value)) => decoding_error(format!(
+                "The integer {} is outside the range of an i64.",
+                value
+            )),
+            _ => conversion_error(IonType::Integer, &element),
+        }
+    }
+}
+
+impl TryFrom<OwnedElement> for f64 {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        match element.value {
+            OwnedValue::Float(value) => Ok(value),
+            _ => conversion_error(IonType::Float, &element),
+        }
+    }
+}
+
+impl TryFrom<OwnedElement> for bool {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        match element.value {
+            OwnedValue::Boolean(value) => Ok(value),
+            _ => conversion_error(IonType::Boolean, &element),
+        }
+    }
+}
+
+/// Converts a string. Symbols are not converted; use [`Element::as_sym`] to read their text.
+impl TryFrom<OwnedElement> for String {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        match element.value {
+            OwnedValue::String(value) => Ok(value),
+            _ => conversion_error(IonType::String, &element),
+        }
+    }
+}
+
+/// Converts the bytes of a blob or a clob.
+impl TryFrom<OwnedElement> for Vec<u8> {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        match element.value {
+            OwnedValue::Blob(bytes) | OwnedValue::Clob(bytes) => Ok(bytes),
+            _ => conversion_error(IonType::Blob, &element),
+        }
+    }
+}
+
+/// Converts the elements of a list or an s-expression.
+impl TryFrom<OwnedElement> for Vec<OwnedElement> {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        match element.value {
+            OwnedValue::List(sequence) | OwnedValue::SExpression(sequence) => {
+                Ok(sequence.into_iter().collect())
+            }
+            _ => conversion_error(IonType::List, &element),
+        }
+    }
+}
+
+// Collects the fields of a struct into a map. If several fields have the same name, the last one
+// is kept, as with [`Struct::get`].
+fn struct_into_map<M>(element: OwnedElement) -> IonResult<M>
+where
+    M: FromIterator<(String, OwnedElement)>,
+{
+    let fields = match element.value {
+        OwnedValue::Struct(fields) => fields,
+        _ => return conversion_error(IonType::Struct, &element),
+    };
+    fields
+        .into_iter()
+        .map(|(name, value)| match name.text() {
+            Some(text) => Ok((text.to_string(), value)),
+            None => decoding_error("Found a struct field name with unknown text."),
+        })
+        .collect()
+}
+
+/// Converts the fields of a struct, failing if any field name has unknown text. If several
+/// fields have the same name, the last one is kept.
+impl TryFrom<OwnedElement> for HashMap<String, OwnedElement> {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        struct_into_map(element)
+    }
+}
+
+/// Converts the fields of a struct, failing if any field name has unknown text. If several
+/// fields have the same name, the last one is kept.
+impl TryFrom<OwnedElement> for BTreeMap<String, OwnedElement> {
+    type Error = IonError;
+
+    fn try_from(element: OwnedElement) -> IonResult<Self> {
+        struct_into_map(element)
+    }
+}
+
+/// Returns a builder for a struct [`OwnedElement`].
+///
+/// ```
+/// use ion_rs::value::owned::{list_builder, struct_builder, OwnedElement};
+///
+/// let element = struct_builder()
+///     .field("name", "widget")
+///     .field("sizes", list_builder().push(1).push(2).build())
+///     .build();
+/// let expected = OwnedElement::read_all(br#"{name: "widget", sizes: [1, 2]}"#)?;
+/// assert_eq!(element, expected[0]);
+/// # Ok::<(), ion_rs::result::IonError>(())
+/// ```
+pub fn struct_builder() -> OwnedStructBuilder {
+    OwnedStructBuilder {
+        fields: OwnedStruct::default(),
+    }
+}
+
+/// Returns a builder for a list [`OwnedElement`].
+pub fn list_builder() -> OwnedSequenceBuilder {
+    OwnedSequenceBuilder {
+        ion_type: IonType::List,
+        children: Vec::new(),
+    }
+}
+
+/// Returns a builder for an s-expression [`OwnedElement`].
+pub fn sexp_builder() -> OwnedSequenceBuilder {
+    OwnedSequenceBuilder {
+        ion_type: IonType::SExpression,
+        children: Vec::new(),
+    }
+}
+
+/// Builds a struct [`OwnedElement`] one field at a time. See [`struct_builder`].
+#[derive(Debug, Clone)]
+pub struct OwnedStructBuilder {
+    fields: OwnedStruct,
+}
+
+impl OwnedStructBuilder {
+    /// Adds a field, as [`OwnedStruct::add_field`] does.
+    pub fn field<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<OwnedSymbolToken>,
+        V: Into<OwnedElement>,
+    {
+        self.fields.add_field(name, value);
+        self
+    }
+
+    pub fn build(self) -> OwnedElement {
+        self.fields.into()
+    }
+}
+
+/// Builds a list or s-expression [`OwnedElement`] one element at a time. See [`list_builder`]
+/// and [`sexp_builder`].
+#[derive(Debug, Clone)]
+pub struct OwnedSequenceBuilder {
+    ion_type: IonType,
+    children: Vec<OwnedElement>,
+}
+
+impl OwnedSequenceBuilder {
+    /// Appends `element` to the sequence.
+    pub fn push<E: Into<OwnedElement>>(mut self, element: E) -> Self {
+        self.children.push(element.into());
+        self
+    }
+
+    pub fn build(self) -> OwnedElement {
+        let sequence = OwnedSequence::new(self.children);
+        match self.ion_type {
+            IonType::SExpression => OwnedValue::SExpression(sequence).into(),
+            _ => OwnedValue::List(sequence).into(),
+        }
+    }
+}
+
 impl Element for OwnedElement {
     type SymbolToken = OwnedSymbolToken;
     type Sequence = OwnedSequence;
@@ -610,6 +1075,102 @@ impl Element for OwnedElement {
     }
 }
 
+// Generates tokens with text, along with the occasional `$0`: the only symbol ID that reads back
+// without text regardless of the symbol table in effect.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OwnedSymbolToken {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        if u.ratio(1, 8)? {
+            return Ok(local_sid_token(0));
+        }
+        Ok(text_token(u.arbitrary::<String>()?))
+    }
+}
+
+// The deepest that containers are nested in generated elements, so that generating, writing and
+// dropping an element cannot overflow the stack.
+#[cfg(feature = "arbitrary")]
+const MAX_ARBITRARY_DEPTH: usize = 8;
+
+// Generates elements of every Ion type with up to three annotations. Integers that are read
+// as arbitrary-size integers (those outside of the `i64` range) are generated that way.
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for OwnedElement {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        arbitrary_element(u, MAX_ARBITRARY_DEPTH)
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+fn arbitrary_element(
+    u: &mut arbitrary::Unstructured,
+    depth: usize,
+) -> arbitrary::Result<OwnedElement> {
+    const NULL_TYPES: &[IonType] = &[
+        IonType::Null,
+        IonType::Boolean,
+        IonType::Integer,
+        IonType::Float,
+        IonType::Decimal,
+        IonType::Timestamp,
+        IonType::Symbol,
+        IonType::String,
+        IonType::Clob,
+        IonType::Blob,
+        IonType::List,
+        IonType::SExpression,
+        IonType::Struct,
+    ];
+    let num_annotations = u.int_in_range(0..=3)?;
+    let annotations = (0..num_annotations)
+        .map(|_| u.arbitrary())
+        .collect::<arbitrary::Result<Vec<OwnedSymbolToken>>>()?;
+    let max_choice = if depth == 0 { 10 } else { 13 };
+    let value = match u.int_in_range(0..=max_choice)? {
+        0 => OwnedValue::Null(*u.choose(NULL_TYPES)?),
+        1 => OwnedValue::Boolean(u.arbitrary()?),
+        2 => OwnedValue::Integer(AnyInt::I64(u.arbitrary()?)),
+        3 => {
+            let magnitude: BigInt = BigInt::from(i64::MAX) + 1 + u.arbitrary::<u64>()?;
+            let big_int = if u.arbitrary()? {
+                magnitude
+            } else {
+                -magnitude
+            };
+            OwnedValue::Integer(AnyInt::BigInt(big_int))
+        }
+        4 => OwnedValue::Float(u.arbitrary()?),
+        5 => OwnedValue::Decimal(u.arbitrary()?),
+        6 => OwnedValue::Timestamp(u.arbitrary()?),
+        7 => OwnedValue::String(u.arbitrary()?),
+        8 => OwnedValue::Symbol(u.arbitrary()?),
+        9 => OwnedValue::Blob(u.arbitrary()?),
+        10 => OwnedValue::Clob(u.arbitrary()?),
+        choice => {
+            let length = u.int_in_range(0..=4)?;
+            if choice == 13 {
+                let fields = (0..length)
+                    .map(|_| -> arbitrary::Result<_> {
+                        let field_name: OwnedSymbolToken = u.arbitrary()?;
+                        Ok((field_name, arbitrary_element(u, depth - 1)?))
+                    })
+                    .collect::<arbitrary::Result<Vec<_>>>()?;
+                OwnedValue::Struct(fields.into_iter().collect())
+            } else {
+                let children = (0..length)
+                    .map(|_| arbitrary_element(u, depth - 1))
+                    .collect::<arbitrary::Result<Vec<_>>>()?;
+                if choice == 11 {
+                    OwnedValue::List(OwnedSequence::new(children))
+                } else {
+                    OwnedValue::SExpression(OwnedSequence::new(children))
+                }
+            }
+        }
+    };
+    Ok(OwnedElement::new(annotations, value))
+}
+
 #[cfg(test)]
 mod value_tests {
     use super::*;
@@ -634,4 +1195,125 @@ mod value_tests {
         // assert if both the element construction creates the same element
         assert_eq!(elem1, elem2);
     }
+
+    #[test]
+    #[cfg(feature = "ion-c")]
+    fn read_modify_write() -> IonResult<()> {
+        use crate::value::writer::{Format, TextKind};
+        use crate::value::IntAccess;
+
+        let mut elements = OwnedElement::read_all(b"foo::1 \"hello\"")?;
+        assert_eq!(2, elements.len());
+        *elements[0].value_mut() = OwnedValue::Integer(AnyInt::I64(2));
+        assert_eq!(&OwnedValue::String("hello".into()), elements[1].value());
+
+        let mut buf = vec![0u8; 64];
+        let mut writer = Format::Text(TextKind::Compact).element_writer_for_slice(&mut buf)?;
+        for element in elements.iter() {
+            element.write_to(&mut writer)?;
+        }
+        let output = writer.finish()?;
+        assert_eq!(elements, OwnedElement::read_all(output)?);
+        assert_eq!(&[text_token("foo")], &elements[0].annotations[..]);
+        assert_eq!(Some(2), elements[0].as_i64());
+        Ok(())
+    }
+
+    #[test]
+    fn edit_in_place() -> IonResult<()> {
+        let mut element =
+            OwnedElement::read_all(b"doc::{a: 1, x: 2, b: [1, 2], x: 3, c: (sum 1)}")?.remove(0);
+        let fields = element.as_struct_mut().unwrap();
+        assert_eq!(fields.remove_field("x"), vec![2.into(), 3.into()]);
+        assert!(fields.remove_field("x").is_empty());
+        *fields.get_mut("a").unwrap() = "one".into();
+        fields.add_field("a", true);
+        fields.add_field("d", list_builder().push(4).build());
+        let list = fields.get_mut("b").unwrap().as_sequence_mut().unwrap();
+        list.push(3);
+        list.insert(0, 0);
+        assert_eq!(list.remove(1), Some(1.into()));
+        assert_eq!(list.remove(9), None);
+        *list.get_mut(0).unwrap() = OwnedElement::null(IonType::Integer);
+        let sexp = fields.get_mut("c").unwrap().as_sequence_mut().unwrap();
+        sexp.iter_mut()
+            .for_each(|e| *e = e.clone().with_annotations(vec!["x"]));
+        assert!(fields.get_mut("missing").is_none());
+        element.annotations_mut().push(text_token("edited"));
+
+        let expected = OwnedElement::read_all(
+            br#"doc::edited::{a: "one", b: [null.int, 2, 3], c: (x::sum x::1), a: true, d: [4]}"#,
+        )?;
+        assert_eq!(element, expected[0]);
+        // The remaining fields keep the order in which they were added
+        let names: Vec<_> = element
+            .as_struct()
+            .unwrap()
+            .iter_in_order()
+            .map(|(name, _)| name.text().unwrap().to_string())
+            .collect();
+        assert_eq!(names, vec!["a", "b", "c", "a", "d"]);
+        assert!(element.as_sequence_mut().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn builders() {
+        let element = struct_builder()
+            .field("a", 1)
+            .field("b", sexp_builder().push(text_token("x")).push(2.5).build())
+            .field("a", "two")
+            .build()
+            .with_annotations(vec!["built"]);
+        let expected = OwnedElement::read_all(br#"built::{a: 1, b: (x 2.5e0), a: "two"}"#)
+            .unwrap()
+            .remove(0);
+        assert_eq!(element, expected);
+        assert_eq!(list_builder().build().as_sequence().unwrap().len(), 0);
+        assert_eq!(
+            expected
+                .with_annotations(Vec::<&str>::new())
+                .annotations()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn rust_type_conversions() -> IonResult<()> {
+        let read = |text: &str| OwnedElement::read_all(text.as_bytes()).unwrap().remove(0);
+        assert_eq!(i64::try_from(read("a::5"))?, 5);
+        assert_eq!(String::try_from(read("\"hi\""))?, "hi");
+        assert_eq!(Vec::<u8>::try_from(read("{{\"ab\"}}"))?, b"ab".to_vec());
+        assert_eq!(
+            Vec::<OwnedElement>::try_from(read("(1 2)"))?,
+            vec![1.into(), 2.into()]
+        );
+        let map = HashMap::<String, OwnedElement>::try_from(read("{a: 1, a: 2, b: x}"))?;
+        assert_eq!(map.len(), 2);
+        assert_eq!(map["a"], 2.into());
+
+        let mut fields = BTreeMap::new();
+        fields.insert(
+            "b".to_string(),
+            OwnedElement::from(vec![OwnedElement::from(true)]),
+        );
+        fields.insert("a".to_string(), OwnedElement::from(b"xy".to_vec()));
+        let element = OwnedElement::from(fields.clone());
+        assert_eq!(element, read("{a: {{eHk=}}, b: [true]}"));
+        assert_eq!(BTreeMap::try_from(element)?, fields);
+
+        assert_eq!(
+            i64::try_from(read("1.5")),
+            Err(IonError::UnexpectedType {
+                expected: IonType::Integer,
+                found: IonType::Decimal
+            })
+        );
+        assert!(i64::try_from(read("null.int")).is_err());
+        assert!(i64::try_from(read("18446744073709551616")).is_err());
+        assert!(String::try_from(read("sym")).is_err());
+        assert!(HashMap::<String, OwnedElement>::try_from(read("{$0: 1}")).is_err());
+        Ok(())
+    }
 }
@@ -4,13 +4,22 @@
 //! as slices or files.
 
 use crate::result::{decoding_error, IonResult};
-use crate::value::owned::{OwnedElement, OwnedSequence, OwnedStruct, OwnedSymbolToken, OwnedValue};
+use crate::value::owned::OwnedElement;
+#[cfg(feature = "ion-c")]
+use crate::value::owned::{OwnedSequence, OwnedStruct, OwnedSymbolToken, OwnedValue};
+#[cfg(feature = "ion-c")]
 use crate::value::AnyInt;
+#[cfg(feature = "ion-c")]
 use crate::IonType;
+use crate::{RawReader, Reader};
+#[cfg(feature = "ion-c")]
 use ion_c_sys::reader::{IonCReader, IonCReaderHandle};
+#[cfg(feature = "ion-c")]
 use ion_c_sys::ION_TYPE;
+#[cfg(feature = "ion-c")]
 use std::convert::{TryFrom, TryInto};
 
+#[cfg(feature = "ion-c")]
 use super::owned::text_token;
 
 // TODO add/refactor trait/implementation for borrowing over some context
@@ -71,11 +80,13 @@ pub trait ElementReader {
     }
 }
 
+#[cfg(feature = "ion-c")]
 struct IonCReaderIterator<'a> {
     reader: IonCReaderHandle<'a>,
     done: bool,
 }
 
+#[cfg(feature = "ion-c")]
 impl<'a> IonCReaderIterator<'a> {
     /// Moves the reader forward converting to `IonResult`.
     #[inline]
@@ -169,6 +180,7 @@ impl<'a> IonCReaderIterator<'a> {
     }
 }
 
+#[cfg(feature = "ion-c")]
 impl<'a> Iterator for IonCReaderIterator<'a> {
     type Item = IonResult<OwnedElement>;
 
@@ -200,8 +212,10 @@ impl<'a> Iterator for IonCReaderIterator<'a> {
     }
 }
 
+#[cfg(feature = "ion-c")]
 struct IonCElementReader;
 
+#[cfg(feature = "ion-c")]
 impl ElementReader for IonCElementReader {
     fn iterate_over<'a, 'b>(
         &'a self,
@@ -216,12 +230,116 @@ impl ElementReader for IonCElementReader {
     }
 }
 
-/// Returns an implementation defined [`ElementReader`] instance.
+// Reads each top-level value with a [Reader], which detects whether the data is binary or text.
+struct NativeReaderIterator<'a> {
+    reader: Reader<Box<dyn RawReader + 'a>>,
+    done: bool,
+}
+
+impl<'a> Iterator for NativeReaderIterator<'a> {
+    type Item = IonResult<OwnedElement>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // if we previously returned an error, we're done
+        if self.done {
+            return None;
+        }
+        let result = match self.reader.next() {
+            Ok(Some(_)) => self.reader.read_element().transpose()?,
+            Ok(None) => {
+                self.done = true;
+                return None;
+            }
+            Err(e) => Err(e),
+        };
+        // a failure means the iterator is done
+        self.done = result.is_err();
+        Some(result)
+    }
+}
+
+struct NativeElementReader;
+
+impl ElementReader for NativeElementReader {
+    fn iterate_over<'a, 'b>(
+        &'a self,
+        data: &'b [u8],
+    ) -> IonResult<Box<dyn Iterator<Item = IonResult<OwnedElement>> + 'b>> {
+        Ok(Box::new(NativeReaderIterator {
+            reader: Reader::from_input(data)?,
+            done: false,
+        }))
+    }
+}
+
+/// Returns an implementation defined [`ElementReader`] instance. This is backed by Ion C when
+/// the `ion-c` feature is enabled (as it is by default) and is the
+/// [native_element_reader] otherwise.
+#[cfg(feature = "ion-c")]
 pub fn element_reader() -> impl ElementReader {
     IonCElementReader {}
 }
 
+/// Returns an implementation defined [`ElementReader`] instance. This is backed by Ion C when
+/// the `ion-c` feature is enabled (as it is by default) and is the
+/// [native_element_reader] otherwise.
+#[cfg(not(feature = "ion-c"))]
+pub fn element_reader() -> impl ElementReader {
+    NativeElementReader {}
+}
+
+/// Returns an [`ElementReader`] that is implemented in Rust using a [Reader], and so is available
+/// whether or not the `ion-c` feature is enabled. Symbols whose text is unknown are read as their
+/// local symbol IDs, and a truncated value at the end of the data is reported as an error.
+pub fn native_element_reader() -> impl ElementReader {
+    NativeElementReader {}
+}
+
 #[cfg(test)]
+mod native_reader_tests {
+    use super::*;
+    use crate::value::owned::{text_token, OwnedValue};
+    use crate::value::{AnyInt, Element};
+
+    #[test]
+    fn native_element_reader_reads_text_and_binary() -> IonResult<()> {
+        let expected = vec![
+            OwnedElement::new(vec![text_token("a")], OwnedValue::Integer(AnyInt::I64(1))),
+            OwnedElement::new(vec![], OwnedValue::String("two".into())),
+        ];
+        assert_eq!(native_element_reader().read_all(b"a::1 \"two\"")?, expected);
+        #[rustfmt::skip]
+        let binary = [
+            0xE0, 0x01, 0x00, 0xEA,
+            0xE4, 0x81, 0x84, 0x21, 0x01, // $4::1 (`name::1`)
+            0x83, b't', b'w', b'o',       // "two"
+        ];
+        let elements = native_element_reader().read_all(&binary)?;
+        assert_eq!(
+            elements[0].annotations().collect::<Vec<_>>(),
+            vec![&text_token("name")]
+        );
+        assert_eq!(elements[1], expected[1]);
+        Ok(())
+    }
+
+    #[test]
+    fn native_element_reader_stops_after_an_error() -> IonResult<()> {
+        let mut iter = native_element_reader().iterate_over(b"1 [2, 3")?;
+        assert_eq!(
+            iter.next().transpose()?,
+            Some(OwnedElement::new(
+                vec![],
+                OwnedValue::Integer(AnyInt::I64(1))
+            ))
+        );
+        assert!(matches!(iter.next(), Some(Err(_))));
+        assert!(iter.next().is_none());
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "ion-c"))]
 mod reader_tests {
     use super::*;
     use crate::types::timestamp::Timestamp as TS;
@@ -0,0 +1,417 @@
+// Copyright Amazon.com, Inc. or its affiliates.
+
+//! Loads tabular data in CSV or TSV form as Ion with a [`TabularLoader`], which converts each
+//! row into a struct whose field names are the column headers.
+//!
+//! The input is parsed as described in [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180): the
+//! first record is the header, records are separated by `\n` or `\r\n`, and fields that contain
+//! the delimiter, a line break or a `"` must be enclosed in double quotes, with each `"` inside
+//! them written twice. Blank lines are skipped. Every field is a string unless its column has been
+//! configured with another [`ColumnType`].
+//!
+//! ```
+//! use ion_rs::result::IonResult;
+//! use ion_rs::value::reader::{element_reader, ElementReader};
+//! use ion_rs::value::tabular::{ColumnType, TabularLoader};
+//!
+//! # fn main() -> IonResult<()> {
+//! let csv = "id,name,price\n1,\"Widget, large\",12.50\n2,Gadget,\n";
+//! let rows = TabularLoader::csv()
+//!     .with_column_type("id", ColumnType::Int)
+//!     .with_column_type("price", ColumnType::Decimal)
+//!     .read_all(csv)?;
+//! let expected = element_reader().read_all(
+//!     br#"{id: 1, name: "Widget, large", price: 12.50} {id: 2, name: "Gadget", price: null.decimal}"#,
+//! )?;
+//! assert_eq!(expected, rows);
+//! # Ok(())
+//! # }
+//! ```
+
+use num_bigint::BigInt;
+
+use super::owned::{text_token, OwnedElement, OwnedValue};
+use super::writer::write_element_to_binary;
+use super::AnyInt;
+use crate::binary::writer::BinarySystemWriter;
+use crate::result::{decoding_error, illegal_operation, IonResult};
+use crate::text::parsers::timestamp::parse_timestamp;
+use crate::text::text_value::TextValue;
+use crate::types::decimal::Decimal;
+use crate::types::timestamp::Timestamp;
+use crate::IonType;
+
+/// The Ion type that a [`TabularLoader`] converts the fields in a column to.
+///
+/// Fields in columns of any type other than [`ColumnType::String`] have leading and trailing
+/// whitespace removed before they are converted, and empty fields become a null of the column's
+/// type. A field that cannot be converted produces a decoding error.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+pub enum ColumnType {
+    /// The field is kept as a string, including when it is empty.
+    #[default]
+    String,
+    /// The field is parsed as a base-10 integer of any size, like `-17` or `+3`.
+    Int,
+    /// The field is parsed as Ion decimal text, like `12.50` or `125d-1`. Integers like `15` are
+    /// accepted too and become decimals with an exponent of zero.
+    Decimal,
+    /// The field is parsed as Ion timestamp text, like `2022-01-02` or `2022-01-02T03:04:05Z`.
+    Timestamp,
+}
+
+/// Parses CSV or TSV text into [`OwnedElement`]s, one struct per row. See the
+/// [module documentation](self) for the accepted syntax.
+///
+/// [to_binary](Self::to_binary) encodes the rows as a binary Ion stream in a single step.
+#[derive(Clone, Debug)]
+pub struct TabularLoader {
+    delimiter: u8,
+    // The types configured for individual columns, by header
+    column_types: Vec<(String, ColumnType)>,
+    default_column_type: ColumnType,
+}
+
+impl TabularLoader {
+    /// Creates a loader for comma-separated values.
+    pub fn csv() -> Self {
+        TabularLoader {
+            delimiter: b',',
+            column_types: Vec::new(),
+            default_column_type: ColumnType::default(),
+        }
+    }
+
+    /// Creates a loader for tab-separated values.
+    pub fn tsv() -> Self {
+        TabularLoader::csv().with_delimiter(b'\t')
+    }
+
+    /// Sets the byte that separates the fields of a record. It must be an ASCII character other
+    /// than `"`, `\r` or `\n`; reading with any other delimiter produces an error.
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Converts the fields in the column with the header `column` to `column_type`. Configuring
+    /// a column that the input does not have produces an error.
+    pub fn with_column_type<S: Into<String>>(mut self, column: S, column_type: ColumnType) -> Self {
+        let column = column.into();
+        self.column_types.retain(|(name, _)| *name != column);
+        self.column_types.push((column, column_type));
+        self
+    }
+
+    /// Sets the type of the columns that have not been configured with
+    /// [with_column_type](Self::with_column_type). The default is [`ColumnType::String`].
+    pub fn with_default_column_type(mut self, column_type: ColumnType) -> Self {
+        self.default_column_type = column_type;
+        self
+    }
+
+    /// Parses `text`, returning a struct for each record after the header. Input that contains
+    /// only a header (or nothing at all) produces no structs.
+    pub fn read_all(&self, text: &str) -> IonResult<Vec<OwnedElement>> {
+        if !self.delimiter.is_ascii() || matches!(self.delimiter, b'"' | b'\r' | b'\n') {
+            return illegal_operation(format!(
+                "{:?} cannot be used as a delimiter.",
+                self.delimiter as char
+            ));
+        }
+        let mut parser = RecordParser::new(text, self.delimiter);
+        let header = match parser.next_record()? {
+            Some((_, header)) => header,
+            None => Vec::new(),
+        };
+        for (column, _) in &self.column_types {
+            if !header.contains(column) {
+                return illegal_operation(format!(
+                    "A type was configured for the column '{}', which the header does not have.",
+                    column
+                ));
+            }
+        }
+        let column_types: Vec<ColumnType> = header
+            .iter()
+            .map(|name| {
+                self.column_types
+                    .iter()
+                    .find(|(column, _)| column == name)
+                    .map(|(_, column_type)| *column_type)
+                    .unwrap_or(self.default_column_type)
+            })
+            .collect();
+
+        let mut rows = Vec::new();
+        while let Some((line, record)) = parser.next_record()? {
+            if record.len() != header.len() {
+                return decoding_error(format!(
+                    "The record on line {} has {} fields, but the header has {}.",
+                    line,
+                    record.len(),
+                    header.len()
+                ));
+            }
+            let fields = header
+                .iter()
+                .zip(&column_types)
+                .zip(record)
+                .map(|((name, column_type), field)| {
+                    let value = convert_field(field, *column_type).or_else(|_| {
+                        decoding_error(format!(
+                            "The '{}' field of the record on line {} is not a valid {:?}.",
+                            name, line, column_type
+                        ))
+                    })?;
+                    Ok((text_token(name.as_str()), OwnedElement::from(value)))
+                })
+                .collect::<IonResult<Vec<_>>>()?;
+            rows.push(OwnedValue::Struct(fields.into_iter().collect()).into());
+        }
+        Ok(rows)
+    }
+
+    /// Parses `text` as [read_all](Self::read_all) does and encodes the rows as a binary Ion
+    /// stream.
+    pub fn to_binary(&self, text: &str) -> IonResult<Vec<u8>> {
+        let mut writer = BinarySystemWriter::new(Vec::new());
+        for row in self.read_all(text)? {
+            write_element_to_binary(&mut writer, &row)?;
+        }
+        writer.flush()?;
+        Ok(std::mem::take(writer.output_mut()))
+    }
+}
+
+impl Default for TabularLoader {
+    fn default() -> Self {
+        TabularLoader::csv()
+    }
+}
+
+fn convert_field(field: String, column_type: ColumnType) -> IonResult<OwnedValue> {
+    let text = field.trim();
+    let null_type = match column_type {
+        ColumnType::String => return Ok(OwnedValue::String(field)),
+        ColumnType::Int => IonType::Integer,
+        ColumnType::Decimal => IonType::Decimal,
+        ColumnType::Timestamp => IonType::Timestamp,
+    };
+    if text.is_empty() {
+        return Ok(OwnedValue::Null(null_type));
+    }
+    let value = match column_type {
+        ColumnType::String => unreachable!("strings are returned above"),
+        ColumnType::Int => OwnedValue::Integer(parse_int(text)?),
+        ColumnType::Decimal => match text.parse::<Decimal>() {
+            Ok(decimal) => OwnedValue::Decimal(decimal),
+            Err(error) => match parse_int(text)? {
+                AnyInt::I64(value) => OwnedValue::Decimal(value.into()),
+                AnyInt::BigInt(_) => return Err(error),
+            },
+        },
+        ColumnType::Timestamp => OwnedValue::Timestamp(parse_timestamp_text(text)?),
+    };
+    Ok(value)
+}
+
+fn parse_int(text: &str) -> IonResult<AnyInt> {
+    if let Ok(value) = text.parse::<i64>() {
+        return Ok(AnyInt::I64(value));
+    }
+    match text.parse::<BigInt>() {
+        Ok(value) => Ok(AnyInt::BigInt(value)),
+        Err(_) => decoding_error(format!("'{}' is not a valid integer", text)),
+    }
+}
+
+fn parse_timestamp_text(text: &str) -> IonResult<Timestamp> {
+    // The text parser only accepts a timestamp that is followed by a stop character.
+    let terminated = format!("{} ", text);
+    match parse_timestamp(&terminated) {
+        Ok((" ", TextValue::Timestamp(timestamp))) => Ok(timestamp),
+        _ => decoding_error(format!("'{}' is not a valid Ion timestamp", text)),
+    }
+}
+
+// Splits delimited text into records of unescaped fields.
+struct RecordParser<'a> {
+    text: &'a str,
+    // The byte offset of the next unread character in `text`
+    position: usize,
+    // The line number of the next unread character in `text`, starting from 1
+    line: usize,
+    delimiter: u8,
+}
+
+impl<'a> RecordParser<'a> {
+    fn new(text: &'a str, delimiter: u8) -> Self {
+        RecordParser {
+            text,
+            position: 0,
+            line: 1,
+            delimiter,
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.position).copied()
+    }
+
+    fn at_line_break(&self) -> bool {
+        let rest = &self.text.as_bytes()[self.position..];
+        rest.starts_with(b"\n") || rest.starts_with(b"\r\n")
+    }
+
+    // Consumes a line break if one is next in the input.
+    fn consume_line_break(&mut self) -> bool {
+        if !self.at_line_break() {
+            return false;
+        }
+        self.position += if self.peek() == Some(b'\r') { 2 } else { 1 };
+        self.line += 1;
+        true
+    }
+
+    // Returns the line on which the next record begins, along with its fields.
+    fn next_record(&mut self) -> IonResult<Option<(usize, Vec<String>)>> {
+        while self.consume_line_break() {}
+        if self.position >= self.text.len() {
+            return Ok(None);
+        }
+        let line = self.line;
+        let mut fields = Vec::new();
+        loop {
+            fields.push(self.next_field()?);
+            if self.peek() == Some(self.delimiter) {
+                self.position += 1;
+                continue;
+            }
+            // The field ends at a line break or at the end of the input.
+            self.consume_line_break();
+            return Ok(Some((line, fields)));
+        }
+    }
+
+    fn next_field(&mut self) -> IonResult<String> {
+        if self.peek() != Some(b'"') {
+            let start = self.position;
+            while let Some(byte) = self.peek() {
+                if byte == self.delimiter || self.at_line_break() {
+                    break;
+                }
+                self.position += 1;
+            }
+            return Ok(self.text[start..self.position].to_string());
+        }
+
+        let start_line = self.line;
+        self.position += 1; // The opening '"'
+        let mut field = String::new();
+        loop {
+            let rest = &self.text[self.position..];
+            let end = match rest.find('"') {
+                Some(end) => end,
+                None => {
+                    return decoding_error(format!(
+                        "The quoted field that begins on line {} is never closed.",
+                        start_line
+                    ))
+                }
+            };
+            field.push_str(&rest[..end]);
+            self.line += rest[..end].matches('\n').count();
+            self.position += end + 1;
+            // A doubled quote stands for a single quote inside the field.
+            if self.peek() == Some(b'"') {
+                field.push('"');
+                self.position += 1;
+                continue;
+            }
+            break;
+        }
+        match self.peek() {
+            None => Ok(field),
+            Some(byte) if byte == self.delimiter || self.at_line_break() => Ok(field),
+            _ => decoding_error(format!(
+                "Unexpected text after the quoted field that ends on line {}.",
+                self.line
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tabular_tests {
+    use super::*;
+    use crate::value::reader::{element_reader, ElementReader};
+    use crate::{RawBinaryReader, Reader};
+    use rstest::*;
+    use std::io;
+
+    #[test]
+    fn read_quoted_and_blank_fields() -> IonResult<()> {
+        let csv = "name,note,empty\r\n\"Smith, J\",\"says \"\"hi\"\"\nand leaves\",\n\nDoe,,\"\"";
+        let expected = element_reader().read_all(
+            br#"
+            {name: "Smith, J", note: "says \"hi\"\nand leaves", empty: ""}
+            {name: "Doe", note: "", empty: ""}
+            "#,
+        )?;
+        assert_eq!(expected, TabularLoader::csv().read_all(csv)?);
+        assert!(TabularLoader::csv().read_all("")?.is_empty());
+        assert!(TabularLoader::csv().read_all("a,b\n")?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn column_types() -> IonResult<()> {
+        let tsv = "id\tprice\twhen\tcount\n\
+            7\t 12.50\t2022-01-02\t123456789012345678901234567890\n\
+            -8\t15\t2022-01-02T03:04:05Z\t\n";
+        let rows = TabularLoader::tsv()
+            .with_default_column_type(ColumnType::Int)
+            .with_column_type("price", ColumnType::String)
+            .with_column_type("price", ColumnType::Decimal)
+            .with_column_type("when", ColumnType::Timestamp)
+            .read_all(tsv)?;
+        let expected = element_reader().read_all(
+            br#"
+            {id: 7, price: 12.50, when: 2022-01-02, count: 123456789012345678901234567890}
+            {id: -8, price: 15., when: 2022-01-02T03:04:05Z, count: null.int}
+            "#,
+        )?;
+        assert_eq!(expected, rows);
+        Ok(())
+    }
+
+    #[rstest]
+    // the second record has too few fields
+    #[case::missing_field(TabularLoader::csv(), "a,b\n1,2\n3\n")]
+    // the second record's quoted field is never closed
+    #[case::unclosed_quote(TabularLoader::csv(), "a,b\n1,2\n3,\"4\n")]
+    #[case::text_after_quote(TabularLoader::csv(), "a\n\"1\"2\n")]
+    #[case::invalid_int(TabularLoader::csv().with_column_type("a", ColumnType::Int), "a\n1.5\n")]
+    #[case::invalid_decimal(TabularLoader::csv().with_column_type("a", ColumnType::Decimal), "a\n1e3\n")]
+    #[case::invalid_timestamp(TabularLoader::csv().with_column_type("a", ColumnType::Timestamp), "a\n2022-13-01\n")]
+    #[case::unknown_column(TabularLoader::csv().with_column_type("b", ColumnType::Int), "a\n1\n")]
+    #[case::invalid_delimiter(TabularLoader::csv().with_delimiter(b'"'), "a\n1\n")]
+    fn invalid_input(#[case] loader: TabularLoader, #[case] text: &str) {
+        assert!(loader.read_all(text).is_err());
+    }
+
+    #[test]
+    fn to_binary() -> IonResult<()> {
+        let loader = TabularLoader::csv().with_column_type("id", ColumnType::Int);
+        let csv = "id,name\n1,first\n2,second\n";
+        let data = loader.to_binary(csv)?;
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(data.as_slice())));
+        let mut elements = Vec::new();
+        while reader.next()?.is_some() {
+            elements.push(reader.read_element()?.unwrap());
+        }
+        assert_eq!(loader.read_all(csv)?, elements);
+        Ok(())
+    }
+}
@@ -4,16 +4,35 @@
 //! such as byte buffers or files.
 
 use super::{AnyInt, Element, Sequence, Struct, SymbolToken};
-use crate::result::{illegal_operation, IonError, IonResult};
+use crate::binary::decimal::DecimalBinaryEncoder;
+use crate::binary::timestamp::TimestampBinaryEncoder;
+use crate::binary::uint;
+use crate::binary::writer::{BinarySystemWriter, StructFieldOrder, MAX_INLINE_LENGTH};
+use crate::binary::VarUInt;
+#[cfg(feature = "ion-c")]
+use crate::result::IonError;
+use crate::result::{illegal_operation, IonResult};
+use crate::text::writer::TextWriter;
+use crate::types::SymbolId;
 use crate::IonType;
+#[cfg(feature = "ion-c")]
 use ion_c_sys::writer::{IonCValueWriter, IonCWriter, IonCWriterHandle};
+#[cfg(feature = "ion-c")]
 use ion_c_sys::ION_WRITER_OPTIONS;
+#[cfg(feature = "ion-c")]
 use std::convert::TryInto;
+use std::fmt;
+use std::io::{self, Write};
 
 pub use Format::*;
 pub use TextKind::*;
 
 /// Serializes [`Element`] instances into some kind of output sink.
+///
+/// Output is reproducible: writing the same elements produces byte-identical output. Binary
+/// writers assign local symbol IDs in the order that symbol text is first encountered during a
+/// depth-first traversal of the elements, and the [`Struct`] implementations in this crate iterate
+/// over their fields in an order that depends only on their contents.
 pub trait ElementWriter {
     /// The output of the writer when finishing, it could be a managed buffer,
     /// some concept of a stream, metadata about a file, or something appropriate
@@ -49,6 +68,9 @@ pub trait ElementWriter {
 /// because an opaque type makes using this with the associated lifetimes of the
 /// output difficult.  A type alias [`SliceElementWriter`] is a better reference for this
 /// would be opaque type.
+///
+/// Requires the `ion-c` feature.
+#[cfg(feature = "ion-c")]
 pub struct IonCSliceElementWriter<'a> {
     /// Raw pointer to the slice we write to--this is borrowed by the Ion C writer
     /// opaquely, so we retain it such that we can return the written data as a
@@ -58,8 +80,10 @@ pub struct IonCSliceElementWriter<'a> {
     error: Option<IonError>,
 }
 
+#[cfg(feature = "ion-c")]
 pub type SliceElementWriter<'a> = IonCSliceElementWriter<'a>;
 
+#[cfg(feature = "ion-c")]
 impl<'a> IonCSliceElementWriter<'a> {
     fn new(buf: &'a mut [u8], format: Format) -> IonResult<Self> {
         let data = buf.as_ptr();
@@ -189,6 +213,7 @@ impl<'a> IonCSliceElementWriter<'a> {
     }
 }
 
+#[cfg(feature = "ion-c")]
 impl<'a> ElementWriter for IonCSliceElementWriter<'a> {
     type Output = &'a [u8];
 
@@ -237,9 +262,13 @@ impl Format {
 
     // TODO eliminate limitation around reading only from slices
 
-    /// Creates a [`ElementWriter`] for the format over a slice.
+    /// Creates a [`ElementWriter`] for the format over a slice. Requires the `ion-c` feature;
+    /// without it, elements can be written with
+    /// [`BinaryWriter::write_element`](crate::binary::writer::BinaryWriter::write_element) or
+    /// as text with their [`Display`](fmt::Display) implementations.
     ///
     /// Returns [`Err`] if the [`ElementWriter`] cannot be constructed.
+    #[cfg(feature = "ion-c")]
     pub fn element_writer_for_slice(self, slice: &mut [u8]) -> IonResult<SliceElementWriter> {
         IonCSliceElementWriter::new(slice, self)
     }
@@ -247,16 +276,309 @@ impl Format {
     // TODO into files, cursors, or other such things
 }
 
-#[cfg(test)]
+/// Writes an [`Element`] (and any children it has) as a single value to a [`TextWriter`].
+pub(crate) fn write_element_to_text<E: Element, W: Write>(
+    writer: &mut TextWriter<W>,
+    element: &E,
+) -> IonResult<()> {
+    let annotations_opt: Option<Vec<_>> = element.annotations().map(|tok| tok.text()).collect();
+    match annotations_opt {
+        Some(annotations) => {
+            writer.set_annotations(&annotations);
+        }
+        None => {
+            return illegal_operation(format!(
+                "Could not serialize annotation(s) with no text: {:?}",
+                element
+            ))
+        }
+    }
+
+    let ion_type = element.ion_type();
+    if let Some(null_type) = element.null_type() {
+        return writer.write_null(null_type);
+    }
+    match ion_type {
+        IonType::Null => unreachable!("non-null element with a null type"),
+        IonType::Boolean => writer.write_bool(try_to!(element.as_bool())),
+        IonType::Integer => match try_to!(element.as_any_int()) {
+            AnyInt::I64(i64_val) => writer.write_i64(*i64_val),
+            AnyInt::BigInt(big_val) => writer.write_big_int(big_val),
+        },
+        IonType::Float => writer.write_f64(try_to!(element.as_f64())),
+        IonType::Decimal => writer.write_decimal(try_to!(element.as_decimal())),
+        IonType::Timestamp => writer.write_timestamp(try_to!(element.as_timestamp())),
+        IonType::Symbol => writer.write_symbol(try_to!(element.as_str())),
+        IonType::String => writer.write_string(try_to!(element.as_str())),
+        IonType::Clob => writer.write_clob(try_to!(element.as_bytes())),
+        IonType::Blob => writer.write_blob(try_to!(element.as_bytes())),
+        IonType::List | IonType::SExpression => {
+            writer.step_in(ion_type)?;
+            for child in try_to!(element.as_sequence()).iter() {
+                write_element_to_text(writer, child)?;
+            }
+            writer.step_out()
+        }
+        IonType::Struct => {
+            writer.step_in(ion_type)?;
+            for (field_name_token, child) in try_to!(element.as_struct()).iter() {
+                writer.set_field_name(try_to!(field_name_token.text()));
+                write_element_to_text(writer, child)?;
+            }
+            writer.step_out()
+        }
+    }
+}
+
+/// Writes an [`Element`] (and any children it has) as a single value to a
+/// [`BinarySystemWriter`]. Annotations, field names and symbol values with text are interned in
+/// the writer's local symbol table. Those without text are written using their symbol IDs as-is,
+/// so `$0` always round-trips, but any other symbol ID will only have unknown text when it is
+/// read back if the writer's symbol table does not define it.
+pub(crate) fn write_element_to_binary<E: Element, W: Write>(
+    writer: &mut BinarySystemWriter<W>,
+    element: &E,
+) -> IonResult<()> {
+    let annotation_ids = element
+        .annotations()
+        .map(|token| binary_symbol_id(writer, token))
+        .collect::<IonResult<Vec<_>>>()?;
+    writer.set_annotation_ids(&annotation_ids);
+
+    let ion_type = element.ion_type();
+    if let Some(null_type) = element.null_type() {
+        return writer.write_null(null_type);
+    }
+    match ion_type {
+        IonType::Null => unreachable!("non-null element with a null type"),
+        IonType::Boolean => writer.write_bool(try_to!(element.as_bool())),
+        IonType::Integer => match try_to!(element.as_any_int()) {
+            AnyInt::I64(i64_val) => writer.write_i64(*i64_val),
+            AnyInt::BigInt(big_val) => illegal_operation(format!(
+                "The binary writer does not support integers outside of the i64 range: {}",
+                big_val
+            )),
+        },
+        IonType::Float => writer.write_f64(try_to!(element.as_f64())),
+        IonType::Decimal => writer.write_decimal(try_to!(element.as_decimal())),
+        IonType::Timestamp => writer.write_timestamp(try_to!(element.as_timestamp())),
+        IonType::Symbol => {
+            let symbol_id = binary_symbol_id(writer, try_to!(element.as_sym()))?;
+            writer.write_symbol_id(symbol_id)
+        }
+        IonType::String => writer.write_string(try_to!(element.as_str())),
+        IonType::Clob => writer.write_clob(try_to!(element.as_bytes())),
+        IonType::Blob => writer.write_blob(try_to!(element.as_bytes())),
+        IonType::List | IonType::SExpression => {
+            writer.step_in(ion_type)?;
+            for child in try_to!(element.as_sequence()).iter() {
+                write_element_to_binary(writer, child)?;
+            }
+            writer.step_out()
+        }
+        IonType::Struct => {
+            let fields = try_to!(element.as_struct());
+            writer.step_in(ion_type)?;
+            match writer.struct_field_order() {
+                StructFieldOrder::Insertion => {
+                    write_fields_to_binary(writer, fields.iter_in_order())?
+                }
+                StructFieldOrder::Text => write_fields_to_binary(writer, fields.iter())?,
+                StructFieldOrder::SymbolId => {
+                    let mut fields = fields
+                        .iter_in_order()
+                        .map(|(field_name_token, child)| {
+                            Ok((binary_symbol_id(writer, field_name_token)?, child))
+                        })
+                        .collect::<IonResult<Vec<_>>>()?;
+                    // a stable sort, so repeated fields keep their relative order
+                    fields.sort_by_key(|(field_id, _)| *field_id);
+                    for (field_id, child) in fields {
+                        writer.set_field_id(field_id);
+                        write_element_to_binary(writer, child)?;
+                    }
+                }
+            }
+            writer.step_out()
+        }
+    }
+}
+
+// Writes each of `fields` in the order provided, interning each field name just before its value.
+fn write_fields_to_binary<'a, T, E, W>(
+    writer: &mut BinarySystemWriter<W>,
+    fields: impl Iterator<Item = (&'a T, &'a E)>,
+) -> IonResult<()>
+where
+    T: SymbolToken + ?Sized + 'a,
+    E: Element + 'a,
+    W: Write,
+{
+    for (field_name_token, child) in fields {
+        let field_id = binary_symbol_id(writer, field_name_token)?;
+        writer.set_field_id(field_id);
+        write_element_to_binary(writer, child)?;
+    }
+    Ok(())
+}
+
+// Returns the symbol ID that `token` should be written with: the ID of its text in the writer's
+// symbol table if it has text, or its own symbol ID otherwise.
+fn binary_symbol_id<T: SymbolToken + ?Sized, W: Write>(
+    writer: &mut BinarySystemWriter<W>,
+    token: &T,
+) -> IonResult<SymbolId> {
+    match (token.text(), token.local_sid()) {
+        (Some(text), _) => Ok(writer.intern(text)),
+        (None, Some(symbol_id)) => Ok(symbol_id),
+        (None, None) => {
+            illegal_operation("Could not serialize a symbol with neither text nor a symbol ID.")
+        }
+    }
+}
+
+/// Returns the number of bytes that a binary writer is expected to use to encode `element`,
+/// including its annotations and any values nested inside of it. This can be passed to
+/// [`BinarySystemWriter::with_capacity`] or [`BinarySystemWriter::reserve`] (or used to size the
+/// writer's output `Vec`) so that large values are encoded without repeatedly growing a buffer:
+///
+/// ```
+/// use ion_rs::binary::writer::BinaryWriter;
+/// use ion_rs::value::owned::OwnedElement;
+/// use ion_rs::value::writer::encoded_size_hint;
+/// # fn main() -> ion_rs::result::IonResult<()> {
+///
+/// let elements = OwnedElement::read_all(&b"{name: \"widget\", sizes: [1, 2, 3]}"[..])?;
+/// let size_hint: usize = elements.iter().map(encoded_size_hint).sum();
+/// let mut writer = BinaryWriter::with_capacity(Vec::new(), size_hint);
+/// for element in &elements {
+///     writer.write_element(element)?;
+/// }
+/// writer.flush()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The estimate is exact for everything but symbol IDs. Symbols with text are not assigned a
+/// symbol ID until they are written, so each one is assumed to need a two-byte ID; that holds for
+/// the first 16,383 symbols in a symbol table. The IVM and the local symbol table that precede
+/// the values in a stream are not included.
+pub fn encoded_size_hint<E: Element>(element: &E) -> usize {
+    let value_size = value_size_hint(element);
+    let annotations_size: usize = element
+        .annotations()
+        .map(|token| VarUInt::encoded_size(symbol_id_hint(token) as u64))
+        .sum();
+    if annotations_size == 0 {
+        return value_size;
+    }
+    let wrapped_size =
+        VarUInt::encoded_size(annotations_size as u64) + annotations_size + value_size;
+    header_size_hint(wrapped_size) + wrapped_size
+}
+
+// The size of an encoded value, not including its annotations wrapper.
+fn value_size_hint<E: Element>(element: &E) -> usize {
+    if element.null_type().is_some() {
+        return 1;
+    }
+    // Values that cannot be encoded will fail to be written regardless of this estimate.
+    let body_size = match element.ion_type() {
+        IonType::Null | IonType::Boolean => 0,
+        IonType::Integer => match element.as_any_int() {
+            Some(AnyInt::I64(value)) => uint::encode_uint(value.unsigned_abs()).as_bytes().len(),
+            Some(AnyInt::BigInt(value)) => value.bits().div_ceil(8) as usize,
+            None => 0,
+        },
+        IonType::Float => match element.as_f64() {
+            // Only positive zero has a zero-length encoding
+            Some(value) if value == 0f64 && value.is_sign_positive() => 0,
+            _ => 8,
+        },
+        // These encoders report their full size, including the type descriptor and length
+        IonType::Decimal => {
+            return element
+                .as_decimal()
+                .and_then(|value| io::sink().encode_decimal_value(value).ok())
+                .unwrap_or(1)
+        }
+        IonType::Timestamp => {
+            return element
+                .as_timestamp()
+                .and_then(|value| io::sink().encode_timestamp_value(value).ok())
+                .unwrap_or(1)
+        }
+        IonType::Symbol => match element.as_sym() {
+            Some(token) => uint::encode_uint(symbol_id_hint(token) as u64)
+                .as_bytes()
+                .len(),
+            None => 0,
+        },
+        IonType::String => element.as_str().map_or(0, str::len),
+        IonType::Clob | IonType::Blob => element.as_bytes().map_or(0, <[u8]>::len),
+        IonType::List | IonType::SExpression => element
+            .as_sequence()
+            .map_or(0, |sequence| sequence.iter().map(encoded_size_hint).sum()),
+        IonType::Struct => element.as_struct().map_or(0, |fields| {
+            fields
+                .iter_in_order()
+                .map(|(field_name, child)| {
+                    VarUInt::encoded_size(symbol_id_hint(field_name) as u64)
+                        + encoded_size_hint(child)
+                })
+                .sum()
+        }),
+    };
+    header_size_hint(body_size) + body_size
+}
+
+// The size of the type descriptor and (if it does not fit in the type descriptor) the VarUInt
+// length of a value with a body of `body_size` bytes.
+fn header_size_hint(body_size: usize) -> usize {
+    if body_size <= MAX_INLINE_LENGTH {
+        1
+    } else {
+        1 + VarUInt::encoded_size(body_size as u64)
+    }
+}
+
+// The symbol ID that `token` is expected to be written with. See [encoded_size_hint].
+fn symbol_id_hint<T: SymbolToken + ?Sized>(token: &T) -> SymbolId {
+    // The largest symbol ID that can be encoded as a two-byte VarUInt
+    const ASSUMED_SYMBOL_ID: SymbolId = (1 << 14) - 1;
+    match (token.text(), token.local_sid()) {
+        (None, Some(symbol_id)) => symbol_id,
+        _ => ASSUMED_SYMBOL_ID,
+    }
+}
+
+/// Formats an [`Element`] as Ion text, used to implement [`Display`](fmt::Display) for the
+/// concrete element types.
+pub(crate) fn fmt_element<E: Element>(element: &E, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut writer = TextWriter::new(Vec::new());
+    write_element_to_text(&mut writer, element).map_err(|_| fmt::Error)?;
+    writer.flush().map_err(|_| fmt::Error)?;
+    let text = std::str::from_utf8(writer.output()).map_err(|_| fmt::Error)?;
+    // the text writer terminates top-level values with a newline
+    f.write_str(text.trim_end_matches('\n'))
+}
+
+#[cfg(all(test, feature = "ion-c"))]
 mod writer_tests {
     use super::*;
     use crate::result::IonResult;
+    use crate::text::raw_text_reader::RawTextReader;
     use crate::types::decimal::Decimal;
     use crate::types::timestamp::Timestamp;
     use crate::value::borrowed::BorrowedElement;
-    use crate::value::owned::OwnedElement;
+    use crate::value::owned::{
+        local_sid_token, text_token, OwnedElement, OwnedSequence, OwnedSymbolToken, OwnedValue,
+    };
+    use crate::value::reader::{element_reader, ElementReader};
     use crate::value::Builder;
+    use crate::{RawBinaryReader, Reader};
     use rstest::*;
+    use std::io;
     use std::str::from_utf8;
 
     #[inline]
@@ -502,4 +824,298 @@ mod writer_tests {
         );
         Ok(())
     }
+
+    #[rstest]
+    #[case::null(IonType::Null, 0x0F, "null")]
+    #[case::bool(IonType::Boolean, 0x1F, "null.bool")]
+    #[case::int(IonType::Integer, 0x2F, "null.int")]
+    #[case::float(IonType::Float, 0x4F, "null.float")]
+    #[case::decimal(IonType::Decimal, 0x5F, "null.decimal")]
+    #[case::timestamp(IonType::Timestamp, 0x6F, "null.timestamp")]
+    #[case::symbol(IonType::Symbol, 0x7F, "null.symbol")]
+    #[case::string(IonType::String, 0x8F, "null.string")]
+    #[case::clob(IonType::Clob, 0x9F, "null.clob")]
+    #[case::blob(IonType::Blob, 0xAF, "null.blob")]
+    #[case::list(IonType::List, 0xBF, "null.list")]
+    #[case::sexp(IonType::SExpression, 0xCF, "null.sexp")]
+    #[case::struct_(IonType::Struct, 0xDF, "null.struct")]
+    fn typed_null(
+        #[case] ion_type: IonType,
+        #[case] type_descriptor: u8,
+        #[case] text: &'static str,
+    ) -> IonResult<()> {
+        let element = OwnedElement::null(ion_type);
+        assert!(element.is_null());
+        assert_eq!(Some(ion_type), element.null_type());
+        assert_eq!(text, format!("{}", element));
+        assert_eq!(text, format!("{}", BorrowedElement::null(ion_type)));
+
+        let binary = ion_binary(&[type_descriptor]);
+        assert_write(&binary, &element, |buf| {
+            Binary.element_writer_for_slice(buf)
+        })?;
+        assert_write(text.as_bytes(), &element, |buf| {
+            Text(Compact).element_writer_for_slice(buf)
+        })?;
+
+        // reading the encoding back must yield the same typed null
+        let round_tripped = element_reader().read_one(&binary)?;
+        assert_eq!(Some(ion_type), round_tripped.null_type());
+        assert_eq!(element, round_tripped);
+        let round_tripped = element_reader().read_one(text.as_bytes())?;
+        assert_eq!(element, round_tripped);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case::list(IonType::List, OwnedValue::List(OwnedSequence::new(vec![])), 0xB0, "[]")]
+    #[case::sexp(IonType::SExpression, OwnedValue::SExpression(OwnedSequence::new(vec![])), 0xC0, "()")]
+    #[case::struct_(IonType::Struct, OwnedValue::Struct(Vec::<(&str, OwnedElement)>::new().into_iter().collect()), 0xD0, "{}")]
+    fn null_container_is_not_empty_container(
+        #[case] ion_type: IonType,
+        #[case] empty_value: OwnedValue,
+        #[case] type_descriptor: u8,
+        #[case] text: &'static str,
+    ) -> IonResult<()> {
+        let null_container = OwnedElement::null(ion_type);
+        let empty_container: OwnedElement = empty_value.into();
+        assert_ne!(null_container, empty_container);
+        assert!(!empty_container.is_null());
+        assert_eq!(None, empty_container.null_type());
+        assert_eq!(ion_type, empty_container.ion_type());
+        assert_eq!(text, format!("{}", empty_container));
+
+        let binary = ion_binary(&[type_descriptor]);
+        assert_write(&binary, &empty_container, |buf| {
+            Binary.element_writer_for_slice(buf)
+        })?;
+        let round_tripped = element_reader().read_one(&binary)?;
+        assert_eq!(empty_container, round_tripped);
+        assert_ne!(null_container, round_tripped);
+        Ok(())
+    }
+
+    fn write_binary(elements: &[OwnedElement]) -> IonResult<Vec<u8>> {
+        let mut buf = vec![0u8; TEST_BUF_LEN];
+        let mut writer = Binary.element_writer_for_slice(&mut buf)?;
+        for element in elements {
+            writer.write(element)?;
+        }
+        Ok(writer.finish()?.to_vec())
+    }
+
+    #[test]
+    fn binary_output_is_reproducible() -> IonResult<()> {
+        let text = br#"a::{b: 1, c: [d, e::2], f: {g: h, i: j}, k: "l"} m::n {o: p, b: q}"#;
+        // each read constructs new structs from scratch
+        let first = write_binary(&element_reader().read_all(text)?)?;
+        let second = write_binary(&element_reader().read_all(text)?)?;
+        assert_eq!(first, second);
+
+        // field order is not significant, so reordering the fields produces the same output
+        let reordered = br#"a::{k: "l", f: {i: j, g: h}, c: [d, e::2], b: 1} m::n {b: q, o: p}"#;
+        let third = write_binary(&element_reader().read_all(reordered)?)?;
+        assert_eq!(first, third);
+        Ok(())
+    }
+
+    // A small xorshift generator, so that the random elements below are the same on every run.
+    struct Random(u64);
+
+    impl Random {
+        fn below(&mut self, bound: u64) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0 % bound
+        }
+    }
+
+    // Generates a random symbol token. If `allow_sids` is true, the token may have no text.
+    fn random_token(random: &mut Random, allow_sids: bool) -> OwnedSymbolToken {
+        match random.below(if allow_sids { 6 } else { 4 }) {
+            // $0 and a symbol ID that is not defined in the writer's symbol table
+            4 => local_sid_token(0),
+            5 => local_sid_token(10_000),
+            n => text_token(["a", "b", "name", "$ion"][n as usize]),
+        }
+    }
+
+    // Generates a random element with up to three annotations, nesting containers at most
+    // `depth` levels deep.
+    fn random_element(random: &mut Random, depth: usize, allow_sids: bool) -> OwnedElement {
+        let annotations = (0..random.below(4))
+            .map(|_| random_token(random, allow_sids))
+            .collect();
+        let value = match random.below(if depth == 0 { 5 } else { 8 }) {
+            0 => OwnedValue::Boolean(random.below(2) == 1),
+            1 => OwnedValue::Integer(AnyInt::I64(random.below(1_000) as i64 - 500)),
+            2 => OwnedValue::String(format!("s{}", random.below(10))),
+            3 => OwnedValue::Symbol(random_token(random, allow_sids)),
+            4 => OwnedValue::Null(IonType::Symbol),
+            5 => OwnedValue::List(
+                (0..random.below(4))
+                    .map(|_| random_element(random, depth - 1, allow_sids))
+                    .collect(),
+            ),
+            6 => OwnedValue::SExpression(
+                (0..random.below(4))
+                    .map(|_| random_element(random, depth - 1, allow_sids))
+                    .collect(),
+            ),
+            _ => OwnedValue::Struct(
+                (0..random.below(4))
+                    .map(|_| {
+                        (
+                            random_token(random, allow_sids),
+                            random_element(random, depth - 1, allow_sids),
+                        )
+                    })
+                    .collect(),
+            ),
+        };
+        OwnedElement::new(annotations, value)
+    }
+
+    #[test]
+    fn annotated_elements_round_trip_through_binary() -> IonResult<()> {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+        let elements: Vec<OwnedElement> = (0..500)
+            .map(|_| random_element(&mut random, 3, true))
+            .collect();
+        let mut buffer = vec![];
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        for element in &elements {
+            writer.write_element(element)?;
+        }
+        writer.flush()?;
+        drop(writer);
+
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        for element in &elements {
+            assert!(reader.next()?.is_some());
+            assert_eq!(element, &reader.read_element()?.unwrap());
+        }
+        assert_eq!(reader.next()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn sid_only_annotations_keep_their_symbol_ids() -> IonResult<()> {
+        let element = OwnedElement::new(
+            vec![local_sid_token(0), text_token("a"), local_sid_token(10_000)],
+            OwnedValue::Symbol(local_sid_token(0)),
+        );
+        let mut buffer = vec![];
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        writer.write_element(&element)?;
+        writer.flush()?;
+        drop(writer);
+
+        let mut reader = Reader::new(RawBinaryReader::new(io::Cursor::new(buffer.as_slice())));
+        reader.next()?;
+        let round_tripped = reader.read_element()?.unwrap();
+        let annotations: Vec<_> = round_tripped
+            .annotations()
+            .map(|token| (token.text(), token.local_sid()))
+            .collect();
+        assert_eq!(
+            annotations,
+            vec![(None, Some(0)), (Some("a"), None), (None, Some(10_000))]
+        );
+        assert_eq!(round_tripped.as_sym().unwrap().local_sid(), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn annotated_elements_round_trip_through_text() -> IonResult<()> {
+        let mut random = Random(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..200 {
+            let element = random_element(&mut random, 3, false);
+            let text = element.to_string();
+            let mut reader = Reader::new(RawTextReader::new(text.as_str()));
+            assert!(reader.next()?.is_some());
+            assert_eq!(element, reader.read_element()?.unwrap(), "{}", text);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn display_element() {
+        let element = element_reader()
+            .read_one(br#"a::{b: [1, null.timestamp, (c null.list), "d"]}"#)
+            .unwrap();
+        // the text writer emits trailing delimiters within containers
+        assert_eq!(
+            "'a'::{b:[1,null.timestamp,('c' null.list ),\"d\",],}",
+            format!("{}", element)
+        );
+    }
+}
+
+#[cfg(test)]
+mod encoded_size_hint_tests {
+    use super::*;
+    use crate::binary::constants::v1_0::IVM;
+    use crate::value::owned::OwnedElement;
+    use rstest::*;
+
+    fn read_one(text: &str) -> IonResult<OwnedElement> {
+        Ok(OwnedElement::read_all(text.as_bytes())?.remove(0))
+    }
+
+    // Returns the number of bytes that `element` occupies in a binary stream, excluding the IVM
+    // and the local symbol table.
+    fn encoded_size(element: &OwnedElement) -> IonResult<usize> {
+        let mut buffer = Vec::new();
+        let mut writer = BinarySystemWriter::new(&mut buffer);
+        writer.write_element(element)?;
+        writer.flush()?;
+        let symbol_table_bytes = writer.stats().symbol_table_bytes();
+        drop(writer);
+        Ok(buffer.len() - IVM.len() - symbol_table_bytes)
+    }
+
+    #[rstest]
+    #[case::null("null.struct", true)]
+    #[case::bool("true", true)]
+    #[case::ints("[0, -1, 300, 9223372036854775807]", true)]
+    #[case::floats("(0e0 -0e0 1.5e0)", true)]
+    #[case::decimals("[0d0, 1.50, -0d-3, 123456789012345678901234567890.1]", true)]
+    #[case::timestamps(
+        "[2021T, 2021-02-03T04:05:06.789-08:00, 2021-02-03T04:05:06.1234567890123Z]",
+        true
+    )]
+    #[case::long_string(
+        "\"a string that is too long to have its length in the type descriptor\"",
+        true
+    )]
+    #[case::lobs("[{{\"clob\"}}, {{YmxvYg==}}]", true)]
+    #[case::symbol_ids("$0::{$0: $0}", true)]
+    #[case::symbols("[foo, bar::baz]", false)]
+    #[case::nested_struct(
+        "a::b::{name: \"widget\", parts: [{id: 1}, {id: 2, tags: (x y)}]}",
+        false
+    )]
+    fn size_hint_covers_encoded_size(#[case] text: &str, #[case] is_exact: bool) -> IonResult<()> {
+        let element = read_one(&text)?;
+        let actual = encoded_size(&element)?;
+        let hint = encoded_size_hint(&element);
+        if is_exact {
+            assert_eq!(hint, actual, "{}", text);
+        } else {
+            assert!(hint >= actual, "{}: {} < {}", text, hint, actual);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn size_hint_for_large_container() -> IonResult<()> {
+        let text = format!("[{}]", vec!["\"0123456789\""; 10_000].join(", "));
+        let element = read_one(&text)?;
+        // A three-byte VarUInt length follows the list's type descriptor
+        assert_eq!(encoded_size_hint(&element), 1 + 3 + 11 * 10_000);
+        assert_eq!(encoded_size_hint(&element), encoded_size(&element)?);
+        Ok(())
+    }
 }
@@ -1,10 +1,13 @@
 // Copyright Amazon.com, Inc. or its affiliates.
 
+// These tests compare the writers against the Ion C-backed element reader and writer.
+#![cfg(feature = "ion-c")]
+
 use ion_rs::result::{decoding_error, IonError, IonResult};
 use ion_rs::value::owned::OwnedElement;
 use ion_rs::value::reader::{element_reader, ElementReader};
 use ion_rs::value::writer::{ElementWriter, Format, SliceElementWriter, TextKind};
-use ion_rs::value::{Element, Sequence, SymbolToken};
+use ion_rs::value::{streams_ion_eq, Element, Sequence, SymbolToken};
 use pretty_hex::*;
 use std::fs::read;
 use std::path::MAIN_SEPARATOR as PATH_SEPARATOR;
@@ -33,18 +36,8 @@ const ALL_SKIP_LIST: &[&str] = &[
     "ion-tests/iontestdata/good/typecodes/T7-small.10n",
 ];
 
-/// Files that should not be tested for equivalence with read_one against read_all
-const READ_ONE_EQUIVS_SKIP_LIST: &[&str] = &[
-    // we need a structural equality (IonEq) for these (amzn/ion-rust#220)
-    "ion-tests/iontestdata/good/floatSpecials.ion",
-];
-
 /// Files that should not be tested for equivalence in round-trip testing
 const ROUND_TRIP_SKIP_LIST: &[&str] = &[
-    // we need a structural equality (IonEq) for these (amzn/ion-rust#220)
-    "ion-tests/iontestdata/good/float32.10n",
-    "ion-tests/iontestdata/good/floatSpecials.ion",
-    "ion-tests/iontestdata/good/non-equivs/floats.ion",
     // appears to be a bug with Ion C or ion-c-sys (specifically binary) (amzn/ion-rust#235)
     "ion-tests/iontestdata/good/equivs/bigInts.ion",
     "ion-tests/iontestdata/good/subfieldUInt.ion",
@@ -55,10 +48,6 @@ const EQUIVS_SKIP_LIST: &[&str] = &[];
 
 /// Files that should only be skipped in non-equivalence file testing
 const NON_EQUIVS_SKIP_LIST: &[&str] = &[
-    // we need a structural equality (IonEq) for these (amzn/ion-rust#220)
-    "ion-tests/iontestdata/good/non-equivs/decimals.ion",
-    "ion-tests/iontestdata/good/non-equivs/floatsVsDecimals.ion",
-    "ion-tests/iontestdata/good/non-equivs/floats.ion",
     // these have symbols with unknown text (amzn/ion-rust#219)
     "ion-tests/iontestdata/good/non-equivs/symbolTablesUnknownText.ion",
 ];
@@ -84,6 +73,26 @@ fn contains_path(paths: &[&str], file_name: &str) -> bool {
         .is_some()
 }
 
+/// Asserts that two elements are equivalent under the Ion data model.
+fn assert_ion_eq(this: &OwnedElement, that: &OwnedElement) {
+    assert!(
+        this.ion_eq(that),
+        "{:?} is not equivalent to {:?}",
+        this,
+        that
+    );
+}
+
+/// Asserts that two streams of elements are equivalent under the Ion data model.
+fn assert_streams_ion_eq(this: &[OwnedElement], that: &[OwnedElement]) {
+    assert!(
+        streams_ion_eq(this, that),
+        "{:?} is not equivalent to {:?}",
+        this,
+        that
+    );
+}
+
 fn read_file<R: ElementReader>(reader: &R, file_name: &str) -> IonResult<Vec<OwnedElement>> {
     // TODO have a better API that doesn't require buffering into memory everything...
     let data = read(file_name)?;
@@ -95,12 +104,7 @@ fn read_file<R: ElementReader>(reader: &R, file_name: &str) -> IonResult<Vec<Own
         Ok(elems) => {
             if elems.len() == 1 {
                 match single_result {
-                    Ok(elem) => {
-                        // only compare if we know equality to work
-                        if !contains_path(READ_ONE_EQUIVS_SKIP_LIST, file_name) {
-                            assert_eq!(elems[0], elem)
-                        }
-                    }
+                    Ok(elem) => assert_ion_eq(&elems[0], &elem),
                     Err(e) => panic!("Expected element {:?}, got {:?}", elems, e),
                 }
             } else {
@@ -110,7 +114,7 @@ fn read_file<R: ElementReader>(reader: &R, file_name: &str) -> IonResult<Vec<Own
                         elems, elem
                     ),
                     Err(e) => match e {
-                        IonError::DecodingError { description: _ } => (),
+                        IonError::DecodingError { .. } => (),
                         other => {
                             panic!("Got an error we did not expect for duplicates: {:?}", other)
                         }
@@ -141,7 +145,13 @@ where
     writer.write_all(source_elements)?;
     let output = writer.finish()?;
     let new_elements = element_reader().read_all(output)?;
-    assert_eq!(*source_elements, new_elements, "{:?}", output.hex_dump());
+    assert!(
+        streams_ion_eq(source_elements, &new_elements),
+        "{:?} is not equivalent to {:?}\n{:?}",
+        source_elements,
+        new_elements,
+        output.hex_dump()
+    );
     Ok(new_elements)
 }
 
@@ -160,7 +170,7 @@ where
     }
     let first_write_elements = assert_round_trip(&source_elements, first_writer)?;
     let second_write_elements = assert_round_trip(&first_write_elements, second_writer)?;
-    assert_eq!(source_elements, second_write_elements);
+    assert_streams_ion_eq(&source_elements, &second_write_elements);
     Ok(())
 }
 
@@ -357,8 +367,8 @@ fn equivs(file_name: &str) {
         read_group(
             element_reader(),
             file_name,
-            |this, that| assert_eq!(this, that),
-            |this_group, that_group| assert_eq!(this_group, that_group),
+            |this, that| assert_ion_eq(this, that),
+            |this_group, that_group| assert_streams_ion_eq(this_group, that_group),
         )
     });
 }
@@ -375,16 +385,21 @@ fn non_equivs(file_name: &str) {
             file_name,
             |this, that| {
                 if std::ptr::eq(this, that) {
-                    assert_eq!(this, that);
+                    assert_ion_eq(this, that);
                 } else {
-                    assert_ne!(this, that);
+                    assert!(!this.ion_eq(that), "{:?} is equivalent to {:?}", this, that);
                 }
             },
             |this_group, that_group| {
                 if std::ptr::eq(this_group, that_group) {
-                    assert_eq!(this_group, that_group);
+                    assert_streams_ion_eq(this_group, that_group);
                 } else {
-                    assert_ne!(this_group, that_group);
+                    assert!(
+                        !streams_ion_eq(this_group, that_group),
+                        "{:?} is equivalent to {:?}",
+                        this_group,
+                        that_group
+                    );
                 }
             },
         )